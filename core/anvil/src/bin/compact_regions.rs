@@ -0,0 +1,117 @@
+//! Offline tool which checks and compacts the region files of a world.
+//!
+//! Usage: `feather-compact-regions [--check] [--compression <codec>] <world directory>`
+//!
+//! With `--check`, region files are only checked for corrupt chunk
+//! entries and no files are modified. Otherwise, each region file is
+//! rewritten without gaps between chunks, removing corrupt chunk entries.
+//!
+//! With `--compression`, all chunks are also converted to the given
+//! compression: one of `gzip`, `zlib`, `none`, `lz4` or `zstd`.
+//!
+//! The server must not be running on the world while it is compacted.
+
+use feather_anvil::region::{self, ChunkCompression, CompactionReport, RegionPosition};
+use std::path::PathBuf;
+use std::process;
+
+fn main() -> anyhow::Result<()> {
+    let mut check_only = false;
+    let mut compression = None;
+    let mut world_dir = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => check_only = true,
+            "--compression" => {
+                let name = args.next().unwrap_or_else(|| usage());
+                compression = Some(ChunkCompression::from_name(&name).unwrap_or_else(|| usage()));
+            }
+            _ if world_dir.is_none() => world_dir = Some(PathBuf::from(&arg)),
+            _ => usage(),
+        }
+    }
+    let world_dir = world_dir.unwrap_or_else(|| usage());
+
+    let mut regions = vec![];
+    for entry in std::fs::read_dir(world_dir.join("region"))? {
+        let name = entry?.file_name();
+        if let Some(pos) = name.to_str().and_then(parse_region_file_name) {
+            regions.push((name, pos));
+        }
+    }
+    regions.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut total = CompactionReport::default();
+    for (name, pos) in regions {
+        let name = name.to_string_lossy();
+        let result = match (check_only, compression) {
+            (true, _) => region::check_region(&world_dir, pos),
+            (false, Some(compression)) => region::convert_region(&world_dir, pos, compression),
+            (false, None) => region::compact_region(&world_dir, pos),
+        };
+
+        let report = match result {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("{}: failed to read region: {}", name, e);
+                continue;
+            }
+        };
+
+        println!(
+            "{}: {} chunks, {} -> {} sectors",
+            name, report.chunks, report.sectors_before, report.sectors_after
+        );
+        for corrupt in &report.corrupt_chunks {
+            println!("    chunk {}: {}", corrupt.position, corrupt.problem);
+        }
+
+        total.chunks += report.chunks;
+        total.sectors_before += report.sectors_before;
+        total.sectors_after += report.sectors_after;
+        total.corrupt_chunks.extend(report.corrupt_chunks);
+    }
+
+    // Converting to a weaker compression may grow the regions.
+    let saved_mib =
+        (total.sectors_before as f64 - total.sectors_after as f64) * 4096.0 / (1024.0 * 1024.0);
+    if check_only {
+        println!(
+            "Checked {} chunks: {} corrupt, compaction would save {:.1} MiB",
+            total.chunks,
+            total.corrupt_chunks.len(),
+            saved_mib
+        );
+    } else {
+        println!(
+            "Compacted {} chunks: removed {} corrupt, saved {:.1} MiB",
+            total.chunks,
+            total.corrupt_chunks.len(),
+            saved_mib
+        );
+    }
+
+    Ok(())
+}
+
+/// Parses the position of a region from its file name, `r.<x>.<z>.mca`.
+fn parse_region_file_name(name: &str) -> Option<RegionPosition> {
+    let mut parts = name.split('.');
+    if parts.next() != Some("r") {
+        return None;
+    }
+    let x = parts.next()?.parse().ok()?;
+    let z = parts.next()?.parse().ok()?;
+    if parts.next() != Some("mca") || parts.next().is_some() {
+        return None;
+    }
+    Some(RegionPosition::new(x, z))
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "Usage: feather-compact-regions [--check] [--compression <gzip|zlib|none|lz4|zstd>] <world directory>"
+    );
+    process::exit(1);
+}
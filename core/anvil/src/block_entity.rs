@@ -29,6 +29,14 @@ pub struct BlockEntityBase {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "id")]
 pub enum BlockEntityKind {
+    #[serde(rename = "minecraft:banner")]
+    #[serde(rename_all = "PascalCase")]
+    Banner {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+        #[serde(default)]
+        patterns: Vec<BannerPattern>,
+    },
     #[serde(rename = "minecraft:beacon")]
     #[serde(rename_all = "PascalCase")]
     Beacon {
@@ -42,8 +50,13 @@ pub enum BlockEntityKind {
     #[serde(rename = "minecraft:brewing_stand")]
     #[serde(rename_all = "PascalCase")]
     BrewingStand {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+        #[serde(default)]
         items: Vec<InventorySlot>,
+        #[serde(default)]
         brew_time: i16,
+        #[serde(default)]
         fuel: i8,
     },
     #[serde(rename = "minecraft:cauldron")]
@@ -84,10 +97,20 @@ pub enum BlockEntityKind {
     DaylightDetector, // empty
     #[serde(rename = "minecraft:dispenser")]
     #[serde(rename_all = "PascalCase")]
-    Dispenser { items: Vec<InventorySlot> },
+    Dispenser {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+        #[serde(default)]
+        items: Vec<InventorySlot>,
+    },
     #[serde(rename = "minecraft:dropper")]
     #[serde(rename_all = "PascalCase")]
-    Dropper { items: Vec<InventorySlot> },
+    Dropper {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+        #[serde(default)]
+        items: Vec<InventorySlot>,
+    },
     #[serde(rename = "minecraft:enchanting_table")]
     #[serde(rename_all = "PascalCase")]
     EnchantingTable,
@@ -103,15 +126,25 @@ pub enum BlockEntityKind {
     #[serde(rename = "minecraft:furnace")]
     #[serde(rename_all = "PascalCase")]
     Furnace {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+        #[serde(default)]
         items: Vec<InventorySlot>,
+        #[serde(default)]
         burn_time: i16,
+        #[serde(default)]
         cook_time: i16,
+        #[serde(default)]
         cook_time_total: i16,
     },
     #[serde(rename = "minecraft:hopper")]
     #[serde(rename_all = "PascalCase")]
     Hopper {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        custom_name: Option<String>,
+        #[serde(default)]
         items: Vec<InventorySlot>,
+        #[serde(default)]
         transfer_cooldown: i32,
     },
     #[serde(rename = "minecraft:jigsaw")]
@@ -126,6 +159,25 @@ pub enum BlockEntityKind {
     #[serde(rename = "minecraft:jukebox")]
     #[serde(rename_all = "PascalCase")]
     Jukebox { record_item: InventorySlot },
+    #[serde(rename = "minecraft:sign")]
+    #[serde(rename_all = "PascalCase")]
+    Sign {
+        /// JSON text of each line.
+        #[serde(default = "empty_sign_line")]
+        text1: String,
+        #[serde(default = "empty_sign_line")]
+        text2: String,
+        #[serde(default = "empty_sign_line")]
+        text3: String,
+        #[serde(default = "empty_sign_line")]
+        text4: String,
+    },
+    #[serde(rename = "minecraft:skull")]
+    #[serde(rename_all = "PascalCase")]
+    Skull {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        owner: Option<SkullOwner>,
+    },
     // TODO: a few more
     /// Fallback type for unknown block entities
     #[serde(other, serialize_with = "BlockEntityKind::serialize_unknown")]
@@ -139,6 +191,7 @@ impl BlockEntityKind {
 
     pub fn variant(&self) -> BlockEntityVariant {
         match self {
+            BlockEntityKind::Banner { .. } => BlockEntityVariant::Banner,
             BlockEntityKind::Beacon { .. } => BlockEntityVariant::Beacon,
             BlockEntityKind::Bed { .. } => BlockEntityVariant::Bed,
             BlockEntityKind::BrewingStand { .. } => BlockEntityVariant::BrewingStand,
@@ -157,6 +210,8 @@ impl BlockEntityKind {
             BlockEntityKind::Hopper { .. } => BlockEntityVariant::Hopper,
             BlockEntityKind::Jigsaw { .. } => BlockEntityVariant::Jigsaw,
             BlockEntityKind::Jukebox { .. } => BlockEntityVariant::Jukebox,
+            BlockEntityKind::Sign { .. } => BlockEntityVariant::Sign,
+            BlockEntityKind::Skull { .. } => BlockEntityVariant::Skull,
             BlockEntityKind::Unknown { .. } => BlockEntityVariant::Unknown,
         }
     }
@@ -165,6 +220,7 @@ impl BlockEntityKind {
 /// Variant of a `BlockEntityKind`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum BlockEntityVariant {
+    Banner,
     Beacon,
     Bed,
     BrewingStand,
@@ -183,5 +239,184 @@ pub enum BlockEntityVariant {
     Hopper,
     Jigsaw,
     Jukebox,
+    Sign,
+    Skull,
     Unknown,
 }
+
+/// The JSON text of an empty sign line.
+pub fn empty_sign_line() -> String {
+    String::from(r#"{"text":""}"#)
+}
+
+/// A pattern layer on a banner.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct BannerPattern {
+    /// Dye color of the pattern, from 0 (white) to 15 (black).
+    pub color: i32,
+    /// Short code of the pattern, e.g. `bs` for a bottom stripe.
+    pub pattern: String,
+}
+
+/// The player whose head is shown by a player head.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SkullOwner {
+    /// UUID of the player, as a hyphenated string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SkullProperties>,
+}
+
+/// Profile properties of a skull owner.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkullProperties {
+    #[serde(default)]
+    pub textures: Vec<SkullTexture>,
+}
+
+/// A skin texture of a skull owner.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SkullTexture {
+    /// Base64-encoded texture information.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nbt::{Blob, Value};
+
+    fn round_trip(blob: &Blob) -> BlockEntityData {
+        let mut buf = vec![];
+        blob.to_writer(&mut buf).unwrap();
+        let data: BlockEntityData = nbt::from_reader(buf.as_slice()).unwrap();
+
+        let mut buf = vec![];
+        nbt::to_writer(&mut buf, &data, None).unwrap();
+        nbt::from_reader(buf.as_slice()).unwrap()
+    }
+
+    fn base(id: &str) -> Blob {
+        let mut blob = Blob::new();
+        blob.insert("id", id).unwrap();
+        blob.insert("x", 1).unwrap();
+        blob.insert("y", 64).unwrap();
+        blob.insert("z", -3).unwrap();
+        blob
+    }
+
+    #[test]
+    fn test_round_trip_sign() {
+        let mut blob = base("minecraft:sign");
+        blob.insert("Text1", r#"{"text":"Hello"}"#).unwrap();
+        blob.insert("Text2", r#"{"text":""}"#).unwrap();
+        blob.insert("Text3", r#"{"text":"world"}"#).unwrap();
+        blob.insert("Text4", r#"{"text":""}"#).unwrap();
+
+        let data = round_trip(&blob);
+        assert_eq!((data.base.x, data.base.y, data.base.z), (1, 64, -3));
+        match data.kind {
+            BlockEntityKind::Sign {
+                text1,
+                text2,
+                text3,
+                text4,
+            } => {
+                assert_eq!(text1, r#"{"text":"Hello"}"#);
+                assert_eq!(text2, empty_sign_line());
+                assert_eq!(text3, r#"{"text":"world"}"#);
+                assert_eq!(text4, empty_sign_line());
+            }
+            kind => panic!("expected sign, got {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_furnace() {
+        let item = compound(vec![
+            ("Slot", Value::Byte(0)),
+            ("id", Value::String(String::from("minecraft:iron_ore"))),
+            ("Count", Value::Byte(12)),
+        ]);
+
+        let mut blob = base("minecraft:furnace");
+        blob.insert("Items", Value::List(vec![item])).unwrap();
+        blob.insert("BurnTime", 120i16).unwrap();
+        blob.insert("CookTime", 40i16).unwrap();
+        blob.insert("CookTimeTotal", 200i16).unwrap();
+
+        match round_trip(&blob).kind {
+            BlockEntityKind::Furnace {
+                custom_name,
+                items,
+                burn_time,
+                cook_time,
+                cook_time_total,
+            } => {
+                assert_eq!(custom_name, None);
+                assert_eq!(items.len(), 1);
+                assert_eq!(items[0].item, "minecraft:iron_ore");
+                assert_eq!(items[0].count, 12);
+                assert_eq!((burn_time, cook_time, cook_time_total), (120, 40, 200));
+            }
+            kind => panic!("expected furnace, got {:?}", kind),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_banner_and_skull() {
+        let pattern = compound(vec![
+            ("Color", Value::Int(14)),
+            ("Pattern", Value::String(String::from("bs"))),
+        ]);
+        let mut blob = base("minecraft:banner");
+        blob.insert("Patterns", Value::List(vec![pattern])).unwrap();
+
+        match round_trip(&blob).kind {
+            BlockEntityKind::Banner {
+                custom_name,
+                patterns,
+            } => {
+                assert_eq!(custom_name, None);
+                assert_eq!(
+                    patterns,
+                    vec![BannerPattern {
+                        color: 14,
+                        pattern: String::from("bs"),
+                    }]
+                );
+            }
+            kind => panic!("expected banner, got {:?}", kind),
+        }
+
+        let owner = compound(vec![("Name", Value::String(String::from("Notch")))]);
+        let mut blob = base("minecraft:skull");
+        blob.insert("Owner", owner).unwrap();
+
+        match round_trip(&blob).kind {
+            BlockEntityKind::Skull { owner } => {
+                let owner = owner.unwrap();
+                assert_eq!(owner.name.as_deref(), Some("Notch"));
+                assert_eq!(owner.id, None);
+            }
+            kind => panic!("expected skull, got {:?}", kind),
+        }
+    }
+
+    fn compound(entries: Vec<(&str, Value)>) -> Value {
+        Value::Compound(
+            entries
+                .into_iter()
+                .map(|(name, value)| (name.to_owned(), value))
+                .collect(),
+        )
+    }
+}
@@ -1,5 +1,5 @@
 use arrayvec::ArrayVec;
-use feather_items::{Item, ItemStack};
+use feather_items::{Item, ItemStack, NbtCompound};
 use feather_util::{vec3, Position, Vec3d};
 use serde::ser::Error;
 use serde::{Deserialize, Serialize, Serializer};
@@ -9,16 +9,63 @@ use thiserror::Error;
 pub enum EntityDataKind {
     Item,
     Arrow,
-    Cow,
-    Pig,
+    Bat,
+    Blaze,
+    CaveSpider,
     Chicken,
-    Sheep,
+    Cod,
+    Cow,
+    Creeper,
+    Dolphin,
+    Donkey,
+    Drowned,
+    ElderGuardian,
+    EnderDragon,
+    Enderman,
+    Endermite,
+    Evoker,
+    Ghast,
+    Giant,
+    Guardian,
     Horse,
+    Husk,
+    Illusioner,
+    IronGolem,
     Llama,
+    MagmaCube,
     Mooshroom,
+    Mule,
+    Ocelot,
+    Parrot,
+    Phantom,
+    Pig,
+    PolarBear,
+    Pufferfish,
     Rabbit,
+    Salmon,
+    Sheep,
+    Shulker,
+    Silverfish,
+    Skeleton,
+    SkeletonHorse,
+    Slime,
+    SnowGolem,
+    Spider,
     Squid,
-    Donkey,
+    Stray,
+    TropicalFish,
+    Turtle,
+    Vex,
+    Villager,
+    Vindicator,
+    Witch,
+    Wither,
+    WitherSkeleton,
+    Wolf,
+    Zombie,
+    ZombieHorse,
+    ZombiePigman,
+    ZombieVillager,
     Unknown,
 }
 
@@ -27,16 +74,63 @@ impl<'a> From<&'a EntityData> for EntityDataKind {
         match data {
             EntityData::Arrow(_) => EntityDataKind::Arrow,
             EntityData::Item(_) => EntityDataKind::Item,
-            EntityData::Cow(_) => EntityDataKind::Cow,
-            EntityData::Pig(_) => EntityDataKind::Pig,
+            EntityData::Bat(_) => EntityDataKind::Bat,
+            EntityData::Blaze(_) => EntityDataKind::Blaze,
+            EntityData::CaveSpider(_) => EntityDataKind::CaveSpider,
             EntityData::Chicken(_) => EntityDataKind::Chicken,
-            EntityData::Sheep(_) => EntityDataKind::Sheep,
+            EntityData::Cod(_) => EntityDataKind::Cod,
+            EntityData::Cow(_) => EntityDataKind::Cow,
+            EntityData::Creeper(_) => EntityDataKind::Creeper,
+            EntityData::Dolphin(_) => EntityDataKind::Dolphin,
+            EntityData::Donkey(_) => EntityDataKind::Donkey,
+            EntityData::Drowned(_) => EntityDataKind::Drowned,
+            EntityData::ElderGuardian(_) => EntityDataKind::ElderGuardian,
+            EntityData::EnderDragon(_) => EntityDataKind::EnderDragon,
+            EntityData::Enderman(_) => EntityDataKind::Enderman,
+            EntityData::Endermite(_) => EntityDataKind::Endermite,
+            EntityData::Evoker(_) => EntityDataKind::Evoker,
+            EntityData::Ghast(_) => EntityDataKind::Ghast,
+            EntityData::Giant(_) => EntityDataKind::Giant,
+            EntityData::Guardian(_) => EntityDataKind::Guardian,
             EntityData::Horse(_) => EntityDataKind::Horse,
+            EntityData::Husk(_) => EntityDataKind::Husk,
+            EntityData::Illusioner(_) => EntityDataKind::Illusioner,
+            EntityData::IronGolem(_) => EntityDataKind::IronGolem,
             EntityData::Llama(_) => EntityDataKind::Llama,
+            EntityData::MagmaCube(_) => EntityDataKind::MagmaCube,
             EntityData::Mooshroom(_) => EntityDataKind::Mooshroom,
+            EntityData::Mule(_) => EntityDataKind::Mule,
+            EntityData::Ocelot(_) => EntityDataKind::Ocelot,
+            EntityData::Parrot(_) => EntityDataKind::Parrot,
+            EntityData::Phantom(_) => EntityDataKind::Phantom,
+            EntityData::Pig(_) => EntityDataKind::Pig,
+            EntityData::PolarBear(_) => EntityDataKind::PolarBear,
+            EntityData::Pufferfish(_) => EntityDataKind::Pufferfish,
             EntityData::Rabbit(_) => EntityDataKind::Rabbit,
+            EntityData::Salmon(_) => EntityDataKind::Salmon,
+            EntityData::Sheep(_) => EntityDataKind::Sheep,
+            EntityData::Shulker(_) => EntityDataKind::Shulker,
+            EntityData::Silverfish(_) => EntityDataKind::Silverfish,
+            EntityData::Skeleton(_) => EntityDataKind::Skeleton,
+            EntityData::SkeletonHorse(_) => EntityDataKind::SkeletonHorse,
+            EntityData::Slime(_) => EntityDataKind::Slime,
+            EntityData::SnowGolem(_) => EntityDataKind::SnowGolem,
+            EntityData::Spider(_) => EntityDataKind::Spider,
             EntityData::Squid(_) => EntityDataKind::Squid,
-            EntityData::Donkey(_) => EntityDataKind::Donkey,
+            EntityData::Stray(_) => EntityDataKind::Stray,
+            EntityData::TropicalFish(_) => EntityDataKind::TropicalFish,
+            EntityData::Turtle(_) => EntityDataKind::Turtle,
+            EntityData::Vex(_) => EntityDataKind::Vex,
+            EntityData::Villager(_) => EntityDataKind::Villager,
+            EntityData::Vindicator(_) => EntityDataKind::Vindicator,
+            EntityData::Witch(_) => EntityDataKind::Witch,
+            EntityData::Wither(_) => EntityDataKind::Wither,
+            EntityData::WitherSkeleton(_) => EntityDataKind::WitherSkeleton,
+            EntityData::Wolf(_) => EntityDataKind::Wolf,
+            EntityData::Zombie(_) => EntityDataKind::Zombie,
+            EntityData::ZombieHorse(_) => EntityDataKind::ZombieHorse,
+            EntityData::ZombiePigman(_) => EntityDataKind::ZombiePigman,
+            EntityData::ZombieVillager(_) => EntityDataKind::ZombieVillager,
             EntityData::Unknown => EntityDataKind::Unknown,
         }
     }
@@ -49,26 +143,120 @@ pub enum EntityData {
     Item(ItemEntityData),
     #[serde(rename = "minecraft:arrow")]
     Arrow(ArrowEntityData),
-    #[serde(rename = "minecraft:cow")]
-    Cow(AnimalData),
-    #[serde(rename = "minecraft:pig")]
-    Pig(AnimalData),
+    #[serde(rename = "minecraft:bat")]
+    Bat(MobData),
+    #[serde(rename = "minecraft:blaze")]
+    Blaze(MobData),
+    #[serde(rename = "minecraft:cave_spider")]
+    CaveSpider(MobData),
     #[serde(rename = "minecraft:chicken")]
-    Chicken(AnimalData),
-    #[serde(rename = "minecraft:sheep")]
-    Sheep(AnimalData),
+    Chicken(MobData),
+    #[serde(rename = "minecraft:cod")]
+    Cod(MobData),
+    #[serde(rename = "minecraft:cow")]
+    Cow(MobData),
+    #[serde(rename = "minecraft:creeper")]
+    Creeper(MobData),
+    #[serde(rename = "minecraft:dolphin")]
+    Dolphin(MobData),
+    #[serde(rename = "minecraft:donkey")]
+    Donkey(MobData),
+    #[serde(rename = "minecraft:drowned")]
+    Drowned(MobData),
+    #[serde(rename = "minecraft:elder_guardian")]
+    ElderGuardian(MobData),
+    #[serde(rename = "minecraft:ender_dragon")]
+    EnderDragon(MobData),
+    #[serde(rename = "minecraft:enderman")]
+    Enderman(MobData),
+    #[serde(rename = "minecraft:endermite")]
+    Endermite(MobData),
+    #[serde(rename = "minecraft:evoker")]
+    Evoker(MobData),
+    #[serde(rename = "minecraft:ghast")]
+    Ghast(MobData),
+    #[serde(rename = "minecraft:giant")]
+    Giant(MobData),
+    #[serde(rename = "minecraft:guardian")]
+    Guardian(MobData),
     #[serde(rename = "minecraft:horse")]
-    Horse(AnimalData),
+    Horse(MobData),
+    #[serde(rename = "minecraft:husk")]
+    Husk(MobData),
+    #[serde(rename = "minecraft:illusioner")]
+    Illusioner(MobData),
+    #[serde(rename = "minecraft:iron_golem")]
+    IronGolem(MobData),
     #[serde(rename = "minecraft:llama")]
-    Llama(AnimalData),
-    #[serde(rename = "minectaft:mooshroom")]
-    Mooshroom(AnimalData),
-    #[serde(rename = "minecraft:rabbit}")]
-    Rabbit(AnimalData),
+    Llama(MobData),
+    #[serde(rename = "minecraft:magma_cube")]
+    MagmaCube(MobData),
+    #[serde(rename = "minecraft:mooshroom")]
+    Mooshroom(MobData),
+    #[serde(rename = "minecraft:mule")]
+    Mule(MobData),
+    #[serde(rename = "minecraft:ocelot")]
+    Ocelot(MobData),
+    #[serde(rename = "minecraft:parrot")]
+    Parrot(MobData),
+    #[serde(rename = "minecraft:phantom")]
+    Phantom(MobData),
+    #[serde(rename = "minecraft:pig")]
+    Pig(MobData),
+    #[serde(rename = "minecraft:polar_bear")]
+    PolarBear(MobData),
+    #[serde(rename = "minecraft:pufferfish")]
+    Pufferfish(MobData),
+    #[serde(rename = "minecraft:rabbit")]
+    Rabbit(MobData),
+    #[serde(rename = "minecraft:salmon")]
+    Salmon(MobData),
+    #[serde(rename = "minecraft:sheep")]
+    Sheep(MobData),
+    #[serde(rename = "minecraft:shulker")]
+    Shulker(MobData),
+    #[serde(rename = "minecraft:silverfish")]
+    Silverfish(MobData),
+    #[serde(rename = "minecraft:skeleton")]
+    Skeleton(MobData),
+    #[serde(rename = "minecraft:skeleton_horse")]
+    SkeletonHorse(MobData),
+    #[serde(rename = "minecraft:slime")]
+    Slime(MobData),
+    #[serde(rename = "minecraft:snow_golem")]
+    SnowGolem(MobData),
+    #[serde(rename = "minecraft:spider")]
+    Spider(MobData),
     #[serde(rename = "minecraft:squid")]
-    Squid(AnimalData),
-    #[serde(rename = "minecraft:donkey")]
-    Donkey(AnimalData),
+    Squid(MobData),
+    #[serde(rename = "minecraft:stray")]
+    Stray(MobData),
+    #[serde(rename = "minecraft:tropical_fish")]
+    TropicalFish(MobData),
+    #[serde(rename = "minecraft:turtle")]
+    Turtle(MobData),
+    #[serde(rename = "minecraft:vex")]
+    Vex(MobData),
+    #[serde(rename = "minecraft:villager")]
+    Villager(MobData),
+    #[serde(rename = "minecraft:vindicator")]
+    Vindicator(MobData),
+    #[serde(rename = "minecraft:witch")]
+    Witch(MobData),
+    #[serde(rename = "minecraft:wither")]
+    Wither(MobData),
+    #[serde(rename = "minecraft:wither_skeleton")]
+    WitherSkeleton(MobData),
+    #[serde(rename = "minecraft:wolf")]
+    Wolf(MobData),
+    #[serde(rename = "minecraft:zombie")]
+    Zombie(MobData),
+    #[serde(rename = "minecraft:zombie_horse")]
+    ZombieHorse(MobData),
+    #[serde(rename = "minecraft:zombie_pigman")]
+    ZombiePigman(MobData),
+    #[serde(rename = "minecraft:zombie_villager")]
+    ZombieVillager(MobData),
 
     /// Fallback type for unknown entities
     #[serde(other, serialize_with = "EntityData::serialize_unknown")]
@@ -168,6 +356,85 @@ impl Default for AnimalData {
     }
 }
 
+/// Tags common to all mobs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MobData {
+    #[serde(flatten)]
+    pub animal: AnimalData,
+    /// JSON text of the mob's name tag.
+    #[serde(rename = "CustomName", skip_serializing_if = "Option::is_none")]
+    pub custom_name: Option<String>,
+    // TODO: Change this field to `bool` when issue with hematite_nbt is resolved.
+    // See: https://github.com/PistonDevelopers/hematite_nbt/issues/43
+    #[serde(rename = "CustomNameVisible", default)]
+    pub custom_name_visible: i8,
+    /// Age in ticks. Negative for babies, which grow up at zero;
+    /// positive for adults which may not breed yet.
+    #[serde(rename = "Age", default)]
+    pub age: i32,
+    /// Whether the mob never despawns naturally.
+    #[serde(rename = "PersistenceRequired", default)]
+    pub persistence_required: i8,
+    #[serde(rename = "Attributes", default)]
+    pub attributes: Vec<AttributeData>,
+}
+
+impl MobData {
+    /// Creates a `MobData` with the given base data and health
+    /// and no other tags set.
+    pub fn new(base: BaseEntityData, health: f32) -> Self {
+        Self {
+            animal: AnimalData::new(base, health),
+            custom_name: None,
+            custom_name_visible: 0,
+            age: 0,
+            persistence_required: 0,
+            attributes: vec![],
+        }
+    }
+
+    /// Returns the attribute with the given name, e.g. `generic.maxHealth`.
+    pub fn attribute(&self, name: &str) -> Option<&AttributeData> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+}
+
+impl Default for MobData {
+    fn default() -> Self {
+        Self::new(Default::default(), 20.0)
+    }
+}
+
+/// An attribute of a mob, such as its maximum health or movement speed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AttributeData {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Base")]
+    pub base: f64,
+    #[serde(rename = "Modifiers", default, skip_serializing_if = "Vec::is_empty")]
+    pub modifiers: Vec<AttributeModifierData>,
+}
+
+/// A modifier applied to an attribute, e.g. by armor or a potion.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AttributeModifierData {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Amount")]
+    pub amount: f64,
+    /// 0 adds the amount, 1 adds a multiple of the base
+    /// and 2 multiplies the total.
+    #[serde(rename = "Operation")]
+    pub operation: i32,
+    #[serde(rename = "UUIDMost")]
+    pub uuid_most: i64,
+    #[serde(rename = "UUIDLeast")]
+    pub uuid_least: i64,
+}
+
 /// Represents a single item, without slot information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemData {
@@ -231,17 +498,20 @@ where
 pub struct ItemNbt {
     #[serde(rename = "Damage")]
     pub damage: Option<i32>,
-    // TODO enchantments, display name, ...
+    /// All other tags, e.g. enchantments and the display name.
+    #[serde(flatten)]
+    pub tag: NbtCompound,
 }
 
 impl ItemNbt {
     /// Create an `ItemStack` of the specified item and amount, setting any nbt present.
     pub fn item_stack(nbt: &Option<Self>, item: Item, amount: u8) -> ItemStack {
-        ItemStack {
-            ty: item,
-            amount,
-            damage: nbt.as_ref().map(|n| n.damage).flatten(),
+        let mut stack = ItemStack::new(item, amount);
+        if let Some(nbt) = nbt {
+            stack.damage = nbt.damage;
+            stack.modify_tag(|tag| *tag = nbt.tag.clone());
         }
+        stack
     }
 }
 
@@ -253,6 +523,10 @@ where
         let stack = s.borrow();
         Self {
             damage: stack.damage,
+            tag: stack
+                .tag
+                .map(|tag| tag.compound().clone())
+                .unwrap_or_default(),
         }
     }
 }
@@ -293,7 +567,9 @@ pub struct ArrowEntityData {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use feather_items::Enchantment;
     use feather_util::position;
+    use nbt::{Blob, Value};
 
     #[test]
     fn test_read_position() {
@@ -335,4 +611,76 @@ mod tests {
         assert_eq!(data.read_position(), Ok(pos));
         assert_eq!(data.read_velocity(), Ok(vel));
     }
+
+    #[test]
+    fn test_round_trip_mob() {
+        let doubles =
+            |values: &[f64]| Value::List(values.iter().copied().map(Value::Double).collect());
+        let attribute = Value::Compound(
+            vec![
+                (
+                    String::from("Name"),
+                    Value::String(String::from("generic.maxHealth")),
+                ),
+                (String::from("Base"), Value::Double(20.0)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let mut blob = Blob::new();
+        blob.insert("id", "minecraft:zombie").unwrap();
+        blob.insert("Pos", doubles(&[1.5, 64.0, -3.5])).unwrap();
+        blob.insert("Motion", doubles(&[0.0, -0.08, 0.0])).unwrap();
+        blob.insert(
+            "Rotation",
+            Value::List(vec![Value::Float(90.0), Value::Float(0.0)]),
+        )
+        .unwrap();
+        blob.insert("Health", 17.0f32).unwrap();
+        blob.insert("CustomName", r#"{"text":"Bob"}"#).unwrap();
+        blob.insert("PersistenceRequired", 1i8).unwrap();
+        blob.insert("Attributes", Value::List(vec![attribute]))
+            .unwrap();
+        // Tags we don't know about are ignored.
+        blob.insert("CanBreakDoors", 0i8).unwrap();
+
+        let mut buf = vec![];
+        blob.to_writer(&mut buf).unwrap();
+        let data: EntityData = nbt::from_reader(buf.as_slice()).unwrap();
+        assert_eq!(EntityDataKind::from(&data), EntityDataKind::Zombie);
+
+        let mut buf = vec![];
+        nbt::to_writer(&mut buf, &data, None).unwrap();
+        let data: EntityData = nbt::from_reader(buf.as_slice()).unwrap();
+
+        match data {
+            EntityData::Zombie(mob) => {
+                assert_eq!(mob.animal.health, 17.0);
+                assert_eq!(
+                    mob.animal.base.read_position().unwrap(),
+                    position!(1.5, 64.0, -3.5, 0.0, 90.0)
+                );
+                assert_eq!(mob.custom_name.as_deref(), Some(r#"{"text":"Bob"}"#));
+                assert_eq!(mob.persistence_required, 1);
+                assert_eq!(mob.age, 0);
+                assert_eq!(mob.attribute("generic.maxHealth").unwrap().base, 20.0);
+            }
+            data => panic!("expected zombie, got {:?}", data),
+        }
+    }
+
+    #[test]
+    fn test_round_trip_item_tag() {
+        let mut stack = ItemStack::new(Item::DiamondPickaxe, 1);
+        stack.damage = Some(10);
+        stack.add_enchantment(Enchantment::Fortune, 3);
+        stack.set_display_name(Some(r#"{"text":"Digger"}"#.to_owned()));
+
+        let mut buf = vec![];
+        nbt::to_writer(&mut buf, &ItemData::from(stack), None).unwrap();
+        let data: ItemData = nbt::from_reader(buf.as_slice()).unwrap();
+
+        assert_eq!(ItemStack::from(data), stack);
+    }
 }
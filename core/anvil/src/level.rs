@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::io::Cursor;
 use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use uuid::Uuid;
 
 /// Root level tag
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +34,20 @@ pub struct LevelData {
     pub border_safe_zone: f64,
     #[serde(rename = "BorderSize")]
     pub border_size: f64,
+    /// Diameter the border is moving towards.
+    #[serde(default)]
+    #[serde(rename = "BorderSizeLerpTarget")]
+    pub border_size_lerp_target: f64,
+    /// Time, in milliseconds, until the border reaches its target diameter.
+    #[serde(default)]
+    #[serde(rename = "BorderSizeLerpTime")]
+    pub border_size_lerp_time: i64,
+    #[serde(default = "default_border_warning_blocks")]
+    #[serde(rename = "BorderWarningBlocks")]
+    pub border_warning_blocks: f64,
+    #[serde(default = "default_border_warning_time")]
+    #[serde(rename = "BorderWarningTime")]
+    pub border_warning_time: f64,
 
     #[serde(rename = "clearWeatherTime")]
     pub clear_weather_time: i32,
@@ -77,6 +92,23 @@ pub struct LevelData {
     pub generator_name: String,
     #[serde(rename = "generatorOptions")]
     pub generator_options: Option<SuperflatGeneratorOptions>,
+
+    /// Boss bars created using `/bossbar`, keyed by ID.
+    #[serde(default)]
+    #[serde(rename = "CustomBossEvents")]
+    pub custom_boss_events: HashMap<String, BossEventData>,
+
+    #[serde(default)]
+    #[serde(rename = "GameRules")]
+    pub game_rules: GameRules,
+}
+
+fn default_border_warning_blocks() -> f64 {
+    5.0
+}
+
+fn default_border_warning_time() -> f64 {
+    15.0
 }
 
 impl LevelData {
@@ -144,6 +176,100 @@ impl Default for SuperflatGeneratorOptions {
     }
 }
 
+/// The game rules of a level. Values are stored as
+/// strings, keyed by the rule's name, e.g. `randomTickSpeed`.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct GameRules(pub HashMap<String, String>);
+
+impl GameRules {
+    /// Default value of `randomTickSpeed`.
+    pub const DEFAULT_RANDOM_TICK_SPEED: u32 = 3;
+
+    /// Returns the value of an integer game rule, or `None`
+    /// if it is unset or not an integer.
+    pub fn get_int(&self, name: &str) -> Option<i32> {
+        self.0.get(name)?.parse().ok()
+    }
+
+    /// Returns the value of a boolean game rule, or `None`
+    /// if it is unset or not a boolean.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.0.get(name)?.parse().ok()
+    }
+
+    /// Sets the value of a game rule.
+    pub fn set(&mut self, name: &str, value: impl ToString) {
+        self.0.insert(name.to_owned(), value.to_string());
+    }
+
+    /// Number of blocks in each chunk section which
+    /// receive a random tick every game tick.
+    pub fn random_tick_speed(&self) -> u32 {
+        self.get_int("randomTickSpeed")
+            .map(|speed| speed.max(0) as u32)
+            .unwrap_or(Self::DEFAULT_RANDOM_TICK_SPEED)
+    }
+}
+
+/// A custom boss bar.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BossEventData {
+    /// JSON text.
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// One of `pink`, `blue`, `red`, `green`, `yellow`, `purple` or `white`.
+    #[serde(rename = "Color")]
+    pub color: String,
+    /// One of `progress`, `notched_6`, `notched_10`, `notched_12` or `notched_20`.
+    #[serde(rename = "Overlay")]
+    pub overlay: String,
+    #[serde(rename = "Value")]
+    pub value: i32,
+    #[serde(rename = "Max")]
+    pub max: i32,
+    #[serde(rename = "Visible")]
+    pub visible: bool,
+    /// Players the boss bar is shown to.
+    #[serde(default)]
+    #[serde(rename = "Players")]
+    pub players: Vec<UuidData>,
+    #[serde(default)]
+    #[serde(rename = "CreateWorldFog")]
+    pub create_world_fog: bool,
+    #[serde(default)]
+    #[serde(rename = "DarkenScreen")]
+    pub darken_screen: bool,
+    #[serde(default)]
+    #[serde(rename = "PlayBossMusic")]
+    pub play_boss_music: bool,
+}
+
+/// A UUID stored as its most and least significant bits.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct UuidData {
+    #[serde(rename = "M")]
+    pub most: i64,
+    #[serde(rename = "L")]
+    pub least: i64,
+}
+
+impl From<Uuid> for UuidData {
+    fn from(uuid: Uuid) -> Self {
+        let bits = uuid.as_u128();
+        Self {
+            most: (bits >> 64) as i64,
+            least: bits as i64,
+        }
+    }
+}
+
+impl From<UuidData> for Uuid {
+    fn from(data: UuidData) -> Self {
+        Uuid::from_u128(((data.most as u64 as u128) << 64) | data.least as u64 as u128)
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct SuperflatLayer {
     pub block: String, // TODO: Use "Block" enum and implement (de)serialization
@@ -205,5 +331,32 @@ mod tests {
         assert_eq!(level.thunder_time, 5252);
         assert_eq!(level.generator_name, "default");
         assert!(level.generator_options.is_none());
+        assert!(level.custom_boss_events.is_empty());
+        assert_eq!(level.game_rules.random_tick_speed(), 3);
+    }
+
+    #[test]
+    fn test_game_rules() {
+        let mut rules = GameRules::default();
+        assert_eq!(rules.random_tick_speed(), 3);
+        assert_eq!(rules.get_bool("doDaylightCycle"), None);
+
+        rules.set("randomTickSpeed", 10);
+        rules.set("doDaylightCycle", false);
+        assert_eq!(rules.random_tick_speed(), 10);
+        assert_eq!(rules.get_int("randomTickSpeed"), Some(10));
+        assert_eq!(rules.get_bool("doDaylightCycle"), Some(false));
+
+        rules.set("randomTickSpeed", -1);
+        assert_eq!(rules.random_tick_speed(), 0);
+    }
+
+    #[test]
+    fn test_uuid_data() {
+        let uuid = Uuid::parse_str("f81d4fae-7dec-11d0-a765-00a0c91e6bf6").unwrap();
+        let data = UuidData::from(uuid);
+        assert_eq!(data.most, 0xf81d_4fae_7dec_11d0_u64 as i64);
+        assert_eq!(data.least, 0xa765_00a0_c91e_6bf6_u64 as i64);
+        assert_eq!(Uuid::from(data), uuid);
     }
 }
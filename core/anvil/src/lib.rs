@@ -1,10 +1,12 @@
 //! Module containing functions for loading and saving to
 //! world saves. Currently includes region file loading,
-//! player data loading, and level data loading.
+//! player data loading, level data loading and
+//! scoreboard loading.
 
 pub mod block_entity;
 pub mod entity;
 pub mod level;
 pub mod player;
 pub mod region;
+pub mod scoreboard;
 mod serialization_helper;
@@ -15,11 +15,15 @@ use tokio::prelude::{AsyncRead, AsyncWrite};
 use uuid::Uuid;
 
 /// Represents the contents of a player data file.
-#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerData {
     // Inherit base entity data
     #[serde(flatten)]
     pub animal: AnimalData,
+    #[serde(rename = "FallDistance", default)]
+    pub fall_distance: f32,
+    #[serde(rename = "Dimension", default)]
+    pub dimension: i32,
 
     #[serde(rename = "playerGameType")]
     pub gamemode: i32,
@@ -27,6 +31,105 @@ pub struct PlayerData {
     pub inventory: Vec<InventorySlot>,
     #[serde(rename = "SelectedItemSlot")]
     pub held_item: i32,
+    #[serde(rename = "EnderItems", default)]
+    pub ender_items: Vec<InventorySlot>,
+
+    #[serde(rename = "foodLevel", default = "default_food_level")]
+    pub food_level: i32,
+    #[serde(
+        rename = "foodSaturationLevel",
+        default = "default_food_saturation_level"
+    )]
+    pub food_saturation_level: f32,
+    #[serde(rename = "foodExhaustionLevel", default)]
+    pub food_exhaustion_level: f32,
+    #[serde(rename = "foodTickTimer", default)]
+    pub food_tick_timer: i32,
+
+    #[serde(rename = "XpLevel", default)]
+    pub xp_level: i32,
+    /// Progress towards the next level, between 0 and 1.
+    #[serde(rename = "XpP", default)]
+    pub xp_progress: f32,
+    #[serde(rename = "XpTotal", default)]
+    pub xp_total: i32,
+    #[serde(rename = "XpSeed", default)]
+    pub xp_seed: i32,
+    #[serde(rename = "Score", default)]
+    pub score: i32,
+
+    /// The player's bed or respawn position, if any.
+    #[serde(rename = "SpawnX", skip_serializing_if = "Option::is_none")]
+    pub spawn_x: Option<i32>,
+    #[serde(rename = "SpawnY", skip_serializing_if = "Option::is_none")]
+    pub spawn_y: Option<i32>,
+    #[serde(rename = "SpawnZ", skip_serializing_if = "Option::is_none")]
+    pub spawn_z: Option<i32>,
+    /// Whether the player spawns at the spawn
+    /// position even if there is no bed there.
+    #[serde(rename = "SpawnForced", skip_serializing_if = "Option::is_none")]
+    pub spawn_forced: Option<bool>,
+
+    #[serde(rename = "ActiveEffects", default)]
+    pub active_effects: Vec<ActiveEffectData>,
+}
+
+fn default_food_level() -> i32 {
+    20
+}
+
+fn default_food_saturation_level() -> f32 {
+    5.0
+}
+
+impl Default for PlayerData {
+    fn default() -> Self {
+        Self {
+            animal: Default::default(),
+            fall_distance: 0.0,
+            dimension: 0,
+            gamemode: 0,
+            inventory: vec![],
+            held_item: 0,
+            ender_items: vec![],
+            food_level: default_food_level(),
+            food_saturation_level: default_food_saturation_level(),
+            food_exhaustion_level: 0.0,
+            food_tick_timer: 0,
+            xp_level: 0,
+            xp_progress: 0.0,
+            xp_total: 0,
+            xp_seed: 0,
+            score: 0,
+            spawn_x: None,
+            spawn_y: None,
+            spawn_z: None,
+            spawn_forced: None,
+            active_effects: vec![],
+        }
+    }
+}
+
+/// A status effect active on an entity.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActiveEffectData {
+    #[serde(rename = "Id")]
+    pub id: i8,
+    #[serde(rename = "Amplifier")]
+    pub amplifier: i8,
+    /// Remaining duration in ticks.
+    #[serde(rename = "Duration")]
+    pub duration: i32,
+    #[serde(rename = "Ambient")]
+    pub ambient: bool,
+    #[serde(rename = "ShowParticles")]
+    pub show_particles: bool,
+    #[serde(rename = "ShowIcon", default = "default_show_icon")]
+    pub show_icon: bool,
+}
+
+fn default_show_icon() -> bool {
+    true
 }
 
 /// Represents a single inventory slot (including position index).
@@ -191,6 +294,182 @@ mod tests {
         assert_eq!(player.gamemode, i32::from(Gamemode::Creative.id()));
         assert_eq!(player.inventory[0].item, "minecraft:diamond_shovel");
         assert_eq!(player.inventory[0].nbt, Some(ItemNbt { damage: Some(3) }));
+
+        assert_eq!(player.animal.health, 20.0);
+        assert_eq!(player.food_level, 20);
+        assert_eq!(player.food_saturation_level, 5.0);
+        assert_eq!(player.xp_level, 0);
+        assert_eq!(player.xp_seed, -372_296_885);
+        assert_eq!(player.dimension, 0);
+        assert!(player.ender_items.is_empty());
+        assert!(player.active_effects.is_empty());
+        assert_eq!(player.spawn_x, None);
+        assert_eq!(player.spawn_forced, None);
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_player_progress() {
+        let cursor = Cursor::new(include_bytes!("player_progress.dat").to_vec());
+
+        let player = load_from_file(cursor).await.unwrap();
+        assert_eq!(player.xp_level, 30);
+        assert_eq!(player.xp_progress, 0.25);
+        assert_eq!(player.xp_total, 1400);
+        assert_eq!(player.score, 1400);
+        assert_eq!(
+            (player.spawn_x, player.spawn_y, player.spawn_z),
+            (Some(-120), Some(70), Some(256))
+        );
+        assert_eq!(player.spawn_forced, Some(true));
+        assert_eq!(
+            player.active_effects,
+            vec![
+                ActiveEffectData {
+                    id: 1,
+                    amplifier: 1,
+                    duration: 3600,
+                    ambient: false,
+                    show_particles: true,
+                    show_icon: true,
+                },
+                ActiveEffectData {
+                    id: 16,
+                    amplifier: 0,
+                    duration: 180,
+                    ambient: true,
+                    show_particles: false,
+                    show_icon: true,
+                },
+            ]
+        );
+        assert_eq!(player.ender_items[0].item, "minecraft:ender_pearl");
+        assert_eq!(player.ender_items[0].count, 16);
+        assert_eq!(player.ender_items[1].slot, 26);
+        assert_eq!(
+            player.ender_items[1].nbt,
+            Some(ItemNbt { damage: Some(10) })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_vanilla_player() {
+        let files: [&[u8]; 2] = [
+            include_bytes!("player.dat"),
+            include_bytes!("player_progress.dat"),
+        ];
+        for file in files.iter() {
+            let vanilla = load_from_file(Cursor::new(file.to_vec())).await.unwrap();
+
+            let mut buf = vec![];
+            save_to_file(&mut buf, &vanilla).await.unwrap();
+            let player = load_from_file(Cursor::new(buf)).await.unwrap();
+
+            assert_eq!(player.animal.base.position, vanilla.animal.base.position);
+            assert_eq!(player.animal.health, vanilla.animal.health);
+            assert_eq!(player.gamemode, vanilla.gamemode);
+            assert_eq!(player.inventory, vanilla.inventory);
+            assert_eq!(player.held_item, vanilla.held_item);
+            assert_eq!(player.food_level, vanilla.food_level);
+            assert_eq!(player.food_saturation_level, vanilla.food_saturation_level);
+            assert_eq!(player.food_exhaustion_level, vanilla.food_exhaustion_level);
+            assert_eq!(player.xp_level, vanilla.xp_level);
+            assert_eq!(player.xp_progress, vanilla.xp_progress);
+            assert_eq!(player.xp_total, vanilla.xp_total);
+            assert_eq!(player.xp_seed, vanilla.xp_seed);
+            assert_eq!(player.score, vanilla.score);
+            assert_eq!(
+                (player.spawn_x, player.spawn_y, player.spawn_z),
+                (vanilla.spawn_x, vanilla.spawn_y, vanilla.spawn_z)
+            );
+            assert_eq!(player.spawn_forced, vanilla.spawn_forced);
+            assert_eq!(player.active_effects, vanilla.active_effects);
+            assert_eq!(player.ender_items, vanilla.ender_items);
+            assert_eq!(player.fall_distance, vanilla.fall_distance);
+            assert_eq!(player.dimension, vanilla.dimension);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_player_state() {
+        let effect = ActiveEffectData {
+            id: 1,
+            amplifier: 2,
+            duration: 600,
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+        };
+        let data = PlayerData {
+            fall_distance: 3.5,
+            dimension: -1,
+            ender_items: vec![InventorySlot {
+                count: 16,
+                slot: 26,
+                item: String::from(Item::EnderPearl.identifier()),
+                nbt: None,
+            }],
+            food_level: 7,
+            food_saturation_level: 1.5,
+            food_exhaustion_level: 2.25,
+            food_tick_timer: 40,
+            xp_level: 30,
+            xp_progress: 0.75,
+            xp_total: 1500,
+            xp_seed: 42,
+            score: 1500,
+            spawn_x: Some(10),
+            spawn_y: Some(64),
+            spawn_z: Some(-20),
+            spawn_forced: Some(true),
+            active_effects: vec![effect],
+            ..Default::default()
+        };
+
+        let mut buf = vec![];
+        save_to_file(&mut buf, &data).await.unwrap();
+
+        // Tags must have the same types as in vanilla files.
+        let blob = nbt::Blob::from_gzip_reader(&mut buf.as_slice()).unwrap();
+        assert_eq!(blob["foodLevel"], Value::Int(7));
+        assert_eq!(blob["foodSaturationLevel"], Value::Float(1.5));
+        assert_eq!(blob["XpLevel"], Value::Int(30));
+        assert_eq!(blob["XpP"], Value::Float(0.75));
+        assert_eq!(blob["SpawnX"], Value::Int(10));
+        assert_eq!(blob["SpawnForced"], Value::Byte(1));
+        assert_eq!(blob["FallDistance"], Value::Float(3.5));
+        assert_eq!(blob["Dimension"], Value::Int(-1));
+        match &blob["ActiveEffects"] {
+            Value::List(effects) => match &effects[0] {
+                Value::Compound(effect) => {
+                    assert_eq!(effect["Id"], Value::Byte(1));
+                    assert_eq!(effect["Amplifier"], Value::Byte(2));
+                    assert_eq!(effect["Duration"], Value::Int(600));
+                    assert_eq!(effect["ShowParticles"], Value::Byte(1));
+                }
+                value => panic!("invalid effect {:?}", value),
+            },
+            value => panic!("invalid effect list {:?}", value),
+        }
+
+        let player = load_from_file(Cursor::new(buf)).await.unwrap();
+        assert_eq!(player.fall_distance, 3.5);
+        assert_eq!(player.dimension, -1);
+        assert_eq!(player.ender_items, data.ender_items);
+        assert_eq!(player.food_level, 7);
+        assert_eq!(player.food_saturation_level, 1.5);
+        assert_eq!(player.food_exhaustion_level, 2.25);
+        assert_eq!(player.food_tick_timer, 40);
+        assert_eq!(player.xp_level, 30);
+        assert_eq!(player.xp_progress, 0.75);
+        assert_eq!(player.xp_total, 1500);
+        assert_eq!(player.xp_seed, 42);
+        assert_eq!(player.score, 1500);
+        assert_eq!(
+            (player.spawn_x, player.spawn_y, player.spawn_z),
+            (Some(10), Some(64), Some(-20))
+        );
+        assert_eq!(player.spawn_forced, Some(true));
+        assert_eq!(player.active_effects, vec![effect]);
     }
 
     #[test]
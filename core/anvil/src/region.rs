@@ -4,20 +4,23 @@
 use super::serialization_helper::packed_u9;
 use crate::{block_entity::BlockEntityData, entity::EntityData};
 use bitvec::{bitvec, vec::BitVec};
-use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
 use feather_biomes::Biome;
 use feather_blocks::BlockId;
 use feather_chunk::{BitArray, Chunk, ChunkSection};
 use feather_util::ChunkPosition;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::{GzEncoder, ZlibEncoder};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::fmt::{self, Display, Formatter};
 use std::fs::{File, OpenOptions};
 use std::io::prelude::*;
-use std::io::{Cursor, SeekFrom};
+use std::io::SeekFrom;
 use std::ops::Deref;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::{fs, io, iter};
 
 /// The length and width of a region, in chunks.
@@ -30,6 +33,20 @@ const DATA_VERSION: i32 = 1631;
 /// Length, in bytes, of a sector.
 const SECTOR_BYTES: usize = 4096;
 
+/// The zstd compression level used for chunks. Low levels
+/// are much faster while compressing nearly as well.
+const ZSTD_LEVEL: i32 = 1;
+
+/// Compression type bytes of the compressions specific to Feather.
+/// Vanilla uses 1 to 4 and 127, and sets bit 128 for chunks stored
+/// outside the region file, so these are chosen well outside that range.
+const LZ4_ID: u8 = 64;
+const ZSTD_ID: u8 = 65;
+
+/// Maximum size of the raw NBT data of a chunk. Used to
+/// reject corrupt LZ4 data before allocating its stated size.
+const MAX_CHUNK_DATA_BYTES: usize = 32 * 1024 * 1024;
+
 /// Represents the data for a chunk after the "Chunk [x, y]" tag.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "PascalCase")]
@@ -61,9 +78,9 @@ pub struct ChunkLevel {
     #[serde(rename = "LiquidsToBeTicked")]
     awaiting_liquid_updates: Vec<Vec<i16>>,
     post_processing: Vec<Vec<i16>>,
-    #[serde(rename = "TileTicks")]
+    #[serde(rename = "TileTicks", default)]
     scheduled_block_updates: Vec<ScheduledBlockUpdate>,
-    #[serde(rename = "LiquidTicks")]
+    #[serde(rename = "LiquidTicks", default)]
     scheduled_liquid_updates: Vec<ScheduledBlockUpdate>,
     #[serde(rename = "Status")]
     worldgen_status: Cow<'static, str>,
@@ -134,18 +151,18 @@ pub struct LevelProperties {
 }
 
 /// Represents a block update scheduled for a specific time.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct ScheduledBlockUpdate {
     /// The identifier of the type of this block
     #[serde(rename = "i")]
-    name: Cow<'static, str>,
+    pub name: Cow<'static, str>,
     /// When this update should be executed in ticks from current time, can be negative if overdue
     #[serde(rename = "t")]
-    ticks_from_now: i32,
+    pub ticks_from_now: i32,
     /// Lower priority is handled first when happening on the same tick
-    #[serde(rename = "t")]
-    priority: i32,
-    // TODO are these global or chunk coordinates?
+    #[serde(rename = "p")]
+    pub priority: i32,
+    // Coordinates are global, not relative to the chunk.
     /// X coordinate
     pub x: i32,
     /// Y coordinate
@@ -154,6 +171,111 @@ pub struct ScheduledBlockUpdate {
     pub z: i32,
 }
 
+/// The compression of chunk data in a region file.
+///
+/// Only gzip, zlib and uncompressed chunks can be read by vanilla.
+/// LZ4 and zstd are faster to save and load, but are specific to Feather.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChunkCompression {
+    Gzip,
+    Zlib,
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl Default for ChunkCompression {
+    fn default() -> Self {
+        ChunkCompression::Zlib
+    }
+}
+
+impl ChunkCompression {
+    /// Returns the compression type byte which
+    /// precedes chunk data compressed with this compression.
+    pub fn id(self) -> u8 {
+        match self {
+            ChunkCompression::Gzip => 1,
+            ChunkCompression::Zlib => 2,
+            ChunkCompression::None => 3,
+            ChunkCompression::Lz4 => LZ4_ID,
+            ChunkCompression::Zstd => ZSTD_ID,
+        }
+    }
+
+    pub fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(ChunkCompression::Gzip),
+            2 => Some(ChunkCompression::Zlib),
+            3 => Some(ChunkCompression::None),
+            LZ4_ID => Some(ChunkCompression::Lz4),
+            ZSTD_ID => Some(ChunkCompression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Returns the compression with the given name, as used in `feather.toml`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "gzip" => Some(ChunkCompression::Gzip),
+            "zlib" => Some(ChunkCompression::Zlib),
+            "none" => Some(ChunkCompression::None),
+            "lz4" => Some(ChunkCompression::Lz4),
+            "zstd" => Some(ChunkCompression::Zstd),
+            _ => None,
+        }
+    }
+
+    /// Compresses the given raw NBT data, appending it to `out`.
+    fn compress_into(self, data: &[u8], out: &mut Vec<u8>) -> Result<(), io::Error> {
+        match self {
+            ChunkCompression::Gzip => {
+                let mut encoder = GzEncoder::new(out, flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()?;
+            }
+            ChunkCompression::Zlib => {
+                let mut encoder = ZlibEncoder::new(out, flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()?;
+            }
+            ChunkCompression::None => out.extend_from_slice(data),
+            ChunkCompression::Lz4 => out.extend_from_slice(&lz4_flex::compress_prepend_size(data)),
+            ChunkCompression::Zstd => zstd::stream::copy_encode(data, out, ZSTD_LEVEL)?,
+        }
+        Ok(())
+    }
+
+    /// Decompresses the given data, returning the raw NBT data.
+    fn decompress(self, data: &[u8]) -> Result<Vec<u8>, io::Error> {
+        let mut out = Vec::with_capacity(data.len() * 4);
+        match self {
+            ChunkCompression::Gzip => {
+                GzDecoder::new(data).read_to_end(&mut out)?;
+            }
+            ChunkCompression::Zlib => {
+                ZlibDecoder::new(data).read_to_end(&mut out)?;
+            }
+            ChunkCompression::None => out.extend_from_slice(data),
+            ChunkCompression::Lz4 => {
+                // The decompressed size precedes the data.
+                let mut header = data;
+                let size = header.read_u32::<LittleEndian>()? as usize;
+                if size > MAX_CHUNK_DATA_BYTES {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("chunk data of {} bytes is too large", size),
+                    ));
+                }
+                out = lz4_flex::decompress_size_prepended(data)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+            }
+            ChunkCompression::Zstd => zstd::stream::copy_decode(data, &mut out)?,
+        }
+        Ok(out)
+    }
+}
+
 /// A block of sectors in a region file.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct SectorBlock {
@@ -171,9 +293,18 @@ pub struct RegionHandle {
     header: RegionHeader,
     /// Sector allocator to allocate sectors where we can store chunks.
     allocator: SectorAllocator,
+    /// Compression used for saved chunks.
+    compression: ChunkCompression,
 }
 
 impl RegionHandle {
+    /// Sets the compression used for chunks saved from now on.
+    /// Chunks which are already stored are loaded regardless
+    /// of their compression.
+    pub fn set_compression(&mut self, compression: ChunkCompression) {
+        self.compression = compression;
+    }
+
     /// Loads the chunk at the given position (global, not region-relative).
     ///
     /// The specified chunk is expected to be contained within this region.
@@ -184,7 +315,15 @@ impl RegionHandle {
     pub fn load_chunk(
         &mut self,
         mut pos: ChunkPosition,
-    ) -> Result<(Chunk, Vec<EntityData>, Vec<BlockEntityData>), Error> {
+    ) -> Result<
+        (
+            Chunk,
+            Vec<EntityData>,
+            Vec<BlockEntityData>,
+            Vec<ScheduledBlockUpdate>,
+        ),
+        Error,
+    > {
         // Get a copy of the original position before clipping
         let original_pos = pos;
         // Clip chunk position to region-local coordinates.
@@ -225,18 +364,8 @@ impl RegionHandle {
         let mut buf = vec![0u8; len as usize];
         self.file.read_exact(&mut buf).map_err(Error::Io)?;
 
-        // The compression type is indicated by a byte.
-        // 1 corresponds to gzip compression, while 2
-        // corresponds to zlib.
-        let compression_type = buf[0];
-
         // Parse NBT data
-        let cursor = Cursor::new(&buf[1..]);
-        let root: ChunkRoot = match compression_type {
-            1 => nbt::from_gzip_reader(cursor).map_err(Error::Nbt)?,
-            2 => nbt::from_zlib_reader(cursor).map_err(Error::Nbt)?,
-            _ => return Err(Error::InvalidCompression(compression_type)),
-        };
+        let root: ChunkRoot = read_chunk_nbt(&buf)?;
 
         // Check data version
         if root.data_version != DATA_VERSION {
@@ -267,7 +396,21 @@ impl RegionHandle {
 
         chunk.recalculate_heightmap();
 
-        Ok((chunk, level.entities.clone(), level.block_entities.clone()))
+        // Fluid and other block ticks are stored separately,
+        // but are run from the same queue.
+        let scheduled_updates = level
+            .scheduled_block_updates
+            .iter()
+            .chain(&level.scheduled_liquid_updates)
+            .cloned()
+            .collect();
+
+        Ok((
+            chunk,
+            level.entities.clone(),
+            level.block_entities.clone(),
+            scheduled_updates,
+        ))
     }
 
     /// Saves the given chunk to this region file. The header will be updated
@@ -280,6 +423,7 @@ impl RegionHandle {
         chunk: &Chunk,
         entities: &[EntityData],
         block_entities: &[BlockEntityData],
+        scheduled_updates: &[ScheduledBlockUpdate],
     ) -> Result<(), Error> {
         let chunk_pos = chunk.position();
 
@@ -294,13 +438,17 @@ impl RegionHandle {
         }
 
         // Write chunk to `ChunkRoot` tag.
-        let root = chunk_to_chunk_root(chunk, entities, block_entities);
+        let root = chunk_to_chunk_root(chunk, entities, block_entities, scheduled_updates);
 
         // Write to intermediate buffer, because we need to know the length.
-        let mut buf = Vec::with_capacity(4096);
-        buf.write_u8(2).map_err(Error::Io)?; // Compression type: zlib
+        let mut nbt_data = Vec::with_capacity(16384);
+        nbt::to_writer(&mut nbt_data, &root, None).map_err(Error::Nbt)?;
 
-        nbt::to_zlib_writer(&mut buf, &root, None).map_err(Error::Nbt)?;
+        let mut buf = Vec::with_capacity(4096);
+        buf.write_u8(self.compression.id()).map_err(Error::Io)?;
+        self.compression
+            .compress_into(&nbt_data, &mut buf)
+            .map_err(Error::Io)?;
 
         let total_len = buf.len() + 4; // 4 bytes for length header
 
@@ -319,7 +467,7 @@ impl RegionHandle {
         self.file.write_all(&buf).map_err(Error::Io)?;
 
         // Write padding to align to sector count
-        let padding_count = SECTOR_BYTES - total_len % SECTOR_BYTES;
+        let padding_count = (SECTOR_BYTES - total_len % SECTOR_BYTES) % SECTOR_BYTES;
 
         for _ in 0..padding_count {
             self.file.write_u8(0).map_err(Error::Io)?;
@@ -340,6 +488,21 @@ impl RegionHandle {
     }
 }
 
+/// Decompresses and parses the NBT data of a chunk. `buf` begins
+/// with the compression type byte, which is followed by the data.
+fn read_chunk_nbt<T: DeserializeOwned>(buf: &[u8]) -> Result<T, Error> {
+    let data = decompress_chunk(buf)?;
+    nbt::from_reader(data.as_slice()).map_err(Error::Nbt)
+}
+
+/// Decompresses the data of a chunk, returning its raw NBT. `buf`
+/// begins with the compression type byte, which is followed by the data.
+fn decompress_chunk(buf: &[u8]) -> Result<Vec<u8>, Error> {
+    let compression =
+        ChunkCompression::from_id(buf[0]).ok_or_else(|| Error::InvalidCompression(buf[0]))?;
+    compression.decompress(&buf[1..]).map_err(Error::Io)
+}
+
 fn read_section_into_chunk(section: &LevelSection, chunk: &mut Chunk) -> Result<(), Error> {
     let data = &section.states;
 
@@ -417,7 +580,13 @@ fn chunk_to_chunk_root(
     chunk: &Chunk,
     entities: &[EntityData],
     block_entities: &[BlockEntityData],
+    scheduled_updates: &[ScheduledBlockUpdate],
 ) -> ChunkRoot {
+    let (scheduled_liquid_updates, scheduled_block_updates): (Vec<_>, Vec<_>) = scheduled_updates
+        .iter()
+        .cloned()
+        .partition(|update| is_liquid(&update.name));
+
     ChunkRoot {
         level: ChunkLevel {
             x_pos: chunk.position().x,
@@ -472,8 +641,8 @@ fn chunk_to_chunk_root(
             },
             awaiting_block_updates: vec![vec![]; 16], // TODO
             awaiting_liquid_updates: vec![vec![]; 16], // TODO
-            scheduled_block_updates: vec![],          // TODO
-            scheduled_liquid_updates: vec![],
+            scheduled_block_updates,
+            scheduled_liquid_updates,
             post_processing: vec![vec![]; 16],
             worldgen_status: "postprocessed".into(),
         },
@@ -481,6 +650,12 @@ fn chunk_to_chunk_root(
     }
 }
 
+/// Returns whether scheduled updates for the block with the
+/// given identifier are stored in `LiquidTicks`.
+fn is_liquid(name: &str) -> bool {
+    name == "minecraft:water" || name == "minecraft:lava"
+}
+
 fn convert_palette(section: &mut ChunkSection) -> Vec<LevelPaletteEntry> {
     section.convert_palette_to_section();
     raw_palette_to_palette_entries(section.palette().unwrap())
@@ -692,6 +867,7 @@ pub fn load_region(dir: &PathBuf, pos: RegionPosition) -> Result<RegionHandle, E
         file,
         header,
         allocator,
+        compression: ChunkCompression::default(),
     })
 }
 
@@ -723,9 +899,255 @@ pub fn create_region(dir: &PathBuf, pos: RegionPosition) -> Result<RegionHandle,
         file,
         header,
         allocator,
+        compression: ChunkCompression::default(),
+    })
+}
+
+/// A problem with a chunk entry in a region file
+/// which prevents the chunk from being loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkProblem {
+    /// The header points into the header itself or
+    /// past the end of the file.
+    OutOfBounds,
+    /// The length of the chunk data is zero or
+    /// does not fit within its sectors.
+    InvalidLength(u32),
+    /// The chunk data uses an unknown compression type.
+    InvalidCompression(u8),
+    /// The chunk data could not be decompressed or parsed.
+    InvalidNbt(String),
+    /// The chunk data is for another chunk.
+    WrongPosition(ChunkPosition),
+}
+
+impl Display for ChunkProblem {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ChunkProblem::OutOfBounds => f.write_str("header points outside of the file"),
+            ChunkProblem::InvalidLength(len) => write!(f, "invalid length of {} bytes", len),
+            ChunkProblem::InvalidCompression(id) => write!(f, "invalid compression type {}", id),
+            ChunkProblem::InvalidNbt(e) => write!(f, "invalid NBT: {}", e),
+            ChunkProblem::WrongPosition(pos) => write!(f, "contains data for chunk {}", pos),
+        }
+    }
+}
+
+/// A chunk entry in a region file which cannot be loaded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorruptChunk {
+    /// Position of the chunk (global, not region-relative).
+    pub position: ChunkPosition,
+    pub problem: ChunkProblem,
+}
+
+/// The result of checking or compacting a region file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Number of valid chunks in the region.
+    pub chunks: usize,
+    /// Chunk entries which cannot be loaded. Compaction
+    /// removes these from the file.
+    pub corrupt_chunks: Vec<CorruptChunk>,
+    /// Size of the file before compaction, in sectors.
+    pub sectors_before: u32,
+    /// Size of the file after compaction, in sectors.
+    pub sectors_after: u32,
+}
+
+/// The contents of a region file as read by `scan_region`.
+struct ScannedRegion {
+    header: RegionHeader,
+    /// Header index and data (starting with the compression
+    /// type) of each valid chunk.
+    chunks: Vec<(usize, Vec<u8>)>,
+    report: CompactionReport,
+}
+
+/// Checks the region file at the given position for
+/// corrupt chunk entries without modifying it.
+///
+/// The returned report includes the size the file
+/// would have after compaction.
+pub fn check_region(dir: &PathBuf, pos: RegionPosition) -> Result<CompactionReport, Error> {
+    scan_region(&region_file_path(dir, pos), pos).map(|scanned| scanned.report)
+}
+
+/// Rewrites the region file at the given position so that
+/// its chunks are stored in consecutive sectors, leaving no gaps.
+/// Corrupt chunk entries are removed from the file.
+///
+/// The region must not be open elsewhere, e.g. by a running server,
+/// while it is being compacted.
+pub fn compact_region(dir: &PathBuf, pos: RegionPosition) -> Result<CompactionReport, Error> {
+    rewrite_region(dir, pos, None)
+}
+
+/// Compacts the region file at the given position, as with
+/// `compact_region`, and converts all of its chunks
+/// to the given compression.
+pub fn convert_region(
+    dir: &PathBuf,
+    pos: RegionPosition,
+    compression: ChunkCompression,
+) -> Result<CompactionReport, Error> {
+    rewrite_region(dir, pos, Some(compression))
+}
+
+fn rewrite_region(
+    dir: &PathBuf,
+    pos: RegionPosition,
+    compression: Option<ChunkCompression>,
+) -> Result<CompactionReport, Error> {
+    let path = region_file_path(dir, pos);
+    let ScannedRegion {
+        header,
+        chunks,
+        mut report,
+    } = scan_region(&path, pos)?;
+
+    let mut new_header = RegionHeader::default();
+    let mut data = vec![];
+    let mut offset = 2;
+    for (index, mut buf) in chunks {
+        if let Some(compression) = compression {
+            if buf[0] != compression.id() {
+                let nbt_data = decompress_chunk(&buf)?;
+                buf = vec![compression.id()];
+                compression
+                    .compress_into(&nbt_data, &mut buf)
+                    .map_err(Error::Io)?;
+            }
+        }
+
+        let count = sectors_for_chunk(buf.len());
+        new_header.locations[index] = ChunkLocation(SectorBlock { offset, count });
+        new_header.timestamps[index] = header.timestamps[index];
+        offset += count;
+
+        data.write_u32::<BigEndian>(buf.len() as u32)
+            .map_err(Error::Io)?;
+        data.extend_from_slice(&buf);
+        data.resize(((offset - 2) as usize) * SECTOR_BYTES, 0);
+    }
+    report.sectors_after = offset;
+
+    // Write to a temporary file first so that the
+    // region is not lost if writing fails.
+    let temp_path = path.with_extension("mca.tmp");
+    {
+        let mut file = File::create(&temp_path).map_err(Error::Io)?;
+        new_header.write_to(&mut file).map_err(Error::Io)?;
+        file.write_all(&data).map_err(Error::Io)?;
+        file.sync_all().map_err(Error::Io)?;
+    }
+    fs::rename(&temp_path, &path).map_err(Error::Io)?;
+
+    Ok(report)
+}
+
+/// Reads the region file at the given path and
+/// validates each of its chunk entries.
+fn scan_region(path: &Path, pos: RegionPosition) -> Result<ScannedRegion, Error> {
+    let mut file = File::open(path).map_err(Error::Io)?;
+    let header = read_header(&mut file)?;
+
+    let mut contents = vec![];
+    file.seek(SeekFrom::Start(0)).map_err(Error::Io)?;
+    file.read_to_end(&mut contents).map_err(Error::Io)?;
+
+    let mut report = CompactionReport {
+        sectors_before: ((contents.len() + SECTOR_BYTES - 1) / SECTOR_BYTES) as u32,
+        sectors_after: 2,
+        ..Default::default()
+    };
+    let mut chunks = vec![];
+
+    for (index, location) in header.locations.iter().enumerate() {
+        if !location.exists() {
+            continue;
+        }
+
+        let position = ChunkPosition::new(
+            pos.x * REGION_SIZE as i32 + (index % REGION_SIZE) as i32,
+            pos.z * REGION_SIZE as i32 + (index / REGION_SIZE) as i32,
+        );
+
+        match read_raw_chunk(&contents, *location, position) {
+            Ok(buf) => {
+                report.chunks += 1;
+                report.sectors_after += sectors_for_chunk(buf.len());
+                chunks.push((index, buf.to_vec()));
+            }
+            Err(problem) => report
+                .corrupt_chunks
+                .push(CorruptChunk { position, problem }),
+        }
+    }
+
+    Ok(ScannedRegion {
+        header,
+        chunks,
+        report,
     })
 }
 
+/// Returns the data of the chunk at the given location, starting
+/// with the compression type, if it is a valid chunk at `position`.
+fn read_raw_chunk(
+    contents: &[u8],
+    location: ChunkLocation,
+    position: ChunkPosition,
+) -> Result<&[u8], ChunkProblem> {
+    let SectorBlock { offset, count } = location.0;
+    let start = offset as usize * SECTOR_BYTES;
+    let end = (offset + count) as usize * SECTOR_BYTES;
+    if offset < 2 || end > contents.len() {
+        return Err(ChunkProblem::OutOfBounds);
+    }
+
+    let mut sectors = &contents[start..end];
+    let len = sectors.read_u32::<BigEndian>().unwrap();
+    if len == 0 || len > 1_048_576 || len as usize > sectors.len() {
+        return Err(ChunkProblem::InvalidLength(len));
+    }
+
+    let buf = &sectors[..len as usize];
+    let root: ChunkPositionRoot = match read_chunk_nbt(buf) {
+        Ok(root) => root,
+        Err(Error::InvalidCompression(id)) => return Err(ChunkProblem::InvalidCompression(id)),
+        Err(e) => return Err(ChunkProblem::InvalidNbt(e.to_string())),
+    };
+
+    let found = ChunkPosition::new(root.level.x_pos, root.level.z_pos);
+    if found != position {
+        return Err(ChunkProblem::WrongPosition(found));
+    }
+
+    Ok(buf)
+}
+
+/// Returns the number of sectors needed to store chunk
+/// data of the given length, including its length prefix.
+fn sectors_for_chunk(len: usize) -> u32 {
+    ((len + 4 + SECTOR_BYTES - 1) / SECTOR_BYTES) as u32
+}
+
+/// The tags of a chunk needed to check its position.
+#[derive(Deserialize)]
+struct ChunkPositionRoot {
+    #[serde(rename = "Level")]
+    level: ChunkPositionLevel,
+}
+
+#[derive(Deserialize)]
+struct ChunkPositionLevel {
+    #[serde(rename = "xPos")]
+    x_pos: i32,
+    #[serde(rename = "zPos")]
+    z_pos: i32,
+}
+
 fn open_opts() -> OpenOptions {
     OpenOptions::new()
         .read(true)
@@ -877,6 +1299,11 @@ pub struct RegionPosition {
 }
 
 impl RegionPosition {
+    /// Creates a `RegionPosition` from region coordinates.
+    pub fn new(x: i32, z: i32) -> Self {
+        Self { x, z }
+    }
+
     /// Returns the coordinates of the region corresponding
     /// to the specified chunk position.
     pub fn from_chunk(chunk_coords: ChunkPosition) -> Self {
@@ -942,4 +1369,274 @@ mod tests {
             }
         );
     }
+
+    /// Returns an empty world directory for a test.
+    fn test_world_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("feather-region-test-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// Returns a chunk whose blocks are hard to compress,
+    /// so that it needs several sectors.
+    fn noisy_chunk(pos: ChunkPosition) -> Chunk {
+        let mut chunk = Chunk::new(pos);
+        let mut state = 1u32;
+        for y in 0..64 {
+            for z in 0..16 {
+                for x in 0..16 {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    chunk.set_block_at(
+                        x,
+                        y,
+                        z,
+                        BlockId::from_vanilla_id((state >> 16) as u16 % 64),
+                    );
+                }
+            }
+        }
+        chunk
+    }
+
+    fn stone_chunk(pos: ChunkPosition) -> Chunk {
+        let mut chunk = Chunk::new(pos);
+        chunk.set_block_at(1, 2, 3, BlockId::stone());
+        chunk
+    }
+
+    #[test]
+    fn test_compact_region() {
+        let dir = test_world_dir("compact");
+        let pos = RegionPosition::new(0, 0);
+        let chunks = [
+            ChunkPosition::new(0, 0),
+            ChunkPosition::new(1, 0),
+            ChunkPosition::new(0, 1),
+        ];
+
+        let mut region = create_region(&dir, pos).unwrap();
+        for &chunk in &chunks {
+            region
+                .save_chunk(&stone_chunk(chunk), &[], &[], &[])
+                .unwrap();
+        }
+        // The chunk outgrows its sector, leaving a gap.
+        let noisy = noisy_chunk(chunks[1]);
+        region.save_chunk(&noisy, &[], &[], &[]).unwrap();
+        drop(region);
+
+        let checked = check_region(&dir, pos).unwrap();
+        assert_eq!(checked.chunks, 3);
+        assert!(checked.corrupt_chunks.is_empty());
+        assert!(checked.sectors_after < checked.sectors_before);
+
+        let report = compact_region(&dir, pos).unwrap();
+        assert_eq!(report, checked);
+        let file_len = fs::metadata(region_file_path(&dir, pos)).unwrap().len();
+        assert_eq!(file_len, report.sectors_after as u64 * SECTOR_BYTES as u64);
+
+        let mut region = load_region(&dir, pos).unwrap();
+        let (chunk, ..) = region.load_chunk(chunks[0]).unwrap();
+        assert_eq!(chunk.block_at(1, 2, 3), BlockId::stone());
+        let (chunk, ..) = region.load_chunk(chunks[1]).unwrap();
+        for y in 0..64 {
+            assert_eq!(chunk.block_at(5, y, 7), noisy.block_at(5, y, 7));
+        }
+
+        // Compacting again changes nothing.
+        let again = compact_region(&dir, pos).unwrap();
+        assert_eq!(again.sectors_before, again.sectors_after);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compact_region_corrupt_chunks() {
+        let dir = test_world_dir("corrupt");
+        let pos = RegionPosition::new(0, 0);
+
+        let mut region = create_region(&dir, pos).unwrap();
+        for x in 0..4 {
+            region
+                .save_chunk(&stone_chunk(ChunkPosition::new(x, 0)), &[], &[], &[])
+                .unwrap();
+        }
+        // Chunk 2 claims to be stored where chunk 0 is.
+        let mut location = region.header.location_for_chunk(ChunkPosition::new(0, 0));
+        region
+            .header
+            .set_location_for_chunk(ChunkPosition::new(2, 0), location);
+        // Chunk 4 points past the end of the file.
+        location.0.offset = 100;
+        region
+            .header
+            .set_location_for_chunk(ChunkPosition::new(4, 0), location);
+        region.save_header().unwrap();
+
+        // Chunk 3 uses an invalid compression type.
+        let offset = region
+            .header
+            .location_for_chunk(ChunkPosition::new(3, 0))
+            .0
+            .offset;
+        region
+            .file
+            .seek(SeekFrom::Start(offset as u64 * SECTOR_BYTES as u64 + 4))
+            .unwrap();
+        region.file.write_u8(9).unwrap();
+        drop(region);
+
+        let report = compact_region(&dir, pos).unwrap();
+        assert_eq!(report.chunks, 2);
+        assert_eq!(
+            report.corrupt_chunks,
+            vec![
+                CorruptChunk {
+                    position: ChunkPosition::new(2, 0),
+                    problem: ChunkProblem::WrongPosition(ChunkPosition::new(0, 0)),
+                },
+                CorruptChunk {
+                    position: ChunkPosition::new(3, 0),
+                    problem: ChunkProblem::InvalidCompression(9),
+                },
+                CorruptChunk {
+                    position: ChunkPosition::new(4, 0),
+                    problem: ChunkProblem::OutOfBounds,
+                },
+            ]
+        );
+
+        let report = check_region(&dir, pos).unwrap();
+        assert_eq!(report.chunks, 2);
+        assert!(report.corrupt_chunks.is_empty());
+
+        let mut region = load_region(&dir, pos).unwrap();
+        assert!(region.load_chunk(ChunkPosition::new(0, 0)).is_ok());
+        assert!(region.load_chunk(ChunkPosition::new(1, 0)).is_ok());
+        assert!(matches!(
+            region.load_chunk(ChunkPosition::new(2, 0)),
+            Err(Error::ChunkNotExist)
+        ));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    const COMPRESSIONS: [ChunkCompression; 5] = [
+        ChunkCompression::Gzip,
+        ChunkCompression::Zlib,
+        ChunkCompression::None,
+        ChunkCompression::Lz4,
+        ChunkCompression::Zstd,
+    ];
+
+    #[test]
+    fn test_chunk_compression() {
+        let dir = test_world_dir("compression");
+        let pos = RegionPosition::new(0, 0);
+        let noisy = noisy_chunk(ChunkPosition::new(0, 0));
+
+        let mut region = create_region(&dir, pos).unwrap();
+        for (x, &compression) in COMPRESSIONS.iter().enumerate() {
+            assert_eq!(
+                ChunkCompression::from_id(compression.id()),
+                Some(compression)
+            );
+
+            region.set_compression(compression);
+            let mut chunk = stone_chunk(ChunkPosition::new(x as i32, 0));
+            chunk.set_section_at(1, noisy.section(1).cloned());
+            region.save_chunk(&chunk, &[], &[], &[]).unwrap();
+        }
+        drop(region);
+
+        let mut region = load_region(&dir, pos).unwrap();
+        for x in 0..COMPRESSIONS.len() {
+            let (chunk, ..) = region.load_chunk(ChunkPosition::new(x as i32, 0)).unwrap();
+            assert_eq!(chunk.block_at(1, 2, 3), BlockId::stone());
+            assert_eq!(chunk.block_at(4, 20, 6), noisy.block_at(4, 20, 6));
+        }
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_lz4_size_limit() {
+        let mut data = vec![];
+        data.write_u32::<LittleEndian>(u32::MAX).unwrap();
+        data.extend_from_slice(&[0x10, 0x00]);
+
+        let err = ChunkCompression::Lz4.decompress(&data).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let err = ChunkCompression::Lz4.decompress(&[1, 0]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_convert_region() {
+        let dir = test_world_dir("convert");
+        let pos = RegionPosition::new(0, 0);
+
+        let mut region = create_region(&dir, pos).unwrap();
+        for x in 0..4 {
+            region
+                .save_chunk(&stone_chunk(ChunkPosition::new(x, 0)), &[], &[], &[])
+                .unwrap();
+        }
+        drop(region);
+
+        for &compression in &COMPRESSIONS {
+            let report = convert_region(&dir, pos, compression).unwrap();
+            assert_eq!(report.chunks, 4);
+
+            let mut region = load_region(&dir, pos).unwrap();
+            for x in 0..4 {
+                let location = region.header.location_for_chunk(ChunkPosition::new(x, 0));
+                region
+                    .file
+                    .seek(SeekFrom::Start(
+                        location.0.offset as u64 * SECTOR_BYTES as u64 + 4,
+                    ))
+                    .unwrap();
+                assert_eq!(region.file.read_u8().unwrap(), compression.id());
+
+                let (chunk, ..) = region.load_chunk(ChunkPosition::new(x, 0)).unwrap();
+                assert_eq!(chunk.block_at(1, 2, 3), BlockId::stone());
+            }
+        }
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_scheduled_updates() {
+        let dir = test_world_dir("scheduled");
+        let pos = ChunkPosition::new(1, 2);
+        let update = |name: &'static str, ticks_from_now| ScheduledBlockUpdate {
+            name: name.into(),
+            ticks_from_now,
+            priority: -1,
+            x: 20,
+            y: 64,
+            z: 40,
+        };
+        let updates = [
+            update("minecraft:repeater", 2),
+            update("minecraft:water", -3),
+        ];
+
+        let mut region = create_region(&dir, RegionPosition::from_chunk(pos)).unwrap();
+        region
+            .save_chunk(&stone_chunk(pos), &[], &[], &updates)
+            .unwrap();
+
+        let root = chunk_to_chunk_root(&stone_chunk(pos), &[], &[], &updates);
+        assert_eq!(root.level.scheduled_block_updates, &updates[..1]);
+        assert_eq!(root.level.scheduled_liquid_updates, &updates[1..]);
+
+        let (_, _, _, loaded) = region.load_chunk(pos).unwrap();
+        assert_eq!(loaded, updates);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,170 @@
+//! Implements `data/scoreboard.dat` file loading.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Cursor;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const DATA_VERSION: i32 = 1631;
+
+/// Root scoreboard tag
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Root {
+    #[serde(rename = "DataVersion")]
+    #[serde(default)]
+    pub data_version: i32,
+    pub data: ScoreboardData,
+}
+
+/// Represents the contents of a scoreboard file.
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScoreboardData {
+    #[serde(rename = "Objectives")]
+    #[serde(default)]
+    pub objectives: Vec<ObjectiveData>,
+    #[serde(rename = "PlayerScores")]
+    #[serde(default)]
+    pub player_scores: Vec<ScoreData>,
+    #[serde(rename = "Teams")]
+    #[serde(default)]
+    pub teams: Vec<TeamData>,
+    /// Maps display slots (`slot_<index>`) to objective names.
+    #[serde(rename = "DisplaySlots")]
+    #[serde(default)]
+    pub display_slots: HashMap<String, String>,
+}
+
+impl ScoreboardData {
+    pub async fn load_from_file(file: &mut File) -> anyhow::Result<Self> {
+        let mut buf = vec![];
+        file.read_to_end(&mut buf).await?;
+
+        nbt::from_gzip_reader::<_, Root>(Cursor::new(&buf))
+            .map_err(Into::into)
+            .map(|root| root.data)
+    }
+
+    pub async fn save_to_file(&self, file: &mut File) -> anyhow::Result<()> {
+        let mut buf = vec![];
+        let root = Root {
+            data_version: DATA_VERSION,
+            data: self.clone(),
+        };
+        nbt::to_gzip_writer(&mut buf, &root, None)?;
+
+        file.write_all(&buf).await?;
+        Ok(())
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ObjectiveData {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "CriteriaName")]
+    pub criteria_name: String,
+    /// JSON text.
+    #[serde(rename = "DisplayName")]
+    pub display_name: String,
+    /// Either `integer` or `hearts`.
+    #[serde(rename = "RenderType")]
+    pub render_type: String,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScoreData {
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Objective")]
+    pub objective: String,
+    #[serde(rename = "Score")]
+    pub score: i32,
+    /// Whether the score is locked, i.e. the holder
+    /// cannot change it using `/trigger`.
+    #[serde(rename = "Locked")]
+    pub locked: bool,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TeamData {
+    #[serde(rename = "Name")]
+    pub name: String,
+    /// JSON text.
+    #[serde(rename = "DisplayName")]
+    pub display_name: String,
+    /// JSON text.
+    #[serde(rename = "MemberNamePrefix")]
+    pub member_name_prefix: String,
+    /// JSON text.
+    #[serde(rename = "MemberNameSuffix")]
+    pub member_name_suffix: String,
+    /// The name of the team color. Not present
+    /// if the team has no color.
+    #[serde(rename = "TeamColor")]
+    pub team_color: Option<String>,
+    #[serde(rename = "AllowFriendlyFire")]
+    pub allow_friendly_fire: bool,
+    #[serde(rename = "SeeFriendlyInvisibles")]
+    pub see_friendly_invisibles: bool,
+    #[serde(rename = "NameTagVisibility")]
+    pub name_tag_visibility: String,
+    #[serde(rename = "DeathMessageVisibility")]
+    pub death_message_visibility: String,
+    #[serde(rename = "CollisionRule")]
+    pub collision_rule: String,
+    #[serde(rename = "Players")]
+    #[serde(default)]
+    pub players: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scoreboard_roundtrip() {
+        let mut display_slots = HashMap::new();
+        display_slots.insert("slot_1".to_owned(), "kills".to_owned());
+
+        let data = ScoreboardData {
+            objectives: vec![ObjectiveData {
+                name: "kills".to_owned(),
+                criteria_name: "playerKillCount".to_owned(),
+                display_name: r#"{"text":"Kills"}"#.to_owned(),
+                render_type: "integer".to_owned(),
+            }],
+            player_scores: vec![ScoreData {
+                name: "caelunshun".to_owned(),
+                objective: "kills".to_owned(),
+                score: 12,
+                locked: true,
+            }],
+            teams: vec![TeamData {
+                name: "red".to_owned(),
+                display_name: r#"{"text":"red"}"#.to_owned(),
+                member_name_prefix: r#"{"text":""}"#.to_owned(),
+                member_name_suffix: r#"{"text":""}"#.to_owned(),
+                team_color: Some("red".to_owned()),
+                allow_friendly_fire: true,
+                see_friendly_invisibles: true,
+                name_tag_visibility: "always".to_owned(),
+                death_message_visibility: "always".to_owned(),
+                collision_rule: "always".to_owned(),
+                players: vec!["caelunshun".to_owned()],
+            }],
+            display_slots,
+        };
+
+        let mut buf = vec![];
+        let root = Root {
+            data_version: DATA_VERSION,
+            data: data.clone(),
+        };
+        nbt::to_gzip_writer(&mut buf, &root, None).unwrap();
+
+        let root = nbt::from_gzip_reader::<_, Root>(Cursor::new(&buf)).unwrap();
+        assert_eq!(root.data_version, DATA_VERSION);
+        assert_eq!(root.data, data);
+    }
+}
@@ -1,6 +1,10 @@
 use num_derive::{FromPrimitive, ToPrimitive};
 use strum_macros::*;
 
+mod spawns;
+
+pub use spawns::{SpawnCategory, SpawnEntry};
+
 #[derive(
     Debug,
     Clone,
@@ -0,0 +1,376 @@
+//! Lists of mobs which spawn naturally in each biome.
+//!
+//! Weights and group sizes match vanilla.
+
+use crate::Biome;
+
+/// A category of naturally spawning mobs. Each
+/// category has its own list of mobs in a biome
+/// and its own cap on the number of mobs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SpawnCategory {
+    /// Hostile mobs, which spawn in the dark.
+    Monster,
+    /// Passive animals, which spawn on grass in the light.
+    Creature,
+    /// Bats.
+    Ambient,
+    /// Squid, fish and dolphins.
+    WaterCreature,
+}
+
+impl SpawnCategory {
+    /// All categories, in the order vanilla spawns them.
+    pub const ALL: [SpawnCategory; 4] = [
+        SpawnCategory::Monster,
+        SpawnCategory::Creature,
+        SpawnCategory::Ambient,
+        SpawnCategory::WaterCreature,
+    ];
+
+    /// Returns the maximum number of mobs in this category
+    /// around a single player, i.e. per 289 (17x17) chunks.
+    pub fn cap(self) -> u32 {
+        match self {
+            SpawnCategory::Monster => 70,
+            SpawnCategory::Creature => 10,
+            SpawnCategory::Ambient => 15,
+            SpawnCategory::WaterCreature => 5,
+        }
+    }
+
+    /// Returns whether mobs in this category are
+    /// peaceful, i.e. spawn on peaceful difficulty.
+    pub fn is_peaceful(self) -> bool {
+        self != SpawnCategory::Monster
+    }
+}
+
+/// A mob which can spawn in a biome.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SpawnEntry {
+    /// Namespaced entity ID, e.g. `minecraft:zombie`.
+    pub entity: &'static str,
+    /// Weight used to choose between the entries of a biome.
+    pub weight: u32,
+    /// Minimum number of mobs in a group.
+    pub min_group: u32,
+    /// Maximum number of mobs in a group.
+    pub max_group: u32,
+}
+
+macro_rules! spawns {
+    ($($entity:literal $weight:literal $min:literal $max:literal),* $(,)?) => {
+        &[$(SpawnEntry {
+            entity: concat!("minecraft:", $entity),
+            weight: $weight,
+            min_group: $min,
+            max_group: $max,
+        }),*]
+    };
+}
+
+const NONE: &[SpawnEntry] = &[];
+
+const DEFAULT_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+];
+const PLAINS_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "horse" 5 2 6,
+    "donkey" 1 1 3,
+];
+const FOREST_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "wolf" 5 4 4,
+];
+const FLOWER_FOREST_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "rabbit" 4 2 3,
+];
+const TAIGA_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "wolf" 8 4 4,
+    "rabbit" 4 2 3,
+];
+const SAVANNA_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "horse" 1 2 6,
+    "donkey" 1 1 1,
+];
+const SAVANNA_PLATEAU_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "horse" 1 2 6,
+    "donkey" 1 1 1,
+    "llama" 8 4 4,
+];
+const MOUNTAINS_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "llama" 5 4 6,
+];
+const JUNGLE_CREATURES: &[SpawnEntry] = spawns![
+    "sheep" 12 4 4,
+    "pig" 10 4 4,
+    "chicken" 10 4 4,
+    "cow" 8 4 4,
+    "parrot" 40 1 2,
+    "ocelot" 2 1 1,
+];
+const DESERT_CREATURES: &[SpawnEntry] = spawns!["rabbit" 4 2 3];
+const SNOWY_CREATURES: &[SpawnEntry] = spawns!["rabbit" 10 2 3, "polar_bear" 1 1 2];
+const MUSHROOM_CREATURES: &[SpawnEntry] = spawns!["mooshroom" 8 4 8];
+const BEACH_CREATURES: &[SpawnEntry] = spawns!["turtle" 5 2 5];
+
+const DEFAULT_MONSTERS: &[SpawnEntry] = spawns![
+    "spider" 100 4 4,
+    "zombie" 95 4 4,
+    "zombie_villager" 5 1 1,
+    "skeleton" 100 4 4,
+    "creeper" 100 4 4,
+    "slime" 100 4 4,
+    "enderman" 10 1 4,
+    "witch" 5 1 1,
+];
+const DESERT_MONSTERS: &[SpawnEntry] = spawns![
+    "spider" 100 4 4,
+    "zombie" 19 4 4,
+    "zombie_villager" 1 1 1,
+    "skeleton" 100 4 4,
+    "creeper" 100 4 4,
+    "slime" 100 4 4,
+    "enderman" 10 1 4,
+    "witch" 5 1 1,
+    "husk" 80 4 4,
+];
+const SNOWY_MONSTERS: &[SpawnEntry] = spawns![
+    "spider" 100 4 4,
+    "zombie" 95 4 4,
+    "zombie_villager" 5 1 1,
+    "skeleton" 20 4 4,
+    "creeper" 100 4 4,
+    "slime" 100 4 4,
+    "enderman" 10 1 4,
+    "witch" 5 1 1,
+    "stray" 80 4 4,
+];
+const SWAMP_MONSTERS: &[SpawnEntry] = spawns![
+    "spider" 100 4 4,
+    "zombie" 95 4 4,
+    "zombie_villager" 5 1 1,
+    "skeleton" 100 4 4,
+    "creeper" 100 4 4,
+    "slime" 100 4 4,
+    "enderman" 10 1 4,
+    "witch" 5 1 1,
+    "slime" 1 1 1,
+];
+const OCEAN_MONSTERS: &[SpawnEntry] = spawns![
+    "spider" 100 4 4,
+    "zombie" 95 4 4,
+    "zombie_villager" 5 1 1,
+    "skeleton" 100 4 4,
+    "creeper" 100 4 4,
+    "slime" 100 4 4,
+    "enderman" 10 1 4,
+    "witch" 5 1 1,
+    "drowned" 5 1 1,
+];
+const RIVER_MONSTERS: &[SpawnEntry] = spawns![
+    "spider" 100 4 4,
+    "zombie" 95 4 4,
+    "zombie_villager" 5 1 1,
+    "skeleton" 100 4 4,
+    "creeper" 100 4 4,
+    "slime" 100 4 4,
+    "enderman" 10 1 4,
+    "witch" 5 1 1,
+    "drowned" 100 1 1,
+];
+const NETHER_MONSTERS: &[SpawnEntry] = spawns![
+    "ghast" 50 4 4,
+    "zombie_pigman" 100 4 4,
+    "magma_cube" 2 4 4,
+    "enderman" 1 4 4,
+];
+const END_MONSTERS: &[SpawnEntry] = spawns!["enderman" 10 4 4];
+
+const AMBIENT: &[SpawnEntry] = spawns!["bat" 10 8 8];
+
+const OCEAN_WATER_CREATURES: &[SpawnEntry] = spawns![
+    "squid" 1 1 4,
+    "cod" 10 3 6,
+    "dolphin" 1 1 2,
+];
+const WARM_OCEAN_WATER_CREATURES: &[SpawnEntry] = spawns![
+    "squid" 10 4 4,
+    "pufferfish" 15 1 3,
+    "tropical_fish" 25 8 8,
+    "dolphin" 2 1 2,
+];
+const LUKEWARM_OCEAN_WATER_CREATURES: &[SpawnEntry] = spawns![
+    "squid" 10 1 4,
+    "cod" 15 3 6,
+    "pufferfish" 5 1 3,
+    "tropical_fish" 25 8 8,
+    "dolphin" 2 1 2,
+];
+const COLD_OCEAN_WATER_CREATURES: &[SpawnEntry] = spawns![
+    "squid" 3 1 4,
+    "cod" 15 3 6,
+    "salmon" 15 1 5,
+];
+const FROZEN_OCEAN_WATER_CREATURES: &[SpawnEntry] = spawns!["squid" 1 1 4, "salmon" 15 1 5];
+const RIVER_WATER_CREATURES: &[SpawnEntry] = spawns!["squid" 2 1 4, "salmon" 5 1 5];
+
+impl Biome {
+    /// Returns the mobs in the given category which
+    /// spawn naturally in this biome.
+    pub fn spawns(self, category: SpawnCategory) -> &'static [SpawnEntry] {
+        match category {
+            SpawnCategory::Monster => self.monsters(),
+            SpawnCategory::Creature => self.creatures(),
+            SpawnCategory::Ambient => self.ambient(),
+            SpawnCategory::WaterCreature => self.water_creatures(),
+        }
+    }
+
+    fn creatures(self) -> &'static [SpawnEntry] {
+        use Biome::*;
+        match self {
+            Plains | SunflowerPlains => PLAINS_CREATURES,
+            Forest | WoodedHills => FOREST_CREATURES,
+            FlowerForest => FLOWER_FOREST_CREATURES,
+            BirchForest | BirchForestHills | TallBirchForest | TallBirchHills | DarkForest
+            | DarkForestHills | Swamp | SwampHills => DEFAULT_CREATURES,
+            Taiga
+            | TaigaHills
+            | TaigaMountains
+            | GiantTreeTaiga
+            | GiantTreeTaigaHills
+            | GiantSpruceTaiga
+            | GiantSpruceTaigaHills
+            | SnowyTaiga
+            | SnowyTaigaHills
+            | SnowyTaigaMountains => TAIGA_CREATURES,
+            Savanna | ShatteredSavanna => SAVANNA_CREATURES,
+            SavannaPlateau | ShatteredSavannaPlateau => SAVANNA_PLATEAU_CREATURES,
+            Mountains
+            | MountainEdge
+            | WoodedMountains
+            | GravellyMountains
+            | ModifiedGravellyMountains => MOUNTAINS_CREATURES,
+            Jungle | JungleHills | JungleEdge | ModifiedJungle | ModifiedJungleEdge => {
+                JUNGLE_CREATURES
+            }
+            Desert | DesertHills | DesertLakes => DESERT_CREATURES,
+            SnowyTundra | SnowyMountains | IceSpikes => SNOWY_CREATURES,
+            MushroomFields | MushroomFieldShore => MUSHROOM_CREATURES,
+            Beach => BEACH_CREATURES,
+            _ => NONE,
+        }
+    }
+
+    fn monsters(self) -> &'static [SpawnEntry] {
+        use Biome::*;
+        match self {
+            Nether => NETHER_MONSTERS,
+            TheEnd | EndBarrens | EndHighlands | EndMidlands | SmallEndIslands => END_MONSTERS,
+            TheVoid | MushroomFields | MushroomFieldShore => NONE,
+            Desert | DesertHills | DesertLakes => DESERT_MONSTERS,
+            SnowyTundra | SnowyMountains | IceSpikes => SNOWY_MONSTERS,
+            Swamp | SwampHills => SWAMP_MONSTERS,
+            River | FrozenRiver => RIVER_MONSTERS,
+            _ if self.is_ocean() => OCEAN_MONSTERS,
+            _ => DEFAULT_MONSTERS,
+        }
+    }
+
+    fn ambient(self) -> &'static [SpawnEntry] {
+        use Biome::*;
+        match self {
+            Nether | TheEnd | EndBarrens | EndHighlands | EndMidlands | SmallEndIslands
+            | TheVoid => NONE,
+            _ => AMBIENT,
+        }
+    }
+
+    fn water_creatures(self) -> &'static [SpawnEntry] {
+        use Biome::*;
+        match self {
+            Ocean | DeepOcean => OCEAN_WATER_CREATURES,
+            WarmOcean | DeepWarmOcean => WARM_OCEAN_WATER_CREATURES,
+            LukewarmOcean | DeepLukewarmOcean => LUKEWARM_OCEAN_WATER_CREATURES,
+            ColdOcean | DeepColdOcean => COLD_OCEAN_WATER_CREATURES,
+            FrozenOcean | DeepFrozenOcean => FROZEN_OCEAN_WATER_CREATURES,
+            River | FrozenRiver => RIVER_WATER_CREATURES,
+            _ => NONE,
+        }
+    }
+
+    fn is_ocean(self) -> bool {
+        use Biome::*;
+        matches!(
+            self,
+            Ocean
+                | DeepOcean
+                | WarmOcean
+                | DeepWarmOcean
+                | LukewarmOcean
+                | DeepLukewarmOcean
+                | ColdOcean
+                | DeepColdOcean
+                | FrozenOcean
+                | DeepFrozenOcean
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawns() {
+        let plains = Biome::Plains.spawns(SpawnCategory::Creature);
+        assert!(plains.iter().any(|entry| entry.entity == "minecraft:horse"));
+        assert_eq!(
+            Biome::Desert.spawns(SpawnCategory::Monster)[8],
+            SpawnEntry {
+                entity: "minecraft:husk",
+                weight: 80,
+                min_group: 4,
+                max_group: 4,
+            }
+        );
+        assert!(Biome::MushroomFields
+            .spawns(SpawnCategory::Monster)
+            .is_empty());
+        assert!(Biome::Plains
+            .spawns(SpawnCategory::WaterCreature)
+            .is_empty());
+    }
+}
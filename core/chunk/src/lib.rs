@@ -232,7 +232,7 @@ impl Chunk {
                 return; // Nothing to do - section already empty
             }
 
-            let new_section = ChunkSection::default();
+            let new_section = self.new_section(y / 16);
             self.set_section_at(y / 16, Some(new_section));
             section = self.section_mut(y / 16).unwrap();
         }
@@ -319,9 +319,9 @@ impl Chunk {
         for HeightMapCheckContext(valid_block, check_mask, map_getter, map_setter) in checks.iter()
         {
             // Check heightmap type
-            if valid_block(old_block) && map_getter(self.heightmap_mut(x, z)) == y {
+            if valid_block(old_block) && map_getter(self.heightmap_mut(x, z)) == y + 1 {
                 // This was the highest block
-                self.heightmap_mut(x, z).set_light_blocking(0);
+                map_setter(self.heightmap_mut(x, z), 0);
 
                 for i in (0..y).rev() {
                     let block = self.block_at(x, i as usize, z);
@@ -333,9 +333,9 @@ impl Chunk {
                 }
                 mask |= *check_mask;
             }
-            if valid_block(new_block) && map_getter(self.heightmap_mut(x, z)) < y {
+            if valid_block(new_block) && map_getter(self.heightmap_mut(x, z)) <= y {
                 // This is the new highest block
-                map_setter(self.heightmap_mut(x, z), y);
+                map_setter(self.heightmap_mut(x, z), y + 1);
                 mask |= *check_mask;
             }
         }
@@ -396,7 +396,32 @@ impl Chunk {
     }
 
     fn section_for_y_mut(&mut self, y: usize) -> &mut ChunkSection {
-        self.sections[y / 16].get_or_insert_with(ChunkSection::default)
+        if self.sections[y / 16].is_none() {
+            self.sections[y / 16] = Some(self.new_section(y / 16));
+        }
+        self.sections[y / 16].as_mut().unwrap()
+    }
+
+    /// Creates an empty section to be placed at the given section index.
+    ///
+    /// A missing section is treated as having full sky light
+    /// above the light-blocking heightmap, so the new section's
+    /// sky light is initialized accordingly.
+    fn new_section(&self, index: usize) -> ChunkSection {
+        let mut section = ChunkSection::default();
+
+        for x in 0..SECTION_WIDTH {
+            for z in 0..SECTION_WIDTH {
+                let height = self.heightmap(x, z).light_blocking() as usize;
+                for y in 0..SECTION_HEIGHT {
+                    if index * SECTION_HEIGHT + y >= height {
+                        section.set_sky_light_at(x, y, z, 15);
+                    }
+                }
+            }
+        }
+
+        section
     }
 
     fn check_coords(x: usize, y: usize, z: usize) {
@@ -1430,6 +1455,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_section_sky_light() {
+        let mut chunk = Chunk::new(ChunkPosition::new(0, 0));
+        chunk.set_block_at(0, 100, 0, BlockId::stone());
+        assert_eq!(chunk.heightmap(0, 0).light_blocking(), 101);
+
+        // Creates a new section below the stone block
+        chunk.set_block_at(5, 20, 5, BlockId::stone());
+
+        assert_eq!(chunk.sky_light_at(0, 20, 0), 0);
+        assert_eq!(chunk.sky_light_at(5, 25, 5), 15);
+        assert_eq!(chunk.sky_light_at(0, 101, 0), 15);
+    }
+
+    #[test]
+    fn test_heightmap() {
+        let mut chunk = Chunk::new(ChunkPosition::new(0, 0));
+        chunk.set_block_at(0, 10, 0, BlockId::stone());
+        chunk.set_block_at(0, 20, 0, BlockId::stone());
+        assert_eq!(chunk.heightmap(0, 0).light_blocking(), 21);
+        assert_eq!(chunk.heightmap(0, 0).world_surface(), 21);
+
+        chunk.set_block_at(0, 20, 0, BlockId::air());
+        assert_eq!(chunk.heightmap(0, 0).light_blocking(), 11);
+
+        chunk.set_block_at(0, 10, 0, BlockId::glass());
+        assert_eq!(chunk.heightmap(0, 0).light_blocking(), 0);
+        assert_eq!(chunk.heightmap(0, 0).world_surface(), 11);
+    }
+
     #[test]
     fn test_light() {
         let mut chunk = Chunk::default();
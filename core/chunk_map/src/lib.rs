@@ -71,6 +71,25 @@ impl ChunkMap {
             .is_some()
     }
 
+    /// Retrieves the sky light and block light at the
+    /// given position, or `None` if its chunk is not loaded
+    /// or the coordinates are out of bounds.
+    ///
+    /// Empty chunk sections store no light; blocks in them are in
+    /// full sky light if above the highest light-blocking block.
+    pub fn light_at(&self, pos: BlockPosition) -> Option<(u8, u8)> {
+        check_coords(pos)?;
+        let chunk = self.chunk_at(pos.into())?;
+        let (x, y, z) = chunk_relative_pos(pos);
+
+        if chunk.section(y / 16).is_none() {
+            let height = usize::from(chunk.heightmap(x, z).light_blocking());
+            let sky = if y >= height { 15 } else { 0 };
+            return Some((sky, 0));
+        }
+        Some((chunk.sky_light_at(x, y, z), chunk.block_light_at(x, y, z)))
+    }
+
     /// Returns an iterator over chunks.
     pub fn iter_chunks(&self) -> impl IntoIterator<Item = &Arc<RwLock<Chunk>>> {
         self.0.values()
@@ -65,6 +65,25 @@ pub enum Area {
     ///
     /// Note that this is not the chestplate slot; use `Torso` instead.
     Chest,
+
+    /// The item being smelted in a furnace (1 slot)
+    FurnaceIngredient,
+    /// The fuel of a furnace (1 slot)
+    FurnaceFuel,
+    /// The smelting result of a furnace (1 slot)
+    FurnaceOutput,
+
+    /// The potion slots of a brewing stand (3 slots)
+    BrewingBottle,
+    /// The ingredient slot of a brewing stand (1 slot)
+    BrewingIngredient,
+    /// The blaze powder slot of a brewing stand (1 slot)
+    BrewingFuel,
+
+    /// Hopper storage (5 slots)
+    Hopper,
+    /// Dispenser or dropper storage (9 slots)
+    Dispenser,
 }
 
 /// Index into a slot.
@@ -157,7 +176,7 @@ impl Inventory {
     pub fn crafting_table() -> Self {
         let slots = btreemap! {
             Area::CraftingInput => empty(9),
-            Area::CraftingOutput => empty(9),
+            Area::CraftingOutput => empty(1),
         };
 
         Self { slots }
@@ -173,6 +192,48 @@ impl Inventory {
         Self { slots }
     }
 
+    /// Creates an inventory for a furnace.
+    pub fn furnace() -> Self {
+        let slots = btreemap! {
+            Area::FurnaceIngredient => empty(1),
+            Area::FurnaceFuel => empty(1),
+            Area::FurnaceOutput => empty(1),
+        };
+
+        Self { slots }
+    }
+
+    /// Creates an inventory for a brewing stand.
+    pub fn brewing_stand() -> Self {
+        let slots = btreemap! {
+            Area::BrewingBottle => empty(3),
+            Area::BrewingIngredient => empty(1),
+            Area::BrewingFuel => empty(1),
+        };
+
+        Self { slots }
+    }
+
+    /// Creates an inventory for a hopper.
+    /// Contains a single `Hopper` area with 5 slots.
+    pub fn hopper() -> Self {
+        let slots = btreemap! {
+            Area::Hopper => empty(5),
+        };
+
+        Self { slots }
+    }
+
+    /// Creates an inventory for a dispenser or dropper.
+    /// Contains a single `Dispenser` area with 9 slots.
+    pub fn dispenser() -> Self {
+        let slots = btreemap! {
+            Area::Dispenser => empty(9),
+        };
+
+        Self { slots }
+    }
+
     /// Returns the item at the given
     /// index inside some area.
     pub fn item_at(&self, area: Area, index: usize) -> Result<Slot, Error> {
@@ -224,6 +285,11 @@ impl Inventory {
             .map(|(area, (index, slot))| (SlotIndex { area, slot: index }, *slot.read()))
     }
 
+    /// Returns the number of slots in the given area.
+    pub fn area_size(&self, area: Area) -> Result<usize, Error> {
+        self.slots(area).map(<[_]>::len)
+    }
+
     /// Returns an iterator over the areas in this inventory.
     pub fn areas<'a>(&'a self) -> impl Iterator<Item = Area> + 'a {
         self.slots.keys().copied()
@@ -133,6 +133,28 @@ impl Window {
         }
     }
 
+    /// Creates a new `Window` for an opened crafting table.
+    ///
+    /// `grid` is the entity holding the crafting grid's inventory.
+    pub fn crafting_table(player: Entity, grid: Entity) -> Self {
+        Self {
+            protocol_to_slot: crafting_table_to_slot,
+            slot_to_protocol: crafting_table_from_slot,
+            inventories: smallvec![player, grid],
+        }
+    }
+
+    /// Creates a new `Window` for an opened furnace.
+    ///
+    /// Also used for blast furnaces and smokers, which share its layout.
+    pub fn furnace(player: Entity, furnace: Entity) -> Self {
+        Self {
+            protocol_to_slot: furnace_to_slot,
+            slot_to_protocol: furnace_from_slot,
+            inventories: smallvec![player, furnace],
+        }
+    }
+
     /// Returns the entities other than the player
     /// which this window wraps over. For example,
     /// for `Window::chest(),` this will return the chest.
@@ -321,6 +343,50 @@ fn large_chest_from_slot(slot: Index) -> usize {
     }
 }
 
+fn crafting_table_to_slot(x: usize) -> Option<Index> {
+    Some(match x {
+        0 => index(1, Area::CraftingOutput, 0),
+        1..=9 => index(1, Area::CraftingInput, x - 1),
+        10..=36 => index(0, Area::Main, x - 10),
+        37..=45 => index(0, Area::Hotbar, x - 37),
+        _ => return None,
+    })
+}
+
+fn crafting_table_from_slot(slot: Index) -> usize {
+    use Area::*;
+    match slot.area {
+        CraftingOutput => 0,
+        CraftingInput => slot.slot + 1,
+        Main => slot.slot + 10,
+        Hotbar => slot.slot + 37,
+        x => panic!("unreachable area {:?} for crafting table window", x),
+    }
+}
+
+fn furnace_to_slot(x: usize) -> Option<Index> {
+    Some(match x {
+        0 => index(1, Area::FurnaceIngredient, 0),
+        1 => index(1, Area::FurnaceFuel, 0),
+        2 => index(1, Area::FurnaceOutput, 0),
+        3..=29 => index(0, Area::Main, x - 3),
+        30..=38 => index(0, Area::Hotbar, x - 30),
+        _ => return None,
+    })
+}
+
+fn furnace_from_slot(slot: Index) -> usize {
+    use Area::*;
+    match slot.area {
+        FurnaceIngredient => 0,
+        FurnaceFuel => 1,
+        FurnaceOutput => 2,
+        Main => slot.slot + 3,
+        Hotbar => slot.slot + 30,
+        x => panic!("unreachable area {:?} for furnace window", x),
+    }
+}
+
 fn index(inventory: usize, area: Area, slot: usize) -> Index {
     Index {
         inventory,
@@ -347,4 +413,19 @@ mod tests {
     fn large_chest_roundtrip() {
         (0..89).for_each(|i| assert_eq!(i, large_chest_from_slot(large_chest_to_slot(i).unwrap())));
     }
+
+    #[test]
+    fn crafting_table_roundtrip() {
+        (0..=45).for_each(|i| {
+            assert_eq!(
+                i,
+                crafting_table_from_slot(crafting_table_to_slot(i).unwrap())
+            )
+        });
+    }
+
+    #[test]
+    fn furnace_roundtrip() {
+        (0..=38).for_each(|i| assert_eq!(i, furnace_from_slot(furnace_to_slot(i).unwrap())));
+    }
 }
@@ -0,0 +1,566 @@
+//! Enchantments and the data the enchanting algorithm needs about them.
+
+use crate::Item;
+use feather_definitions::{Tool, ToolMaterial};
+
+/// An enchantment which may be applied to an item.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Enchantment {
+    Protection,
+    FireProtection,
+    FeatherFalling,
+    BlastProtection,
+    ProjectileProtection,
+    Respiration,
+    AquaAffinity,
+    Thorns,
+    DepthStrider,
+    FrostWalker,
+    BindingCurse,
+    Sharpness,
+    Smite,
+    BaneOfArthropods,
+    Knockback,
+    FireAspect,
+    Looting,
+    Sweeping,
+    Efficiency,
+    SilkTouch,
+    Unbreaking,
+    Fortune,
+    Power,
+    Punch,
+    Flame,
+    Infinity,
+    LuckOfTheSea,
+    Lure,
+    Loyalty,
+    Impaling,
+    Riptide,
+    Channeling,
+    Mending,
+    VanishingCurse,
+}
+
+/// The kinds of items an enchantment can be applied to
+/// by an enchanting table.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EnchantmentTarget {
+    /// Any enchantable item.
+    All,
+    Armor,
+    ArmorFeet,
+    ArmorLegs,
+    ArmorChest,
+    ArmorHead,
+    /// Armor and other items which can be worn, e.g. elytra and heads.
+    Wearable,
+    Weapon,
+    Digger,
+    FishingRod,
+    Trident,
+    /// Any item with durability.
+    Breakable,
+    Bow,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ArmorSlot {
+    Feet,
+    Legs,
+    Chest,
+    Head,
+}
+
+/// Returns the armor slot `item` is worn in, if it is armor.
+fn armor_slot(item: Item) -> Option<ArmorSlot> {
+    Some(match item {
+        Item::LeatherBoots
+        | Item::ChainmailBoots
+        | Item::IronBoots
+        | Item::GoldenBoots
+        | Item::DiamondBoots => ArmorSlot::Feet,
+        Item::LeatherLeggings
+        | Item::ChainmailLeggings
+        | Item::IronLeggings
+        | Item::GoldenLeggings
+        | Item::DiamondLeggings => ArmorSlot::Legs,
+        Item::LeatherChestplate
+        | Item::ChainmailChestplate
+        | Item::IronChestplate
+        | Item::GoldenChestplate
+        | Item::DiamondChestplate => ArmorSlot::Chest,
+        Item::LeatherHelmet
+        | Item::ChainmailHelmet
+        | Item::IronHelmet
+        | Item::GoldenHelmet
+        | Item::DiamondHelmet
+        | Item::TurtleHelmet => ArmorSlot::Head,
+        _ => return None,
+    })
+}
+
+impl EnchantmentTarget {
+    const VALUES: [EnchantmentTarget; 12] = [
+        EnchantmentTarget::Armor,
+        EnchantmentTarget::ArmorFeet,
+        EnchantmentTarget::ArmorLegs,
+        EnchantmentTarget::ArmorChest,
+        EnchantmentTarget::ArmorHead,
+        EnchantmentTarget::Wearable,
+        EnchantmentTarget::Weapon,
+        EnchantmentTarget::Digger,
+        EnchantmentTarget::FishingRod,
+        EnchantmentTarget::Trident,
+        EnchantmentTarget::Breakable,
+        EnchantmentTarget::Bow,
+    ];
+
+    /// Returns whether `item` is of this kind.
+    pub fn includes(self, item: Item) -> bool {
+        match self {
+            EnchantmentTarget::All => Self::VALUES.iter().any(|target| target.includes(item)),
+            EnchantmentTarget::Armor => armor_slot(item).is_some(),
+            EnchantmentTarget::ArmorFeet => armor_slot(item) == Some(ArmorSlot::Feet),
+            EnchantmentTarget::ArmorLegs => armor_slot(item) == Some(ArmorSlot::Legs),
+            EnchantmentTarget::ArmorChest => armor_slot(item) == Some(ArmorSlot::Chest),
+            EnchantmentTarget::ArmorHead => armor_slot(item) == Some(ArmorSlot::Head),
+            EnchantmentTarget::Wearable => {
+                armor_slot(item).is_some()
+                    || match item {
+                        Item::Elytra
+                        | Item::CarvedPumpkin
+                        | Item::SkeletonSkull
+                        | Item::WitherSkeletonSkull
+                        | Item::ZombieHead
+                        | Item::PlayerHead
+                        | Item::CreeperHead
+                        | Item::DragonHead => true,
+                        _ => false,
+                    }
+            }
+            EnchantmentTarget::Weapon => item.tool() == Some(Tool::Sword),
+            EnchantmentTarget::Digger => match item.tool() {
+                Some(Tool::Axe) | Some(Tool::Pickaxe) | Some(Tool::Shovel) => true,
+                _ => false,
+            },
+            EnchantmentTarget::FishingRod => item == Item::FishingRod,
+            EnchantmentTarget::Trident => item == Item::Trident,
+            EnchantmentTarget::Breakable => item.durability().is_some(),
+            EnchantmentTarget::Bow => item == Item::Bow,
+        }
+    }
+}
+
+/// Returns how well `item` takes enchantments in an enchanting table.
+/// Items which can't be enchanted there return zero.
+pub fn enchantability(item: Item) -> u32 {
+    if let Some(material) = item.tool_material() {
+        return match material {
+            ToolMaterial::Wooden => 15,
+            ToolMaterial::Stone => 5,
+            ToolMaterial::Iron => 14,
+            ToolMaterial::Diamond => 10,
+            ToolMaterial::Golden => 22,
+        };
+    }
+
+    match item {
+        Item::LeatherHelmet
+        | Item::LeatherChestplate
+        | Item::LeatherLeggings
+        | Item::LeatherBoots => 15,
+        Item::ChainmailHelmet
+        | Item::ChainmailChestplate
+        | Item::ChainmailLeggings
+        | Item::ChainmailBoots => 12,
+        Item::IronHelmet | Item::IronChestplate | Item::IronLeggings | Item::IronBoots => 9,
+        Item::GoldenHelmet | Item::GoldenChestplate | Item::GoldenLeggings | Item::GoldenBoots => {
+            25
+        }
+        Item::DiamondHelmet
+        | Item::DiamondChestplate
+        | Item::DiamondLeggings
+        | Item::DiamondBoots => 10,
+        Item::TurtleHelmet => 9,
+        Item::Book | Item::Bow | Item::FishingRod | Item::Trident => 1,
+        _ => 0,
+    }
+}
+
+impl Enchantment {
+    /// All enchantments.
+    pub const VALUES: [Enchantment; 34] = [
+        Enchantment::Protection,
+        Enchantment::FireProtection,
+        Enchantment::FeatherFalling,
+        Enchantment::BlastProtection,
+        Enchantment::ProjectileProtection,
+        Enchantment::Respiration,
+        Enchantment::AquaAffinity,
+        Enchantment::Thorns,
+        Enchantment::DepthStrider,
+        Enchantment::FrostWalker,
+        Enchantment::BindingCurse,
+        Enchantment::Sharpness,
+        Enchantment::Smite,
+        Enchantment::BaneOfArthropods,
+        Enchantment::Knockback,
+        Enchantment::FireAspect,
+        Enchantment::Looting,
+        Enchantment::Sweeping,
+        Enchantment::Efficiency,
+        Enchantment::SilkTouch,
+        Enchantment::Unbreaking,
+        Enchantment::Fortune,
+        Enchantment::Power,
+        Enchantment::Punch,
+        Enchantment::Flame,
+        Enchantment::Infinity,
+        Enchantment::LuckOfTheSea,
+        Enchantment::Lure,
+        Enchantment::Loyalty,
+        Enchantment::Impaling,
+        Enchantment::Riptide,
+        Enchantment::Channeling,
+        Enchantment::Mending,
+        Enchantment::VanishingCurse,
+    ];
+
+    /// Returns the namespaced identifier of this enchantment,
+    /// e.g. `minecraft:silk_touch`.
+    pub fn identifier(self) -> &'static str {
+        match self {
+            Enchantment::Protection => "minecraft:protection",
+            Enchantment::FireProtection => "minecraft:fire_protection",
+            Enchantment::FeatherFalling => "minecraft:feather_falling",
+            Enchantment::BlastProtection => "minecraft:blast_protection",
+            Enchantment::ProjectileProtection => "minecraft:projectile_protection",
+            Enchantment::Respiration => "minecraft:respiration",
+            Enchantment::AquaAffinity => "minecraft:aqua_affinity",
+            Enchantment::Thorns => "minecraft:thorns",
+            Enchantment::DepthStrider => "minecraft:depth_strider",
+            Enchantment::FrostWalker => "minecraft:frost_walker",
+            Enchantment::BindingCurse => "minecraft:binding_curse",
+            Enchantment::Sharpness => "minecraft:sharpness",
+            Enchantment::Smite => "minecraft:smite",
+            Enchantment::BaneOfArthropods => "minecraft:bane_of_arthropods",
+            Enchantment::Knockback => "minecraft:knockback",
+            Enchantment::FireAspect => "minecraft:fire_aspect",
+            Enchantment::Looting => "minecraft:looting",
+            Enchantment::Sweeping => "minecraft:sweeping",
+            Enchantment::Efficiency => "minecraft:efficiency",
+            Enchantment::SilkTouch => "minecraft:silk_touch",
+            Enchantment::Unbreaking => "minecraft:unbreaking",
+            Enchantment::Fortune => "minecraft:fortune",
+            Enchantment::Power => "minecraft:power",
+            Enchantment::Punch => "minecraft:punch",
+            Enchantment::Flame => "minecraft:flame",
+            Enchantment::Infinity => "minecraft:infinity",
+            Enchantment::LuckOfTheSea => "minecraft:luck_of_the_sea",
+            Enchantment::Lure => "minecraft:lure",
+            Enchantment::Loyalty => "minecraft:loyalty",
+            Enchantment::Impaling => "minecraft:impaling",
+            Enchantment::Riptide => "minecraft:riptide",
+            Enchantment::Channeling => "minecraft:channeling",
+            Enchantment::Mending => "minecraft:mending",
+            Enchantment::VanishingCurse => "minecraft:vanishing_curse",
+        }
+    }
+
+    /// Returns the enchantment with the given identifier.
+    /// The `minecraft:` namespace is optional.
+    pub fn from_identifier(identifier: &str) -> Option<Self> {
+        let identifier = identifier.trim_start_matches("minecraft:");
+        Self::VALUES
+            .iter()
+            .copied()
+            .find(|enchantment| &enchantment.identifier()[10..] == identifier)
+    }
+
+    /// Returns the highest level of this enchantment
+    /// which can be obtained without commands.
+    pub fn max_level(self) -> u32 {
+        match self {
+            Enchantment::Sharpness
+            | Enchantment::Smite
+            | Enchantment::BaneOfArthropods
+            | Enchantment::Efficiency
+            | Enchantment::Power
+            | Enchantment::Impaling => 5,
+            Enchantment::Protection
+            | Enchantment::FireProtection
+            | Enchantment::FeatherFalling
+            | Enchantment::BlastProtection
+            | Enchantment::ProjectileProtection => 4,
+            Enchantment::Respiration
+            | Enchantment::Thorns
+            | Enchantment::DepthStrider
+            | Enchantment::Looting
+            | Enchantment::Sweeping
+            | Enchantment::Unbreaking
+            | Enchantment::Fortune
+            | Enchantment::LuckOfTheSea
+            | Enchantment::Lure
+            | Enchantment::Loyalty
+            | Enchantment::Riptide => 3,
+            Enchantment::FrostWalker
+            | Enchantment::Knockback
+            | Enchantment::FireAspect
+            | Enchantment::Punch => 2,
+            _ => 1,
+        }
+    }
+
+    /// Returns the weight with which this enchantment is chosen
+    /// by an enchanting table.
+    pub fn weight(self) -> u32 {
+        match self {
+            Enchantment::Protection
+            | Enchantment::Sharpness
+            | Enchantment::Efficiency
+            | Enchantment::Power => 10,
+            Enchantment::FireProtection
+            | Enchantment::FeatherFalling
+            | Enchantment::ProjectileProtection
+            | Enchantment::Smite
+            | Enchantment::BaneOfArthropods
+            | Enchantment::Knockback
+            | Enchantment::Unbreaking
+            | Enchantment::Loyalty => 5,
+            Enchantment::BlastProtection
+            | Enchantment::Respiration
+            | Enchantment::AquaAffinity
+            | Enchantment::DepthStrider
+            | Enchantment::FrostWalker
+            | Enchantment::FireAspect
+            | Enchantment::Looting
+            | Enchantment::Sweeping
+            | Enchantment::Fortune
+            | Enchantment::Punch
+            | Enchantment::Flame
+            | Enchantment::LuckOfTheSea
+            | Enchantment::Lure
+            | Enchantment::Impaling
+            | Enchantment::Riptide
+            | Enchantment::Mending => 2,
+            Enchantment::Thorns
+            | Enchantment::BindingCurse
+            | Enchantment::SilkTouch
+            | Enchantment::Infinity
+            | Enchantment::Channeling
+            | Enchantment::VanishingCurse => 1,
+        }
+    }
+
+    /// Returns the lowest enchanting power which can yield `level` of this enchantment.
+    pub fn min_cost(self, level: u32) -> u32 {
+        let level = level.max(1);
+        match self {
+            Enchantment::Protection | Enchantment::Sharpness => 1 + (level - 1) * 11,
+            Enchantment::FireProtection => 10 + (level - 1) * 8,
+            Enchantment::BlastProtection => 5 + (level - 1) * 8,
+            Enchantment::FeatherFalling => 5 + (level - 1) * 6,
+            Enchantment::ProjectileProtection => 3 + (level - 1) * 6,
+            Enchantment::Respiration | Enchantment::DepthStrider | Enchantment::FrostWalker => {
+                10 * level
+            }
+            Enchantment::AquaAffinity => 1,
+            Enchantment::Thorns => 10 + 20 * (level - 1),
+            Enchantment::Smite | Enchantment::BaneOfArthropods | Enchantment::Unbreaking => {
+                5 + (level - 1) * 8
+            }
+            Enchantment::Knockback => 5 + 20 * (level - 1),
+            Enchantment::FireAspect => 10 + 20 * (level - 1),
+            Enchantment::Looting
+            | Enchantment::Fortune
+            | Enchantment::LuckOfTheSea
+            | Enchantment::Lure => 15 + (level - 1) * 9,
+            Enchantment::Sweeping => 5 + (level - 1) * 9,
+            Enchantment::Efficiency | Enchantment::Power => 1 + (level - 1) * 10,
+            Enchantment::SilkTouch => 15,
+            Enchantment::Punch => 12 + (level - 1) * 20,
+            Enchantment::Flame | Enchantment::Infinity => 20,
+            Enchantment::Loyalty => 5 + level * 7,
+            Enchantment::Impaling => 1 + (level - 1) * 8,
+            Enchantment::Riptide => 10 + level * 7,
+            Enchantment::Channeling | Enchantment::BindingCurse | Enchantment::VanishingCurse => 25,
+            Enchantment::Mending => level * 25,
+        }
+    }
+
+    /// Returns the highest enchanting power which can yield `level` of this enchantment.
+    pub fn max_cost(self, level: u32) -> u32 {
+        let level = level.max(1);
+        let min_cost = self.min_cost(level);
+        match self {
+            Enchantment::Protection => min_cost + 11,
+            Enchantment::FireProtection | Enchantment::BlastProtection => min_cost + 8,
+            Enchantment::FeatherFalling | Enchantment::ProjectileProtection => min_cost + 6,
+            Enchantment::Respiration => min_cost + 30,
+            Enchantment::AquaAffinity => min_cost + 40,
+            Enchantment::DepthStrider | Enchantment::FrostWalker | Enchantment::Sweeping => {
+                min_cost + 15
+            }
+            Enchantment::Power => min_cost + 15,
+            Enchantment::Sharpness
+            | Enchantment::Smite
+            | Enchantment::BaneOfArthropods
+            | Enchantment::Impaling => min_cost + 20,
+            Enchantment::Punch => min_cost + 25,
+            Enchantment::Mending => min_cost + 50,
+            Enchantment::Thorns
+            | Enchantment::Knockback
+            | Enchantment::FireAspect
+            | Enchantment::Looting
+            | Enchantment::Efficiency
+            | Enchantment::SilkTouch
+            | Enchantment::Unbreaking
+            | Enchantment::Fortune
+            | Enchantment::LuckOfTheSea
+            | Enchantment::Lure => 1 + level * 10 + 50,
+            Enchantment::Flame
+            | Enchantment::Infinity
+            | Enchantment::Loyalty
+            | Enchantment::Riptide
+            | Enchantment::Channeling
+            | Enchantment::BindingCurse
+            | Enchantment::VanishingCurse => 50,
+        }
+    }
+
+    /// Returns whether this enchantment is only found as loot or through trading,
+    /// never from an enchanting table.
+    pub fn is_treasure(self) -> bool {
+        match self {
+            Enchantment::FrostWalker
+            | Enchantment::BindingCurse
+            | Enchantment::Mending
+            | Enchantment::VanishingCurse => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the kinds of items an enchanting table applies this enchantment to.
+    pub fn target(self) -> EnchantmentTarget {
+        match self {
+            Enchantment::Protection
+            | Enchantment::FireProtection
+            | Enchantment::BlastProtection
+            | Enchantment::ProjectileProtection => EnchantmentTarget::Armor,
+            Enchantment::FeatherFalling | Enchantment::DepthStrider | Enchantment::FrostWalker => {
+                EnchantmentTarget::ArmorFeet
+            }
+            Enchantment::Respiration | Enchantment::AquaAffinity => EnchantmentTarget::ArmorHead,
+            Enchantment::Thorns => EnchantmentTarget::ArmorChest,
+            Enchantment::BindingCurse => EnchantmentTarget::Wearable,
+            Enchantment::Sharpness
+            | Enchantment::Smite
+            | Enchantment::BaneOfArthropods
+            | Enchantment::Knockback
+            | Enchantment::FireAspect
+            | Enchantment::Looting
+            | Enchantment::Sweeping => EnchantmentTarget::Weapon,
+            Enchantment::Efficiency | Enchantment::SilkTouch | Enchantment::Fortune => {
+                EnchantmentTarget::Digger
+            }
+            Enchantment::Unbreaking | Enchantment::Mending => EnchantmentTarget::Breakable,
+            Enchantment::Power
+            | Enchantment::Punch
+            | Enchantment::Flame
+            | Enchantment::Infinity => EnchantmentTarget::Bow,
+            Enchantment::LuckOfTheSea | Enchantment::Lure => EnchantmentTarget::FishingRod,
+            Enchantment::Loyalty
+            | Enchantment::Impaling
+            | Enchantment::Riptide
+            | Enchantment::Channeling => EnchantmentTarget::Trident,
+            Enchantment::VanishingCurse => EnchantmentTarget::All,
+        }
+    }
+
+    /// Returns whether this enchantment can be applied to `item`,
+    /// e.g. by an anvil or `enchant_randomly` loot function.
+    /// This accepts a few more items than `target` does.
+    pub fn can_apply(self, item: Item) -> bool {
+        match self {
+            Enchantment::Sharpness | Enchantment::Smite | Enchantment::BaneOfArthropods
+                if item.tool() == Some(Tool::Axe) =>
+            {
+                true
+            }
+            Enchantment::Efficiency if item == Item::Shears => true,
+            Enchantment::Thorns if EnchantmentTarget::Armor.includes(item) => true,
+            _ => self.target().includes(item),
+        }
+    }
+
+    /// Returns whether this enchantment and `other` may be on the same item.
+    pub fn is_compatible_with(self, other: Enchantment) -> bool {
+        self.allows(other) && other.allows(self)
+    }
+
+    fn allows(self, other: Enchantment) -> bool {
+        use Enchantment::*;
+        if self == other {
+            return false;
+        }
+        match self {
+            Protection | FireProtection | BlastProtection | ProjectileProtection => match other {
+                Protection | FireProtection | BlastProtection | ProjectileProtection => false,
+                _ => true,
+            },
+            Sharpness | Smite | BaneOfArthropods => match other {
+                Sharpness | Smite | BaneOfArthropods => false,
+                _ => true,
+            },
+            DepthStrider => other != FrostWalker,
+            SilkTouch => other != Fortune,
+            Looting | Fortune | LuckOfTheSea => other != SilkTouch,
+            Infinity => other != Mending,
+            Riptide => other != Loyalty && other != Channeling,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identifiers_round_trip() {
+        for enchantment in Enchantment::VALUES.iter().copied() {
+            assert_eq!(
+                Enchantment::from_identifier(enchantment.identifier()),
+                Some(enchantment)
+            );
+        }
+        assert_eq!(
+            Enchantment::from_identifier("silk_touch"),
+            Some(Enchantment::SilkTouch)
+        );
+        assert_eq!(Enchantment::from_identifier("minecraft:soul_speed"), None);
+    }
+
+    #[test]
+    fn targets() {
+        assert!(Enchantment::Fortune.can_apply(Item::IronPickaxe));
+        assert!(!Enchantment::Fortune.can_apply(Item::IronHoe));
+        assert!(!Enchantment::Sharpness.target().includes(Item::IronAxe));
+        assert!(Enchantment::Sharpness.can_apply(Item::IronAxe));
+        assert!(Enchantment::VanishingCurse.can_apply(Item::PlayerHead));
+        assert!(!Enchantment::VanishingCurse.can_apply(Item::Stone));
+        assert_eq!(enchantability(Item::GoldenPickaxe), 22);
+        assert_eq!(enchantability(Item::Stone), 0);
+    }
+
+    #[test]
+    fn compatibility() {
+        assert!(!Enchantment::SilkTouch.is_compatible_with(Enchantment::Fortune));
+        assert!(!Enchantment::Protection.is_compatible_with(Enchantment::BlastProtection));
+        assert!(Enchantment::Protection.is_compatible_with(Enchantment::FeatherFalling));
+        assert!(!Enchantment::Loyalty.is_compatible_with(Enchantment::Riptide));
+        assert!(!Enchantment::Mending.is_compatible_with(Enchantment::Mending));
+        assert!(Enchantment::Efficiency.is_compatible_with(Enchantment::Fortune));
+    }
+}
@@ -1,6 +1,11 @@
 #![forbid(unsafe_code, warnings)]
 
+pub mod enchantment;
+mod tag;
+
+pub use enchantment::Enchantment;
 pub use feather_definitions::Item;
+pub use tag::{parse_snbt, ItemTag, NbtCompound, NbtValue, SnbtError};
 
 /// Represents an item stack.
 ///
@@ -13,7 +18,8 @@ pub struct ItemStack {
     pub amount: u8,
     /// Amount of damage taken on tools/equipment (how much durability expended).
     pub damage: Option<i32>,
-    // TODO enchantments, more
+    /// Any other NBT data, e.g. enchantments and the display name.
+    pub tag: Option<ItemTag>,
 }
 
 impl Default for ItemStack {
@@ -28,6 +34,7 @@ impl ItemStack {
             ty,
             amount,
             damage: None,
+            tag: None,
         }
     }
 
@@ -41,6 +48,146 @@ impl ItemStack {
     pub fn eq_ignore_amount(self, other: Self) -> bool {
         self.of_amount(0) == other.of_amount(0)
     }
+
+    /// Modifies the NBT data of this stack.
+    /// The tag is removed if `f` leaves it empty.
+    ///
+    /// `Damage` is moved to the `damage` field if `f` sets it.
+    pub fn modify_tag(&mut self, f: impl FnOnce(&mut NbtCompound)) {
+        let mut compound = self
+            .tag
+            .map(|tag| tag.compound().clone())
+            .unwrap_or_default();
+        f(&mut compound);
+        if let Some(damage) = compound.remove("Damage") {
+            self.damage = damage.as_i64().map(|damage| damage as i32);
+        }
+        self.tag = if compound.is_empty() {
+            None
+        } else {
+            Some(ItemTag::new(compound))
+        };
+    }
+
+    /// Returns the enchantments on this stack and their levels.
+    pub fn enchantments(&self) -> Vec<(Enchantment, u32)> {
+        self.enchantment_list("Enchantments")
+    }
+
+    /// Returns the enchantments stored in this enchanted book,
+    /// which are applied by combining it with an item.
+    pub fn stored_enchantments(&self) -> Vec<(Enchantment, u32)> {
+        self.enchantment_list("StoredEnchantments")
+    }
+
+    /// Returns the level of `enchantment` on this stack,
+    /// or zero if it isn't enchanted with it.
+    pub fn enchantment_level(&self, enchantment: Enchantment) -> u32 {
+        self.enchantments()
+            .into_iter()
+            .filter(|(e, _)| *e == enchantment)
+            .map(|(_, level)| level)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Adds an enchantment to this stack, replacing a lower level of it.
+    /// Enchanted books store the enchantment instead.
+    pub fn add_enchantment(&mut self, enchantment: Enchantment, level: u32) {
+        let key = if self.ty == Item::EnchantedBook {
+            "StoredEnchantments"
+        } else {
+            "Enchantments"
+        };
+
+        self.modify_tag(|tag| {
+            let mut list = match tag.remove(key) {
+                Some(NbtValue::List(list)) => list,
+                _ => Vec::new(),
+            };
+
+            let existing = list.iter_mut().find(|entry| {
+                entry
+                    .as_compound()
+                    .and_then(|entry| entry.get("id"))
+                    .and_then(NbtValue::as_str)
+                    .and_then(Enchantment::from_identifier)
+                    == Some(enchantment)
+            });
+            let level = level.min(i16::MAX as u32) as i16;
+            match existing.and_then(NbtValue::as_compound_mut) {
+                Some(entry) => {
+                    let old = entry.get("lvl").and_then(NbtValue::as_i64).unwrap_or(0);
+                    if i64::from(level) > old {
+                        entry.insert("lvl".to_owned(), NbtValue::Short(level));
+                    }
+                }
+                None => {
+                    let mut entry = NbtCompound::new();
+                    entry.insert(
+                        "id".to_owned(),
+                        NbtValue::String(enchantment.identifier().to_owned()),
+                    );
+                    entry.insert("lvl".to_owned(), NbtValue::Short(level));
+                    list.push(NbtValue::Compound(entry));
+                }
+            }
+
+            tag.insert(key.to_owned(), NbtValue::List(list));
+        });
+    }
+
+    fn enchantment_list(&self, key: &str) -> Vec<(Enchantment, u32)> {
+        let list = self
+            .tag
+            .as_ref()
+            .and_then(|tag| tag.get(key))
+            .and_then(NbtValue::as_list)
+            .unwrap_or_default();
+
+        list.iter()
+            .filter_map(NbtValue::as_compound)
+            .filter_map(|entry| {
+                let enchantment = entry
+                    .get("id")
+                    .and_then(NbtValue::as_str)
+                    .and_then(Enchantment::from_identifier)?;
+                let level = entry.get("lvl").and_then(NbtValue::as_i64).unwrap_or(0);
+                Some((enchantment, level.max(0) as u32))
+            })
+            .collect()
+    }
+
+    /// Returns the custom name of this stack as a JSON text component.
+    pub fn display_name(&self) -> Option<&str> {
+        self.tag
+            .map(ItemTag::compound)
+            .and_then(|tag| tag.get("display"))
+            .and_then(NbtValue::as_compound)
+            .and_then(|display| display.get("Name"))
+            .and_then(NbtValue::as_str)
+    }
+
+    /// Sets or removes the custom name of this stack, given as a JSON text component.
+    pub fn set_display_name(&mut self, name: Option<String>) {
+        self.modify_tag(|tag| {
+            let mut display = match tag.remove("display") {
+                Some(NbtValue::Compound(display)) => display,
+                _ => NbtCompound::new(),
+            };
+            match name {
+                Some(name) => {
+                    display.insert("Name".to_owned(), NbtValue::String(name));
+                }
+                None => {
+                    display.remove("Name");
+                }
+            }
+            if !display.is_empty() {
+                tag.insert("display".to_owned(), NbtValue::Compound(display));
+            }
+        });
+    }
 }
 
 #[cfg(test)]
@@ -53,4 +200,39 @@ mod tests {
         assert_eq!(item.vanilla_id(), 0);
         assert_eq!(Item::from_vanilla_id(0), Some(item));
     }
+
+    #[test]
+    fn enchantments() {
+        let mut pickaxe = ItemStack::new(Item::DiamondPickaxe, 1);
+        assert_eq!(pickaxe.enchantment_level(Enchantment::Fortune), 0);
+
+        pickaxe.add_enchantment(Enchantment::Fortune, 2);
+        pickaxe.add_enchantment(Enchantment::Efficiency, 5);
+        pickaxe.add_enchantment(Enchantment::Fortune, 1);
+        assert_eq!(pickaxe.enchantment_level(Enchantment::Fortune), 2);
+        assert_eq!(
+            pickaxe.enchantments(),
+            vec![(Enchantment::Fortune, 2), (Enchantment::Efficiency, 5)]
+        );
+        assert!(!pickaxe.eq_ignore_amount(ItemStack::new(Item::DiamondPickaxe, 1)));
+
+        let mut book = ItemStack::new(Item::EnchantedBook, 1);
+        book.add_enchantment(Enchantment::SilkTouch, 1);
+        assert!(book.enchantments().is_empty());
+        assert_eq!(
+            book.stored_enchantments(),
+            vec![(Enchantment::SilkTouch, 1)]
+        );
+    }
+
+    #[test]
+    fn display_name() {
+        let mut stack = ItemStack::new(Item::Chest, 1);
+        stack.set_display_name(Some(r#"{"text":"Loot"}"#.to_owned()));
+        assert_eq!(stack.display_name(), Some(r#"{"text":"Loot"}"#));
+
+        stack.set_display_name(None);
+        assert_eq!(stack.display_name(), None);
+        assert_eq!(stack, ItemStack::new(Item::Chest, 1));
+    }
 }
@@ -0,0 +1,563 @@
+//! NBT data stored on item stacks, such as enchantments
+//! and display names.
+
+use once_cell::sync::Lazy;
+use serde::de::{MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::Deref;
+use std::sync::Mutex;
+
+/// An NBT compound, mapping names to values.
+pub type NbtCompound = BTreeMap<String, NbtValue>;
+
+/// A single NBT value.
+///
+/// Floating-point values compare and hash by their bits,
+/// so that values can be used as keys.
+///
+/// NBT arrays are (de)serialized as lists, since Serde has
+/// no way of telling them apart.
+#[derive(Debug, Clone)]
+pub enum NbtValue {
+    Byte(i8),
+    Short(i16),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    String(String),
+    ByteArray(Vec<i8>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
+    List(Vec<NbtValue>),
+    Compound(NbtCompound),
+}
+
+impl NbtValue {
+    /// Returns the value of an integer tag.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            NbtValue::Byte(x) => Some(i64::from(*x)),
+            NbtValue::Short(x) => Some(i64::from(*x)),
+            NbtValue::Int(x) => Some(i64::from(*x)),
+            NbtValue::Long(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            NbtValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[NbtValue]> {
+        match self {
+            NbtValue::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    pub fn as_compound(&self) -> Option<&NbtCompound> {
+        match self {
+            NbtValue::Compound(compound) => Some(compound),
+            _ => None,
+        }
+    }
+
+    pub fn as_compound_mut(&mut self) -> Option<&mut NbtCompound> {
+        match self {
+            NbtValue::Compound(compound) => Some(compound),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for NbtValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (NbtValue::Byte(a), NbtValue::Byte(b)) => a == b,
+            (NbtValue::Short(a), NbtValue::Short(b)) => a == b,
+            (NbtValue::Int(a), NbtValue::Int(b)) => a == b,
+            (NbtValue::Long(a), NbtValue::Long(b)) => a == b,
+            (NbtValue::Float(a), NbtValue::Float(b)) => a.to_bits() == b.to_bits(),
+            (NbtValue::Double(a), NbtValue::Double(b)) => a.to_bits() == b.to_bits(),
+            (NbtValue::String(a), NbtValue::String(b)) => a == b,
+            (NbtValue::ByteArray(a), NbtValue::ByteArray(b)) => a == b,
+            (NbtValue::IntArray(a), NbtValue::IntArray(b)) => a == b,
+            (NbtValue::LongArray(a), NbtValue::LongArray(b)) => a == b,
+            (NbtValue::List(a), NbtValue::List(b)) => a == b,
+            (NbtValue::Compound(a), NbtValue::Compound(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for NbtValue {}
+
+impl Hash for NbtValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            NbtValue::Byte(x) => x.hash(state),
+            NbtValue::Short(x) => x.hash(state),
+            NbtValue::Int(x) => x.hash(state),
+            NbtValue::Long(x) => x.hash(state),
+            NbtValue::Float(x) => x.to_bits().hash(state),
+            NbtValue::Double(x) => x.to_bits().hash(state),
+            NbtValue::String(x) => x.hash(state),
+            NbtValue::ByteArray(x) => x.hash(state),
+            NbtValue::IntArray(x) => x.hash(state),
+            NbtValue::LongArray(x) => x.hash(state),
+            NbtValue::List(x) => x.hash(state),
+            NbtValue::Compound(x) => x.hash(state),
+        }
+    }
+}
+
+impl Serialize for NbtValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            NbtValue::Byte(x) => serializer.serialize_i8(*x),
+            NbtValue::Short(x) => serializer.serialize_i16(*x),
+            NbtValue::Int(x) => serializer.serialize_i32(*x),
+            NbtValue::Long(x) => serializer.serialize_i64(*x),
+            NbtValue::Float(x) => serializer.serialize_f32(*x),
+            NbtValue::Double(x) => serializer.serialize_f64(*x),
+            NbtValue::String(x) => serializer.serialize_str(x),
+            NbtValue::ByteArray(x) => serializer.collect_seq(x),
+            NbtValue::IntArray(x) => serializer.collect_seq(x),
+            NbtValue::LongArray(x) => serializer.collect_seq(x),
+            NbtValue::List(x) => serializer.collect_seq(x),
+            NbtValue::Compound(x) => serializer.collect_map(x),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for NbtValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NbtValueVisitor)
+    }
+}
+
+struct NbtValueVisitor;
+
+impl<'de> Visitor<'de> for NbtValueVisitor {
+    type Value = NbtValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an NBT value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<NbtValue, E> {
+        Ok(NbtValue::Byte(v as i8))
+    }
+
+    fn visit_i8<E>(self, v: i8) -> Result<NbtValue, E> {
+        Ok(NbtValue::Byte(v))
+    }
+
+    fn visit_i16<E>(self, v: i16) -> Result<NbtValue, E> {
+        Ok(NbtValue::Short(v))
+    }
+
+    fn visit_i32<E>(self, v: i32) -> Result<NbtValue, E> {
+        Ok(NbtValue::Int(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<NbtValue, E> {
+        Ok(NbtValue::Long(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<NbtValue, E> {
+        Ok(NbtValue::Long(v as i64))
+    }
+
+    fn visit_f32<E>(self, v: f32) -> Result<NbtValue, E> {
+        Ok(NbtValue::Float(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<NbtValue, E> {
+        Ok(NbtValue::Double(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<NbtValue, E> {
+        Ok(NbtValue::String(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<NbtValue, E> {
+        Ok(NbtValue::String(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<NbtValue, A::Error> {
+        let mut list = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            list.push(value);
+        }
+        Ok(NbtValue::List(list))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<NbtValue, A::Error> {
+        let mut compound = NbtCompound::new();
+        while let Some((key, value)) = map.next_entry()? {
+            compound.insert(key, value);
+        }
+        Ok(NbtValue::Compound(compound))
+    }
+}
+
+/// The set of all tags which have been put on an item stack.
+static TAGS: Lazy<Mutex<HashSet<&'static NbtCompound>>> = Lazy::new(Default::default);
+
+/// The NBT compound of an `ItemStack`, excluding its damage.
+///
+/// Tags are interned so that item stacks stay `Copy`:
+/// equal tags share one allocation, which lives
+/// for the rest of the program.
+#[derive(Copy, Clone)]
+pub struct ItemTag(&'static NbtCompound);
+
+impl ItemTag {
+    pub fn new(compound: NbtCompound) -> Self {
+        let mut tags = TAGS.lock().expect("item tag set poisoned");
+        if let Some(tag) = tags.get(&compound) {
+            return ItemTag(tag);
+        }
+
+        let tag: &'static NbtCompound = Box::leak(Box::new(compound));
+        tags.insert(tag);
+        ItemTag(tag)
+    }
+
+    pub fn compound(self) -> &'static NbtCompound {
+        self.0
+    }
+}
+
+impl Deref for ItemTag {
+    type Target = NbtCompound;
+
+    fn deref(&self) -> &NbtCompound {
+        self.0
+    }
+}
+
+impl PartialEq for ItemTag {
+    fn eq(&self, other: &Self) -> bool {
+        // Interning makes equal tags the same allocation.
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for ItemTag {}
+
+impl Hash for ItemTag {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0 as *const NbtCompound).hash(state);
+    }
+}
+
+impl fmt::Debug for ItemTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Error returned when stringified NBT is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnbtError {
+    /// Byte offset of the error in the input
+    pub position: usize,
+    pub message: &'static str,
+}
+
+impl fmt::Display for SnbtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for SnbtError {}
+
+/// Parses a compound from stringified NBT, e.g. `{Potion:"minecraft:water"}`,
+/// the format used by commands and loot tables.
+pub fn parse_snbt(input: &str) -> Result<NbtCompound, SnbtError> {
+    let mut parser = SnbtParser { input, position: 0 };
+    let compound = parser.compound()?;
+    parser.skip_whitespace();
+    if parser.position < input.len() {
+        return Err(parser.error("trailing data"));
+    }
+    Ok(compound)
+}
+
+struct SnbtParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> SnbtParser<'a> {
+    fn error(&self, message: &'static str) -> SnbtError {
+        SnbtError {
+            position: self.position,
+            message,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.position += c.len_utf8();
+        }
+    }
+
+    /// Skips whitespace and consumes `expected` if it is next.
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char, message: &'static str) -> Result<(), SnbtError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(self.error(message))
+        }
+    }
+
+    fn compound(&mut self) -> Result<NbtCompound, SnbtError> {
+        self.expect('{', "expected '{'")?;
+        let mut compound = NbtCompound::new();
+        if self.eat('}') {
+            return Ok(compound);
+        }
+
+        loop {
+            let key = self.string()?;
+            if key.is_empty() {
+                return Err(self.error("expected key"));
+            }
+            self.expect(':', "expected ':'")?;
+            let value = self.value()?;
+            compound.insert(key, value);
+
+            if self.eat('}') {
+                return Ok(compound);
+            }
+            self.expect(',', "expected ',' or '}'")?;
+        }
+    }
+
+    fn value(&mut self) -> Result<NbtValue, SnbtError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.compound().map(NbtValue::Compound),
+            Some('[') => self.list(),
+            Some('"') | Some('\'') => self.string().map(NbtValue::String),
+            _ => {
+                let start = self.position;
+                let word = self.string()?;
+                if word.is_empty() {
+                    self.position = start;
+                    return Err(self.error("expected value"));
+                }
+                Ok(typed_value(word))
+            }
+        }
+    }
+
+    fn list(&mut self) -> Result<NbtValue, SnbtError> {
+        self.expect('[', "expected '['")?;
+
+        let rest = &self.input[self.position..];
+        if let Some(kind) = ["B;", "I;", "L;"]
+            .iter()
+            .find(|kind| rest.starts_with(*kind))
+        {
+            self.position += 2;
+            return self.array(kind.as_bytes()[0]);
+        }
+
+        let mut list = Vec::new();
+        if self.eat(']') {
+            return Ok(NbtValue::List(list));
+        }
+        loop {
+            let value = self.value()?;
+            if let Some(first) = list.first() {
+                if mem::discriminant(first) != mem::discriminant(&value) {
+                    return Err(self.error("list elements must have the same type"));
+                }
+            }
+            list.push(value);
+
+            if self.eat(']') {
+                return Ok(NbtValue::List(list));
+            }
+            self.expect(',', "expected ',' or ']'")?;
+        }
+    }
+
+    fn array(&mut self, kind: u8) -> Result<NbtValue, SnbtError> {
+        let mut values = Vec::new();
+        if !self.eat(']') {
+            loop {
+                let value = self.value()?.as_i64();
+                values.push(value.ok_or_else(|| self.error("expected integer"))?);
+
+                if self.eat(']') {
+                    break;
+                }
+                self.expect(',', "expected ',' or ']'")?;
+            }
+        }
+
+        Ok(match kind {
+            b'B' => NbtValue::ByteArray(values.into_iter().map(|x| x as i8).collect()),
+            b'I' => NbtValue::IntArray(values.into_iter().map(|x| x as i32).collect()),
+            _ => NbtValue::LongArray(values),
+        })
+    }
+
+    /// Reads a quoted string, or an unquoted one
+    /// made of alphanumerics and `_-.+`.
+    fn string(&mut self) -> Result<String, SnbtError> {
+        self.skip_whitespace();
+        let quote = match self.peek() {
+            Some(c @ '"') | Some(c @ '\'') => c,
+            _ => {
+                let start = self.position;
+                while let Some(c) = self.peek() {
+                    if !(c.is_ascii_alphanumeric() || "_-.+".contains(c)) {
+                        break;
+                    }
+                    self.position += 1;
+                }
+                return Ok(self.input[start..self.position].to_owned());
+            }
+        };
+
+        self.position += 1;
+        let mut string = String::new();
+        let mut escaped = false;
+        while let Some(c) = self.peek() {
+            self.position += c.len_utf8();
+            if escaped {
+                if c != quote && c != '\\' {
+                    return Err(self.error("invalid escape sequence"));
+                }
+                string.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == quote {
+                return Ok(string);
+            } else {
+                string.push(c);
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+}
+
+/// Determines the type of an unquoted value from its suffix
+/// and contents. Values which aren't valid numbers are strings.
+fn typed_value(word: String) -> NbtValue {
+    let lower = word.to_ascii_lowercase();
+    let is_integer = |s: &str| {
+        let digits = s.trim_start_matches(|c| c == '-' || c == '+');
+        s.len() - digits.len() <= 1
+            && !digits.is_empty()
+            && digits.chars().all(|c| c.is_ascii_digit())
+    };
+    let is_decimal = |s: &str| {
+        s.chars().any(|c| c.is_ascii_digit())
+            && s.chars().all(|c| c.is_ascii_digit() || "+-.e".contains(c))
+    };
+    let (body, suffix) = lower.split_at(lower.len() - 1);
+
+    let value = match suffix {
+        "b" if is_integer(body) => body.parse().ok().map(NbtValue::Byte),
+        "s" if is_integer(body) => body.parse().ok().map(NbtValue::Short),
+        "l" if is_integer(body) => body.parse().ok().map(NbtValue::Long),
+        "f" if is_decimal(body) => body.parse().ok().map(NbtValue::Float),
+        "d" if is_decimal(body) => body.parse().ok().map(NbtValue::Double),
+        _ if is_integer(&lower) => lower.parse().ok().map(NbtValue::Int),
+        _ if is_decimal(&lower) && lower.contains('.') => lower.parse().ok().map(NbtValue::Double),
+        _ if lower == "true" => Some(NbtValue::Byte(1)),
+        _ if lower == "false" => Some(NbtValue::Byte(0)),
+        _ => None,
+    };
+    value.unwrap_or(NbtValue::String(word))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning() {
+        let mut compound = NbtCompound::new();
+        compound.insert("a".to_owned(), NbtValue::Float(1.5));
+        let a = ItemTag::new(compound.clone());
+        let b = ItemTag::new(compound.clone());
+        assert_eq!(a, b);
+        assert!(std::ptr::eq(a.compound(), b.compound()));
+
+        compound.insert("b".to_owned(), NbtValue::Byte(1));
+        assert_ne!(a, ItemTag::new(compound));
+    }
+
+    #[test]
+    fn snbt() {
+        let compound = parse_snbt(
+            r#"{Potion:"minecraft:water", a: 1b, b:-2s, c:3, d:4L, e:1.5f, f:2.5,
+                g:[I;1,2], h:[{x:'y\'z'}], i:true, j:abc, k:1e}"#,
+        )
+        .unwrap();
+        let get = |key: &str| compound.get(key).unwrap().clone();
+
+        assert_eq!(
+            get("Potion"),
+            NbtValue::String("minecraft:water".to_owned())
+        );
+        assert_eq!(get("a"), NbtValue::Byte(1));
+        assert_eq!(get("b"), NbtValue::Short(-2));
+        assert_eq!(get("c"), NbtValue::Int(3));
+        assert_eq!(get("d"), NbtValue::Long(4));
+        assert_eq!(get("e"), NbtValue::Float(1.5));
+        assert_eq!(get("f"), NbtValue::Double(2.5));
+        assert_eq!(get("g"), NbtValue::IntArray(vec![1, 2]));
+        let mut inner = NbtCompound::new();
+        inner.insert("x".to_owned(), NbtValue::String("y'z".to_owned()));
+        assert_eq!(get("h"), NbtValue::List(vec![NbtValue::Compound(inner)]));
+        assert_eq!(get("i"), NbtValue::Byte(1));
+        assert_eq!(get("j"), NbtValue::String("abc".to_owned()));
+        assert_eq!(get("k"), NbtValue::String("1e".to_owned()));
+    }
+
+    #[test]
+    fn invalid_snbt() {
+        assert!(parse_snbt("").is_err());
+        assert!(parse_snbt("{a:1").is_err());
+        assert!(parse_snbt("{a:[1,2b]}").is_err());
+        assert!(parse_snbt("{a:1} b").is_err());
+        assert!(parse_snbt("{:1}").is_err());
+    }
+}
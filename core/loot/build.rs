@@ -1,5 +1,5 @@
 use anyhow::Context;
-use feather_loot_model::LootTable;
+use feather_loot_model::{Condition, LootTable};
 use std::io::{Read, Write};
 use std::{env, fs::File};
 use walkdir::WalkDir;
@@ -24,6 +24,7 @@ fn run() -> anyhow::Result<()> {
     );
 
     let mut map = feather_loot_model::LootTableSet::default();
+    let mut unsupported = Vec::new();
 
     for entry in WalkDir::new(&input) {
         let entry = entry.context("entry access failed")?;
@@ -53,8 +54,11 @@ fn run() -> anyhow::Result<()> {
             .with_context(|| format!("failed to parse loot table `{}`", relative_path))?;
 
         if let Some(table) = table {
-            map.0.insert(relative_path.into(), table);
+            map.0.insert(relative_path.clone().into(), table);
         }
+
+        let json: serde_json::Value = serde_json::from_str(&s)?;
+        find_unsupported_conditions(&json, &relative_path, &mut unsupported);
     }
 
     // Write the loot table map out to the dump
@@ -63,5 +67,32 @@ fn run() -> anyhow::Result<()> {
     let vec = serde_json::to_vec(&map).unwrap();
     dump.write_all(vec.as_slice())?;
 
+    // List conditions which deserialize to `Condition::Unknown`
+    // for the `unsupported_conditions` test.
+    let unsupported_path = format!("{}/unsupported_conditions.json", env::var("OUT_DIR")?);
+    let mut file = File::create(&unsupported_path)?;
+    file.write_all(&serde_json::to_vec(&unsupported)?)?;
+
     Ok(())
 }
+
+fn find_unsupported_conditions(json: &serde_json::Value, table: &str, out: &mut Vec<String>) {
+    match json {
+        serde_json::Value::Object(object) => {
+            if let Some(name) = object.get("condition") {
+                if let Ok(Condition::Unknown) = serde_json::from_value(json.clone()) {
+                    out.push(format!("{}: {}", table, name));
+                }
+            }
+            for value in object.values() {
+                find_unsupported_conditions(value, table, out);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                find_unsupported_conditions(value, table, out);
+            }
+        }
+        _ => (),
+    }
+}
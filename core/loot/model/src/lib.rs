@@ -29,6 +29,9 @@ pub struct LootTable {
     pub kind: Option<LootTableKind>,
     #[serde(default)]
     pub pools: SmallVec<[Pool; 2]>,
+    /// Functions to apply to all items yielded by the table.
+    #[serde(default)]
+    pub functions: SmallVec<[Function; 1]>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -145,7 +148,66 @@ pub enum Condition {
     #[serde(alias = "minecraft:random_chance")]
     RandomChance { chance: f64 },
 
-    // TODO
+    /// Like `RandomChance`, but the chance is increased
+    /// by `looting_multiplier` per level of Looting.
+    #[serde(alias = "minecraft:random_chance_with_looting")]
+    RandomChanceWithLooting {
+        chance: f64,
+        looting_multiplier: f64,
+    },
+
+    /// Satisfied with a chance of one over the explosion
+    /// radius if the loot was caused by an explosion.
+    #[serde(alias = "minecraft:survives_explosion")]
+    SurvivesExplosion,
+
+    /// Satisfied with a chance depending on the level
+    /// of an enchantment on the tool. The chance for
+    /// level `n` is `chances[n]`, or the last value if
+    /// the level is too high.
+    #[serde(alias = "minecraft:table_bonus")]
+    TableBonus {
+        enchantment: InlinableString,
+        chances: SmallVec<[f64; 4]>,
+    },
+
+    /// The broken block must be `block` and have
+    /// the given values for its properties.
+    #[serde(alias = "minecraft:block_state_property")]
+    BlockStateProperty {
+        block: InlinableString,
+        #[serde(default)]
+        properties: HashMap<InlinableString, InlinableString>,
+    },
+
+    /// The entity must have been killed by a player,
+    /// or not if `inverse` is set.
+    #[serde(alias = "minecraft:killed_by_player")]
+    KilledByPlayer {
+        #[serde(default)]
+        inverse: bool,
+    },
+
+    /// An entity involved in producing the loot must match the predicate.
+    #[serde(alias = "minecraft:entity_properties")]
+    EntityProperties {
+        entity: EntityTarget,
+        #[serde(default)]
+        predicate: EntityPredicate,
+    },
+
+    /// Satisfied if `term` isn't.
+    #[serde(alias = "minecraft:inverted")]
+    Inverted { term: Box<Condition> },
+
+    /// Satisfied if any of `terms` is.
+    #[serde(alias = "minecraft:alternative")]
+    Alternative { terms: Vec<Condition> },
+
+    // TODO: damage_source_properties, entity_scores, location_check,
+    // reference, time_check, weather_check.
+    // Conditions which aren't supported are never satisfied.
+    // The `unsupported_conditions` test lists any used by the bundled tables.
     #[serde(other)]
     Unknown,
 }
@@ -154,18 +216,56 @@ pub enum Condition {
 pub struct ItemPredicate {
     /// Enchantments present on the item
     #[serde(default)]
-    pub enchantments: SmallVec<[Enchantment; 2]>,
+    pub enchantments: SmallVec<[EnchantmentPredicate; 2]>,
     /// Item identifier of the held item
     pub item: Option<InlinableString>,
-    // TODO: tag, count, durability, nbt, potion
+    /// Item tag which must contain the held item
+    pub tag: Option<InlinableString>,
+    // TODO: count, durability, nbt, potion
 }
 
+/// Matches an enchantment on an item.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "enchantment")]
-pub enum Enchantment {
-    // TODO (blocked on Feather enchantment support)
-    #[serde(other)]
-    Unknown,
+pub struct EnchantmentPredicate {
+    /// Identifier of the enchantment. If not set,
+    /// any enchantment matches.
+    pub enchantment: Option<InlinableString>,
+    /// Levels of the enchantment which match.
+    pub levels: Option<IntRange>,
+}
+
+/// An entity which an `EntityProperties` condition checks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntityTarget {
+    /// The entity dropping the loot.
+    This,
+    /// The entity which killed it.
+    Killer,
+    /// The entity which directly killed it, e.g. an arrow's shooter.
+    DirectKiller,
+    /// The player which killed it.
+    KillerPlayer,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntityPredicate {
+    /// Identifier of the entity type, or of an entity type tag
+    /// if it starts with `#`.
+    #[serde(rename = "type")]
+    pub kind: Option<InlinableString>,
+    pub flags: Option<EntityFlags>,
+    // TODO: distance, effects, equipment, location, nbt, player
+}
+
+/// Flags which must have the given values. Unset flags aren't checked.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EntityFlags {
+    pub is_on_fire: Option<bool>,
+    pub is_sneaking: Option<bool>,
+    pub is_sprinting: Option<bool>,
+    pub is_swimming: Option<bool>,
+    pub is_baby: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,19 +280,280 @@ pub struct Function {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "function")]
+#[serde(rename_all = "snake_case")]
 pub enum FunctionKind {
-    // TODO
-    // apply_bonus, copy_name, copy_nbt, copy_state, enchant_randomly, enchant_with_levels, exploration_map,
-    // explosion_decay, furnace_smelt, fill_player_head, set_attribute, set_contents, set_damage, set_lore,
-    // set_name, set_nbt, set_stew_effect
     /// Sets the stack amount.
     #[serde(alias = "minecraft:set_count")]
     SetCount { count: SetCountValue },
 
+    /// Increases the stack amount based on the level
+    /// of an enchantment on the tool, usually Fortune.
+    #[serde(alias = "minecraft:apply_bonus")]
+    ApplyBonus {
+        enchantment: InlinableString,
+        formula: BonusFormula,
+        #[serde(default)]
+        parameters: BonusParameters,
+    },
+
+    /// Increases the stack amount by the Looting level
+    /// times a value sampled from `count`.
+    #[serde(alias = "minecraft:looting_enchant")]
+    LootingEnchant {
+        count: FixedOrRandom,
+        /// Maximum stack amount. Zero means no limit.
+        #[serde(default)]
+        limit: u32,
+    },
+
+    /// Clamps the stack amount to a range.
+    #[serde(alias = "minecraft:limit_count")]
+    LimitCount { limit: IntRange },
+
+    /// Removes each item in the stack with a chance of
+    /// one minus one over the explosion radius, if the
+    /// loot was caused by an explosion.
+    #[serde(alias = "minecraft:explosion_decay")]
+    ExplosionDecay,
+
+    /// Sets the durability left as a fraction of the maximum durability.
+    #[serde(alias = "minecraft:set_damage")]
+    SetDamage { damage: FixedOrRandom },
+
+    /// Replaces the item with the result of smelting it.
+    #[serde(alias = "minecraft:furnace_smelt")]
+    FurnaceSmelt,
+
+    /// Enchants the item with one of `enchantments`,
+    /// or with any applicable enchantment if empty.
+    #[serde(alias = "minecraft:enchant_randomly")]
+    EnchantRandomly {
+        #[serde(default)]
+        enchantments: Vec<InlinableString>,
+    },
+
+    /// Enchants the item as an enchanting table would
+    /// with the given number of levels.
+    #[serde(alias = "minecraft:enchant_with_levels")]
+    EnchantWithLevels {
+        levels: FixedOrRandom,
+        #[serde(default)]
+        treasure: bool,
+    },
+
+    /// Copies the custom name of `source` to the item.
+    #[serde(alias = "minecraft:copy_name")]
+    CopyName { source: NbtSource },
+
+    /// Copies NBT from `source` to the item.
+    #[serde(alias = "minecraft:copy_nbt")]
+    CopyNbt {
+        source: NbtSource,
+        ops: Vec<CopyNbtOperation>,
+    },
+
+    /// Copies `properties` of the broken block
+    /// to the item's `BlockStateTag`.
+    #[serde(alias = "minecraft:copy_state")]
+    CopyState {
+        block: InlinableString,
+        properties: Vec<InlinableString>,
+    },
+
+    /// Turns an empty map into an explorer map to the nearest structure.
+    /// No structures are generated, so none is ever found, and
+    /// the map is left as it is, as vanilla does in that case.
+    #[serde(alias = "minecraft:exploration_map")]
+    ExplorationMap,
+
+    /// Sets the owner of a player head to `entity`, if it is a player.
+    #[serde(alias = "minecraft:fill_player_head")]
+    FillPlayerHead { entity: EntityTarget },
+
+    /// Adds attribute modifiers to the item.
+    #[serde(alias = "minecraft:set_attributes")]
+    SetAttributes { modifiers: Vec<AttributeModifier> },
+
+    /// Stores the items yielded by `entries` in the item's
+    /// `BlockEntityTag`, e.g. the contents of a shulker box.
+    #[serde(alias = "minecraft:set_contents")]
+    SetContents { entries: Vec<Entry> },
+
+    /// Adds lines of lore, given as JSON text, to the item.
+    /// Selectors and scores in the text aren't resolved.
+    #[serde(alias = "minecraft:set_lore")]
+    SetLore {
+        #[serde(default)]
+        lore: Vec<serde_json::Value>,
+        /// Whether to replace the existing lore instead of appending to it
+        #[serde(default)]
+        replace: bool,
+    },
+
+    /// Sets the custom name of the item, given as JSON text.
+    /// Selectors and scores in the text aren't resolved.
+    #[serde(alias = "minecraft:set_name")]
+    SetName { name: Option<serde_json::Value> },
+
+    /// Merges stringified NBT into the item's tag.
+    #[serde(alias = "minecraft:set_nbt")]
+    SetNbt { tag: String },
+
+    /// Adds an effect to suspicious stew.
+    /// Suspicious stew doesn't exist in this version,
+    /// so this never has an effect.
+    #[serde(alias = "minecraft:set_stew_effect")]
+    SetStewEffect,
+
     #[serde(other)]
     Unknown,
 }
 
+/// Where `CopyName` and `CopyNbt` copy from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NbtSource {
+    /// The block entity of the broken block.
+    BlockEntity,
+    /// The entity dropping the loot.
+    This,
+    /// The entity which killed it.
+    Killer,
+    /// The player which killed it.
+    KillerPlayer,
+}
+
+/// Copies the NBT at path `source` to path `target` on the item.
+/// Paths are names of nested compounds separated by dots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CopyNbtOperation {
+    pub source: String,
+    pub target: String,
+    pub op: CopyNbtAction,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyNbtAction {
+    /// Replaces the target with the source.
+    Replace,
+    /// Appends the elements of a source list to the target list.
+    Append,
+    /// Merges a source compound into the target compound.
+    Merge,
+}
+
+/// An attribute modifier added by `SetAttributes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttributeModifier {
+    pub name: String,
+    /// Name of the modified attribute, e.g. `generic.attackDamage`
+    pub attribute: String,
+    pub operation: AttributeOperation,
+    pub amount: FixedOrRandom,
+    /// UUID of the modifier. Random if not set.
+    pub id: Option<String>,
+    /// Equipment slot the modifier applies in.
+    /// One is chosen at random if several are given.
+    pub slot: OneOrMany<InlinableString>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeOperation {
+    Addition,
+    MultiplyBase,
+    MultiplyTotal,
+}
+
+impl AttributeOperation {
+    /// Returns the ID of this operation used in NBT.
+    pub fn id(self) -> i32 {
+        match self {
+            AttributeOperation::Addition => 0,
+            AttributeOperation::MultiplyBase => 1,
+            AttributeOperation::MultiplyTotal => 2,
+        }
+    }
+}
+
+/// Either a single value or a list of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            OneOrMany::One(value) => std::slice::from_ref(value),
+            OneOrMany::Many(values) => values,
+        }
+    }
+}
+
+/// Formula used by `FunctionKind::ApplyBonus`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BonusFormula {
+    /// Multiplies the amount by a random number
+    /// between one and the level plus one.
+    #[serde(alias = "minecraft:ore_drops")]
+    OreDrops,
+    /// Adds a random number between zero and
+    /// `bonusMultiplier` times the level.
+    #[serde(alias = "minecraft:uniform_bonus_count")]
+    UniformBonusCount,
+    /// Adds one for each success in the level plus `extra`
+    /// trials with a chance of `probability`.
+    #[serde(alias = "minecraft:binomial_with_bonus_count")]
+    BinomialWithBonusCount,
+}
+
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize)]
+pub struct BonusParameters {
+    #[serde(rename = "bonusMultiplier")]
+    pub bonus_multiplier: Option<f64>,
+    pub extra: Option<u32>,
+    pub probability: Option<f64>,
+}
+
+/// Either an exact value or a range
+/// with optional inclusive bounds.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IntRange {
+    Exact(f64),
+    Range { min: Option<f64>, max: Option<f64> },
+}
+
+impl IntRange {
+    /// Returns whether `value` is in this range.
+    pub fn contains(&self, value: u32) -> bool {
+        let value = f64::from(value);
+        match self {
+            IntRange::Exact(n) => (n.round() - value).abs() < f64::EPSILON,
+            IntRange::Range { min, max } => {
+                min.map_or(true, |min| value >= min.round())
+                    && max.map_or(true, |max| value <= max.round())
+            }
+        }
+    }
+
+    /// Clamps `value` to this range.
+    pub fn clamp(&self, value: u32) -> u32 {
+        match self {
+            IntRange::Exact(n) => n.round() as u32,
+            IntRange::Range { min, max } => {
+                let value = min.map_or(value, |min| value.max(min.round() as u32));
+                max.map_or(value, |max| value.min(max.round() as u32))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SetCountValue {
@@ -256,4 +617,14 @@ impl FixedOrRandom {
             }
         }
     }
+
+    /// Given an RNG, returns a value for this number
+    /// without rounding it to an integer.
+    pub fn sample_float(&self, rng: &mut impl Rng) -> f64 {
+        match self {
+            FixedOrRandom::Fixed(n) => *n,
+            FixedOrRandom::Random { min, max } if min < max => rng.gen_range(*min, *max),
+            FixedOrRandom::Random { min, .. } => *min,
+        }
+    }
 }
@@ -1,14 +1,19 @@
 //! Implements sampling of loot tables.
 
 use ahash::AHashMap;
-use feather_items::{Item, ItemStack};
+use feather_items::{enchantment, parse_snbt, Enchantment, Item, ItemStack, NbtCompound, NbtValue};
 use feather_loot_model as model;
 use inlinable_string::InlinableString;
 use itertools::Itertools;
-use model::{Condition, Entry, EntryKind, Function, FunctionKind, LootTableSet, Pool};
+use model::{
+    AttributeModifier, BonusFormula, Condition, CopyNbtAction, EnchantmentPredicate,
+    EntityPredicate, EntityTarget, Entry, EntryKind, Function, FunctionKind, LootTableSet,
+    NbtSource, Pool,
+};
 use once_cell::sync::Lazy;
 use rand::Rng;
 use smallvec::SmallVec;
+use std::collections::BTreeMap;
 use std::iter;
 use thiserror::Error;
 
@@ -35,8 +40,93 @@ pub fn loot_table(id: &str) -> Option<&'static LootTable> {
 /// Condition context used to determine whether loot table conditions are satisfied.
 #[derive(Debug, Default)]
 pub struct Conditions {
-    /// The item used to break a block
+    /// The item used to break a block or kill an entity
     pub item: Option<ItemStack>,
+    /// The broken block
+    pub block: Option<BlockState>,
+    /// NBT data of the broken block's block entity, if it has one
+    pub block_entity: Option<NbtCompound>,
+    /// Radius of the explosion which caused the loot, if any
+    pub explosion_radius: Option<f32>,
+    /// Whether the entity dropping the loot was killed by a player
+    pub killed_by_player: bool,
+    /// The entity dropping the loot
+    pub this_entity: Option<LootEntity>,
+    /// The entity which killed `this_entity`
+    pub killer: Option<LootEntity>,
+}
+
+impl Conditions {
+    /// Returns the level of an enchantment on `item`, or zero if it isn't enchanted with it.
+    pub fn enchantment_level(&self, enchantment: &str) -> u32 {
+        match (&self.item, Enchantment::from_identifier(enchantment)) {
+            (Some(item), Some(enchantment)) => item.enchantment_level(enchantment),
+            _ => 0,
+        }
+    }
+
+    fn entity(&self, target: EntityTarget) -> Option<&LootEntity> {
+        match target {
+            EntityTarget::This => self.this_entity.as_ref(),
+            EntityTarget::Killer | EntityTarget::DirectKiller => self.killer.as_ref(),
+            EntityTarget::KillerPlayer => self.killer.as_ref().filter(|_| self.killed_by_player),
+        }
+    }
+
+    fn source_entity(&self, source: NbtSource) -> Option<&LootEntity> {
+        match source {
+            NbtSource::BlockEntity => None,
+            NbtSource::This => self.entity(EntityTarget::This),
+            NbtSource::Killer => self.entity(EntityTarget::Killer),
+            NbtSource::KillerPlayer => self.entity(EntityTarget::KillerPlayer),
+        }
+    }
+
+    /// Returns the custom name of `source` as JSON text.
+    fn source_name(&self, source: NbtSource) -> Option<&str> {
+        match source {
+            NbtSource::BlockEntity => self
+                .block_entity
+                .as_ref()
+                .and_then(|nbt| nbt.get("CustomName"))
+                .and_then(NbtValue::as_str),
+            _ => self.source_entity(source)?.custom_name.as_deref(),
+        }
+    }
+
+    fn source_nbt(&self, source: NbtSource) -> Option<&NbtCompound> {
+        match source {
+            NbtSource::BlockEntity => self.block_entity.as_ref(),
+            _ => self.source_entity(source).map(|entity| &entity.nbt),
+        }
+    }
+}
+
+/// The state of a broken block.
+#[derive(Debug, Clone)]
+pub struct BlockState {
+    /// Identifier of the block, e.g. `minecraft:wheat`
+    pub identifier: &'static str,
+    /// Values of the block's properties, e.g. `age`
+    pub properties: BTreeMap<&'static str, &'static str>,
+}
+
+/// An entity involved in producing loot.
+#[derive(Debug, Clone, Default)]
+pub struct LootEntity {
+    /// Identifier of the entity's type, e.g. `minecraft:cow`
+    pub kind: InlinableString,
+    pub on_fire: bool,
+    pub sneaking: bool,
+    pub sprinting: bool,
+    pub swimming: bool,
+    pub baby: bool,
+    /// Custom name of the entity as JSON text
+    pub custom_name: Option<String>,
+    /// Username of the entity, if it is a player
+    pub player_name: Option<String>,
+    /// NBT data of the entity
+    pub nbt: NbtCompound,
 }
 
 /// Opaque wrapper over `model::LootTable`.
@@ -50,6 +140,8 @@ pub enum SampleError {
     InvalidItem(String),
     #[error("missing loot table {0}")]
     MissingLootTable(String),
+    #[error("invalid NBT {0:?}: {1}")]
+    InvalidNbt(String, feather_items::SnbtError),
     /// Should be handled gracefully.
     #[error("dynamic loot table {0:?}")]
     IsDynamic(DynamicKind),
@@ -87,6 +179,11 @@ impl LootTable {
             sample_pool(pool, rng, &mut results, conditions)?;
         }
 
+        results
+            .iter_mut()
+            .try_for_each(|item| apply_functions(self.0.functions.iter(), item, rng, conditions))?;
+        results.retain(|item| item.amount > 0);
+
         Ok(results)
     }
 }
@@ -100,6 +197,10 @@ fn sample_pool(
     // `rolls` times, choose an entry at random based on weighting
     // and yield its results.
 
+    if !satisfies_conditions(pool.conditions.iter(), conditions, rng) {
+        return Ok(());
+    }
+
     // Only select from entries with their conditions satisfied
     let entries = pool
         .entries
@@ -108,6 +209,11 @@ fn sample_pool(
         .collect::<SmallVec<[&Entry; 4]>>();
 
     let weight_sum = entries.iter().map(|entry| entry.weight).sum::<u32>();
+    if weight_sum == 0 {
+        return Ok(());
+    }
+
+    let start = results.len();
     for _ in 0..pool.rolls.sample(rng) {
         // We choose an integer at random from [0, weight_sum) and
         // determine which entry has a cumulative weight matching
@@ -132,8 +238,8 @@ fn sample_pool(
         sample_entry(entry, rng, results, conditions)?;
     }
 
-    // apply functions to results
-    results
+    // apply functions to the results of this pool
+    results[start..]
         .iter_mut()
         .try_for_each(|item| apply_functions(pool.functions.iter(), item, rng, conditions))?;
 
@@ -251,8 +357,205 @@ fn apply_functions<'a>(
         match &function.kind {
             FunctionKind::SetCount { count } => {
                 let count = count.sample(rng);
-                item.amount = count as u8;
+                set_amount(item, count);
+            }
+            FunctionKind::ApplyBonus {
+                enchantment,
+                formula,
+                parameters,
+            } => {
+                let level = conditions.enchantment_level(enchantment);
+                let amount = u32::from(item.amount);
+                let amount = match formula {
+                    // Multiplies by a number from 1 to `level + 1`, with 1 twice as likely.
+                    BonusFormula::OreDrops if level > 0 => {
+                        amount * rng.gen_range(0, level + 2).max(1)
+                    }
+                    BonusFormula::OreDrops => amount,
+                    BonusFormula::UniformBonusCount => {
+                        let multiplier = parameters.bonus_multiplier.unwrap_or(1.0);
+                        let max = (multiplier * f64::from(level)).round() as u32;
+                        amount + rng.gen_range(0, max + 1)
+                    }
+                    BonusFormula::BinomialWithBonusCount => {
+                        let probability = parameters.probability.unwrap_or(0.0);
+                        let trials = level + parameters.extra.unwrap_or(0);
+                        amount
+                            + (0..trials)
+                                .filter(|_| rng.gen::<f64>() < probability)
+                                .count() as u32
+                    }
+                };
+                set_amount(item, amount);
             }
+            FunctionKind::LootingEnchant { count, limit } => {
+                let looting = conditions.enchantment_level("looting");
+                if looting > 0 {
+                    let bonus = (f64::from(looting) * count.sample_float(rng)).round() as u32;
+                    let mut amount = u32::from(item.amount) + bonus;
+                    if *limit > 0 {
+                        amount = amount.min(*limit);
+                    }
+                    set_amount(item, amount);
+                }
+            }
+            FunctionKind::LimitCount { limit } => {
+                set_amount(item, limit.clamp(u32::from(item.amount)));
+            }
+            FunctionKind::ExplosionDecay => {
+                if let Some(radius) = conditions.explosion_radius {
+                    let chance = explosion_survival_chance(radius);
+                    let amount = (0..item.amount).filter(|_| rng.gen_bool(chance)).count();
+                    item.amount = amount as u8;
+                }
+            }
+            FunctionKind::SetDamage { damage } => {
+                if let Some(durability) = item.ty.durability() {
+                    let left = damage.sample_float(rng).max(0.0).min(1.0);
+                    item.damage = Some(((1.0 - left) * f64::from(durability)).floor() as i32);
+                }
+            }
+            FunctionKind::FurnaceSmelt => {
+                if let Some(smelted) = smelted(item.ty) {
+                    item.ty = smelted;
+                }
+            }
+            FunctionKind::EnchantRandomly { enchantments } => {
+                let candidates = if enchantments.is_empty() {
+                    Enchantment::VALUES
+                        .iter()
+                        .copied()
+                        .filter(|e| item.ty == Item::Book || e.can_apply(item.ty))
+                        .collect::<SmallVec<[Enchantment; 8]>>()
+                } else {
+                    enchantments
+                        .iter()
+                        .filter_map(|id| Enchantment::from_identifier(id))
+                        .collect()
+                };
+
+                if !candidates.is_empty() {
+                    let enchantment = candidates[rng.gen_range(0, candidates.len())];
+                    let level = rng.gen_range(1, enchantment.max_level() + 1);
+                    if item.ty == Item::Book {
+                        *item = ItemStack::new(Item::EnchantedBook, 1);
+                    }
+                    item.add_enchantment(enchantment, level);
+                }
+            }
+            FunctionKind::EnchantWithLevels { levels, treasure } => {
+                let enchantments = choose_enchantments(item.ty, levels.sample(rng), *treasure, rng);
+                if item.ty == Item::Book {
+                    *item = ItemStack::new(Item::EnchantedBook, 1);
+                }
+                for (enchantment, level) in enchantments {
+                    item.add_enchantment(enchantment, level);
+                }
+            }
+            FunctionKind::CopyName { source } => {
+                if let Some(name) = conditions.source_name(*source) {
+                    item.set_display_name(Some(name.to_owned()));
+                }
+            }
+            FunctionKind::CopyNbt { source, ops } => {
+                if let Some(nbt) = conditions.source_nbt(*source) {
+                    item.modify_tag(|tag| {
+                        for op in ops {
+                            if let Some(value) = nbt_at_path(nbt, &op.source) {
+                                copy_nbt(tag, &op.target, value, op.op);
+                            }
+                        }
+                    });
+                }
+            }
+            FunctionKind::CopyState { properties, .. } => {
+                if let Some(block) = &conditions.block {
+                    item.modify_tag(|tag| {
+                        let mut state = NbtCompound::new();
+                        for property in properties {
+                            if let Some(value) = block.properties.get(&**property) {
+                                state.insert(
+                                    property.to_string(),
+                                    NbtValue::String((*value).to_owned()),
+                                );
+                            }
+                        }
+                        copy_nbt(
+                            tag,
+                            "BlockStateTag",
+                            &NbtValue::Compound(state),
+                            CopyNbtAction::Merge,
+                        );
+                    });
+                }
+            }
+            FunctionKind::ExplorationMap => (),
+            FunctionKind::FillPlayerHead { entity } => {
+                let name = conditions
+                    .entity(*entity)
+                    .and_then(|entity| entity.player_name.as_ref());
+                if let (Item::PlayerHead, Some(name)) = (item.ty, name) {
+                    let mut owner = NbtCompound::new();
+                    owner.insert("Name".to_owned(), NbtValue::String(name.clone()));
+                    item.modify_tag(|tag| {
+                        tag.insert("SkullOwner".to_owned(), NbtValue::Compound(owner));
+                    });
+                }
+            }
+            FunctionKind::SetAttributes { modifiers } => {
+                let modifiers = modifiers
+                    .iter()
+                    .filter_map(|modifier| attribute_modifier(modifier, rng))
+                    .collect::<Vec<_>>();
+                item.modify_tag(|tag| {
+                    copy_nbt(
+                        tag,
+                        "AttributeModifiers",
+                        &NbtValue::List(modifiers),
+                        CopyNbtAction::Append,
+                    );
+                });
+            }
+            FunctionKind::SetContents { entries } => {
+                let contents = sample_contents(entries, rng, conditions)?;
+                if !contents.is_empty() {
+                    let mut block_entity = NbtCompound::new();
+                    block_entity.insert("Items".to_owned(), NbtValue::List(contents));
+                    item.modify_tag(|tag| {
+                        copy_nbt(
+                            tag,
+                            "BlockEntityTag",
+                            &NbtValue::Compound(block_entity),
+                            CopyNbtAction::Merge,
+                        );
+                    });
+                }
+            }
+            FunctionKind::SetLore { lore, replace } => {
+                // Lore is plain text in this version, rather than JSON text.
+                let lines = lore
+                    .iter()
+                    .map(|line| NbtValue::String(plain_text(line)))
+                    .collect();
+                let action = if *replace {
+                    CopyNbtAction::Replace
+                } else {
+                    CopyNbtAction::Append
+                };
+                item.modify_tag(|tag| {
+                    copy_nbt(tag, "display.Lore", &NbtValue::List(lines), action)
+                });
+            }
+            FunctionKind::SetName { name } => {
+                if let Some(name) = name {
+                    item.set_display_name(Some(name.to_string()));
+                }
+            }
+            FunctionKind::SetNbt { tag } => {
+                let nbt = parse_snbt(tag).map_err(|e| SampleError::InvalidNbt(tag.clone(), e))?;
+                item.modify_tag(|tag| merge_compound(tag, &nbt));
+            }
+            FunctionKind::SetStewEffect => (),
             FunctionKind::Unknown => (),
         }
     }
@@ -260,12 +563,315 @@ fn apply_functions<'a>(
     Ok(())
 }
 
+fn set_amount(item: &mut ItemStack, amount: u32) {
+    item.amount = amount.min(u32::from(u8::MAX)) as u8;
+}
+
+/// Chooses enchantments for `item` as an enchanting table would
+/// with `level` levels.
+fn choose_enchantments(
+    item: Item,
+    level: u32,
+    treasure: bool,
+    rng: &mut impl Rng,
+) -> Vec<(Enchantment, u32)> {
+    let enchantability = enchantment::enchantability(item);
+    if enchantability == 0 {
+        return vec![];
+    }
+
+    // Randomize the level by the item's enchantability and up to 15% either way.
+    let level = level
+        + 1
+        + rng.gen_range(0, enchantability / 4 + 1)
+        + rng.gen_range(0, enchantability / 4 + 1);
+    let deviation = (rng.gen::<f32>() + rng.gen::<f32>() - 1.0) * 0.15;
+    let mut level = (level as f32 * (1.0 + deviation)).round().max(1.0) as u32;
+
+    let mut candidates = Enchantment::VALUES
+        .iter()
+        .copied()
+        .filter(|e| treasure || !e.is_treasure())
+        .filter(|e| item == Item::Book || e.target().includes(item))
+        .filter_map(|e| {
+            (1..=e.max_level())
+                .rev()
+                .find(|l| level >= e.min_cost(*l) && level <= e.max_cost(*l))
+                .map(|l| (e, l))
+        })
+        .collect::<Vec<_>>();
+
+    let mut chosen = Vec::new();
+    while let Some(choice) = choose_weighted(&candidates, rng) {
+        chosen.push(choice);
+        // Each further enchantment is less likely.
+        if rng.gen_range(0, 50) > level {
+            break;
+        }
+        candidates.retain(|(e, _)| e.is_compatible_with(choice.0));
+        level /= 2;
+    }
+    chosen
+}
+
+fn choose_weighted(
+    candidates: &[(Enchantment, u32)],
+    rng: &mut impl Rng,
+) -> Option<(Enchantment, u32)> {
+    let weight_sum = candidates.iter().map(|(e, _)| e.weight()).sum::<u32>();
+    if weight_sum == 0 {
+        return None;
+    }
+
+    let mut n = rng.gen_range(0, weight_sum);
+    candidates
+        .iter()
+        .find(|(e, _)| {
+            if n < e.weight() {
+                true
+            } else {
+                n -= e.weight();
+                false
+            }
+        })
+        .copied()
+}
+
+/// Returns the value at a path of compound names separated by dots.
+fn nbt_at_path<'a>(compound: &'a NbtCompound, path: &str) -> Option<&'a NbtValue> {
+    let mut parts = path.split('.');
+    let first = compound.get(parts.next()?)?;
+    parts.try_fold(first, |value, part| value.as_compound()?.get(part))
+}
+
+/// Writes `value` to a path of compound names separated by dots,
+/// creating compounds along the path as needed.
+fn copy_nbt(tag: &mut NbtCompound, path: &str, value: &NbtValue, action: CopyNbtAction) {
+    let mut parts = path.split('.').collect::<SmallVec<[&str; 4]>>();
+    let last = match parts.pop() {
+        Some(last) => last,
+        None => return,
+    };
+
+    let mut compound = tag;
+    for part in parts {
+        let next = compound
+            .entry(part.to_owned())
+            .or_insert_with(|| NbtValue::Compound(NbtCompound::new()));
+        compound = match next.as_compound_mut() {
+            Some(next) => next,
+            None => return,
+        };
+    }
+
+    match (action, value) {
+        (CopyNbtAction::Replace, _) => {
+            compound.insert(last.to_owned(), value.clone());
+        }
+        (CopyNbtAction::Append, NbtValue::List(values)) => {
+            let target = compound
+                .entry(last.to_owned())
+                .or_insert_with(|| NbtValue::List(Vec::new()));
+            if let NbtValue::List(target) = target {
+                target.extend(values.iter().cloned());
+            }
+        }
+        (CopyNbtAction::Merge, NbtValue::Compound(values)) => {
+            let target = compound
+                .entry(last.to_owned())
+                .or_insert_with(|| NbtValue::Compound(NbtCompound::new()));
+            if let Some(target) = target.as_compound_mut() {
+                merge_compound(target, values);
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Merges `source` into `target`, merging nested compounds
+/// and replacing all other values.
+fn merge_compound(target: &mut NbtCompound, source: &NbtCompound) {
+    for (key, value) in source {
+        match (target.get_mut(key), value) {
+            (Some(NbtValue::Compound(target)), NbtValue::Compound(value)) => {
+                merge_compound(target, value)
+            }
+            _ => {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Creates the NBT for an attribute modifier. Returns `None`
+/// if the modifier has no equipment slots.
+fn attribute_modifier(modifier: &AttributeModifier, rng: &mut impl Rng) -> Option<NbtValue> {
+    let slots = modifier.slot.as_slice();
+    if slots.is_empty() {
+        return None;
+    }
+    let slot = &slots[rng.gen_range(0, slots.len())];
+    let (most, least) = match modifier.id.as_deref().and_then(parse_uuid) {
+        Some(uuid) => uuid,
+        None => (rng.gen(), rng.gen()),
+    };
+
+    let mut nbt = NbtCompound::new();
+    nbt.insert(
+        "AttributeName".to_owned(),
+        NbtValue::String(modifier.attribute.clone()),
+    );
+    nbt.insert("Name".to_owned(), NbtValue::String(modifier.name.clone()));
+    nbt.insert(
+        "Amount".to_owned(),
+        NbtValue::Double(modifier.amount.sample_float(rng)),
+    );
+    nbt.insert(
+        "Operation".to_owned(),
+        NbtValue::Int(modifier.operation.id()),
+    );
+    nbt.insert("UUIDMost".to_owned(), NbtValue::Long(most));
+    nbt.insert("UUIDLeast".to_owned(), NbtValue::Long(least));
+    nbt.insert("Slot".to_owned(), NbtValue::String(slot.to_string()));
+    Some(NbtValue::Compound(nbt))
+}
+
+/// Parses a UUID into its most and least significant halves.
+fn parse_uuid(uuid: &str) -> Option<(i64, i64)> {
+    let hex = uuid.replace('-', "");
+    if hex.len() != 32 {
+        return None;
+    }
+    let value = u128::from_str_radix(&hex, 16).ok()?;
+    Some(((value >> 64) as i64, value as i64))
+}
+
+/// Samples the items yielded by `entries` as NBT item slots.
+/// Dynamic `contents` entries yield the items of the block entity.
+fn sample_contents(
+    entries: &[Entry],
+    rng: &mut impl Rng,
+    conditions: &Conditions,
+) -> Result<Vec<NbtValue>, SampleError> {
+    let mut contents = Vec::new();
+    let mut next_slot = 0;
+    for entry in entries {
+        if let EntryKind::Dynamic = entry.kind {
+            if strip_namespace(&entry.name) != "contents" {
+                continue;
+            }
+            let items = conditions
+                .block_entity
+                .as_ref()
+                .and_then(|nbt| nbt.get("Items"))
+                .and_then(NbtValue::as_list)
+                .unwrap_or_default();
+            for item in items {
+                let slot = item
+                    .as_compound()
+                    .and_then(|item| item.get("Slot"))
+                    .and_then(NbtValue::as_i64)
+                    .unwrap_or(0);
+                next_slot = next_slot.max(slot + 1);
+                contents.push(item.clone());
+            }
+            continue;
+        }
+
+        let mut items = SmallVec::new();
+        sample_entry(entry, rng, &mut items, conditions)?;
+        for item in items {
+            contents.push(item_slot(item, next_slot));
+            next_slot += 1;
+        }
+    }
+    Ok(contents)
+}
+
+/// Returns the NBT for an item in an inventory slot.
+fn item_slot(item: ItemStack, slot: i64) -> NbtValue {
+    let mut nbt = NbtCompound::new();
+    nbt.insert("Slot".to_owned(), NbtValue::Byte(slot as i8));
+    nbt.insert(
+        "id".to_owned(),
+        NbtValue::String(item.ty.identifier().to_owned()),
+    );
+    nbt.insert("Count".to_owned(), NbtValue::Byte(item.amount as i8));
+
+    let mut tag = item
+        .tag
+        .map(|tag| tag.compound().clone())
+        .unwrap_or_default();
+    if let Some(damage) = item.damage {
+        tag.insert("Damage".to_owned(), NbtValue::Int(damage));
+    }
+    if !tag.is_empty() {
+        nbt.insert("tag".to_owned(), NbtValue::Compound(tag));
+    }
+    NbtValue::Compound(nbt)
+}
+
+/// Returns the literal text of JSON text, without formatting.
+fn plain_text(text: &serde_json::Value) -> String {
+    match text {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(parts) => parts.iter().map(plain_text).collect(),
+        serde_json::Value::Object(component) => {
+            let mut text = component
+                .get("text")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default()
+                .to_owned();
+            if let Some(extra) = component.get("extra") {
+                text.push_str(&plain_text(extra));
+            }
+            text
+        }
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Returns the item produced by smelting `item` in a furnace.
+/// Only covers the items which loot tables smelt.
+fn smelted(item: Item) -> Option<Item> {
+    Some(match item {
+        Item::Beef => Item::CookedBeef,
+        Item::Porkchop => Item::CookedPorkchop,
+        Item::Chicken => Item::CookedChicken,
+        Item::Mutton => Item::CookedMutton,
+        Item::Rabbit => Item::CookedRabbit,
+        Item::Cod => Item::CookedCod,
+        Item::Salmon => Item::CookedSalmon,
+        Item::Potato => Item::BakedPotato,
+        Item::Kelp => Item::DriedKelp,
+        _ => return None,
+    })
+}
+
+/// Returns the chance for loot to survive an explosion with the given radius.
+fn explosion_survival_chance(radius: f32) -> f64 {
+    if radius <= 1.0 {
+        1.0
+    } else {
+        1.0 / f64::from(radius)
+    }
+}
+
+fn strip_namespace(id: &str) -> &str {
+    id.trim_start_matches("minecraft:")
+}
+
 fn satisfies_conditions<'a>(
     mut conditions: impl Iterator<Item = &'a Condition>,
     input: &Conditions,
     rng: &mut impl Rng,
 ) -> bool {
-    conditions.all(|condition| match condition {
+    conditions.all(|condition| satisfies_condition(condition, input, rng))
+}
+
+fn satisfies_condition(condition: &Condition, input: &Conditions, rng: &mut impl Rng) -> bool {
+    match condition {
         Condition::MatchTool { predicate } => {
             if let Some(item) = &predicate.item {
                 match &input.item {
@@ -278,31 +884,164 @@ fn satisfies_conditions<'a>(
                 }
             }
 
-            // enchantments are not yet supported
-            if !predicate.enchantments.is_empty() {
-                return false;
+            if let Some(tag) = &predicate.tag {
+                let tag = format!("minecraft:{}", strip_namespace(tag));
+                match &input.item {
+                    Some(stack) => {
+                        if !feather_recipes::item_tag(&tag).contains(&stack.ty) {
+                            return false;
+                        }
+                    }
+                    None => return false,
+                }
             }
 
-            true
+            predicate
+                .enchantments
+                .iter()
+                .all(|enchantment| matches_enchantment(enchantment, input))
         }
-        Condition::RandomChance { chance } => {
-            let chance = chance.max(0.0).min(1.0);
-            rng.gen_bool(chance)
+        Condition::RandomChance { chance } => random_chance(*chance, rng),
+        Condition::RandomChanceWithLooting {
+            chance,
+            looting_multiplier,
+        } => {
+            let looting = f64::from(input.enchantment_level("looting"));
+            random_chance(chance + looting * looting_multiplier, rng)
         }
-        Condition::Unknown => true,
-    })
+        Condition::SurvivesExplosion => match input.explosion_radius {
+            Some(radius) => rng.gen_bool(explosion_survival_chance(radius)),
+            None => true,
+        },
+        Condition::TableBonus {
+            enchantment,
+            chances,
+        } => {
+            let level = input.enchantment_level(enchantment) as usize;
+            let chance = chances.get(level).or_else(|| chances.last());
+            random_chance(chance.copied().unwrap_or(0.0), rng)
+        }
+        Condition::BlockStateProperty { block, properties } => match &input.block {
+            Some(state) => {
+                strip_namespace(state.identifier) == strip_namespace(block)
+                    && properties.iter().all(|(name, value)| {
+                        state.properties.get(&**name).copied() == Some(&**value)
+                    })
+            }
+            None => false,
+        },
+        Condition::KilledByPlayer { inverse } => input.killed_by_player != *inverse,
+        Condition::EntityProperties { entity, predicate } => input
+            .entity(*entity)
+            .map_or(false, |entity| matches_entity(predicate, entity)),
+        Condition::Inverted { term } => !satisfies_condition(term, input, rng),
+        Condition::Alternative { terms } => terms
+            .iter()
+            .any(|term| satisfies_condition(term, input, rng)),
+        Condition::Unknown => false,
+    }
+}
+
+fn random_chance(chance: f64, rng: &mut impl Rng) -> bool {
+    rng.gen_bool(chance.max(0.0).min(1.0))
+}
+
+fn matches_enchantment(predicate: &EnchantmentPredicate, input: &Conditions) -> bool {
+    let levels_match = |level| {
+        predicate
+            .levels
+            .map_or(true, |levels| levels.contains(level))
+    };
+    match &predicate.enchantment {
+        Some(enchantment) => {
+            let level = input.enchantment_level(enchantment);
+            level > 0 && levels_match(level)
+        }
+        None => input.item.map_or(false, |item| {
+            item.enchantments()
+                .into_iter()
+                .any(|(_, level)| levels_match(level))
+        }),
+    }
+}
+
+fn matches_entity(predicate: &EntityPredicate, entity: &LootEntity) -> bool {
+    let kind_matches = match &predicate.kind {
+        // Entity type tags aren't loaded yet.
+        Some(kind) if kind.starts_with('#') => false,
+        Some(kind) => strip_namespace(kind) == strip_namespace(&entity.kind),
+        None => true,
+    };
+
+    let flags = match &predicate.flags {
+        Some(flags) => flags,
+        None => return kind_matches,
+    };
+    let flag_matches = |expected: Option<bool>, actual| expected.map_or(true, |e| e == actual);
+    kind_matches
+        && flag_matches(flags.is_on_fire, entity.on_fire)
+        && flag_matches(flags.is_sneaking, entity.sneaking)
+        && flag_matches(flags.is_sprinting, entity.sprinting)
+        && flag_matches(flags.is_swimming, entity.swimming)
+        && flag_matches(flags.is_baby, entity.baby)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::rngs::mock::StepRng;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn store_deserializes_successfully() {
         Lazy::force(&STORE);
     }
 
+    #[test]
+    fn unsupported_conditions() {
+        // Written by the build script, listing conditions in the bundled
+        // tables which are parsed as `Condition::Unknown`.
+        static UNSUPPORTED: &str =
+            include_str!(concat!(env!("OUT_DIR"), "/unsupported_conditions.json"));
+        let unsupported: Vec<String> = serde_json::from_str(UNSUPPORTED).unwrap();
+
+        assert!(
+            unsupported.is_empty(),
+            "loot tables use conditions which are never satisfied:\n{}",
+            unsupported.join("\n")
+        );
+    }
+
+    #[test]
+    fn match_tool_tag() {
+        let mut rng = StepRng::new(0, 1);
+        let condition: Condition = serde_json::from_str(
+            r#"{"condition": "minecraft:match_tool", "predicate": {"tag": "minecraft:logs"}}"#,
+        )
+        .unwrap();
+        let holding = |item| Conditions {
+            item: Some(ItemStack::new(item, 1)),
+            ..Default::default()
+        };
+
+        assert!(satisfies_condition(
+            &condition,
+            &holding(Item::OakLog),
+            &mut rng
+        ));
+        assert!(!satisfies_condition(
+            &condition,
+            &holding(Item::Stick),
+            &mut rng
+        ));
+        assert!(!satisfies_condition(
+            &condition,
+            &Conditions::default(),
+            &mut rng
+        ));
+    }
+
     #[test]
     fn sample_dirt() {
         let table = loot_table("blocks/dirt").expect("missing loot table for dirt block");
@@ -325,8 +1064,458 @@ mod tests {
 
         let mut rng = StepRng::new(0, 1);
 
-        let items = table.sample(&mut rng, &Conditions { item: None }).unwrap();
+        let conditions = Conditions {
+            item: None,
+            ..Default::default()
+        };
+        let items = table.sample(&mut rng, &conditions).unwrap();
 
         assert_eq!(items.as_slice(), &[ItemStack::new(Item::Dirt, 1)]);
     }
+
+    fn sample(id: &str, rng: &mut impl Rng, conditions: &Conditions) -> SmallVec<[ItemStack; 2]> {
+        loot_table(id)
+            .unwrap_or_else(|| panic!("missing loot table {}", id))
+            .sample(rng, conditions)
+            .unwrap()
+    }
+
+    fn enchanted(item: Item, enchantment: &str, level: u32) -> Conditions {
+        let mut item = ItemStack::new(item, 1);
+        item.add_enchantment(Enchantment::from_identifier(enchantment).unwrap(), level);
+        Conditions {
+            item: Some(item),
+            ..Default::default()
+        }
+    }
+
+    fn apply(function: &str, item: &mut ItemStack, rng: &mut impl Rng, conditions: &Conditions) {
+        let function: Function = serde_json::from_str(function).unwrap();
+        apply_functions(iter::once(&function), item, rng, conditions).unwrap();
+    }
+
+    #[test]
+    fn silk_touch() {
+        let mut rng = StepRng::new(0, 1);
+        let silk_touch = enchanted(Item::DiamondPickaxe, "minecraft:silk_touch", 1);
+
+        assert!(sample("blocks/glass", &mut rng, &Conditions::default()).is_empty());
+        assert_eq!(
+            sample("blocks/glass", &mut rng, &silk_touch).as_slice(),
+            &[ItemStack::new(Item::Glass, 1)]
+        );
+        assert_eq!(
+            sample("blocks/diamond_ore", &mut rng, &silk_touch).as_slice(),
+            &[ItemStack::new(Item::DiamondOre, 1)]
+        );
+    }
+
+    #[test]
+    fn fortune_ore_drops() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let pickaxe = Conditions {
+            item: Some(ItemStack::new(Item::DiamondPickaxe, 1)),
+            ..Default::default()
+        };
+        let fortune = enchanted(Item::DiamondPickaxe, "minecraft:fortune", 3);
+
+        for _ in 0..100 {
+            assert_eq!(
+                sample("blocks/diamond_ore", &mut rng, &pickaxe).as_slice(),
+                &[ItemStack::new(Item::Diamond, 1)]
+            );
+        }
+
+        let amounts: Vec<_> = (0..100)
+            .map(|_| {
+                let items = sample("blocks/diamond_ore", &mut rng, &fortune);
+                assert_eq!(items.len(), 1);
+                assert_eq!(items[0].ty, Item::Diamond);
+                items[0].amount
+            })
+            .collect();
+        assert!(amounts.iter().all(|amount| (1..=4).contains(amount)));
+        assert!(amounts.iter().any(|amount| *amount > 1));
+    }
+
+    #[test]
+    fn fortune_table_bonus() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let fortune = enchanted(Item::DiamondShovel, "minecraft:fortune", 3);
+
+        let gravel: Vec<_> = (0..200)
+            .flat_map(|_| sample("blocks/gravel", &mut rng, &Conditions::default()))
+            .collect();
+        assert!(gravel.contains(&ItemStack::new(Item::Gravel, 1)));
+        assert!(gravel.contains(&ItemStack::new(Item::Flint, 1)));
+
+        // Fortune III always drops flint.
+        for _ in 0..100 {
+            assert_eq!(
+                sample("blocks/gravel", &mut rng, &fortune).as_slice(),
+                &[ItemStack::new(Item::Flint, 1)]
+            );
+        }
+    }
+
+    #[test]
+    fn survives_explosion() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let explosion = Conditions {
+            explosion_radius: Some(4.0),
+            ..Default::default()
+        };
+
+        let drops: Vec<_> = (0..200)
+            .map(|_| sample("blocks/dirt", &mut rng, &explosion).len())
+            .collect();
+        assert!(drops.contains(&0));
+        assert!(drops.contains(&1));
+    }
+
+    #[test]
+    fn explosion_decay() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let explosion = Conditions {
+            explosion_radius: Some(2.0),
+            ..Default::default()
+        };
+        let function = r#"{"function": "minecraft:explosion_decay"}"#;
+
+        let mut item = ItemStack::new(Item::Diamond, 64);
+        apply(function, &mut item, &mut rng, &explosion);
+        assert!((1..64).contains(&item.amount));
+
+        let mut item = ItemStack::new(Item::Diamond, 64);
+        apply(function, &mut item, &mut rng, &Conditions::default());
+        assert_eq!(item.amount, 64);
+    }
+
+    #[test]
+    fn block_state_property() {
+        let mut rng = StepRng::new(0, 1);
+        let wheat = |age| Conditions {
+            block: Some(BlockState {
+                identifier: "minecraft:wheat",
+                properties: iter::once(("age", age)).collect(),
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sample("blocks/wheat", &mut rng, &wheat("0")).as_slice(),
+            &[ItemStack::new(Item::WheatSeeds, 1)]
+        );
+
+        let items = sample("blocks/wheat", &mut rng, &wheat("7"));
+        assert_eq!(items.len(), 2);
+        assert!(items.contains(&ItemStack::new(Item::Wheat, 1)));
+        // All three bonus seeds succeed with this RNG.
+        assert!(items.contains(&ItemStack::new(Item::WheatSeeds, 4)));
+    }
+
+    #[test]
+    fn alternative_and_inverted() {
+        let mut rng = StepRng::new(0, 1);
+        let shears = Conditions {
+            item: Some(ItemStack::new(Item::Shears, 1)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            sample("blocks/oak_leaves", &mut rng, &shears).as_slice(),
+            &[ItemStack::new(Item::OakLeaves, 1)]
+        );
+
+        let items = sample("blocks/oak_leaves", &mut rng, &Conditions::default());
+        assert!(items.iter().all(|item| item.ty != Item::OakLeaves));
+        assert!(items.iter().any(|item| item.ty == Item::OakSapling));
+    }
+
+    #[test]
+    fn killed_by_player() {
+        let mut rng = StepRng::new(0, 1);
+        let rare = [Item::IronIngot, Item::Carrot, Item::Potato];
+        let rare_drops = |items: SmallVec<[ItemStack; 2]>| {
+            items.iter().filter(|item| rare.contains(&item.ty)).count()
+        };
+
+        let by_mob = Conditions::default();
+        assert_eq!(rare_drops(sample("entities/zombie", &mut rng, &by_mob)), 0);
+
+        let killed = Conditions {
+            killed_by_player: true,
+            ..Default::default()
+        };
+        assert_eq!(rare_drops(sample("entities/zombie", &mut rng, &killed)), 1);
+    }
+
+    #[test]
+    fn furnace_smelt_on_fire() {
+        let mut rng = StepRng::new(0, 1);
+        let cow = |on_fire| Conditions {
+            this_entity: Some(LootEntity {
+                kind: "minecraft:cow".into(),
+                on_fire,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let items = sample("entities/cow", &mut rng, &cow(false));
+        assert!(items.iter().any(|item| item.ty == Item::Beef));
+
+        let items = sample("entities/cow", &mut rng, &cow(true));
+        assert!(items.iter().any(|item| item.ty == Item::CookedBeef));
+        assert!(items.iter().all(|item| item.ty != Item::Beef));
+    }
+
+    #[test]
+    fn set_damage() {
+        let mut rng = StepRng::new(0, 1);
+        let conditions = Conditions::default();
+
+        let mut sword = ItemStack::new(Item::DiamondSword, 1);
+        apply(
+            r#"{"function": "minecraft:set_damage", "damage": 0.25}"#,
+            &mut sword,
+            &mut rng,
+            &conditions,
+        );
+        assert_eq!(sword.damage, Some(1170));
+    }
+
+    #[test]
+    fn enchant_randomly() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let conditions = Conditions::default();
+
+        let mut book = ItemStack::new(Item::Book, 1);
+        apply(
+            r#"{"function": "minecraft:enchant_randomly"}"#,
+            &mut book,
+            &mut rng,
+            &conditions,
+        );
+        assert_eq!(book.ty, Item::EnchantedBook);
+        assert_eq!(book.stored_enchantments().len(), 1);
+
+        for _ in 0..20 {
+            let mut sword = ItemStack::new(Item::DiamondSword, 1);
+            apply(
+                r#"{"function": "minecraft:enchant_randomly", "enchantments": ["minecraft:sharpness"]}"#,
+                &mut sword,
+                &mut rng,
+                &conditions,
+            );
+            assert!((1..=5).contains(&sword.enchantment_level(Enchantment::Sharpness)));
+        }
+    }
+
+    #[test]
+    fn enchant_with_levels() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let conditions = Conditions::default();
+        let function = r#"{"function": "minecraft:enchant_with_levels", "levels": 30}"#;
+
+        for _ in 0..20 {
+            let mut pickaxe = ItemStack::new(Item::IronPickaxe, 1);
+            apply(function, &mut pickaxe, &mut rng, &conditions);
+
+            let enchantments = pickaxe.enchantments();
+            assert!(!enchantments.is_empty());
+            for (i, (enchantment, level)) in enchantments.iter().enumerate() {
+                assert!(enchantment.target().includes(Item::IronPickaxe));
+                assert!(!enchantment.is_treasure());
+                assert!((1..=enchantment.max_level()).contains(level));
+                for (other, _) in &enchantments[i + 1..] {
+                    assert!(enchantment.is_compatible_with(*other));
+                }
+            }
+        }
+
+        let mut stick = ItemStack::new(Item::Stick, 1);
+        apply(function, &mut stick, &mut rng, &conditions);
+        assert_eq!(stick, ItemStack::new(Item::Stick, 1));
+    }
+
+    #[test]
+    fn shulker_box_contents() {
+        let mut rng = StepRng::new(0, 1);
+
+        let mut slot = NbtCompound::new();
+        slot.insert("Slot".to_owned(), NbtValue::Byte(3));
+        slot.insert(
+            "id".to_owned(),
+            NbtValue::String("minecraft:diamond".to_owned()),
+        );
+        slot.insert("Count".to_owned(), NbtValue::Byte(5));
+        let mut block_entity = NbtCompound::new();
+        block_entity.insert(
+            "CustomName".to_owned(),
+            NbtValue::String(r#"{"text":"Loot"}"#.to_owned()),
+        );
+        block_entity.insert(
+            "Items".to_owned(),
+            NbtValue::List(vec![NbtValue::Compound(slot.clone())]),
+        );
+        let conditions = Conditions {
+            block_entity: Some(block_entity),
+            ..Default::default()
+        };
+
+        let items = sample("blocks/shulker_box", &mut rng, &conditions);
+        assert_eq!(items.len(), 1);
+        let shulker_box = items[0];
+        assert_eq!(shulker_box.ty, Item::ShulkerBox);
+        assert_eq!(shulker_box.display_name(), Some(r#"{"text":"Loot"}"#));
+
+        let tag = shulker_box.tag.unwrap();
+        let contents = nbt_at_path(&tag, "BlockEntityTag.Items").and_then(NbtValue::as_list);
+        assert_eq!(contents, Some(&[NbtValue::Compound(slot)][..]));
+
+        // Without a block entity, the box drops empty.
+        let items = sample("blocks/shulker_box", &mut rng, &Conditions::default());
+        assert_eq!(items.as_slice(), &[ItemStack::new(Item::ShulkerBox, 1)]);
+    }
+
+    #[test]
+    fn set_contents() {
+        let mut rng = StepRng::new(0, 1);
+        let mut chest = ItemStack::new(Item::Chest, 1);
+        apply(
+            r#"{
+                "function": "minecraft:set_contents",
+                "entries": [
+                    {"type": "minecraft:item", "name": "minecraft:diamond"},
+                    {"type": "minecraft:item", "name": "minecraft:stick"}
+                ]
+            }"#,
+            &mut chest,
+            &mut rng,
+            &Conditions::default(),
+        );
+
+        let tag = chest.tag.unwrap();
+        let contents = nbt_at_path(&tag, "BlockEntityTag.Items")
+            .and_then(NbtValue::as_list)
+            .unwrap();
+        let ids: Vec<_> = contents
+            .iter()
+            .map(|item| {
+                let item = item.as_compound().unwrap();
+                (item["Slot"].as_i64(), item["id"].as_str())
+            })
+            .collect();
+        assert_eq!(
+            ids,
+            &[
+                (Some(0), Some("minecraft:diamond")),
+                (Some(1), Some("minecraft:stick"))
+            ]
+        );
+    }
+
+    #[test]
+    fn copy_nbt() {
+        let mut rng = StepRng::new(0, 1);
+        let mut block_entity = NbtCompound::new();
+        block_entity.insert("Lock".to_owned(), NbtValue::String("key".to_owned()));
+        let conditions = Conditions {
+            block_entity: Some(block_entity),
+            ..Default::default()
+        };
+
+        let mut chest = ItemStack::new(Item::Chest, 1);
+        apply(
+            r#"{
+                "function": "minecraft:copy_nbt",
+                "source": "block_entity",
+                "ops": [
+                    {"source": "Lock", "target": "BlockEntityTag.Lock", "op": "replace"},
+                    {"source": "Missing", "target": "BlockEntityTag.Missing", "op": "replace"}
+                ]
+            }"#,
+            &mut chest,
+            &mut rng,
+            &conditions,
+        );
+
+        let tag = chest.tag.unwrap();
+        assert_eq!(
+            nbt_at_path(&tag, "BlockEntityTag.Lock").and_then(NbtValue::as_str),
+            Some("key")
+        );
+        assert!(nbt_at_path(&tag, "BlockEntityTag.Missing").is_none());
+    }
+
+    #[test]
+    fn set_name_and_lore() {
+        let mut rng = StepRng::new(0, 1);
+        let mut stick = ItemStack::new(Item::Stick, 1);
+        apply(
+            r#"{"function": "minecraft:set_name", "name": {"text": "Wand"}}"#,
+            &mut stick,
+            &mut rng,
+            &Conditions::default(),
+        );
+        apply(
+            r#"{"function": "minecraft:set_lore", "lore": [{"text": "Old", "extra": [" wood"]}]}"#,
+            &mut stick,
+            &mut rng,
+            &Conditions::default(),
+        );
+
+        assert_eq!(stick.display_name(), Some(r#"{"text":"Wand"}"#));
+        let tag = stick.tag.unwrap();
+        assert_eq!(
+            nbt_at_path(&tag, "display.Lore").and_then(NbtValue::as_list),
+            Some(&[NbtValue::String("Old wood".to_owned())][..])
+        );
+    }
+
+    #[test]
+    fn set_nbt() {
+        let mut rng = StepRng::new(0, 1);
+        let mut sword = ItemStack::new(Item::IronSword, 1);
+        apply(
+            r#"{"function": "minecraft:set_nbt", "tag": "{Unbreakable:1b,Damage:10}"}"#,
+            &mut sword,
+            &mut rng,
+            &Conditions::default(),
+        );
+
+        assert_eq!(sword.damage, Some(10));
+        assert_eq!(
+            sword.tag.unwrap().get("Unbreakable"),
+            Some(&NbtValue::Byte(1))
+        );
+
+        let function: Function =
+            serde_json::from_str(r#"{"function": "minecraft:set_nbt", "tag": "{"}"#).unwrap();
+        let result = apply_functions(
+            iter::once(&function),
+            &mut sword,
+            &mut rng,
+            &Conditions::default(),
+        );
+        assert!(matches!(result, Err(SampleError::InvalidNbt(_, _))));
+    }
+
+    #[test]
+    fn unsupported_condition_fails() {
+        let mut rng = StepRng::new(0, 1);
+        let mut cobblestone = ItemStack::new(Item::Cobblestone, 1);
+        apply(
+            r#"{
+                "function": "minecraft:set_count",
+                "count": 5,
+                "conditions": [{"condition": "minecraft:weather_check", "raining": true}]
+            }"#,
+            &mut cobblestone,
+            &mut rng,
+            &Conditions::default(),
+        );
+        assert_eq!(cobblestone.amount, 1);
+    }
 }
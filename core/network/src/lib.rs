@@ -4,7 +4,9 @@ mod mctypes;
 mod packet;
 pub mod packets;
 
+pub use bytes_ext::TryGetError;
 pub use codec::{Error, MinecraftCodec};
+pub use mctypes::{McTypeRead, McTypeWrite};
 pub use packet::{Packet, PacketBuilder, PacketDirection, PacketId, PacketStage, PacketType};
 
 pub fn cast_packet<P: packet::Packet + 'static + Send>(packet: Box<dyn Packet>) -> P {
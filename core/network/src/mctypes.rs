@@ -20,6 +20,10 @@ pub trait McTypeWrite {
     ///
     /// Returns the number of bytes used to encode this integer.
     fn push_var_int(&mut self, x: i32) -> usize;
+    /// Writes a `VarLong` to the object.
+    ///
+    /// Returns the number of bytes used to encode this integer.
+    fn push_var_long(&mut self, x: i64) -> usize;
     /// Writes a string to the object. This method
     /// will first write the length of the string in bytes
     /// encodes as a `VarInt` and will then write
@@ -77,6 +81,25 @@ impl McTypeWrite for BytesMut {
         bytes_written
     }
 
+    fn push_var_long(&mut self, x: i64) -> usize {
+        let mut x = x as u64;
+        let mut bytes_written = 0;
+        loop {
+            let mut temp = (x & 0b0111_1111) as u8;
+            x >>= 7;
+            if x != 0 {
+                temp |= 0b1000_0000;
+            }
+            self.push_u8(temp);
+            bytes_written += 1;
+            if x == 0 {
+                break;
+            }
+        }
+
+        bytes_written
+    }
+
     /// Writes a string to the object. This method
     /// will first write the length of the string in bytes
     /// encodes as a `VarInt` and will then write
@@ -223,11 +246,7 @@ impl<B: Buf + Read> McTypeRead for B {
         let amount = self.try_get_i8()? as u8;
         let nbt: Option<ItemNbt> = self.try_get_nbt().ok();
 
-        Ok(Some(ItemStack {
-            ty,
-            amount,
-            damage: nbt.map(|t| t.damage).flatten(),
-        }))
+        Ok(Some(ItemNbt::item_stack(&nbt, ty, amount)))
     }
 }
 
@@ -393,4 +412,19 @@ mod tests {
         buf.extend_from_slice(&[0xff, 0x01]);
         assert_eq!(Cursor::new(&buf).try_get_var_int(), Ok(255));
     }
+
+    #[test]
+    fn test_write_var_long() {
+        // Examples from wiki.vg
+        let mut buf = BytesMut::new();
+        assert_eq!(buf.push_var_long(2_147_483_648), 5);
+        assert_eq!(&buf[..], &[0x80, 0x80, 0x80, 0x80, 0x08]);
+
+        let mut buf = BytesMut::new();
+        assert_eq!(buf.push_var_long(-1), 10);
+        assert_eq!(
+            &buf[..],
+            &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+        );
+    }
 }
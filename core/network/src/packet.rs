@@ -485,6 +485,10 @@ static PACKET_ID_MAPPINGS: Lazy<AHashMap<PacketId, PacketType>> = Lazy::new(|| {
         PacketType::BlockAction,
     );
 
+    m.insert(
+        PacketId(0x11, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::DeclareCommands,
+    );
     m.insert(
         PacketId(0x12, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::ConfirmTransactionClientbound,
@@ -547,6 +551,11 @@ static PACKET_ID_MAPPINGS: Lazy<AHashMap<PacketId, PacketType>> = Lazy::new(|| {
         PacketType::BlockChange,
     );
 
+    m.insert(
+        PacketId(0x0C, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::BossBar,
+    );
+
     m.insert(
         PacketId(0x20, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::ChangeGameState,
@@ -597,6 +606,11 @@ static PACKET_ID_MAPPINGS: Lazy<AHashMap<PacketId, PacketType>> = Lazy::new(|| {
         PacketType::PlayerPositionAndLookClientbound,
     );
 
+    m.insert(
+        PacketId(0x34, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::UnlockRecipes,
+    );
+
     m.insert(
         PacketId(0x35, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::DestroyEntities,
@@ -617,16 +631,31 @@ static PACKET_ID_MAPPINGS: Lazy<AHashMap<PacketId, PacketType>> = Lazy::new(|| {
         PacketType::EntityHeadLook,
     );
 
+    m.insert(
+        PacketId(0x3B, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::WorldBorder,
+    );
+
     m.insert(
         PacketId(0x3D, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::HeldItemChangeClientbound,
     );
 
+    m.insert(
+        PacketId(0x3E, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::DisplayScoreboard,
+    );
+
     m.insert(
         PacketId(0x3F, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::EntityMetadata,
     );
 
+    m.insert(
+        PacketId(0x40, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::SetExperience,
+    );
+
     m.insert(
         PacketId(0x41, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::EntityVelocity,
@@ -642,6 +671,21 @@ static PACKET_ID_MAPPINGS: Lazy<AHashMap<PacketId, PacketType>> = Lazy::new(|| {
         PacketType::UpdateHealth,
     );
 
+    m.insert(
+        PacketId(0x45, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::ScoreboardObjective,
+    );
+
+    m.insert(
+        PacketId(0x47, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::Teams,
+    );
+
+    m.insert(
+        PacketId(0x48, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::UpdateScore,
+    );
+
     m.insert(
         PacketId(0x49, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::SpawnPosition,
@@ -662,6 +706,16 @@ static PACKET_ID_MAPPINGS: Lazy<AHashMap<PacketId, PacketType>> = Lazy::new(|| {
         PacketType::EntityTeleport,
     );
 
+    m.insert(
+        PacketId(0x53, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::EntityEffect,
+    );
+
+    m.insert(
+        PacketId(0x54, PacketDirection::Clientbound, PacketStage::Play),
+        PacketType::DeclareRecipes,
+    );
+
     m.insert(
         PacketId(0x55, PacketDirection::Clientbound, PacketStage::Play),
         PacketType::Tags,
@@ -60,6 +60,10 @@ pub static IMPL_MAP: Lazy<AHashMap<PacketType, PacketBuilder>> = Lazy::new(|| {
         PacketType::EncryptionResponse,
         PacketBuilder::with(|| Box::new(EncryptionResponse::default())),
     );
+    m.insert(
+        PacketType::LoginPluginResponse,
+        PacketBuilder::with(|| Box::new(LoginPluginResponse::default())),
+    );
 
     m.insert(
         PacketType::Request,
@@ -265,6 +269,7 @@ pub static IMPL_MAP: Lazy<AHashMap<PacketType, PacketBuilder>> = Lazy::new(|| {
         EncryptionRequest,
         LoginSuccess,
         SetCompression,
+        LoginPluginRequest,
         SpawnObject,
         SpawnExperienceOrb,
         SpawnGlobalEntity,
@@ -287,6 +292,7 @@ pub static IMPL_MAP: Lazy<AHashMap<PacketType, PacketBuilder>> = Lazy::new(|| {
         SetCooldown,
         PluginMessageClientbound,
         NamedSoundEffect,
+        DeclareCommands,
         DisconnectPlay,
         EntityStatus,
         NBTQueryResponse,
@@ -308,19 +314,28 @@ pub static IMPL_MAP: Lazy<AHashMap<PacketType, PacketBuilder>> = Lazy::new(|| {
         PlayerInfo,
         PlayerPositionAndLookClientbound,
         UseBed,
+        UnlockRecipes,
         DestroyEntities,
         RemoveEntityEffect,
         ResourcePackSend,
         Respawn,
         EntityHeadLook,
+        WorldBorder,
         EntityVelocity,
         EntityEquipment,
         HeldItemChangeClientbound,
+        DisplayScoreboard,
+        SetExperience,
         UpdateHealth,
+        ScoreboardObjective,
+        Teams,
+        UpdateScore,
         SpawnPosition,
         TimeUpdate,
         CollectItem,
         EntityTeleport,
+        EntityEffect,
+        DeclareRecipes,
         Tags,
         Response,
         Pong,
@@ -1135,6 +1150,48 @@ impl Packet for PlayerBlockPlacement {
     }
 }
 
+#[derive(Default, AsAny, Clone)]
+pub struct LoginPluginResponse {
+    pub message_id: VarInt,
+    pub successful: bool,
+    /// Only present if `successful` is true.
+    pub data: Vec<u8>,
+}
+
+impl Packet for LoginPluginResponse {
+    fn read_from(&mut self, buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        self.message_id = buf.try_get_var_int()?;
+        self.successful = buf.try_get_bool()?;
+
+        let start = buf.position() as usize;
+        self.data = buf.get_ref()[start..].to_vec();
+        buf.advance(buf.remaining());
+
+        Ok(())
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_var_int(self.message_id);
+        buf.push_bool(self.successful);
+        buf.extend_from_slice(&self.data);
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::LoginPluginResponse
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::LoginPluginResponse
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
 #[derive(Default, AsAny, Packet, Clone)]
 pub struct UseItem {
     pub hand: VarInt,
@@ -1207,6 +1264,47 @@ pub struct SetCompression {
     pub threshold: VarInt,
 }
 
+#[derive(Default, AsAny, Clone)]
+pub struct LoginPluginRequest {
+    pub message_id: VarInt,
+    pub channel: String,
+    pub data: Vec<u8>,
+}
+
+impl Packet for LoginPluginRequest {
+    fn read_from(&mut self, buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        self.message_id = buf.try_get_var_int()?;
+        self.channel = buf.try_get_string()?;
+
+        let start = buf.position() as usize;
+        self.data = buf.get_ref()[start..].to_vec();
+        buf.advance(buf.remaining());
+
+        Ok(())
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_var_int(self.message_id);
+        buf.push_string(&self.channel);
+        buf.extend_from_slice(&self.data);
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::LoginPluginRequest
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::LoginPluginRequest
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
 #[derive(Default, AsAny, Packet, Clone)]
 pub struct Response {
     pub json_response: String,
@@ -1498,7 +1596,7 @@ impl BossBarAction {
     }
 }
 
-#[derive(Clone, Copy, Debug, FromPrimitive, ToPrimitive)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
 pub enum BossBarColor {
     Pink,
     Blue,
@@ -1515,7 +1613,7 @@ impl Default for BossBarColor {
     }
 }
 
-#[derive(Clone, Copy, Debug, FromPrimitive, ToPrimitive)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
 pub enum BossBarDivision {
     NoDivision,
     SixNotches,
@@ -1543,7 +1641,123 @@ pub struct ChatMessageClientbound {
 
 // TODO MultiBlockChange
 // TODO TabCompleteClientbound
-// TODO DeclareCommands
+
+#[derive(Default, AsAny, Clone)]
+pub struct DeclareCommands {
+    pub nodes: Vec<CommandNode>,
+    pub root_index: VarInt,
+}
+
+impl Packet for DeclareCommands {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_var_int(self.nodes.len() as i32);
+
+        for node in &self.nodes {
+            let mut flags = node.kind.id();
+            if node.executable {
+                flags |= 0x04;
+            }
+            if node.redirect.is_some() {
+                flags |= 0x08;
+            }
+            if let CommandNodeKind::Argument {
+                suggestions: Some(_),
+                ..
+            } = &node.kind
+            {
+                flags |= 0x10;
+            }
+            buf.push_u8(flags);
+
+            buf.push_var_int(node.children.len() as i32);
+            for child in &node.children {
+                buf.push_var_int(*child);
+            }
+
+            if let Some(redirect) = node.redirect {
+                buf.push_var_int(redirect);
+            }
+
+            match &node.kind {
+                CommandNodeKind::Root => (),
+                CommandNodeKind::Literal { name } => {
+                    buf.push_string(name);
+                }
+                CommandNodeKind::Argument {
+                    name,
+                    parser,
+                    properties,
+                    suggestions,
+                } => {
+                    buf.push_string(name);
+                    buf.push_string(parser);
+                    buf.put_slice(properties);
+                    if let Some(suggestions) = suggestions {
+                        buf.push_string(suggestions);
+                    }
+                }
+            }
+        }
+
+        buf.push_var_int(self.root_index);
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::DeclareCommands
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::DeclareCommands
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
+/// A node in the command graph sent in `DeclareCommands`.
+#[derive(Clone, Debug)]
+pub struct CommandNode {
+    pub kind: CommandNodeKind,
+    pub executable: bool,
+    /// Indices of the child nodes in `DeclareCommands::nodes`.
+    pub children: Vec<VarInt>,
+    pub redirect: Option<VarInt>,
+}
+
+#[derive(Clone, Debug)]
+pub enum CommandNodeKind {
+    Root,
+    Literal {
+        name: String,
+    },
+    Argument {
+        name: String,
+        /// Parser identifier, e.g. `brigadier:string`.
+        parser: String,
+        /// Parser properties, already encoded in the
+        /// format expected for `parser`.
+        properties: Vec<u8>,
+        suggestions: Option<String>,
+    },
+}
+
+impl CommandNodeKind {
+    fn id(&self) -> u8 {
+        match self {
+            CommandNodeKind::Root => 0,
+            CommandNodeKind::Literal { .. } => 1,
+            CommandNodeKind::Argument { .. } => 2,
+        }
+    }
+}
 
 #[derive(Default, AsAny, Packet, Clone)]
 pub struct ConfirmTransactionClientbound {
@@ -2274,7 +2488,61 @@ pub struct UseBed {
     pub location: BlockPosition,
 }
 
-// TODO Unlock Recipes
+#[derive(Default, AsAny, Clone)]
+pub struct UnlockRecipes {
+    /// 0 = init, 1 = add, 2 = remove.
+    pub action: VarInt,
+    pub crafting_book_open: bool,
+    pub crafting_filter_active: bool,
+    pub smelting_book_open: bool,
+    pub smelting_filter_active: bool,
+    /// IDs of the recipes to unlock or lock.
+    pub recipes: Vec<String>,
+    /// For the init action, IDs of the recipes which are
+    /// highlighted as newly unlocked.
+    pub displayed_recipes: Vec<String>,
+}
+
+impl Packet for UnlockRecipes {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_var_int(self.action);
+        buf.push_bool(self.crafting_book_open);
+        buf.push_bool(self.crafting_filter_active);
+        buf.push_bool(self.smelting_book_open);
+        buf.push_bool(self.smelting_filter_active);
+
+        buf.push_var_int(self.recipes.len() as i32);
+        for recipe in &self.recipes {
+            buf.push_string(recipe);
+        }
+
+        if self.action == 0 {
+            buf.push_var_int(self.displayed_recipes.len() as i32);
+            for recipe in &self.displayed_recipes {
+                buf.push_string(recipe);
+            }
+        }
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::UnlockRecipes
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::UnlockRecipes
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
 
 #[derive(Default, AsAny, Clone)]
 pub struct DestroyEntities {
@@ -2390,6 +2658,24 @@ pub struct HeldItemChangeClientbound {
     pub slot: i8,
 }
 
+#[derive(Default, AsAny, Packet, Clone)]
+pub struct DisplayScoreboard {
+    /// 0 = list, 1 = sidebar, 2 = below name,
+    /// 3-18 = team-specific sidebars
+    pub position: i8,
+    /// The objective to display, or an empty
+    /// string to clear the slot.
+    pub score_name: String,
+}
+
+#[derive(Default, AsAny, Packet, Clone)]
+pub struct SetExperience {
+    /// Progress towards the next level, between 0 and 1.
+    pub experience_bar: f32,
+    pub level: VarInt,
+    pub total_experience: VarInt,
+}
+
 #[derive(Default, AsAny, Packet, Clone)]
 pub struct UpdateHealth {
     pub health: f32,
@@ -2397,8 +2683,383 @@ pub struct UpdateHealth {
     pub saturation: f32,
 }
 
+#[derive(Default, AsAny, Clone)]
+pub struct ScoreboardObjective {
+    pub objective_name: String,
+    pub action: ScoreboardObjectiveAction,
+}
+
+impl Packet for ScoreboardObjective {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_string(&self.objective_name);
+        buf.push_i8(self.action.id());
+
+        match &self.action {
+            ScoreboardObjectiveAction::Create(display_name, render_type)
+            | ScoreboardObjectiveAction::Update(display_name, render_type) => {
+                buf.push_string(display_name);
+                buf.push_var_int(ToPrimitive::to_i32(render_type).unwrap());
+            }
+            ScoreboardObjectiveAction::Remove => (),
+        }
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::ScoreboardObjective
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::ScoreboardObjective
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ScoreboardObjectiveAction {
+    /// Creates an objective with the given display name (JSON text).
+    Create(String, ObjectiveRenderType),
+    Remove,
+    /// Updates the display name and render type of an objective.
+    Update(String, ObjectiveRenderType),
+}
+
+impl Default for ScoreboardObjectiveAction {
+    fn default() -> Self {
+        ScoreboardObjectiveAction::Remove
+    }
+}
+
+impl ScoreboardObjectiveAction {
+    fn id(&self) -> i8 {
+        match &self {
+            ScoreboardObjectiveAction::Create(_, _) => 0,
+            ScoreboardObjectiveAction::Remove => 1,
+            ScoreboardObjectiveAction::Update(_, _) => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive, ToPrimitive)]
+pub enum ObjectiveRenderType {
+    Integer,
+    Hearts,
+}
+
+impl Default for ObjectiveRenderType {
+    fn default() -> Self {
+        ObjectiveRenderType::Integer
+    }
+}
+
+#[derive(Default, AsAny, Clone)]
+pub struct Teams {
+    pub team_name: String,
+    pub action: TeamsAction,
+}
+
+impl Packet for Teams {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_string(&self.team_name);
+        buf.push_i8(self.action.id());
+
+        match &self.action {
+            TeamsAction::Create(info, entities) => {
+                info.write_to(buf);
+                write_team_entities(buf, entities);
+            }
+            TeamsAction::Remove => (),
+            TeamsAction::UpdateInfo(info) => info.write_to(buf),
+            TeamsAction::AddEntities(entities) | TeamsAction::RemoveEntities(entities) => {
+                write_team_entities(buf, entities)
+            }
+        }
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::Teams
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::Teams
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
+fn write_team_entities(buf: &mut BytesMut, entities: &[String]) {
+    buf.push_var_int(entities.len() as i32);
+    for entity in entities {
+        buf.push_string(entity);
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TeamsAction {
+    /// Creates a team with the given info and members.
+    Create(TeamInfo, Vec<String>),
+    Remove,
+    UpdateInfo(TeamInfo),
+    AddEntities(Vec<String>),
+    RemoveEntities(Vec<String>),
+}
+
+impl Default for TeamsAction {
+    fn default() -> Self {
+        TeamsAction::Remove
+    }
+}
+
+impl TeamsAction {
+    fn id(&self) -> i8 {
+        match &self {
+            TeamsAction::Create(_, _) => 0,
+            TeamsAction::Remove => 1,
+            TeamsAction::UpdateInfo(_) => 2,
+            TeamsAction::AddEntities(_) => 3,
+            TeamsAction::RemoveEntities(_) => 4,
+        }
+    }
+}
+
+/// The properties of a team sent in the `Teams` packet.
+#[derive(Clone, Debug, Default)]
+pub struct TeamInfo {
+    /// JSON text.
+    pub display_name: String,
+    /// Bit mask. 0x01 = allow friendly fire,
+    /// 0x02 = can see invisible players on same team.
+    pub friendly_flags: i8,
+    /// One of `always`, `hideForOtherTeams`, `hideForOwnTeam` or `never`.
+    pub name_tag_visibility: String,
+    /// One of `always`, `pushOtherTeams`, `pushOwnTeam` or `never`.
+    pub collision_rule: String,
+    /// The formatting code of the team color, or 21 for none.
+    pub color: VarInt,
+    /// JSON text.
+    pub prefix: String,
+    /// JSON text.
+    pub suffix: String,
+}
+
+impl TeamInfo {
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_string(&self.display_name);
+        buf.push_i8(self.friendly_flags);
+        buf.push_string(&self.name_tag_visibility);
+        buf.push_string(&self.collision_rule);
+        buf.push_var_int(self.color);
+        buf.push_string(&self.prefix);
+        buf.push_string(&self.suffix);
+    }
+}
+
+#[derive(Default, AsAny, Clone)]
+pub struct UpdateScore {
+    /// The name of the score holder, i.e. a player's
+    /// username or an entity's UUID.
+    pub entity_name: String,
+    pub objective_name: String,
+    pub action: UpdateScoreAction,
+}
+
+impl Packet for UpdateScore {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_string(&self.entity_name);
+        match self.action {
+            UpdateScoreAction::Update(value) => {
+                buf.push_i8(0);
+                buf.push_string(&self.objective_name);
+                buf.push_var_int(value);
+            }
+            UpdateScoreAction::Remove => {
+                buf.push_i8(1);
+                buf.push_string(&self.objective_name);
+            }
+        }
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::UpdateScore
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::UpdateScore
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpdateScoreAction {
+    /// Creates or updates the score with the given value.
+    Update(VarInt),
+    /// Removes the score. An empty objective name
+    /// removes the holder's scores in all objectives.
+    Remove,
+}
+
+impl Default for UpdateScoreAction {
+    fn default() -> Self {
+        UpdateScoreAction::Remove
+    }
+}
+
 // TODO Select Advancement Tab
-// TODO World Border
+#[derive(Default, AsAny, Clone)]
+pub struct WorldBorder {
+    pub action: WorldBorderAction,
+}
+
+impl Packet for WorldBorder {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_var_int(self.action.id());
+
+        match self.action {
+            WorldBorderAction::SetSize { diameter } => {
+                buf.push_f64(diameter);
+            }
+            WorldBorderAction::LerpSize {
+                old_diameter,
+                new_diameter,
+                speed,
+            } => {
+                buf.push_f64(old_diameter);
+                buf.push_f64(new_diameter);
+                buf.push_var_long(speed);
+            }
+            WorldBorderAction::SetCenter { x, z } => {
+                buf.push_f64(x);
+                buf.push_f64(z);
+            }
+            WorldBorderAction::Initialize {
+                x,
+                z,
+                old_diameter,
+                new_diameter,
+                speed,
+                portal_teleport_boundary,
+                warning_time,
+                warning_blocks,
+            } => {
+                buf.push_f64(x);
+                buf.push_f64(z);
+                buf.push_f64(old_diameter);
+                buf.push_f64(new_diameter);
+                buf.push_var_long(speed);
+                buf.push_var_int(portal_teleport_boundary);
+                buf.push_var_int(warning_time);
+                buf.push_var_int(warning_blocks);
+            }
+            WorldBorderAction::SetWarningTime { warning_time } => {
+                buf.push_var_int(warning_time);
+            }
+            WorldBorderAction::SetWarningBlocks { warning_blocks } => {
+                buf.push_var_int(warning_blocks);
+            }
+        }
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::WorldBorder
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::WorldBorder
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
+/// An action of the World Border packet. Diameters are
+/// in blocks and `speed` is the time, in milliseconds,
+/// until the border reaches its new diameter.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WorldBorderAction {
+    SetSize {
+        diameter: f64,
+    },
+    LerpSize {
+        old_diameter: f64,
+        new_diameter: f64,
+        speed: i64,
+    },
+    SetCenter {
+        x: f64,
+        z: f64,
+    },
+    Initialize {
+        x: f64,
+        z: f64,
+        old_diameter: f64,
+        new_diameter: f64,
+        speed: i64,
+        portal_teleport_boundary: i32,
+        warning_time: i32,
+        warning_blocks: i32,
+    },
+    SetWarningTime {
+        warning_time: i32,
+    },
+    SetWarningBlocks {
+        warning_blocks: i32,
+    },
+}
+
+impl Default for WorldBorderAction {
+    fn default() -> Self {
+        WorldBorderAction::SetSize { diameter: 0.0 }
+    }
+}
+
+impl WorldBorderAction {
+    fn id(&self) -> i32 {
+        match self {
+            WorldBorderAction::SetSize { .. } => 0,
+            WorldBorderAction::LerpSize { .. } => 1,
+            WorldBorderAction::SetCenter { .. } => 2,
+            WorldBorderAction::Initialize { .. } => 3,
+            WorldBorderAction::SetWarningTime { .. } => 4,
+            WorldBorderAction::SetWarningBlocks { .. } => 5,
+        }
+    }
+}
 
 #[derive(Default, AsAny, Packet, Clone)]
 pub struct SpawnPosition {
@@ -2429,6 +3090,148 @@ pub struct EntityTeleport {
     pub on_ground: bool,
 }
 
+#[derive(Default, AsAny, Packet, Clone)]
+pub struct EntityEffect {
+    pub entity_id: VarInt,
+    pub effect_id: i8,
+    pub amplifier: i8,
+    /// Duration in ticks.
+    pub duration: VarInt,
+    /// Bit field: 0x01 = ambient, 0x02 = show particles,
+    /// 0x04 = show icon.
+    pub flags: i8,
+}
+
+#[derive(Default, AsAny, Clone)]
+pub struct DeclareRecipes {
+    pub recipes: Vec<DeclaredRecipe>,
+}
+
+impl Packet for DeclareRecipes {
+    fn read_from(&mut self, _buf: &mut Cursor<&[u8]>) -> anyhow::Result<()> {
+        unimplemented!()
+    }
+
+    fn write_to(&self, buf: &mut BytesMut) {
+        buf.push_var_int(self.recipes.len() as i32);
+
+        for recipe in &self.recipes {
+            buf.push_string(&recipe.id);
+            buf.push_string(recipe.kind.ty());
+
+            match &recipe.kind {
+                DeclaredRecipeKind::Shapeless {
+                    group,
+                    ingredients,
+                    result,
+                } => {
+                    buf.push_string(group);
+                    buf.push_var_int(ingredients.len() as i32);
+                    for ingredient in ingredients {
+                        push_ingredient(buf, ingredient);
+                    }
+                    buf.push_slot(*result);
+                }
+                DeclaredRecipeKind::Shaped {
+                    width,
+                    height,
+                    group,
+                    ingredients,
+                    result,
+                } => {
+                    buf.push_var_int(*width);
+                    buf.push_var_int(*height);
+                    buf.push_string(group);
+                    for ingredient in ingredients {
+                        push_ingredient(buf, ingredient);
+                    }
+                    buf.push_slot(*result);
+                }
+                DeclaredRecipeKind::Smelting {
+                    group,
+                    ingredient,
+                    result,
+                    experience,
+                    cooking_time,
+                } => {
+                    buf.push_string(group);
+                    push_ingredient(buf, ingredient);
+                    buf.push_slot(*result);
+                    buf.push_f32(*experience);
+                    buf.push_var_int(*cooking_time);
+                }
+            }
+        }
+    }
+
+    fn ty(&self) -> PacketType {
+        PacketType::DeclareRecipes
+    }
+
+    fn ty_sized() -> PacketType
+    where
+        Self: Sized,
+    {
+        PacketType::DeclareRecipes
+    }
+
+    fn box_clone(&self) -> Box<dyn Packet> {
+        box_clone_impl!(self);
+    }
+}
+
+/// Writes an ingredient: the list of items it accepts.
+fn push_ingredient(buf: &mut BytesMut, ingredient: &[Slot]) {
+    buf.push_var_int(ingredient.len() as i32);
+    for slot in ingredient {
+        buf.push_slot(*slot);
+    }
+}
+
+/// A recipe sent in `DeclareRecipes`.
+#[derive(Clone, Debug)]
+pub struct DeclaredRecipe {
+    /// Namespaced ID of the recipe.
+    pub id: String,
+    pub kind: DeclaredRecipeKind,
+}
+
+/// The type and data of a `DeclaredRecipe`. Each ingredient
+/// is the list of items it accepts; an empty list is an empty slot.
+#[derive(Clone, Debug)]
+pub enum DeclaredRecipeKind {
+    Shapeless {
+        group: String,
+        ingredients: Vec<Vec<Slot>>,
+        result: Slot,
+    },
+    Shaped {
+        width: VarInt,
+        height: VarInt,
+        group: String,
+        /// `width * height` ingredients in row-major order.
+        ingredients: Vec<Vec<Slot>>,
+        result: Slot,
+    },
+    Smelting {
+        group: String,
+        ingredient: Vec<Slot>,
+        result: Slot,
+        experience: f32,
+        cooking_time: VarInt,
+    },
+}
+
+impl DeclaredRecipeKind {
+    fn ty(&self) -> &'static str {
+        match self {
+            DeclaredRecipeKind::Shapeless { .. } => "crafting_shapeless",
+            DeclaredRecipeKind::Shaped { .. } => "crafting_shaped",
+            DeclaredRecipeKind::Smelting { .. } => "smelting",
+        }
+    }
+}
+
 #[derive(Default, AsAny, Clone)]
 pub struct Tags {
     pub block_tags: Vec<(String, Vec<VarInt>)>,
@@ -0,0 +1,145 @@
+use anyhow::Context;
+use feather_recipes_model::{Ingredient, IngredientChoice, Recipe, RecipeSet};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::{env, fs::File};
+use walkdir::WalkDir;
+
+fn main() {
+    if let Err(e) = run() {
+        panic!("{:?}", e);
+    }
+
+    println!(
+        "cargo:rerun-if-changed={}",
+        concat!(env!("CARGO_MANIFEST_DIR"), "/build.rs")
+    );
+}
+
+/// An item tag file as found in data packs.
+#[derive(Deserialize)]
+struct TagFile {
+    values: Vec<String>,
+}
+
+fn run() -> anyhow::Result<()> {
+    // Load in all recipes and item tags, then dump them into
+    // ${OUT_DIR}/dump.json for inclusion in `feather-recipes`.
+    let base = format!(
+        "{}/minecraft-1.15/data/minecraft",
+        feather_data::minecraft::PATH
+    );
+
+    let raw_tags =
+        read_dir::<TagFile>(&format!("{}/tags/items", base)).context("failed to load item tags")?;
+    let raw_tags: HashMap<String, Vec<String>> = raw_tags
+        .into_iter()
+        .map(|(name, tag)| (format!("minecraft:{}", name), tag.values))
+        .collect();
+    let item_tags = raw_tags
+        .keys()
+        .map(|name| (name.clone(), resolve_tag(&raw_tags, name, 0)))
+        .collect::<HashMap<_, _>>();
+
+    let mut recipes = read_dir::<Recipe>(&format!("{}/recipes", base))
+        .context("failed to load recipes")?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+    for recipe in recipes.values_mut() {
+        resolve_recipe_tags(recipe, &item_tags);
+    }
+
+    let set = RecipeSet { recipes, item_tags };
+
+    // Write the recipe set out to the dump
+    let dump_path = format!("{}/dump.json", env::var("OUT_DIR")?);
+    let mut dump = File::create(&dump_path)?;
+    let vec = serde_json::to_vec(&set).unwrap();
+    dump.write_all(vec.as_slice())?;
+
+    Ok(())
+}
+
+/// Parses every JSON file in a directory, keyed by its path relative
+/// to the directory without the `.json` suffix.
+fn read_dir<T: for<'de> Deserialize<'de>>(input: &str) -> anyhow::Result<Vec<(String, T)>> {
+    let mut values = Vec::new();
+
+    for entry in WalkDir::new(input) {
+        let entry = entry.context("entry access failed")?;
+
+        if entry.metadata()?.is_dir() {
+            continue;
+        }
+
+        let relative_path = entry
+            .path()
+            .strip_prefix(Path::new(input))
+            .with_context(|| format!("failed to strip prefix for `{}`", entry.path().display()))?
+            .to_str()
+            .context("path contains invalid UTF-8")?;
+        // strip .json suffix and replace \\ with / for windows
+        let relative_path = relative_path[..relative_path.len() - 5].replace("\\", "/");
+
+        let mut s = String::new();
+        let mut file = File::open(entry.path())?;
+        file.read_to_string(&mut s)?;
+
+        let value = serde_json::from_str::<T>(&s)
+            .with_context(|| format!("failed to parse `{}`", relative_path))?;
+        values.push((relative_path, value));
+    }
+
+    Ok(values)
+}
+
+/// Flattens a tag into the items it contains, following
+/// references to other tags.
+fn resolve_tag(tags: &HashMap<String, Vec<String>>, name: &str, depth: usize) -> Vec<String> {
+    // Vanilla tags nest at most a few levels; this guards against cycles.
+    if depth > 16 {
+        return vec![];
+    }
+
+    let mut items = Vec::new();
+    for value in tags.get(name).map(Vec::as_slice).unwrap_or_default() {
+        if let Some(nested) = value.strip_prefix('#') {
+            items.extend(resolve_tag(tags, nested, depth + 1));
+        } else if !items.contains(value) {
+            items.push(value.clone());
+        }
+    }
+    items
+}
+
+/// Replaces tag ingredients in a recipe with the items in the tag.
+fn resolve_recipe_tags(recipe: &mut Recipe, tags: &HashMap<String, Vec<String>>) {
+    let ingredients: Vec<&mut Ingredient> = match recipe {
+        Recipe::CraftingShaped(recipe) => recipe.key.values_mut().collect(),
+        Recipe::CraftingShapeless(recipe) => recipe.ingredients.iter_mut().collect(),
+        Recipe::Smelting(recipe)
+        | Recipe::Blasting(recipe)
+        | Recipe::Smoking(recipe)
+        | Recipe::CampfireCooking(recipe) => vec![&mut recipe.ingredient],
+        Recipe::Other => vec![],
+    };
+
+    for ingredient in ingredients {
+        let mut choices = Vec::new();
+        for choice in ingredient.choices() {
+            match choice {
+                IngredientChoice::Item { .. } => choices.push(choice.clone()),
+                IngredientChoice::Tag { tag } => choices.extend(
+                    tags.get(tag)
+                        .map(Vec::as_slice)
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|item| IngredientChoice::Item { item: item.clone() }),
+                ),
+            }
+        }
+        *ingredient = Ingredient::AnyOf(choices);
+    }
+}
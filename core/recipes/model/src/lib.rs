@@ -0,0 +1,118 @@
+//! Defines a Serde model for vanilla recipes and item tags.
+//! Used as an intermediate representation of the recipe data.
+//!
+//! The build script for `feather-recipes` requires this functionality,
+//! which is why it has been split into another crate.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The set of all loaded recipes, along with the item
+/// tags needed to interpret them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecipeSet {
+    /// Recipes keyed by their ID, e.g. `iron_ingot_from_smelting`.
+    pub recipes: HashMap<String, Recipe>,
+    /// Item tags keyed by name without the leading `#`,
+    /// e.g. `minecraft:logs`. Nested tags are already
+    /// resolved, so each tag is a flat list of item identifiers.
+    pub item_tags: HashMap<String, Vec<String>>,
+}
+
+/// See https://minecraft.gamepedia.com/Recipe#JSON_format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Recipe {
+    #[serde(rename = "minecraft:crafting_shaped", alias = "crafting_shaped")]
+    CraftingShaped(ShapedRecipe),
+    #[serde(rename = "minecraft:crafting_shapeless", alias = "crafting_shapeless")]
+    CraftingShapeless(ShapelessRecipe),
+    #[serde(rename = "minecraft:smelting", alias = "smelting")]
+    Smelting(CookingRecipe),
+    #[serde(rename = "minecraft:blasting")]
+    Blasting(CookingRecipe),
+    #[serde(rename = "minecraft:smoking")]
+    Smoking(CookingRecipe),
+    #[serde(rename = "minecraft:campfire_cooking")]
+    CampfireCooking(CookingRecipe),
+    /// Special recipes implemented in code, such as
+    /// armor dyeing or map cloning, and stonecutting.
+    #[serde(other)]
+    Other,
+}
+
+/// A crafting recipe whose ingredients must be placed in a pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShapedRecipe {
+    #[serde(default)]
+    pub group: Option<String>,
+    /// Rows of the pattern, top to bottom. Each character is
+    /// a key into `key`; spaces are empty slots.
+    pub pattern: Vec<String>,
+    pub key: HashMap<char, Ingredient>,
+    pub result: CraftingResult,
+}
+
+/// A crafting recipe whose ingredients may be placed anywhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShapelessRecipe {
+    #[serde(default)]
+    pub group: Option<String>,
+    pub ingredients: Vec<Ingredient>,
+    pub result: CraftingResult,
+}
+
+/// A recipe for a furnace, blast furnace, smoker or campfire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CookingRecipe {
+    #[serde(default)]
+    pub group: Option<String>,
+    pub ingredient: Ingredient,
+    /// Identifier of the resulting item.
+    pub result: String,
+    #[serde(default)]
+    pub experience: f32,
+    /// Cooking time in ticks. Defaults depend on the recipe type.
+    #[serde(rename = "cookingtime", default)]
+    pub cooking_time: Option<u32>,
+}
+
+/// The output of a crafting recipe.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CraftingResult {
+    pub item: String,
+    #[serde(default = "one")]
+    pub count: u8,
+}
+
+fn one() -> u8 {
+    1
+}
+
+/// An item slot in a recipe, which accepts any
+/// of a set of items.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Ingredient {
+    Single(IngredientChoice),
+    AnyOf(Vec<IngredientChoice>),
+}
+
+impl Ingredient {
+    /// Returns the choices accepted by this ingredient.
+    pub fn choices(&self) -> &[IngredientChoice] {
+        match self {
+            Ingredient::Single(choice) => std::slice::from_ref(choice),
+            Ingredient::AnyOf(choices) => choices.as_slice(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IngredientChoice {
+    /// A single item, by identifier.
+    Item { item: String },
+    /// Any item in the item tag, by name.
+    Tag { tag: String },
+}
@@ -0,0 +1,292 @@
+//! Matching of items in a crafting grid against shaped
+//! and shapeless crafting recipes.
+
+use crate::{ingredient_items, model, SET};
+use feather_items::{Item, ItemStack};
+use model::{CraftingResult, Recipe};
+use once_cell::sync::Lazy;
+
+/// All crafting recipes whose items exist in this version,
+/// sorted by ID so that matching is deterministic.
+static CRAFTING: Lazy<Vec<CraftingRecipe>> = Lazy::new(|| {
+    let mut recipes: Vec<CraftingRecipe> = SET
+        .recipes
+        .iter()
+        .filter_map(|(id, recipe)| CraftingRecipe::from_model(id, recipe))
+        .collect();
+    recipes.sort_by(|a, b| a.id.cmp(&b.id));
+    recipes
+});
+
+/// A shaped or shapeless crafting recipe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CraftingRecipe {
+    /// Namespaced ID of the recipe, e.g. `minecraft:oak_planks`.
+    pub id: String,
+    /// Recipes in the same group are shown together in the recipe book.
+    pub group: String,
+    pub shape: Shape,
+    pub result: ItemStack,
+}
+
+/// How the ingredients of a `CraftingRecipe` must be arranged.
+/// Each ingredient is the list of items it accepts.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Shape {
+    /// Ingredients must be placed in a pattern, which may
+    /// be mirrored horizontally. `ingredients` is in row-major
+    /// order; `None` entries must be left empty.
+    Shaped {
+        width: usize,
+        height: usize,
+        ingredients: Vec<Option<Vec<Item>>>,
+    },
+    /// Ingredients may be placed anywhere in the grid.
+    Shapeless { ingredients: Vec<Vec<Item>> },
+}
+
+impl CraftingRecipe {
+    fn from_model(id: &str, recipe: &Recipe) -> Option<Self> {
+        let (group, shape, result) = match recipe {
+            Recipe::CraftingShaped(recipe) => (&recipe.group, shaped(recipe)?, &recipe.result),
+            Recipe::CraftingShapeless(recipe) => {
+                let ingredients = recipe
+                    .ingredients
+                    .iter()
+                    .map(|ingredient| non_empty(ingredient_items(ingredient).collect()))
+                    .collect::<Option<Vec<_>>>()?;
+                (
+                    &recipe.group,
+                    Shape::Shapeless { ingredients },
+                    &recipe.result,
+                )
+            }
+            _ => return None,
+        };
+
+        Some(Self {
+            id: format!("minecraft:{}", id),
+            group: group.clone().unwrap_or_default(),
+            shape,
+            result: result_stack(result)?,
+        })
+    }
+
+    /// Determines whether the items in a crafting grid
+    /// with the given width match this recipe.
+    pub fn matches(&self, grid: &[Option<Item>], grid_width: usize) -> bool {
+        match &self.shape {
+            Shape::Shaped {
+                width,
+                height,
+                ingredients,
+            } => {
+                let (x, y, w, h) = match bounds(grid, grid_width) {
+                    Some(bounds) => bounds,
+                    None => return false,
+                };
+                if (w, h) != (*width, *height) {
+                    return false;
+                }
+
+                let matches_with = |mirrored: bool| {
+                    (0..h).all(|row| {
+                        (0..w).all(|column| {
+                            let recipe_column = if mirrored { w - 1 - column } else { column };
+                            let item = grid[(y + row) * grid_width + x + column];
+                            match (&ingredients[row * w + recipe_column], item) {
+                                (None, None) => true,
+                                (Some(accepted), Some(item)) => accepted.contains(&item),
+                                _ => false,
+                            }
+                        })
+                    })
+                };
+                matches_with(false) || matches_with(true)
+            }
+            Shape::Shapeless { ingredients } => {
+                let items: Vec<Item> = grid.iter().filter_map(|item| *item).collect();
+                items.len() == ingredients.len()
+                    && assign(&items, ingredients, &mut vec![false; ingredients.len()])
+            }
+        }
+    }
+}
+
+/// Returns all crafting recipes.
+pub fn crafting_recipes() -> &'static [CraftingRecipe] {
+    &CRAFTING
+}
+
+/// Finds the crafting recipe matching the items in a crafting grid.
+///
+/// `grid` is in row-major order and `grid_width` is
+/// 2 for a player's inventory or 3 for a crafting table.
+pub fn crafting_recipe(
+    grid: &[Option<Item>],
+    grid_width: usize,
+) -> Option<&'static CraftingRecipe> {
+    if grid.iter().all(Option::is_none) {
+        return None;
+    }
+    CRAFTING
+        .iter()
+        .find(|recipe| recipe.matches(grid, grid_width))
+}
+
+/// Returns the item left in a crafting grid slot after the given
+/// item is used in a recipe, e.g. an empty bucket for a milk bucket.
+pub fn crafting_remainder(item: Item) -> Option<Item> {
+    match item {
+        Item::MilkBucket | Item::WaterBucket | Item::LavaBucket => Some(Item::Bucket),
+        Item::DragonBreath => Some(Item::GlassBottle),
+        _ => None,
+    }
+}
+
+fn shaped(recipe: &model::ShapedRecipe) -> Option<Shape> {
+    let rows: Vec<Vec<char>> = recipe
+        .pattern
+        .iter()
+        .map(|row| row.chars().collect())
+        .collect();
+    let width = rows.iter().map(Vec::len).max()?;
+
+    // Vanilla trims empty rows and columns from the edges of patterns.
+    let used_columns: Vec<usize> = (0..width)
+        .filter(|column| {
+            rows.iter()
+                .any(|row| row.get(*column).map_or(false, |c| *c != ' '))
+        })
+        .collect();
+    let used_rows: Vec<&Vec<char>> = rows
+        .iter()
+        .filter(|row| row.iter().any(|c| *c != ' '))
+        .collect();
+    let (first, last) = (*used_columns.first()?, *used_columns.last()?);
+
+    let mut ingredients = Vec::new();
+    for row in &used_rows {
+        for column in first..=last {
+            let ingredient = match row.get(column).copied().unwrap_or(' ') {
+                ' ' => None,
+                key => Some(non_empty(
+                    ingredient_items(recipe.key.get(&key)?).collect(),
+                )?),
+            };
+            ingredients.push(ingredient);
+        }
+    }
+
+    Some(Shape::Shaped {
+        width: last - first + 1,
+        height: used_rows.len(),
+        ingredients,
+    })
+}
+
+fn result_stack(result: &CraftingResult) -> Option<ItemStack> {
+    Item::from_identifier(&result.item).map(|item| ItemStack::new(item, result.count))
+}
+
+/// Ingredients whose items all do not exist in this
+/// version can never be matched, so their recipes are skipped.
+fn non_empty(items: Vec<Item>) -> Option<Vec<Item>> {
+    if items.is_empty() {
+        None
+    } else {
+        Some(items)
+    }
+}
+
+/// Returns the column, row, width and height of the smallest
+/// rectangle containing all items in the grid.
+fn bounds(grid: &[Option<Item>], grid_width: usize) -> Option<(usize, usize, usize, usize)> {
+    let occupied = || {
+        grid.iter()
+            .enumerate()
+            .filter(|(_, item)| item.is_some())
+            .map(|(i, _)| (i % grid_width, i / grid_width))
+    };
+    let min_x = occupied().map(|(x, _)| x).min()?;
+    let max_x = occupied().map(|(x, _)| x).max()?;
+    let min_y = occupied().map(|(_, y)| y).min()?;
+    let max_y = occupied().map(|(_, y)| y).max()?;
+    Some((min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
+}
+
+/// Attempts to give each item its own ingredient which accepts it.
+fn assign(items: &[Item], ingredients: &[Vec<Item>], used: &mut [bool]) -> bool {
+    let (item, rest) = match items.split_first() {
+        Some(split) => split,
+        None => return true,
+    };
+
+    for (i, ingredient) in ingredients.iter().enumerate() {
+        if !used[i] && ingredient.contains(item) {
+            used[i] = true;
+            if assign(rest, ingredients, used) {
+                return true;
+            }
+            used[i] = false;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shaped() {
+        // A crafting table from planks, anywhere in a 3x3 grid.
+        let p = Some(Item::OakPlanks);
+        let grid = [None, None, None, None, p, p, None, p, p];
+        let recipe = crafting_recipe(&grid, 3).unwrap();
+        assert_eq!(recipe.result, ItemStack::new(Item::CraftingTable, 1));
+
+        // Sticks need two planks stacked vertically.
+        let recipe = crafting_recipe(&[p, None, p, None], 2).unwrap();
+        assert_eq!(recipe.result, ItemStack::new(Item::Stick, 4));
+        assert!(crafting_recipe(&[p, p, None, None], 2).is_none());
+    }
+
+    #[test]
+    fn test_mirrored() {
+        let s = Some(Item::Stick);
+        let c = Some(Item::Cobblestone);
+        let axe = [c, c, None, c, s, None, None, s, None];
+        let mirrored = [None, c, c, None, s, c, None, s, None];
+        assert_eq!(crafting_recipe(&axe, 3).unwrap().result.ty, Item::StoneAxe);
+        assert_eq!(
+            crafting_recipe(&mirrored, 3).unwrap().result.ty,
+            Item::StoneAxe
+        );
+    }
+
+    #[test]
+    fn test_shapeless() {
+        let recipe = crafting_recipe(&[None, Some(Item::OakLog), None, None], 2).unwrap();
+        assert_eq!(recipe.result, ItemStack::new(Item::OakPlanks, 4));
+
+        let book = [
+            Some(Item::Paper),
+            Some(Item::Leather),
+            None,
+            Some(Item::Paper),
+            None,
+            None,
+            None,
+            None,
+            Some(Item::Paper),
+        ];
+        assert_eq!(crafting_recipe(&book, 3).unwrap().result.ty, Item::Book);
+    }
+
+    #[test]
+    fn test_remainder() {
+        assert_eq!(crafting_remainder(Item::MilkBucket), Some(Item::Bucket));
+        assert_eq!(crafting_remainder(Item::Sugar), None);
+    }
+}
@@ -0,0 +1,274 @@
+//! Vanilla recipes and furnace fuels.
+//!
+//! Recipes are loaded from the vanilla data pack by the build
+//! script; fuels are hardcoded in vanilla and are likewise
+//! hardcoded here.
+
+mod crafting;
+
+use ahash::AHashMap;
+use feather_items::{Item, ItemStack};
+use feather_recipes_model as model;
+use model::{Ingredient, IngredientChoice, Recipe, RecipeSet};
+use once_cell::sync::Lazy;
+
+pub use crafting::{crafting_recipe, crafting_recipes, crafting_remainder, CraftingRecipe, Shape};
+
+/// The recipe set embedded by the build script.
+static SET: Lazy<RecipeSet> = Lazy::new(|| {
+    static BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/dump.json"));
+
+    serde_json::from_slice(BYTES).expect("invalid recipe dump")
+});
+
+/// All cooking recipes whose items exist in this version, sorted by ID.
+static COOKING_RECIPES: Lazy<Vec<CookingRecipeEntry>> = Lazy::new(|| {
+    let mut recipes = Vec::new();
+
+    for (id, recipe) in &SET.recipes {
+        let (method, recipe) = match recipe {
+            Recipe::Smelting(recipe) => (CookingMethod::Smelting, recipe),
+            Recipe::Blasting(recipe) => (CookingMethod::Blasting, recipe),
+            Recipe::Smoking(recipe) => (CookingMethod::Smoking, recipe),
+            Recipe::CampfireCooking(recipe) => (CookingMethod::Campfire, recipe),
+            _ => continue,
+        };
+
+        // Recipes for items which do not exist in this version are skipped.
+        let result = match Item::from_identifier(&recipe.result) {
+            Some(item) => ItemStack::new(item, 1),
+            None => continue,
+        };
+        let inputs: Vec<Item> = ingredient_items(&recipe.ingredient).collect();
+        if inputs.is_empty() {
+            continue;
+        }
+
+        recipes.push(CookingRecipeEntry {
+            id: format!("minecraft:{}", id),
+            group: recipe.group.clone().unwrap_or_default(),
+            method,
+            inputs,
+            recipe: CookingRecipe {
+                result,
+                experience: recipe.experience,
+                cook_time: recipe
+                    .cooking_time
+                    .unwrap_or_else(|| method.default_cook_time()),
+            },
+        });
+    }
+
+    recipes.sort_by(|a, b| a.id.cmp(&b.id));
+    recipes
+});
+
+/// Cooking recipes keyed by method and input item.
+static COOKING: Lazy<AHashMap<(CookingMethod, Item), CookingRecipe>> = Lazy::new(|| {
+    let mut map = AHashMap::new();
+    for entry in COOKING_RECIPES.iter() {
+        for input in entry.inputs.iter().copied() {
+            map.insert((entry.method, input), entry.recipe);
+        }
+    }
+    map
+});
+
+/// Burn times of furnace fuels, in ticks.
+static FUELS: Lazy<AHashMap<Item, u32>> = Lazy::new(|| {
+    // Entries starting with `#` are item tags.
+    const FUELS: &[(&str, u32)] = &[
+        ("minecraft:lava_bucket", 20000),
+        ("minecraft:coal_block", 16000),
+        ("minecraft:blaze_rod", 2400),
+        ("minecraft:coal", 1600),
+        ("minecraft:charcoal", 1600),
+        ("#minecraft:logs", 300),
+        ("#minecraft:planks", 300),
+        ("#minecraft:wooden_stairs", 300),
+        ("#minecraft:wooden_slabs", 150),
+        ("#minecraft:wooden_trapdoors", 300),
+        ("#minecraft:wooden_pressure_plates", 300),
+        ("minecraft:oak_fence", 300),
+        ("minecraft:spruce_fence", 300),
+        ("minecraft:birch_fence", 300),
+        ("minecraft:jungle_fence", 300),
+        ("minecraft:acacia_fence", 300),
+        ("minecraft:dark_oak_fence", 300),
+        ("minecraft:oak_fence_gate", 300),
+        ("minecraft:spruce_fence_gate", 300),
+        ("minecraft:birch_fence_gate", 300),
+        ("minecraft:jungle_fence_gate", 300),
+        ("minecraft:acacia_fence_gate", 300),
+        ("minecraft:dark_oak_fence_gate", 300),
+        ("minecraft:note_block", 300),
+        ("minecraft:bookshelf", 300),
+        ("minecraft:jukebox", 300),
+        ("minecraft:chest", 300),
+        ("minecraft:trapped_chest", 300),
+        ("minecraft:crafting_table", 300),
+        ("minecraft:daylight_detector", 300),
+        ("#minecraft:banners", 300),
+        ("minecraft:bow", 300),
+        ("minecraft:fishing_rod", 300),
+        ("minecraft:ladder", 300),
+        ("minecraft:sign", 200),
+        ("#minecraft:signs", 200),
+        ("minecraft:wooden_shovel", 200),
+        ("minecraft:wooden_sword", 200),
+        ("minecraft:wooden_hoe", 200),
+        ("minecraft:wooden_axe", 200),
+        ("minecraft:wooden_pickaxe", 200),
+        ("#minecraft:wooden_doors", 200),
+        ("#minecraft:boats", 1200),
+        ("#minecraft:wool", 100),
+        ("#minecraft:wooden_buttons", 100),
+        ("minecraft:stick", 100),
+        ("#minecraft:saplings", 100),
+        ("minecraft:bowl", 100),
+        ("#minecraft:carpets", 67),
+        ("minecraft:dried_kelp_block", 4001),
+    ];
+
+    let mut map = AHashMap::new();
+    for (name, burn_time) in FUELS.iter().copied() {
+        let items = match name.strip_prefix('#') {
+            Some(tag) => item_tag(tag),
+            None => Item::from_identifier(name).into_iter().collect(),
+        };
+        for item in items {
+            map.insert(item, burn_time);
+        }
+    }
+    map
+});
+
+/// A kind of block which cooks items using fuel.
+/// Each has its own set of recipes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CookingMethod {
+    /// Furnaces.
+    Smelting,
+    /// Blast furnaces, which smelt ores twice as fast.
+    Blasting,
+    /// Smokers, which cook food twice as fast.
+    Smoking,
+    /// Campfires, which cook food slowly without fuel.
+    Campfire,
+}
+
+impl CookingMethod {
+    /// Returns the cooking time in ticks of recipes
+    /// which do not specify one.
+    pub fn default_cook_time(self) -> u32 {
+        match self {
+            CookingMethod::Smelting => 200,
+            CookingMethod::Blasting | CookingMethod::Smoking => 100,
+            CookingMethod::Campfire => 600,
+        }
+    }
+}
+
+/// A recipe turning one item into another in a furnace or similar.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CookingRecipe {
+    /// The item produced by each cooked item.
+    pub result: ItemStack,
+    /// Experience awarded for each cooked item.
+    pub experience: f32,
+    /// Number of ticks needed to cook one item.
+    pub cook_time: u32,
+}
+
+/// A cooking recipe along with the information needed
+/// to show it in the recipe book.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CookingRecipeEntry {
+    /// Namespaced ID of the recipe, e.g. `minecraft:iron_ingot`.
+    pub id: String,
+    /// Recipes in the same group are shown together in the recipe book.
+    pub group: String,
+    pub method: CookingMethod,
+    /// The items which can be cooked by this recipe.
+    pub inputs: Vec<Item>,
+    pub recipe: CookingRecipe,
+}
+
+/// Returns all cooking recipes.
+pub fn cooking_recipes() -> &'static [CookingRecipeEntry] {
+    &COOKING_RECIPES
+}
+
+/// Returns the recipe for cooking the given item with the given method,
+/// if there is one.
+pub fn cooking_recipe(method: CookingMethod, input: Item) -> Option<&'static CookingRecipe> {
+    COOKING.get(&(method, input))
+}
+
+/// Returns the number of ticks the given item burns for when
+/// used as furnace fuel, or `None` if it is not a fuel.
+pub fn fuel_burn_time(item: Item) -> Option<u32> {
+    FUELS.get(&item).copied()
+}
+
+/// Returns the item left in the fuel slot after the given
+/// fuel is burned, e.g. an empty bucket for a lava bucket.
+pub fn fuel_remainder(item: Item) -> Option<Item> {
+    match item {
+        Item::LavaBucket => Some(Item::Bucket),
+        _ => None,
+    }
+}
+
+/// Returns the items in the item tag with the given name,
+/// e.g. `minecraft:logs`. Items which do not exist in this
+/// version are omitted.
+pub fn item_tag(name: &str) -> Vec<Item> {
+    SET.item_tags
+        .get(name)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| Item::from_identifier(item))
+        .collect()
+}
+
+/// Returns the items accepted by an ingredient which exist in this version.
+fn ingredient_items(ingredient: &Ingredient) -> impl Iterator<Item = Item> + '_ {
+    ingredient.choices().iter().flat_map(|choice| match choice {
+        IngredientChoice::Item { item } => Item::from_identifier(item).into_iter().collect(),
+        // Tags are resolved by the build script.
+        IngredientChoice::Tag { tag } => item_tag(tag),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smelting() {
+        let recipe = cooking_recipe(CookingMethod::Smelting, Item::IronOre).unwrap();
+        assert_eq!(recipe.result, ItemStack::new(Item::IronIngot, 1));
+        assert_eq!(recipe.cook_time, 200);
+
+        let recipe = cooking_recipe(CookingMethod::Blasting, Item::IronOre).unwrap();
+        assert_eq!(recipe.cook_time, 100);
+
+        // Logs are smelted through the `minecraft:logs` tag.
+        let recipe = cooking_recipe(CookingMethod::Smelting, Item::OakLog).unwrap();
+        assert_eq!(recipe.result.ty, Item::Charcoal);
+
+        assert!(cooking_recipe(CookingMethod::Smoking, Item::IronOre).is_none());
+        assert!(cooking_recipe(CookingMethod::Smelting, Item::Stick).is_none());
+    }
+
+    #[test]
+    fn test_fuel() {
+        assert_eq!(fuel_burn_time(Item::Coal), Some(1600));
+        assert_eq!(fuel_burn_time(Item::OakPlanks), Some(300));
+        assert_eq!(fuel_burn_time(Item::LavaBucket), Some(20000));
+        assert_eq!(fuel_remainder(Item::LavaBucket), Some(Item::Bucket));
+        assert_eq!(fuel_burn_time(Item::Stone), None);
+    }
+}
@@ -10,6 +10,7 @@ pub extern crate feather_items as items;
 pub extern crate feather_loot as loot;
 pub extern crate feather_misc as misc;
 pub extern crate feather_network as network;
+pub extern crate feather_recipes as recipes;
 pub extern crate feather_text as text;
 pub extern crate feather_util as util;
 
@@ -343,6 +343,19 @@ pub enum Hover {
     ShowEntity(Entity),
 }
 
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// The contents of a score component.
+pub struct Score {
+    /// The name of the score holder. `*` refers to
+    /// the player viewing the text.
+    pub name: Cow<'static, str>,
+    pub objective: Cow<'static, str>,
+    /// The resolved value of the score. The server fills this in
+    /// before sending the text to a client.
+    pub value: Option<Cow<'static, str>>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
 /// Text component can either be Text, Translate, Score, Selector, Keybind, or Nbt.
@@ -355,9 +368,7 @@ pub enum TextValue {
         with: Vec<Text>,
     },
     Score {
-        name: Cow<'static, str>,
-        objective: Cow<'static, str>,
-        value: Option<Cow<'static, str>>,
+        score: Score,
     },
     Selector {
         selector: Cow<'static, str>,
@@ -417,9 +428,11 @@ impl TextValue {
         value: Option<C>,
     ) -> Self {
         TextValue::Score {
-            name: name.into(),
-            objective: objective.into(),
-            value: value.map(|v| v.into()),
+            score: Score {
+                name: name.into(),
+                objective: objective.into(),
+                value: value.map(|v| v.into()),
+            },
         }
     }
 
@@ -468,6 +481,26 @@ impl TextComponent {
     pub fn empty() -> TextComponent {
         TextComponent::from("")
     }
+
+    fn resolve_scores(&mut self, resolve: &mut dyn FnMut(&str, &str) -> Option<i32>) {
+        match &mut self.value {
+            TextValue::Score { score } => {
+                if let Some(value) = resolve(&score.name, &score.objective) {
+                    score.value = Some(value.to_string().into());
+                }
+            }
+            TextValue::Translate { with, .. } => with
+                .iter_mut()
+                .for_each(|text| text.resolve_scores(resolve)),
+            _ => (),
+        }
+
+        if let Some(extra) = &mut self.extra {
+            extra
+                .iter_mut()
+                .for_each(|text| text.resolve_scores(resolve));
+        }
+    }
 }
 
 pub enum Reset {
@@ -958,6 +991,21 @@ impl Text {
         Text::from(TextValue::keybind(keybind))
     }
 
+    /// Fills in the values of all score components in this text.
+    ///
+    /// `resolve` is called with the score holder name and
+    /// objective of each score component. Components for which
+    /// it returns `None` are left unchanged.
+    pub fn resolve_scores(&mut self, resolve: &mut dyn FnMut(&str, &str) -> Option<i32>) {
+        match self {
+            Text::String(_) => (),
+            Text::Array(texts) => texts
+                .iter_mut()
+                .for_each(|text| text.resolve_scores(resolve)),
+            Text::Component(component) => component.resolve_scores(resolve),
+        }
+    }
+
     pub fn nbt<A: Into<nbt::Blob>>(nbt: A) -> Text {
         Text::from(TextValue::nbt(nbt))
     }
@@ -1184,4 +1232,34 @@ mod tests {
 
         assert_eq!(root_json, r#"{"text":"hello"}"#);
     }
+
+    #[test]
+    fn text_score() -> Result<(), Box<dyn Error>> {
+        let mut text = Text::from("Kills: ") + Text::score("*", "kills", None::<&str>);
+
+        let text_json = serde_json::to_string(&text)?;
+        assert_eq!(
+            text_json,
+            r#"["Kills: ",{"score":{"name":"*","objective":"kills"}}]"#
+        );
+
+        text.resolve_scores(&mut |name, objective| {
+            assert_eq!((name, objective), ("*", "kills"));
+            Some(5)
+        });
+
+        let text_json = serde_json::to_string(&text)?;
+        assert_eq!(
+            text_json,
+            r#"["Kills: ",{"score":{"name":"*","objective":"kills","value":"5"}}]"#
+        );
+
+        let text: Text = serde_json::from_str(&text_json)?;
+        assert_eq!(
+            text,
+            Text::from("Kills: ") + Text::score("*", "kills", Some("5"))
+        );
+
+        Ok(())
+    }
 }
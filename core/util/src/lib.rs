@@ -100,6 +100,14 @@ impl Dimension {
             Dimension::End => 1,
         }
     }
+
+    pub fn from_id(id: i32) -> Self {
+        match id {
+            -1 => Dimension::Nether,
+            1 => Dimension::End,
+            _ => Dimension::Overwold,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -0,0 +1,71 @@
+use crate::container;
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{
+    BannerPattern, BlockEntityData, BlockEntityKind, BlockEntityVariant,
+};
+use feather_core::network::Packet;
+use feather_core::util::BlockPosition;
+use feather_server_types::{
+    BlockEntityLoaderRegistration, BlockSerializer, Game, SpawnPacketCreator,
+};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Banner,
+});
+
+/// `UpdateBlockEntity` action which sets the patterns on a banner.
+const UPDATE_ACTION: u8 = 6;
+
+/// The pattern layers of a banner, from bottom to top.
+/// The base color is stored in the block state.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BannerPatterns(pub Vec<BannerPattern>);
+
+/// Creates a banner without patterns.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    create_with_patterns(pos, BannerPatterns::default())
+}
+
+/// Creates a banner with the given patterns.
+pub fn create_with_patterns(pos: BlockPosition, patterns: BannerPatterns) -> EntityBuilder {
+    crate::base(pos)
+        .with(patterns)
+        .with(SpawnPacketCreator(&create_spawn_packet))
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn create_spawn_packet(accessor: &EntityRef) -> Box<dyn Packet> {
+    Box::new(crate::update_packet(UPDATE_ACTION, &data(accessor)))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    data(accessor)
+}
+
+fn data(accessor: &EntityRef) -> BlockEntityData {
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Banner {
+            custom_name: container::custom_name(accessor),
+            patterns: accessor.get::<BannerPatterns>().0.clone(),
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Banner {
+            custom_name,
+            patterns,
+        } => Ok(container::with_custom_name(
+            create_with_patterns(pos, BannerPatterns(patterns)),
+            custom_name,
+        )),
+        _ => bail!("not a banner"),
+    }
+}
@@ -0,0 +1,38 @@
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{BlockEntityData, BlockEntityKind, BlockEntityVariant};
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockEntityLoaderRegistration, BlockSerializer, Game};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Bed,
+});
+
+/// Marker component for beds. Beds have no
+/// block entity data; the color is stored in the block state.
+pub struct Bed;
+
+/// Creates a bed.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    crate::base(pos)
+        .with(Bed)
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Bed,
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Bed => Ok(create(pos)),
+        _ => bail!("not a bed"),
+    }
+}
@@ -0,0 +1,88 @@
+use crate::container::{self, Container, SlotLayout};
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{BlockEntityData, BlockEntityKind, BlockEntityVariant};
+use feather_core::inventory::Area;
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockEntityLoaderRegistration, BlockSerializer, Game, Inventory};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::BrewingStand,
+});
+
+/// Slots of a brewing stand: three bottles, the ingredient and the fuel.
+pub const SLOTS: SlotLayout = &[
+    (Area::BrewingBottle, 3),
+    (Area::BrewingIngredient, 1),
+    (Area::BrewingFuel, 1),
+];
+
+/// Marker component for brewing stands.
+pub struct BrewingStand;
+
+/// Brewing progress of a brewing stand.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BrewingState {
+    /// Number of ticks until the current potions are brewed.
+    pub brew_time: i16,
+    /// Remaining fuel, in brewing operations.
+    pub fuel: i8,
+}
+
+/// Creates a brewing stand.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    create_with_state(pos, Inventory::brewing_stand(), BrewingState::default())
+}
+
+/// Creates a brewing stand with the given inventory and brewing progress.
+pub fn create_with_state(
+    pos: BlockPosition,
+    inventory: Inventory,
+    state: BrewingState,
+) -> EntityBuilder {
+    crate::base(pos)
+        .with(BrewingStand)
+        .with(Container)
+        .with(state)
+        .with(inventory)
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    let state = *accessor.get::<BrewingState>();
+
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::BrewingStand {
+            custom_name: container::custom_name(accessor),
+            items: container::serialize_items(&*accessor.get::<Inventory>(), SLOTS),
+            brew_time: state.brew_time,
+            fuel: state.fuel,
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::BrewingStand {
+            custom_name,
+            items,
+            brew_time,
+            fuel,
+        } => {
+            let inventory = Inventory::brewing_stand();
+            container::load_items(&inventory, SLOTS, &items);
+            let state = BrewingState { brew_time, fuel };
+
+            Ok(container::with_custom_name(
+                create_with_state(pos, inventory, state),
+                custom_name,
+            ))
+        }
+        _ => bail!("not a brewing stand"),
+    }
+}
@@ -0,0 +1,52 @@
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{BlockEntityData, BlockEntityKind, BlockEntityVariant};
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockEntityLoaderRegistration, BlockSerializer, Game};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Comparator,
+});
+
+/// Marker component for redstone comparators.
+pub struct Comparator;
+
+/// The signal strength a comparator outputs, which
+/// isn't stored in the block state.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ComparatorOutput(pub u8);
+
+/// Creates a comparator which outputs no signal.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    create_with_output(pos, ComparatorOutput::default())
+}
+
+fn create_with_output(pos: BlockPosition, output: ComparatorOutput) -> EntityBuilder {
+    crate::base(pos)
+        .with(Comparator)
+        .with(output)
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Comparator {
+            output_signal: i32::from(accessor.get::<ComparatorOutput>().0),
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Comparator { output_signal } => Ok(create_with_output(
+            pos,
+            ComparatorOutput(output_signal.max(0).min(15) as u8),
+        )),
+        _ => bail!("not a comparator"),
+    }
+}
@@ -0,0 +1,154 @@
+//! Functionality shared by block entities which store items,
+//! such as furnaces, hoppers and dispensers.
+
+use feather_core::anvil::player::InventorySlot;
+use feather_core::inventory::Area;
+use feather_core::items::Item;
+use feather_core::util::Position;
+use feather_server_entity::drops::drop_item;
+use feather_server_types::{BumpVec, EntityDespawnEvent, Game, Inventory};
+use fecs::{EntityBuilder, EntityRef, World};
+
+/// Describes how the slots of a block entity's inventory
+/// map to the `Slot` indices used in the Anvil format.
+///
+/// Each entry is an area and its number of slots. Anvil
+/// slot indices count up through the areas in order.
+pub type SlotLayout = &'static [(Area, usize)];
+
+/// Marker component for block entities whose items
+/// are dropped when the block is broken.
+///
+/// Chests have their own handling and do not use this component.
+pub struct Container;
+
+/// The custom name of a block entity, set by renaming its item
+/// in an anvil. Stored as JSON text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomName(pub String);
+
+/// Adds a `CustomName` to the builder if `name` is `Some`.
+pub fn with_custom_name(builder: EntityBuilder, name: Option<String>) -> EntityBuilder {
+    match name {
+        Some(name) => builder.with(CustomName(name)),
+        None => builder,
+    }
+}
+
+/// Returns the custom name of a block entity, if it has one.
+pub fn custom_name(accessor: &EntityRef) -> Option<String> {
+    accessor.try_get::<CustomName>().map(|name| name.0.clone())
+}
+
+/// Converts the items in an inventory to Anvil slots.
+pub fn serialize_items(inventory: &Inventory, layout: SlotLayout) -> Vec<InventorySlot> {
+    let mut slots = Vec::new();
+    let mut index = 0;
+    for (area, count) in layout.iter().copied() {
+        for i in 0..count {
+            if let Ok(Some(item)) = inventory.item_at(area, i) {
+                slots.push(InventorySlot::from_inventory_index(index as i8, item));
+            }
+            index += 1;
+        }
+    }
+    slots
+}
+
+/// Places the items from a list of Anvil slots into an inventory.
+pub fn load_items(inventory: &Inventory, layout: SlotLayout, slots: &[InventorySlot]) {
+    for slot in slots {
+        if Item::from_identifier(&slot.item).is_none() {
+            continue;
+        }
+
+        match locate(layout, slot.slot as usize) {
+            Some((area, index)) => {
+                if let Err(e) = inventory.set_item_at(area, index, slot.into()) {
+                    log::warn!("Invalid block entity slot: {}", e);
+                }
+            }
+            None => log::warn!("Block entity slot {} is out of bounds", slot.slot),
+        }
+    }
+}
+
+/// Finds the area and index within that area of an Anvil slot index.
+fn locate(layout: SlotLayout, mut slot: usize) -> Option<(Area, usize)> {
+    for (area, count) in layout.iter().copied() {
+        if slot < count {
+            return Some((area, slot));
+        }
+        slot -= count;
+    }
+    None
+}
+
+/// When a container is despawned, drops its contents.
+#[fecs::event_handler]
+pub fn on_container_break_drop_contents(
+    event: &EntityDespawnEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    let entity = event.entity;
+    if !world.has::<Container>(entity) {
+        return;
+    }
+
+    let items = BumpVec::from_iter_in(
+        world
+            .get::<Inventory>(entity)
+            .iter_mut()
+            .filter_map(|mut guard| guard.take()),
+        game.bump(),
+    );
+    let pos = *world.get::<Position>(entity);
+    for item in items {
+        drop_item(game, world, item, pos);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::items::ItemStack;
+
+    const LAYOUT: SlotLayout = &[
+        (Area::BrewingBottle, 3),
+        (Area::BrewingIngredient, 1),
+        (Area::BrewingFuel, 1),
+    ];
+
+    #[test]
+    fn test_locate() {
+        assert_eq!(locate(LAYOUT, 0), Some((Area::BrewingBottle, 0)));
+        assert_eq!(locate(LAYOUT, 2), Some((Area::BrewingBottle, 2)));
+        assert_eq!(locate(LAYOUT, 3), Some((Area::BrewingIngredient, 0)));
+        assert_eq!(locate(LAYOUT, 4), Some((Area::BrewingFuel, 0)));
+        assert_eq!(locate(LAYOUT, 5), None);
+    }
+
+    #[test]
+    fn test_items_round_trip() {
+        let inventory = Inventory::brewing_stand();
+        inventory
+            .set_item_at(
+                Area::BrewingIngredient,
+                0,
+                ItemStack::new(Item::NetherWart, 3),
+            )
+            .unwrap();
+
+        let slots = serialize_items(&inventory, LAYOUT);
+        assert_eq!(slots.len(), 1);
+        assert_eq!(slots[0].slot, 3);
+
+        let loaded = Inventory::brewing_stand();
+        load_items(&loaded, LAYOUT, &slots);
+        assert_eq!(
+            loaded.item_at(Area::BrewingIngredient, 0).unwrap(),
+            Some(ItemStack::new(Item::NetherWart, 3))
+        );
+    }
+}
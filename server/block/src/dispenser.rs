@@ -0,0 +1,90 @@
+//! Dispensers and droppers. The two share an inventory
+//! layout and differ only in what they do with the items.
+
+use crate::container::{self, Container, SlotLayout};
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{BlockEntityData, BlockEntityKind, BlockEntityVariant};
+use feather_core::inventory::Area;
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockEntityLoaderRegistration, BlockSerializer, Game, Inventory};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Dispenser,
+});
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Dropper,
+});
+
+pub const SLOTS: SlotLayout = &[(Area::Dispenser, 9)];
+
+/// Component for dispensers and droppers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Dispenser {
+    /// Dispenses items, e.g. by firing arrows or placing water.
+    Dispenser,
+    /// Drops items or pushes them into containers.
+    Dropper,
+}
+
+/// Creates a dispenser.
+pub fn create_dispenser(pos: BlockPosition) -> EntityBuilder {
+    create_with_inventory(pos, Dispenser::Dispenser, Inventory::dispenser())
+}
+
+/// Creates a dropper.
+pub fn create_dropper(pos: BlockPosition) -> EntityBuilder {
+    create_with_inventory(pos, Dispenser::Dropper, Inventory::dispenser())
+}
+
+/// Creates a dispenser or dropper with the given inventory.
+pub fn create_with_inventory(
+    pos: BlockPosition,
+    kind: Dispenser,
+    inventory: Inventory,
+) -> EntityBuilder {
+    crate::base(pos)
+        .with(kind)
+        .with(Container)
+        .with(inventory)
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    let custom_name = container::custom_name(accessor);
+    let items = container::serialize_items(&*accessor.get::<Inventory>(), SLOTS);
+
+    let kind = match *accessor.get::<Dispenser>() {
+        Dispenser::Dispenser => BlockEntityKind::Dispenser { custom_name, items },
+        Dispenser::Dropper => BlockEntityKind::Dropper { custom_name, items },
+    };
+
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind,
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    let (kind, custom_name, items) = match data.kind {
+        BlockEntityKind::Dispenser { custom_name, items } => {
+            (Dispenser::Dispenser, custom_name, items)
+        }
+        BlockEntityKind::Dropper { custom_name, items } => (Dispenser::Dropper, custom_name, items),
+        _ => bail!("not a dispenser or dropper"),
+    };
+
+    let inventory = Inventory::dispenser();
+    container::load_items(&inventory, SLOTS, &items);
+
+    Ok(container::with_custom_name(
+        create_with_inventory(pos, kind, inventory),
+        custom_name,
+    ))
+}
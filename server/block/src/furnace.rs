@@ -0,0 +1,475 @@
+//! Furnaces, which smelt items using fuel.
+//!
+//! Cooking is implemented for any `CookingMethod`, so blast furnaces
+//! and smokers only need a block entity which uses their method.
+//! Neither block exists in 1.13, so only furnaces are created for now.
+
+use crate::container::{self, Container, SlotLayout};
+use crate::ShouldReplace;
+use anyhow::bail;
+use arrayvec::ArrayVec;
+use feather_core::anvil::block_entity::{BlockEntityData, BlockEntityKind, BlockEntityVariant};
+use feather_core::blocks::BlockKind;
+use feather_core::inventory::{slot, Area, Window};
+use feather_core::items::ItemStack;
+use feather_core::network::packets::{OpenWindow, SetSlot, WindowItems, WindowProperty};
+use feather_core::recipes::{self, CookingMethod};
+use feather_core::text::TextRoot;
+use feather_core::util::BlockPosition;
+use feather_server_types::{
+    BlockEntityLoaderRegistration, BlockSerializer, BlockUpdateCause, BumpVec, Game,
+    InteractionHandler, Inventory, Network, WindowCloseEvent, WindowOpenEvent,
+};
+use fecs::{Entity, EntityBuilder, EntityRef, IntoQuery, Read, World, Write};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Furnace,
+});
+
+/// Slots of a furnace: ingredient, fuel and output.
+pub const SLOTS: SlotLayout = &[
+    (Area::FurnaceIngredient, 1),
+    (Area::FurnaceFuel, 1),
+    (Area::FurnaceOutput, 1),
+];
+
+/// Marker component for furnaces.
+pub struct Furnace;
+
+/// Players viewing a furnace's window, along with the
+/// window ID each player has it open under.
+#[derive(Clone, Debug, Default)]
+pub struct FurnaceViewers(pub Vec<(Entity, u8)>);
+
+/// Smelting progress of a furnace. All values are in ticks.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FurnaceState {
+    /// Number of ticks until the current fuel runs out.
+    pub burn_time: i16,
+    /// Number of ticks the current fuel burns for in total.
+    /// Used by clients to draw the flame; not saved.
+    pub burn_time_total: i16,
+    /// Number of ticks the current item has been cooking.
+    pub cook_time: i16,
+    /// Number of ticks needed to cook the current item.
+    pub cook_time_total: i16,
+}
+
+/// Creates a furnace.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    create_with_state(pos, Inventory::furnace(), FurnaceState::default())
+}
+
+/// Creates a furnace with the given inventory and smelting progress.
+pub fn create_with_state(
+    pos: BlockPosition,
+    inventory: Inventory,
+    state: FurnaceState,
+) -> EntityBuilder {
+    crate::base(pos)
+        .with(Furnace)
+        .with(Container)
+        .with(CookingMethod::Smelting)
+        .with(FurnaceViewers::default())
+        .with(state)
+        .with(inventory)
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    let state = *accessor.get::<FurnaceState>();
+
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Furnace {
+            custom_name: container::custom_name(accessor),
+            items: container::serialize_items(&*accessor.get::<Inventory>(), SLOTS),
+            burn_time: state.burn_time,
+            cook_time: state.cook_time,
+            cook_time_total: state.cook_time_total,
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Furnace {
+            custom_name,
+            items,
+            burn_time,
+            cook_time,
+            cook_time_total,
+        } => {
+            let inventory = Inventory::furnace();
+            container::load_items(&inventory, SLOTS, &items);
+            // Vanilla does not save the total burn time of the current
+            // fuel; it assumes the fuel slot still holds the same fuel.
+            let burn_time_total = inventory
+                .item_at(Area::FurnaceFuel, 0)
+                .ok()
+                .flatten()
+                .and_then(|fuel| recipes::fuel_burn_time(fuel.ty))
+                .map(|total| total as i16)
+                .unwrap_or(burn_time);
+            let state = FurnaceState {
+                burn_time,
+                burn_time_total,
+                cook_time,
+                cook_time_total,
+            };
+
+            Ok(container::with_custom_name(
+                create_with_state(pos, inventory, state),
+                custom_name,
+            ))
+        }
+        _ => bail!("not a furnace"),
+    }
+}
+
+/// Advances a furnace by one tick, following vanilla's logic.
+/// Returns the areas whose items changed.
+fn tick(
+    inventory: &Inventory,
+    state: &mut FurnaceState,
+    method: CookingMethod,
+) -> ArrayVec<[Area; 3]> {
+    let mut changed = ArrayVec::new();
+
+    if state.burn_time > 0 {
+        state.burn_time -= 1;
+    }
+
+    let input = inventory.item_at(Area::FurnaceIngredient, 0).unwrap();
+    let fuel = inventory.item_at(Area::FurnaceFuel, 0).unwrap();
+    let recipe = input.and_then(|input| recipes::cooking_recipe(method, input.ty));
+
+    if state.burn_time == 0 && (input.is_none() || fuel.is_none()) {
+        // Nothing to do; progress cools down.
+        state.cook_time = (state.cook_time - 2).max(0);
+        return changed;
+    }
+
+    let recipe = match recipe {
+        Some(recipe) => recipe,
+        None => {
+            state.cook_time = 0;
+            return changed;
+        }
+    };
+    state.cook_time_total = recipe.cook_time as i16;
+
+    let output = inventory.item_at(Area::FurnaceOutput, 0).unwrap();
+    if !fits_output(output, recipe.result) {
+        state.cook_time = 0;
+        return changed;
+    }
+
+    if state.burn_time == 0 {
+        let fuel = fuel.expect("checked above");
+        if let Some(burn_time) = recipes::fuel_burn_time(fuel.ty) {
+            state.burn_time = burn_time as i16;
+            state.burn_time_total = burn_time as i16;
+            consume_fuel(inventory, fuel);
+            changed.push(Area::FurnaceFuel);
+        }
+    }
+
+    if state.burn_time == 0 {
+        state.cook_time = 0;
+        return changed;
+    }
+
+    state.cook_time += 1;
+    if state.cook_time >= state.cook_time_total {
+        state.cook_time = 0;
+        let input = input.expect("recipe exists");
+        take_one(inventory, Area::FurnaceIngredient, input);
+        let output = match output {
+            Some(output) => output.of_amount(output.amount + recipe.result.amount),
+            None => recipe.result,
+        };
+        inventory
+            .set_item_at(Area::FurnaceOutput, 0, output)
+            .unwrap();
+        changed.push(Area::FurnaceIngredient);
+        changed.push(Area::FurnaceOutput);
+    }
+
+    changed
+}
+
+/// Determines whether `result` can be added to the output slot.
+fn fits_output(output: Option<ItemStack>, result: ItemStack) -> bool {
+    match output {
+        None => true,
+        Some(output) => {
+            output.eq_ignore_amount(result)
+                && u32::from(output.amount) + u32::from(result.amount) <= output.ty.stack_size()
+        }
+    }
+}
+
+/// Burns one fuel item, leaving behind its remainder if it has one.
+fn consume_fuel(inventory: &Inventory, fuel: ItemStack) {
+    match recipes::fuel_remainder(fuel.ty) {
+        Some(remainder) if fuel.amount == 1 => {
+            inventory
+                .set_item_at(Area::FurnaceFuel, 0, ItemStack::new(remainder, 1))
+                .unwrap();
+        }
+        _ => take_one(inventory, Area::FurnaceFuel, fuel),
+    }
+}
+
+fn take_one(inventory: &Inventory, area: Area, stack: ItemStack) {
+    if stack.amount > 1 {
+        inventory
+            .set_item_at(area, 0, stack.of_amount(stack.amount - 1))
+            .unwrap();
+    } else {
+        inventory.remove_item_at(area, 0).unwrap();
+    }
+}
+
+/// System which smelts items in furnaces, lights and extinguishes
+/// furnace blocks, and keeps viewers' windows up to date.
+#[fecs::system]
+pub fn tick_furnaces(game: &mut Game, world: &mut World) {
+    let mut updates = BumpVec::new_in(game.bump());
+    for (furnace, (mut state, inventory, method)) in
+        <(Write<FurnaceState>, Read<Inventory>, Read<CookingMethod>)>::query()
+            .iter_entities_mut(world.inner_mut())
+    {
+        let old = *state;
+        let changed = tick(&inventory, &mut state, *method);
+        if old != *state || !changed.is_empty() {
+            updates.push((furnace, old, *state, changed));
+        }
+    }
+
+    for (furnace, old, new, changed) in updates {
+        if (old.burn_time > 0) != (new.burn_time > 0) {
+            set_lit(game, world, furnace, new.burn_time > 0);
+        }
+
+        let viewers = world.get::<FurnaceViewers>(furnace).0.clone();
+        for (player, window_id) in viewers {
+            let network = match world.try_get::<Network>(player) {
+                Some(network) => network,
+                None => continue,
+            };
+
+            for ((property, old), (_, new)) in properties(old).zip(properties(new)) {
+                if old != new {
+                    network.send(WindowProperty {
+                        window_id,
+                        property,
+                        value: new,
+                    });
+                }
+            }
+
+            let window = world.get::<Window>(player);
+            let inventory = world.get::<Inventory>(furnace);
+            for area in changed.iter().copied() {
+                if let Some(index) = window.convert_slot(slot(area, 0), furnace) {
+                    network.send(SetSlot {
+                        window_id: window_id as i8,
+                        slot: index as i16,
+                        slot_data: inventory.item_at(area, 0).unwrap(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Returns the window properties of a furnace as (property, value) pairs.
+/// See https://wiki.vg/Protocol#Window_Property.
+fn properties(state: FurnaceState) -> impl Iterator<Item = (i16, i16)> {
+    ArrayVec::from([
+        (0, state.burn_time),
+        (1, state.burn_time_total),
+        (2, state.cook_time),
+        (3, state.cook_time_total),
+    ])
+    .into_iter()
+}
+
+fn set_lit(game: &mut Game, world: &mut World, furnace: Entity, lit: bool) {
+    let pos = *world.get::<BlockPosition>(furnace);
+    if let Some(block) = game.block_at(pos) {
+        if block.lit().is_some() {
+            game.set_block_at(world, pos, block.with_lit(lit), BlockUpdateCause::Unknown);
+        }
+    }
+}
+
+/// Handler for player right clicking on furnaces.
+pub struct FurnaceInteraction;
+inventory::submit!(Box::new(FurnaceInteraction) as Box<dyn InteractionHandler>);
+
+impl InteractionHandler for FurnaceInteraction {
+    fn handle_interaction(
+        &self,
+        game: &mut Game,
+        world: &mut World,
+        pos: BlockPosition,
+        player: Entity,
+        window_id: u8,
+    ) {
+        let furnace = match game.block_entities.get(&pos).copied() {
+            Some(furnace) if world.has::<Furnace>(furnace) => furnace,
+            _ => return,
+        };
+
+        {
+            let inventory = world.get::<Inventory>(furnace);
+            let slots = SLOTS
+                .iter()
+                .map(|(area, _)| inventory.item_at(*area, 0).unwrap())
+                .collect();
+
+            let network = world.get::<Network>(player);
+            network.send(OpenWindow {
+                window_id,
+                window_type: String::from("minecraft:furnace"),
+                window_title: TextRoot::from("Furnace").into(),
+                number_of_slots: SLOTS.len() as u8,
+                entity_id: None,
+            });
+            network.send(WindowItems { window_id, slots });
+            for (property, value) in properties(*world.get::<FurnaceState>(furnace)) {
+                network.send(WindowProperty {
+                    window_id,
+                    property,
+                    value,
+                });
+            }
+        }
+
+        *world.get_mut::<Window>(player) = Window::furnace(player, furnace);
+        world
+            .get_mut::<FurnaceViewers>(furnace)
+            .0
+            .push((player, window_id));
+
+        game.handle(
+            world,
+            WindowOpenEvent {
+                player,
+                opened: furnace,
+            },
+        );
+    }
+
+    fn block_kind(&self) -> BlockKind {
+        BlockKind::Furnace
+    }
+}
+
+/// When a player closes a furnace, stops sending them its updates.
+#[fecs::event_handler]
+pub fn on_furnace_close_remove_viewer(event: &WindowCloseEvent, world: &mut World) {
+    if let Some(mut viewers) = world.try_get_mut::<FurnaceViewers>(event.closed) {
+        viewers.0.retain(|(player, _)| *player != event.player);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::items::Item;
+
+    fn furnace(input: Option<ItemStack>, fuel: Option<ItemStack>) -> Inventory {
+        let inventory = Inventory::furnace();
+        if let Some(input) = input {
+            inventory
+                .set_item_at(Area::FurnaceIngredient, 0, input)
+                .unwrap();
+        }
+        if let Some(fuel) = fuel {
+            inventory.set_item_at(Area::FurnaceFuel, 0, fuel).unwrap();
+        }
+        inventory
+    }
+
+    #[test]
+    fn test_smelt() {
+        let inventory = furnace(
+            Some(ItemStack::new(Item::IronOre, 2)),
+            Some(ItemStack::new(Item::Coal, 1)),
+        );
+        let mut state = FurnaceState::default();
+
+        let changed = tick(&inventory, &mut state, CookingMethod::Smelting);
+        assert_eq!(changed.as_slice(), &[Area::FurnaceFuel]);
+        assert_eq!(state.burn_time, 1600);
+        assert_eq!(state.burn_time_total, 1600);
+        assert_eq!(state.cook_time, 1);
+        assert_eq!(state.cook_time_total, 200);
+        assert_eq!(inventory.item_at(Area::FurnaceFuel, 0).unwrap(), None);
+
+        for _ in 1..200 {
+            tick(&inventory, &mut state, CookingMethod::Smelting);
+        }
+        assert_eq!(state.cook_time, 0);
+        assert_eq!(
+            inventory.item_at(Area::FurnaceIngredient, 0).unwrap(),
+            Some(ItemStack::new(Item::IronOre, 1))
+        );
+        assert_eq!(
+            inventory.item_at(Area::FurnaceOutput, 0).unwrap(),
+            Some(ItemStack::new(Item::IronIngot, 1))
+        );
+    }
+
+    #[test]
+    fn test_no_fuel() {
+        let inventory = furnace(Some(ItemStack::new(Item::IronOre, 1)), None);
+        let mut state = FurnaceState {
+            cook_time: 5,
+            ..Default::default()
+        };
+
+        let changed = tick(&inventory, &mut state, CookingMethod::Smelting);
+        assert!(changed.is_empty());
+        assert_eq!(state.cook_time, 3);
+    }
+
+    #[test]
+    fn test_lava_bucket_remainder() {
+        let inventory = furnace(
+            Some(ItemStack::new(Item::Cobblestone, 1)),
+            Some(ItemStack::new(Item::LavaBucket, 1)),
+        );
+        let mut state = FurnaceState::default();
+
+        tick(&inventory, &mut state, CookingMethod::Smelting);
+        assert_eq!(
+            inventory.item_at(Area::FurnaceFuel, 0).unwrap(),
+            Some(ItemStack::new(Item::Bucket, 1))
+        );
+    }
+
+    #[test]
+    fn test_output_full() {
+        let inventory = furnace(
+            Some(ItemStack::new(Item::IronOre, 1)),
+            Some(ItemStack::new(Item::Coal, 1)),
+        );
+        inventory
+            .set_item_at(Area::FurnaceOutput, 0, ItemStack::new(Item::GoldIngot, 1))
+            .unwrap();
+        let mut state = FurnaceState::default();
+
+        // Fuel is not wasted when the result cannot be stored.
+        let changed = tick(&inventory, &mut state, CookingMethod::Smelting);
+        assert!(changed.is_empty());
+        assert_eq!(state.burn_time, 0);
+    }
+}
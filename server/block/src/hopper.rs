@@ -0,0 +1,73 @@
+use crate::container::{self, Container, SlotLayout};
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{BlockEntityData, BlockEntityKind, BlockEntityVariant};
+use feather_core::inventory::Area;
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockEntityLoaderRegistration, BlockSerializer, Game, Inventory};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Hopper,
+});
+
+pub const SLOTS: SlotLayout = &[(Area::Hopper, 5)];
+
+/// Marker component for hoppers.
+pub struct Hopper;
+
+/// Number of ticks until a hopper may transfer another item.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransferCooldown(pub i32);
+
+/// Creates a hopper.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    create_with_inventory(pos, Inventory::hopper(), TransferCooldown::default())
+}
+
+/// Creates a hopper with the given inventory and transfer cooldown.
+pub fn create_with_inventory(
+    pos: BlockPosition,
+    inventory: Inventory,
+    cooldown: TransferCooldown,
+) -> EntityBuilder {
+    crate::base(pos)
+        .with(Hopper)
+        .with(Container)
+        .with(cooldown)
+        .with(inventory)
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Hopper {
+            custom_name: container::custom_name(accessor),
+            items: container::serialize_items(&*accessor.get::<Inventory>(), SLOTS),
+            transfer_cooldown: accessor.get::<TransferCooldown>().0,
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Hopper {
+            custom_name,
+            items,
+            transfer_cooldown,
+        } => {
+            let inventory = Inventory::hopper();
+            container::load_items(&inventory, SLOTS, &items);
+
+            Ok(container::with_custom_name(
+                create_with_inventory(pos, inventory, TransferCooldown(transfer_cooldown)),
+                custom_name,
+            ))
+        }
+        _ => bail!("not a hopper"),
+    }
+}
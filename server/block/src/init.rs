@@ -1,6 +1,9 @@
-use crate::{chest, ShouldReplace};
+use crate::{
+    banner, bed, brewing_stand, chest, comparator, dispenser, furnace, hopper, sign, skull,
+    ShouldReplace,
+};
 use ahash::AHashMap;
-use feather_core::blocks::BlockKind;
+use feather_core::blocks::SimplifiedBlockKind;
 use feather_core::util::BlockPosition;
 use feather_server_types::{BlockEntity, BlockUpdateEvent, EntitySpawnEvent, Game};
 use fecs::{EntityBuilder, World};
@@ -9,13 +12,42 @@ use once_cell::sync::Lazy;
 type BlockEntityCreator = fn(BlockPosition) -> EntityBuilder;
 
 /// Global mapping of blocks which require block entities.
-static BLOCK_ENTITY_MAP: Lazy<AHashMap<BlockKind, BlockEntityCreator>> = Lazy::new(|| {
-    let mut map: AHashMap<_, fn(BlockPosition) -> EntityBuilder> = AHashMap::new();
+static BLOCK_ENTITY_MAP: Lazy<AHashMap<SimplifiedBlockKind, BlockEntityCreator>> =
+    Lazy::new(|| {
+        let mut map: AHashMap<_, fn(BlockPosition) -> EntityBuilder> = AHashMap::new();
 
-    map.insert(BlockKind::Chest, chest::create);
+        map.insert(SimplifiedBlockKind::Chest, chest::create);
+        map.insert(SimplifiedBlockKind::Furnace, furnace::create);
+        map.insert(SimplifiedBlockKind::BrewingStand, brewing_stand::create);
+        map.insert(SimplifiedBlockKind::Hopper, hopper::create);
+        map.insert(SimplifiedBlockKind::Dispenser, dispenser::create_dispenser);
+        map.insert(SimplifiedBlockKind::Dropper, dispenser::create_dropper);
+        map.insert(SimplifiedBlockKind::Sign, sign::create);
+        map.insert(SimplifiedBlockKind::WallSign, sign::create);
+        map.insert(SimplifiedBlockKind::Banner, banner::create);
+        map.insert(SimplifiedBlockKind::WallBanner, banner::create);
+        map.insert(SimplifiedBlockKind::Bed, bed::create);
+        map.insert(SimplifiedBlockKind::Comparator, comparator::create);
 
-    map
-});
+        for kind in &[
+            SimplifiedBlockKind::SkeletonSkull,
+            SimplifiedBlockKind::SkeletonWallSkull,
+            SimplifiedBlockKind::WitherSkeletonSkull,
+            SimplifiedBlockKind::WitherSkeletonWallSkull,
+            SimplifiedBlockKind::ZombieHead,
+            SimplifiedBlockKind::ZombieWallHead,
+            SimplifiedBlockKind::PlayerHead,
+            SimplifiedBlockKind::PlayerWallHead,
+            SimplifiedBlockKind::CreeperHead,
+            SimplifiedBlockKind::CreeperWallHead,
+            SimplifiedBlockKind::DragonHead,
+            SimplifiedBlockKind::DragonWallHead,
+        ] {
+            map.insert(*kind, skull::create);
+        }
+
+        map
+    });
 
 /// When a block is created, and there is a block entity kind
 /// associated with it, creates the block entity. Additionally,
@@ -38,7 +70,7 @@ pub fn on_block_update_create_block_entity(
         game.despawn(entity, world);
     }
 
-    if let Some(init) = BLOCK_ENTITY_MAP.get(&event.new.kind()) {
+    if let Some(init) = BLOCK_ENTITY_MAP.get(&event.new.simplified_kind()) {
         // Spawn block entity
         let entity = init(event.pos).build().spawn_in(world);
 
@@ -0,0 +1,187 @@
+//! Leaf decay. Leaves store their distance to the closest log,
+//! which is updated through scheduled ticks whenever a neighbouring
+//! block changes. Leaves which are not persistent and too far
+//! from a log decay on a random tick.
+
+use feather_core::blocks::{BlockId, SimplifiedBlockKind};
+use feather_core::chunk_map::ChunkMap;
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockUpdateCause, BlockUpdateEvent, Game, ScheduledTickEvent};
+use feather_server_util::adjacent_blocks;
+use fecs::World;
+
+/// Distance of leaves which aren't connected to a log.
+pub const MAX_DISTANCE: i32 = 7;
+
+/// When a block is updated, schedules the leaves
+/// next to it to update their distance.
+#[fecs::event_handler]
+pub fn on_block_update_schedule_leaves(event: &BlockUpdateEvent, game: &mut Game) {
+    for neighbor in adjacent_blocks(event.pos) {
+        if let Some(block) = game.block_at(neighbor) {
+            if is_leaves(block) {
+                let due = game.tick_count + 1;
+                game.scheduled_ticks.schedule(neighbor, block, due, 0);
+            }
+        }
+    }
+}
+
+/// Updates the distance of leaves to the closest log.
+#[fecs::event_handler]
+pub fn on_scheduled_tick_update_leaves(
+    event: &ScheduledTickEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    if !is_leaves(event.block) {
+        return;
+    }
+
+    let distance = distance_to_log(&game.chunk_map, event.pos);
+    if event.block.distance() != Some(distance) {
+        let block = event.block.with_distance(distance);
+        game.set_block_at(world, event.pos, block, BlockUpdateCause::Unknown);
+    }
+}
+
+/// Returns whether the given leaves are not
+/// persistent and are too far from a log.
+pub fn should_decay(block: BlockId) -> bool {
+    is_leaves(block) && block.persistent() == Some(false) && block.distance() == Some(MAX_DISTANCE)
+}
+
+/// Makes leaves decay, dropping their loot, if they should.
+pub fn decay(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    if should_decay(block) {
+        game.set_block_at(world, pos, BlockId::air(), BlockUpdateCause::Destroyed);
+    }
+}
+
+fn is_leaves(block: BlockId) -> bool {
+    block.simplified_kind() == SimplifiedBlockKind::Leaves
+}
+
+/// Computes the distance of leaves at `pos` from the
+/// distances of the adjacent leaves and logs.
+fn distance_to_log(chunk_map: &ChunkMap, pos: BlockPosition) -> i32 {
+    adjacent_blocks(pos)
+        .into_iter()
+        .filter_map(|neighbor| chunk_map.block_at(neighbor))
+        .map(|block| match block.simplified_kind() {
+            SimplifiedBlockKind::Log => 0,
+            SimplifiedBlockKind::Leaves => block.distance().unwrap_or(MAX_DISTANCE),
+            _ => MAX_DISTANCE,
+        })
+        .min()
+        .map_or(MAX_DISTANCE, |distance| (distance + 1).min(MAX_DISTANCE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_test_framework::Test;
+
+    fn leaves(distance: i32) -> BlockId {
+        BlockId::oak_leaves()
+            .with_distance(distance)
+            .with_persistent(false)
+    }
+
+    fn set(test: &mut Test, pos: BlockPosition, block: BlockId) {
+        let old = test.game.chunk_map.block_at(pos).unwrap();
+        test.game.chunk_map.set_block_at(pos, block);
+        let event = BlockUpdateEvent {
+            pos,
+            old,
+            new: block,
+            cause: BlockUpdateCause::Unknown,
+        };
+        test.handle(event, on_block_update_schedule_leaves);
+    }
+
+    fn run_ticks(test: &mut Test, ticks: u64) {
+        for _ in 0..ticks {
+            test.game.tick_count += 1;
+            let due = test
+                .game
+                .scheduled_ticks
+                .take_due(test.game.tick_count, usize::MAX);
+            for tick in due {
+                let old = test.game.chunk_map.block_at(tick.pos).unwrap();
+                let event = ScheduledTickEvent {
+                    pos: tick.pos,
+                    block: old,
+                };
+                test.handle(event, on_scheduled_tick_update_leaves);
+
+                // Changed leaves update their neighbours in turn.
+                let new = test.game.chunk_map.block_at(tick.pos).unwrap();
+                if new != old {
+                    let event = BlockUpdateEvent {
+                        pos: tick.pos,
+                        old,
+                        new,
+                        cause: BlockUpdateCause::Unknown,
+                    };
+                    test.handle(event, on_block_update_schedule_leaves);
+                }
+            }
+        }
+    }
+
+    fn distances(test: &Test) -> Vec<i32> {
+        (1..=9)
+            .map(|x| {
+                let pos = BlockPosition::new(x, 64, 0);
+                test.game
+                    .chunk_map
+                    .block_at(pos)
+                    .unwrap()
+                    .distance()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_distance_updates() {
+        let mut test = Test::new();
+        for x in 1..=9 {
+            set(
+                &mut test,
+                BlockPosition::new(x, 64, 0),
+                leaves(MAX_DISTANCE),
+            );
+        }
+        set(&mut test, BlockPosition::new(0, 64, 0), BlockId::oak_log());
+        run_ticks(&mut test, 20);
+        assert_eq!(distances(&test), vec![1, 2, 3, 4, 5, 6, 7, 7, 7]);
+        assert!(test.game.scheduled_ticks.is_empty());
+
+        set(&mut test, BlockPosition::new(0, 64, 0), BlockId::air());
+        run_ticks(&mut test, 50);
+        assert_eq!(distances(&test), vec![7; 9]);
+    }
+
+    #[test]
+    fn test_should_decay() {
+        assert!(should_decay(leaves(MAX_DISTANCE)));
+        assert!(!should_decay(leaves(6)));
+        assert!(!should_decay(leaves(MAX_DISTANCE).with_persistent(true)));
+        assert!(!should_decay(BlockId::oak_log()));
+    }
+
+    #[test]
+    fn test_decay() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(0, 64, 0);
+        test.game.chunk_map.set_block_at(pos, leaves(6));
+        decay(&mut test.game, &mut test.world, pos, leaves(6));
+        assert_eq!(test.game.chunk_map.block_at(pos), Some(leaves(6)));
+
+        test.game.chunk_map.set_block_at(pos, leaves(MAX_DISTANCE));
+        decay(&mut test.game, &mut test.world, pos, leaves(MAX_DISTANCE));
+        assert_eq!(test.game.chunk_map.block_at(pos), Some(BlockId::air()));
+    }
+}
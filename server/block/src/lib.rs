@@ -1,20 +1,41 @@
 #![forbid(unsafe_code)]
 
+pub mod banner;
+pub mod bed;
+pub mod brewing_stand;
 pub mod chest;
+pub mod comparator;
+pub mod container;
+pub mod dispenser;
+pub mod furnace;
+pub mod hopper;
 mod init;
+pub mod leaves;
+mod random_tick;
+pub mod redstone;
+pub mod sign;
+pub mod skull;
 
 pub use chest::{
     on_chest_break_drop_contents, on_chest_break_try_disconnect, on_chest_close_decrement_viewers,
     on_chest_create_try_connect, on_chest_open_increment_viewers,
 };
+pub use container::on_container_break_drop_contents;
 use feather_core::{
-    anvil::block_entity::BlockEntityBase,
+    anvil::block_entity::{BlockEntityBase, BlockEntityData},
     blocks::BlockId,
+    network::packets::UpdateBlockEntity,
     util::{BlockPosition, Position},
 };
 use feather_server_types::BlockEntity;
 use fecs::{EntityBuilder, EntityRef};
+pub use furnace::{on_furnace_close_remove_viewer, tick_furnaces};
 pub use init::{on_block_entity_create_insert_to_map, on_block_update_create_block_entity};
+pub use leaves::{on_block_update_schedule_leaves, on_scheduled_tick_update_leaves};
+pub use random_tick::random_tick_blocks;
+pub use redstone::{
+    on_block_update_update_redstone, on_scheduled_tick_update_redstone, update_comparators,
+};
 
 /// A function which determines whether a given change between
 /// block states should cause a block entity to be destroyed/recreated.
@@ -24,6 +45,13 @@ pub use init::{on_block_entity_create_insert_to_map, on_block_update_create_bloc
 /// if it should be replaced with a block entity for the new block.
 pub struct ShouldReplace(pub fn(BlockId, BlockId) -> bool);
 
+/// A `ShouldReplace` function which replaces the block entity
+/// only if the block changes to a different kind, ignoring
+/// properties such as facing and color.
+fn kind_changed(old: BlockId, new: BlockId) -> bool {
+    old.simplified_kind() != new.simplified_kind()
+}
+
 /// Returns the base components all block entities have.
 fn base(pos: BlockPosition) -> EntityBuilder {
     EntityBuilder::new()
@@ -44,3 +72,21 @@ fn serialize_base(accessor: &EntityRef) -> BlockEntityBase {
 fn load_base(data: &BlockEntityBase) -> BlockPosition {
     BlockPosition::new(data.x, data.y, data.z)
 }
+
+/// Creates an `UpdateBlockEntity` packet which sends the given
+/// block entity data to clients.
+fn update_packet(action: u8, data: &BlockEntityData) -> UpdateBlockEntity {
+    let mut bytes = Vec::new();
+    let blob = nbt::to_writer(&mut bytes, data, None)
+        .and_then(|_| nbt::Blob::from_reader(&mut bytes.as_slice()))
+        .unwrap_or_else(|e| {
+            log::warn!("Failed to encode block entity data: {}", e);
+            nbt::Blob::new()
+        });
+
+    UpdateBlockEntity {
+        location: load_base(&data.base),
+        action,
+        data: blob,
+    }
+}
@@ -0,0 +1,647 @@
+//! Random block ticks, which make crops and saplings grow,
+//! grass and mycelium spread, ice and snow melt and leaves decay.
+//!
+//! Each game tick, `randomTickSpeed` positions are chosen at random
+//! in every non-empty chunk section of the loaded chunks near players,
+//! and the blocks at them are ticked. Most blocks ignore random ticks.
+
+use crate::leaves;
+use feather_core::blocks::{BlockId, BlockKind, SimplifiedBlockKind};
+use feather_core::chunk_map::ChunkMap;
+use feather_core::util::{BlockPosition, ChunkPosition, Position};
+use feather_server_types::{BlockUpdateCause, Game, Player};
+use feather_server_worldgen::sapling_tree;
+use fecs::{component, IntoQuery, Read, World};
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Radius, in chunks, around each player in which blocks are random ticked.
+const TICK_CHUNK_RADIUS: i32 = 8;
+
+/// Minimum light level above crops and saplings for them to grow,
+/// and above grass and mycelium for them to spread.
+const MIN_GROWTH_LIGHT: u8 = 9;
+
+/// Minimum light level above dirt for grass and mycelium to spread to it.
+const MIN_SPREAD_LIGHT: u8 = 4;
+
+/// Ice and snow layers melt when the block light at them exceeds this.
+const MELT_LIGHT: u8 = 11;
+
+/// System which ticks `randomTickSpeed` random blocks
+/// in each chunk section near a player.
+#[fecs::system]
+pub fn random_tick_blocks(game: &mut Game, world: &mut World) {
+    let speed = game.level.game_rules.random_tick_speed();
+    if speed == 0 {
+        return;
+    }
+
+    let players: Vec<Position> = <Read<Position>>::query()
+        .filter(component::<Player>())
+        .iter(world.inner())
+        .map(|pos| *pos)
+        .collect();
+    let chunks = ticking_chunks(&game.chunk_map, &players);
+
+    let chosen = choose_blocks(&game.chunk_map, &chunks, speed, &mut *game.rng());
+    for (pos, block) in chosen {
+        // An earlier random tick may have changed the block.
+        if game.block_at(pos) == Some(block) {
+            random_tick(game, world, pos, block);
+        }
+    }
+}
+
+/// Returns the loaded chunks within `TICK_CHUNK_RADIUS` of a player.
+fn ticking_chunks(chunk_map: &ChunkMap, players: &[Position]) -> HashSet<ChunkPosition> {
+    let mut chunks = HashSet::new();
+    for player in players {
+        let center = player.chunk();
+        for dx in -TICK_CHUNK_RADIUS..=TICK_CHUNK_RADIUS {
+            for dz in -TICK_CHUNK_RADIUS..=TICK_CHUNK_RADIUS {
+                let chunk = ChunkPosition::new(center.x + dx, center.z + dz);
+                if chunk_map.chunk_at(chunk).is_some() {
+                    chunks.insert(chunk);
+                }
+            }
+        }
+    }
+    chunks
+}
+
+/// Chooses `speed` random positions in each non-empty chunk section
+/// of `chunks` and returns those containing blocks which have
+/// random tick behavior.
+fn choose_blocks(
+    chunk_map: &ChunkMap,
+    chunks: &HashSet<ChunkPosition>,
+    speed: u32,
+    rng: &mut impl Rng,
+) -> Vec<(BlockPosition, BlockId)> {
+    let mut chosen = vec![];
+
+    for chunk_pos in chunks {
+        let chunk = match chunk_map.chunk_at(*chunk_pos) {
+            Some(chunk) => chunk,
+            None => continue,
+        };
+        let chunk_pos = chunk.position();
+
+        for (section_y, section) in chunk.sections().into_iter().enumerate() {
+            let section = match section {
+                Some(section) if !section.empty() => section,
+                _ => continue,
+            };
+
+            for _ in 0..speed {
+                let (x, y, z) = (
+                    rng.gen_range(0, 16),
+                    rng.gen_range(0, 16),
+                    rng.gen_range(0, 16),
+                );
+                let block = section.block_at(x, y, z);
+                if ticks_randomly(block) {
+                    let pos = BlockPosition::new(
+                        chunk_pos.x * 16 + x as i32,
+                        (section_y * 16 + y) as i32,
+                        chunk_pos.z * 16 + z as i32,
+                    );
+                    chosen.push((pos, block));
+                }
+            }
+        }
+    }
+
+    chosen
+}
+
+/// Returns whether the given block does anything on a random tick.
+fn ticks_randomly(block: BlockId) -> bool {
+    match block.kind() {
+        BlockKind::Wheat
+        | BlockKind::Carrots
+        | BlockKind::Potatoes
+        | BlockKind::OakSapling
+        | BlockKind::SpruceSapling
+        | BlockKind::BirchSapling
+        | BlockKind::JungleSapling
+        | BlockKind::AcaciaSapling
+        | BlockKind::DarkOakSapling
+        | BlockKind::GrassBlock
+        | BlockKind::Mycelium
+        | BlockKind::Ice
+        | BlockKind::Snow => true,
+        _ => leaves::should_decay(block),
+    }
+}
+
+/// Dispatches a random tick to the ticked block's handler.
+fn random_tick(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    match block.kind() {
+        BlockKind::Wheat | BlockKind::Carrots | BlockKind::Potatoes => {
+            grow_crop(game, world, pos, block)
+        }
+        BlockKind::OakSapling
+        | BlockKind::SpruceSapling
+        | BlockKind::BirchSapling
+        | BlockKind::JungleSapling
+        | BlockKind::AcaciaSapling
+        | BlockKind::DarkOakSapling => grow_sapling(game, world, pos, block),
+        BlockKind::GrassBlock | BlockKind::Mycelium => spread_grass(game, world, pos, block),
+        BlockKind::Ice => melt_ice(game, world, pos),
+        BlockKind::Snow => melt_snow(game, world, pos),
+        _ => leaves::decay(game, world, pos, block),
+    }
+}
+
+/// Returns the light level at the given position, ignoring
+/// how the sky light is reduced at night.
+fn light_at(game: &Game, pos: BlockPosition) -> u8 {
+    game.chunk_map
+        .light_at(pos)
+        .map(|(sky, block)| sky.max(block))
+        .unwrap_or(0)
+}
+
+fn block_light_at(game: &Game, pos: BlockPosition) -> u8 {
+    game.chunk_map
+        .light_at(pos)
+        .map(|(_, block)| block)
+        .unwrap_or(0)
+}
+
+fn offset(pos: BlockPosition, dx: i32, dy: i32, dz: i32) -> BlockPosition {
+    BlockPosition::new(pos.x + dx, pos.y + dy, pos.z + dz)
+}
+
+/// Ages wheat, carrots and potatoes, with a chance
+/// depending on the farmland they are planted in.
+fn grow_crop(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    if light_at(game, pos.up()) < MIN_GROWTH_LIGHT {
+        return;
+    }
+    let age = match block.age_0_7() {
+        Some(age) if age < 7 => age,
+        _ => return,
+    };
+
+    let chance = crop_growth_chance(&game.chunk_map, pos, block.kind());
+    if game.rng().gen_range(0, (25.0 / chance) as i32 + 1) == 0 {
+        game.set_block_at(
+            world,
+            pos,
+            block.with_age_0_7(age + 1),
+            BlockUpdateCause::Unknown,
+        );
+    }
+}
+
+/// Returns how fast a crop grows, from 1 up to 10 for a crop
+/// on moist farmland surrounded by moist farmland. Crops grow
+/// half as fast when planted next to crops of the same kind
+/// in more than a single row.
+fn crop_growth_chance(chunk_map: &ChunkMap, pos: BlockPosition, kind: BlockKind) -> f32 {
+    let mut chance = 1.0;
+
+    for dx in -1..=1 {
+        for dz in -1..=1 {
+            let soil = chunk_map.block_at(offset(pos, dx, -1, dz));
+            let mut soil_chance = match soil {
+                Some(soil) if soil.kind() == BlockKind::Farmland => {
+                    if soil.moisture().unwrap_or(0) > 0 {
+                        3.0
+                    } else {
+                        1.0
+                    }
+                }
+                _ => 0.0,
+            };
+            if dx != 0 || dz != 0 {
+                soil_chance /= 4.0;
+            }
+            chance += soil_chance;
+        }
+    }
+
+    let same_kind = |dx, dz| {
+        chunk_map
+            .block_at(offset(pos, dx, 0, dz))
+            .map(|block| block.kind() == kind)
+            .unwrap_or(false)
+    };
+    let row_x = same_kind(-1, 0) || same_kind(1, 0);
+    let row_z = same_kind(0, -1) || same_kind(0, 1);
+    let diagonal = same_kind(-1, -1) || same_kind(1, -1) || same_kind(1, 1) || same_kind(-1, 1);
+    if (row_x && row_z) || diagonal {
+        chance /= 2.0;
+    }
+
+    chance
+}
+
+/// Advances a sapling's growth stage, growing
+/// it into a tree once it is fully grown.
+fn grow_sapling(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    if light_at(game, pos.up()) < MIN_GROWTH_LIGHT || game.rng().gen_range(0, 7) != 0 {
+        return;
+    }
+
+    if block.stage() == Some(0) {
+        game.set_block_at(world, pos, block.with_stage(1), BlockUpdateCause::Unknown);
+    } else {
+        grow_tree(game, world, pos, block.kind());
+    }
+}
+
+/// Replaces a sapling with a tree if there is room for its trunk.
+fn grow_tree(game: &mut Game, world: &mut World, pos: BlockPosition, sapling: BlockKind) {
+    // Dark oak trees only grow from a 2x2 square of saplings.
+    let (origin, saplings) = if sapling == BlockKind::DarkOakSapling {
+        match dark_oak_square(&game.chunk_map, pos) {
+            Some(square) => square,
+            None => return,
+        }
+    } else {
+        (pos, vec![pos])
+    };
+
+    let seed = game.rng().gen();
+    let blocks = match sapling_tree(sapling, origin, seed) {
+        Some(blocks) => blocks,
+        None => return,
+    };
+
+    let has_room = blocks
+        .iter()
+        .filter(|(_, block)| block.simplified_kind() == SimplifiedBlockKind::Log)
+        .all(|(log_pos, _)| {
+            saplings.contains(log_pos)
+                || game
+                    .block_at(*log_pos)
+                    .map(|block| {
+                        block.is_replaceable()
+                            || block.simplified_kind() == SimplifiedBlockKind::Leaves
+                    })
+                    .unwrap_or(false)
+        });
+    if !has_room {
+        return;
+    }
+
+    for (block_pos, block) in blocks {
+        // Leaves don't replace existing blocks.
+        let is_leaves = block.simplified_kind() == SimplifiedBlockKind::Leaves;
+        if is_leaves && game.block_at(block_pos).map(BlockId::is_air) != Some(true) {
+            continue;
+        }
+        game.set_block_at(world, block_pos, block, BlockUpdateCause::Unknown);
+    }
+}
+
+/// Finds a 2x2 square of dark oak saplings containing `pos`.
+/// Returns the corner with the lowest coordinates and
+/// the positions of the saplings in the square.
+fn dark_oak_square(
+    chunk_map: &ChunkMap,
+    pos: BlockPosition,
+) -> Option<(BlockPosition, Vec<BlockPosition>)> {
+    [(0, 0), (-1, 0), (0, -1), (-1, -1)]
+        .iter()
+        .map(|(dx, dz)| offset(pos, *dx, 0, *dz))
+        .find_map(|corner| {
+            let square: Vec<_> = [(0, 0), (1, 0), (0, 1), (1, 1)]
+                .iter()
+                .map(|(dx, dz)| offset(corner, *dx, 0, *dz))
+                .collect();
+            let all_saplings = square.iter().all(|pos| {
+                chunk_map.block_at(*pos).map(BlockId::kind) == Some(BlockKind::DarkOakSapling)
+            });
+            if all_saplings {
+                Some((corner, square))
+            } else {
+                None
+            }
+        })
+}
+
+/// Spreads grass and mycelium to nearby lit dirt, or turns
+/// the block into dirt if it is covered by an opaque block.
+fn spread_grass(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    if !can_stay_grass(&game.chunk_map, pos) {
+        game.set_block_at(world, pos, BlockId::dirt(), BlockUpdateCause::Unknown);
+        return;
+    }
+    if light_at(game, pos.up()) < MIN_GROWTH_LIGHT {
+        return;
+    }
+
+    let spread = match block.kind() {
+        BlockKind::Mycelium => BlockId::mycelium(),
+        _ => BlockId::grass_block(),
+    };
+    for _ in 0..4 {
+        let target = {
+            let mut rng = game.rng();
+            offset(
+                pos,
+                rng.gen_range(-1, 2),
+                rng.gen_range(-3, 2),
+                rng.gen_range(-1, 2),
+            )
+        };
+
+        let is_dirt = game.block_at(target).map(BlockId::kind) == Some(BlockKind::Dirt);
+        if is_dirt
+            && light_at(game, target.up()) >= MIN_SPREAD_LIGHT
+            && can_spread_to(&game.chunk_map, target)
+        {
+            let above = game.block_at(target.up());
+            let snowy = above.map(BlockId::kind) == Some(BlockKind::Snow);
+            game.set_block_at(
+                world,
+                target,
+                spread.with_snowy(snowy),
+                BlockUpdateCause::Unknown,
+            );
+        }
+    }
+}
+
+/// Returns whether grass or mycelium at the given
+/// position may stay, which is not the case below
+/// opaque blocks and snow more than one layer deep.
+fn can_stay_grass(chunk_map: &ChunkMap, pos: BlockPosition) -> bool {
+    match chunk_map.block_at(pos.up()) {
+        Some(above) if above.kind() == BlockKind::Snow => above.layers() == Some(1),
+        Some(above) => !above.is_opaque(),
+        None => true,
+    }
+}
+
+/// Returns whether grass or mycelium may spread to dirt at
+/// the given position. It doesn't spread under water.
+fn can_spread_to(chunk_map: &ChunkMap, pos: BlockPosition) -> bool {
+    let under_water = chunk_map.block_at(pos.up()).map(BlockId::kind) == Some(BlockKind::Water);
+    can_stay_grass(chunk_map, pos) && !under_water
+}
+
+/// Melts ice into water near bright light sources.
+fn melt_ice(game: &mut Game, world: &mut World, pos: BlockPosition) {
+    if block_light_at(game, pos) > MELT_LIGHT {
+        game.set_block_at(world, pos, BlockId::water(), BlockUpdateCause::Unknown);
+    }
+}
+
+/// Melts snow layers near bright light sources.
+fn melt_snow(game: &mut Game, world: &mut World, pos: BlockPosition) {
+    if block_light_at(game, pos) > MELT_LIGHT {
+        game.set_block_at(world, pos, BlockId::air(), BlockUpdateCause::Unknown);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::position;
+    use feather_test_framework::Test;
+
+    fn set(test: &Test, pos: BlockPosition, block: BlockId) {
+        test.game.chunk_map.set_block_at(pos, block);
+    }
+
+    fn block(test: &Test, pos: BlockPosition) -> BlockId {
+        test.game.chunk_map.block_at(pos).unwrap()
+    }
+
+    fn set_light(test: &Test, pos: BlockPosition, sky: u8, block_light: u8) {
+        let mut chunk = test.game.chunk_map.chunk_at_mut(pos.chunk()).unwrap();
+        let (x, y, z) = (pos.x as usize & 0xf, pos.y as usize, pos.z as usize & 0xf);
+        chunk.set_sky_light_at(x, y, z, sky);
+        chunk.set_block_light_at(x, y, z, block_light);
+    }
+
+    /// Random ticks the block at `pos` the given number of times.
+    fn tick(test: &mut Test, pos: BlockPosition, times: usize) {
+        for _ in 0..times {
+            let block = block(test, pos);
+            random_tick(&mut test.game, &mut test.world, pos, block);
+        }
+    }
+
+    #[test]
+    fn test_choose_blocks() {
+        let test = Test::new();
+        for x in 0..16 {
+            for y in 64..80 {
+                for z in 0..16 {
+                    set(&test, BlockPosition::new(x, y, z), BlockId::wheat());
+                }
+            }
+        }
+        set(&test, BlockPosition::new(20, 10, 20), BlockId::stone());
+
+        let chunks: HashSet<_> = vec![ChunkPosition::new(0, 0), ChunkPosition::new(1, 1)]
+            .into_iter()
+            .collect();
+        let chosen = choose_blocks(&test.game.chunk_map, &chunks, 3, &mut rand::thread_rng());
+        assert_eq!(chosen.len(), 3);
+        for (pos, block) in chosen {
+            assert_eq!(block, BlockId::wheat());
+            assert!((64..80).contains(&pos.y));
+            assert_eq!(test.game.chunk_map.block_at(pos), Some(block));
+        }
+
+        let chosen = choose_blocks(&test.game.chunk_map, &chunks, 0, &mut rand::thread_rng());
+        assert!(chosen.is_empty());
+
+        // Chunks which aren't near a player aren't ticked.
+        let far: HashSet<_> = vec![ChunkPosition::new(1, 1)].into_iter().collect();
+        assert!(choose_blocks(&test.game.chunk_map, &far, 3, &mut rand::thread_rng()).is_empty());
+    }
+
+    #[test]
+    fn test_ticking_chunks() {
+        let test = Test::new();
+        let chunk_map = &test.game.chunk_map;
+
+        assert!(ticking_chunks(chunk_map, &[]).is_empty());
+
+        let near = ticking_chunks(chunk_map, &[position!(0.0, 64.0, 0.0)]);
+        assert!(near.contains(&ChunkPosition::new(0, 0)));
+        assert!(near.contains(&ChunkPosition::new(-1, 1)));
+        assert!(near
+            .iter()
+            .all(|chunk| chunk_map.chunk_at(*chunk).is_some()));
+
+        let far = ticking_chunks(chunk_map, &[position!(16.0 * 9.0, 64.0, 0.0)]);
+        assert!(!far.contains(&ChunkPosition::new(0, 0)));
+        assert!(far.contains(&ChunkPosition::new(1, 0)));
+    }
+
+    #[test]
+    fn test_crop_growth() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        set(&test, pos.down(), BlockId::farmland().with_moisture(7));
+        set(&test, pos, BlockId::wheat());
+
+        // Crops don't grow in the dark.
+        tick(&mut test, pos, 200);
+        assert_eq!(block(&test, pos), BlockId::wheat());
+
+        set_light(&test, pos.up(), 15, 0);
+        tick(&mut test, pos, 1000);
+        assert_eq!(block(&test, pos), BlockId::wheat().with_age_0_7(7));
+    }
+
+    #[test]
+    fn test_crop_growth_chance() {
+        let test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        let chance =
+            |test: &Test| crop_growth_chance(&test.game.chunk_map, pos, BlockKind::Carrots);
+        assert_eq!(chance(&test), 1.0);
+
+        for dx in -1..=1 {
+            for dz in -1..=1 {
+                let soil = BlockId::farmland().with_moisture(7);
+                set(&test, offset(pos, dx, -1, dz), soil);
+            }
+        }
+        assert_eq!(chance(&test), 10.0);
+
+        // A single row of crops doesn't slow down growth.
+        set(&test, offset(pos, 1, 0, 0), BlockId::carrots());
+        set(&test, offset(pos, -1, 0, 0), BlockId::carrots());
+        assert_eq!(chance(&test), 10.0);
+
+        set(&test, offset(pos, 0, 0, 1), BlockId::carrots());
+        assert_eq!(chance(&test), 5.0);
+    }
+
+    #[test]
+    fn test_sapling_grows() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        set(&test, pos.down(), BlockId::grass_block());
+        set(&test, pos, BlockId::oak_sapling());
+        set_light(&test, pos.up(), 15, 0);
+
+        tick(&mut test, pos, 500);
+
+        assert_eq!(block(&test, pos.down()), BlockId::dirt());
+        assert_eq!(block(&test, pos), BlockId::oak_log());
+        assert_eq!(block(&test, pos.up()), BlockId::oak_log());
+    }
+
+    #[test]
+    fn test_dark_oak_needs_four_saplings() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        for (dx, dz) in &[(0, 0), (1, 0), (0, 1)] {
+            set(&test, offset(pos, *dx, 0, *dz), BlockId::dark_oak_sapling());
+        }
+        set_light(&test, pos.up(), 15, 0);
+
+        tick(&mut test, pos, 500);
+        assert_eq!(block(&test, pos).kind(), BlockKind::DarkOakSapling);
+
+        set(&test, offset(pos, 1, 0, 1), BlockId::dark_oak_sapling());
+        tick(&mut test, pos, 500);
+        for (dx, dz) in &[(0, 0), (1, 0), (0, 1), (1, 1)] {
+            assert_eq!(
+                block(&test, offset(pos, *dx, 0, *dz)),
+                BlockId::dark_oak_log()
+            );
+        }
+    }
+
+    #[test]
+    fn test_grass_spreads() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        for dx in -1..=1 {
+            for dz in -1..=1 {
+                set(&test, offset(pos, dx, 0, dz), BlockId::dirt());
+            }
+        }
+        set(&test, pos, BlockId::grass_block());
+        set(&test, offset(pos, 1, 1, 0), BlockId::stone());
+        for dx in -1..=1 {
+            for dz in -1..=1 {
+                set_light(&test, offset(pos, dx, 1, dz), 15, 0);
+            }
+        }
+
+        tick(&mut test, pos, 200);
+
+        for dx in -1..=1 {
+            for dz in -1..=1 {
+                let expected = if (dx, dz) == (1, 0) {
+                    // Covered by stone.
+                    BlockId::dirt()
+                } else {
+                    BlockId::grass_block()
+                };
+                assert_eq!(block(&test, offset(pos, dx, 0, dz)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_grass_needs_light_to_spread() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        let dark = offset(pos, 1, 0, 0);
+        let dim = offset(pos, -1, 0, 0);
+        set(&test, pos, BlockId::grass_block());
+        set(&test, dark, BlockId::dirt());
+        set(&test, dim, BlockId::dirt());
+        set_light(&test, pos.up(), 15, 0);
+        set_light(&test, dark.up(), 0, 3);
+        set_light(&test, dim.up(), 0, 4);
+
+        tick(&mut test, pos, 200);
+
+        assert_eq!(block(&test, dark), BlockId::dirt());
+        assert_eq!(block(&test, dim), BlockId::grass_block());
+    }
+
+    #[test]
+    fn test_grass_dies_when_covered() {
+        let mut test = Test::new();
+        let pos = BlockPosition::new(8, 64, 8);
+        set(&test, pos, BlockId::mycelium());
+        set(&test, pos.up(), BlockId::snow().with_layers(1));
+        tick(&mut test, pos, 1);
+        assert_eq!(block(&test, pos), BlockId::mycelium());
+
+        set(&test, pos.up(), BlockId::stone());
+        tick(&mut test, pos, 1);
+        assert_eq!(block(&test, pos), BlockId::dirt());
+    }
+
+    #[test]
+    fn test_ice_and_snow_melt() {
+        let mut test = Test::new();
+        let ice = BlockPosition::new(8, 64, 8);
+        let snow = BlockPosition::new(10, 64, 8);
+        set(&test, ice, BlockId::ice());
+        set(&test, snow, BlockId::snow());
+        set_light(&test, ice, 15, 11);
+        set_light(&test, snow, 15, 11);
+
+        tick(&mut test, ice, 1);
+        tick(&mut test, snow, 1);
+        assert_eq!(block(&test, ice), BlockId::ice());
+        assert_eq!(block(&test, snow), BlockId::snow());
+
+        set_light(&test, ice, 0, 12);
+        set_light(&test, snow, 0, 12);
+        tick(&mut test, ice, 1);
+        tick(&mut test, snow, 1);
+        assert_eq!(block(&test, ice), BlockId::water());
+        assert!(block(&test, snow).is_air());
+    }
+}
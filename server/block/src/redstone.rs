@@ -0,0 +1,638 @@
+//! Redstone power.
+//!
+//! Power is computed on demand from the blocks around a position.
+//! Sources such as levers, torches and redstone blocks emit power
+//! to their neighbours, and opaque full blocks conduct the power
+//! they receive strongly to their own neighbours.
+//!
+//! When a block changes, the redstone components near it are added
+//! to the queue in `Game::redstone`. The queue is processed in a loop
+//! rather than recursively, so large circuits can't overflow the stack.
+//! Wire updates instantly, recomputing the power of a whole network of
+//! wire at once; torches, repeaters, comparators, lamps turning off,
+//! buttons and pistons act after a delay using scheduled ticks.
+
+mod diode;
+mod piston;
+mod wire;
+
+use feather_core::blocks::{BlockId, BlockKind, Face, FacingCubic, SimplifiedBlockKind};
+use feather_core::util::BlockPosition;
+use feather_server_types::{
+    BlockUpdateCause, BlockUpdateEvent, Game, InteractionHandler, ScheduledTickEvent,
+};
+use fecs::{Entity, World};
+
+pub use diode::update_comparators;
+
+/// All directions, in the order vanilla checks them in.
+const DIRECTIONS: [FacingCubic; 6] = [
+    FacingCubic::West,
+    FacingCubic::East,
+    FacingCubic::Down,
+    FacingCubic::Up,
+    FacingCubic::North,
+    FacingCubic::South,
+];
+
+const HORIZONTAL: [FacingCubic; 4] = [
+    FacingCubic::North,
+    FacingCubic::East,
+    FacingCubic::South,
+    FacingCubic::West,
+];
+
+/// The highest power level.
+const MAX_POWER: u8 = 15;
+
+/// Delay before a torch toggles, in game ticks.
+const TORCH_DELAY: u64 = 2;
+/// A torch which turns off this many times within
+/// `BURNOUT_WINDOW` ticks burns out.
+const BURNOUT_TOGGLES: usize = 8;
+const BURNOUT_WINDOW: u64 = 60;
+/// Delay after which a burnt out torch tries to light again.
+const BURNOUT_DELAY: u64 = 160;
+/// Delay before an unpowered lamp turns off.
+const LAMP_DELAY: u64 = 4;
+/// Number of game ticks buttons stay pressed for.
+const STONE_BUTTON_DELAY: u64 = 20;
+const WOODEN_BUTTON_DELAY: u64 = 30;
+
+/// When a block changes, updates the redstone components near it.
+#[fecs::event_handler]
+pub fn on_block_update_update_redstone(
+    event: &BlockUpdateEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    queue_around(game, event.pos);
+    process_updates(game, world);
+}
+
+/// Runs the delayed actions of redstone components.
+#[fecs::event_handler]
+pub fn on_scheduled_tick_update_redstone(
+    event: &ScheduledTickEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    let (pos, block) = (event.pos, event.block);
+    match block.simplified_kind() {
+        SimplifiedBlockKind::RedstoneTorch | SimplifiedBlockKind::RedstoneWallTorch => {
+            tick_torch(game, world, pos, block)
+        }
+        SimplifiedBlockKind::Repeater => diode::tick_repeater(game, world, pos, block),
+        SimplifiedBlockKind::Comparator => diode::tick_comparator(game, world, pos, block),
+        SimplifiedBlockKind::RedstoneLamp => {
+            if block.lit() == Some(true) && !is_powered(game, world, pos) {
+                set(game, world, pos, block.with_lit(false));
+            }
+        }
+        SimplifiedBlockKind::StoneButton | SimplifiedBlockKind::WoodenButton => {
+            if block.powered() == Some(true) {
+                set(game, world, pos, block.with_powered(false));
+            }
+        }
+        SimplifiedBlockKind::Piston | SimplifiedBlockKind::StickyPiston => {
+            piston::tick(game, world, pos, block)
+        }
+        _ => (),
+    }
+}
+
+/// Queues updates for the redstone components which may be
+/// affected by a change at `pos`: those next to it, and those
+/// powered through the blocks next to it.
+fn queue_around(game: &mut Game, pos: BlockPosition) {
+    queue(game, pos);
+    for dir in &DIRECTIONS {
+        let neighbor = offset(pos, *dir);
+        queue(game, neighbor);
+        for dir in &DIRECTIONS {
+            queue(game, offset(neighbor, *dir));
+        }
+    }
+}
+
+fn queue(game: &mut Game, pos: BlockPosition) {
+    if game.block_at(pos).map_or(false, is_component) {
+        game.redstone.push(pos);
+    }
+}
+
+/// Processes queued updates until the queue is empty, unless
+/// it is already being processed further up the call stack.
+fn process_updates(game: &mut Game, world: &mut World) {
+    if !game.redstone.begin_processing() {
+        return;
+    }
+
+    while let Some(pos) = game.redstone.pop() {
+        update(game, world, pos);
+    }
+
+    game.redstone.end_processing();
+}
+
+/// Runs `f`, only processing the updates
+/// it causes once it has returned.
+fn batch(game: &mut Game, world: &mut World, f: impl FnOnce(&mut Game, &mut World)) {
+    let outermost = game.redstone.begin_processing();
+    f(game, world);
+    if outermost {
+        game.redstone.end_processing();
+        process_updates(game, world);
+    }
+}
+
+/// Updates the state of the redstone component at `pos`
+/// after the power around it may have changed.
+fn update(game: &mut Game, world: &mut World, pos: BlockPosition) {
+    let block = match game.block_at(pos) {
+        Some(block) => block,
+        None => return,
+    };
+
+    match block.simplified_kind() {
+        SimplifiedBlockKind::RedstoneWire => wire::update_network(game, world, pos),
+        SimplifiedBlockKind::RedstoneTorch | SimplifiedBlockKind::RedstoneWallTorch => {
+            // A torch turns off when the block it is attached to is powered.
+            let powered = is_torch_powered(game, world, pos, block);
+            if block.lit() == Some(powered) {
+                schedule(game, pos, block, TORCH_DELAY, 0);
+            }
+        }
+        SimplifiedBlockKind::Repeater => diode::update_repeater(game, world, pos, block),
+        SimplifiedBlockKind::Comparator => diode::update_comparator(game, world, pos, block),
+        SimplifiedBlockKind::RedstoneLamp => {
+            let powered = is_powered(game, world, pos);
+            if powered && block.lit() == Some(false) {
+                set(game, world, pos, block.with_lit(true));
+            } else if !powered && block.lit() == Some(true) {
+                schedule(game, pos, block, LAMP_DELAY, 0);
+            }
+        }
+        SimplifiedBlockKind::Piston | SimplifiedBlockKind::StickyPiston => {
+            piston::update(game, world, pos, block)
+        }
+        SimplifiedBlockKind::PistonHead => piston::update_head(game, world, pos, block),
+        _ => (),
+    }
+}
+
+fn tick_torch(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    let powered = is_torch_powered(game, world, pos, block);
+    let now = game.tick_count;
+    if block.lit() == Some(true) {
+        if powered {
+            set(game, world, pos, block.with_lit(false));
+            let toggles = game.redstone.record_torch_toggle(pos, now, BURNOUT_WINDOW);
+            if toggles >= BURNOUT_TOGGLES {
+                // Burnt out; try again once the toggles have expired.
+                schedule(game, pos, block.with_lit(false), BURNOUT_DELAY, 0);
+            }
+        }
+    } else if !powered && game.redstone.torch_toggles(pos, now, BURNOUT_WINDOW) < BURNOUT_TOGGLES {
+        set(game, world, pos, block.with_lit(true));
+    }
+}
+
+fn is_torch_powered(game: &Game, world: &World, pos: BlockPosition, block: BlockId) -> bool {
+    match facing_away(block) {
+        Some(facing) => power_from(game, world, pos, facing.opposite(), true) > 0,
+        None => false,
+    }
+}
+
+/// Returns whether the block is updated by `update`.
+fn is_component(block: BlockId) -> bool {
+    matches!(
+        block.simplified_kind(),
+        SimplifiedBlockKind::RedstoneWire
+            | SimplifiedBlockKind::RedstoneTorch
+            | SimplifiedBlockKind::RedstoneWallTorch
+            | SimplifiedBlockKind::Repeater
+            | SimplifiedBlockKind::Comparator
+            | SimplifiedBlockKind::RedstoneLamp
+            | SimplifiedBlockKind::Piston
+            | SimplifiedBlockKind::StickyPiston
+            | SimplifiedBlockKind::PistonHead
+    )
+}
+
+/// Returns whether the block emits power, either
+/// by itself or from power it receives.
+fn is_power_source(block: BlockId) -> bool {
+    matches!(
+        block.simplified_kind(),
+        SimplifiedBlockKind::RedstoneWire
+            | SimplifiedBlockKind::RedstoneTorch
+            | SimplifiedBlockKind::RedstoneWallTorch
+            | SimplifiedBlockKind::RedstoneBlock
+            | SimplifiedBlockKind::Lever
+            | SimplifiedBlockKind::StoneButton
+            | SimplifiedBlockKind::WoodenButton
+            | SimplifiedBlockKind::Repeater
+            | SimplifiedBlockKind::Comparator
+    )
+}
+
+/// Returns whether the block conducts power it receives
+/// strongly, i.e. whether it is an opaque full block.
+fn is_conductor(block: BlockId) -> bool {
+    block.is_opaque() && block.is_full_block() && !is_power_source(block)
+}
+
+fn offset(pos: BlockPosition, dir: FacingCubic) -> BlockPosition {
+    pos + dir.offset()
+}
+
+/// For torches, levers and buttons, returns the direction from
+/// the block they are attached to towards the component.
+fn facing_away(block: BlockId) -> Option<FacingCubic> {
+    match block.simplified_kind() {
+        SimplifiedBlockKind::RedstoneTorch => Some(FacingCubic::Up),
+        SimplifiedBlockKind::RedstoneWallTorch => block
+            .facing_cardinal()
+            .map(|facing| facing.to_facing_cubic()),
+        SimplifiedBlockKind::Lever
+        | SimplifiedBlockKind::StoneButton
+        | SimplifiedBlockKind::WoodenButton => match block.face()? {
+            Face::Floor => Some(FacingCubic::Up),
+            Face::Ceiling => Some(FacingCubic::Down),
+            Face::Wall => block
+                .facing_cardinal()
+                .map(|facing| facing.to_facing_cubic()),
+        },
+        _ => None,
+    }
+}
+
+/// Returns the power the block at `pos` emits to the neighbour
+/// for which it is in direction `side`. Only strong power is
+/// conducted by opaque blocks to their own neighbours.
+fn emitted_power(
+    game: &Game,
+    world: &World,
+    pos: BlockPosition,
+    block: BlockId,
+    side: FacingCubic,
+    strong: bool,
+) -> u8 {
+    match block.simplified_kind() {
+        SimplifiedBlockKind::RedstoneWire => wire::emitted_power(game, pos, block, side),
+        SimplifiedBlockKind::RedstoneBlock if !strong => MAX_POWER,
+        SimplifiedBlockKind::RedstoneTorch | SimplifiedBlockKind::RedstoneWallTorch => {
+            let powers = if strong {
+                // Torches only power the block above them strongly.
+                side == FacingCubic::Down
+            } else {
+                facing_away(block) != Some(side)
+            };
+            if block.lit() == Some(true) && powers {
+                MAX_POWER
+            } else {
+                0
+            }
+        }
+        SimplifiedBlockKind::Lever
+        | SimplifiedBlockKind::StoneButton
+        | SimplifiedBlockKind::WoodenButton => {
+            // Strong power only goes into the block they are attached to.
+            let powers = !strong || facing_away(block) == Some(side);
+            if block.powered() == Some(true) && powers {
+                MAX_POWER
+            } else {
+                0
+            }
+        }
+        SimplifiedBlockKind::Repeater | SimplifiedBlockKind::Comparator => {
+            diode::emitted_power(game, world, pos, block, side)
+        }
+        _ => 0,
+    }
+}
+
+/// Returns the power the block at `pos` receives from its neighbour
+/// in direction `side`. An opaque neighbour passes on the strong power
+/// it receives. If `wires` is `false`, power from wire is ignored.
+fn power_from(
+    game: &Game,
+    world: &World,
+    pos: BlockPosition,
+    side: FacingCubic,
+    wires: bool,
+) -> u8 {
+    let neighbor = offset(pos, side);
+    let block = match game.block_at(neighbor) {
+        Some(block) => block,
+        None => return 0,
+    };
+
+    if is_conductor(block) {
+        strong_power_into(game, world, neighbor, wires)
+    } else if !wires && block.kind() == BlockKind::RedstoneWire {
+        0
+    } else {
+        emitted_power(game, world, neighbor, block, side, false)
+    }
+}
+
+/// Returns the strong power the block at `pos` receives.
+fn strong_power_into(game: &Game, world: &World, pos: BlockPosition, wires: bool) -> u8 {
+    DIRECTIONS
+        .iter()
+        .filter_map(|side| {
+            let neighbor = offset(pos, *side);
+            let block = game.block_at(neighbor)?;
+            if !wires && block.kind() == BlockKind::RedstoneWire {
+                return None;
+            }
+            Some(emitted_power(game, world, neighbor, block, *side, true))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the highest power the block at `pos` receives from any side.
+pub fn received_power(game: &Game, world: &World, pos: BlockPosition) -> u8 {
+    DIRECTIONS
+        .iter()
+        .map(|side| power_from(game, world, pos, *side, true))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns whether the block at `pos` receives any power.
+pub fn is_powered(game: &Game, world: &World, pos: BlockPosition) -> bool {
+    received_power(game, world, pos) > 0
+}
+
+fn set(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    game.set_block_at(world, pos, block, BlockUpdateCause::Unknown);
+}
+
+fn schedule(game: &mut Game, pos: BlockPosition, block: BlockId, delay: u64, priority: i32) {
+    let due = game.tick_count + delay;
+    game.scheduled_ticks.schedule(pos, block, due, priority);
+}
+
+/// Handler for players right clicking on levers, buttons,
+/// repeaters and comparators.
+pub struct RedstoneInteraction(BlockKind);
+
+inventory::submit!(Box::new(RedstoneInteraction(BlockKind::Lever)) as Box<dyn InteractionHandler>);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::StoneButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::OakButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::SpruceButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::BirchButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::JungleButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::AcaciaButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::DarkOakButton)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::Repeater)) as Box<dyn InteractionHandler>
+);
+inventory::submit!(
+    Box::new(RedstoneInteraction(BlockKind::Comparator)) as Box<dyn InteractionHandler>
+);
+
+impl InteractionHandler for RedstoneInteraction {
+    fn handle_interaction(
+        &self,
+        game: &mut Game,
+        world: &mut World,
+        pos: BlockPosition,
+        _player: Entity,
+        _window_id: u8,
+    ) {
+        let block = match game.block_at(pos) {
+            Some(block) if block.kind() == self.0 => block,
+            _ => return,
+        };
+
+        if let Some(block) = interact(game, pos, block) {
+            set(game, world, pos, block);
+        }
+    }
+
+    fn block_kind(&self) -> BlockKind {
+        self.0
+    }
+}
+
+/// Returns the block a redstone component changes to when a
+/// player right clicks it, scheduling any delayed changes.
+fn interact(game: &mut Game, pos: BlockPosition, block: BlockId) -> Option<BlockId> {
+    match block.simplified_kind() {
+        SimplifiedBlockKind::Lever => Some(block.with_powered(block.powered() != Some(true))),
+        SimplifiedBlockKind::StoneButton | SimplifiedBlockKind::WoodenButton => {
+            if block.powered() == Some(true) {
+                return None;
+            }
+            let delay = if block.simplified_kind() == SimplifiedBlockKind::StoneButton {
+                STONE_BUTTON_DELAY
+            } else {
+                WOODEN_BUTTON_DELAY
+            };
+            let block = block.with_powered(true);
+            schedule(game, pos, block, delay, 0);
+            Some(block)
+        }
+        SimplifiedBlockKind::Repeater => {
+            let delay = block.delay().unwrap_or(1) % 4 + 1;
+            Some(block.with_delay(delay))
+        }
+        SimplifiedBlockKind::Comparator => Some(diode::toggle_mode(block)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{on_block_entity_create_insert_to_map, on_block_update_create_block_entity};
+    use feather_core::blocks::FacingCardinal;
+    use feather_server_types::run_scheduled_ticks;
+    use feather_test_framework::Test;
+    use fecs::EventHandlers;
+    use std::sync::Arc;
+
+    /// Creates a test in which changed blocks cause redstone updates.
+    pub(super) fn setup() -> Test {
+        let mut test = Test::new();
+        let mut event_handlers = EventHandlers::new()
+            .with(on_block_update_create_block_entity)
+            .with(on_block_entity_create_insert_to_map)
+            .with(on_block_update_update_redstone)
+            .with(on_scheduled_tick_update_redstone);
+        event_handlers.set_up(
+            Arc::get_mut(&mut test.game.resources).unwrap(),
+            &mut test.world,
+        );
+        test.game.event_handlers = Arc::new(event_handlers);
+        test
+    }
+
+    pub(super) fn place(test: &mut Test, pos: BlockPosition, block: BlockId) {
+        test.game
+            .set_block_at(&mut test.world, pos, block, BlockUpdateCause::Unknown);
+    }
+
+    pub(super) fn block(test: &Test, pos: BlockPosition) -> BlockId {
+        test.game.block_at(pos).unwrap()
+    }
+
+    pub(super) fn run_ticks(test: &mut Test, ticks: u64) {
+        for _ in 0..ticks {
+            test.game.tick_count += 1;
+            test.run(run_scheduled_ticks);
+        }
+    }
+
+    pub(super) fn power_at(test: &Test, pos: BlockPosition) -> u8 {
+        received_power(&test.game, &test.world, pos)
+    }
+
+    pub(super) fn pos(x: i32, y: i32, z: i32) -> BlockPosition {
+        BlockPosition::new(x, y, z)
+    }
+
+    #[test]
+    fn test_lever_powers_neighbours() {
+        let mut test = setup();
+        place(&mut test, pos(0, 64, -1), BlockId::stone());
+        let lever = BlockId::lever()
+            .with_face(Face::Wall)
+            .with_facing_cardinal(FacingCardinal::South)
+            .with_powered(true);
+        place(&mut test, pos(0, 64, 0), lever);
+
+        assert_eq!(power_at(&test, pos(1, 64, 0)), 15);
+        // The block the lever is attached to is strongly
+        // powered, and so powers its own neighbours.
+        assert_eq!(power_at(&test, pos(0, 64, -1)), 15);
+        assert_eq!(power_at(&test, pos(0, 64, -2)), 15);
+        assert_eq!(power_at(&test, pos(2, 64, 0)), 0);
+
+        place(&mut test, pos(0, 64, 0), lever.with_powered(false));
+        assert_eq!(power_at(&test, pos(0, 64, -2)), 0);
+    }
+
+    #[test]
+    fn test_torch_inverts() {
+        let mut test = setup();
+        let torch = pos(0, 65, 0);
+        let lamp = pos(1, 65, 0);
+        place(&mut test, pos(0, 64, 0), BlockId::stone());
+        place(&mut test, torch, BlockId::redstone_torch().with_lit(true));
+        place(&mut test, lamp, BlockId::redstone_lamp());
+        assert_eq!(block(&test, lamp).lit(), Some(true));
+        assert_eq!(power_at(&test, pos(0, 66, 0)), 15);
+
+        // Redstone blocks don't power the blocks next to them.
+        place(&mut test, pos(-1, 64, 0), BlockId::redstone_block());
+        run_ticks(&mut test, 10);
+        assert_eq!(block(&test, torch).lit(), Some(true));
+
+        let lever = BlockId::lever()
+            .with_face(Face::Wall)
+            .with_facing_cardinal(FacingCardinal::West)
+            .with_powered(true);
+        place(&mut test, pos(-1, 64, 0), lever);
+        run_ticks(&mut test, TORCH_DELAY - 1);
+        assert_eq!(block(&test, torch).lit(), Some(true));
+        run_ticks(&mut test, 1);
+        assert_eq!(block(&test, torch).lit(), Some(false));
+        run_ticks(&mut test, LAMP_DELAY);
+        assert_eq!(block(&test, lamp).lit(), Some(false));
+
+        place(&mut test, pos(-1, 64, 0), lever.with_powered(false));
+        run_ticks(&mut test, TORCH_DELAY);
+        assert_eq!(block(&test, torch).lit(), Some(true));
+        assert_eq!(block(&test, lamp).lit(), Some(true));
+    }
+
+    #[test]
+    fn test_torch_burnout() {
+        let mut test = setup();
+        let torch = pos(0, 65, 0);
+        let lever = BlockId::lever()
+            .with_face(Face::Wall)
+            .with_facing_cardinal(FacingCardinal::West);
+        place(&mut test, pos(0, 64, 0), BlockId::stone());
+        place(&mut test, torch, BlockId::redstone_torch().with_lit(true));
+        place(&mut test, pos(-1, 64, 0), lever);
+
+        let mut toggles = 0;
+        for _ in 0..20 {
+            place(&mut test, pos(-1, 64, 0), lever.with_powered(true));
+            run_ticks(&mut test, TORCH_DELAY);
+            if block(&test, torch).lit() == Some(false) {
+                toggles += 1;
+            }
+            place(&mut test, pos(-1, 64, 0), lever.with_powered(false));
+            run_ticks(&mut test, TORCH_DELAY);
+            if block(&test, torch).lit() == Some(true) {
+                continue;
+            }
+            break;
+        }
+        // The torch stays off after turning off too often.
+        assert_eq!(toggles, BURNOUT_TOGGLES);
+        assert_eq!(block(&test, torch).lit(), Some(false));
+
+        run_ticks(&mut test, BURNOUT_DELAY);
+        assert_eq!(block(&test, torch).lit(), Some(true));
+    }
+
+    #[test]
+    fn test_lamp_delay() {
+        let mut test = setup();
+        let lamp = pos(0, 64, 0);
+        place(&mut test, lamp, BlockId::redstone_lamp());
+        place(&mut test, pos(1, 64, 0), BlockId::redstone_block());
+        assert_eq!(block(&test, lamp).lit(), Some(true));
+
+        place(&mut test, pos(1, 64, 0), BlockId::air());
+        run_ticks(&mut test, LAMP_DELAY - 1);
+        assert_eq!(block(&test, lamp).lit(), Some(true));
+        run_ticks(&mut test, 1);
+        assert_eq!(block(&test, lamp).lit(), Some(false));
+    }
+
+    #[test]
+    fn test_interactions() {
+        let mut test = setup();
+        let button = BlockId::stone_button().with_face(Face::Floor);
+        let pressed = interact(&mut test.game, pos(0, 64, 0), button).unwrap();
+        assert_eq!(pressed.powered(), Some(true));
+        assert!(interact(&mut test.game, pos(0, 64, 0), pressed).is_none());
+
+        place(&mut test, pos(0, 64, 0), pressed);
+        run_ticks(&mut test, STONE_BUTTON_DELAY - 1);
+        assert_eq!(block(&test, pos(0, 64, 0)).powered(), Some(true));
+        run_ticks(&mut test, 1);
+        assert_eq!(block(&test, pos(0, 64, 0)).powered(), Some(false));
+
+        let repeater = BlockId::repeater().with_delay(4);
+        let repeater = interact(&mut test.game, pos(1, 64, 0), repeater).unwrap();
+        assert_eq!(repeater.delay(), Some(1));
+        let lever = interact(&mut test.game, pos(1, 64, 0), BlockId::lever()).unwrap();
+        assert_eq!(lever.powered(), Some(true));
+    }
+}
@@ -0,0 +1,484 @@
+//! Repeaters and comparators, which take power from behind
+//! them and output it in front after a delay.
+//!
+//! Repeaters output full power and are locked by powered diodes to
+//! their sides. Comparators output the power they receive, or the
+//! fullness of a container behind them, compared with or minus the
+//! power they receive from their sides.
+
+use super::{
+    is_power_source, offset, power_from, process_updates, queue_around, schedule, set, MAX_POWER,
+};
+use crate::chest;
+use crate::comparator::{Comparator, ComparatorOutput};
+use arrayvec::ArrayVec;
+use feather_core::blocks::{BlockId, ComparatorMode, FacingCubic, SimplifiedBlockKind};
+use feather_core::util::BlockPosition;
+use feather_server_types::{Game, Inventory};
+use fecs::{component, IntoQuery, Read, World};
+
+/// Delay of comparators, in game ticks.
+const COMPARATOR_DELAY: u64 = 2;
+
+fn is_diode(block: BlockId) -> bool {
+    matches!(
+        block.simplified_kind(),
+        SimplifiedBlockKind::Repeater | SimplifiedBlockKind::Comparator
+    )
+}
+
+/// Returns the direction from a diode towards its input.
+/// Diodes output in the opposite direction.
+fn input_direction(block: BlockId) -> FacingCubic {
+    block
+        .facing_cardinal()
+        .map_or(FacingCubic::North, |facing| facing.to_facing_cubic())
+}
+
+/// Returns the power the diode at `pos` emits to the
+/// neighbour for which it is in direction `side`.
+pub(super) fn emitted_power(
+    game: &Game,
+    world: &World,
+    pos: BlockPosition,
+    block: BlockId,
+    side: FacingCubic,
+) -> u8 {
+    if block.powered() != Some(true) || side != input_direction(block) {
+        return 0;
+    }
+
+    match block.simplified_kind() {
+        SimplifiedBlockKind::Repeater => MAX_POWER,
+        _ => comparator_output(game, world, pos),
+    }
+}
+
+/// Returns the power a diode receives from behind it.
+/// Diodes read the power of wire behind them directly,
+/// whichever way the wire points.
+fn input_power(game: &Game, world: &World, pos: BlockPosition, block: BlockId) -> u8 {
+    let dir = input_direction(block);
+    let power = power_from(game, world, pos, dir, true);
+    match game.block_at(offset(pos, dir)) {
+        Some(input) if input.simplified_kind() == SimplifiedBlockKind::RedstoneWire => {
+            power.max(input.power().unwrap_or(0) as u8)
+        }
+        _ => power,
+    }
+}
+
+/// Returns the power a diode receives from its sides. Repeaters
+/// only take power from diodes, while comparators take it from
+/// any component.
+fn side_power(game: &Game, world: &World, pos: BlockPosition, block: BlockId) -> u8 {
+    let facing = match block.facing_cardinal() {
+        Some(facing) => facing,
+        None => return 0,
+    };
+    let diodes_only = block.simplified_kind() == SimplifiedBlockKind::Repeater;
+
+    [facing.left(), facing.right()]
+        .iter()
+        .filter_map(|dir| {
+            let side = dir.to_facing_cubic();
+            let neighbor = offset(pos, side);
+            let neighbor_block = game.block_at(neighbor)?;
+            Some(match neighbor_block.simplified_kind() {
+                SimplifiedBlockKind::RedstoneBlock if !diodes_only => MAX_POWER,
+                SimplifiedBlockKind::RedstoneWire if !diodes_only => {
+                    neighbor_block.power().unwrap_or(0) as u8
+                }
+                _ if is_diode(neighbor_block)
+                    || (!diodes_only && is_power_source(neighbor_block)) =>
+                {
+                    super::emitted_power(game, world, neighbor, neighbor_block, side, true)
+                }
+                _ => 0,
+            })
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns whether the diode outputs into another diode which
+/// doesn't output back into it. Such diodes update first, so
+/// chains of diodes update in order.
+fn faces_diode(game: &Game, pos: BlockPosition, block: BlockId) -> bool {
+    let output = input_direction(block).opposite();
+    match game.block_at(offset(pos, output)) {
+        Some(other) => is_diode(other) && input_direction(other) != output,
+        None => false,
+    }
+}
+
+/// Returns the delay of a repeater, in game ticks.
+fn repeater_delay(block: BlockId) -> u64 {
+    block.delay().unwrap_or(1).max(1) as u64 * 2
+}
+
+pub(super) fn update_repeater(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+) {
+    let locked = side_power(game, world, pos, block) > 0;
+    if block.locked() != Some(locked) {
+        // Setting the block updates the repeater again.
+        set(game, world, pos, block.with_locked(locked));
+        return;
+    }
+
+    if locked || game.scheduled_ticks.is_scheduled(pos, block.kind()) {
+        return;
+    }
+
+    let powered = block.powered() == Some(true);
+    if powered != (input_power(game, world, pos, block) > 0) {
+        let priority = if faces_diode(game, pos, block) {
+            -3
+        } else if powered {
+            -2
+        } else {
+            -1
+        };
+        schedule(game, pos, block, repeater_delay(block), priority);
+    }
+}
+
+pub(super) fn tick_repeater(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+) {
+    if block.locked() == Some(true) {
+        return;
+    }
+
+    let should_power = input_power(game, world, pos, block) > 0;
+    if block.powered() == Some(true) {
+        if !should_power {
+            set(game, world, pos, block.with_powered(false));
+        }
+    } else {
+        let block = block.with_powered(true);
+        set(game, world, pos, block);
+        if !should_power {
+            // Pulses shorter than the delay are extended to it.
+            schedule(game, pos, block, repeater_delay(block), -3);
+        }
+    }
+}
+
+/// Returns the signal the comparator at `pos` outputs.
+fn comparator_output(game: &Game, world: &World, pos: BlockPosition) -> u8 {
+    game.block_entities
+        .get(&pos)
+        .and_then(|entity| world.try_get::<ComparatorOutput>(*entity))
+        .map_or(0, |output| output.0)
+}
+
+/// Sets the signal the comparator at `pos` outputs,
+/// returning whether it changed.
+fn set_comparator_output(game: &Game, world: &mut World, pos: BlockPosition, signal: u8) -> bool {
+    let entity = match game.block_entities.get(&pos) {
+        Some(entity) => *entity,
+        None => return false,
+    };
+    match world.try_get_mut::<ComparatorOutput>(entity) {
+        Some(mut output) if output.0 != signal => {
+            output.0 = signal;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Returns the signal a comparator reads from a container at `pos`,
+/// from 0 when it is empty to 15 when it is full, or `None` if
+/// there is no container. A double chest counts as one container.
+fn container_signal(game: &Game, world: &World, pos: BlockPosition) -> Option<u8> {
+    let block = game.block_at(pos)?;
+    let positions: ArrayVec<[BlockPosition; 2]> = match chest::connected_chest(pos, block) {
+        Some((left, right)) => ArrayVec::from([left, right]),
+        None => std::iter::once(pos).collect(),
+    };
+
+    let mut fullness = 0.0;
+    let mut slots = 0;
+    let mut empty = true;
+    for pos in positions {
+        let inventory = match game
+            .block_entities
+            .get(&pos)
+            .and_then(|entity| world.try_get::<Inventory>(*entity))
+        {
+            Some(inventory) => inventory,
+            None => continue,
+        };
+
+        for (_, slot) in inventory.enumerate() {
+            slots += 1;
+            if let Some(stack) = slot {
+                fullness += f32::from(stack.amount) / stack.ty.stack_size() as f32;
+                empty = false;
+            }
+        }
+    }
+
+    if slots == 0 {
+        None
+    } else if empty {
+        Some(0)
+    } else {
+        Some((fullness / slots as f32 * 14.0) as u8 + 1)
+    }
+}
+
+/// Returns the signal a comparator receives from behind it: the
+/// signal of a container, either directly behind it or behind
+/// an opaque block, or else the power it receives.
+fn comparator_input(game: &Game, world: &World, pos: BlockPosition, block: BlockId) -> u8 {
+    let dir = input_direction(block);
+    let behind = offset(pos, dir);
+    if let Some(signal) = container_signal(game, world, behind) {
+        return signal;
+    }
+
+    let power = input_power(game, world, pos, block);
+    if power < MAX_POWER && game.block_at(behind).map_or(false, super::is_conductor) {
+        if let Some(signal) = container_signal(game, world, offset(behind, dir)) {
+            return signal;
+        }
+    }
+    power
+}
+
+/// Computes the signal the comparator at `pos` should output.
+fn calculate_output(game: &Game, world: &World, pos: BlockPosition, block: BlockId) -> u8 {
+    let input = comparator_input(game, world, pos, block);
+    let side = side_power(game, world, pos, block);
+    if side > input {
+        0
+    } else if block.comparator_mode() == Some(ComparatorMode::Subtract) {
+        input - side
+    } else {
+        input
+    }
+}
+
+pub(super) fn update_comparator(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+) {
+    if game.scheduled_ticks.is_scheduled(pos, block.kind()) {
+        return;
+    }
+
+    let output = calculate_output(game, world, pos, block);
+    if output != comparator_output(game, world, pos) || block.powered() != Some(output > 0) {
+        let priority = if faces_diode(game, pos, block) { -1 } else { 0 };
+        schedule(game, pos, block, COMPARATOR_DELAY, priority);
+    }
+}
+
+pub(super) fn tick_comparator(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+) {
+    let output = calculate_output(game, world, pos, block);
+    let changed = set_comparator_output(game, world, pos, output);
+    if block.powered() != Some(output > 0) {
+        set(game, world, pos, block.with_powered(output > 0));
+    } else if changed {
+        // The block itself is unchanged, so update its neighbours manually.
+        queue_around(game, pos);
+        process_updates(game, world);
+    }
+}
+
+/// Switches a comparator between comparing and subtracting.
+pub(super) fn toggle_mode(block: BlockId) -> BlockId {
+    match block.comparator_mode() {
+        Some(ComparatorMode::Compare) => block.with_comparator_mode(ComparatorMode::Subtract),
+        _ => block.with_comparator_mode(ComparatorMode::Compare),
+    }
+}
+
+/// System which updates comparators whose output should change.
+/// This is needed for comparators reading containers, as changes
+/// to inventories don't cause block updates.
+#[fecs::system]
+pub fn update_comparators(game: &mut Game, world: &mut World) {
+    let positions: Vec<BlockPosition> = <Read<BlockPosition>>::query()
+        .filter(component::<Comparator>())
+        .iter(world.inner())
+        .map(|pos| *pos)
+        .collect();
+
+    for pos in positions {
+        if let Some(block) = game.block_at(pos) {
+            if block.simplified_kind() == SimplifiedBlockKind::Comparator {
+                update_comparator(game, world, pos, block);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{block, place, pos, run_ticks, setup};
+    use super::*;
+    use feather_core::blocks::FacingCardinal;
+    use feather_core::inventory::Area;
+    use feather_core::items::{Item, ItemStack};
+
+    fn repeater(facing: FacingCardinal, delay: i32) -> BlockId {
+        BlockId::repeater()
+            .with_facing_cardinal(facing)
+            .with_delay(delay)
+    }
+
+    fn comparator(facing: FacingCardinal) -> BlockId {
+        BlockId::comparator().with_facing_cardinal(facing)
+    }
+
+    #[test]
+    fn test_repeater_delay() {
+        let mut test = setup();
+        // Input to the west, output to the east.
+        place(&mut test, pos(1, 64, 0), repeater(FacingCardinal::West, 2));
+        place(&mut test, pos(2, 64, 0), BlockId::redstone_lamp());
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+
+        run_ticks(&mut test, 3);
+        assert_eq!(block(&test, pos(2, 64, 0)).lit(), Some(false));
+        run_ticks(&mut test, 1);
+        assert_eq!(block(&test, pos(1, 64, 0)).powered(), Some(true));
+        assert_eq!(block(&test, pos(2, 64, 0)).lit(), Some(true));
+
+        place(&mut test, pos(0, 64, 0), BlockId::air());
+        run_ticks(&mut test, 4);
+        assert_eq!(block(&test, pos(1, 64, 0)).powered(), Some(false));
+    }
+
+    #[test]
+    fn test_repeater_extends_pulses() {
+        let mut test = setup();
+        place(&mut test, pos(1, 64, 0), repeater(FacingCardinal::West, 4));
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+        run_ticks(&mut test, 1);
+        place(&mut test, pos(0, 64, 0), BlockId::air());
+
+        run_ticks(&mut test, 7);
+        assert_eq!(block(&test, pos(1, 64, 0)).powered(), Some(true));
+        run_ticks(&mut test, 8);
+        assert_eq!(block(&test, pos(1, 64, 0)).powered(), Some(false));
+    }
+
+    #[test]
+    fn test_repeater_lock() {
+        let mut test = setup();
+        place(&mut test, pos(1, 64, 0), repeater(FacingCardinal::West, 1));
+        // A powered repeater pointing into its side locks it.
+        place(&mut test, pos(1, 64, 1), repeater(FacingCardinal::South, 1));
+        place(&mut test, pos(1, 64, 2), BlockId::redstone_block());
+        run_ticks(&mut test, 2);
+        assert_eq!(block(&test, pos(1, 64, 0)).locked(), Some(true));
+
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+        run_ticks(&mut test, 10);
+        assert_eq!(block(&test, pos(1, 64, 0)).powered(), Some(false));
+
+        place(&mut test, pos(1, 64, 2), BlockId::air());
+        run_ticks(&mut test, 2);
+        assert_eq!(block(&test, pos(1, 64, 0)).locked(), Some(false));
+        run_ticks(&mut test, 2);
+        assert_eq!(block(&test, pos(1, 64, 0)).powered(), Some(true));
+    }
+
+    #[test]
+    fn test_comparator_modes() {
+        let mut test = setup();
+        let comparator_pos = pos(1, 64, 0);
+        place(&mut test, comparator_pos, comparator(FacingCardinal::West));
+        // Power 14 from the side.
+        place(&mut test, pos(1, 64, 1), BlockId::redstone_wire());
+        place(&mut test, pos(1, 64, 2), BlockId::redstone_wire());
+        place(&mut test, pos(1, 64, 3), BlockId::redstone_block());
+        // Power 13 from behind.
+        for x in -2..=0 {
+            place(&mut test, pos(x, 64, 0), BlockId::redstone_wire());
+        }
+        place(&mut test, pos(-3, 64, 0), BlockId::redstone_block());
+        run_ticks(&mut test, COMPARATOR_DELAY);
+        assert_eq!(
+            comparator_output(&test.game, &test.world, comparator_pos),
+            0
+        );
+
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+        run_ticks(&mut test, COMPARATOR_DELAY);
+        assert_eq!(
+            comparator_output(&test.game, &test.world, comparator_pos),
+            15
+        );
+        assert_eq!(block(&test, comparator_pos).powered(), Some(true));
+
+        let subtract = toggle_mode(block(&test, comparator_pos));
+        place(&mut test, comparator_pos, subtract);
+        run_ticks(&mut test, COMPARATOR_DELAY);
+        assert_eq!(
+            comparator_output(&test.game, &test.world, comparator_pos),
+            1
+        );
+        assert_eq!(block(&test, comparator_pos).powered(), Some(true));
+    }
+
+    #[test]
+    fn test_comparator_reads_chest() {
+        let mut test = setup();
+        let comparator_pos = pos(1, 64, 0);
+        place(&mut test, pos(0, 64, 0), BlockId::chest());
+        place(&mut test, comparator_pos, comparator(FacingCardinal::West));
+        place(&mut test, pos(2, 64, 0), BlockId::redstone_lamp());
+        run_ticks(&mut test, 4);
+        assert_eq!(
+            comparator_output(&test.game, &test.world, comparator_pos),
+            0
+        );
+
+        let chest = test.game.block_entities[&pos(0, 64, 0)];
+        test.world
+            .get::<Inventory>(chest)
+            .set_item_at(Area::Chest, 0, ItemStack::new(Item::Stone, 64))
+            .unwrap();
+        test.run(update_comparators);
+        run_ticks(&mut test, COMPARATOR_DELAY);
+        // One of 27 slots is full.
+        assert_eq!(
+            comparator_output(&test.game, &test.world, comparator_pos),
+            1
+        );
+        assert_eq!(block(&test, pos(2, 64, 0)).lit(), Some(true));
+
+        for slot in 1..27 {
+            test.world
+                .get::<Inventory>(chest)
+                .set_item_at(Area::Chest, slot, ItemStack::new(Item::Stone, 64))
+                .unwrap();
+        }
+        test.run(update_comparators);
+        run_ticks(&mut test, COMPARATOR_DELAY);
+        assert_eq!(
+            comparator_output(&test.game, &test.world, comparator_pos),
+            15
+        );
+    }
+}
@@ -0,0 +1,275 @@
+//! Pistons, which push the blocks in front of them when powered,
+//! and sticky pistons, which also pull a block back when unpowered.
+//!
+//! Blocks are moved instantly, without the moving piston animation.
+
+use super::{batch, offset, power_from, schedule, set, DIRECTIONS};
+use feather_core::blocks::{BlockId, BlockKind, FacingCubic, PistonKind, SimplifiedBlockKind};
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockUpdateCause, Game};
+use fecs::World;
+
+/// The most blocks a piston can push.
+const MAX_PUSHED: usize = 12;
+/// Delay before a piston moves after its power changes.
+const PISTON_DELAY: u64 = 2;
+
+fn is_piston(block: BlockId) -> bool {
+    matches!(
+        block.simplified_kind(),
+        SimplifiedBlockKind::Piston | SimplifiedBlockKind::StickyPiston
+    )
+}
+
+fn facing(block: BlockId) -> FacingCubic {
+    block.facing_cubic().unwrap_or(FacingCubic::Up)
+}
+
+/// Returns whether the piston at `pos` is powered. Pistons take power
+/// from all sides except their front, and from the block above them.
+fn should_extend(game: &Game, world: &World, pos: BlockPosition, facing: FacingCubic) -> bool {
+    let above = offset(pos, FacingCubic::Up);
+    DIRECTIONS
+        .iter()
+        .any(|side| *side != facing && power_from(game, world, pos, *side, true) > 0)
+        || DIRECTIONS.iter().any(|side| {
+            *side != FacingCubic::Down && power_from(game, world, above, *side, true) > 0
+        })
+}
+
+/// Returns whether a piston can move the block at `pos`.
+fn is_movable(game: &Game, pos: BlockPosition, block: BlockId) -> bool {
+    match block.kind() {
+        BlockKind::Obsidian
+        | BlockKind::Bedrock
+        | BlockKind::Barrier
+        | BlockKind::EndPortalFrame
+        | BlockKind::PistonHead
+        | BlockKind::MovingPiston => false,
+        _ if is_piston(block) => block.extended() != Some(true),
+        _ => !game.block_entities.contains_key(&pos),
+    }
+}
+
+/// Returns the blocks a piston at `pos` would push, nearest first,
+/// and the position the farthest block moves into. The block there
+/// isn't solid and is destroyed. Returns `None` if the piston can't
+/// push the blocks in front of it.
+fn pushed_blocks(
+    game: &Game,
+    pos: BlockPosition,
+    facing: FacingCubic,
+) -> Option<(Vec<(BlockPosition, BlockId)>, BlockPosition)> {
+    let mut pushed = Vec::new();
+    let mut next = offset(pos, facing);
+    loop {
+        let block = game.block_at(next)?;
+        if !block.is_solid() {
+            return Some((pushed, next));
+        }
+        if !is_movable(game, next, block) || pushed.len() == MAX_PUSHED {
+            return None;
+        }
+        pushed.push((next, block));
+        next = offset(next, facing);
+    }
+}
+
+/// Returns whether the head of the extended piston at `pos` is in place.
+fn has_head(game: &Game, pos: BlockPosition, piston: BlockId) -> bool {
+    game.block_at(offset(pos, facing(piston)))
+        .map_or(false, |head| {
+            head.kind() == BlockKind::PistonHead && facing(head) == facing(piston)
+        })
+}
+
+pub(super) fn update(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    let extended = block.extended() == Some(true);
+    if extended && !has_head(game, pos, block) {
+        // The head was removed, e.g. by a player breaking it.
+        game.set_block_at(world, pos, BlockId::air(), BlockUpdateCause::Destroyed);
+        return;
+    }
+
+    if should_extend(game, world, pos, facing(block)) != extended {
+        schedule(game, pos, block, PISTON_DELAY, 0);
+    }
+}
+
+/// Removes piston heads which aren't attached to an extended piston.
+pub(super) fn update_head(game: &mut Game, world: &mut World, pos: BlockPosition, head: BlockId) {
+    let base = offset(pos, facing(head).opposite());
+    let attached = game.block_at(base).map_or(false, |piston| {
+        is_piston(piston) && piston.extended() == Some(true) && facing(piston) == facing(head)
+    });
+    if !attached {
+        set(game, world, pos, BlockId::air());
+    }
+}
+
+pub(super) fn tick(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    let facing = facing(block);
+    let extended = block.extended() == Some(true);
+    let should_extend = should_extend(game, world, pos, facing);
+    if should_extend && !extended {
+        extend(game, world, pos, block, facing);
+    } else if !should_extend && extended {
+        retract(game, world, pos, block, facing);
+    }
+}
+
+fn extend(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+    facing: FacingCubic,
+) {
+    let (pushed, end) = match pushed_blocks(game, pos, facing) {
+        Some(pushed) => pushed,
+        None => return,
+    };
+
+    let kind = if block.kind() == BlockKind::StickyPiston {
+        PistonKind::Sticky
+    } else {
+        PistonKind::Normal
+    };
+    let head = BlockId::piston_head()
+        .with_facing_cubic(facing)
+        .with_piston_kind(kind)
+        .with_short(false);
+
+    // Update the surroundings once everything has moved, so the piston
+    // isn't seen as extended without its head in the meantime.
+    batch(game, world, |game, world| {
+        if game.block_at(end).map_or(false, |block| !block.is_air()) {
+            game.set_block_at(world, end, BlockId::air(), BlockUpdateCause::Destroyed);
+        }
+        for (from, moved) in pushed.into_iter().rev() {
+            set(game, world, offset(from, facing), moved);
+        }
+        set(game, world, offset(pos, facing), head);
+        set(game, world, pos, block.with_extended(true));
+    });
+}
+
+fn retract(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+    facing: FacingCubic,
+) {
+    let head = offset(pos, facing);
+    let pulled = offset(head, facing);
+    let has_head = has_head(game, pos, block);
+
+    batch(game, world, |game, world| {
+        set(game, world, pos, block.with_extended(false));
+        if !has_head {
+            return;
+        }
+
+        match game.block_at(pulled) {
+            Some(pulled_block)
+                if block.kind() == BlockKind::StickyPiston
+                    && pulled_block.is_solid()
+                    && is_movable(game, pulled, pulled_block) =>
+            {
+                set(game, world, head, pulled_block);
+                set(game, world, pulled, BlockId::air());
+            }
+            _ => set(game, world, head, BlockId::air()),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{block, place, pos, run_ticks, setup};
+    use super::*;
+
+    fn piston(facing: FacingCubic) -> BlockId {
+        BlockId::piston()
+            .with_facing_cubic(facing)
+            .with_extended(false)
+    }
+
+    #[test]
+    fn test_push_and_retract() {
+        let mut test = setup();
+        place(&mut test, pos(0, 64, 0), piston(FacingCubic::East));
+        place(&mut test, pos(1, 64, 0), BlockId::stone());
+        place(&mut test, pos(2, 64, 0), BlockId::dirt());
+        place(&mut test, pos(0, 64, 1), BlockId::redstone_block());
+        run_ticks(&mut test, PISTON_DELAY);
+
+        assert_eq!(block(&test, pos(0, 64, 0)).extended(), Some(true));
+        assert_eq!(block(&test, pos(1, 64, 0)).kind(), BlockKind::PistonHead);
+        assert_eq!(block(&test, pos(2, 64, 0)), BlockId::stone());
+        assert_eq!(block(&test, pos(3, 64, 0)), BlockId::dirt());
+
+        place(&mut test, pos(0, 64, 1), BlockId::air());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(0, 64, 0)).extended(), Some(false));
+        assert!(block(&test, pos(1, 64, 0)).is_air());
+        assert_eq!(block(&test, pos(2, 64, 0)), BlockId::stone());
+    }
+
+    #[test]
+    fn test_sticky_piston_pulls() {
+        let mut test = setup();
+        let sticky = BlockId::sticky_piston()
+            .with_facing_cubic(FacingCubic::East)
+            .with_extended(false);
+        place(&mut test, pos(0, 64, 0), sticky);
+        place(&mut test, pos(1, 64, 0), BlockId::stone());
+        // Powered through the block above the piston.
+        place(&mut test, pos(0, 65, 1), BlockId::redstone_block());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(2, 64, 0)), BlockId::stone());
+
+        place(&mut test, pos(0, 65, 1), BlockId::air());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(1, 64, 0)), BlockId::stone());
+        assert!(block(&test, pos(2, 64, 0)).is_air());
+    }
+
+    #[test]
+    fn test_immovable() {
+        let mut test = setup();
+        place(&mut test, pos(0, 64, 0), piston(FacingCubic::East));
+        place(&mut test, pos(1, 64, 0), BlockId::obsidian());
+        place(&mut test, pos(0, 64, 1), BlockId::redstone_block());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(0, 64, 0)).extended(), Some(false));
+
+        // Too many blocks.
+        place(&mut test, pos(0, 64, 1), BlockId::air());
+        for x in 1..=(MAX_PUSHED as i32 + 1) {
+            place(&mut test, pos(x, 64, 0), BlockId::stone());
+        }
+        place(&mut test, pos(0, 64, 1), BlockId::redstone_block());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(0, 64, 0)).extended(), Some(false));
+
+        place(&mut test, pos(MAX_PUSHED as i32 + 1, 64, 0), BlockId::air());
+        place(&mut test, pos(0, 64, 1), BlockId::air());
+        place(&mut test, pos(0, 64, 1), BlockId::redstone_block());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(0, 64, 0)).extended(), Some(true));
+    }
+
+    #[test]
+    fn test_breaking_head() {
+        let mut test = setup();
+        place(&mut test, pos(0, 64, 0), piston(FacingCubic::East));
+        place(&mut test, pos(0, 64, 1), BlockId::redstone_block());
+        run_ticks(&mut test, PISTON_DELAY);
+        assert_eq!(block(&test, pos(1, 64, 0)).kind(), BlockKind::PistonHead);
+
+        place(&mut test, pos(1, 64, 0), BlockId::air());
+        assert!(block(&test, pos(0, 64, 0)).is_air());
+    }
+}
@@ -0,0 +1,298 @@
+//! Redstone wire, which carries power between components,
+//! losing a level of power for each block it passes through.
+//!
+//! A change anywhere in a network of connected wire recomputes the
+//! power of the whole network at once. This avoids the cascade
+//! of updates vanilla causes by updating one wire at a time.
+
+use super::{
+    is_conductor, is_power_source, offset, power_from, set, DIRECTIONS, HORIZONTAL, MAX_POWER,
+};
+use ahash::{AHashMap, AHashSet};
+use arrayvec::ArrayVec;
+use feather_core::blocks::{
+    BlockId, EastWire, FacingCubic, NorthWire, SimplifiedBlockKind, SouthWire, WestWire,
+};
+use feather_core::util::BlockPosition;
+use feather_server_types::Game;
+use fecs::World;
+
+/// How wire connects to the block in a horizontal direction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Connection {
+    /// Connects to wire on top of the block.
+    Up,
+    Side,
+    None,
+}
+
+fn is_wire(block: Option<BlockId>) -> bool {
+    block.map(|block| block.simplified_kind()) == Some(SimplifiedBlockKind::RedstoneWire)
+}
+
+fn is_conductor_at(game: &Game, pos: BlockPosition) -> bool {
+    game.block_at(pos).map_or(false, is_conductor)
+}
+
+/// Returns whether wire connects to `block`, which is
+/// next to it in direction `side`.
+fn connects_to(block: BlockId, side: FacingCubic) -> bool {
+    match block.simplified_kind() {
+        SimplifiedBlockKind::Repeater => block
+            .facing_cardinal()
+            .map(|facing| facing.to_facing_cubic())
+            .map_or(false, |facing| facing == side || facing == side.opposite()),
+        SimplifiedBlockKind::Observer => block.facing_cubic() == Some(side),
+        _ => is_power_source(block),
+    }
+}
+
+/// Returns how the wire at `pos` connects in direction `dir`.
+fn connection(game: &Game, pos: BlockPosition, dir: FacingCubic) -> Connection {
+    let neighbor = offset(pos, dir);
+    let block = match game.block_at(neighbor) {
+        Some(block) => block,
+        None => return Connection::None,
+    };
+    let conductor = is_conductor(block);
+
+    if conductor
+        && !is_conductor_at(game, offset(pos, FacingCubic::Up))
+        && is_wire(game.block_at(offset(neighbor, FacingCubic::Up)))
+    {
+        Connection::Up
+    } else if connects_to(block, dir)
+        || (!conductor && is_wire(game.block_at(offset(neighbor, FacingCubic::Down))))
+    {
+        Connection::Side
+    } else {
+        Connection::None
+    }
+}
+
+/// Returns the wire which the wire at `pos` exchanges power with.
+fn wire_neighbors(game: &Game, pos: BlockPosition) -> ArrayVec<[BlockPosition; 8]> {
+    let covered = is_conductor_at(game, offset(pos, FacingCubic::Up));
+    let mut neighbors = ArrayVec::new();
+    for dir in &HORIZONTAL {
+        let neighbor = offset(pos, *dir);
+        let block = game.block_at(neighbor);
+        if is_wire(block) {
+            neighbors.push(neighbor);
+        }
+
+        if block.map_or(false, is_conductor) {
+            let above = offset(neighbor, FacingCubic::Up);
+            if !covered && is_wire(game.block_at(above)) {
+                neighbors.push(above);
+            }
+        } else {
+            let below = offset(neighbor, FacingCubic::Down);
+            if is_wire(game.block_at(below)) {
+                neighbors.push(below);
+            }
+        }
+    }
+    neighbors
+}
+
+/// Returns the power the wire at `pos` emits to the
+/// neighbour for which it is in direction `side`.
+///
+/// Wire powers the block below it, and blocks to its
+/// sides which its connections point towards.
+pub(super) fn emitted_power(
+    game: &Game,
+    pos: BlockPosition,
+    block: BlockId,
+    side: FacingCubic,
+) -> u8 {
+    let power = block.power().unwrap_or(0) as u8;
+    match side {
+        _ if power == 0 => 0,
+        FacingCubic::Up => power,
+        FacingCubic::Down => 0,
+        _ => {
+            let connected: ArrayVec<[FacingCubic; 4]> = HORIZONTAL
+                .iter()
+                .copied()
+                .filter(|dir| connection(game, pos, *dir) != Connection::None)
+                .collect();
+            let side_cardinal = side.to_facing_cardinal().expect("side is horizontal");
+            let points_to_side = connected.contains(&side)
+                && !connected.contains(&side_cardinal.left().to_facing_cubic())
+                && !connected.contains(&side_cardinal.right().to_facing_cubic());
+
+            if connected.is_empty() || points_to_side {
+                power
+            } else {
+                0
+            }
+        }
+    }
+}
+
+/// Updates the power and shape of all wire connected to the wire at `start`.
+pub(super) fn update_network(game: &mut Game, world: &mut World, start: BlockPosition) {
+    let mut network = vec![start];
+    let mut found = AHashSet::new();
+    found.insert(start);
+    let mut i = 0;
+    while let Some(pos) = network.get(i).copied() {
+        for neighbor in wire_neighbors(game, pos) {
+            if found.insert(neighbor) {
+                network.push(neighbor);
+            }
+        }
+        i += 1;
+    }
+
+    // Start with the power each wire receives from other components,
+    // then spread it through the network from the strongest wire down.
+    let mut power: AHashMap<BlockPosition, u8> = network
+        .iter()
+        .map(|pos| (*pos, source_power(game, world, *pos)))
+        .collect();
+    let mut levels = vec![Vec::new(); MAX_POWER as usize + 1];
+    for (pos, level) in &power {
+        levels[*level as usize].push(*pos);
+    }
+
+    for level in (2..=MAX_POWER).rev() {
+        for pos in std::mem::take(&mut levels[level as usize]) {
+            if power[&pos] != level {
+                continue;
+            }
+            for neighbor in wire_neighbors(game, pos) {
+                if let Some(neighbor_power) = power.get_mut(&neighbor) {
+                    if *neighbor_power < level - 1 {
+                        *neighbor_power = level - 1;
+                        levels[level as usize - 1].push(neighbor);
+                    }
+                }
+            }
+        }
+    }
+
+    for pos in &network {
+        let block = match game.block_at(*pos) {
+            Some(block) => block,
+            None => continue,
+        };
+        let new = shape(game, *pos, block).with_power(i32::from(power[pos]));
+        if new != block {
+            set(game, world, *pos, new);
+        }
+    }
+
+    // The whole network is up to date, so the updates
+    // for its wire queued while changing it are redundant.
+    for pos in network {
+        game.redstone.remove(pos);
+    }
+}
+
+/// Returns the power the wire at `pos` receives, ignoring other wire.
+fn source_power(game: &Game, world: &World, pos: BlockPosition) -> u8 {
+    DIRECTIONS
+        .iter()
+        .map(|side| power_from(game, world, pos, *side, false))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the wire at `pos` with its connections updated.
+fn shape(game: &Game, pos: BlockPosition, block: BlockId) -> BlockId {
+    let connects = |dir| connection(game, pos, dir);
+    block
+        .with_north_wire(match connects(FacingCubic::North) {
+            Connection::Up => NorthWire::Up,
+            Connection::Side => NorthWire::Side,
+            Connection::None => NorthWire::None,
+        })
+        .with_east_wire(match connects(FacingCubic::East) {
+            Connection::Up => EastWire::Up,
+            Connection::Side => EastWire::Side,
+            Connection::None => EastWire::None,
+        })
+        .with_south_wire(match connects(FacingCubic::South) {
+            Connection::Up => SouthWire::Up,
+            Connection::Side => SouthWire::Side,
+            Connection::None => SouthWire::None,
+        })
+        .with_west_wire(match connects(FacingCubic::West) {
+            Connection::Up => WestWire::Up,
+            Connection::Side => WestWire::Side,
+            Connection::None => WestWire::None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{block, place, pos, power_at, setup};
+    use super::*;
+
+    fn power(test: &feather_test_framework::Test, pos: BlockPosition) -> i32 {
+        block(test, pos).power().unwrap()
+    }
+
+    #[test]
+    fn test_line() {
+        let mut test = setup();
+        for x in 1..=16 {
+            place(&mut test, pos(x, 64, 0), BlockId::redstone_wire());
+        }
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+
+        let powers: Vec<_> = (1..=16).map(|x| power(&test, pos(x, 64, 0))).collect();
+        assert_eq!(powers, (0..=15).rev().collect::<Vec<_>>());
+        assert_eq!(
+            block(&test, pos(1, 64, 0)).west_wire(),
+            Some(WestWire::Side)
+        );
+        assert_eq!(
+            block(&test, pos(1, 64, 0)).north_wire(),
+            Some(NorthWire::None)
+        );
+        // Wire powers the block below it, but not
+        // those to its sides which it doesn't point at.
+        assert_eq!(power_at(&test, pos(2, 63, 0)), 14);
+        assert_eq!(power_at(&test, pos(2, 64, 1)), 0);
+
+        place(&mut test, pos(0, 64, 0), BlockId::air());
+        assert!((1..=16).all(|x| power(&test, pos(x, 64, 0)) == 0));
+    }
+
+    #[test]
+    fn test_step() {
+        let mut test = setup();
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+        place(&mut test, pos(1, 64, 0), BlockId::redstone_wire());
+        place(&mut test, pos(2, 64, 0), BlockId::stone());
+        place(&mut test, pos(2, 65, 0), BlockId::redstone_wire());
+        assert_eq!(block(&test, pos(1, 64, 0)).east_wire(), Some(EastWire::Up));
+        assert_eq!(power(&test, pos(2, 65, 0)), 14);
+
+        // A block on top of the lower wire cuts the connection.
+        place(&mut test, pos(1, 65, 0), BlockId::stone());
+        assert_eq!(power(&test, pos(2, 65, 0)), 0);
+    }
+
+    #[test]
+    fn test_large_network() {
+        let mut test = setup();
+        for x in -16..32 {
+            for z in -16..32 {
+                test.game
+                    .chunk_map
+                    .set_block_at(pos(x, 64, z), BlockId::redstone_wire());
+            }
+        }
+        place(&mut test, pos(0, 64, 0), BlockId::redstone_block());
+
+        assert_eq!(power(&test, pos(1, 64, 0)), 15);
+        assert_eq!(power(&test, pos(5, 64, 5)), 6);
+        assert_eq!(power(&test, pos(-10, 64, -10)), 0);
+        assert!(test.game.redstone.is_empty());
+    }
+}
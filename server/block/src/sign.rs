@@ -0,0 +1,133 @@
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{
+    empty_sign_line, BlockEntityData, BlockEntityKind, BlockEntityVariant,
+};
+use feather_core::network::Packet;
+use feather_core::util::BlockPosition;
+use feather_server_types::{
+    BlockEntityLoaderRegistration, BlockSerializer, Game, SpawnPacketCreator,
+};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Sign,
+});
+
+/// `UpdateBlockEntity` action which sets the text on a sign.
+const UPDATE_ACTION: u8 = 9;
+
+/// The four lines of text on a sign, as JSON text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignText(pub [String; 4]);
+
+impl Default for SignText {
+    fn default() -> Self {
+        Self([
+            empty_sign_line(),
+            empty_sign_line(),
+            empty_sign_line(),
+            empty_sign_line(),
+        ])
+    }
+}
+
+/// Creates a blank sign.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    create_with_text(pos, SignText::default())
+}
+
+/// Creates a sign with the given text.
+pub fn create_with_text(pos: BlockPosition, text: SignText) -> EntityBuilder {
+    crate::base(pos)
+        .with(text)
+        .with(SpawnPacketCreator(&create_spawn_packet))
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn create_spawn_packet(accessor: &EntityRef) -> Box<dyn Packet> {
+    Box::new(crate::update_packet(UPDATE_ACTION, &data(accessor)))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    data(accessor)
+}
+
+fn data(accessor: &EntityRef) -> BlockEntityData {
+    let [text1, text2, text3, text4] = accessor.get::<SignText>().0.clone();
+
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Sign {
+            text1,
+            text2,
+            text3,
+            text4,
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Sign {
+            text1,
+            text2,
+            text3,
+            text4,
+        } => Ok(create_with_text(
+            pos,
+            SignText([text1, text2, text3, text4]),
+        )),
+        _ => bail!("not a sign"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::anvil::block_entity::BlockEntityBase;
+    use feather_test_framework::Test;
+
+    #[test]
+    fn test_sign_round_trip() {
+        let mut test = Test::new();
+
+        let text = SignText([
+            String::from(r#"{"text":"Welcome"}"#),
+            empty_sign_line(),
+            String::from(r#"{"text":"to Feather"}"#),
+            empty_sign_line(),
+        ]);
+        let data = BlockEntityData {
+            base: BlockEntityBase { x: 5, y: 70, z: -2 },
+            kind: BlockEntityKind::Sign {
+                text1: text.0[0].clone(),
+                text2: text.0[1].clone(),
+                text3: text.0[2].clone(),
+                text4: text.0[3].clone(),
+            },
+        };
+
+        let sign = test.entity(load(data).unwrap());
+        assert_eq!(*test.world.get::<SignText>(sign), text);
+        assert_eq!(
+            *test.world.get::<BlockPosition>(sign),
+            BlockPosition::new(5, 70, -2)
+        );
+
+        let saved = test
+            .world
+            .get::<BlockSerializer>(sign)
+            .serialize(&test.game, &test.world.entity(sign).unwrap());
+        match saved.kind {
+            BlockEntityKind::Sign { text1, text3, .. } => {
+                assert_eq!(text1, text.0[0]);
+                assert_eq!(text3, text.0[2]);
+            }
+            kind => panic!("expected sign, got {:?}", kind),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::ShouldReplace;
+use anyhow::bail;
+use feather_core::anvil::block_entity::{
+    BlockEntityData, BlockEntityKind, BlockEntityVariant, SkullOwner,
+};
+use feather_core::network::Packet;
+use feather_core::util::BlockPosition;
+use feather_server_types::{
+    BlockEntityLoaderRegistration, BlockSerializer, Game, SpawnPacketCreator,
+};
+use fecs::{EntityBuilder, EntityRef};
+
+inventory::submit!(BlockEntityLoaderRegistration {
+    f: &load,
+    kind: BlockEntityVariant::Skull,
+});
+
+/// `UpdateBlockEntity` action which sets the owner of a skull.
+const UPDATE_ACTION: u8 = 4;
+
+/// Marker component for skulls and heads. The kind
+/// of head is stored in the block state.
+pub struct Skull;
+
+/// The player whose skin is shown on a player head.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Owner(pub SkullOwner);
+
+/// Creates a skull without an owner.
+pub fn create(pos: BlockPosition) -> EntityBuilder {
+    crate::base(pos)
+        .with(Skull)
+        .with(SpawnPacketCreator(&create_spawn_packet))
+        .with(BlockSerializer(&serialize))
+        .with(ShouldReplace(crate::kind_changed))
+}
+
+fn create_spawn_packet(accessor: &EntityRef) -> Box<dyn Packet> {
+    Box::new(crate::update_packet(UPDATE_ACTION, &data(accessor)))
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> BlockEntityData {
+    data(accessor)
+}
+
+fn data(accessor: &EntityRef) -> BlockEntityData {
+    BlockEntityData {
+        base: crate::serialize_base(accessor),
+        kind: BlockEntityKind::Skull {
+            owner: accessor.try_get::<Owner>().map(|owner| owner.0.clone()),
+        },
+    }
+}
+
+fn load(data: BlockEntityData) -> anyhow::Result<EntityBuilder> {
+    let pos = crate::load_base(&data.base);
+    match data.kind {
+        BlockEntityKind::Skull { owner } => {
+            let builder = create(pos);
+            Ok(match owner {
+                Some(owner) => builder.with(Owner(owner)),
+                None => builder,
+            })
+        }
+        _ => bail!("not a skull"),
+    }
+}
@@ -8,13 +8,15 @@ use std::sync::atomic::{AtomicU32, Ordering};
 use crate::chunk_worker;
 use ahash::AHashSet;
 use chunk_worker::ChunkSave;
-use feather_core::anvil::{block_entity::BlockEntityData, entity::EntityData};
+use feather_core::anvil::{
+    block_entity::BlockEntityData, entity::EntityData, region::ScheduledBlockUpdate,
+};
 use feather_core::chunk::Chunk;
 use feather_core::util::ChunkPosition;
 use feather_server_types::{
     ChunkHolder, ChunkHolderReleaseEvent, ChunkLoadEvent, ChunkLoadFailEvent, ChunkUnloadEvent,
     EntityDespawnEvent, EntitySpawnEvent, Game, HoldChunkRequest, LoadChunkRequest,
-    ReleaseChunkRequest, TPS,
+    ReleaseChunkRequest, ScheduledTick, TPS,
 };
 use feather_server_util::current_time_in_millis;
 use fecs::{Entity, World};
@@ -52,6 +54,12 @@ pub fn handle_chunk_worker_replies(
                     Ok(loaded) => {
                         game.chunk_map.insert(loaded.chunk);
 
+                        for update in &loaded.scheduled_ticks {
+                            if let Some(tick) = ScheduledTick::from_anvil(update, game.tick_count) {
+                                game.scheduled_ticks.insert(tick);
+                            }
+                        }
+
                         loaded.entities.into_iter().for_each(|builder| {
                             let entity = builder.build().spawn_in(world);
                             game.handle(world, EntitySpawnEvent { entity });
@@ -159,6 +167,7 @@ pub fn chunk_unload(
                     },
                 );
                 game.chunk_map.remove(unload.chunk);
+                game.scheduled_ticks.remove_chunk(unload.chunk);
                 log::trace!("Unloaded chunk at {}", unload.chunk);
             }
             chunk_unload_queue.queue.pop_front();
@@ -307,11 +316,13 @@ pub fn save_chunk(
     chunk: Arc<RwLock<Chunk>>,
     entities: SmallVec<[EntityData; 4]>,
     block_entities: SmallVec<[BlockEntityData; 4]>,
+    scheduled_ticks: Vec<ScheduledBlockUpdate>,
 ) {
     let save = ChunkSave {
         chunk,
         entities,
         block_entities,
+        scheduled_ticks,
     };
     handle
         .sender
@@ -10,7 +10,7 @@ use feather_core::anvil::entity::EntityData;
 use feather_core::anvil::region;
 use feather_core::anvil::{
     block_entity::BlockEntityData,
-    region::{RegionHandle, RegionPosition},
+    region::{ChunkCompression, RegionHandle, RegionPosition, ScheduledBlockUpdate},
 };
 use feather_core::chunk::Chunk;
 use feather_core::util::ChunkPosition;
@@ -32,6 +32,8 @@ pub struct ChunkSave {
     pub entities: SmallVec<[EntityData; 4]>,
     /// Block entities within this chunk.
     pub block_entities: SmallVec<[BlockEntityData; 4]>,
+    /// Scheduled ticks pending within this chunk.
+    pub scheduled_ticks: Vec<ScheduledBlockUpdate>,
 }
 
 /// Stores a chunk and associated data loaded
@@ -41,6 +43,9 @@ pub struct ChunkLoad {
     /// Entities within this chunk, pre-built with their components.
     /// Includes block entities as well.
     pub entities: SmallVec<[EntityBuilder; 4]>,
+    /// Scheduled ticks pending within this chunk, due
+    /// relative to the game tick at which they are loaded.
+    pub scheduled_ticks: Vec<ScheduledBlockUpdate>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -91,6 +96,9 @@ struct ChunkWorker {
 
     /// State for loading entities.
     entity_loader: EntityLoader,
+
+    /// Compression used for saved chunks.
+    compression: ChunkCompression,
 }
 
 /// Starts a chunk worker on a new thread.
@@ -99,6 +107,7 @@ struct ChunkWorker {
 pub fn start(
     world_dir: &Path,
     world_gen: Arc<dyn WorldGenerator>,
+    compression: ChunkCompression,
 ) -> (Sender<Request>, Receiver<Reply>) {
     let (request_tx, request_rx) = crossbeam::channel::unbounded();
     let (reply_tx, reply_rx) = crossbeam::channel::unbounded();
@@ -110,6 +119,7 @@ pub fn start(
         open_regions: AHashMap::new(),
         world_generator: world_gen,
         entity_loader: EntityLoader::new(),
+        compression,
     };
 
     log::info!("Starting chunk worker");
@@ -150,7 +160,12 @@ fn run(mut worker: ChunkWorker) {
 fn load_chunk(worker: &mut ChunkWorker, pos: ChunkPosition) -> Option<Reply> {
     let rpos = RegionPosition::from_chunk(pos);
 
-    let file = worker_region(&mut worker.open_regions, &worker.dir, rpos);
+    let file = worker_region(
+        &mut worker.open_regions,
+        &worker.dir,
+        rpos,
+        worker.compression,
+    );
     // Load from region file
     load_chunk_from_handle(
         pos,
@@ -171,7 +186,7 @@ fn load_chunk_from_handle(
     let result = handle.load_chunk(pos);
 
     match result {
-        Ok((chunk, entities, block_entities)) => {
+        Ok((chunk, entities, block_entities, scheduled_ticks)) => {
             let entities = entities
                 .into_iter()
                 .filter_map(|entity| entity_loader.load(entity))
@@ -185,7 +200,11 @@ fn load_chunk_from_handle(
             Some(Reply::LoadedChunk(
                 pos,
                 match entities {
-                    Ok(entities) => Ok(ChunkLoad { chunk, entities }),
+                    Ok(entities) => Ok(ChunkLoad {
+                        chunk,
+                        entities,
+                        scheduled_ticks,
+                    }),
                     Err(e) => Err(e),
                 },
             ))
@@ -222,6 +241,7 @@ fn generate_new_chunk(pos: ChunkPosition, generator: &Arc<dyn WorldGenerator>) -
         Ok(ChunkLoad {
             chunk: generator.generate_chunk(pos),
             entities: SmallVec::new(),
+            scheduled_ticks: Vec::new(),
         }),
     )
 }
@@ -231,11 +251,19 @@ fn save_chunk(worker: &mut ChunkWorker, save: ChunkSave) {
     let chunk = save.chunk.read();
     let rpos = RegionPosition::from_chunk(chunk.position());
 
-    let file = worker_region(&mut worker.open_regions, &worker.dir, rpos);
+    let file = worker_region(
+        &mut worker.open_regions,
+        &worker.dir,
+        rpos,
+        worker.compression,
+    );
 
-    let result = file
-        .handle
-        .save_chunk(&*chunk, &save.entities, &save.block_entities);
+    let result = file.handle.save_chunk(
+        &*chunk,
+        &save.entities,
+        &save.block_entities,
+        &save.scheduled_ticks,
+    );
 
     worker
         .sender
@@ -259,6 +287,7 @@ fn worker_region<'a>(
     open_regions: &'a mut AHashMap<RegionPosition, RegionFile>,
     dir: &PathBuf,
     rpos: RegionPosition,
+    compression: ChunkCompression,
 ) -> &'a mut RegionFile {
     if !is_region_loaded(open_regions, rpos) {
         // Need to load region into memory
@@ -268,7 +297,8 @@ fn worker_region<'a>(
             handle = region::create_region(&dir, rpos);
         }
 
-        let handle = handle.unwrap();
+        let mut handle = handle.unwrap();
+        handle.set_compression(compression);
 
         let last_used = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -4,13 +4,14 @@ use crate::{chunk_manager, ChunkWorkerHandle};
 use feather_core::anvil::entity::{AnimalData, BaseEntityData, EntityData};
 use feather_core::anvil::{
     block_entity::BlockEntityData,
-    player::{InventorySlot, PlayerData},
+    player::{ActiveEffectData, InventorySlot, PlayerData},
 };
 use feather_core::inventory::{Inventory, Window};
-use feather_core::util::{ChunkPosition, Gamemode, Position, Vec3d};
+use feather_core::util::{ChunkPosition, Dimension, Gamemode, Position, Vec3d};
 use feather_server_types::{
-    tasks, BlockSerializer, ChunkLoadEvent, ChunkUnloadEvent, ComponentSerializer, Game, Health,
-    HeldItem, PlayerLeaveEvent, Uuid, TICK_LENGTH, TPS,
+    tasks, ActiveEffects, BlockSerializer, BlocksFallen, ChunkLoadEvent, ChunkUnloadEvent,
+    ComponentSerializer, EnderChest, Experience, Game, Health, HeldItem, Hunger, PlayerLeaveEvent,
+    SpawnPoint, Uuid, TICK_LENGTH, TPS,
 };
 use fecs::{Entity, World};
 use std::collections::VecDeque;
@@ -119,7 +120,10 @@ pub fn save_chunk_at(
         .chunk_handle_at(pos)
         .expect("chunk does not exist");
 
-    if !chunk.write().check_modified() && game.chunk_entities.entities_in_chunk(pos).is_empty() {
+    if !chunk.write().check_modified()
+        && game.chunk_entities.entities_in_chunk(pos).is_empty()
+        && game.scheduled_ticks.in_chunk(pos).next().is_none()
+    {
         return;
     }
 
@@ -132,6 +136,10 @@ pub fn save_chunk_at(
         game.chunk_map.chunk_handle_at(pos).unwrap(),
         entities.collect(),
         block_entities.collect(),
+        game.scheduled_ticks
+            .in_chunk(pos)
+            .map(|tick| tick.to_anvil(game.tick_count))
+            .collect(),
     );
 }
 
@@ -196,14 +204,75 @@ pub fn save_player_data(game: &Game, world: &World, player: Entity) {
         .try_get::<Health>(player)
         .map(|health| health.0 as f32)
         .unwrap_or(1.0);
+    let hunger = world
+        .try_get::<Hunger>(player)
+        .map(|hunger| *hunger)
+        .unwrap_or_default();
+    let experience = world
+        .try_get::<Experience>(player)
+        .map(|experience| *experience)
+        .unwrap_or_default();
+    let spawn_point = world.try_get::<SpawnPoint>(player).map(|point| *point);
+    let ender_items = world
+        .try_get::<EnderChest>(player)
+        .map(|ender_chest| {
+            ender_chest
+                .0
+                .enumerate()
+                .filter_map(|(index, slot)| {
+                    slot.map(|slot| InventorySlot::from_inventory_index(index.slot as i8, slot))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    let active_effects = world
+        .try_get::<ActiveEffects>(player)
+        .map(|effects| {
+            effects
+                .0
+                .iter()
+                .map(|effect| ActiveEffectData {
+                    id: effect.id as i8,
+                    amplifier: effect.amplifier as i8,
+                    duration: effect.duration as i32,
+                    ambient: effect.ambient,
+                    show_particles: effect.show_particles,
+                    show_icon: effect.show_icon,
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
     let data = PlayerData {
         animal: AnimalData::new(
             BaseEntityData::new(*world.get::<Position>(player), Vec3d::broadcast(0.0)),
             health,
         ),
+        fall_distance: world
+            .try_get::<BlocksFallen>(player)
+            .map_or(0.0, |fallen| fallen.0 as f32),
+        dimension: world
+            .try_get::<Dimension>(player)
+            .map_or(Dimension::Overwold, |dimension| *dimension)
+            .id(),
         gamemode: world.get::<Gamemode>(player).id() as i32,
         inventory,
         held_item: world.get::<HeldItem>(player).0 as i32,
+        ender_items,
+        food_level: hunger.food as i32,
+        food_saturation_level: hunger.saturation,
+        food_exhaustion_level: hunger.exhaustion,
+        food_tick_timer: hunger.tick_timer as i32,
+        xp_level: experience.level as i32,
+        xp_progress: experience.progress,
+        xp_total: experience.total as i32,
+        xp_seed: experience.seed,
+        score: experience.score,
+        spawn_x: spawn_point.map(|point| point.position.x),
+        spawn_y: spawn_point.map(|point| point.position.y),
+        spawn_z: spawn_point.map(|point| point.position.z),
+        spawn_forced: spawn_point.map(|point| point.forced),
+        active_effects,
     };
 
     let uuid = *world.get::<Uuid>(player);
@@ -0,0 +1,128 @@
+//! Provides the `#[command]` attribute used by `feather-server-commands`.
+//!
+//! It wraps `lieutenant`'s attribute of the same name, adding
+//! a required operator level to each command. The generated
+//! function checks the sender's permissions before running,
+//! and the command's usage is registered with the command tree
+//! sent to clients.
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_macro_input, AttributeArgs, FnArg, ItemFn, Lit, LitStr, Meta, NestedMeta, Pat, Stmt, Type,
+};
+
+/// Declares a command.
+///
+/// Takes the command's `usage`, in the format accepted
+/// by `lieutenant`, and the operator `level` required to run it:
+///
+/// ```ignore
+/// #[command(usage = "kick <targets>", level = 3)]
+/// pub fn kick_targets(ctx: &mut CommandCtx, targets: MultiplePlayers) -> anyhow::Result<()> {
+///     ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn command(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(args as AttributeArgs);
+    let function = parse_macro_input!(input as ItemFn);
+
+    expand(args, function)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(args: AttributeArgs, mut function: ItemFn) -> syn::Result<TokenStream> {
+    let mut usage = None;
+    let mut level = None;
+    for arg in args {
+        match arg {
+            NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("usage") => {
+                match pair.lit {
+                    Lit::Str(lit) => usage = Some(lit),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected a string")),
+                }
+            }
+            NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("level") => {
+                match pair.lit {
+                    Lit::Int(lit) => level = Some(lit.base10_parse::<u8>()?),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected an integer")),
+                }
+            }
+            arg => return Err(syn::Error::new_spanned(arg, "unknown argument")),
+        }
+    }
+
+    let usage = usage.ok_or_else(|| syn::Error::new(Span::call_site(), "missing `usage`"))?;
+    let level = level.ok_or_else(|| syn::Error::new(Span::call_site(), "missing `level`"))?;
+
+    let mut inputs = function.sig.inputs.iter();
+    let ctx = match inputs.next() {
+        Some(FnArg::Typed(input)) => match &*input.pat {
+            Pat::Ident(pat) => pat.ident.clone(),
+            pat => return Err(syn::Error::new_spanned(pat, "expected an identifier")),
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &function.sig,
+                "commands must take a `&mut CommandCtx`",
+            ))
+        }
+    };
+    let mut argument_types = inputs.map(|input| match input {
+        FnArg::Typed(input) => Ok(&*input.ty),
+        input => Err(syn::Error::new_spanned(input, "unexpected receiver")),
+    });
+
+    let usage_string = usage.value();
+    let root = usage_string
+        .split_whitespace()
+        .next()
+        .and_then(|literal| literal.split('|').next())
+        .ok_or_else(|| syn::Error::new_spanned(&usage, "empty usage"))?;
+
+    let mut nodes = vec![];
+    for word in usage_string.split_whitespace() {
+        if word.starts_with('<') && word.ends_with('>') {
+            let name = &word[1..word.len() - 1];
+            let ty: &Type = argument_types.next().ok_or_else(|| {
+                syn::Error::new_spanned(&usage, format!("no parameter for `{}`", name))
+            })??;
+            nodes.push(quote! {
+                crate::tree::UsageNode::Argument {
+                    name: #name,
+                    parser: <#ty as crate::tree::DeclaredArgument>::parser(),
+                }
+            });
+        } else {
+            let names: Vec<&str> = word.split('|').collect();
+            nodes.push(quote! {
+                crate::tree::UsageNode::Literal(&[#(#names),*])
+            });
+        }
+    }
+
+    let check: Stmt = syn::parse_quote! {
+        crate::permissions::check(#ctx, #level, #root)?;
+    };
+    function.block.stmts.insert(0, check);
+
+    let name = LitStr::new(&function.sig.ident.to_string(), function.sig.ident.span());
+
+    Ok(quote! {
+        #[lieutenant::command(usage = #usage)]
+        #function
+
+        inventory::submit! {
+            crate::tree::CommandSpec {
+                name: #name,
+                level: #level,
+                nodes: vec![#(#nodes),*],
+            }
+        }
+    })
+}
@@ -0,0 +1,134 @@
+//! Shared logic for the `/ban`, `/ban-ip`, `/banlist`, `/pardon`,
+//! `/pardon-ip`, `/whitelist`, `/op` and `/deop` commands.
+
+use crate::selector::{select_players, SelectorError};
+use crate::CommandCtx;
+use feather_server_types::{offline_mode_uuid, Name, Player, Profile, Uuid};
+use fecs::{component, Entity, IntoQuery, Read, World};
+use std::net::{IpAddr, SocketAddr};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AccessCommandError {
+    #[error("That player does not exist")]
+    UnknownPlayer,
+    #[error("Invalid IP address or unknown player")]
+    InvalidIpOrPlayer,
+    #[error("Invalid IP address")]
+    InvalidIp,
+    #[error(transparent)]
+    Selector(#[from] SelectorError),
+    #[error("Nothing changed. The player is already banned")]
+    AlreadyBanned,
+    #[error("Nothing changed. The player isn't banned")]
+    NotBanned,
+    #[error("Nothing changed. That IP is already banned")]
+    IpAlreadyBanned,
+    #[error("Nothing changed. That IP isn't banned")]
+    IpNotBanned,
+    #[error("Nothing changed. The player already is an operator")]
+    AlreadyAnOperator,
+    #[error("Nothing changed. The player is not an operator")]
+    NotAnOperator,
+    #[error("Player is already whitelisted")]
+    AlreadyWhitelisted,
+    #[error("Player is not whitelisted")]
+    NotWhitelisted,
+    #[error("Whitelist is already turned on")]
+    WhitelistAlreadyOn,
+    #[error("Whitelist is already turned off")]
+    WhitelistAlreadyOff,
+}
+
+/// Resolves a game profile argument to profiles.
+///
+/// The argument may be a target selector, a player name or a UUID.
+/// Players named directly need not be online: their profile is
+/// looked up in the access lists, or computed in offline mode.
+pub fn game_profiles(ctx: &CommandCtx, argument: &str) -> Result<Vec<Profile>, AccessCommandError> {
+    if argument.starts_with('@') {
+        return Ok(select_players(ctx, argument)?
+            .into_iter()
+            .filter_map(|player| profile_of(&ctx.world, player))
+            .collect());
+    }
+
+    let online = <(Read<Uuid>, Read<Name>)>::query()
+        .filter(component::<Player>())
+        .iter(ctx.world.inner())
+        .map(|(uuid, name)| Profile {
+            uuid: *uuid,
+            name: name.0.clone(),
+        })
+        .collect::<Vec<_>>();
+    let access_lists = ctx.game.access_lists.read();
+
+    let profile = if let Ok(uuid) = Uuid::parse_str(argument) {
+        online
+            .into_iter()
+            .find(|profile| profile.uuid == uuid)
+            .unwrap_or_else(|| Profile {
+                uuid,
+                name: argument.to_owned(),
+            })
+    } else if let Some(profile) = online
+        .into_iter()
+        .find(|profile| profile.name.eq_ignore_ascii_case(argument))
+    {
+        profile
+    } else if let Some(profile) = access_lists.profile_by_name(argument) {
+        profile.clone()
+    } else if !ctx.game.config.server.online_mode {
+        Profile {
+            uuid: offline_mode_uuid(argument),
+            name: argument.to_owned(),
+        }
+    } else {
+        return Err(AccessCommandError::UnknownPlayer);
+    };
+
+    Ok(vec![profile])
+}
+
+/// Resolves an IP ban target, which is either an IP
+/// address or the name of an online player.
+pub fn ban_ip_target(ctx: &CommandCtx, argument: &str) -> Result<IpAddr, AccessCommandError> {
+    if let Ok(ip) = argument.parse() {
+        return Ok(ip);
+    }
+
+    <(Read<Name>, Read<SocketAddr>)>::query()
+        .filter(component::<Player>())
+        .iter(ctx.world.inner())
+        .find(|(name, _)| name.0.eq_ignore_ascii_case(argument))
+        .map(|(_, addr)| addr.ip())
+        .ok_or(AccessCommandError::InvalidIpOrPlayer)
+}
+
+/// Returns the online players with the given UUID or IP address.
+pub fn online_players(world: &World, filter: impl Fn(Uuid, IpAddr) -> bool) -> Vec<Entity> {
+    <(Read<Uuid>, Read<SocketAddr>)>::query()
+        .filter(component::<Player>())
+        .iter_entities(world.inner())
+        .filter(|(_, (uuid, addr))| filter(**uuid, addr.ip()))
+        .map(|(entity, _)| entity)
+        .collect()
+}
+
+/// Returns the name recorded as the source of bans
+/// issued by the command sender.
+pub fn ban_source(ctx: &CommandCtx) -> String {
+    if ctx.world.has::<Player>(ctx.sender) {
+        if let Some(name) = ctx.world.try_get::<Name>(ctx.sender) {
+            return name.0.clone();
+        }
+    }
+    String::from("Server")
+}
+
+fn profile_of(world: &World, player: Entity) -> Option<Profile> {
+    Some(Profile {
+        uuid: *world.try_get::<Uuid>(player)?,
+        name: world.try_get::<Name>(player)?.0.clone(),
+    })
+}
@@ -1,14 +1,19 @@
+use crate::tree::{DeclaredArgument, Parser, StringMode};
 use crate::CommandCtx;
 
+use feather_core::blocks::{BlockId, BlockKind};
 use feather_core::position;
+use feather_core::text::Text;
 //use feather_core::util::{Gamemode, Position};
 use feather_core::util::Position;
 //use feather_definitions::Item;
 //use feather_server_types::{Game, Name, NetworkId, Player};
 //use fecs::{component, Entity, IntoQuery, Read, World};
+use feather_server_types::DisplaySlot;
 use lieutenant::{ArgumentKind, Input};
 //use rand::Rng;
 //use smallvec::SmallVec;
+use std::collections::BTreeMap;
 //use std::convert::Infallible;
 use std::num::ParseFloatError;
 use std::str::FromStr;
@@ -1155,10 +1160,21 @@ impl ArgumentKind<CommandCtx> for Coordinates {
 }
 
 #[derive(Debug, Error)]
-pub enum BlockPredicateParseError {}
+pub enum BlockPredicateParseError {
+    #[error("block tags are not supported yet")]
+    TagsUnsupported,
+    #[error(transparent)]
+    BlockState(#[from] BlockStateParseError),
+}
 
 #[derive(Clone, Debug)]
-pub struct BlockPredicate(pub String);
+pub struct BlockPredicate {
+    /// The kind of block matched by this predicate.
+    pub kind: BlockKind,
+    /// Properties which must have the given values.
+    /// Properties not contained in this map may have any value.
+    pub properties: BTreeMap<String, String>,
+}
 /*
 This is used in execute if/unless stamtemtns were we check for some predicate
 of the block at a possition.
@@ -1166,53 +1182,175 @@ of the block at a possition.
 https://minecraft.gamepedia.com/Predicate
 
 Since 1.13 the block states are specified like this:
-    lever[face=floor] or minecraft:lever[face=floor],
+    lever[face=floor] or minecraft:lever[face=floor],
 
 nbt data can also be querried by adding {foo:nbt} at the end. This means that we are
 suposed to be able to parse 'minecraft:lever[face=floor]{foo:nbt}' as a valid block preicate.
 
 It is also suposed to be able to parse Block tags, https://minecraft.gamepedia.com/Tag#Block_tags
 */
+impl BlockPredicate {
+    /// Returns whether the given block matches this predicate.
+    pub fn matches(&self, block: BlockId) -> bool {
+        if block.kind() != self.kind {
+            return false;
+        }
+
+        let block_properties = block.to_properties_map();
+        self.properties
+            .iter()
+            .all(|(name, value)| block_properties.get(name.as_str()) == Some(&value.as_str()))
+    }
+}
+
+impl FromStr for BlockPredicate {
+    type Err = BlockPredicateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // TODO: block tags
+        if s.starts_with('#') {
+            return Err(BlockPredicateParseError::TagsUnsupported);
+        }
+
+        let (block, properties) = parse_block_state(s)?;
+        Ok(BlockPredicate {
+            kind: block.kind(),
+            properties,
+        })
+    }
+}
+
 impl ArgumentKind<CommandCtx> for BlockPredicate {
     type ParseError = BlockPredicateParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
         let text = input.advance_until(" ");
-        //TODO
-        Ok(BlockPredicate(text.to_owned()))
+        BlockPredicate::from_str(text)
     }
 }
 
-#[derive(Debug, Error)]
-pub enum BlockStateParseError {}
+#[derive(Debug, Error, PartialEq)]
+pub enum BlockStateParseError {
+    #[error("unknown block type '{0}'")]
+    UnknownBlock(String),
+    #[error("block properties of '{0}' are not closed with ']'")]
+    UnclosedProperties(String),
+    #[error("expected '<property>=<value>', found '{0}'")]
+    MalformedProperty(String),
+    #[error("block {block} does not have property '{property}'")]
+    UnknownProperty { block: String, property: String },
+    #[error("block {block} does not accept '{value}' for property '{property}'")]
+    InvalidValue {
+        block: String,
+        property: String,
+        value: String,
+    },
+}
 
 #[derive(Clone, Debug)]
-pub struct BlockState(pub String);
+pub struct BlockState(pub BlockId);
 /**
  * It seems that block predicate and block state are close to identical, just that BlockState does
  * not accept Block Tags. https://minecraft.gamepedia.com/Tag#Block_tags
  */
 
+impl FromStr for BlockState {
+    type Err = BlockStateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_block_state(s).map(|(block, _)| BlockState(block))
+    }
+}
+
 impl ArgumentKind<CommandCtx> for BlockState {
     type ParseError = BlockStateParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
         let text = input.advance_until(" ");
-        //TODO
-        Ok(BlockState(text.to_owned()))
+        BlockState::from_str(text)
+    }
+}
+
+/// Parses a block state of the form `minecraft:lever[face=floor]`.
+///
+/// Returns the block, with unspecified properties set to
+/// their default values, along with the properties which
+/// were specified explicitly. The namespace is optional and
+/// NBT data (`{...}`) is currently ignored.
+fn parse_block_state(s: &str) -> Result<(BlockId, BTreeMap<String, String>), BlockStateParseError> {
+    // TODO: NBT
+    let s = match s.find('{') {
+        Some(index) => &s[..index],
+        None => s,
+    };
+
+    let (name, properties) = match s.find('[') {
+        Some(index) => {
+            let properties = &s[index + 1..];
+            if !properties.ends_with(']') {
+                return Err(BlockStateParseError::UnclosedProperties(s.to_owned()));
+            }
+            (&s[..index], &properties[..properties.len() - 1])
+        }
+        None => (s, ""),
+    };
+
+    let identifier = if name.contains(':') {
+        name.to_owned()
+    } else {
+        format!("minecraft:{}", name)
+    };
+
+    let mut block = BlockId::from_identifier(&identifier)
+        .ok_or_else(|| BlockStateParseError::UnknownBlock(name.to_owned()))?;
+
+    let mut all_properties: BTreeMap<String, String> = block
+        .to_properties_map()
+        .into_iter()
+        .map(|(name, value)| (name.to_owned(), value.to_owned()))
+        .collect();
+    let mut specified = BTreeMap::new();
+
+    for property in properties
+        .split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+    {
+        let mut split = property.splitn(2, '=');
+        let (property_name, value) = match (split.next(), split.next()) {
+            (Some(name), Some(value)) => (name.trim(), value.trim()),
+            _ => return Err(BlockStateParseError::MalformedProperty(property.to_owned())),
+        };
+
+        match all_properties.get_mut(property_name) {
+            Some(old) => *old = value.to_owned(),
+            None => {
+                return Err(BlockStateParseError::UnknownProperty {
+                    block: identifier,
+                    property: property_name.to_owned(),
+                })
+            }
+        }
+
+        block = BlockId::from_identifier_and_properties(&identifier, &all_properties).ok_or_else(
+            || BlockStateParseError::InvalidValue {
+                block: identifier.clone(),
+                property: property_name.to_owned(),
+                value: value.to_owned(),
+            },
+        )?;
+        specified.insert(property_name.to_owned(), value.to_owned());
     }
+
+    Ok((block, specified))
 }
 
 #[derive(Debug, Error)]
@@ -1270,6 +1408,32 @@ impl FromStr for Color {
     }
 }
 
+impl Color {
+    /// Returns the text color, or `None` for `reset`.
+    pub fn to_text_color(&self) -> Option<feather_core::text::Color> {
+        use feather_core::text::Color as TextColor;
+        Some(match self {
+            Color::Aqua => TextColor::Aqua,
+            Color::Black => TextColor::Black,
+            Color::Blue => TextColor::Blue,
+            Color::DarkAqua => TextColor::DarkAqua,
+            Color::DarkBlue => TextColor::DarkBlue,
+            Color::DarkGray => TextColor::DarkGray,
+            Color::DarkGreen => TextColor::DarkGreen,
+            Color::DarkPurple => TextColor::DarkPurple,
+            Color::DarkRed => TextColor::DarkRed,
+            Color::Gold => TextColor::Gold,
+            Color::Gray => TextColor::Gray,
+            Color::Green => TextColor::Green,
+            Color::LightPurple => TextColor::LightPurple,
+            Color::Red => TextColor::Red,
+            Color::Reset => return None,
+            Color::Yellow => TextColor::Yellow,
+            Color::White => TextColor::White,
+        })
+    }
+}
+
 impl ArgumentKind<CommandCtx> for Color {
     type ParseError = ColorParseError;
 
@@ -1316,15 +1480,12 @@ impl ArgumentKind<CommandCtx> for ColumnPos {
 
 #[derive(Debug, Error)]
 pub enum ComponentParseError {
-    #[error("Does not start with a open tag!")]
-    DoesNotStartWithOpenTag,
-
-    #[error("Does not have a closing tag!")]
-    HasNoClosingTag,
+    #[error("Invalid chat component: {0}")]
+    Invalid(#[from] serde_json::Error),
 }
 
 #[derive(Clone, Debug)]
-pub struct Component(pub String);
+pub struct Component(pub Text);
 /*
 The component is Raw JSON text format.
 https://minecraft.gamepedia.com/Raw_JSON_text_format
@@ -1333,40 +1494,33 @@ https://minecraft.gamepedia.com/Raw_JSON_text_format
 impl ArgumentKind<CommandCtx> for Component {
     type ParseError = ComponentParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        let mut text = input.advance_until(" ");
-
-        if !text.starts_with('{') {
-            return false;
-        }
-
-        while !text.ends_with('}') {
-            if input.is_empty() {
-                return false;
-            }
-            text = input.advance_until("}");
-        }
-
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
-        let mut text = input.advance_until(" ");
-        let mut res = text.to_owned();
+        // The JSON may contain spaces, so consume words
+        // until they form a valid component.
+        let mut res = input.advance_until(" ").to_owned();
 
-        if !text.starts_with('{') {
-            return Err(Self::ParseError::DoesNotStartWithOpenTag);
-        }
-
-        while !text.ends_with('}') {
-            if input.is_empty() {
-                return Err(Self::ParseError::HasNoClosingTag);
+        loop {
+            match serde_json::from_str(&res) {
+                Ok(text) => return Ok(Component(text)),
+                Err(e) if input.is_empty() => return Err(e.into()),
+                Err(_) => {
+                    res.push(' ');
+                    res += input.advance_until(" ");
+                }
             }
-            text = input.advance_until(" ");
-            res += text;
         }
+    }
+}
+
+impl FromStr for Component {
+    type Err = ComponentParseError;
 
-        Ok(Component(res))
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Component(serde_json::from_str(s)?))
     }
 }
 
@@ -1761,13 +1915,11 @@ impl ArgumentKind<CommandCtx> for Message {
     type ParseError = MessageParseError;
 
     fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        let was_empty = input.is_empty();
-        input.advance_until(" ");
-        was_empty
+        !input.advance_to_end().is_empty()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
-        let text = input.advance_until(" ");
+        let text = input.advance_to_end();
         //TODO convert all target selectors with the name of the entety.
         Ok(Message(text.to_owned()))
     }
@@ -1884,46 +2036,181 @@ impl ArgumentKind<CommandCtx> for Objective {
 }
 
 #[derive(Debug, Error)]
-pub enum ObjectiveCriteriaParseError {}
+pub enum ObjectiveCriteriaParseError {
+    #[error("Unknown criterion '{0}'")]
+    Unknown(String),
+}
 
 #[derive(Clone, Debug)]
 pub struct ObjectiveCriteria(pub String);
 
+/// Criteria without a parameter.
+const SIMPLE_CRITERIA: [&str; 11] = [
+    "dummy",
+    "trigger",
+    "deathCount",
+    "playerKillCount",
+    "totalKillCount",
+    "health",
+    "xp",
+    "level",
+    "food",
+    "air",
+    "armor",
+];
+
+/// Statistic types usable in criteria of the
+/// form `minecraft.<type>:<id>`.
+const STATISTIC_TYPES: [&str; 9] = [
+    "mined",
+    "crafted",
+    "used",
+    "broken",
+    "picked_up",
+    "dropped",
+    "killed",
+    "killed_by",
+    "custom",
+];
+
+impl FromStr for ObjectiveCriteria {
+    type Err = ObjectiveCriteriaParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let valid = if SIMPLE_CRITERIA.contains(&s) {
+            true
+        } else if let Some(color) = s
+            .strip_prefix("teamkill.")
+            .or_else(|| s.strip_prefix("killedByTeam."))
+        {
+            Color::from_str(color).is_ok() && color != "reset"
+        } else {
+            match s.find(':') {
+                Some(index) => {
+                    let ty = &s[..index];
+                    let ty = ty.strip_prefix("minecraft.").unwrap_or(ty);
+                    STATISTIC_TYPES.contains(&ty) && index + 1 < s.len()
+                }
+                None => false,
+            }
+        };
+
+        if valid {
+            Ok(ObjectiveCriteria(s.to_owned()))
+        } else {
+            Err(ObjectiveCriteriaParseError::Unknown(s.to_owned()))
+        }
+    }
+}
+
 impl ArgumentKind<CommandCtx> for ObjectiveCriteria {
     type ParseError = ObjectiveCriteriaParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
         let text = input.advance_until(" ");
-        //TODO
-        Ok(ObjectiveCriteria(text.to_owned()))
+        ObjectiveCriteria::from_str(text)
     }
 }
 
 #[derive(Debug, Error)]
-pub enum OperationParseError {}
+pub enum OperationParseError {
+    #[error("Invalid operation '{0}'")]
+    Invalid(String),
+}
 
-#[derive(Clone, Debug)]
-pub struct Operation(pub String);
+/// An operation of `/scoreboard players operation`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operation {
+    /// `=`
+    Assign,
+    /// `+=`
+    Add,
+    /// `-=`
+    Subtract,
+    /// `*=`
+    Multiply,
+    /// `/=`
+    Divide,
+    /// `%=`
+    Modulo,
+    /// `<`
+    Min,
+    /// `>`
+    Max,
+    /// `><`
+    Swap,
+}
+
+impl Operation {
+    /// Applies the operation to a target score `a` and source score `b`.
+    ///
+    /// Like vanilla, division rounds towards negative infinity and
+    /// division by zero leaves the target unchanged.
+    pub fn apply(self, a: &mut i32, b: &mut i32) {
+        match self {
+            Operation::Assign => *a = *b,
+            Operation::Add => *a = a.wrapping_add(*b),
+            Operation::Subtract => *a = a.wrapping_sub(*b),
+            Operation::Multiply => *a = a.wrapping_mul(*b),
+            Operation::Divide => {
+                if *b != 0 {
+                    *a = floor_div(*a, *b);
+                }
+            }
+            Operation::Modulo => {
+                if *b != 0 {
+                    *a = a.wrapping_sub(floor_div(*a, *b).wrapping_mul(*b));
+                }
+            }
+            Operation::Min => *a = (*a).min(*b),
+            Operation::Max => *a = (*a).max(*b),
+            Operation::Swap => std::mem::swap(a, b),
+        }
+    }
+}
+
+fn floor_div(a: i32, b: i32) -> i32 {
+    let quotient = a.wrapping_div(b);
+    if a % b != 0 && (a < 0) != (b < 0) {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
+
+impl FromStr for Operation {
+    type Err = OperationParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "=" => Ok(Operation::Assign),
+            "+=" => Ok(Operation::Add),
+            "-=" => Ok(Operation::Subtract),
+            "*=" => Ok(Operation::Multiply),
+            "/=" => Ok(Operation::Divide),
+            "%=" => Ok(Operation::Modulo),
+            "<" => Ok(Operation::Min),
+            ">" => Ok(Operation::Max),
+            "><" => Ok(Operation::Swap),
+            _ => Err(OperationParseError::Invalid(s.to_owned())),
+        }
+    }
+}
 
 impl ArgumentKind<CommandCtx> for Operation {
     type ParseError = OperationParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
         let text = input.advance_until(" ");
-        //TODO
-        Ok(Operation(text.to_owned()))
+        Operation::from_str(text)
     }
 }
 
@@ -1950,24 +2237,50 @@ impl ArgumentKind<CommandCtx> for Particle {
 }
 
 #[derive(Debug, Error)]
-pub enum ResourceLocationParseError {}
+pub enum ResourceLocationParseError {
+    #[error("Invalid resource location '{0}'")]
+    Invalid(String),
+}
 
-#[derive(Clone, Debug)]
+/// A namespaced ID such as `minecraft:stone`. The
+/// namespace defaults to `minecraft` if omitted.
+#[derive(Clone, Debug, PartialEq)]
 pub struct ResourceLocation(pub String);
 
+impl FromStr for ResourceLocation {
+    type Err = ResourceLocationParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (namespace, path) = match s.find(':') {
+            Some(index) => (&s[..index], &s[index + 1..]),
+            None => ("minecraft", s),
+        };
+
+        let namespace_valid = namespace
+            .chars()
+            .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.'));
+        let path_valid = path
+            .chars()
+            .all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_' | '-' | '.' | '/'));
+
+        if namespace.is_empty() || path.is_empty() || !namespace_valid || !path_valid {
+            return Err(ResourceLocationParseError::Invalid(s.to_owned()));
+        }
+
+        Ok(ResourceLocation(format!("{}:{}", namespace, path)))
+    }
+}
+
 impl ArgumentKind<CommandCtx> for ResourceLocation {
     type ParseError = ResourceLocationParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
         let text = input.advance_until(" ");
-        //TODO
-        Ok(ResourceLocation(text.to_owned()))
+        ResourceLocation::from_str(text)
     }
 }
 
@@ -2038,24 +2351,26 @@ impl ArgumentKind<CommandCtx> for SingleScoreHolder {
 }
 
 #[derive(Debug, Error)]
-pub enum ScoreboardSlotParseError {}
+pub enum ScoreboardSlotParseError {
+    #[error("Unknown display slot '{0}'")]
+    Unknown(String),
+}
 
 #[derive(Clone, Debug)]
-pub struct ScoreboardSlot(pub String);
+pub struct ScoreboardSlot(pub DisplaySlot);
 
 impl ArgumentKind<CommandCtx> for ScoreboardSlot {
     type ParseError = ScoreboardSlotParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
         let text = input.advance_until(" ");
-        //TODO
-        Ok(ScoreboardSlot(text.to_owned()))
+        DisplaySlot::from_str(text)
+            .map(ScoreboardSlot)
+            .map_err(|_| ScoreboardSlotParseError::Unknown(text.to_owned()))
     }
 }
 
@@ -2147,25 +2462,38 @@ impl ArgumentKind<CommandCtx> for Uuid {
     }
 }
 
-#[derive(Debug, Error)]
-pub enum Vec2ParseError {}
+/// Parses a horizontal position (<x> <z>), with
+/// support for relative coordinates like `Coordinates`.
+#[derive(Copy, Clone, Debug)]
+pub struct Vec2 {
+    pub x: Coordinate,
+    pub z: Coordinate,
+}
 
-#[derive(Clone, Debug)]
-pub struct Vec2(pub String);
+impl Vec2 {
+    /// Converts these coordinates into absolute x and z
+    /// coordinates, relative to the given position.
+    pub fn into_xz(self, relative_to: Position) -> (f64, f64) {
+        let direction = relative_to.direction();
+        (
+            Coordinates::coordinate_into_absolute(self.x, relative_to.x, direction.x),
+            Coordinates::coordinate_into_absolute(self.z, relative_to.z, direction.z),
+        )
+    }
+}
 
 impl ArgumentKind<CommandCtx> for Vec2 {
-    type ParseError = Vec2ParseError;
+    type ParseError = CoordinatesParseError;
 
-    fn satisfies<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
-        input.advance_until(" ");
-        //TODO
-        true
+    fn satisfies<'a>(ctx: &CommandCtx, input: &mut Input<'a>) -> bool {
+        Self::parse(ctx, input).is_ok()
     }
 
     fn parse<'a>(_ctx: &CommandCtx, input: &mut Input<'a>) -> Result<Self, Self::ParseError> {
-        let text = input.advance_until(" ");
-        //TODO
-        Ok(Vec2(text.to_owned()))
+        let x = Coordinate::from_str(input.advance_until(" "))?;
+        let z = Coordinate::from_str(input.advance_until(" "))?;
+
+        Ok(Vec2 { x, z })
     }
 }
 
@@ -2190,3 +2518,201 @@ impl ArgumentKind<CommandCtx> for Vec3 {
         Ok(Vec3(text.to_owned()))
     }
 }
+
+macro_rules! declared_arguments {
+    ($($argument:ty => $parser:expr,)*) => {
+        $(
+            impl DeclaredArgument for $argument {
+                fn parser() -> Parser {
+                    $parser
+                }
+            }
+        )*
+    };
+}
+
+declared_arguments! {
+    BlockPredicate => Parser::new("minecraft:block_predicate"),
+    BlockState => Parser::new("minecraft:block_state"),
+    BoolArgument => Parser::new("brigadier:bool"),
+    Color => Parser::new("minecraft:color"),
+    ColumnPos => Parser::new("minecraft:column_pos"),
+    Component => Parser::new("minecraft:component"),
+    Coordinates => Parser::new("minecraft:block_pos"),
+    DoubleArgument => Parser::double(None, None),
+    Enchantment => Parser::new("minecraft:item_enchantment"),
+    EntityAnchor => Parser::new("minecraft:entity_anchor"),
+    EntitySummon => Parser::new("minecraft:entity_summon"),
+    FloatArgument => Parser::float(None, None),
+    FloatArgumentBetween0And1 => Parser::float(Some(0.0), Some(1.0)),
+    FloatArgumentBetween0And2 => Parser::float(Some(0.0), Some(2.0)),
+    FloatArgumentGreaterThen1 => Parser::float(Some(1.0), None),
+    FloatArgumentPositive => Parser::float(Some(0.0), None),
+    GameProfile => Parser::new("minecraft:game_profile"),
+    IntRange => Parser::new("minecraft:int_range"),
+    IntegerArgument => Parser::integer(None, None),
+    IntegerArgumentBetween0And1000000 => Parser::integer(Some(0), Some(1_000_000)),
+    IntegerArgumentBetween0And255 => Parser::integer(Some(0), Some(255)),
+    IntegerArgumentBetween0And65535 => Parser::integer(Some(0), Some(65535)),
+    IntegerArgumentBetween1And1000000 => Parser::integer(Some(1), Some(1_000_000)),
+    IntegerArgumentBetween1And64 => Parser::integer(Some(1), Some(64)),
+    IntegerArgumentGreaterThen1 => Parser::integer(Some(1), None),
+    IntegerArgumentPositive => Parser::integer(Some(0), None),
+    ItemSlot => Parser::new("minecraft:item_slot"),
+    ItemStack => Parser::new("minecraft:item_stack"),
+    Message => Parser::new("minecraft:message"),
+    MinecraftFunction => Parser::new("minecraft:function"),
+    MobEffect => Parser::new("minecraft:mob_effect"),
+    MultipleEntities => Parser::entity(false, false),
+    MultiplePlayers => Parser::entity(false, true),
+    MultipleScoreHolders => Parser::score_holder(true),
+    NbtCommandTag => Parser::new("minecraft:nbt_compound_tag"),
+    NbtPath => Parser::new("minecraft:nbt_path"),
+    NbtTag => Parser::new("minecraft:nbt_tag"),
+    Objective => Parser::new("minecraft:objective"),
+    ObjectiveCriteria => Parser::new("minecraft:objective_criteria"),
+    Operation => Parser::new("minecraft:operation"),
+    Particle => Parser::new("minecraft:particle"),
+    Predicate => Parser::new("minecraft:resource_location"),
+    ResourceLocation => Parser::new("minecraft:resource_location"),
+    Rotation => Parser::new("minecraft:rotation"),
+    ScoreboardSlot => Parser::new("minecraft:scoreboard_slot"),
+    SingleEntities => Parser::entity(true, false),
+    SinglePlayer => Parser::entity(true, true),
+    SingleScoreHolder => Parser::score_holder(false),
+    StringArgumentGreedy => Parser::string(StringMode::Greedy),
+    StringArgumentPhrase => Parser::string(StringMode::Phrase),
+    StringArgumentWord => Parser::string(StringMode::Word),
+    Team => Parser::new("minecraft:team"),
+    Time => Parser::new("minecraft:time"),
+    // 1.15 clients have no UUID parser.
+    Uuid => Parser::string(StringMode::Word),
+    Vec2 => Parser::new("minecraft:vec2"),
+    Vec3 => Parser::new("minecraft:vec3"),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn component_from_str() {
+        let Component(text) = r#""Hello world""#.parse().unwrap();
+        assert_eq!(text, Text::from("Hello world"));
+
+        assert!(Component::from_str(r#"{"text":"Hello world","color":"red"}"#).is_ok());
+
+        assert!(Component::from_str("{\"text\":").is_err());
+    }
+
+    #[test]
+    fn objective_criteria_from_str() {
+        for criteria in &[
+            "dummy",
+            "trigger",
+            "teamkill.red",
+            "minecraft.mined:minecraft.stone",
+            "minecraft.custom:minecraft.jump",
+        ] {
+            assert!(
+                ObjectiveCriteria::from_str(criteria).is_ok(),
+                "{}",
+                criteria
+            );
+        }
+
+        for criteria in &[
+            "",
+            "dumb",
+            "teamkill.reset",
+            "minecraft.eaten:minecraft.apple",
+        ] {
+            assert!(
+                ObjectiveCriteria::from_str(criteria).is_err(),
+                "{}",
+                criteria
+            );
+        }
+    }
+
+    #[test]
+    fn resource_location_from_str() {
+        assert_eq!(
+            ResourceLocation::from_str("test").unwrap(),
+            ResourceLocation("minecraft:test".to_owned())
+        );
+        assert_eq!(
+            ResourceLocation::from_str("custom:bars/boss_1").unwrap(),
+            ResourceLocation("custom:bars/boss_1".to_owned())
+        );
+        assert!(ResourceLocation::from_str("Upper").is_err());
+        assert!(ResourceLocation::from_str("minecraft:").is_err());
+    }
+
+    #[test]
+    fn operations() {
+        let apply = |operation: &str, mut a: i32, mut b: i32| {
+            Operation::from_str(operation)
+                .unwrap()
+                .apply(&mut a, &mut b);
+            (a, b)
+        };
+
+        assert_eq!(apply("=", 1, 2), (2, 2));
+        assert_eq!(apply("+=", 1, 2), (3, 2));
+        assert_eq!(apply("-=", 1, 2), (-1, 2));
+        assert_eq!(apply("*=", 3, 2), (6, 2));
+        assert_eq!(apply("/=", -7, 2), (-4, 2));
+        assert_eq!(apply("/=", 7, 0), (7, 0));
+        assert_eq!(apply("%=", -7, 2), (1, 2));
+        assert_eq!(apply("<", 1, 2), (1, 2));
+        assert_eq!(apply(">", 1, 2), (2, 2));
+        assert_eq!(apply("><", 1, 2), (2, 1));
+        assert!(Operation::from_str("^=").is_err());
+    }
+
+    #[test]
+    fn block_state_from_str() {
+        let BlockState(block) = "stone".parse().unwrap();
+        assert_eq!(block, BlockId::stone());
+
+        let BlockState(block) = "minecraft:lever[face=floor, powered=true]".parse().unwrap();
+        assert_eq!(block.kind(), BlockKind::Lever);
+        let properties = block.to_properties_map();
+        assert_eq!(properties["face"], "floor");
+        assert_eq!(properties["powered"], "true");
+
+        assert!(matches!(
+            "not_a_block".parse::<BlockState>(),
+            Err(BlockStateParseError::UnknownBlock(_))
+        ));
+        assert!(matches!(
+            "lever[color=red]".parse::<BlockState>(),
+            Err(BlockStateParseError::UnknownProperty { .. })
+        ));
+        assert!(matches!(
+            "lever[face=sideways]".parse::<BlockState>(),
+            Err(BlockStateParseError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            "lever[face=floor".parse::<BlockState>(),
+            Err(BlockStateParseError::UnclosedProperties(_))
+        ));
+    }
+
+    #[test]
+    fn block_predicate_matches() {
+        let predicate: BlockPredicate = "lever[powered=true]".parse().unwrap();
+
+        let BlockState(powered) = "lever[powered=true,face=ceiling]".parse().unwrap();
+        let BlockState(unpowered) = "lever[powered=false]".parse().unwrap();
+        assert!(predicate.matches(powered));
+        assert!(!predicate.matches(unpowered));
+        assert!(!predicate.matches(BlockId::stone()));
+
+        assert!(matches!(
+            "#minecraft:logs".parse::<BlockPredicate>(),
+            Err(BlockPredicateParseError::TagsUnsupported)
+        ));
+    }
+}
@@ -0,0 +1,632 @@
+//! Implementation of the block-editing commands: `/setblock`, `/fill`
+//! and `/clone`.
+//!
+//! All changes go through `Game::set_block_at`, so block entities,
+//! lighting and broadcasting of the changes are handled by the
+//! usual `BlockUpdateEvent` handlers.
+
+use crate::arguments::BlockPredicate;
+use feather_core::blocks::BlockId;
+use feather_core::inventory::{Slot, SlotIndex};
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockUpdateCause, Game, Inventory};
+use fecs::World;
+use std::convert::TryFrom;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BlockCommandError {
+    #[error("Too many blocks in the specified area (maximum {max}, specified {specified})")]
+    TooManyBlocks { max: u64, specified: u64 },
+    #[error("That position is not loaded")]
+    NotLoaded,
+    #[error("Could not set the block")]
+    CouldNotSet,
+    #[error("No blocks were filled")]
+    NoBlocksFilled,
+    #[error("No blocks were cloned")]
+    NoBlocksCloned,
+    #[error("The source and destination areas cannot overlap")]
+    Overlap,
+    #[error("That position is out of this world")]
+    OutOfWorld,
+}
+
+/// A cuboid region of blocks, inclusive on both ends.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Region {
+    pub min: BlockPosition,
+    pub max: BlockPosition,
+}
+
+impl Region {
+    /// Creates the region spanned by two opposite corners.
+    pub fn new(a: BlockPosition, b: BlockPosition) -> Self {
+        Self {
+            min: BlockPosition::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: BlockPosition::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    /// Returns the number of blocks in this region.
+    ///
+    /// Computed in 64 bits, since the size along each axis
+    /// may not fit in an `i32` for very large regions.
+    pub fn volume(&self) -> u64 {
+        let length = |min: i32, max: i32| (i64::from(max) - i64::from(min) + 1) as u64;
+        length(self.min.x, self.max.x)
+            .saturating_mul(length(self.min.y, self.max.y))
+            .saturating_mul(length(self.min.z, self.max.z))
+    }
+
+    /// Returns this region moved so that its minimum corner is at `min`,
+    /// or an error if its maximum corner would not fit in an `i32`.
+    pub fn moved_to(&self, min: BlockPosition) -> Result<Self, BlockCommandError> {
+        let max = |old_min: i32, old_max: i32, new_min: i32| {
+            let max = i64::from(new_min) + i64::from(old_max) - i64::from(old_min);
+            i32::try_from(max).map_err(|_| BlockCommandError::OutOfWorld)
+        };
+        Ok(Self {
+            min,
+            max: BlockPosition::new(
+                max(self.min.x, self.max.x, min.x)?,
+                max(self.min.y, self.max.y, min.y)?,
+                max(self.min.z, self.max.z, min.z)?,
+            ),
+        })
+    }
+
+    pub fn contains(&self, pos: BlockPosition) -> bool {
+        (self.min.x..=self.max.x).contains(&pos.x)
+            && (self.min.y..=self.max.y).contains(&pos.y)
+            && (self.min.z..=self.max.z).contains(&pos.z)
+    }
+
+    /// Returns whether the given position lies on the
+    /// outer faces of this region.
+    pub fn is_on_border(&self, pos: BlockPosition) -> bool {
+        pos.x == self.min.x
+            || pos.x == self.max.x
+            || pos.y == self.min.y
+            || pos.y == self.max.y
+            || pos.z == self.min.z
+            || pos.z == self.max.z
+    }
+
+    pub fn intersects(&self, other: &Region) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    /// Iterates over the positions in this region,
+    /// from the bottom layer upwards.
+    pub fn iter(self) -> impl Iterator<Item = BlockPosition> {
+        (self.min.y..=self.max.y).flat_map(move |y| {
+            (self.min.z..=self.max.z).flat_map(move |z| {
+                (self.min.x..=self.max.x).map(move |x| BlockPosition::new(x, y, z))
+            })
+        })
+    }
+}
+
+/// The mode of `/setblock`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SetBlockMode {
+    /// Replace the existing block.
+    Replace,
+    /// Break the existing block, dropping its loot.
+    Destroy,
+    /// Only place the block if the existing block is air.
+    Keep,
+}
+
+/// The mode of `/fill`.
+#[derive(Clone, Debug)]
+pub enum FillMode {
+    /// Replace all blocks, or only those matching the filter.
+    Replace(Option<BlockPredicate>),
+    /// Break all blocks, dropping their loot.
+    Destroy,
+    /// Fill the outer layer and replace the inside with air.
+    Hollow,
+    /// Fill the outer layer and leave the inside untouched.
+    Outline,
+    /// Only replace air.
+    Keep,
+}
+
+/// Determines which blocks `/clone` copies.
+#[derive(Clone, Debug)]
+pub enum MaskMode {
+    /// Copy all blocks.
+    Replace,
+    /// Copy all blocks except air.
+    Masked,
+    /// Copy only blocks matching the filter.
+    Filtered(BlockPredicate),
+}
+
+/// Determines how `/clone` treats the source region.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CloneMode {
+    /// Copy the blocks. The regions may not overlap.
+    Normal,
+    /// Copy the blocks, even if the regions overlap.
+    Force,
+    /// Copy the blocks and replace the source with air.
+    Move,
+}
+
+/// Sets a single block.
+pub fn setblock(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    block: BlockId,
+    mode: SetBlockMode,
+) -> Result<(), BlockCommandError> {
+    let old = game.block_at(pos).ok_or(BlockCommandError::NotLoaded)?;
+
+    if mode == SetBlockMode::Keep && !old.is_air() {
+        return Err(BlockCommandError::CouldNotSet);
+    }
+
+    if place_block(game, world, pos, old, block, mode == SetBlockMode::Destroy) {
+        Ok(())
+    } else {
+        Err(BlockCommandError::CouldNotSet)
+    }
+}
+
+/// Fills a region with a block. Returns the number of blocks changed.
+pub fn fill(
+    game: &mut Game,
+    world: &mut World,
+    region: Region,
+    block: BlockId,
+    mode: FillMode,
+) -> Result<u64, BlockCommandError> {
+    check_volume(game, region)?;
+    let old_blocks = load_blocks(game, region)?;
+
+    let destroy = matches!(mode, FillMode::Destroy);
+    let mut count = 0;
+    for (pos, old) in old_blocks {
+        let new = match &mode {
+            FillMode::Replace(Some(filter)) if !filter.matches(old) => continue,
+            FillMode::Keep if !old.is_air() => continue,
+            FillMode::Outline if !region.is_on_border(pos) => continue,
+            FillMode::Hollow if !region.is_on_border(pos) => BlockId::air(),
+            _ => block,
+        };
+
+        if place_block(game, world, pos, old, new, destroy) {
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        Err(BlockCommandError::NoBlocksFilled)
+    } else {
+        Ok(count)
+    }
+}
+
+/// A block copied by `/clone`, along with the contents
+/// of its block entity, if it has an inventory.
+struct ClonedBlock {
+    offset: BlockPosition,
+    block: BlockId,
+    contents: Option<Vec<(SlotIndex, Slot)>>,
+}
+
+/// Copies the blocks in `source` to the region whose
+/// minimum corner is `destination`. Returns the number of blocks
+/// in the destination which changed.
+pub fn clone(
+    game: &mut Game,
+    world: &mut World,
+    source: Region,
+    destination: BlockPosition,
+    mask: MaskMode,
+    mode: CloneMode,
+) -> Result<u64, BlockCommandError> {
+    check_volume(game, source)?;
+
+    let destination = source.moved_to(destination)?;
+    if mode != CloneMode::Force && source.intersects(&destination) {
+        return Err(BlockCommandError::Overlap);
+    }
+
+    let source_blocks = load_blocks(game, source)?;
+    // Only done to ensure the destination is loaded.
+    load_blocks(game, destination)?;
+
+    let cloned: Vec<ClonedBlock> = source_blocks
+        .into_iter()
+        .filter(|(_, block)| match &mask {
+            MaskMode::Replace => true,
+            MaskMode::Masked => !block.is_air(),
+            MaskMode::Filtered(filter) => filter.matches(*block),
+        })
+        .map(|(pos, block)| ClonedBlock {
+            offset: BlockPosition::new(
+                pos.x - source.min.x,
+                pos.y - source.min.y,
+                pos.z - source.min.z,
+            ),
+            block,
+            contents: inventory_contents(game, world, pos),
+        })
+        .collect();
+
+    if cloned.is_empty() {
+        return Err(BlockCommandError::NoBlocksCloned);
+    }
+
+    if mode == CloneMode::Move {
+        for cloned_block in &cloned {
+            let pos = source.min + cloned_block.offset;
+            if let Some(old) = game.block_at(pos) {
+                place_block(game, world, pos, old, BlockId::air(), false);
+            }
+        }
+    }
+
+    let mut count = 0;
+    for cloned_block in &cloned {
+        let pos = destination.min + cloned_block.offset;
+        if let Some(old) = game.block_at(pos) {
+            if place_block(game, world, pos, old, cloned_block.block, false) {
+                count += 1;
+            }
+        }
+
+        if let Some(contents) = &cloned_block.contents {
+            set_inventory_contents(game, world, pos, contents);
+        }
+    }
+
+    if count == 0 {
+        Err(BlockCommandError::NoBlocksCloned)
+    } else {
+        Ok(count)
+    }
+}
+
+fn check_volume(game: &Game, region: Region) -> Result<(), BlockCommandError> {
+    let max = game.config.gameplay.max_command_volume;
+    let specified = region.volume();
+    if specified > max {
+        Err(BlockCommandError::TooManyBlocks { max, specified })
+    } else {
+        Ok(())
+    }
+}
+
+/// Retrieves all blocks in a region, failing if any
+/// of them are unloaded or outside the world.
+fn load_blocks(
+    game: &Game,
+    region: Region,
+) -> Result<Vec<(BlockPosition, BlockId)>, BlockCommandError> {
+    region
+        .iter()
+        .map(|pos| {
+            game.block_at(pos)
+                .map(|block| (pos, block))
+                .ok_or(BlockCommandError::NotLoaded)
+        })
+        .collect()
+}
+
+/// Replaces the block at `pos`. Returns whether the block changed.
+///
+/// Unless `destroy` is set, the contents of the old block entity
+/// are cleared first, so that e.g. replacing a chest does not
+/// drop its items.
+fn place_block(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    old: BlockId,
+    new: BlockId,
+    destroy: bool,
+) -> bool {
+    if old == new {
+        return false;
+    }
+
+    if destroy && !old.is_air() {
+        game.set_block_at(world, pos, BlockId::air(), BlockUpdateCause::Destroyed);
+        if new.is_air() {
+            return true;
+        }
+    } else if !destroy {
+        clear_inventory_contents(game, world, pos);
+    }
+
+    game.set_block_at(world, pos, new, BlockUpdateCause::Unknown)
+}
+
+fn inventory_contents(
+    game: &Game,
+    world: &World,
+    pos: BlockPosition,
+) -> Option<Vec<(SlotIndex, Slot)>> {
+    let entity = *game.block_entities.get(&pos)?;
+    let inventory = world.try_get::<Inventory>(entity)?;
+    Some(inventory.enumerate().collect())
+}
+
+fn set_inventory_contents(
+    game: &Game,
+    world: &World,
+    pos: BlockPosition,
+    contents: &[(SlotIndex, Slot)],
+) {
+    if let Some(inventory) = game
+        .block_entities
+        .get(&pos)
+        .and_then(|entity| world.try_get::<Inventory>(*entity))
+    {
+        for (index, slot) in contents {
+            if let Ok(mut guard) = inventory.item_at_mut(index.area, index.slot) {
+                *guard = *slot;
+            }
+        }
+    }
+}
+
+fn clear_inventory_contents(game: &Game, world: &World, pos: BlockPosition) {
+    if let Some(inventory) = game
+        .block_entities
+        .get(&pos)
+        .and_then(|entity| world.try_get::<Inventory>(*entity))
+    {
+        for mut slot in inventory.iter_mut() {
+            slot.take();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_test_framework::Test;
+
+    fn pos(x: i32, y: i32, z: i32) -> BlockPosition {
+        BlockPosition::new(x, y, z)
+    }
+
+    fn block(test: &Test, pos: BlockPosition) -> BlockId {
+        test.game.block_at(pos).unwrap()
+    }
+
+    fn fill_region(test: &mut Test, region: Region, block: BlockId, mode: FillMode) -> u64 {
+        fill(&mut test.game, &mut test.world, region, block, mode).unwrap()
+    }
+
+    #[test]
+    fn region_bounds() {
+        let region = Region::new(BlockPosition::new(3, 10, -2), BlockPosition::new(0, 8, 1));
+        assert_eq!(region.min, BlockPosition::new(0, 8, -2));
+        assert_eq!(region.max, BlockPosition::new(3, 10, 1));
+        assert_eq!(region.volume(), 4 * 3 * 4);
+        assert_eq!(region.iter().count() as u64, region.volume());
+
+        assert!(region.is_on_border(BlockPosition::new(0, 9, 0)));
+        assert!(!region.is_on_border(BlockPosition::new(1, 9, 0)));
+        assert!(region.contains(BlockPosition::new(3, 10, 1)));
+        assert!(!region.contains(BlockPosition::new(4, 10, 1)));
+    }
+
+    #[test]
+    fn region_moved_to_and_intersects() {
+        let region = Region::new(BlockPosition::new(0, 0, 0), BlockPosition::new(2, 2, 2));
+        let moved = region.moved_to(BlockPosition::new(2, 0, 0)).unwrap();
+        assert_eq!(moved.max, BlockPosition::new(4, 2, 2));
+        assert_eq!(moved.volume(), region.volume());
+        assert!(region.intersects(&moved));
+        assert!(!region.intersects(&region.moved_to(BlockPosition::new(3, 0, 0)).unwrap()));
+    }
+
+    #[test]
+    fn region_moved_to_does_not_overflow() {
+        let region = Region::new(pos(i32::MIN, 0, 0), pos(i32::MAX, 0, 0));
+        assert!(matches!(
+            region.moved_to(pos(1, 0, 0)),
+            Err(BlockCommandError::OutOfWorld)
+        ));
+
+        let region = Region::new(pos(0, 0, 0), pos(2, 0, 0));
+        assert!(matches!(
+            region.moved_to(pos(i32::MAX - 1, 0, 0)),
+            Err(BlockCommandError::OutOfWorld)
+        ));
+        assert_eq!(
+            region.moved_to(pos(i32::MAX - 2, 0, 0)).unwrap().max,
+            pos(i32::MAX, 0, 0)
+        );
+    }
+
+    #[test]
+    fn region_volume_does_not_overflow() {
+        let region = Region::new(pos(i32::MIN, 0, i32::MIN), pos(i32::MAX, 255, i32::MAX));
+        assert_eq!(region.volume(), u64::MAX);
+
+        let region = Region::new(pos(i32::MIN, 0, 0), pos(i32::MAX, 0, 0));
+        assert_eq!(region.volume(), 1 << 32);
+    }
+
+    #[test]
+    fn fill_modes() {
+        let mut test = Test::new();
+        let region = Region::new(pos(0, 64, 0), pos(2, 66, 2));
+
+        assert_eq!(
+            fill_region(&mut test, region, BlockId::stone(), FillMode::Replace(None)),
+            27
+        );
+        assert!(region.iter().all(|p| block(&test, p) == BlockId::stone()));
+
+        assert_eq!(
+            fill_region(&mut test, region, BlockId::dirt(), FillMode::Hollow),
+            27
+        );
+        assert!(block(&test, pos(1, 65, 1)).is_air());
+        assert_eq!(block(&test, pos(0, 65, 1)), BlockId::dirt());
+
+        assert_eq!(
+            fill_region(&mut test, region, BlockId::stone(), FillMode::Keep),
+            1
+        );
+        assert_eq!(block(&test, pos(1, 65, 1)), BlockId::stone());
+
+        assert_eq!(
+            fill_region(&mut test, region, BlockId::glass(), FillMode::Outline),
+            26
+        );
+        assert_eq!(block(&test, pos(1, 65, 1)), BlockId::stone());
+        assert_eq!(block(&test, pos(2, 66, 2)), BlockId::glass());
+    }
+
+    #[test]
+    fn fill_errors() {
+        let mut test = Test::new();
+
+        let too_big = Region::new(pos(0, 0, 0), pos(40, 40, 40));
+        assert!(matches!(
+            fill(
+                &mut test.game,
+                &mut test.world,
+                too_big,
+                BlockId::stone(),
+                FillMode::Keep
+            ),
+            Err(BlockCommandError::TooManyBlocks { .. })
+        ));
+
+        // Only the chunks from -1 to 1 are loaded.
+        let unloaded = Region::new(pos(30, 64, 0), pos(34, 64, 0));
+        assert!(matches!(
+            fill(
+                &mut test.game,
+                &mut test.world,
+                unloaded,
+                BlockId::stone(),
+                FillMode::Keep
+            ),
+            Err(BlockCommandError::NotLoaded)
+        ));
+        assert!(block(&test, pos(30, 64, 0)).is_air());
+
+        let region = Region::new(pos(0, 64, 0), pos(1, 64, 1));
+        assert!(matches!(
+            fill(
+                &mut test.game,
+                &mut test.world,
+                region,
+                BlockId::air(),
+                FillMode::Replace(None)
+            ),
+            Err(BlockCommandError::NoBlocksFilled)
+        ));
+    }
+
+    #[test]
+    fn clone_modes() {
+        let mut test = Test::new();
+        let source = Region::new(pos(0, 64, 0), pos(1, 65, 0));
+        fill_region(&mut test, source, BlockId::stone(), FillMode::Replace(None));
+        test.game.set_block_at(
+            &mut test.world,
+            pos(1, 65, 0),
+            BlockId::air(),
+            BlockUpdateCause::Unknown,
+        );
+        fill_region(
+            &mut test,
+            Region::new(pos(0, 64, 5), pos(1, 65, 5)),
+            BlockId::dirt(),
+            FillMode::Replace(None),
+        );
+
+        let cloned = clone(
+            &mut test.game,
+            &mut test.world,
+            source,
+            pos(0, 64, 5),
+            MaskMode::Masked,
+            CloneMode::Normal,
+        )
+        .unwrap();
+        assert_eq!(cloned, 3);
+        assert_eq!(block(&test, pos(0, 65, 5)), BlockId::stone());
+        // Air isn't copied in masked mode.
+        assert_eq!(block(&test, pos(1, 65, 5)), BlockId::dirt());
+
+        // Cloning again changes nothing.
+        assert!(matches!(
+            clone(
+                &mut test.game,
+                &mut test.world,
+                source,
+                pos(0, 64, 5),
+                MaskMode::Masked,
+                CloneMode::Normal,
+            ),
+            Err(BlockCommandError::NoBlocksCloned)
+        ));
+
+        let moved = clone(
+            &mut test.game,
+            &mut test.world,
+            source,
+            pos(0, 64, 10),
+            MaskMode::Replace,
+            CloneMode::Move,
+        )
+        .unwrap();
+        // Air is copied onto air, which doesn't count.
+        assert_eq!(moved, 3);
+        assert!(source.iter().all(|p| block(&test, p).is_air()));
+        assert_eq!(block(&test, pos(1, 64, 10)), BlockId::stone());
+        assert!(block(&test, pos(1, 65, 10)).is_air());
+    }
+
+    #[test]
+    fn clone_overlap() {
+        let mut test = Test::new();
+        let source = Region::new(pos(0, 64, 0), pos(2, 64, 0));
+        fill_region(&mut test, source, BlockId::stone(), FillMode::Replace(None));
+
+        assert!(matches!(
+            clone(
+                &mut test.game,
+                &mut test.world,
+                source,
+                pos(1, 64, 0),
+                MaskMode::Replace,
+                CloneMode::Normal,
+            ),
+            Err(BlockCommandError::Overlap)
+        ));
+
+        let cloned = clone(
+            &mut test.game,
+            &mut test.world,
+            source,
+            pos(1, 64, 0),
+            MaskMode::Replace,
+            CloneMode::Force,
+        )
+        .unwrap();
+        assert_eq!(cloned, 1);
+        assert_eq!(block(&test, pos(3, 64, 0)), BlockId::stone());
+    }
+}
@@ -1,19 +1,30 @@
 //! The implementations of various commands.
 #![allow(non_snake_case)]
 
-
-
+use crate::access::{self, AccessCommandError};
+use crate::blocks::{self, CloneMode, FillMode, MaskMode, Region, SetBlockMode};
+use crate::permissions;
+use crate::scoreboard::{self, ScoreboardCommandError};
+use crate::selector;
 use crate::{arguments::*, CommandCtx};
 
-//use thiserror::Error;
-use lieutenant::command;
+use feather_server_commands_macros::command;
+use thiserror::Error;
 
+use feather_core::network::packets::ObjectiveRenderType;
 //use feather_core::util::{Gamemode, Position};
+use feather_core::util::{BlockPosition, Position};
 //use feather_core::inventory::{Inventory, SlotIndex};
 //use feather_core::text::TextValue;
 use feather_core::text::{Text, TextComponentBuilder};
 //use feather_definitions::Item;
-use feather_server_types::MessageReceiver;
+use feather_server_types::{
+    BanDetails, BossBar, BossBarColor, BossBarError, BossBarStyle, CollisionRule, MessageReceiver,
+    Name, Player, ScoreboardError, Visibility, DEFAULT_OP_LEVEL, MAX_BORDER_CENTER,
+    MAX_BORDER_DIAMETER,
+};
+use fecs::{component, Entity, IntoQuery, Read};
+use std::net::IpAddr;
 /*
 use feather_server_types::{
     ChatEvent, ChatPosition, GamemodeUpdateEvent, InventoryUpdateEvent, Name,
@@ -513,7 +524,7 @@ fn clear_items(
 }
 */
 
-#[command(usage = "advancement grant <targets> everything")]
+#[command(usage = "advancement grant <targets> everything", level = 2)]
 pub fn advancement_grant_targets_everything(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -526,7 +537,7 @@ pub fn advancement_grant_targets_everything(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement grant <targets> from <advancement>")]
+#[command(usage = "advancement grant <targets> from <advancement>", level = 2)]
 pub fn advancement_grant_targets_from_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -540,7 +551,7 @@ pub fn advancement_grant_targets_from_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement grant <targets> only <advancement>")]
+#[command(usage = "advancement grant <targets> only <advancement>", level = 2)]
 pub fn advancement_grant_targets_only_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -554,7 +565,10 @@ pub fn advancement_grant_targets_only_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement grant <targets> only <advancement> <criterion>")]
+#[command(
+    usage = "advancement grant <targets> only <advancement> <criterion>",
+    level = 2
+)]
 pub fn advancement_grant_targets_only_advancement_criterion(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -569,7 +583,7 @@ pub fn advancement_grant_targets_only_advancement_criterion(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement grant <targets> through <advancement>")]
+#[command(usage = "advancement grant <targets> through <advancement>", level = 2)]
 pub fn advancement_grant_targets_through_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -583,7 +597,7 @@ pub fn advancement_grant_targets_through_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement grant <targets> until <advancement>")]
+#[command(usage = "advancement grant <targets> until <advancement>", level = 2)]
 pub fn advancement_grant_targets_until_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -597,7 +611,7 @@ pub fn advancement_grant_targets_until_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement revoke <targets> everything")]
+#[command(usage = "advancement revoke <targets> everything", level = 2)]
 pub fn advancement_revoke_targets_everything(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -610,7 +624,7 @@ pub fn advancement_revoke_targets_everything(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement revoke <targets> from <advancement>")]
+#[command(usage = "advancement revoke <targets> from <advancement>", level = 2)]
 pub fn advancement_revoke_targets_from_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -624,7 +638,7 @@ pub fn advancement_revoke_targets_from_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement revoke <targets> only <advancement>")]
+#[command(usage = "advancement revoke <targets> only <advancement>", level = 2)]
 pub fn advancement_revoke_targets_only_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -638,7 +652,10 @@ pub fn advancement_revoke_targets_only_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement revoke <targets> only <advancement> <criterion>")]
+#[command(
+    usage = "advancement revoke <targets> only <advancement> <criterion>",
+    level = 2
+)]
 pub fn advancement_revoke_targets_only_advancement_criterion(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -653,7 +670,10 @@ pub fn advancement_revoke_targets_only_advancement_criterion(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement revoke <targets> through <advancement>")]
+#[command(
+    usage = "advancement revoke <targets> through <advancement>",
+    level = 2
+)]
 pub fn advancement_revoke_targets_through_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -667,7 +687,7 @@ pub fn advancement_revoke_targets_through_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "advancement revoke <targets> until <advancement>")]
+#[command(usage = "advancement revoke <targets> until <advancement>", level = 2)]
 pub fn advancement_revoke_targets_until_advancement(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -681,7 +701,7 @@ pub fn advancement_revoke_targets_until_advancement(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> base get")]
+#[command(usage = "attribute <target> <attribute> base get", level = 2)]
 pub fn attribute_target_attribute_base_get(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -695,7 +715,7 @@ pub fn attribute_target_attribute_base_get(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> base get <scale>")]
+#[command(usage = "attribute <target> <attribute> base get <scale>", level = 2)]
 pub fn attribute_target_attribute_base_get_scale(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -710,7 +730,7 @@ pub fn attribute_target_attribute_base_get_scale(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> base set <value>")]
+#[command(usage = "attribute <target> <attribute> base set <value>", level = 2)]
 pub fn attribute_target_attribute_base_set_value(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -725,7 +745,7 @@ pub fn attribute_target_attribute_base_set_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> get")]
+#[command(usage = "attribute <target> <attribute> get", level = 2)]
 pub fn attribute_target_attribute_get(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -739,7 +759,7 @@ pub fn attribute_target_attribute_get(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> get <scale>")]
+#[command(usage = "attribute <target> <attribute> get <scale>", level = 2)]
 pub fn attribute_target_attribute_get_scale(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -754,7 +774,10 @@ pub fn attribute_target_attribute_get_scale(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> modifier add <uuid> <name> <value> add")]
+#[command(
+    usage = "attribute <target> <attribute> modifier add <uuid> <name> <value> add",
+    level = 2
+)]
 pub fn attribute_target_attribute_modifier_add_uuid_name_value_add(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -771,7 +794,10 @@ pub fn attribute_target_attribute_modifier_add_uuid_name_value_add(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> modifier add <uuid> <name> <value> multiply")]
+#[command(
+    usage = "attribute <target> <attribute> modifier add <uuid> <name> <value> multiply",
+    level = 2
+)]
 pub fn attribute_target_attribute_modifier_add_uuid_name_value_multiply(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -789,7 +815,8 @@ pub fn attribute_target_attribute_modifier_add_uuid_name_value_multiply(
 }
 
 #[command(
-    usage = "attribute <target> <attribute> modifier add <uuid> <name> <value> multiply_base"
+    usage = "attribute <target> <attribute> modifier add <uuid> <name> <value> multiply_base",
+    level = 2
 )]
 pub fn attribute_target_attribute_modifier_add_uuid_name_value_multiply_base(
     ctx: &mut CommandCtx,
@@ -807,7 +834,10 @@ pub fn attribute_target_attribute_modifier_add_uuid_name_value_multiply_base(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> modifier remove <uuid>")]
+#[command(
+    usage = "attribute <target> <attribute> modifier remove <uuid>",
+    level = 2
+)]
 pub fn attribute_target_attribute_modifier_remove_uuid(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -822,7 +852,10 @@ pub fn attribute_target_attribute_modifier_remove_uuid(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> modifier value get <uuid>")]
+#[command(
+    usage = "attribute <target> <attribute> modifier value get <uuid>",
+    level = 2
+)]
 pub fn attribute_target_attribute_modifier_value_get_uuid(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -837,7 +870,10 @@ pub fn attribute_target_attribute_modifier_value_get_uuid(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "attribute <target> <attribute> modifier value get <uuid> <scale>")]
+#[command(
+    usage = "attribute <target> <attribute> modifier value get <uuid> <scale>",
+    level = 2
+)]
 pub fn attribute_target_attribute_modifier_value_get_uuid_scale(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -853,423 +889,479 @@ pub fn attribute_target_attribute_modifier_value_get_uuid_scale(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "ban <targets>")]
-pub fn ban_targets(ctx: &mut CommandCtx, _targets: GameProfile) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"ban <targets>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+fn run_ban(
+    ctx: &mut CommandCtx,
+    targets: GameProfile,
+    reason: Option<String>,
+) -> anyhow::Result<Option<String>> {
+    let profiles = access::game_profiles(ctx, &targets.0)?;
+    let source = access::ban_source(ctx);
+
+    let mut lines = vec![];
+    for profile in profiles {
+        let details = BanDetails::new(source.clone(), reason.clone());
+        let reason = details.reason.clone();
+        if ctx
+            .game
+            .access_lists
+            .write()
+            .ban(profile.clone(), details)?
+        {
+            for player in access::online_players(&ctx.world, |uuid, _| uuid == profile.uuid) {
+                ctx.game
+                    .kick(player, &mut ctx.world, "You are banned from this server.");
+            }
+            lines.push(format!("Banned {}: {}", profile.name, reason));
+        }
     }
-    Ok(Some("".to_string()))
+
+    if lines.is_empty() {
+        return Err(AccessCommandError::AlreadyBanned.into());
+    }
+    Ok(Some(lines.join("\n")))
 }
 
-#[command(usage = "ban <targets> <reason>")]
-pub fn ban_targets_reason(
+fn run_ban_ip(
     ctx: &mut CommandCtx,
-    _targets: GameProfile,
-    _reason: Message,
-) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"ban <targets> <reason>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    target: StringArgumentWord,
+    reason: Option<String>,
+) -> anyhow::Result<Option<String>> {
+    let ip = access::ban_ip_target(ctx, &target.0)?;
+    let details = BanDetails::new(access::ban_source(ctx), reason);
+    let reason = details.reason.clone();
+
+    if !ctx.game.access_lists.write().ban_ip(ip, details)? {
+        return Err(AccessCommandError::IpAlreadyBanned.into());
+    }
+
+    let players = access::online_players(&ctx.world, |_, addr| addr == ip);
+    for player in &players {
+        ctx.game
+            .kick(*player, &mut ctx.world, "You have been IP banned.");
+    }
+
+    if players.is_empty() {
+        Ok(Some(format!("Banned IP {}: {}", ip, reason)))
+    } else {
+        Ok(Some(format!(
+            "Banned IP {}: {}\nThis ban affects {} player(s)",
+            ip,
+            reason,
+            players.len()
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "ban-ip <target>")]
-pub fn ban_ip_target(ctx: &mut CommandCtx, _target: StringArgumentWord) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"ban-ip <target>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+fn run_banlist(ctx: &mut CommandCtx, players: bool, ips: bool) -> anyhow::Result<Option<String>> {
+    let access_lists = ctx.game.access_lists.read();
+
+    let mut bans = vec![];
+    if players {
+        bans.extend(access_lists.banned_players().map(|ban| {
+            format!(
+                "{} was banned by {}: {}",
+                ban.profile.name, ban.details.source, ban.details.reason
+            )
+        }));
+    }
+    if ips {
+        bans.extend(access_lists.banned_ips().map(|ban| {
+            format!(
+                "{} was banned by {}: {}",
+                ban.ip, ban.details.source, ban.details.reason
+            )
+        }));
+    }
+
+    if bans.is_empty() {
+        Ok(Some("There are no bans".to_string()))
+    } else {
+        Ok(Some(format!(
+            "There are {} ban(s):\n{}",
+            bans.len(),
+            bans.join("\n")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "ban-ip <target> <reason>")]
+#[command(usage = "ban <targets>", level = 3)]
+pub fn ban_targets(ctx: &mut CommandCtx, targets: GameProfile) -> anyhow::Result<()> {
+    run_ban(ctx, targets, None)
+}
+
+#[command(usage = "ban <targets> <reason>", level = 3)]
+pub fn ban_targets_reason(
+    ctx: &mut CommandCtx,
+    targets: GameProfile,
+    reason: Message,
+) -> anyhow::Result<()> {
+    run_ban(ctx, targets, Some(reason.0))
+}
+
+#[command(usage = "ban-ip <target>", level = 3)]
+pub fn ban_ip_target(ctx: &mut CommandCtx, target: StringArgumentWord) -> anyhow::Result<()> {
+    run_ban_ip(ctx, target, None)
+}
+
+#[command(usage = "ban-ip <target> <reason>", level = 3)]
 pub fn ban_ip_target_reason(
     ctx: &mut CommandCtx,
-    _target: StringArgumentWord,
-    _reason: Message,
+    target: StringArgumentWord,
+    reason: Message,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"ban-ip <target> <reason>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_ban_ip(ctx, target, Some(reason.0))
 }
 
-#[command(usage = "banlist")]
+#[command(usage = "banlist", level = 3)]
 pub fn banlist(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text =
-            Text::from("This command \"banlist\" is not implemented in this version of feather.")
-                .gray()
-                .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_banlist(ctx, true, true)
 }
 
-#[command(usage = "banlist ips")]
+#[command(usage = "banlist ips", level = 3)]
 pub fn banlist_ips(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"banlist ips\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_banlist(ctx, false, true)
 }
 
-#[command(usage = "banlist players")]
+#[command(usage = "banlist players", level = 3)]
 pub fn banlist_players(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"banlist players\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+    run_banlist(ctx, true, false)
+}
+
+#[derive(Debug, Error)]
+pub enum BossBarCommandError {
+    #[error("Nothing changed. That's already the {0} of this bossbar")]
+    Unchanged(&'static str),
+}
+
+/// Fails with `BossBarCommandError::Unchanged` if a boss bar
+/// setter reported that nothing changed.
+fn check_changed(changed: bool, property: &'static str) -> anyhow::Result<()> {
+    if changed {
+        Ok(())
+    } else {
+        Err(BossBarCommandError::Unchanged(property).into())
+    }
+}
+
+fn boss_bar<'a>(ctx: &'a CommandCtx, id: &ResourceLocation) -> anyhow::Result<&'a BossBar> {
+    Ok(ctx
+        .game
+        .boss_bars
+        .get(&id.0)
+        .ok_or_else(|| BossBarError::Unknown(id.0.clone()))?)
+}
+
+fn run_set_boss_bar_color(
+    ctx: &mut CommandCtx,
+    id: ResourceLocation,
+    color: BossBarColor,
+) -> anyhow::Result<Option<String>> {
+    let changed = ctx.game.boss_bars.set_color(&ctx.world, &id.0, color)?;
+    check_changed(changed, "color")?;
+    Ok(Some(format!("Custom bossbar [{}] has changed color", id.0)))
+}
+
+fn run_set_boss_bar_style(
+    ctx: &mut CommandCtx,
+    id: ResourceLocation,
+    style: BossBarStyle,
+) -> anyhow::Result<Option<String>> {
+    let changed = ctx.game.boss_bars.set_style(&ctx.world, &id.0, style)?;
+    check_changed(changed, "style")?;
+    Ok(Some(format!("Custom bossbar [{}] has changed style", id.0)))
+}
+
+fn run_set_boss_bar_players(
+    ctx: &mut CommandCtx,
+    id: ResourceLocation,
+    players: Vec<Entity>,
+) -> anyhow::Result<Option<String>> {
+    let names: Vec<String> = players
+        .iter()
+        .filter_map(|player| {
+            ctx.world
+                .try_get::<Name>(*player)
+                .map(|name| name.0.clone())
+        })
+        .collect();
+    let uuids: Vec<feather_server_types::Uuid> = players
+        .iter()
+        .filter_map(|player| {
+            ctx.world
+                .try_get::<feather_server_types::Uuid>(*player)
+                .map(|uuid| *uuid)
+        })
+        .collect();
+
+    let changed = ctx.game.boss_bars.set_players(&ctx.world, &id.0, uuids)?;
+    check_changed(changed, "players")?;
+
+    if names.is_empty() {
+        Ok(Some(format!(
+            "Custom bossbar [{}] no longer has any players",
+            id.0
+        )))
+    } else {
+        Ok(Some(format!(
+            "Custom bossbar [{}] now has {} players: {}",
+            id.0,
+            names.len(),
+            names.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "bossbar add <id> <name>")]
+#[command(usage = "bossbar add <id> <name>", level = 2)]
 pub fn bossbar_add_id_name(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-    _name: Component,
+    id: ResourceLocation,
+    name: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar add <id> <name>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game.boss_bars.add(&id.0, name.0)?;
+    Ok(Some(format!("Created custom bossbar [{}]", id.0)))
 }
 
-#[command(usage = "bossbar get <id> max")]
-pub fn bossbar_get_id_max(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"bossbar get <id> max\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar get <id> max", level = 2)]
+pub fn bossbar_get_id_max(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    let max = boss_bar(ctx, &id)?.max();
+    Ok(Some(format!(
+        "Custom bossbar [{}] has a maximum of {}",
+        id.0, max
+    )))
 }
 
-#[command(usage = "bossbar get <id> players")]
-pub fn bossbar_get_id_players(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar get <id> players\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "bossbar get <id> players", level = 2)]
+pub fn bossbar_get_id_players(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    let bar = boss_bar(ctx, &id)?;
+    let online: Vec<String> = <(Read<feather_server_types::Uuid>, Read<Name>)>::query()
+        .filter(component::<Player>())
+        .iter(ctx.world.inner())
+        .filter(|(uuid, _)| bar.has_player(**uuid))
+        .map(|(_, name)| name.0.clone())
+        .collect();
+
+    if online.is_empty() {
+        Ok(Some(format!(
+            "Custom bossbar [{}] has no players currently online",
+            id.0
+        )))
+    } else {
+        Ok(Some(format!(
+            "Custom bossbar [{}] has {} players currently online: {}",
+            id.0,
+            online.len(),
+            online.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "bossbar get <id> value")]
-pub fn bossbar_get_id_value(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar get <id> value\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar get <id> value", level = 2)]
+pub fn bossbar_get_id_value(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    let value = boss_bar(ctx, &id)?.value();
+    Ok(Some(format!(
+        "Custom bossbar [{}] has a value of {}",
+        id.0, value
+    )))
 }
 
-#[command(usage = "bossbar get <id> visible")]
-pub fn bossbar_get_id_visible(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar get <id> visible\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar get <id> visible", level = 2)]
+pub fn bossbar_get_id_visible(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    let visible = boss_bar(ctx, &id)?.is_visible();
+    Ok(Some(format!(
+        "Custom bossbar [{}] is currently {}",
+        id.0,
+        if visible { "shown" } else { "hidden" }
+    )))
 }
 
-#[command(usage = "bossbar list")]
+#[command(usage = "bossbar list", level = 2)]
 pub fn bossbar_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"bossbar list\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+    let bars: Vec<String> = ctx
+        .game
+        .boss_bars
+        .iter()
+        .map(|bar| format!("[{}]", bar.id()))
+        .collect();
+
+    if bars.is_empty() {
+        Ok(Some("There are no custom bossbars active".to_string()))
+    } else {
+        Ok(Some(format!(
+            "There are {} custom bossbars active: {}",
+            bars.len(),
+            bars.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "bossbar remove <id>")]
-pub fn bossbar_remove_id(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"bossbar remove <id>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar remove <id>", level = 2)]
+pub fn bossbar_remove_id(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    ctx.game.boss_bars.remove(&ctx.world, &id.0)?;
+    Ok(Some(format!("Removed custom bossbar [{}]", id.0)))
 }
 
-#[command(usage = "bossbar set <id> color blue")]
-pub fn bossbar_set_id_color_blue(
-    ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color blue\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar set <id> color blue", level = 2)]
+pub fn bossbar_set_id_color_blue(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    run_set_boss_bar_color(ctx, id, BossBarColor::Blue)
 }
 
-#[command(usage = "bossbar set <id> color green")]
+#[command(usage = "bossbar set <id> color green", level = 2)]
 pub fn bossbar_set_id_color_green(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color green\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_color(ctx, id, BossBarColor::Green)
 }
 
-#[command(usage = "bossbar set <id> color pink")]
-pub fn bossbar_set_id_color_pink(
-    ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color pink\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar set <id> color pink", level = 2)]
+pub fn bossbar_set_id_color_pink(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    run_set_boss_bar_color(ctx, id, BossBarColor::Pink)
 }
 
-#[command(usage = "bossbar set <id> color purple")]
+#[command(usage = "bossbar set <id> color purple", level = 2)]
 pub fn bossbar_set_id_color_purple(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color purple\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_color(ctx, id, BossBarColor::Purple)
 }
 
-#[command(usage = "bossbar set <id> color red")]
-pub fn bossbar_set_id_color_red(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color red\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar set <id> color red", level = 2)]
+pub fn bossbar_set_id_color_red(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    run_set_boss_bar_color(ctx, id, BossBarColor::Red)
 }
 
-#[command(usage = "bossbar set <id> color white")]
+#[command(usage = "bossbar set <id> color white", level = 2)]
 pub fn bossbar_set_id_color_white(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color white\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_color(ctx, id, BossBarColor::White)
 }
 
-#[command(usage = "bossbar set <id> color yellow")]
+#[command(usage = "bossbar set <id> color yellow", level = 2)]
 pub fn bossbar_set_id_color_yellow(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> color yellow\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_color(ctx, id, BossBarColor::Yellow)
 }
 
-#[command(usage = "bossbar set <id> max <max>")]
+#[command(usage = "bossbar set <id> max <max>", level = 2)]
 pub fn bossbar_set_id_max_max(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-    _max: IntegerArgumentGreaterThen1,
+    id: ResourceLocation,
+    max: IntegerArgumentGreaterThen1,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> max <max>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let max = scoreboard::score_value(max.0)?;
+    let changed = ctx.game.boss_bars.set_max(&ctx.world, &id.0, max)?;
+    check_changed(changed, "max")?;
+    Ok(Some(format!(
+        "Custom bossbar [{}] has changed maximum to {}",
+        id.0, max
+    )))
 }
 
-#[command(usage = "bossbar set <id> name <name>")]
+#[command(usage = "bossbar set <id> name <name>", level = 2)]
 pub fn bossbar_set_id_name_name(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-    _name: Component,
+    id: ResourceLocation,
+    name: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> name <name>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let changed = ctx.game.boss_bars.set_name(&ctx.world, &id.0, name.0)?;
+    check_changed(changed, "name")?;
+    Ok(Some(format!("Custom bossbar [{}] has been renamed", id.0)))
 }
 
-#[command(usage = "bossbar set <id> players")]
-pub fn bossbar_set_id_players(ctx: &mut CommandCtx, _id: ResourceLocation) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> players\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "bossbar set <id> players", level = 2)]
+pub fn bossbar_set_id_players(ctx: &mut CommandCtx, id: ResourceLocation) -> anyhow::Result<()> {
+    run_set_boss_bar_players(ctx, id, vec![])
 }
 
-#[command(usage = "bossbar set <id> players <targets>")]
+#[command(usage = "bossbar set <id> players <targets>", level = 2)]
 pub fn bossbar_set_id_players_targets(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-    _targets: MultiplePlayers,
+    id: ResourceLocation,
+    targets: MultiplePlayers,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> players <targets>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let players = selector::select_players(ctx, &targets.0)?;
+    run_set_boss_bar_players(ctx, id, players)
 }
 
-#[command(usage = "bossbar set <id> style notched_10")]
+#[command(usage = "bossbar set <id> style notched_10", level = 2)]
 pub fn bossbar_set_id_style_notched_10(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> style notched_10\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_style(ctx, id, BossBarStyle::TenNotches)
 }
 
-#[command(usage = "bossbar set <id> style notched_12")]
+#[command(usage = "bossbar set <id> style notched_12", level = 2)]
 pub fn bossbar_set_id_style_notched_12(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> style notched_12\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_style(ctx, id, BossBarStyle::TwelveNotches)
 }
 
-#[command(usage = "bossbar set <id> style notched_20")]
+#[command(usage = "bossbar set <id> style notched_20", level = 2)]
 pub fn bossbar_set_id_style_notched_20(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> style notched_20\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_style(ctx, id, BossBarStyle::TwentyNotches)
 }
 
-#[command(usage = "bossbar set <id> style notched_6")]
+#[command(usage = "bossbar set <id> style notched_6", level = 2)]
 pub fn bossbar_set_id_style_notched_6(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> style notched_6\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_style(ctx, id, BossBarStyle::SixNotches)
 }
 
-#[command(usage = "bossbar set <id> style progress")]
+#[command(usage = "bossbar set <id> style progress", level = 2)]
 pub fn bossbar_set_id_style_progress(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
+    id: ResourceLocation,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> style progress\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_boss_bar_style(ctx, id, BossBarStyle::NoDivision)
 }
 
-#[command(usage = "bossbar set <id> value <value>")]
+#[command(usage = "bossbar set <id> value <value>", level = 2)]
 pub fn bossbar_set_id_value_value(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-    _value: IntegerArgumentPositive,
+    id: ResourceLocation,
+    value: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> value <value>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let value = scoreboard::score_value(value.0)?;
+    let changed = ctx.game.boss_bars.set_value(&ctx.world, &id.0, value)?;
+    check_changed(changed, "value")?;
+    Ok(Some(format!(
+        "Custom bossbar [{}] has changed value to {}",
+        id.0, value
+    )))
 }
 
-#[command(usage = "bossbar set <id> visible <visible>")]
+#[command(usage = "bossbar set <id> visible <visible>", level = 2)]
 pub fn bossbar_set_id_visible_visible(
     ctx: &mut CommandCtx,
-    _id: ResourceLocation,
-    _visible: BoolArgument,
+    id: ResourceLocation,
+    visible: BoolArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"bossbar set <id> visible <visible>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let changed = ctx
+        .game
+        .boss_bars
+        .set_visible(&ctx.world, &id.0, visible.0)?;
+    check_changed(changed, "visibility")?;
+    Ok(Some(format!(
+        "Custom bossbar [{}] is now {}",
+        id.0,
+        if visible.0 { "visible" } else { "hidden" }
+    )))
 }
 
-#[command(usage = "clear")]
+#[command(usage = "clear", level = 2)]
 pub fn clear(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -1282,7 +1374,7 @@ pub fn clear(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "clear <targets>")]
+#[command(usage = "clear <targets>", level = 2)]
 pub fn clear_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -1296,7 +1388,7 @@ pub fn clear_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow:
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "clear <targets> <item>")]
+#[command(usage = "clear <targets> <item>", level = 2)]
 pub fn clear_targets_item(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -1310,7 +1402,7 @@ pub fn clear_targets_item(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "clear <targets> <item> <maxCount>")]
+#[command(usage = "clear <targets> <item> <maxCount>", level = 2)]
 pub fn clear_targets_item_maxCount(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -1325,206 +1417,304 @@ pub fn clear_targets_item_maxCount(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "clone <begin> <end> <destination>")]
+/// Resolves coordinates relative to the position of the command sender.
+fn block_position(ctx: &CommandCtx, coordinates: Coordinates) -> BlockPosition {
+    let origin = ctx
+        .world
+        .try_get::<Position>(ctx.sender)
+        .map(|pos| *pos)
+        .unwrap_or_default();
+    coordinates.into_position(origin).block()
+}
+
+fn run_clone(
+    ctx: &mut CommandCtx,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
+    mask: MaskMode,
+    mode: CloneMode,
+) -> anyhow::Result<Option<String>> {
+    let source = Region::new(block_position(ctx, begin), block_position(ctx, end));
+    let destination = block_position(ctx, destination);
+
+    let count = blocks::clone(
+        &mut ctx.game,
+        &mut ctx.world,
+        source,
+        destination,
+        mask,
+        mode,
+    )?;
+    Ok(Some(format!("Successfully cloned {} blocks", count)))
+}
+
+fn run_fill(
+    ctx: &mut CommandCtx,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
+    mode: FillMode,
+) -> anyhow::Result<Option<String>> {
+    let region = Region::new(block_position(ctx, from), block_position(ctx, to));
+
+    let count = blocks::fill(&mut ctx.game, &mut ctx.world, region, block.0, mode)?;
+    Ok(Some(format!("Successfully filled {} blocks", count)))
+}
+
+fn run_setblock(
+    ctx: &mut CommandCtx,
+    pos: Coordinates,
+    block: BlockState,
+    mode: SetBlockMode,
+) -> anyhow::Result<Option<String>> {
+    let pos = block_position(ctx, pos);
+
+    blocks::setblock(&mut ctx.game, &mut ctx.world, pos, block.0, mode)?;
+    Ok(Some(format!(
+        "Changed the block at {}, {}, {}",
+        pos.x, pos.y, pos.z
+    )))
+}
+
+#[command(usage = "clone <begin> <end> <destination>", level = 2)]
 pub fn clone_begin_end_destination(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Replace,
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> filtered <filter>")]
+#[command(
+    usage = "clone <begin> <end> <destination> filtered <filter>",
+    level = 2
+)]
 pub fn clone_begin_end_destination_filtered_filter(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
-    _filter: BlockPredicate,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
+    filter: BlockPredicate,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> filtered <filter>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Filtered(filter),
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> filtered <filter> force")]
+#[command(
+    usage = "clone <begin> <end> <destination> filtered <filter> force",
+    level = 2
+)]
 pub fn clone_begin_end_destination_filtered_filter_force(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
-    _filter: BlockPredicate,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
+    filter: BlockPredicate,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> filtered <filter> force\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Filtered(filter),
+        CloneMode::Force,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> filtered <filter> move")]
+#[command(
+    usage = "clone <begin> <end> <destination> filtered <filter> move",
+    level = 2
+)]
 pub fn clone_begin_end_destination_filtered_filter_move(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
-    _filter: BlockPredicate,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
+    filter: BlockPredicate,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> filtered <filter> move\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Filtered(filter),
+        CloneMode::Move,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> filtered <filter> normal")]
+#[command(
+    usage = "clone <begin> <end> <destination> filtered <filter> normal",
+    level = 2
+)]
 pub fn clone_begin_end_destination_filtered_filter_normal(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
-    _filter: BlockPredicate,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
+    filter: BlockPredicate,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> filtered <filter> normal\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Filtered(filter),
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> masked")]
+#[command(usage = "clone <begin> <end> <destination> masked", level = 2)]
 pub fn clone_begin_end_destination_masked(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> masked\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Masked,
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> masked force")]
+#[command(usage = "clone <begin> <end> <destination> masked force", level = 2)]
 pub fn clone_begin_end_destination_masked_force(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> masked force\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Masked,
+        CloneMode::Force,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> masked move")]
+#[command(usage = "clone <begin> <end> <destination> masked move", level = 2)]
 pub fn clone_begin_end_destination_masked_move(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> masked move\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Masked,
+        CloneMode::Move,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> masked normal")]
+#[command(usage = "clone <begin> <end> <destination> masked normal", level = 2)]
 pub fn clone_begin_end_destination_masked_normal(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> masked normal\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Masked,
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> replace")]
+#[command(usage = "clone <begin> <end> <destination> replace", level = 2)]
 pub fn clone_begin_end_destination_replace(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> replace\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Replace,
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> replace force")]
+#[command(usage = "clone <begin> <end> <destination> replace force", level = 2)]
 pub fn clone_begin_end_destination_replace_force(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> replace force\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Replace,
+        CloneMode::Force,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> replace move")]
+#[command(usage = "clone <begin> <end> <destination> replace move", level = 2)]
 pub fn clone_begin_end_destination_replace_move(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> replace move\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Replace,
+        CloneMode::Move,
+    )
 }
 
-#[command(usage = "clone <begin> <end> <destination> replace normal")]
+#[command(usage = "clone <begin> <end> <destination> replace normal", level = 2)]
 pub fn clone_begin_end_destination_replace_normal(
     ctx: &mut CommandCtx,
-    _begin: Coordinates,
-    _end: Coordinates,
-    _destination: Coordinates,
+    begin: Coordinates,
+    end: Coordinates,
+    destination: Coordinates,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"clone <begin> <end> <destination> replace normal\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_clone(
+        ctx,
+        begin,
+        end,
+        destination,
+        MaskMode::Replace,
+        CloneMode::Normal,
+    )
 }
 
-#[command(usage = "data get block <targetPos>")]
+#[command(usage = "data get block <targetPos>", level = 2)]
 pub fn data_get_block_targetPos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1537,7 +1727,7 @@ pub fn data_get_block_targetPos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get block <targetPos> <path>")]
+#[command(usage = "data get block <targetPos> <path>", level = 2)]
 pub fn data_get_block_targetPos_path(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1551,7 +1741,7 @@ pub fn data_get_block_targetPos_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get block <targetPos> <path> <scale>")]
+#[command(usage = "data get block <targetPos> <path> <scale>", level = 2)]
 pub fn data_get_block_targetPos_path_scale(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1566,7 +1756,7 @@ pub fn data_get_block_targetPos_path_scale(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get entity <target>")]
+#[command(usage = "data get entity <target>", level = 2)]
 pub fn data_get_entity_target(ctx: &mut CommandCtx, _target: SingleEntities) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -1576,7 +1766,7 @@ pub fn data_get_entity_target(ctx: &mut CommandCtx, _target: SingleEntities) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get entity <target> <path>")]
+#[command(usage = "data get entity <target> <path>", level = 2)]
 pub fn data_get_entity_target_path(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -1590,7 +1780,7 @@ pub fn data_get_entity_target_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get entity <target> <path> <scale>")]
+#[command(usage = "data get entity <target> <path> <scale>", level = 2)]
 pub fn data_get_entity_target_path_scale(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -1605,7 +1795,7 @@ pub fn data_get_entity_target_path_scale(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get storage <target>")]
+#[command(usage = "data get storage <target>", level = 2)]
 pub fn data_get_storage_target(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -1618,7 +1808,7 @@ pub fn data_get_storage_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get storage <target> <path>")]
+#[command(usage = "data get storage <target> <path>", level = 2)]
 pub fn data_get_storage_target_path(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -1632,7 +1822,7 @@ pub fn data_get_storage_target_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data get storage <target> <path> <scale>")]
+#[command(usage = "data get storage <target> <path> <scale>", level = 2)]
 pub fn data_get_storage_target_path_scale(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -1647,7 +1837,7 @@ pub fn data_get_storage_target_path_scale(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data merge block <targetPos> <nbt>")]
+#[command(usage = "data merge block <targetPos> <nbt>", level = 2)]
 pub fn data_merge_block_targetPos_nbt(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1661,7 +1851,7 @@ pub fn data_merge_block_targetPos_nbt(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data merge entity <target> <nbt>")]
+#[command(usage = "data merge entity <target> <nbt>", level = 2)]
 pub fn data_merge_entity_target_nbt(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -1675,7 +1865,7 @@ pub fn data_merge_entity_target_nbt(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data merge storage <target> <nbt>")]
+#[command(usage = "data merge storage <target> <nbt>", level = 2)]
 pub fn data_merge_storage_target_nbt(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -1689,7 +1879,10 @@ pub fn data_merge_storage_target_nbt(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> append from block <sourcePos>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> append from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_append_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1705,7 +1898,8 @@ pub fn data_modify_block_targetPos_targetPath_append_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> append from block <sourcePos> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> append from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_append_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -1722,7 +1916,10 @@ pub fn data_modify_block_targetPos_targetPath_append_from_block_sourcePos_source
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> append from entity <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> append from entity <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_append_from_entity_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1738,7 +1935,8 @@ pub fn data_modify_block_targetPos_targetPath_append_from_entity_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> append from entity <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> append from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_append_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -1755,7 +1953,10 @@ pub fn data_modify_block_targetPos_targetPath_append_from_entity_source_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> append from storage <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> append from storage <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_append_from_storage_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1771,7 +1972,8 @@ pub fn data_modify_block_targetPos_targetPath_append_from_storage_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> append from storage <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> append from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_append_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -1788,7 +1990,10 @@ pub fn data_modify_block_targetPos_targetPath_append_from_storage_source_sourceP
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> append value <value>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> append value <value>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_append_value_value(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1804,7 +2009,8 @@ pub fn data_modify_block_targetPos_targetPath_append_value_value(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> insert <index> from block <sourcePos>"
+    usage = "data modify block <targetPos> <targetPath> insert <index> from block <sourcePos>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_insert_index_from_block_sourcePos(
     ctx: &mut CommandCtx,
@@ -1822,7 +2028,8 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> insert <index> from block <sourcePos> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> insert <index> from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_insert_index_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -1840,7 +2047,10 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_from_block_sourcePos_
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> insert <index> from entity <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> insert <index> from entity <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_insert_index_from_entity_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1857,7 +2067,8 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_from_entity_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> insert <index> from entity <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> insert <index> from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_insert_index_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -1876,7 +2087,8 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_from_entity_source_so
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> insert <index> from storage <source>"
+    usage = "data modify block <targetPos> <targetPath> insert <index> from storage <source>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_insert_index_from_storage_source(
     ctx: &mut CommandCtx,
@@ -1894,7 +2106,8 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_from_storage_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> insert <index> from storage <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> insert <index> from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_insert_index_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -1912,7 +2125,10 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_from_storage_source_s
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> insert <index> value <value>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> insert <index> value <value>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_insert_index_value_value(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1928,7 +2144,10 @@ pub fn data_modify_block_targetPos_targetPath_insert_index_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> merge from block <sourcePos>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> merge from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_merge_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1944,7 +2163,8 @@ pub fn data_modify_block_targetPos_targetPath_merge_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> merge from block <sourcePos> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> merge from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_merge_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -1961,7 +2181,10 @@ pub fn data_modify_block_targetPos_targetPath_merge_from_block_sourcePos_sourceP
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> merge from entity <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> merge from entity <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_merge_from_entity_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -1977,7 +2200,8 @@ pub fn data_modify_block_targetPos_targetPath_merge_from_entity_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> merge from entity <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> merge from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_merge_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -1994,7 +2218,10 @@ pub fn data_modify_block_targetPos_targetPath_merge_from_entity_source_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> merge from storage <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> merge from storage <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_merge_from_storage_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2010,7 +2237,8 @@ pub fn data_modify_block_targetPos_targetPath_merge_from_storage_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> merge from storage <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> merge from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_merge_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2027,7 +2255,10 @@ pub fn data_modify_block_targetPos_targetPath_merge_from_storage_source_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> merge value <value>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> merge value <value>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_merge_value_value(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2042,7 +2273,10 @@ pub fn data_modify_block_targetPos_targetPath_merge_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> prepend from block <sourcePos>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> prepend from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_prepend_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2058,7 +2292,8 @@ pub fn data_modify_block_targetPos_targetPath_prepend_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> prepend from block <sourcePos> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> prepend from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_prepend_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2075,7 +2310,10 @@ pub fn data_modify_block_targetPos_targetPath_prepend_from_block_sourcePos_sourc
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> prepend from entity <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> prepend from entity <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_prepend_from_entity_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2091,7 +2329,8 @@ pub fn data_modify_block_targetPos_targetPath_prepend_from_entity_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> prepend from entity <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> prepend from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_prepend_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2108,7 +2347,10 @@ pub fn data_modify_block_targetPos_targetPath_prepend_from_entity_source_sourceP
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> prepend from storage <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> prepend from storage <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_prepend_from_storage_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2124,7 +2366,8 @@ pub fn data_modify_block_targetPos_targetPath_prepend_from_storage_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> prepend from storage <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> prepend from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_prepend_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2141,7 +2384,10 @@ pub fn data_modify_block_targetPos_targetPath_prepend_from_storage_source_source
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> prepend value <value>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> prepend value <value>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_prepend_value_value(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2156,7 +2402,10 @@ pub fn data_modify_block_targetPos_targetPath_prepend_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> set from block <sourcePos>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> set from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_set_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2172,7 +2421,8 @@ pub fn data_modify_block_targetPos_targetPath_set_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> set from block <sourcePos> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> set from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_set_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2189,7 +2439,10 @@ pub fn data_modify_block_targetPos_targetPath_set_from_block_sourcePos_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> set from entity <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> set from entity <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_set_from_entity_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2205,7 +2458,8 @@ pub fn data_modify_block_targetPos_targetPath_set_from_entity_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> set from entity <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> set from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_set_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2222,7 +2476,10 @@ pub fn data_modify_block_targetPos_targetPath_set_from_entity_source_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> set from storage <source>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> set from storage <source>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_set_from_storage_source(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2238,7 +2495,8 @@ pub fn data_modify_block_targetPos_targetPath_set_from_storage_source(
 }
 
 #[command(
-    usage = "data modify block <targetPos> <targetPath> set from storage <source> <sourcePath>"
+    usage = "data modify block <targetPos> <targetPath> set from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_block_targetPos_targetPath_set_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2255,7 +2513,10 @@ pub fn data_modify_block_targetPos_targetPath_set_from_storage_source_sourcePath
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify block <targetPos> <targetPath> set value <value>")]
+#[command(
+    usage = "data modify block <targetPos> <targetPath> set value <value>",
+    level = 2
+)]
 pub fn data_modify_block_targetPos_targetPath_set_value_value(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -2270,7 +2531,10 @@ pub fn data_modify_block_targetPos_targetPath_set_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> append from block <sourcePos>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> append from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_append_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2286,7 +2550,8 @@ pub fn data_modify_entity_target_targetPath_append_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> append from block <sourcePos> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> append from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_append_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2303,7 +2568,10 @@ pub fn data_modify_entity_target_targetPath_append_from_block_sourcePos_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> append from entity <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> append from entity <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_append_from_entity_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2319,7 +2587,8 @@ pub fn data_modify_entity_target_targetPath_append_from_entity_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> append from entity <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> append from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_append_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2336,7 +2605,10 @@ pub fn data_modify_entity_target_targetPath_append_from_entity_source_sourcePath
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> append from storage <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> append from storage <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_append_from_storage_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2352,7 +2624,8 @@ pub fn data_modify_entity_target_targetPath_append_from_storage_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> append from storage <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> append from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_append_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2369,7 +2642,10 @@ pub fn data_modify_entity_target_targetPath_append_from_storage_source_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> append value <value>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> append value <value>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_append_value_value(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2384,7 +2660,10 @@ pub fn data_modify_entity_target_targetPath_append_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> insert <index> from block <sourcePos>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> insert <index> from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_insert_index_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2401,7 +2680,8 @@ pub fn data_modify_entity_target_targetPath_insert_index_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> insert <index> from block <sourcePos> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> insert <index> from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_insert_index_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2419,7 +2699,10 @@ pub fn data_modify_entity_target_targetPath_insert_index_from_block_sourcePos_so
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> insert <index> from entity <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> insert <index> from entity <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_insert_index_from_entity_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2436,7 +2719,8 @@ pub fn data_modify_entity_target_targetPath_insert_index_from_entity_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> insert <index> from entity <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> insert <index> from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_insert_index_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2454,7 +2738,10 @@ pub fn data_modify_entity_target_targetPath_insert_index_from_entity_source_sour
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> insert <index> from storage <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> insert <index> from storage <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_insert_index_from_storage_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2471,7 +2758,8 @@ pub fn data_modify_entity_target_targetPath_insert_index_from_storage_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> insert <index> from storage <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> insert <index> from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_insert_index_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2489,7 +2777,10 @@ pub fn data_modify_entity_target_targetPath_insert_index_from_storage_source_sou
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> insert <index> value <value>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> insert <index> value <value>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_insert_index_value_value(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2505,7 +2796,10 @@ pub fn data_modify_entity_target_targetPath_insert_index_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> merge from block <sourcePos>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> merge from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_merge_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2521,7 +2815,8 @@ pub fn data_modify_entity_target_targetPath_merge_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> merge from block <sourcePos> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> merge from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_merge_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2538,7 +2833,10 @@ pub fn data_modify_entity_target_targetPath_merge_from_block_sourcePos_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> merge from entity <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> merge from entity <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_merge_from_entity_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2554,7 +2852,8 @@ pub fn data_modify_entity_target_targetPath_merge_from_entity_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> merge from entity <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> merge from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_merge_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2571,7 +2870,10 @@ pub fn data_modify_entity_target_targetPath_merge_from_entity_source_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> merge from storage <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> merge from storage <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_merge_from_storage_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2587,7 +2889,8 @@ pub fn data_modify_entity_target_targetPath_merge_from_storage_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> merge from storage <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> merge from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_merge_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2604,7 +2907,10 @@ pub fn data_modify_entity_target_targetPath_merge_from_storage_source_sourcePath
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> merge value <value>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> merge value <value>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_merge_value_value(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2619,7 +2925,10 @@ pub fn data_modify_entity_target_targetPath_merge_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> prepend from block <sourcePos>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> prepend from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_prepend_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2635,7 +2944,8 @@ pub fn data_modify_entity_target_targetPath_prepend_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> prepend from block <sourcePos> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> prepend from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_prepend_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2652,7 +2962,10 @@ pub fn data_modify_entity_target_targetPath_prepend_from_block_sourcePos_sourceP
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> prepend from entity <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> prepend from entity <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_prepend_from_entity_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2668,7 +2981,8 @@ pub fn data_modify_entity_target_targetPath_prepend_from_entity_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> prepend from entity <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> prepend from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_prepend_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2685,7 +2999,10 @@ pub fn data_modify_entity_target_targetPath_prepend_from_entity_source_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> prepend from storage <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> prepend from storage <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_prepend_from_storage_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2701,7 +3018,8 @@ pub fn data_modify_entity_target_targetPath_prepend_from_storage_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> prepend from storage <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> prepend from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_prepend_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2718,7 +3036,10 @@ pub fn data_modify_entity_target_targetPath_prepend_from_storage_source_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> prepend value <value>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> prepend value <value>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_prepend_value_value(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2733,7 +3054,10 @@ pub fn data_modify_entity_target_targetPath_prepend_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> set from block <sourcePos>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> set from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_set_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2749,7 +3073,8 @@ pub fn data_modify_entity_target_targetPath_set_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> set from block <sourcePos> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> set from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_set_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2766,7 +3091,10 @@ pub fn data_modify_entity_target_targetPath_set_from_block_sourcePos_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> set from entity <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> set from entity <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_set_from_entity_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2781,7 +3109,10 @@ pub fn data_modify_entity_target_targetPath_set_from_entity_source(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> set from entity <source> <sourcePath>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> set from entity <source> <sourcePath>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_set_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2797,7 +3128,10 @@ pub fn data_modify_entity_target_targetPath_set_from_entity_source_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> set from storage <source>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> set from storage <source>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_set_from_storage_source(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2813,7 +3147,8 @@ pub fn data_modify_entity_target_targetPath_set_from_storage_source(
 }
 
 #[command(
-    usage = "data modify entity <target> <targetPath> set from storage <source> <sourcePath>"
+    usage = "data modify entity <target> <targetPath> set from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_entity_target_targetPath_set_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2830,7 +3165,10 @@ pub fn data_modify_entity_target_targetPath_set_from_storage_source_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify entity <target> <targetPath> set value <value>")]
+#[command(
+    usage = "data modify entity <target> <targetPath> set value <value>",
+    level = 2
+)]
 pub fn data_modify_entity_target_targetPath_set_value_value(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -2845,7 +3183,10 @@ pub fn data_modify_entity_target_targetPath_set_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> append from block <sourcePos>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> append from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_append_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -2861,7 +3202,8 @@ pub fn data_modify_storage_target_targetPath_append_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> append from block <sourcePos> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> append from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_append_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2878,7 +3220,10 @@ pub fn data_modify_storage_target_targetPath_append_from_block_sourcePos_sourceP
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> append from entity <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> append from entity <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_append_from_entity_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -2894,7 +3239,8 @@ pub fn data_modify_storage_target_targetPath_append_from_entity_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> append from entity <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> append from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_append_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2911,7 +3257,10 @@ pub fn data_modify_storage_target_targetPath_append_from_entity_source_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> append from storage <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> append from storage <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_append_from_storage_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -2927,7 +3276,8 @@ pub fn data_modify_storage_target_targetPath_append_from_storage_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> append from storage <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> append from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_append_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -2944,7 +3294,10 @@ pub fn data_modify_storage_target_targetPath_append_from_storage_source_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> append value <value>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> append value <value>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_append_value_value(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -2960,7 +3313,8 @@ pub fn data_modify_storage_target_targetPath_append_value_value(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> insert <index> from block <sourcePos>"
+    usage = "data modify storage <target> <targetPath> insert <index> from block <sourcePos>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_insert_index_from_block_sourcePos(
     ctx: &mut CommandCtx,
@@ -2978,7 +3332,8 @@ pub fn data_modify_storage_target_targetPath_insert_index_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> insert <index> from block <sourcePos> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> insert <index> from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_insert_index_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -2996,7 +3351,10 @@ pub fn data_modify_storage_target_targetPath_insert_index_from_block_sourcePos_s
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> insert <index> from entity <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> insert <index> from entity <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_insert_index_from_entity_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3013,7 +3371,8 @@ pub fn data_modify_storage_target_targetPath_insert_index_from_entity_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> insert <index> from entity <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> insert <index> from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_insert_index_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3031,7 +3390,10 @@ pub fn data_modify_storage_target_targetPath_insert_index_from_entity_source_sou
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> insert <index> from storage <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> insert <index> from storage <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_insert_index_from_storage_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3048,7 +3410,8 @@ pub fn data_modify_storage_target_targetPath_insert_index_from_storage_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> insert <index> from storage <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> insert <index> from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_insert_index_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3066,7 +3429,10 @@ pub fn data_modify_storage_target_targetPath_insert_index_from_storage_source_so
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> insert <index> value <value>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> insert <index> value <value>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_insert_index_value_value(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3082,7 +3448,10 @@ pub fn data_modify_storage_target_targetPath_insert_index_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> merge from block <sourcePos>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> merge from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_merge_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3098,7 +3467,8 @@ pub fn data_modify_storage_target_targetPath_merge_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> merge from block <sourcePos> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> merge from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_merge_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -3115,7 +3485,10 @@ pub fn data_modify_storage_target_targetPath_merge_from_block_sourcePos_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> merge from entity <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> merge from entity <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_merge_from_entity_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3131,7 +3504,8 @@ pub fn data_modify_storage_target_targetPath_merge_from_entity_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> merge from entity <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> merge from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_merge_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3148,7 +3522,10 @@ pub fn data_modify_storage_target_targetPath_merge_from_entity_source_sourcePath
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> merge from storage <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> merge from storage <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_merge_from_storage_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3164,7 +3541,8 @@ pub fn data_modify_storage_target_targetPath_merge_from_storage_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> merge from storage <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> merge from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_merge_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3181,7 +3559,10 @@ pub fn data_modify_storage_target_targetPath_merge_from_storage_source_sourcePat
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> merge value <value>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> merge value <value>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_merge_value_value(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3196,7 +3577,10 @@ pub fn data_modify_storage_target_targetPath_merge_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> prepend from block <sourcePos>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> prepend from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_prepend_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3212,7 +3596,8 @@ pub fn data_modify_storage_target_targetPath_prepend_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> prepend from block <sourcePos> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> prepend from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_prepend_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -3229,7 +3614,10 @@ pub fn data_modify_storage_target_targetPath_prepend_from_block_sourcePos_source
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> prepend from entity <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> prepend from entity <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_prepend_from_entity_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3245,7 +3633,8 @@ pub fn data_modify_storage_target_targetPath_prepend_from_entity_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> prepend from entity <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> prepend from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_prepend_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3262,8 +3651,11 @@ pub fn data_modify_storage_target_targetPath_prepend_from_entity_source_sourcePa
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> prepend from storage <source>")]
-pub fn data_modify_storage_target_targetPath_prepend_from_storage_source(
+#[command(
+    usage = "data modify storage <target> <targetPath> prepend from storage <source>",
+    level = 2
+)]
+pub fn data_modify_storage_target_targetPath_prepend_from_storage_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
     _targetPath: NbtPath,
@@ -3278,7 +3670,8 @@ pub fn data_modify_storage_target_targetPath_prepend_from_storage_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> prepend from storage <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> prepend from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_prepend_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3295,7 +3688,10 @@ pub fn data_modify_storage_target_targetPath_prepend_from_storage_source_sourceP
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> prepend value <value>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> prepend value <value>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_prepend_value_value(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3310,7 +3706,10 @@ pub fn data_modify_storage_target_targetPath_prepend_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> set from block <sourcePos>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> set from block <sourcePos>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_set_from_block_sourcePos(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3326,7 +3725,8 @@ pub fn data_modify_storage_target_targetPath_set_from_block_sourcePos(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> set from block <sourcePos> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> set from block <sourcePos> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_set_from_block_sourcePos_sourcePath(
     ctx: &mut CommandCtx,
@@ -3343,7 +3743,10 @@ pub fn data_modify_storage_target_targetPath_set_from_block_sourcePos_sourcePath
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> set from entity <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> set from entity <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_set_from_entity_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3359,7 +3762,8 @@ pub fn data_modify_storage_target_targetPath_set_from_entity_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> set from entity <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> set from entity <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_set_from_entity_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3376,7 +3780,10 @@ pub fn data_modify_storage_target_targetPath_set_from_entity_source_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> set from storage <source>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> set from storage <source>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_set_from_storage_source(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3392,7 +3799,8 @@ pub fn data_modify_storage_target_targetPath_set_from_storage_source(
 }
 
 #[command(
-    usage = "data modify storage <target> <targetPath> set from storage <source> <sourcePath>"
+    usage = "data modify storage <target> <targetPath> set from storage <source> <sourcePath>",
+    level = 2
 )]
 pub fn data_modify_storage_target_targetPath_set_from_storage_source_sourcePath(
     ctx: &mut CommandCtx,
@@ -3409,7 +3817,10 @@ pub fn data_modify_storage_target_targetPath_set_from_storage_source_sourcePath(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data modify storage <target> <targetPath> set value <value>")]
+#[command(
+    usage = "data modify storage <target> <targetPath> set value <value>",
+    level = 2
+)]
 pub fn data_modify_storage_target_targetPath_set_value_value(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3424,7 +3835,7 @@ pub fn data_modify_storage_target_targetPath_set_value_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data remove block <targetPos> <path>")]
+#[command(usage = "data remove block <targetPos> <path>", level = 2)]
 pub fn data_remove_block_targetPos_path(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -3438,7 +3849,7 @@ pub fn data_remove_block_targetPos_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data remove entity <target> <path>")]
+#[command(usage = "data remove entity <target> <path>", level = 2)]
 pub fn data_remove_entity_target_path(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -3452,7 +3863,7 @@ pub fn data_remove_entity_target_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "data remove storage <target> <path>")]
+#[command(usage = "data remove storage <target> <path>", level = 2)]
 pub fn data_remove_storage_target_path(
     ctx: &mut CommandCtx,
     _target: ResourceLocation,
@@ -3466,7 +3877,7 @@ pub fn data_remove_storage_target_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack disable <name>")]
+#[command(usage = "datapack disable <name>", level = 2)]
 pub fn datapack_disable_name(
     ctx: &mut CommandCtx,
     _name: StringArgumentPhrase,
@@ -3479,7 +3890,7 @@ pub fn datapack_disable_name(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack enable <name>")]
+#[command(usage = "datapack enable <name>", level = 2)]
 pub fn datapack_enable_name(
     ctx: &mut CommandCtx,
     _name: StringArgumentPhrase,
@@ -3492,7 +3903,7 @@ pub fn datapack_enable_name(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack enable <name> after <existing>")]
+#[command(usage = "datapack enable <name> after <existing>", level = 2)]
 pub fn datapack_enable_name_after_existing(
     ctx: &mut CommandCtx,
     _name: StringArgumentPhrase,
@@ -3506,7 +3917,7 @@ pub fn datapack_enable_name_after_existing(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack enable <name> before <existing>")]
+#[command(usage = "datapack enable <name> before <existing>", level = 2)]
 pub fn datapack_enable_name_before_existing(
     ctx: &mut CommandCtx,
     _name: StringArgumentPhrase,
@@ -3520,7 +3931,7 @@ pub fn datapack_enable_name_before_existing(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack enable <name> first")]
+#[command(usage = "datapack enable <name> first", level = 2)]
 pub fn datapack_enable_name_first(
     ctx: &mut CommandCtx,
     _name: StringArgumentPhrase,
@@ -3533,7 +3944,7 @@ pub fn datapack_enable_name_first(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack enable <name> last")]
+#[command(usage = "datapack enable <name> last", level = 2)]
 pub fn datapack_enable_name_last(
     ctx: &mut CommandCtx,
     _name: StringArgumentPhrase,
@@ -3546,7 +3957,7 @@ pub fn datapack_enable_name_last(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack list")]
+#[command(usage = "datapack list", level = 2)]
 pub fn datapack_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3560,7 +3971,7 @@ pub fn datapack_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack list available")]
+#[command(usage = "datapack list available", level = 2)]
 pub fn datapack_list_available(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3570,7 +3981,7 @@ pub fn datapack_list_available(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "datapack list enabled")]
+#[command(usage = "datapack list enabled", level = 2)]
 pub fn datapack_list_enabled(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3584,7 +3995,7 @@ pub fn datapack_list_enabled(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "debug report")]
+#[command(usage = "debug report", level = 3)]
 pub fn debug_report(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3598,7 +4009,7 @@ pub fn debug_report(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "debug start")]
+#[command(usage = "debug start", level = 3)]
 pub fn debug_start(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3612,7 +4023,7 @@ pub fn debug_start(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "debug stop")]
+#[command(usage = "debug stop", level = 3)]
 pub fn debug_stop(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3626,7 +4037,7 @@ pub fn debug_stop(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "defaultgamemode adventure")]
+#[command(usage = "defaultgamemode adventure", level = 2)]
 pub fn defaultgamemode_adventure(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3636,7 +4047,7 @@ pub fn defaultgamemode_adventure(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "defaultgamemode creative")]
+#[command(usage = "defaultgamemode creative", level = 2)]
 pub fn defaultgamemode_creative(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3646,7 +4057,7 @@ pub fn defaultgamemode_creative(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "defaultgamemode spectator")]
+#[command(usage = "defaultgamemode spectator", level = 2)]
 pub fn defaultgamemode_spectator(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3656,7 +4067,7 @@ pub fn defaultgamemode_spectator(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "defaultgamemode survival")]
+#[command(usage = "defaultgamemode survival", level = 2)]
 pub fn defaultgamemode_survival(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3666,21 +4077,27 @@ pub fn defaultgamemode_survival(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "deop <targets>")]
-pub fn deop_targets(ctx: &mut CommandCtx, _targets: GameProfile) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"deop <targets>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "deop <targets>", level = 3)]
+pub fn deop_targets(ctx: &mut CommandCtx, targets: GameProfile) -> anyhow::Result<()> {
+    let profiles = access::game_profiles(ctx, &targets.0)?;
+
+    let mut lines = vec![];
+    for profile in profiles {
+        if ctx.game.access_lists.write().deop(profile.uuid)? {
+            for player in access::online_players(&ctx.world, |uuid, _| uuid == profile.uuid) {
+                permissions::set_operator_level(&ctx.world, player, 0);
+            }
+            lines.push(format!("Made {} no longer a server operator", profile.name));
+        }
     }
-    Ok(Some("".to_string()))
+
+    if lines.is_empty() {
+        return Err(AccessCommandError::NotAnOperator.into());
+    }
+    Ok(Some(lines.join("\n")))
 }
 
-#[command(usage = "difficulty")]
+#[command(usage = "difficulty", level = 2)]
 pub fn difficulty(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3694,7 +4111,7 @@ pub fn difficulty(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "difficulty easy")]
+#[command(usage = "difficulty easy", level = 2)]
 pub fn difficulty_easy(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3708,7 +4125,7 @@ pub fn difficulty_easy(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "difficulty hard")]
+#[command(usage = "difficulty hard", level = 2)]
 pub fn difficulty_hard(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3722,7 +4139,7 @@ pub fn difficulty_hard(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "difficulty normal")]
+#[command(usage = "difficulty normal", level = 2)]
 pub fn difficulty_normal(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3736,7 +4153,7 @@ pub fn difficulty_normal(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "difficulty peaceful")]
+#[command(usage = "difficulty peaceful", level = 2)]
 pub fn difficulty_peaceful(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3750,7 +4167,7 @@ pub fn difficulty_peaceful(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect clear")]
+#[command(usage = "effect clear", level = 2)]
 pub fn effect_clear(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -3764,7 +4181,7 @@ pub fn effect_clear(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect clear <targets>")]
+#[command(usage = "effect clear <targets>", level = 2)]
 pub fn effect_clear_targets(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3777,7 +4194,7 @@ pub fn effect_clear_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect clear <targets> <effect>")]
+#[command(usage = "effect clear <targets> <effect>", level = 2)]
 pub fn effect_clear_targets_effect(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3791,7 +4208,7 @@ pub fn effect_clear_targets_effect(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect give <targets> <effect>")]
+#[command(usage = "effect give <targets> <effect>", level = 2)]
 pub fn effect_give_targets_effect(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3805,7 +4222,7 @@ pub fn effect_give_targets_effect(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect give <targets> <effect> <seconds>")]
+#[command(usage = "effect give <targets> <effect> <seconds>", level = 2)]
 pub fn effect_give_targets_effect_seconds(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3820,7 +4237,10 @@ pub fn effect_give_targets_effect_seconds(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect give <targets> <effect> <seconds> <amplifier>")]
+#[command(
+    usage = "effect give <targets> <effect> <seconds> <amplifier>",
+    level = 2
+)]
 pub fn effect_give_targets_effect_seconds_amplifier(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3836,7 +4256,10 @@ pub fn effect_give_targets_effect_seconds_amplifier(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "effect give <targets> <effect> <seconds> <amplifier> <hideParticles>")]
+#[command(
+    usage = "effect give <targets> <effect> <seconds> <amplifier> <hideParticles>",
+    level = 2
+)]
 pub fn effect_give_targets_effect_seconds_amplifier_hideParticles(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3853,7 +4276,7 @@ pub fn effect_give_targets_effect_seconds_amplifier_hideParticles(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "enchant <targets> <enchantment>")]
+#[command(usage = "enchant <targets> <enchantment>", level = 2)]
 pub fn enchant_targets_enchantment(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3867,7 +4290,7 @@ pub fn enchant_targets_enchantment(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "enchant <targets> <enchantment> <level>")]
+#[command(usage = "enchant <targets> <enchantment> <level>", level = 2)]
 pub fn enchant_targets_enchantment_level(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -3882,7 +4305,7 @@ pub fn enchant_targets_enchantment_level(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if block <pos> <block>")]
+#[command(usage = "execute if block <pos> <block>", level = 2)]
 pub fn execute_if_block_pos_block(
     ctx: &mut CommandCtx,
     _pos: Coordinates,
@@ -3896,7 +4319,7 @@ pub fn execute_if_block_pos_block(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if blocks <start> <end> <destination> all")]
+#[command(usage = "execute if blocks <start> <end> <destination> all", level = 2)]
 pub fn execute_if_blocks_start_end_destination_all(
     ctx: &mut CommandCtx,
     _start: Coordinates,
@@ -3911,7 +4334,10 @@ pub fn execute_if_blocks_start_end_destination_all(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if blocks <start> <end> <destination> masked")]
+#[command(
+    usage = "execute if blocks <start> <end> <destination> masked",
+    level = 2
+)]
 pub fn execute_if_blocks_start_end_destination_masked(
     ctx: &mut CommandCtx,
     _start: Coordinates,
@@ -3926,7 +4352,7 @@ pub fn execute_if_blocks_start_end_destination_masked(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if data block <sourcePos> <path>")]
+#[command(usage = "execute if data block <sourcePos> <path>", level = 2)]
 pub fn execute_if_data_block_sourcePos_path(
     ctx: &mut CommandCtx,
     _sourcePos: Coordinates,
@@ -3940,7 +4366,7 @@ pub fn execute_if_data_block_sourcePos_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if data entity <source> <path>")]
+#[command(usage = "execute if data entity <source> <path>", level = 2)]
 pub fn execute_if_data_entity_source_path(
     ctx: &mut CommandCtx,
     _source: SingleEntities,
@@ -3954,7 +4380,7 @@ pub fn execute_if_data_entity_source_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if data storage <source> <path>")]
+#[command(usage = "execute if data storage <source> <path>", level = 2)]
 pub fn execute_if_data_storage_source_path(
     ctx: &mut CommandCtx,
     _source: ResourceLocation,
@@ -3968,7 +4394,7 @@ pub fn execute_if_data_storage_source_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if entity <entities>")]
+#[command(usage = "execute if entity <entities>", level = 2)]
 pub fn execute_if_entity_entities(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -3981,7 +4407,7 @@ pub fn execute_if_entity_entities(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if predicate <predicate>")]
+#[command(usage = "execute if predicate <predicate>", level = 2)]
 pub fn execute_if_predicate_predicate(
     ctx: &mut CommandCtx,
     _predicate: ResourceLocation,
@@ -4010,7 +4436,10 @@ pub fn execute_if_score_target_targetObjective_lt_source_sourceObjective(
     Ok(Some("".to_string()))
 }
 */
-#[command(usage = "execute if score <target> <targetObjective> <= <source> <sourceObjective>")]
+#[command(
+    usage = "execute if score <target> <targetObjective> <= <source> <sourceObjective>",
+    level = 2
+)]
 pub fn execute_if_score_target_targetObjective_lteq_source_sourceObjective(
     ctx: &mut CommandCtx,
     _target: SingleScoreHolder,
@@ -4060,7 +4489,10 @@ pub fn execute_if_score_target_targetObjective_gt_source_sourceObjective(
 }
 */
 
-#[command(usage = "execute if score <target> <targetObjective> >= <source> <sourceObjective>")]
+#[command(
+    usage = "execute if score <target> <targetObjective> >= <source> <sourceObjective>",
+    level = 2
+)]
 pub fn execute_if_score_target_targetObjective_gteq_source_sourceObjective(
     ctx: &mut CommandCtx,
     _target: SingleScoreHolder,
@@ -4076,7 +4508,10 @@ pub fn execute_if_score_target_targetObjective_gteq_source_sourceObjective(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute if score <target> <targetObjective> matches <range>")]
+#[command(
+    usage = "execute if score <target> <targetObjective> matches <range>",
+    level = 2
+)]
 pub fn execute_if_score_target_targetObjective_matches_range(
     ctx: &mut CommandCtx,
     _target: SingleScoreHolder,
@@ -4091,7 +4526,7 @@ pub fn execute_if_score_target_targetObjective_matches_range(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless block <pos> <block>")]
+#[command(usage = "execute unless block <pos> <block>", level = 2)]
 pub fn execute_unless_block_pos_block(
     ctx: &mut CommandCtx,
     _pos: Coordinates,
@@ -4105,7 +4540,10 @@ pub fn execute_unless_block_pos_block(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless blocks <start> <end> <destination> all")]
+#[command(
+    usage = "execute unless blocks <start> <end> <destination> all",
+    level = 2
+)]
 pub fn execute_unless_blocks_start_end_destination_all(
     ctx: &mut CommandCtx,
     _start: Coordinates,
@@ -4120,7 +4558,10 @@ pub fn execute_unless_blocks_start_end_destination_all(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless blocks <start> <end> <destination> masked")]
+#[command(
+    usage = "execute unless blocks <start> <end> <destination> masked",
+    level = 2
+)]
 pub fn execute_unless_blocks_start_end_destination_masked(
     ctx: &mut CommandCtx,
     _start: Coordinates,
@@ -4135,7 +4576,7 @@ pub fn execute_unless_blocks_start_end_destination_masked(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless data block <sourcePos> <path>")]
+#[command(usage = "execute unless data block <sourcePos> <path>", level = 2)]
 pub fn execute_unless_data_block_sourcePos_path(
     ctx: &mut CommandCtx,
     _sourcePos: Coordinates,
@@ -4149,7 +4590,7 @@ pub fn execute_unless_data_block_sourcePos_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless data entity <source> <path>")]
+#[command(usage = "execute unless data entity <source> <path>", level = 2)]
 pub fn execute_unless_data_entity_source_path(
     ctx: &mut CommandCtx,
     _source: SingleEntities,
@@ -4163,7 +4604,7 @@ pub fn execute_unless_data_entity_source_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless data storage <source> <path>")]
+#[command(usage = "execute unless data storage <source> <path>", level = 2)]
 pub fn execute_unless_data_storage_source_path(
     ctx: &mut CommandCtx,
     _source: ResourceLocation,
@@ -4177,7 +4618,7 @@ pub fn execute_unless_data_storage_source_path(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless entity <entities>")]
+#[command(usage = "execute unless entity <entities>", level = 2)]
 pub fn execute_unless_entity_entities(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -4190,7 +4631,7 @@ pub fn execute_unless_entity_entities(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless predicate <predicate>")]
+#[command(usage = "execute unless predicate <predicate>", level = 2)]
 pub fn execute_unless_predicate_predicate(
     ctx: &mut CommandCtx,
     _predicate: ResourceLocation,
@@ -4220,7 +4661,10 @@ pub fn execute_unless_score_target_targetObjective_lt_source_sourceObjective(
 }
 */
 
-#[command(usage = "execute unless score <target> <targetObjective> <= <source> <sourceObjective>")]
+#[command(
+    usage = "execute unless score <target> <targetObjective> <= <source> <sourceObjective>",
+    level = 2
+)]
 pub fn execute_unless_score_target_targetObjective_lteq_source_sourceObjective(
     ctx: &mut CommandCtx,
     _target: SingleScoreHolder,
@@ -4269,7 +4713,10 @@ pub fn execute_unless_score_target_targetObjective_gt_source_sourceObjective(
     Ok(Some("".to_string()))
 }
 */
-#[command(usage = "execute unless score <target> <targetObjective> >= <source> <sourceObjective>")]
+#[command(
+    usage = "execute unless score <target> <targetObjective> >= <source> <sourceObjective>",
+    level = 2
+)]
 pub fn execute_unless_score_target_targetObjective_gteq_source_sourceObjective(
     ctx: &mut CommandCtx,
     _target: SingleScoreHolder,
@@ -4285,7 +4732,10 @@ pub fn execute_unless_score_target_targetObjective_gteq_source_sourceObjective(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "execute unless score <target> <targetObjective> matches <range>")]
+#[command(
+    usage = "execute unless score <target> <targetObjective> matches <range>",
+    level = 2
+)]
 pub fn execute_unless_score_target_targetObjective_matches_range(
     ctx: &mut CommandCtx,
     _target: SingleScoreHolder,
@@ -4300,7 +4750,7 @@ pub fn execute_unless_score_target_targetObjective_matches_range(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience add <targets> <amount>")]
+#[command(usage = "experience add <targets> <amount>", level = 2)]
 pub fn experience_add_targets_amount(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -4314,7 +4764,7 @@ pub fn experience_add_targets_amount(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience add <targets> <amount> levels")]
+#[command(usage = "experience add <targets> <amount> levels", level = 2)]
 pub fn experience_add_targets_amount_levels(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -4328,7 +4778,7 @@ pub fn experience_add_targets_amount_levels(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience add <targets> <amount> points")]
+#[command(usage = "experience add <targets> <amount> points", level = 2)]
 pub fn experience_add_targets_amount_points(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -4342,7 +4792,7 @@ pub fn experience_add_targets_amount_points(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience query <targets> levels")]
+#[command(usage = "experience query <targets> levels", level = 2)]
 pub fn experience_query_targets_levels(
     ctx: &mut CommandCtx,
     _targets: SinglePlayer,
@@ -4355,7 +4805,7 @@ pub fn experience_query_targets_levels(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience query <targets> points")]
+#[command(usage = "experience query <targets> points", level = 2)]
 pub fn experience_query_targets_points(
     ctx: &mut CommandCtx,
     _targets: SinglePlayer,
@@ -4368,7 +4818,7 @@ pub fn experience_query_targets_points(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience set <targets> <amount>")]
+#[command(usage = "experience set <targets> <amount>", level = 2)]
 pub fn experience_set_targets_amount(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -4382,7 +4832,7 @@ pub fn experience_set_targets_amount(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience set <targets> <amount> levels")]
+#[command(usage = "experience set <targets> <amount> levels", level = 2)]
 pub fn experience_set_targets_amount_levels(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -4396,7 +4846,7 @@ pub fn experience_set_targets_amount_levels(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "experience set <targets> <amount> points")]
+#[command(usage = "experience set <targets> <amount> points", level = 2)]
 pub fn experience_set_targets_amount_points(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -4410,113 +4860,78 @@ pub fn experience_set_targets_amount_points(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "fill <from> <to> <block>")]
+#[command(usage = "fill <from> <to> <block>", level = 2)]
 pub fn fill_from_to_block(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Replace(None))
 }
 
-#[command(usage = "fill <from> <to> <block> destroy")]
+#[command(usage = "fill <from> <to> <block> destroy", level = 2)]
 pub fn fill_from_to_block_destroy(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block> destroy\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Destroy)
 }
 
-#[command(usage = "fill <from> <to> <block> hollow")]
+#[command(usage = "fill <from> <to> <block> hollow", level = 2)]
 pub fn fill_from_to_block_hollow(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block> hollow\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Hollow)
 }
 
-#[command(usage = "fill <from> <to> <block> keep")]
+#[command(usage = "fill <from> <to> <block> keep", level = 2)]
 pub fn fill_from_to_block_keep(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block> keep\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Keep)
 }
 
-#[command(usage = "fill <from> <to> <block> outline")]
+#[command(usage = "fill <from> <to> <block> outline", level = 2)]
 pub fn fill_from_to_block_outline(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block> outline\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Outline)
 }
 
-#[command(usage = "fill <from> <to> <block> replace")]
+#[command(usage = "fill <from> <to> <block> replace", level = 2)]
 pub fn fill_from_to_block_replace(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block> replace\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Replace(None))
 }
 
-#[command(usage = "fill <from> <to> <block> replace <filter>")]
+#[command(usage = "fill <from> <to> <block> replace <filter>", level = 2)]
 pub fn fill_from_to_block_replace_filter(
     ctx: &mut CommandCtx,
-    _from: Coordinates,
-    _to: Coordinates,
-    _block: BlockState,
-    _filter: BlockPredicate,
+    from: Coordinates,
+    to: Coordinates,
+    block: BlockState,
+    filter: BlockPredicate,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"fill <from> <to> <block> replace <filter>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_fill(ctx, from, to, block, FillMode::Replace(Some(filter)))
 }
 
-#[command(usage = "forceload add <from>")]
+#[command(usage = "forceload add <from>", level = 2)]
 pub fn forceload_add_from(ctx: &mut CommandCtx, _from: ColumnPos) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4530,7 +4945,7 @@ pub fn forceload_add_from(ctx: &mut CommandCtx, _from: ColumnPos) -> anyhow::Res
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "forceload add <from> <to>")]
+#[command(usage = "forceload add <from> <to>", level = 2)]
 pub fn forceload_add_from_to(
     ctx: &mut CommandCtx,
     _from: ColumnPos,
@@ -4544,7 +4959,7 @@ pub fn forceload_add_from_to(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "forceload query")]
+#[command(usage = "forceload query", level = 2)]
 pub fn forceload_query(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4558,7 +4973,7 @@ pub fn forceload_query(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "forceload query <pos>")]
+#[command(usage = "forceload query <pos>", level = 2)]
 pub fn forceload_query_pos(ctx: &mut CommandCtx, _pos: ColumnPos) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4572,7 +4987,7 @@ pub fn forceload_query_pos(ctx: &mut CommandCtx, _pos: ColumnPos) -> anyhow::Res
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "forceload remove all")]
+#[command(usage = "forceload remove all", level = 2)]
 pub fn forceload_remove_all(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4586,7 +5001,7 @@ pub fn forceload_remove_all(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "forceload remove <from>")]
+#[command(usage = "forceload remove <from>", level = 2)]
 pub fn forceload_remove_from(ctx: &mut CommandCtx, _from: ColumnPos) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4596,7 +5011,7 @@ pub fn forceload_remove_from(ctx: &mut CommandCtx, _from: ColumnPos) -> anyhow::
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "forceload remove <from> <to>")]
+#[command(usage = "forceload remove <from> <to>", level = 2)]
 pub fn forceload_remove_from_to(
     ctx: &mut CommandCtx,
     _from: ColumnPos,
@@ -4610,7 +5025,7 @@ pub fn forceload_remove_from_to(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "function <name>")]
+#[command(usage = "function <name>", level = 2)]
 pub fn function_name(ctx: &mut CommandCtx, _name: MinecraftFunction) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4624,7 +5039,7 @@ pub fn function_name(ctx: &mut CommandCtx, _name: MinecraftFunction) -> anyhow::
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode adventure")]
+#[command(usage = "gamemode adventure", level = 2)]
 pub fn gamemode_adventure(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4638,7 +5053,7 @@ pub fn gamemode_adventure(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode adventure <target>")]
+#[command(usage = "gamemode adventure <target>", level = 2)]
 pub fn gamemode_adventure_target(
     ctx: &mut CommandCtx,
     _target: MultiplePlayers,
@@ -4651,7 +5066,7 @@ pub fn gamemode_adventure_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode creative")]
+#[command(usage = "gamemode creative", level = 2)]
 pub fn gamemode_creative(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4665,7 +5080,7 @@ pub fn gamemode_creative(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode creative <target>")]
+#[command(usage = "gamemode creative <target>", level = 2)]
 pub fn gamemode_creative_target(
     ctx: &mut CommandCtx,
     _target: MultiplePlayers,
@@ -4678,7 +5093,7 @@ pub fn gamemode_creative_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode spectator")]
+#[command(usage = "gamemode spectator", level = 2)]
 pub fn gamemode_spectator(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4692,7 +5107,7 @@ pub fn gamemode_spectator(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode spectator <target>")]
+#[command(usage = "gamemode spectator <target>", level = 2)]
 pub fn gamemode_spectator_target(
     ctx: &mut CommandCtx,
     _target: MultiplePlayers,
@@ -4705,7 +5120,7 @@ pub fn gamemode_spectator_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode survival")]
+#[command(usage = "gamemode survival", level = 2)]
 pub fn gamemode_survival(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4719,7 +5134,7 @@ pub fn gamemode_survival(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamemode survival <target>")]
+#[command(usage = "gamemode survival <target>", level = 2)]
 pub fn gamemode_survival_target(
     ctx: &mut CommandCtx,
     _target: MultiplePlayers,
@@ -4732,7 +5147,7 @@ pub fn gamemode_survival_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule announceAdvancements")]
+#[command(usage = "gamerule announceAdvancements", level = 2)]
 pub fn gamerule_announceAdvancements(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4742,7 +5157,7 @@ pub fn gamerule_announceAdvancements(ctx: &mut CommandCtx) -> anyhow::Result<()>
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule announceAdvancements <value>")]
+#[command(usage = "gamerule announceAdvancements <value>", level = 2)]
 pub fn gamerule_announceAdvancements_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4755,7 +5170,7 @@ pub fn gamerule_announceAdvancements_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule commandBlockOutput")]
+#[command(usage = "gamerule commandBlockOutput", level = 2)]
 pub fn gamerule_commandBlockOutput(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4765,7 +5180,7 @@ pub fn gamerule_commandBlockOutput(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule commandBlockOutput <value>")]
+#[command(usage = "gamerule commandBlockOutput <value>", level = 2)]
 pub fn gamerule_commandBlockOutput_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4778,7 +5193,7 @@ pub fn gamerule_commandBlockOutput_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule disableElytraMovementCheck")]
+#[command(usage = "gamerule disableElytraMovementCheck", level = 2)]
 pub fn gamerule_disableElytraMovementCheck(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4788,7 +5203,7 @@ pub fn gamerule_disableElytraMovementCheck(ctx: &mut CommandCtx) -> anyhow::Resu
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule disableElytraMovementCheck <value>")]
+#[command(usage = "gamerule disableElytraMovementCheck <value>", level = 2)]
 pub fn gamerule_disableElytraMovementCheck_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4801,7 +5216,7 @@ pub fn gamerule_disableElytraMovementCheck_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule disableRaids")]
+#[command(usage = "gamerule disableRaids", level = 2)]
 pub fn gamerule_disableRaids(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4815,7 +5230,7 @@ pub fn gamerule_disableRaids(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule disableRaids <value>")]
+#[command(usage = "gamerule disableRaids <value>", level = 2)]
 pub fn gamerule_disableRaids_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4828,7 +5243,7 @@ pub fn gamerule_disableRaids_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doDaylightCycle")]
+#[command(usage = "gamerule doDaylightCycle", level = 2)]
 pub fn gamerule_doDaylightCycle(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4838,7 +5253,7 @@ pub fn gamerule_doDaylightCycle(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doDaylightCycle <value>")]
+#[command(usage = "gamerule doDaylightCycle <value>", level = 2)]
 pub fn gamerule_doDaylightCycle_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4851,7 +5266,7 @@ pub fn gamerule_doDaylightCycle_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doEntityDrops")]
+#[command(usage = "gamerule doEntityDrops", level = 2)]
 pub fn gamerule_doEntityDrops(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4861,7 +5276,7 @@ pub fn gamerule_doEntityDrops(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doEntityDrops <value>")]
+#[command(usage = "gamerule doEntityDrops <value>", level = 2)]
 pub fn gamerule_doEntityDrops_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4874,7 +5289,7 @@ pub fn gamerule_doEntityDrops_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doFireTick")]
+#[command(usage = "gamerule doFireTick", level = 2)]
 pub fn gamerule_doFireTick(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4888,7 +5303,7 @@ pub fn gamerule_doFireTick(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doFireTick <value>")]
+#[command(usage = "gamerule doFireTick <value>", level = 2)]
 pub fn gamerule_doFireTick_value(ctx: &mut CommandCtx, _value: BoolArgument) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4898,7 +5313,7 @@ pub fn gamerule_doFireTick_value(ctx: &mut CommandCtx, _value: BoolArgument) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doImmediateRespawn")]
+#[command(usage = "gamerule doImmediateRespawn", level = 2)]
 pub fn gamerule_doImmediateRespawn(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4908,7 +5323,7 @@ pub fn gamerule_doImmediateRespawn(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doImmediateRespawn <value>")]
+#[command(usage = "gamerule doImmediateRespawn <value>", level = 2)]
 pub fn gamerule_doImmediateRespawn_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4921,7 +5336,7 @@ pub fn gamerule_doImmediateRespawn_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doInsomnia")]
+#[command(usage = "gamerule doInsomnia", level = 2)]
 pub fn gamerule_doInsomnia(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4935,7 +5350,7 @@ pub fn gamerule_doInsomnia(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doInsomnia <value>")]
+#[command(usage = "gamerule doInsomnia <value>", level = 2)]
 pub fn gamerule_doInsomnia_value(ctx: &mut CommandCtx, _value: BoolArgument) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4945,7 +5360,7 @@ pub fn gamerule_doInsomnia_value(ctx: &mut CommandCtx, _value: BoolArgument) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doLimitedCrafting")]
+#[command(usage = "gamerule doLimitedCrafting", level = 2)]
 pub fn gamerule_doLimitedCrafting(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4955,7 +5370,7 @@ pub fn gamerule_doLimitedCrafting(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doLimitedCrafting <value>")]
+#[command(usage = "gamerule doLimitedCrafting <value>", level = 2)]
 pub fn gamerule_doLimitedCrafting_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -4968,7 +5383,7 @@ pub fn gamerule_doLimitedCrafting_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doMobLoot")]
+#[command(usage = "gamerule doMobLoot", level = 2)]
 pub fn gamerule_doMobLoot(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4982,7 +5397,7 @@ pub fn gamerule_doMobLoot(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doMobLoot <value>")]
+#[command(usage = "gamerule doMobLoot <value>", level = 2)]
 pub fn gamerule_doMobLoot_value(ctx: &mut CommandCtx, _value: BoolArgument) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -4992,7 +5407,7 @@ pub fn gamerule_doMobLoot_value(ctx: &mut CommandCtx, _value: BoolArgument) -> a
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doMobSpawning")]
+#[command(usage = "gamerule doMobSpawning", level = 2)]
 pub fn gamerule_doMobSpawning(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5002,7 +5417,7 @@ pub fn gamerule_doMobSpawning(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doMobSpawning <value>")]
+#[command(usage = "gamerule doMobSpawning <value>", level = 2)]
 pub fn gamerule_doMobSpawning_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5015,7 +5430,7 @@ pub fn gamerule_doMobSpawning_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doPatrolSpawning")]
+#[command(usage = "gamerule doPatrolSpawning", level = 2)]
 pub fn gamerule_doPatrolSpawning(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5025,7 +5440,7 @@ pub fn gamerule_doPatrolSpawning(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doPatrolSpawning <value>")]
+#[command(usage = "gamerule doPatrolSpawning <value>", level = 2)]
 pub fn gamerule_doPatrolSpawning_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5038,7 +5453,7 @@ pub fn gamerule_doPatrolSpawning_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doTileDrops")]
+#[command(usage = "gamerule doTileDrops", level = 2)]
 pub fn gamerule_doTileDrops(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5052,7 +5467,7 @@ pub fn gamerule_doTileDrops(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doTileDrops <value>")]
+#[command(usage = "gamerule doTileDrops <value>", level = 2)]
 pub fn gamerule_doTileDrops_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5065,7 +5480,7 @@ pub fn gamerule_doTileDrops_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doTraderSpawning")]
+#[command(usage = "gamerule doTraderSpawning", level = 2)]
 pub fn gamerule_doTraderSpawning(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5075,7 +5490,7 @@ pub fn gamerule_doTraderSpawning(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doTraderSpawning <value>")]
+#[command(usage = "gamerule doTraderSpawning <value>", level = 2)]
 pub fn gamerule_doTraderSpawning_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5088,7 +5503,7 @@ pub fn gamerule_doTraderSpawning_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doWeatherCycle")]
+#[command(usage = "gamerule doWeatherCycle", level = 2)]
 pub fn gamerule_doWeatherCycle(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5098,7 +5513,7 @@ pub fn gamerule_doWeatherCycle(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule doWeatherCycle <value>")]
+#[command(usage = "gamerule doWeatherCycle <value>", level = 2)]
 pub fn gamerule_doWeatherCycle_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5111,7 +5526,7 @@ pub fn gamerule_doWeatherCycle_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule drowningDamage")]
+#[command(usage = "gamerule drowningDamage", level = 2)]
 pub fn gamerule_drowningDamage(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5121,7 +5536,7 @@ pub fn gamerule_drowningDamage(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule drowningDamage <value>")]
+#[command(usage = "gamerule drowningDamage <value>", level = 2)]
 pub fn gamerule_drowningDamage_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5134,7 +5549,7 @@ pub fn gamerule_drowningDamage_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule fallDamage")]
+#[command(usage = "gamerule fallDamage", level = 2)]
 pub fn gamerule_fallDamage(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5148,7 +5563,7 @@ pub fn gamerule_fallDamage(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule fallDamage <value>")]
+#[command(usage = "gamerule fallDamage <value>", level = 2)]
 pub fn gamerule_fallDamage_value(ctx: &mut CommandCtx, _value: BoolArgument) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5158,7 +5573,7 @@ pub fn gamerule_fallDamage_value(ctx: &mut CommandCtx, _value: BoolArgument) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule fireDamage")]
+#[command(usage = "gamerule fireDamage", level = 2)]
 pub fn gamerule_fireDamage(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5172,7 +5587,7 @@ pub fn gamerule_fireDamage(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule fireDamage <value>")]
+#[command(usage = "gamerule fireDamage <value>", level = 2)]
 pub fn gamerule_fireDamage_value(ctx: &mut CommandCtx, _value: BoolArgument) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5182,7 +5597,7 @@ pub fn gamerule_fireDamage_value(ctx: &mut CommandCtx, _value: BoolArgument) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule forgiveDeadPlayers")]
+#[command(usage = "gamerule forgiveDeadPlayers", level = 2)]
 pub fn gamerule_forgiveDeadPlayers(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5192,7 +5607,7 @@ pub fn gamerule_forgiveDeadPlayers(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule forgiveDeadPlayers <value>")]
+#[command(usage = "gamerule forgiveDeadPlayers <value>", level = 2)]
 pub fn gamerule_forgiveDeadPlayers_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5205,7 +5620,7 @@ pub fn gamerule_forgiveDeadPlayers_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule keepInventory")]
+#[command(usage = "gamerule keepInventory", level = 2)]
 pub fn gamerule_keepInventory(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5215,7 +5630,7 @@ pub fn gamerule_keepInventory(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule keepInventory <value>")]
+#[command(usage = "gamerule keepInventory <value>", level = 2)]
 pub fn gamerule_keepInventory_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5228,7 +5643,7 @@ pub fn gamerule_keepInventory_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule logAdminCommands")]
+#[command(usage = "gamerule logAdminCommands", level = 2)]
 pub fn gamerule_logAdminCommands(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5238,7 +5653,7 @@ pub fn gamerule_logAdminCommands(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule logAdminCommands <value>")]
+#[command(usage = "gamerule logAdminCommands <value>", level = 2)]
 pub fn gamerule_logAdminCommands_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5251,7 +5666,7 @@ pub fn gamerule_logAdminCommands_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule maxCommandChainLength")]
+#[command(usage = "gamerule maxCommandChainLength", level = 2)]
 pub fn gamerule_maxCommandChainLength(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5261,7 +5676,7 @@ pub fn gamerule_maxCommandChainLength(ctx: &mut CommandCtx) -> anyhow::Result<()
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule maxCommandChainLength <value>")]
+#[command(usage = "gamerule maxCommandChainLength <value>", level = 2)]
 pub fn gamerule_maxCommandChainLength_value(
     ctx: &mut CommandCtx,
     _value: IntegerArgument,
@@ -5274,7 +5689,7 @@ pub fn gamerule_maxCommandChainLength_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule maxEntityCramming")]
+#[command(usage = "gamerule maxEntityCramming", level = 2)]
 pub fn gamerule_maxEntityCramming(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5284,7 +5699,7 @@ pub fn gamerule_maxEntityCramming(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule maxEntityCramming <value>")]
+#[command(usage = "gamerule maxEntityCramming <value>", level = 2)]
 pub fn gamerule_maxEntityCramming_value(
     ctx: &mut CommandCtx,
     _value: IntegerArgument,
@@ -5297,7 +5712,7 @@ pub fn gamerule_maxEntityCramming_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule mobGriefing")]
+#[command(usage = "gamerule mobGriefing", level = 2)]
 pub fn gamerule_mobGriefing(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5311,7 +5726,7 @@ pub fn gamerule_mobGriefing(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule mobGriefing <value>")]
+#[command(usage = "gamerule mobGriefing <value>", level = 2)]
 pub fn gamerule_mobGriefing_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5324,7 +5739,7 @@ pub fn gamerule_mobGriefing_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule naturalRegeneration")]
+#[command(usage = "gamerule naturalRegeneration", level = 2)]
 pub fn gamerule_naturalRegeneration(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5334,7 +5749,7 @@ pub fn gamerule_naturalRegeneration(ctx: &mut CommandCtx) -> anyhow::Result<()>
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule naturalRegeneration <value>")]
+#[command(usage = "gamerule naturalRegeneration <value>", level = 2)]
 pub fn gamerule_naturalRegeneration_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5347,30 +5762,27 @@ pub fn gamerule_naturalRegeneration_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule randomTickSpeed")]
+#[command(usage = "gamerule randomTickSpeed", level = 2)]
 pub fn gamerule_randomTickSpeed(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"gamerule randomTickSpeed\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    Ok(Some(format!(
+        "Gamerule randomTickSpeed is currently set to: {}",
+        ctx.game.level.game_rules.random_tick_speed()
+    )))
 }
 
-#[command(usage = "gamerule randomTickSpeed <value>")]
+#[command(usage = "gamerule randomTickSpeed <value>", level = 2)]
 pub fn gamerule_randomTickSpeed_value(
     ctx: &mut CommandCtx,
-    _value: IntegerArgument,
+    value: IntegerArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"gamerule randomTickSpeed <value>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game.level.game_rules.set("randomTickSpeed", value.0);
+    Ok(Some(format!(
+        "Gamerule randomTickSpeed is now set to: {}",
+        value.0
+    )))
 }
 
-#[command(usage = "gamerule reducedDebugInfo")]
+#[command(usage = "gamerule reducedDebugInfo", level = 2)]
 pub fn gamerule_reducedDebugInfo(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5380,7 +5792,7 @@ pub fn gamerule_reducedDebugInfo(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule reducedDebugInfo <value>")]
+#[command(usage = "gamerule reducedDebugInfo <value>", level = 2)]
 pub fn gamerule_reducedDebugInfo_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5393,7 +5805,7 @@ pub fn gamerule_reducedDebugInfo_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule sendCommandFeedback")]
+#[command(usage = "gamerule sendCommandFeedback", level = 2)]
 pub fn gamerule_sendCommandFeedback(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5403,7 +5815,7 @@ pub fn gamerule_sendCommandFeedback(ctx: &mut CommandCtx) -> anyhow::Result<()>
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule sendCommandFeedback <value>")]
+#[command(usage = "gamerule sendCommandFeedback <value>", level = 2)]
 pub fn gamerule_sendCommandFeedback_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5416,7 +5828,7 @@ pub fn gamerule_sendCommandFeedback_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule showDeathMessages")]
+#[command(usage = "gamerule showDeathMessages", level = 2)]
 pub fn gamerule_showDeathMessages(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5426,7 +5838,7 @@ pub fn gamerule_showDeathMessages(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule showDeathMessages <value>")]
+#[command(usage = "gamerule showDeathMessages <value>", level = 2)]
 pub fn gamerule_showDeathMessages_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5439,7 +5851,7 @@ pub fn gamerule_showDeathMessages_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule spawnRadius")]
+#[command(usage = "gamerule spawnRadius", level = 2)]
 pub fn gamerule_spawnRadius(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5453,7 +5865,7 @@ pub fn gamerule_spawnRadius(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule spawnRadius <value>")]
+#[command(usage = "gamerule spawnRadius <value>", level = 2)]
 pub fn gamerule_spawnRadius_value(
     ctx: &mut CommandCtx,
     _value: IntegerArgument,
@@ -5466,7 +5878,7 @@ pub fn gamerule_spawnRadius_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule spectatorsGenerateChunks")]
+#[command(usage = "gamerule spectatorsGenerateChunks", level = 2)]
 pub fn gamerule_spectatorsGenerateChunks(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5476,7 +5888,7 @@ pub fn gamerule_spectatorsGenerateChunks(ctx: &mut CommandCtx) -> anyhow::Result
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule spectatorsGenerateChunks <value>")]
+#[command(usage = "gamerule spectatorsGenerateChunks <value>", level = 2)]
 pub fn gamerule_spectatorsGenerateChunks_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5489,7 +5901,7 @@ pub fn gamerule_spectatorsGenerateChunks_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule universalAnger")]
+#[command(usage = "gamerule universalAnger", level = 2)]
 pub fn gamerule_universalAnger(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5499,7 +5911,7 @@ pub fn gamerule_universalAnger(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "gamerule universalAnger <value>")]
+#[command(usage = "gamerule universalAnger <value>", level = 2)]
 pub fn gamerule_universalAnger_value(
     ctx: &mut CommandCtx,
     _value: BoolArgument,
@@ -5512,7 +5924,7 @@ pub fn gamerule_universalAnger_value(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "give <targets> <item>")]
+#[command(usage = "give <targets> <item>", level = 2)]
 pub fn give_targets_item(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -5530,7 +5942,7 @@ pub fn give_targets_item(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "give <targets> <item> <count>")]
+#[command(usage = "give <targets> <item> <count>", level = 2)]
 pub fn give_targets_item_count(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -5545,7 +5957,7 @@ pub fn give_targets_item_count(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "help")]
+#[command(usage = "help", level = 0)]
 pub fn help(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5558,7 +5970,7 @@ pub fn help(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "help <command>")]
+#[command(usage = "help <command>", level = 0)]
 pub fn help_command(ctx: &mut CommandCtx, _command: StringArgumentGreedy) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5572,7 +5984,7 @@ pub fn help_command(ctx: &mut CommandCtx, _command: StringArgumentGreedy) -> any
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "kick <targets>")]
+#[command(usage = "kick <targets>", level = 3)]
 pub fn kick_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5586,7 +5998,7 @@ pub fn kick_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "kick <targets> <reason>")]
+#[command(usage = "kick <targets> <reason>", level = 3)]
 pub fn kick_targets_reason(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -5600,7 +6012,7 @@ pub fn kick_targets_reason(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "kill")]
+#[command(usage = "kill", level = 2)]
 pub fn kill(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5613,7 +6025,7 @@ pub fn kill(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "kill <targets>")]
+#[command(usage = "kill <targets>", level = 2)]
 pub fn kill_targets(ctx: &mut CommandCtx, _targets: MultipleEntities) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5627,7 +6039,7 @@ pub fn kill_targets(ctx: &mut CommandCtx, _targets: MultipleEntities) -> anyhow:
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "list")]
+#[command(usage = "list", level = 0)]
 pub fn list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5640,7 +6052,7 @@ pub fn list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "list uuids")]
+#[command(usage = "list uuids", level = 0)]
 pub fn list_uuids(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5654,7 +6066,7 @@ pub fn list_uuids(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate bastion_remnant")]
+#[command(usage = "locate bastion_remnant", level = 2)]
 pub fn locate_bastion_remnant(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5664,7 +6076,7 @@ pub fn locate_bastion_remnant(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate buried_treasure")]
+#[command(usage = "locate buried_treasure", level = 2)]
 pub fn locate_buried_treasure(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5674,7 +6086,7 @@ pub fn locate_buried_treasure(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate desert_pyramid")]
+#[command(usage = "locate desert_pyramid", level = 2)]
 pub fn locate_desert_pyramid(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5688,7 +6100,7 @@ pub fn locate_desert_pyramid(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate endcity")]
+#[command(usage = "locate endcity", level = 2)]
 pub fn locate_endcity(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5702,7 +6114,7 @@ pub fn locate_endcity(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate fortress")]
+#[command(usage = "locate fortress", level = 2)]
 pub fn locate_fortress(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5716,7 +6128,7 @@ pub fn locate_fortress(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate igloo")]
+#[command(usage = "locate igloo", level = 2)]
 pub fn locate_igloo(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5730,7 +6142,7 @@ pub fn locate_igloo(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate jungle_pyramid")]
+#[command(usage = "locate jungle_pyramid", level = 2)]
 pub fn locate_jungle_pyramid(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5744,7 +6156,7 @@ pub fn locate_jungle_pyramid(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate mansion")]
+#[command(usage = "locate mansion", level = 2)]
 pub fn locate_mansion(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5758,7 +6170,7 @@ pub fn locate_mansion(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate mineshaft")]
+#[command(usage = "locate mineshaft", level = 2)]
 pub fn locate_mineshaft(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5772,7 +6184,7 @@ pub fn locate_mineshaft(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate monument")]
+#[command(usage = "locate monument", level = 2)]
 pub fn locate_monument(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5786,7 +6198,7 @@ pub fn locate_monument(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate nether_fossil")]
+#[command(usage = "locate nether_fossil", level = 2)]
 pub fn locate_nether_fossil(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5800,7 +6212,7 @@ pub fn locate_nether_fossil(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate ocean_ruin")]
+#[command(usage = "locate ocean_ruin", level = 2)]
 pub fn locate_ocean_ruin(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5814,7 +6226,7 @@ pub fn locate_ocean_ruin(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate pillager_outpost")]
+#[command(usage = "locate pillager_outpost", level = 2)]
 pub fn locate_pillager_outpost(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5824,7 +6236,7 @@ pub fn locate_pillager_outpost(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate ruined_portal")]
+#[command(usage = "locate ruined_portal", level = 2)]
 pub fn locate_ruined_portal(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5838,7 +6250,7 @@ pub fn locate_ruined_portal(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate shipwreck")]
+#[command(usage = "locate shipwreck", level = 2)]
 pub fn locate_shipwreck(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5852,7 +6264,7 @@ pub fn locate_shipwreck(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate stronghold")]
+#[command(usage = "locate stronghold", level = 2)]
 pub fn locate_stronghold(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5866,7 +6278,7 @@ pub fn locate_stronghold(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate swamp_hut")]
+#[command(usage = "locate swamp_hut", level = 2)]
 pub fn locate_swamp_hut(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5880,7 +6292,7 @@ pub fn locate_swamp_hut(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locate village")]
+#[command(usage = "locate village", level = 2)]
 pub fn locate_village(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5894,7 +6306,7 @@ pub fn locate_village(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "locatebiome <biome>")]
+#[command(usage = "locatebiome <biome>", level = 2)]
 pub fn locatebiome_biome(ctx: &mut CommandCtx, _biome: ResourceLocation) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -5908,7 +6320,7 @@ pub fn locatebiome_biome(ctx: &mut CommandCtx, _biome: ResourceLocation) -> anyh
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> fish <loot_table> <pos>")]
+#[command(usage = "loot give <players> fish <loot_table> <pos>", level = 2)]
 pub fn loot_give_players_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -5923,7 +6335,10 @@ pub fn loot_give_players_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot give <players> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_give_players_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -5938,7 +6353,10 @@ pub fn loot_give_players_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot give <players> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_give_players_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -5953,7 +6371,10 @@ pub fn loot_give_players_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot give <players> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_give_players_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -5969,7 +6390,7 @@ pub fn loot_give_players_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> kill <target>")]
+#[command(usage = "loot give <players> kill <target>", level = 2)]
 pub fn loot_give_players_kill_target(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -5983,7 +6404,7 @@ pub fn loot_give_players_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> loot <loot_table>")]
+#[command(usage = "loot give <players> loot <loot_table>", level = 2)]
 pub fn loot_give_players_loot_loot_table(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -5997,7 +6418,7 @@ pub fn loot_give_players_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> mine <pos>")]
+#[command(usage = "loot give <players> mine <pos>", level = 2)]
 pub fn loot_give_players_mine_pos(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -6011,7 +6432,7 @@ pub fn loot_give_players_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> mine <pos> mainhand")]
+#[command(usage = "loot give <players> mine <pos> mainhand", level = 2)]
 pub fn loot_give_players_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -6025,7 +6446,7 @@ pub fn loot_give_players_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> mine <pos> offhand")]
+#[command(usage = "loot give <players> mine <pos> offhand", level = 2)]
 pub fn loot_give_players_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -6039,7 +6460,7 @@ pub fn loot_give_players_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot give <players> mine <pos> <tool>")]
+#[command(usage = "loot give <players> mine <pos> <tool>", level = 2)]
 pub fn loot_give_players_mine_pos_tool(
     ctx: &mut CommandCtx,
     _players: MultiplePlayers,
@@ -6054,7 +6475,7 @@ pub fn loot_give_players_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> fish <loot_table> <pos>")]
+#[command(usage = "loot insert <targetPos> fish <loot_table> <pos>", level = 2)]
 pub fn loot_insert_targetPos_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6069,7 +6490,10 @@ pub fn loot_insert_targetPos_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot insert <targetPos> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_insert_targetPos_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6084,7 +6508,10 @@ pub fn loot_insert_targetPos_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot insert <targetPos> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_insert_targetPos_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6099,7 +6526,10 @@ pub fn loot_insert_targetPos_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot insert <targetPos> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_insert_targetPos_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6115,7 +6545,7 @@ pub fn loot_insert_targetPos_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> kill <target>")]
+#[command(usage = "loot insert <targetPos> kill <target>", level = 2)]
 pub fn loot_insert_targetPos_kill_target(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6129,7 +6559,7 @@ pub fn loot_insert_targetPos_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> loot <loot_table>")]
+#[command(usage = "loot insert <targetPos> loot <loot_table>", level = 2)]
 pub fn loot_insert_targetPos_loot_loot_table(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6143,7 +6573,7 @@ pub fn loot_insert_targetPos_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> mine <pos>")]
+#[command(usage = "loot insert <targetPos> mine <pos>", level = 2)]
 pub fn loot_insert_targetPos_mine_pos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6157,7 +6587,7 @@ pub fn loot_insert_targetPos_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> mine <pos> mainhand")]
+#[command(usage = "loot insert <targetPos> mine <pos> mainhand", level = 2)]
 pub fn loot_insert_targetPos_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6171,7 +6601,7 @@ pub fn loot_insert_targetPos_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> mine <pos> offhand")]
+#[command(usage = "loot insert <targetPos> mine <pos> offhand", level = 2)]
 pub fn loot_insert_targetPos_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6185,7 +6615,7 @@ pub fn loot_insert_targetPos_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot insert <targetPos> mine <pos> <tool>")]
+#[command(usage = "loot insert <targetPos> mine <pos> <tool>", level = 2)]
 pub fn loot_insert_targetPos_mine_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6200,7 +6630,10 @@ pub fn loot_insert_targetPos_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6216,7 +6649,10 @@ pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6232,7 +6668,10 @@ pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6248,7 +6687,10 @@ pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6265,7 +6707,10 @@ pub fn loot_replace_block_targetPos_slot_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> kill <target>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> kill <target>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_kill_target(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6280,7 +6725,10 @@ pub fn loot_replace_block_targetPos_slot_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> loot <loot_table>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> loot <loot_table>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_loot_loot_table(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6295,7 +6743,7 @@ pub fn loot_replace_block_targetPos_slot_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> mine <pos>")]
+#[command(usage = "loot replace block <targetPos> <slot> mine <pos>", level = 2)]
 pub fn loot_replace_block_targetPos_slot_mine_pos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6310,7 +6758,10 @@ pub fn loot_replace_block_targetPos_slot_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> mine <pos> mainhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> mine <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6325,7 +6776,10 @@ pub fn loot_replace_block_targetPos_slot_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> mine <pos> offhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> mine <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6340,7 +6794,10 @@ pub fn loot_replace_block_targetPos_slot_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> mine <pos> <tool>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> mine <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_mine_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6356,7 +6813,10 @@ pub fn loot_replace_block_targetPos_slot_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6373,7 +6833,10 @@ pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6390,7 +6853,10 @@ pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6407,7 +6873,10 @@ pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6425,7 +6894,10 @@ pub fn loot_replace_block_targetPos_slot_count_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> kill <target>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> kill <target>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_kill_target(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6441,7 +6913,10 @@ pub fn loot_replace_block_targetPos_slot_count_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> loot <loot_table>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> loot <loot_table>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_loot_loot_table(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6457,7 +6932,10 @@ pub fn loot_replace_block_targetPos_slot_count_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> mine <pos>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> mine <pos>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_mine_pos(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6473,7 +6951,10 @@ pub fn loot_replace_block_targetPos_slot_count_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> mine <pos> mainhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> mine <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6489,7 +6970,10 @@ pub fn loot_replace_block_targetPos_slot_count_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> mine <pos> offhand")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> mine <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6505,7 +6989,10 @@ pub fn loot_replace_block_targetPos_slot_count_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace block <targetPos> <slot> <count> mine <pos> <tool>")]
+#[command(
+    usage = "loot replace block <targetPos> <slot> <count> mine <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_block_targetPos_slot_count_mine_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Coordinates,
@@ -6522,7 +7009,10 @@ pub fn loot_replace_block_targetPos_slot_count_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> fish <loot_table> <pos>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> fish <loot_table> <pos>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6538,7 +7028,10 @@ pub fn loot_replace_entity_entities_slot_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6554,7 +7047,10 @@ pub fn loot_replace_entity_entities_slot_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6570,7 +7066,10 @@ pub fn loot_replace_entity_entities_slot_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6587,7 +7086,10 @@ pub fn loot_replace_entity_entities_slot_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> kill <target>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> kill <target>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_kill_target(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6602,7 +7104,10 @@ pub fn loot_replace_entity_entities_slot_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> loot <loot_table>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> loot <loot_table>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_loot_loot_table(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6617,7 +7122,7 @@ pub fn loot_replace_entity_entities_slot_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> mine <pos>")]
+#[command(usage = "loot replace entity <entities> <slot> mine <pos>", level = 2)]
 pub fn loot_replace_entity_entities_slot_mine_pos(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6632,7 +7137,10 @@ pub fn loot_replace_entity_entities_slot_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> mine <pos> mainhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> mine <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6647,7 +7155,10 @@ pub fn loot_replace_entity_entities_slot_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> mine <pos> offhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> mine <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6662,7 +7173,10 @@ pub fn loot_replace_entity_entities_slot_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> mine <pos> <tool>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> mine <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_mine_pos_tool(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6678,7 +7192,10 @@ pub fn loot_replace_entity_entities_slot_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6695,7 +7212,10 @@ pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6712,7 +7232,10 @@ pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6729,7 +7252,10 @@ pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6747,7 +7273,10 @@ pub fn loot_replace_entity_entities_slot_count_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> kill <target>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> kill <target>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_kill_target(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6763,7 +7292,10 @@ pub fn loot_replace_entity_entities_slot_count_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> loot <loot_table>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> loot <loot_table>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_loot_loot_table(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6779,7 +7311,10 @@ pub fn loot_replace_entity_entities_slot_count_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> mine <pos>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> mine <pos>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_mine_pos(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6795,7 +7330,10 @@ pub fn loot_replace_entity_entities_slot_count_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> mine <pos> mainhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> mine <pos> mainhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6811,7 +7349,10 @@ pub fn loot_replace_entity_entities_slot_count_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> mine <pos> offhand")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> mine <pos> offhand",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6827,7 +7368,10 @@ pub fn loot_replace_entity_entities_slot_count_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot replace entity <entities> <slot> <count> mine <pos> <tool>")]
+#[command(
+    usage = "loot replace entity <entities> <slot> <count> mine <pos> <tool>",
+    level = 2
+)]
 pub fn loot_replace_entity_entities_slot_count_mine_pos_tool(
     ctx: &mut CommandCtx,
     _entities: MultipleEntities,
@@ -6844,7 +7388,7 @@ pub fn loot_replace_entity_entities_slot_count_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> fish <loot_table> <pos>")]
+#[command(usage = "loot spawn <targetPos> fish <loot_table> <pos>", level = 2)]
 pub fn loot_spawn_targetPos_fish_loot_table_pos(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6859,7 +7403,10 @@ pub fn loot_spawn_targetPos_fish_loot_table_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> fish <loot_table> <pos> mainhand")]
+#[command(
+    usage = "loot spawn <targetPos> fish <loot_table> <pos> mainhand",
+    level = 2
+)]
 pub fn loot_spawn_targetPos_fish_loot_table_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6874,7 +7421,10 @@ pub fn loot_spawn_targetPos_fish_loot_table_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> fish <loot_table> <pos> offhand")]
+#[command(
+    usage = "loot spawn <targetPos> fish <loot_table> <pos> offhand",
+    level = 2
+)]
 pub fn loot_spawn_targetPos_fish_loot_table_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6889,7 +7439,10 @@ pub fn loot_spawn_targetPos_fish_loot_table_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> fish <loot_table> <pos> <tool>")]
+#[command(
+    usage = "loot spawn <targetPos> fish <loot_table> <pos> <tool>",
+    level = 2
+)]
 pub fn loot_spawn_targetPos_fish_loot_table_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6905,7 +7458,7 @@ pub fn loot_spawn_targetPos_fish_loot_table_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> kill <target>")]
+#[command(usage = "loot spawn <targetPos> kill <target>", level = 2)]
 pub fn loot_spawn_targetPos_kill_target(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6919,7 +7472,7 @@ pub fn loot_spawn_targetPos_kill_target(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> loot <loot_table>")]
+#[command(usage = "loot spawn <targetPos> loot <loot_table>", level = 2)]
 pub fn loot_spawn_targetPos_loot_loot_table(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6933,7 +7486,7 @@ pub fn loot_spawn_targetPos_loot_loot_table(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> mine <pos>")]
+#[command(usage = "loot spawn <targetPos> mine <pos>", level = 2)]
 pub fn loot_spawn_targetPos_mine_pos(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6947,7 +7500,7 @@ pub fn loot_spawn_targetPos_mine_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> mine <pos> mainhand")]
+#[command(usage = "loot spawn <targetPos> mine <pos> mainhand", level = 2)]
 pub fn loot_spawn_targetPos_mine_pos_mainhand(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6961,7 +7514,7 @@ pub fn loot_spawn_targetPos_mine_pos_mainhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> mine <pos> offhand")]
+#[command(usage = "loot spawn <targetPos> mine <pos> offhand", level = 2)]
 pub fn loot_spawn_targetPos_mine_pos_offhand(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6975,7 +7528,7 @@ pub fn loot_spawn_targetPos_mine_pos_offhand(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "loot spawn <targetPos> mine <pos> <tool>")]
+#[command(usage = "loot spawn <targetPos> mine <pos> <tool>", level = 2)]
 pub fn loot_spawn_targetPos_mine_pos_tool(
     ctx: &mut CommandCtx,
     _targetPos: Vec3,
@@ -6990,7 +7543,7 @@ pub fn loot_spawn_targetPos_mine_pos_tool(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "me <action>")]
+#[command(usage = "me <action>", level = 0)]
 pub fn me_action(ctx: &mut CommandCtx, _action: StringArgumentGreedy) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -7004,7 +7557,7 @@ pub fn me_action(ctx: &mut CommandCtx, _action: StringArgumentGreedy) -> anyhow:
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "msg <targets> <message>")]
+#[command(usage = "msg <targets> <message>", level = 0)]
 pub fn msg_targets_message(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -7018,49 +7571,64 @@ pub fn msg_targets_message(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "op <targets>")]
-pub fn op_targets(ctx: &mut CommandCtx, _targets: GameProfile) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"op <targets>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "op <targets>", level = 3)]
+pub fn op_targets(ctx: &mut CommandCtx, targets: GameProfile) -> anyhow::Result<()> {
+    let profiles = access::game_profiles(ctx, &targets.0)?;
+
+    let mut lines = vec![];
+    for profile in profiles {
+        let name = profile.name.clone();
+        let target = profile.uuid;
+        if ctx
+            .game
+            .access_lists
+            .write()
+            .op(profile, DEFAULT_OP_LEVEL)?
+        {
+            for player in access::online_players(&ctx.world, |uuid, _| uuid == target) {
+                permissions::set_operator_level(&ctx.world, player, DEFAULT_OP_LEVEL);
+            }
+            lines.push(format!("Made {} a server operator", name));
+        }
     }
-    Ok(Some("".to_string()))
+
+    if lines.is_empty() {
+        return Err(AccessCommandError::AlreadyAnOperator.into());
+    }
+    Ok(Some(lines.join("\n")))
 }
 
-#[command(usage = "pardon <targets>")]
-pub fn pardon_targets(ctx: &mut CommandCtx, _targets: GameProfile) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"pardon <targets>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "pardon <targets>", level = 3)]
+pub fn pardon_targets(ctx: &mut CommandCtx, targets: GameProfile) -> anyhow::Result<()> {
+    let profiles = access::game_profiles(ctx, &targets.0)?;
+
+    let mut lines = vec![];
+    for profile in profiles {
+        if ctx.game.access_lists.write().pardon(profile.uuid)? {
+            lines.push(format!("Unbanned {}", profile.name));
+        }
     }
-    Ok(Some("".to_string()))
+
+    if lines.is_empty() {
+        return Err(AccessCommandError::NotBanned.into());
+    }
+    Ok(Some(lines.join("\n")))
 }
 
-#[command(usage = "pardon-ip <target>")]
-pub fn pardon_ip_target(ctx: &mut CommandCtx, _target: StringArgumentWord) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"pardon-ip <target>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "pardon-ip <target>", level = 3)]
+pub fn pardon_ip_target(ctx: &mut CommandCtx, target: StringArgumentWord) -> anyhow::Result<()> {
+    let ip: IpAddr = target
+        .0
+        .parse()
+        .map_err(|_| AccessCommandError::InvalidIp)?;
+
+    if !ctx.game.access_lists.write().pardon_ip(ip)? {
+        return Err(AccessCommandError::IpNotBanned.into());
     }
-    Ok(Some("".to_string()))
+    Ok(Some(format!("Unbanned IP {}", ip)))
 }
 
-#[command(usage = "particle <name>")]
+#[command(usage = "particle <name>", level = 2)]
 pub fn particle_name(ctx: &mut CommandCtx, _name: Particle) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -7074,7 +7642,7 @@ pub fn particle_name(ctx: &mut CommandCtx, _name: Particle) -> anyhow::Result<()
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "particle <name> <pos>")]
+#[command(usage = "particle <name> <pos>", level = 2)]
 pub fn particle_name_pos(ctx: &mut CommandCtx, _name: Particle, _pos: Vec3) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -7088,7 +7656,7 @@ pub fn particle_name_pos(ctx: &mut CommandCtx, _name: Particle, _pos: Vec3) -> a
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "particle <name> <pos> <delta> <speed> <count>")]
+#[command(usage = "particle <name> <pos> <delta> <speed> <count>", level = 2)]
 pub fn particle_name_pos_delta_speed_count(
     ctx: &mut CommandCtx,
     _name: Particle,
@@ -7105,7 +7673,10 @@ pub fn particle_name_pos_delta_speed_count(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "particle <name> <pos> <delta> <speed> <count> force")]
+#[command(
+    usage = "particle <name> <pos> <delta> <speed> <count> force",
+    level = 2
+)]
 pub fn particle_name_pos_delta_speed_count_force(
     ctx: &mut CommandCtx,
     _name: Particle,
@@ -7122,7 +7693,10 @@ pub fn particle_name_pos_delta_speed_count_force(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "particle <name> <pos> <delta> <speed> <count> force <viewers>")]
+#[command(
+    usage = "particle <name> <pos> <delta> <speed> <count> force <viewers>",
+    level = 2
+)]
 pub fn particle_name_pos_delta_speed_count_force_viewers(
     ctx: &mut CommandCtx,
     _name: Particle,
@@ -7140,7 +7714,10 @@ pub fn particle_name_pos_delta_speed_count_force_viewers(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "particle <name> <pos> <delta> <speed> <count> normal")]
+#[command(
+    usage = "particle <name> <pos> <delta> <speed> <count> normal",
+    level = 2
+)]
 pub fn particle_name_pos_delta_speed_count_normal(
     ctx: &mut CommandCtx,
     _name: Particle,
@@ -7157,7 +7734,10 @@ pub fn particle_name_pos_delta_speed_count_normal(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "particle <name> <pos> <delta> <speed> <count> normal <viewers>")]
+#[command(
+    usage = "particle <name> <pos> <delta> <speed> <count> normal <viewers>",
+    level = 2
+)]
 pub fn particle_name_pos_delta_speed_count_normal_viewers(
     ctx: &mut CommandCtx,
     _name: Particle,
@@ -7175,7 +7755,7 @@ pub fn particle_name_pos_delta_speed_count_normal_viewers(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> ambient <targets>")]
+#[command(usage = "playsound <sound> ambient <targets>", level = 2)]
 pub fn playsound_sound_ambient_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7189,7 +7769,7 @@ pub fn playsound_sound_ambient_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> ambient <targets> <pos>")]
+#[command(usage = "playsound <sound> ambient <targets> <pos>", level = 2)]
 pub fn playsound_sound_ambient_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7204,7 +7784,10 @@ pub fn playsound_sound_ambient_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> ambient <targets> <pos> <volume>")]
+#[command(
+    usage = "playsound <sound> ambient <targets> <pos> <volume>",
+    level = 2
+)]
 pub fn playsound_sound_ambient_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7220,7 +7803,10 @@ pub fn playsound_sound_ambient_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> ambient <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> ambient <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_ambient_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7237,7 +7823,10 @@ pub fn playsound_sound_ambient_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> ambient <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> ambient <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_ambient_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7255,7 +7844,7 @@ pub fn playsound_sound_ambient_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> block <targets>")]
+#[command(usage = "playsound <sound> block <targets>", level = 2)]
 pub fn playsound_sound_block_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7269,7 +7858,7 @@ pub fn playsound_sound_block_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> block <targets> <pos>")]
+#[command(usage = "playsound <sound> block <targets> <pos>", level = 2)]
 pub fn playsound_sound_block_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7284,7 +7873,7 @@ pub fn playsound_sound_block_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> block <targets> <pos> <volume>")]
+#[command(usage = "playsound <sound> block <targets> <pos> <volume>", level = 2)]
 pub fn playsound_sound_block_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7300,7 +7889,10 @@ pub fn playsound_sound_block_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> block <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> block <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_block_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7317,7 +7909,10 @@ pub fn playsound_sound_block_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> block <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> block <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_block_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7335,7 +7930,7 @@ pub fn playsound_sound_block_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> hostile <targets>")]
+#[command(usage = "playsound <sound> hostile <targets>", level = 2)]
 pub fn playsound_sound_hostile_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7349,7 +7944,7 @@ pub fn playsound_sound_hostile_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> hostile <targets> <pos>")]
+#[command(usage = "playsound <sound> hostile <targets> <pos>", level = 2)]
 pub fn playsound_sound_hostile_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7364,7 +7959,10 @@ pub fn playsound_sound_hostile_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> hostile <targets> <pos> <volume>")]
+#[command(
+    usage = "playsound <sound> hostile <targets> <pos> <volume>",
+    level = 2
+)]
 pub fn playsound_sound_hostile_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7380,7 +7978,10 @@ pub fn playsound_sound_hostile_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> hostile <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> hostile <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_hostile_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7397,7 +7998,10 @@ pub fn playsound_sound_hostile_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> hostile <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> hostile <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_hostile_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7415,7 +8019,7 @@ pub fn playsound_sound_hostile_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> master <targets>")]
+#[command(usage = "playsound <sound> master <targets>", level = 2)]
 pub fn playsound_sound_master_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7429,7 +8033,7 @@ pub fn playsound_sound_master_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> master <targets> <pos>")]
+#[command(usage = "playsound <sound> master <targets> <pos>", level = 2)]
 pub fn playsound_sound_master_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7444,7 +8048,7 @@ pub fn playsound_sound_master_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> master <targets> <pos> <volume>")]
+#[command(usage = "playsound <sound> master <targets> <pos> <volume>", level = 2)]
 pub fn playsound_sound_master_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7460,7 +8064,10 @@ pub fn playsound_sound_master_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> master <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> master <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_master_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7477,7 +8084,10 @@ pub fn playsound_sound_master_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> master <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> master <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_master_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7495,7 +8105,7 @@ pub fn playsound_sound_master_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> music <targets>")]
+#[command(usage = "playsound <sound> music <targets>", level = 2)]
 pub fn playsound_sound_music_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7509,7 +8119,7 @@ pub fn playsound_sound_music_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> music <targets> <pos>")]
+#[command(usage = "playsound <sound> music <targets> <pos>", level = 2)]
 pub fn playsound_sound_music_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7524,7 +8134,7 @@ pub fn playsound_sound_music_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> music <targets> <pos> <volume>")]
+#[command(usage = "playsound <sound> music <targets> <pos> <volume>", level = 2)]
 pub fn playsound_sound_music_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7540,8 +8150,11 @@ pub fn playsound_sound_music_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> music <targets> <pos> <volume> <pitch>")]
-pub fn playsound_sound_music_targets_pos_volume_pitch(
+#[command(
+    usage = "playsound <sound> music <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
+pub fn playsound_sound_music_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
     _targets: MultiplePlayers,
@@ -7557,7 +8170,10 @@ pub fn playsound_sound_music_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> music <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> music <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_music_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7575,7 +8191,7 @@ pub fn playsound_sound_music_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> neutral <targets>")]
+#[command(usage = "playsound <sound> neutral <targets>", level = 2)]
 pub fn playsound_sound_neutral_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7589,7 +8205,7 @@ pub fn playsound_sound_neutral_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> neutral <targets> <pos>")]
+#[command(usage = "playsound <sound> neutral <targets> <pos>", level = 2)]
 pub fn playsound_sound_neutral_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7604,7 +8220,10 @@ pub fn playsound_sound_neutral_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> neutral <targets> <pos> <volume>")]
+#[command(
+    usage = "playsound <sound> neutral <targets> <pos> <volume>",
+    level = 2
+)]
 pub fn playsound_sound_neutral_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7620,7 +8239,10 @@ pub fn playsound_sound_neutral_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> neutral <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> neutral <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_neutral_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7637,7 +8259,10 @@ pub fn playsound_sound_neutral_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> neutral <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> neutral <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_neutral_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7655,7 +8280,7 @@ pub fn playsound_sound_neutral_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> player <targets>")]
+#[command(usage = "playsound <sound> player <targets>", level = 2)]
 pub fn playsound_sound_player_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7669,7 +8294,7 @@ pub fn playsound_sound_player_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> player <targets> <pos>")]
+#[command(usage = "playsound <sound> player <targets> <pos>", level = 2)]
 pub fn playsound_sound_player_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7684,7 +8309,7 @@ pub fn playsound_sound_player_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> player <targets> <pos> <volume>")]
+#[command(usage = "playsound <sound> player <targets> <pos> <volume>", level = 2)]
 pub fn playsound_sound_player_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7700,7 +8325,10 @@ pub fn playsound_sound_player_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> player <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> player <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_player_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7717,7 +8345,10 @@ pub fn playsound_sound_player_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> player <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> player <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_player_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7735,7 +8366,7 @@ pub fn playsound_sound_player_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> record <targets>")]
+#[command(usage = "playsound <sound> record <targets>", level = 2)]
 pub fn playsound_sound_record_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7749,7 +8380,7 @@ pub fn playsound_sound_record_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> record <targets> <pos>")]
+#[command(usage = "playsound <sound> record <targets> <pos>", level = 2)]
 pub fn playsound_sound_record_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7764,7 +8395,7 @@ pub fn playsound_sound_record_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> record <targets> <pos> <volume>")]
+#[command(usage = "playsound <sound> record <targets> <pos> <volume>", level = 2)]
 pub fn playsound_sound_record_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7780,7 +8411,10 @@ pub fn playsound_sound_record_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> record <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> record <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_record_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7797,7 +8431,10 @@ pub fn playsound_sound_record_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> record <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> record <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_record_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7815,7 +8452,7 @@ pub fn playsound_sound_record_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> voice <targets>")]
+#[command(usage = "playsound <sound> voice <targets>", level = 2)]
 pub fn playsound_sound_voice_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7829,7 +8466,7 @@ pub fn playsound_sound_voice_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> voice <targets> <pos>")]
+#[command(usage = "playsound <sound> voice <targets> <pos>", level = 2)]
 pub fn playsound_sound_voice_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7844,7 +8481,7 @@ pub fn playsound_sound_voice_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> voice <targets> <pos> <volume>")]
+#[command(usage = "playsound <sound> voice <targets> <pos> <volume>", level = 2)]
 pub fn playsound_sound_voice_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7860,7 +8497,10 @@ pub fn playsound_sound_voice_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> voice <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> voice <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_voice_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7877,7 +8517,10 @@ pub fn playsound_sound_voice_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> voice <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> voice <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_voice_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7895,7 +8538,7 @@ pub fn playsound_sound_voice_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> weather <targets>")]
+#[command(usage = "playsound <sound> weather <targets>", level = 2)]
 pub fn playsound_sound_weather_targets(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7909,7 +8552,7 @@ pub fn playsound_sound_weather_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> weather <targets> <pos>")]
+#[command(usage = "playsound <sound> weather <targets> <pos>", level = 2)]
 pub fn playsound_sound_weather_targets_pos(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7924,7 +8567,10 @@ pub fn playsound_sound_weather_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> weather <targets> <pos> <volume>")]
+#[command(
+    usage = "playsound <sound> weather <targets> <pos> <volume>",
+    level = 2
+)]
 pub fn playsound_sound_weather_targets_pos_volume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7940,7 +8586,10 @@ pub fn playsound_sound_weather_targets_pos_volume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> weather <targets> <pos> <volume> <pitch>")]
+#[command(
+    usage = "playsound <sound> weather <targets> <pos> <volume> <pitch>",
+    level = 2
+)]
 pub fn playsound_sound_weather_targets_pos_volume_pitch(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7957,7 +8606,10 @@ pub fn playsound_sound_weather_targets_pos_volume_pitch(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "playsound <sound> weather <targets> <pos> <volume> <pitch> <minVolume>")]
+#[command(
+    usage = "playsound <sound> weather <targets> <pos> <volume> <pitch> <minVolume>",
+    level = 2
+)]
 pub fn playsound_sound_weather_targets_pos_volume_pitch_minVolume(
     ctx: &mut CommandCtx,
     _sound: ResourceLocation,
@@ -7975,7 +8627,7 @@ pub fn playsound_sound_weather_targets_pos_volume_pitch_minVolume(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "publish")]
+#[command(usage = "publish", level = 4)]
 pub fn publish(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -7988,7 +8640,7 @@ pub fn publish(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "publish <port>")]
+#[command(usage = "publish <port>", level = 4)]
 pub fn publish_port(
     ctx: &mut CommandCtx,
     _port: IntegerArgumentBetween0And65535,
@@ -8019,7 +8671,7 @@ pub fn recipe_give_targets_star(
 }
 */
 
-#[command(usage = "recipe give <targets> <recipe>")]
+#[command(usage = "recipe give <targets> <recipe>", level = 2)]
 pub fn recipe_give_targets_recipe(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8047,7 +8699,7 @@ pub fn recipe_take_targets_star(
 }
 */
 
-#[command(usage = "recipe take <targets> <recipe>")]
+#[command(usage = "recipe take <targets> <recipe>", level = 2)]
 pub fn recipe_take_targets_recipe(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8061,7 +8713,7 @@ pub fn recipe_take_targets_recipe(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "reload")]
+#[command(usage = "reload", level = 2)]
 pub fn reload(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8074,7 +8726,7 @@ pub fn reload(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "replaceitem block <pos> <slot> <item>")]
+#[command(usage = "replaceitem block <pos> <slot> <item>", level = 2)]
 pub fn replaceitem_block_pos_slot_item(
     ctx: &mut CommandCtx,
     _pos: Coordinates,
@@ -8089,7 +8741,7 @@ pub fn replaceitem_block_pos_slot_item(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "replaceitem block <pos> <slot> <item> <count>")]
+#[command(usage = "replaceitem block <pos> <slot> <item> <count>", level = 2)]
 pub fn replaceitem_block_pos_slot_item_count(
     ctx: &mut CommandCtx,
     _pos: Coordinates,
@@ -8105,7 +8757,7 @@ pub fn replaceitem_block_pos_slot_item_count(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "replaceitem entity <targets> <slot> <item>")]
+#[command(usage = "replaceitem entity <targets> <slot> <item>", level = 2)]
 pub fn replaceitem_entity_targets_slot_item(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -8120,7 +8772,10 @@ pub fn replaceitem_entity_targets_slot_item(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "replaceitem entity <targets> <slot> <item> <count>")]
+#[command(
+    usage = "replaceitem entity <targets> <slot> <item> <count>",
+    level = 2
+)]
 pub fn replaceitem_entity_targets_slot_item_count(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -8136,7 +8791,7 @@ pub fn replaceitem_entity_targets_slot_item_count(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "save-all")]
+#[command(usage = "save-all", level = 4)]
 pub fn save_all(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8149,7 +8804,7 @@ pub fn save_all(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "save-all flush")]
+#[command(usage = "save-all flush", level = 4)]
 pub fn save_all_flush(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8163,7 +8818,7 @@ pub fn save_all_flush(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "save-off")]
+#[command(usage = "save-off", level = 4)]
 pub fn save_off(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8176,7 +8831,7 @@ pub fn save_off(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "save-on")]
+#[command(usage = "save-on", level = 4)]
 pub fn save_on(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8189,7 +8844,7 @@ pub fn save_on(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "say <message>")]
+#[command(usage = "say <message>", level = 2)]
 pub fn say_message(ctx: &mut CommandCtx, _message: Message) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8203,7 +8858,7 @@ pub fn say_message(ctx: &mut CommandCtx, _message: Message) -> anyhow::Result<()
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "schedule clear <function>")]
+#[command(usage = "schedule clear <function>", level = 2)]
 pub fn schedule_clear_function(
     ctx: &mut CommandCtx,
     _function: StringArgumentGreedy,
@@ -8216,7 +8871,7 @@ pub fn schedule_clear_function(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "schedule function <function> <time>")]
+#[command(usage = "schedule function <function> <time>", level = 2)]
 pub fn schedule_function_function_time(
     ctx: &mut CommandCtx,
     _function: MinecraftFunction,
@@ -8230,7 +8885,7 @@ pub fn schedule_function_function_time(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "schedule function <function> <time> append")]
+#[command(usage = "schedule function <function> <time> append", level = 2)]
 pub fn schedule_function_function_time_append(
     ctx: &mut CommandCtx,
     _function: MinecraftFunction,
@@ -8244,7 +8899,7 @@ pub fn schedule_function_function_time_append(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "schedule function <function> <time> replace")]
+#[command(usage = "schedule function <function> <time> replace", level = 2)]
 pub fn schedule_function_function_time_replace(
     ctx: &mut CommandCtx,
     _function: MinecraftFunction,
@@ -8258,268 +8913,451 @@ pub fn schedule_function_function_time_replace(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "scoreboard objectives add <objective> <criteria>")]
+fn run_add_objective(
+    ctx: &mut CommandCtx,
+    objective: StringArgumentWord,
+    criteria: ObjectiveCriteria,
+    display_name: Option<Text>,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .add_objective(&ctx.world, &objective.0, &criteria.0, display_name)?;
+    Ok(Some(format!("Created new objective [{}]", objective.0)))
+}
+
+fn run_set_render_type(
+    ctx: &mut CommandCtx,
+    objective: Objective,
+    render_type: ObjectiveRenderType,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .modify_objective(&ctx.world, &objective.0, |objective| {
+            objective.render_type = render_type
+        })?;
+    Ok(Some(format!(
+        "Changed the render type of objective [{}]",
+        objective.0
+    )))
+}
+
+/// Sets the scores of `targets` in `objective` to the result
+/// of `update`, which is passed the current score.
+///
+/// Returns the updated holders and the last new score.
+fn update_scores(
+    ctx: &mut CommandCtx,
+    targets: &MultipleScoreHolders,
+    objective: &Objective,
+    update: impl Fn(i32) -> i32,
+) -> anyhow::Result<(Vec<String>, i32)> {
+    let holders = scoreboard::score_holders(ctx, &targets.0, true)?;
+
+    let mut value = 0;
+    for holder in &holders {
+        let current = ctx
+            .game
+            .scoreboard
+            .score(holder, &objective.0)
+            .map(|score| score.value)
+            .unwrap_or_default();
+        value = update(current);
+        ctx.game
+            .scoreboard
+            .set_score(&ctx.world, holder, &objective.0, value)?;
+    }
+
+    Ok((holders, value))
+}
+
+/// Describes a list of score holders in a command feedback message.
+fn describe_holders(holders: &[String]) -> String {
+    match holders {
+        [holder] => holder.clone(),
+        _ => format!("{} entities", holders.len()),
+    }
+}
+
+#[command(usage = "scoreboard objectives add <objective> <criteria>", level = 2)]
 pub fn scoreboard_objectives_add_objective_criteria(
     ctx: &mut CommandCtx,
-    _objective: StringArgumentWord,
-    _criteria: ObjectiveCriteria,
+    objective: StringArgumentWord,
+    criteria: ObjectiveCriteria,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives add <objective> <criteria>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_add_objective(ctx, objective, criteria, None)
 }
 
-#[command(usage = "scoreboard objectives add <objective> <criteria> <displayName>")]
+#[command(
+    usage = "scoreboard objectives add <objective> <criteria> <displayName>",
+    level = 2
+)]
 pub fn scoreboard_objectives_add_objective_criteria_displayName(
     ctx: &mut CommandCtx,
-    _objective: StringArgumentWord,
-    _criteria: ObjectiveCriteria,
-    _displayName: Component,
+    objective: StringArgumentWord,
+    criteria: ObjectiveCriteria,
+    displayName: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives add <objective> <criteria> <displayName>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_add_objective(ctx, objective, criteria, Some(displayName.0))
 }
 
-#[command(usage = "scoreboard objectives list")]
+#[command(usage = "scoreboard objectives list", level = 2)]
 pub fn scoreboard_objectives_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives list\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let objectives: Vec<String> = ctx
+        .game
+        .scoreboard
+        .objectives()
+        .map(|objective| format!("[{}]", objective.name))
+        .collect();
+
+    if objectives.is_empty() {
+        Ok(Some("There are no objectives".to_string()))
+    } else {
+        Ok(Some(format!(
+            "There are {} objectives: {}",
+            objectives.len(),
+            objectives.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "scoreboard objectives modify <objective> displayname <displayName>")]
+#[command(
+    usage = "scoreboard objectives modify <objective> displayname <displayName>",
+    level = 2
+)]
 pub fn scoreboard_objectives_modify_objective_displayname_displayName(
     ctx: &mut CommandCtx,
-    _objective: Objective,
-    _displayName: Component,
+    objective: Objective,
+    displayName: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives modify <objective> displayname <displayName>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .modify_objective(&ctx.world, &objective.0, |objective| {
+            objective.display_name = displayName.0
+        })?;
+    Ok(Some(format!(
+        "Changed the display name of objective [{}]",
+        objective.0
+    )))
 }
 
-#[command(usage = "scoreboard objectives modify <objective> rendertype hearts")]
+#[command(
+    usage = "scoreboard objectives modify <objective> rendertype hearts",
+    level = 2
+)]
 pub fn scoreboard_objectives_modify_objective_rendertype_hearts(
     ctx: &mut CommandCtx,
-    _objective: Objective,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives modify <objective> rendertype hearts\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_render_type(ctx, objective, ObjectiveRenderType::Hearts)
 }
 
-#[command(usage = "scoreboard objectives modify <objective> rendertype integer")]
+#[command(
+    usage = "scoreboard objectives modify <objective> rendertype integer",
+    level = 2
+)]
 pub fn scoreboard_objectives_modify_objective_rendertype_integer(
     ctx: &mut CommandCtx,
-    _objective: Objective,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives modify <objective> rendertype integer\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_render_type(ctx, objective, ObjectiveRenderType::Integer)
 }
 
-#[command(usage = "scoreboard objectives remove <objective>")]
+#[command(usage = "scoreboard objectives remove <objective>", level = 2)]
 pub fn scoreboard_objectives_remove_objective(
     ctx: &mut CommandCtx,
-    _objective: Objective,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives remove <objective>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .remove_objective(&ctx.world, &objective.0)?;
+    Ok(Some(format!("Removed objective [{}]", objective.0)))
 }
 
-#[command(usage = "scoreboard objectives setdisplay <slot>")]
+#[command(usage = "scoreboard objectives setdisplay <slot>", level = 2)]
 pub fn scoreboard_objectives_setdisplay_slot(
     ctx: &mut CommandCtx,
-    _slot: ScoreboardSlot,
+    slot: ScoreboardSlot,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives setdisplay <slot>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .set_display_slot(&ctx.world, slot.0, None)?;
+    Ok(Some(format!(
+        "Cleared any objectives in display slot {}",
+        slot.0
+    )))
 }
 
-#[command(usage = "scoreboard objectives setdisplay <slot> <objective>")]
+#[command(
+    usage = "scoreboard objectives setdisplay <slot> <objective>",
+    level = 2
+)]
 pub fn scoreboard_objectives_setdisplay_slot_objective(
     ctx: &mut CommandCtx,
-    _slot: ScoreboardSlot,
-    _objective: Objective,
+    slot: ScoreboardSlot,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard objectives setdisplay <slot> <objective>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .set_display_slot(&ctx.world, slot.0, Some(&objective.0))?;
+    Ok(Some(format!(
+        "Set display slot {} to show objective [{}]",
+        slot.0, objective.0
+    )))
 }
 
-#[command(usage = "scoreboard players add <targets> <objective> <score>")]
+#[command(
+    usage = "scoreboard players add <targets> <objective> <score>",
+    level = 2
+)]
 pub fn scoreboard_players_add_targets_objective_score(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
-    _objective: Objective,
-    _score: IntegerArgumentPositive,
+    targets: MultipleScoreHolders,
+    objective: Objective,
+    score: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players add <targets> <objective> <score>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let amount = scoreboard::score_value(score.0)?;
+    let (holders, value) = update_scores(ctx, &targets, &objective, |score| {
+        score.wrapping_add(amount)
+    })?;
+
+    let mut message = format!(
+        "Added {} to [{}] for {}",
+        amount,
+        objective.0,
+        describe_holders(&holders)
+    );
+    if holders.len() == 1 {
+        message += &format!(" (now {})", value);
     }
-    Ok(Some("".to_string()))
+    Ok(Some(message))
 }
 
-#[command(usage = "scoreboard players enable <targets> <objective>")]
+#[command(usage = "scoreboard players enable <targets> <objective>", level = 2)]
 pub fn scoreboard_players_enable_targets_objective(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
-    _objective: Objective,
+    targets: MultipleScoreHolders,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players enable <targets> <objective>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let holders = scoreboard::score_holders(ctx, &targets.0, true)?;
+    for holder in &holders {
+        ctx.game
+            .scoreboard
+            .enable_trigger(&ctx.world, holder, &objective.0)?;
     }
-    Ok(Some("".to_string()))
+
+    Ok(Some(format!(
+        "Enabled trigger [{}] for {}",
+        objective.0,
+        describe_holders(&holders)
+    )))
 }
 
-#[command(usage = "scoreboard players get <target> <objective>")]
+#[command(usage = "scoreboard players get <target> <objective>", level = 2)]
 pub fn scoreboard_players_get_target_objective(
     ctx: &mut CommandCtx,
-    _target: SingleScoreHolder,
-    _objective: Objective,
+    target: SingleScoreHolder,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players get <target> <objective>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let holder = scoreboard::single_score_holder(ctx, &target.0)?;
+    if ctx.game.scoreboard.objective(&objective.0).is_none() {
+        return Err(ScoreboardError::UnknownObjective(objective.0).into());
+    }
+
+    match ctx.game.scoreboard.score(&holder, &objective.0) {
+        Some(score) => Ok(Some(format!(
+            "{} has {} [{}]",
+            holder, score.value, objective.0
+        ))),
+        None => Err(ScoreboardError::NoScore {
+            holder,
+            objective: objective.0,
+        }
+        .into()),
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "scoreboard players list")]
+#[command(usage = "scoreboard players list", level = 2)]
 pub fn scoreboard_players_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players list\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let holders: Vec<&str> = ctx.game.scoreboard.holders().collect();
+
+    if holders.is_empty() {
+        Ok(Some("There are no tracked entities".to_string()))
+    } else {
+        Ok(Some(format!(
+            "There are {} tracked entities: {}",
+            holders.len(),
+            holders.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "scoreboard players list <target>")]
+#[command(usage = "scoreboard players list <target>", level = 2)]
 pub fn scoreboard_players_list_target(
     ctx: &mut CommandCtx,
-    _target: SingleScoreHolder,
+    target: SingleScoreHolder,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players list <target>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let holder = scoreboard::single_score_holder(ctx, &target.0)?;
+    let scores: Vec<String> = ctx
+        .game
+        .scoreboard
+        .scores_of(&holder)
+        .map(|(objective, score)| format!("\n[{}]: {}", objective, score.value))
+        .collect();
+
+    if scores.is_empty() {
+        Ok(Some(format!("{} has no scores", holder)))
+    } else {
+        Ok(Some(format!(
+            "{} has {} scores:{}",
+            holder,
+            scores.len(),
+            scores.concat()
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
 #[command(
-    usage = "scoreboard players operation <targets> <targetObjective> <operation> <source> <sourceObjective>"
+    usage = "scoreboard players operation <targets> <targetObjective> <operation> <source> <sourceObjective>",
+    level = 2
 )]
 pub fn scoreboard_players_operation_targets_targetObjective_operation_source_sourceObjective(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
-    _targetObjective: Objective,
-    _operation: Operation,
-    _source: MultipleScoreHolders,
-    _sourceObjective: Objective,
-) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players operation <targets> <targetObjective> <operation> <source> <sourceObjective>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    targets: MultipleScoreHolders,
+    targetObjective: Objective,
+    operation: Operation,
+    source: MultipleScoreHolders,
+    sourceObjective: Objective,
+) -> anyhow::Result<()> {
+    let targets = scoreboard::score_holders(ctx, &targets.0, true)?;
+    let sources = scoreboard::score_holders(ctx, &source.0, true)?;
+
+    let score = |ctx: &CommandCtx, holder: &str, objective: &str| {
+        ctx.game
+            .scoreboard
+            .score(holder, objective)
+            .map(|score| score.value)
+            .unwrap_or_default()
+    };
+
+    let mut value = 0;
+    for target in &targets {
+        for source in &sources {
+            let mut a = score(ctx, target, &targetObjective.0);
+            let mut b = score(ctx, source, &sourceObjective.0);
+            operation.apply(&mut a, &mut b);
+
+            ctx.game
+                .scoreboard
+                .set_score(&ctx.world, target, &targetObjective.0, a)?;
+            if operation == Operation::Swap {
+                ctx.game
+                    .scoreboard
+                    .set_score(&ctx.world, source, &sourceObjective.0, b)?;
+            }
+            value = a;
+        }
     }
-    Ok(Some("".to_string()))
+
+    let mut message = format!(
+        "Set [{}] for {}",
+        targetObjective.0,
+        describe_holders(&targets)
+    );
+    if targets.len() == 1 {
+        message += &format!(" to {}", value);
+    }
+    Ok(Some(message))
 }
 
-#[command(usage = "scoreboard players remove <targets> <objective> <score>")]
+#[command(
+    usage = "scoreboard players remove <targets> <objective> <score>",
+    level = 2
+)]
 pub fn scoreboard_players_remove_targets_objective_score(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
-    _objective: Objective,
-    _score: IntegerArgumentPositive,
+    targets: MultipleScoreHolders,
+    objective: Objective,
+    score: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players remove <targets> <objective> <score>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let amount = scoreboard::score_value(score.0)?;
+    let (holders, value) = update_scores(ctx, &targets, &objective, |score| {
+        score.wrapping_sub(amount)
+    })?;
+
+    let mut message = format!(
+        "Removed {} from [{}] for {}",
+        amount,
+        objective.0,
+        describe_holders(&holders)
+    );
+    if holders.len() == 1 {
+        message += &format!(" (now {})", value);
     }
-    Ok(Some("".to_string()))
+    Ok(Some(message))
 }
 
-#[command(usage = "scoreboard players reset <targets>")]
+#[command(usage = "scoreboard players reset <targets>", level = 2)]
 pub fn scoreboard_players_reset_targets(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
+    targets: MultipleScoreHolders,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players reset <targets>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let holders = scoreboard::score_holders(ctx, &targets.0, true)?;
+    for holder in &holders {
+        ctx.game.scoreboard.reset_score(&ctx.world, holder, None);
     }
-    Ok(Some("".to_string()))
+
+    Ok(Some(format!(
+        "Reset scores for {}",
+        describe_holders(&holders)
+    )))
 }
 
-#[command(usage = "scoreboard players reset <targets> <objective>")]
+#[command(usage = "scoreboard players reset <targets> <objective>", level = 2)]
 pub fn scoreboard_players_reset_targets_objective(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
-    _objective: Objective,
+    targets: MultipleScoreHolders,
+    objective: Objective,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players reset <targets> <objective>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let holders = scoreboard::score_holders(ctx, &targets.0, true)?;
+    if ctx.game.scoreboard.objective(&objective.0).is_none() {
+        return Err(ScoreboardError::UnknownObjective(objective.0).into());
     }
-    Ok(Some("".to_string()))
+
+    for holder in &holders {
+        ctx.game
+            .scoreboard
+            .reset_score(&ctx.world, holder, Some(&objective.0));
+    }
+
+    Ok(Some(format!(
+        "Reset [{}] for {}",
+        objective.0,
+        describe_holders(&holders)
+    )))
 }
 
-#[command(usage = "scoreboard players set <targets> <objective> <score>")]
+#[command(
+    usage = "scoreboard players set <targets> <objective> <score>",
+    level = 2
+)]
 pub fn scoreboard_players_set_targets_objective_score(
     ctx: &mut CommandCtx,
-    _targets: MultipleScoreHolders,
-    _objective: Objective,
-    _score: IntegerArgument,
+    targets: MultipleScoreHolders,
+    objective: Objective,
+    score: IntegerArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"scoreboard players set <targets> <objective> <score>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let value = scoreboard::score_value(score.0)?;
+    let (holders, _) = update_scores(ctx, &targets, &objective, |_| value)?;
+
+    Ok(Some(format!(
+        "Set [{}] for {} to {}",
+        objective.0,
+        describe_holders(&holders),
+        value
+    )))
 }
 
-#[command(usage = "seed")]
+#[command(usage = "seed", level = 2)]
 pub fn seed(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8532,63 +9370,43 @@ pub fn seed(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "setblock <pos> <block>")]
+#[command(usage = "setblock <pos> <block>", level = 2)]
 pub fn setblock_pos_block(
     ctx: &mut CommandCtx,
-    _pos: Coordinates,
-    _block: BlockState,
+    pos: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"setblock <pos> <block>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_setblock(ctx, pos, block, SetBlockMode::Replace)
 }
 
-#[command(usage = "setblock <pos> <block> destroy")]
+#[command(usage = "setblock <pos> <block> destroy", level = 2)]
 pub fn setblock_pos_block_destroy(
     ctx: &mut CommandCtx,
-    _pos: Coordinates,
-    _block: BlockState,
+    pos: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"setblock <pos> <block> destroy\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_setblock(ctx, pos, block, SetBlockMode::Destroy)
 }
 
-#[command(usage = "setblock <pos> <block> keep")]
+#[command(usage = "setblock <pos> <block> keep", level = 2)]
 pub fn setblock_pos_block_keep(
     ctx: &mut CommandCtx,
-    _pos: Coordinates,
-    _block: BlockState,
+    pos: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"setblock <pos> <block> keep\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_setblock(ctx, pos, block, SetBlockMode::Keep)
 }
 
-#[command(usage = "setblock <pos> <block> replace")]
+#[command(usage = "setblock <pos> <block> replace", level = 2)]
 pub fn setblock_pos_block_replace(
     ctx: &mut CommandCtx,
-    _pos: Coordinates,
-    _block: BlockState,
+    pos: Coordinates,
+    block: BlockState,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"setblock <pos> <block> replace\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_setblock(ctx, pos, block, SetBlockMode::Replace)
 }
 
-#[command(usage = "setidletimeout <minutes>")]
+#[command(usage = "setidletimeout <minutes>", level = 3)]
 pub fn setidletimeout_minutes(
     ctx: &mut CommandCtx,
     _minutes: IntegerArgumentPositive,
@@ -8601,7 +9419,7 @@ pub fn setidletimeout_minutes(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "setworldspawn")]
+#[command(usage = "setworldspawn", level = 2)]
 pub fn setworldspawn(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8615,7 +9433,7 @@ pub fn setworldspawn(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "setworldspawn <pos>")]
+#[command(usage = "setworldspawn <pos>", level = 2)]
 pub fn setworldspawn_pos(ctx: &mut CommandCtx, _pos: Coordinates) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8629,7 +9447,7 @@ pub fn setworldspawn_pos(ctx: &mut CommandCtx, _pos: Coordinates) -> anyhow::Res
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spawnpoint")]
+#[command(usage = "spawnpoint", level = 2)]
 pub fn spawnpoint(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8643,7 +9461,7 @@ pub fn spawnpoint(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spawnpoint <targets>")]
+#[command(usage = "spawnpoint <targets>", level = 2)]
 pub fn spawnpoint_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8657,7 +9475,7 @@ pub fn spawnpoint_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> an
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spawnpoint <targets> <pos>")]
+#[command(usage = "spawnpoint <targets> <pos>", level = 2)]
 pub fn spawnpoint_targets_pos(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8671,7 +9489,7 @@ pub fn spawnpoint_targets_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spectate")]
+#[command(usage = "spectate", level = 2)]
 pub fn spectate(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8684,7 +9502,7 @@ pub fn spectate(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spectate <target>")]
+#[command(usage = "spectate <target>", level = 2)]
 pub fn spectate_target(ctx: &mut CommandCtx, _target: SingleEntities) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8698,7 +9516,7 @@ pub fn spectate_target(ctx: &mut CommandCtx, _target: SingleEntities) -> anyhow:
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spectate <target> <player>")]
+#[command(usage = "spectate <target> <player>", level = 2)]
 pub fn spectate_target_player(
     ctx: &mut CommandCtx,
     _target: SingleEntities,
@@ -8713,7 +9531,8 @@ pub fn spectate_target_player(
 }
 
 #[command(
-    usage = "spreadplayers <center> <spreadDistance> <maxRange> under <maxHeight> <respectTeams> <targets>"
+    usage = "spreadplayers <center> <spreadDistance> <maxRange> under <maxHeight> <respectTeams> <targets>",
+    level = 2
 )]
 pub fn spreadplayers_center_spreadDistance_maxRange_under_maxHeight_respectTeams_targets(
     ctx: &mut CommandCtx,
@@ -8732,7 +9551,10 @@ pub fn spreadplayers_center_spreadDistance_maxRange_under_maxHeight_respectTeams
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "spreadplayers <center> <spreadDistance> <maxRange> <respectTeams> <targets>")]
+#[command(
+    usage = "spreadplayers <center> <spreadDistance> <maxRange> <respectTeams> <targets>",
+    level = 2
+)]
 pub fn spreadplayers_center_spreadDistance_maxRange_respectTeams_targets(
     ctx: &mut CommandCtx,
     _center: Vec2,
@@ -8749,7 +9571,7 @@ pub fn spreadplayers_center_spreadDistance_maxRange_respectTeams_targets(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stop")]
+#[command(usage = "stop", level = 4)]
 pub fn stop(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8762,7 +9584,7 @@ pub fn stop(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets>")]
+#[command(usage = "stopsound <targets>", level = 2)]
 pub fn stopsound_targets(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -8791,7 +9613,7 @@ pub fn stopsound_targets_star_sound(
 }
 */
 
-#[command(usage = "stopsound <targets> ambient")]
+#[command(usage = "stopsound <targets> ambient", level = 2)]
 pub fn stopsound_targets_ambient(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8804,7 +9626,7 @@ pub fn stopsound_targets_ambient(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> ambient <sound>")]
+#[command(usage = "stopsound <targets> ambient <sound>", level = 2)]
 pub fn stopsound_targets_ambient_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8818,7 +9640,7 @@ pub fn stopsound_targets_ambient_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> block")]
+#[command(usage = "stopsound <targets> block", level = 2)]
 pub fn stopsound_targets_block(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8831,7 +9653,7 @@ pub fn stopsound_targets_block(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> block <sound>")]
+#[command(usage = "stopsound <targets> block <sound>", level = 2)]
 pub fn stopsound_targets_block_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8845,7 +9667,7 @@ pub fn stopsound_targets_block_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> hostile")]
+#[command(usage = "stopsound <targets> hostile", level = 2)]
 pub fn stopsound_targets_hostile(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8858,7 +9680,7 @@ pub fn stopsound_targets_hostile(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> hostile <sound>")]
+#[command(usage = "stopsound <targets> hostile <sound>", level = 2)]
 pub fn stopsound_targets_hostile_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8872,7 +9694,7 @@ pub fn stopsound_targets_hostile_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> master")]
+#[command(usage = "stopsound <targets> master", level = 2)]
 pub fn stopsound_targets_master(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8885,7 +9707,7 @@ pub fn stopsound_targets_master(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> master <sound>")]
+#[command(usage = "stopsound <targets> master <sound>", level = 2)]
 pub fn stopsound_targets_master_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8899,7 +9721,7 @@ pub fn stopsound_targets_master_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> music")]
+#[command(usage = "stopsound <targets> music", level = 2)]
 pub fn stopsound_targets_music(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8912,7 +9734,7 @@ pub fn stopsound_targets_music(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> music <sound>")]
+#[command(usage = "stopsound <targets> music <sound>", level = 2)]
 pub fn stopsound_targets_music_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8926,7 +9748,7 @@ pub fn stopsound_targets_music_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> neutral")]
+#[command(usage = "stopsound <targets> neutral", level = 2)]
 pub fn stopsound_targets_neutral(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8939,7 +9761,7 @@ pub fn stopsound_targets_neutral(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> neutral <sound>")]
+#[command(usage = "stopsound <targets> neutral <sound>", level = 2)]
 pub fn stopsound_targets_neutral_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8953,7 +9775,7 @@ pub fn stopsound_targets_neutral_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> player")]
+#[command(usage = "stopsound <targets> player", level = 2)]
 pub fn stopsound_targets_player(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8966,7 +9788,7 @@ pub fn stopsound_targets_player(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> player <sound>")]
+#[command(usage = "stopsound <targets> player <sound>", level = 2)]
 pub fn stopsound_targets_player_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8980,7 +9802,7 @@ pub fn stopsound_targets_player_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> record")]
+#[command(usage = "stopsound <targets> record", level = 2)]
 pub fn stopsound_targets_record(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -8993,7 +9815,7 @@ pub fn stopsound_targets_record(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> record <sound>")]
+#[command(usage = "stopsound <targets> record <sound>", level = 2)]
 pub fn stopsound_targets_record_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9007,7 +9829,7 @@ pub fn stopsound_targets_record_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> voice")]
+#[command(usage = "stopsound <targets> voice", level = 2)]
 pub fn stopsound_targets_voice(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9020,7 +9842,7 @@ pub fn stopsound_targets_voice(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> voice <sound>")]
+#[command(usage = "stopsound <targets> voice <sound>", level = 2)]
 pub fn stopsound_targets_voice_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9034,7 +9856,7 @@ pub fn stopsound_targets_voice_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> weather")]
+#[command(usage = "stopsound <targets> weather", level = 2)]
 pub fn stopsound_targets_weather(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9047,7 +9869,7 @@ pub fn stopsound_targets_weather(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "stopsound <targets> weather <sound>")]
+#[command(usage = "stopsound <targets> weather <sound>", level = 2)]
 pub fn stopsound_targets_weather_sound(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9061,7 +9883,7 @@ pub fn stopsound_targets_weather_sound(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "summon <entity>")]
+#[command(usage = "summon <entity>", level = 2)]
 pub fn summon_entity(ctx: &mut CommandCtx, _entity: EntitySummon) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9075,7 +9897,7 @@ pub fn summon_entity(ctx: &mut CommandCtx, _entity: EntitySummon) -> anyhow::Res
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "summon <entity> <pos>")]
+#[command(usage = "summon <entity> <pos>", level = 2)]
 pub fn summon_entity_pos(
     ctx: &mut CommandCtx,
     _entity: EntitySummon,
@@ -9093,7 +9915,7 @@ pub fn summon_entity_pos(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "summon <entity> <pos> <nbt>")]
+#[command(usage = "summon <entity> <pos> <nbt>", level = 2)]
 pub fn summon_entity_pos_nbt(
     ctx: &mut CommandCtx,
     _entity: EntitySummon,
@@ -9108,7 +9930,7 @@ pub fn summon_entity_pos_nbt(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tag <targets> add <name>")]
+#[command(usage = "tag <targets> add <name>", level = 2)]
 pub fn tag_targets_add_name(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9122,7 +9944,7 @@ pub fn tag_targets_add_name(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tag <targets> list")]
+#[command(usage = "tag <targets> list", level = 2)]
 pub fn tag_targets_list(ctx: &mut CommandCtx, _targets: MultipleEntities) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9136,7 +9958,7 @@ pub fn tag_targets_list(ctx: &mut CommandCtx, _targets: MultipleEntities) -> any
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tag <targets> remove <name>")]
+#[command(usage = "tag <targets> remove <name>", level = 2)]
 pub fn tag_targets_remove_name(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9150,389 +9972,428 @@ pub fn tag_targets_remove_name(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "team add <team>")]
-pub fn team_add_team(ctx: &mut CommandCtx, _team: StringArgumentWord) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"team add <team>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+fn run_add_team(
+    ctx: &mut CommandCtx,
+    team: StringArgumentWord,
+    display_name: Option<Text>,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .add_team(&ctx.world, &team.0, display_name)?;
+    Ok(Some(format!("Created team [{}]", team.0)))
 }
 
-#[command(usage = "team add <team> <displayName>")]
-pub fn team_add_team_displayName(
+fn run_join_team(
     ctx: &mut CommandCtx,
-    _team: StringArgumentWord,
-    _displayName: Component,
-) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team add <team> <displayName>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    team: Team,
+    members: Vec<String>,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .join_team(&ctx.world, &team.0, &members)?;
+    Ok(Some(match members.as_slice() {
+        [member] => format!("Added {} to team [{}]", member, team.0),
+        _ => format!("Added {} members to team [{}]", members.len(), team.0),
+    }))
 }
 
-#[command(usage = "team empty <team>")]
-pub fn team_empty_team(ctx: &mut CommandCtx, _team: Team) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"team empty <team>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+fn run_set_collision_rule(
+    ctx: &mut CommandCtx,
+    team: Team,
+    rule: CollisionRule,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| team.collision_rule = rule)?;
+    Ok(Some(format!(
+        "Collision rule for team [{}] is now \"{}\"",
+        team.0,
+        rule.as_str()
+    )))
 }
 
-#[command(usage = "team join <team>")]
-pub fn team_join_team(ctx: &mut CommandCtx, _team: Team) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"team join <team>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+fn run_set_death_message_visibility(
+    ctx: &mut CommandCtx,
+    team: Team,
+    visibility: Visibility,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| {
+            team.death_message_visibility = visibility
+        })?;
+    Ok(Some(format!(
+        "Death message visibility for team [{}] is now \"{}\"",
+        team.0,
+        visibility.as_str()
+    )))
 }
 
-#[command(usage = "team join <team> <members>")]
+fn run_set_nametag_visibility(
+    ctx: &mut CommandCtx,
+    team: Team,
+    visibility: Visibility,
+) -> anyhow::Result<Option<String>> {
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| {
+            team.name_tag_visibility = visibility
+        })?;
+    Ok(Some(format!(
+        "Nametag visibility for team [{}] is now \"{}\"",
+        team.0,
+        visibility.as_str()
+    )))
+}
+
+#[command(usage = "team add <team>", level = 2)]
+pub fn team_add_team(ctx: &mut CommandCtx, team: StringArgumentWord) -> anyhow::Result<()> {
+    run_add_team(ctx, team, None)
+}
+
+#[command(usage = "team add <team> <displayName>", level = 2)]
+pub fn team_add_team_displayName(
+    ctx: &mut CommandCtx,
+    team: StringArgumentWord,
+    displayName: Component,
+) -> anyhow::Result<()> {
+    run_add_team(ctx, team, Some(displayName.0))
+}
+
+#[command(usage = "team empty <team>", level = 2)]
+pub fn team_empty_team(ctx: &mut CommandCtx, team: Team) -> anyhow::Result<()> {
+    let count = ctx.game.scoreboard.empty_team(&ctx.world, &team.0)?;
+    Ok(Some(format!(
+        "Removed {} members from team [{}]",
+        count, team.0
+    )))
+}
+
+#[command(usage = "team join <team>", level = 2)]
+pub fn team_join_team(ctx: &mut CommandCtx, team: Team) -> anyhow::Result<()> {
+    let member =
+        scoreboard::holder_name(&ctx.world, ctx.sender).ok_or(ScoreboardCommandError::NoneFound)?;
+    run_join_team(ctx, team, vec![member])
+}
+
+#[command(usage = "team join <team> <members>", level = 2)]
 pub fn team_join_team_members(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _members: MultipleScoreHolders,
+    team: Team,
+    members: MultipleScoreHolders,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team join <team> <members>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let members = scoreboard::score_holders(ctx, &members.0, true)?;
+    run_join_team(ctx, team, members)
 }
 
-#[command(usage = "team leave <members>")]
+#[command(usage = "team leave <members>", level = 2)]
 pub fn team_leave_members(
     ctx: &mut CommandCtx,
-    _members: MultipleScoreHolders,
+    members: MultipleScoreHolders,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"team leave <members>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let members = scoreboard::score_holders(ctx, &members.0, true)?;
+    ctx.game.scoreboard.leave_team(&ctx.world, &members);
+
+    Ok(Some(match members.as_slice() {
+        [member] => format!("Removed {} from any team", member),
+        _ => format!("Removed {} members from any team", members.len()),
+    }))
 }
 
-#[command(usage = "team list")]
+#[command(usage = "team list", level = 2)]
 pub fn team_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text =
-            Text::from("This command \"team list\" is not implemented in this version of feather.")
-                .gray()
-                .italic();
-        sender_message_receiver.send(return_text);
+    let teams: Vec<String> = ctx
+        .game
+        .scoreboard
+        .teams()
+        .map(|team| format!("[{}]", team.name))
+        .collect();
+
+    if teams.is_empty() {
+        Ok(Some("There are no teams".to_string()))
+    } else {
+        Ok(Some(format!(
+            "There are {} teams: {}",
+            teams.len(),
+            teams.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "team list <team>")]
-pub fn team_list_team(ctx: &mut CommandCtx, _team: Team) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"team list <team>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "team list <team>", level = 2)]
+pub fn team_list_team(ctx: &mut CommandCtx, team: Team) -> anyhow::Result<()> {
+    let members: Vec<&str> = ctx
+        .game
+        .scoreboard
+        .team(&team.0)
+        .ok_or_else(|| ScoreboardError::UnknownTeam(team.0.clone()))?
+        .members()
+        .collect();
+
+    if members.is_empty() {
+        Ok(Some(format!("There are no members on team [{}]", team.0)))
+    } else {
+        Ok(Some(format!(
+            "Team [{}] has {} members: {}",
+            team.0,
+            members.len(),
+            members.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "team modify <team> collisionRule always")]
+#[command(usage = "team modify <team> collisionRule always", level = 2)]
 pub fn team_modify_team_collisionRule_always(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> collisionRule always\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_collision_rule(ctx, team, CollisionRule::Always)
 }
 
-#[command(usage = "team modify <team> collisionRule never")]
+#[command(usage = "team modify <team> collisionRule never", level = 2)]
 pub fn team_modify_team_collisionRule_never(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> collisionRule never\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_collision_rule(ctx, team, CollisionRule::Never)
 }
 
-#[command(usage = "team modify <team> collisionRule pushOtherTeams")]
+#[command(usage = "team modify <team> collisionRule pushOtherTeams", level = 2)]
 pub fn team_modify_team_collisionRule_pushOtherTeams(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> collisionRule pushOtherTeams\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_collision_rule(ctx, team, CollisionRule::PushOtherTeams)
 }
 
-#[command(usage = "team modify <team> collisionRule pushOwnTeam")]
+#[command(usage = "team modify <team> collisionRule pushOwnTeam", level = 2)]
 pub fn team_modify_team_collisionRule_pushOwnTeam(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> collisionRule pushOwnTeam\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_collision_rule(ctx, team, CollisionRule::PushOwnTeam)
 }
 
-#[command(usage = "team modify <team> color <value>")]
+#[command(usage = "team modify <team> color <value>", level = 2)]
 pub fn team_modify_team_color_value(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _value: Color,
+    team: Team,
+    value: Color,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> color <value>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let color = value.to_text_color();
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| team.color = color)?;
+    Ok(Some(format!("Updated the color for team [{}]", team.0)))
 }
 
-#[command(usage = "team modify <team> deathMessageVisibility always")]
+#[command(usage = "team modify <team> deathMessageVisibility always", level = 2)]
 pub fn team_modify_team_deathMessageVisibility_always(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> deathMessageVisibility always\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_death_message_visibility(ctx, team, Visibility::Always)
 }
 
-#[command(usage = "team modify <team> deathMessageVisibility hideForOtherTeams")]
+#[command(
+    usage = "team modify <team> deathMessageVisibility hideForOtherTeams",
+    level = 2
+)]
 pub fn team_modify_team_deathMessageVisibility_hideForOtherTeams(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> deathMessageVisibility hideForOtherTeams\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_death_message_visibility(ctx, team, Visibility::HideForOtherTeams)
 }
 
-#[command(usage = "team modify <team> deathMessageVisibility hideForOwnTeam")]
+#[command(
+    usage = "team modify <team> deathMessageVisibility hideForOwnTeam",
+    level = 2
+)]
 pub fn team_modify_team_deathMessageVisibility_hideForOwnTeam(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> deathMessageVisibility hideForOwnTeam\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_death_message_visibility(ctx, team, Visibility::HideForOwnTeam)
 }
 
-#[command(usage = "team modify <team> deathMessageVisibility never")]
+#[command(usage = "team modify <team> deathMessageVisibility never", level = 2)]
 pub fn team_modify_team_deathMessageVisibility_never(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> deathMessageVisibility never\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_death_message_visibility(ctx, team, Visibility::Never)
 }
 
-#[command(usage = "team modify <team> displayName <displayName>")]
+#[command(usage = "team modify <team> displayName <displayName>", level = 2)]
 pub fn team_modify_team_displayName_displayName(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _displayName: Component,
+    team: Team,
+    displayName: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> displayName <displayName>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| {
+            team.display_name = displayName.0
+        })?;
+    Ok(Some(format!("Updated the name of team [{}]", team.0)))
 }
 
-#[command(usage = "team modify <team> friendlyFire <allowed>")]
+#[command(usage = "team modify <team> friendlyFire <allowed>", level = 2)]
 pub fn team_modify_team_friendlyFire_allowed(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _allowed: BoolArgument,
+    team: Team,
+    allowed: BoolArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> friendlyFire <allowed>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| team.friendly_fire = allowed.0)?;
+    Ok(Some(format!(
+        "{} friendly fire for team [{}]",
+        if allowed.0 { "Enabled" } else { "Disabled" },
+        team.0
+    )))
 }
 
-#[command(usage = "team modify <team> nametagVisibility always")]
+#[command(usage = "team modify <team> nametagVisibility always", level = 2)]
 pub fn team_modify_team_nametagVisibility_always(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> nametagVisibility always\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_nametag_visibility(ctx, team, Visibility::Always)
 }
 
-#[command(usage = "team modify <team> nametagVisibility hideForOtherTeams")]
+#[command(
+    usage = "team modify <team> nametagVisibility hideForOtherTeams",
+    level = 2
+)]
 pub fn team_modify_team_nametagVisibility_hideForOtherTeams(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> nametagVisibility hideForOtherTeams\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_nametag_visibility(ctx, team, Visibility::HideForOtherTeams)
 }
 
-#[command(usage = "team modify <team> nametagVisibility hideForOwnTeam")]
+#[command(
+    usage = "team modify <team> nametagVisibility hideForOwnTeam",
+    level = 2
+)]
 pub fn team_modify_team_nametagVisibility_hideForOwnTeam(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> nametagVisibility hideForOwnTeam\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_nametag_visibility(ctx, team, Visibility::HideForOwnTeam)
 }
 
-#[command(usage = "team modify <team> nametagVisibility never")]
+#[command(usage = "team modify <team> nametagVisibility never", level = 2)]
 pub fn team_modify_team_nametagVisibility_never(
     ctx: &mut CommandCtx,
-    _team: Team,
+    team: Team,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> nametagVisibility never\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_nametag_visibility(ctx, team, Visibility::Never)
 }
 
-#[command(usage = "team modify <team> prefix <prefix>")]
+#[command(usage = "team modify <team> prefix <prefix>", level = 2)]
 pub fn team_modify_team_prefix_prefix(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _prefix: Component,
+    team: Team,
+    prefix: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> prefix <prefix>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| team.prefix = prefix.0)?;
+    Ok(Some(format!("Updated the prefix of team [{}]", team.0)))
 }
 
-#[command(usage = "team modify <team> seeFriendlyInvisibles <allowed>")]
+#[command(
+    usage = "team modify <team> seeFriendlyInvisibles <allowed>",
+    level = 2
+)]
 pub fn team_modify_team_seeFriendlyInvisibles_allowed(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _allowed: BoolArgument,
+    team: Team,
+    allowed: BoolArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> seeFriendlyInvisibles <allowed>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| {
+            team.see_friendly_invisibles = allowed.0
+        })?;
+    Ok(Some(format!(
+        "Team [{}] can {} see invisible teammates",
+        team.0,
+        if allowed.0 { "now" } else { "no longer" }
+    )))
 }
 
-#[command(usage = "team modify <team> suffix <suffix>")]
+#[command(usage = "team modify <team> suffix <suffix>", level = 2)]
 pub fn team_modify_team_suffix_suffix(
     ctx: &mut CommandCtx,
-    _team: Team,
-    _suffix: Component,
+    team: Team,
+    suffix: Component,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"team modify <team> suffix <suffix>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    ctx.game
+        .scoreboard
+        .modify_team(&ctx.world, &team.0, |team| team.suffix = suffix.0)?;
+    Ok(Some(format!("Updated the suffix of team [{}]", team.0)))
+}
+
+#[command(usage = "team remove <team>", level = 2)]
+pub fn team_remove_team(ctx: &mut CommandCtx, team: Team) -> anyhow::Result<()> {
+    ctx.game.scoreboard.remove_team(&ctx.world, &team.0)?;
+    Ok(Some(format!("Removed team [{}]", team.0)))
+}
+
+/// Sends a message to all players on the sender's team.
+fn run_team_message(ctx: &mut CommandCtx, message: Message) -> anyhow::Result<Option<String>> {
+    let sender = scoreboard::player_sender(ctx)?;
+    let team = ctx
+        .game
+        .scoreboard
+        .team_of(&sender)
+        .ok_or(ScoreboardCommandError::NoTeam)?;
+
+    let mut team_name =
+        Text::translate_with("chat.square_brackets", vec![team.display_name.clone()]);
+    if let Some(color) = team.color.clone() {
+        team_name = team_name.color(color);
+    }
+    let members: Vec<String> = team.members().map(ToOwned::to_owned).collect();
+
+    let recipients: Vec<(Entity, bool)> = <Read<Name>>::query()
+        .filter(component::<Player>())
+        .iter_entities(ctx.world.inner())
+        .filter(|(_, name)| members.contains(&name.0))
+        .map(|(entity, name)| (entity, name.0 == sender))
+        .collect();
+
+    for (recipient, is_sender) in recipients {
+        if let Some(mut receiver) = ctx.world.try_get_mut::<MessageReceiver>(recipient) {
+            let key = if is_sender {
+                "chat.type.team.sent"
+            } else {
+                "chat.type.team.text"
+            };
+            receiver.send(Text::translate_with(
+                key,
+                vec![
+                    team_name.clone(),
+                    Text::from(sender.clone()),
+                    Text::from(message.0.clone()),
+                ],
+            ));
+        }
     }
-    Ok(Some("".to_string()))
-}
 
-#[command(usage = "team remove <team>")]
-pub fn team_remove_team(ctx: &mut CommandCtx, _team: Team) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"team remove <team>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    Ok(None)
 }
 
-#[command(usage = "teammsg <message>")]
-pub fn teammsg_message(ctx: &mut CommandCtx, _message: Message) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"teammsg <message>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "teammsg <message>", level = 0)]
+pub fn teammsg_message(ctx: &mut CommandCtx, message: Message) -> anyhow::Result<()> {
+    run_team_message(ctx, message)
 }
 
-#[command(usage = "teleport <destination>")]
+#[command(usage = "teleport <destination>", level = 2)]
 pub fn teleport_destination(
     ctx: &mut CommandCtx,
     _destination: SingleEntities,
@@ -9545,7 +10406,7 @@ pub fn teleport_destination(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <location>")]
+#[command(usage = "teleport <location>", level = 2)]
 pub fn teleport_location(ctx: &mut CommandCtx, _location: Vec3) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9559,7 +10420,7 @@ pub fn teleport_location(ctx: &mut CommandCtx, _location: Vec3) -> anyhow::Resul
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <targets> <destination>")]
+#[command(usage = "teleport <targets> <destination>", level = 2)]
 pub fn teleport_targets_destination(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9573,7 +10434,7 @@ pub fn teleport_targets_destination(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <targets> <location>")]
+#[command(usage = "teleport <targets> <location>", level = 2)]
 pub fn teleport_targets_location(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9587,7 +10448,10 @@ pub fn teleport_targets_location(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <targets> <location> facing entity <facingEntity>")]
+#[command(
+    usage = "teleport <targets> <location> facing entity <facingEntity>",
+    level = 2
+)]
 pub fn teleport_targets_location_facing_entity_facingEntity(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9602,7 +10466,10 @@ pub fn teleport_targets_location_facing_entity_facingEntity(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <targets> <location> facing entity <facingEntity> <facingAnchor>")]
+#[command(
+    usage = "teleport <targets> <location> facing entity <facingEntity> <facingAnchor>",
+    level = 2
+)]
 pub fn teleport_targets_location_facing_entity_facingEntity_facingAnchor(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9618,7 +10485,10 @@ pub fn teleport_targets_location_facing_entity_facingEntity_facingAnchor(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <targets> <location> facing <facingLocation>")]
+#[command(
+    usage = "teleport <targets> <location> facing <facingLocation>",
+    level = 2
+)]
 pub fn teleport_targets_location_facing_facingLocation(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9633,7 +10503,7 @@ pub fn teleport_targets_location_facing_facingLocation(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "teleport <targets> <location> <rotation>")]
+#[command(usage = "teleport <targets> <location> <rotation>", level = 2)]
 pub fn teleport_targets_location_rotation(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9648,7 +10518,7 @@ pub fn teleport_targets_location_rotation(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tell <targets> <message>")]
+#[command(usage = "tell <targets> <message>", level = 0)]
 pub fn tell_targets_message(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9662,7 +10532,7 @@ pub fn tell_targets_message(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tellraw <targets> <message>")]
+#[command(usage = "tellraw <targets> <message>", level = 2)]
 pub fn tellraw_targets_message(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9676,7 +10546,7 @@ pub fn tellraw_targets_message(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time add <time>")]
+#[command(usage = "time add <time>", level = 2)]
 pub fn time_add_time(ctx: &mut CommandCtx, _time: Time) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9690,7 +10560,7 @@ pub fn time_add_time(ctx: &mut CommandCtx, _time: Time) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time query day")]
+#[command(usage = "time query day", level = 2)]
 pub fn time_query_day(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9704,7 +10574,7 @@ pub fn time_query_day(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time query daytime")]
+#[command(usage = "time query daytime", level = 2)]
 pub fn time_query_daytime(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9718,7 +10588,7 @@ pub fn time_query_daytime(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time query gametime")]
+#[command(usage = "time query gametime", level = 2)]
 pub fn time_query_gametime(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9732,7 +10602,7 @@ pub fn time_query_gametime(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time set day")]
+#[command(usage = "time set day", level = 2)]
 pub fn time_set_day(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9746,7 +10616,7 @@ pub fn time_set_day(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time set midnight")]
+#[command(usage = "time set midnight", level = 2)]
 pub fn time_set_midnight(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9760,7 +10630,7 @@ pub fn time_set_midnight(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time set night")]
+#[command(usage = "time set night", level = 2)]
 pub fn time_set_night(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9774,7 +10644,7 @@ pub fn time_set_night(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time set noon")]
+#[command(usage = "time set noon", level = 2)]
 pub fn time_set_noon(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9788,7 +10658,7 @@ pub fn time_set_noon(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "time set <time>")]
+#[command(usage = "time set <time>", level = 2)]
 pub fn time_set_time(ctx: &mut CommandCtx, _time: Time) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9802,7 +10672,7 @@ pub fn time_set_time(ctx: &mut CommandCtx, _time: Time) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "title <targets> actionbar <title>")]
+#[command(usage = "title <targets> actionbar <title>", level = 2)]
 pub fn title_targets_actionbar_title(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9816,7 +10686,7 @@ pub fn title_targets_actionbar_title(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "title <targets> clear")]
+#[command(usage = "title <targets> clear", level = 2)]
 pub fn title_targets_clear(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9830,7 +10700,7 @@ pub fn title_targets_clear(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> a
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "title <targets> reset")]
+#[command(usage = "title <targets> reset", level = 2)]
 pub fn title_targets_reset(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9844,7 +10714,7 @@ pub fn title_targets_reset(ctx: &mut CommandCtx, _targets: MultiplePlayers) -> a
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "title <targets> subtitle <title>")]
+#[command(usage = "title <targets> subtitle <title>", level = 2)]
 pub fn title_targets_subtitle_title(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9858,7 +10728,7 @@ pub fn title_targets_subtitle_title(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "title <targets> times <fadeIn> <stay> <fadeOut>")]
+#[command(usage = "title <targets> times <fadeIn> <stay> <fadeOut>", level = 2)]
 pub fn title_targets_times_fadeIn_stay_fadeOut(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9874,7 +10744,7 @@ pub fn title_targets_times_fadeIn_stay_fadeOut(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "title <targets> title <title>")]
+#[command(usage = "title <targets> title <title>", level = 2)]
 pub fn title_targets_title_title(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -9888,21 +10758,12 @@ pub fn title_targets_title_title(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tm <message>")]
-pub fn tm_message(ctx: &mut CommandCtx, _message: Message) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"tm <message>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+#[command(usage = "tm <message>", level = 0)]
+pub fn tm_message(ctx: &mut CommandCtx, message: Message) -> anyhow::Result<()> {
+    run_team_message(ctx, message)
 }
 
-#[command(usage = "tp <destination>")]
+#[command(usage = "tp <destination>", level = 2)]
 pub fn tp_destination(ctx: &mut CommandCtx, _destination: SingleEntities) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9916,7 +10777,7 @@ pub fn tp_destination(ctx: &mut CommandCtx, _destination: SingleEntities) -> any
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <location>")]
+#[command(usage = "tp <location>", level = 2)]
 pub fn tp_location(ctx: &mut CommandCtx, _location: Vec3) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -9930,7 +10791,7 @@ pub fn tp_location(ctx: &mut CommandCtx, _location: Vec3) -> anyhow::Result<()>
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <targets> <destination>")]
+#[command(usage = "tp <targets> <destination>", level = 2)]
 pub fn tp_targets_destination(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9944,7 +10805,7 @@ pub fn tp_targets_destination(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <targets> <location>")]
+#[command(usage = "tp <targets> <location>", level = 2)]
 pub fn tp_targets_location(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9958,7 +10819,10 @@ pub fn tp_targets_location(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <targets> <location> facing entity <facingEntity>")]
+#[command(
+    usage = "tp <targets> <location> facing entity <facingEntity>",
+    level = 2
+)]
 pub fn tp_targets_location_facing_entity_facingEntity(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9973,7 +10837,10 @@ pub fn tp_targets_location_facing_entity_facingEntity(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <targets> <location> facing entity <facingEntity> <facingAnchor>")]
+#[command(
+    usage = "tp <targets> <location> facing entity <facingEntity> <facingAnchor>",
+    level = 2
+)]
 pub fn tp_targets_location_facing_entity_facingEntity_facingAnchor(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -9989,7 +10856,7 @@ pub fn tp_targets_location_facing_entity_facingEntity_facingAnchor(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <targets> <location> facing <facingLocation>")]
+#[command(usage = "tp <targets> <location> facing <facingLocation>", level = 2)]
 pub fn tp_targets_location_facing_facingLocation(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -10004,7 +10871,7 @@ pub fn tp_targets_location_facing_facingLocation(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "tp <targets> <location> <rotation>")]
+#[command(usage = "tp <targets> <location> <rotation>", level = 2)]
 pub fn tp_targets_location_rotation(
     ctx: &mut CommandCtx,
     _targets: MultipleEntities,
@@ -10019,46 +10886,53 @@ pub fn tp_targets_location_rotation(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "trigger <objective>")]
-pub fn trigger_objective(ctx: &mut CommandCtx, _objective: Objective) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"trigger <objective>\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+/// Changes the sender's score in a trigger objective,
+/// returning the new score.
+fn run_trigger(
+    ctx: &mut CommandCtx,
+    objective: &Objective,
+    update: impl FnOnce(i32) -> i32,
+) -> anyhow::Result<i32> {
+    let sender = scoreboard::player_sender(ctx)?;
+    let value = ctx
+        .game
+        .scoreboard
+        .trigger(&ctx.world, &sender, &objective.0, update)?;
+    Ok(value)
 }
 
-#[command(usage = "trigger <objective> add <value>")]
+#[command(usage = "trigger <objective>", level = 0)]
+pub fn trigger_objective(ctx: &mut CommandCtx, objective: Objective) -> anyhow::Result<()> {
+    run_trigger(ctx, &objective, |value| value.wrapping_add(1))?;
+    Ok(Some(format!("Triggered [{}]", objective.0)))
+}
+
+#[command(usage = "trigger <objective> add <value>", level = 0)]
 pub fn trigger_objective_add_value(
     ctx: &mut CommandCtx,
-    _objective: Objective,
-    _value: IntegerArgument,
+    objective: Objective,
+    value: IntegerArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"trigger <objective> add <value>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let amount = scoreboard::score_value(value.0)?;
+    run_trigger(ctx, &objective, |value| value.wrapping_add(amount))?;
+    Ok(Some(format!(
+        "Triggered [{}] (added {} to value)",
+        objective.0, amount
+    )))
 }
 
-#[command(usage = "trigger <objective> set <value>")]
+#[command(usage = "trigger <objective> set <value>", level = 0)]
 pub fn trigger_objective_set_value(
     ctx: &mut CommandCtx,
-    _objective: Objective,
-    _value: IntegerArgument,
+    objective: Objective,
+    value: IntegerArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"trigger <objective> set <value>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let value = scoreboard::score_value(value.0)?;
+    run_trigger(ctx, &objective, |_| value)?;
+    Ok(Some(format!(
+        "Triggered [{}] (set value to {})",
+        objective.0, value
+    )))
 }
 /*
 #[command(usage="w <targets> <message>")]
@@ -10076,7 +10950,7 @@ pub fn w_targets_message(
 }
 */
 
-#[command(usage = "weather clear")]
+#[command(usage = "weather clear", level = 2)]
 pub fn weather_clear(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -10090,7 +10964,7 @@ pub fn weather_clear(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "weather clear <duration>")]
+#[command(usage = "weather clear <duration>", level = 2)]
 pub fn weather_clear_duration(
     ctx: &mut CommandCtx,
     _duration: IntegerArgumentBetween0And1000000,
@@ -10103,7 +10977,7 @@ pub fn weather_clear_duration(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "weather rain")]
+#[command(usage = "weather rain", level = 2)]
 pub fn weather_rain(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -10117,7 +10991,7 @@ pub fn weather_rain(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "weather rain <duration>")]
+#[command(usage = "weather rain <duration>", level = 2)]
 pub fn weather_rain_duration(
     ctx: &mut CommandCtx,
     _duration: IntegerArgumentBetween0And1000000,
@@ -10130,7 +11004,7 @@ pub fn weather_rain_duration(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "weather thunder")]
+#[command(usage = "weather thunder", level = 2)]
 pub fn weather_thunder(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -10144,7 +11018,7 @@ pub fn weather_thunder(ctx: &mut CommandCtx) -> anyhow::Result<()> {
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "weather thunder <duration>")]
+#[command(usage = "weather thunder <duration>", level = 2)]
 pub fn weather_thunder_duration(
     ctx: &mut CommandCtx,
     _duration: IntegerArgumentBetween0And1000000,
@@ -10157,213 +11031,294 @@ pub fn weather_thunder_duration(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "whitelist add <targets>")]
-pub fn whitelist_add_targets(ctx: &mut CommandCtx, _targets: GameProfile) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"whitelist add <targets>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "whitelist add <targets>", level = 3)]
+pub fn whitelist_add_targets(ctx: &mut CommandCtx, targets: GameProfile) -> anyhow::Result<()> {
+    let profiles = access::game_profiles(ctx, &targets.0)?;
+
+    let mut lines = vec![];
+    for profile in profiles {
+        let name = profile.name.clone();
+        if ctx.game.access_lists.write().add_to_whitelist(profile)? {
+            lines.push(format!("Added {} to the whitelist", name));
+        }
     }
-    Ok(Some("".to_string()))
+
+    if lines.is_empty() {
+        return Err(AccessCommandError::AlreadyWhitelisted.into());
+    }
+    Ok(Some(lines.join("\n")))
 }
 
-#[command(usage = "whitelist list")]
+#[command(usage = "whitelist list", level = 3)]
 pub fn whitelist_list(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"whitelist list\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+    let names: Vec<String> = ctx
+        .game
+        .access_lists
+        .read()
+        .whitelist()
+        .map(|profile| profile.name.clone())
+        .collect();
+
+    if names.is_empty() {
+        Ok(Some("There are no whitelisted players".to_string()))
+    } else {
+        Ok(Some(format!(
+            "There are {} whitelisted players: {}",
+            names.len(),
+            names.join(", ")
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "whitelist off")]
+#[command(usage = "whitelist off", level = 3)]
 pub fn whitelist_off(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"whitelist off\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+    if !ctx.game.access_lists.write().set_whitelist_enabled(false) {
+        return Err(AccessCommandError::WhitelistAlreadyOff.into());
     }
-    Ok(Some("".to_string()))
+    Ok(Some("Whitelist is now turned off".to_string()))
 }
 
-#[command(usage = "whitelist on")]
+#[command(usage = "whitelist on", level = 3)]
 pub fn whitelist_on(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"whitelist on\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+    if !ctx.game.access_lists.write().set_whitelist_enabled(true) {
+        return Err(AccessCommandError::WhitelistAlreadyOn.into());
     }
-    Ok(Some("".to_string()))
+    Ok(Some("Whitelist is now turned on".to_string()))
 }
 
-#[command(usage = "whitelist reload")]
+#[command(usage = "whitelist reload", level = 3)]
 pub fn whitelist_reload(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"whitelist reload\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
+    ctx.game.access_lists.write().reload_whitelist()?;
+    Ok(Some("Reloaded the whitelist".to_string()))
+}
+
+#[command(usage = "whitelist remove <targets>", level = 3)]
+pub fn whitelist_remove_targets(ctx: &mut CommandCtx, targets: GameProfile) -> anyhow::Result<()> {
+    let profiles = access::game_profiles(ctx, &targets.0)?;
+
+    let mut lines = vec![];
+    for profile in profiles {
+        if ctx
+            .game
+            .access_lists
+            .write()
+            .remove_from_whitelist(profile.uuid)?
+        {
+            lines.push(format!("Removed {} from the whitelist", profile.name));
+        }
     }
-    Ok(Some("".to_string()))
-}
 
-#[command(usage = "whitelist remove <targets>")]
-pub fn whitelist_remove_targets(ctx: &mut CommandCtx, _targets: GameProfile) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"whitelist remove <targets>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    if lines.is_empty() {
+        return Err(AccessCommandError::NotWhitelisted.into());
     }
-    Ok(Some("".to_string()))
+    Ok(Some(lines.join("\n")))
 }
 
-#[command(usage = "worldborder add <distance>")]
+#[derive(Debug, Error)]
+pub enum WorldBorderCommandError {
+    #[error("Nothing changed. The world border is already that size")]
+    SizeUnchanged,
+    #[error("World border cannot be smaller than 1 block wide")]
+    TooSmall,
+    #[error("World border cannot be bigger than 60,000,000 blocks wide")]
+    TooBig,
+    #[error("The world border cannot take that long to move")]
+    TimeTooLong,
+    #[error("Nothing changed. The world border is already centered there")]
+    CenterUnchanged,
+    #[error("The world border center is too far out")]
+    CenterTooFar,
+    #[error("Nothing changed. The world border damage is already that amount")]
+    DamageUnchanged,
+    #[error("Nothing changed. The world border damage buffer is already that distance")]
+    BufferUnchanged,
+    #[error("Nothing changed. The world border warning is already that distance")]
+    WarningDistanceUnchanged,
+    #[error("Nothing changed. The world border warning is already that amount of time")]
+    WarningTimeUnchanged,
+}
+
+#[command(usage = "worldborder add <distance>", level = 2)]
 pub fn worldborder_add_distance(
     ctx: &mut CommandCtx,
-    _distance: FloatArgument,
+    distance: FloatArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder add <distance>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let diameter = ctx.game.world_border.diameter() + distance.0 as f64;
+    run_set_world_border(ctx, diameter, 0)
 }
 
-#[command(usage = "worldborder add <distance> <time>")]
+#[command(usage = "worldborder add <distance> <time>", level = 2)]
 pub fn worldborder_add_distance_time(
     ctx: &mut CommandCtx,
-    _distance: FloatArgument,
-    _time: IntegerArgumentPositive,
+    distance: FloatArgument,
+    time: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder add <distance> <time>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    let diameter = ctx.game.world_border.diameter() + distance.0 as f64;
+    run_set_world_border(ctx, diameter, time.0)
 }
 
-#[command(usage = "worldborder center <pos>")]
-pub fn worldborder_center_pos(ctx: &mut CommandCtx, _pos: Vec2) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder center <pos>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+#[command(usage = "worldborder center <pos>", level = 2)]
+pub fn worldborder_center_pos(ctx: &mut CommandCtx, pos: Vec2) -> anyhow::Result<()> {
+    let origin = ctx
+        .world
+        .try_get::<Position>(ctx.sender)
+        .map(|pos| *pos)
+        .unwrap_or_default();
+    let (x, z) = pos.into_xz(origin);
+
+    if x.abs() > MAX_BORDER_CENTER || z.abs() > MAX_BORDER_CENTER {
+        return Err(WorldBorderCommandError::CenterTooFar.into());
     }
-    Ok(Some("".to_string()))
+    if ctx.game.world_border.center() == (x, z) {
+        return Err(WorldBorderCommandError::CenterUnchanged.into());
+    }
+
+    ctx.game.world_border.set_center(&ctx.world, x, z);
+    Ok(Some(format!(
+        "Set the center of the world border to {:.2}, {:.2}",
+        x, z
+    )))
 }
 
-#[command(usage = "worldborder damage amount <damagePerBlock>")]
+#[command(usage = "worldborder damage amount <damagePerBlock>", level = 2)]
 pub fn worldborder_damage_amount_damagePerBlock(
     ctx: &mut CommandCtx,
-    _damagePerBlock: FloatArgumentPositive,
+    damagePerBlock: FloatArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder damage amount <damagePerBlock>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let damage = damagePerBlock.0 as f64;
+    if ctx.game.world_border.damage_per_block() == damage {
+        return Err(WorldBorderCommandError::DamageUnchanged.into());
     }
-    Ok(Some("".to_string()))
+
+    ctx.game.world_border.set_damage_per_block(damage);
+    Ok(Some(format!(
+        "Set the world border damage to {:.2} per block each second",
+        damage
+    )))
 }
 
-#[command(usage = "worldborder damage buffer <distance>")]
+#[command(usage = "worldborder damage buffer <distance>", level = 2)]
 pub fn worldborder_damage_buffer_distance(
     ctx: &mut CommandCtx,
-    _distance: FloatArgumentPositive,
+    distance: FloatArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder damage buffer <distance>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let distance = distance.0 as f64;
+    if ctx.game.world_border.safe_zone() == distance {
+        return Err(WorldBorderCommandError::BufferUnchanged.into());
     }
-    Ok(Some("".to_string()))
+
+    ctx.game.world_border.set_safe_zone(distance);
+    Ok(Some(format!(
+        "Set the world border damage buffer to {:.2} blocks",
+        distance
+    )))
 }
 
-#[command(usage = "worldborder get")]
+#[command(usage = "worldborder get", level = 2)]
 pub fn worldborder_get(ctx: &mut CommandCtx) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from(
-            "This command \"worldborder get\" is not implemented in this version of feather.",
-        )
-        .gray()
-        .italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    Ok(Some(format!(
+        "The world border is currently {:.0} blocks wide",
+        ctx.game.world_border.diameter()
+    )))
 }
 
-#[command(usage = "worldborder set <distance>")]
+#[command(usage = "worldborder set <distance>", level = 2)]
 pub fn worldborder_set_distance(
     ctx: &mut CommandCtx,
-    _distance: FloatArgument,
+    distance: FloatArgument,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder set <distance>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_world_border(ctx, distance.0 as f64, 0)
 }
 
-#[command(usage = "worldborder set <distance> <time>")]
+#[command(usage = "worldborder set <distance> <time>", level = 2)]
 pub fn worldborder_set_distance_time(
     ctx: &mut CommandCtx,
-    _distance: FloatArgument,
-    _time: IntegerArgumentPositive,
+    distance: FloatArgument,
+    time: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder set <distance> <time>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
-    }
-    Ok(Some("".to_string()))
+    run_set_world_border(ctx, distance.0 as f64, time.0)
 }
 
-#[command(usage = "worldborder warning distance <distance>")]
+#[command(usage = "worldborder warning distance <distance>", level = 2)]
 pub fn worldborder_warning_distance_distance(
     ctx: &mut CommandCtx,
-    _distance: IntegerArgumentPositive,
+    distance: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder warning distance <distance>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let distance = distance.0.min(i32::MAX as i64) as i32;
+    if ctx.game.world_border.warning_blocks() == distance {
+        return Err(WorldBorderCommandError::WarningDistanceUnchanged.into());
     }
-    Ok(Some("".to_string()))
+
+    ctx.game
+        .world_border
+        .set_warning_blocks(&ctx.world, distance);
+    Ok(Some(format!(
+        "Set the world border warning distance to {} blocks",
+        distance
+    )))
 }
 
-#[command(usage = "worldborder warning time <time>")]
+#[command(usage = "worldborder warning time <time>", level = 2)]
 pub fn worldborder_warning_time_time(
     ctx: &mut CommandCtx,
-    _time: IntegerArgumentPositive,
+    time: IntegerArgumentPositive,
 ) -> anyhow::Result<()> {
-    if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
-    {
-        let return_text = Text::from("This command \"worldborder warning time <time>\" is not implemented in this version of feather.").gray().italic();
-        sender_message_receiver.send(return_text);
+    let time = time.0.min(i32::MAX as i64) as i32;
+    if ctx.game.world_border.warning_time() == time {
+        return Err(WorldBorderCommandError::WarningTimeUnchanged.into());
+    }
+
+    ctx.game.world_border.set_warning_time(&ctx.world, time);
+    Ok(Some(format!(
+        "Set the world border warning time to {} seconds",
+        time
+    )))
+}
+
+/// Moves the world border to the given diameter
+/// over the given time, in seconds.
+fn run_set_world_border(
+    ctx: &mut CommandCtx,
+    diameter: f64,
+    time: i64,
+) -> anyhow::Result<Option<String>> {
+    let current = ctx.game.world_border.diameter();
+    if diameter == current {
+        return Err(WorldBorderCommandError::SizeUnchanged.into());
+    }
+    if diameter < 1.0 {
+        return Err(WorldBorderCommandError::TooSmall.into());
+    }
+    if diameter > MAX_BORDER_DIAMETER {
+        return Err(WorldBorderCommandError::TooBig.into());
+    }
+
+    if time > 0 {
+        let millis = time
+            .checked_mul(1000)
+            .ok_or(WorldBorderCommandError::TimeTooLong)?;
+        ctx.game
+            .world_border
+            .lerp_diameter(&ctx.world, diameter, millis as u64);
+        let direction = if diameter < current {
+            "Shrinking"
+        } else {
+            "Growing"
+        };
+        Ok(Some(format!(
+            "{} the world border to {:.1} blocks wide over {} seconds",
+            direction, diameter, time
+        )))
+    } else {
+        ctx.game.world_border.set_diameter(&ctx.world, diameter);
+        Ok(Some(format!(
+            "Set the world border to {:.1} blocks wide",
+            diameter
+        )))
     }
-    Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp add <targets> <amount>")]
+#[command(usage = "xp add <targets> <amount>", level = 2)]
 pub fn xp_add_targets_amount(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -10377,7 +11332,7 @@ pub fn xp_add_targets_amount(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp add <targets> <amount> levels")]
+#[command(usage = "xp add <targets> <amount> levels", level = 2)]
 pub fn xp_add_targets_amount_levels(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -10391,7 +11346,7 @@ pub fn xp_add_targets_amount_levels(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp add <targets> <amount> points")]
+#[command(usage = "xp add <targets> <amount> points", level = 2)]
 pub fn xp_add_targets_amount_points(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -10405,7 +11360,7 @@ pub fn xp_add_targets_amount_points(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp query <targets> levels")]
+#[command(usage = "xp query <targets> levels", level = 2)]
 pub fn xp_query_targets_levels(ctx: &mut CommandCtx, _targets: SinglePlayer) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -10415,7 +11370,7 @@ pub fn xp_query_targets_levels(ctx: &mut CommandCtx, _targets: SinglePlayer) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp query <targets> points")]
+#[command(usage = "xp query <targets> points", level = 2)]
 pub fn xp_query_targets_points(ctx: &mut CommandCtx, _targets: SinglePlayer) -> anyhow::Result<()> {
     if let Some(mut sender_message_receiver) = ctx.world.try_get_mut::<MessageReceiver>(ctx.sender)
     {
@@ -10425,7 +11380,7 @@ pub fn xp_query_targets_points(ctx: &mut CommandCtx, _targets: SinglePlayer) ->
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp set <targets> <amount>")]
+#[command(usage = "xp set <targets> <amount>", level = 2)]
 pub fn xp_set_targets_amount(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -10439,7 +11394,7 @@ pub fn xp_set_targets_amount(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp set <targets> <amount> levels")]
+#[command(usage = "xp set <targets> <amount> levels", level = 2)]
 pub fn xp_set_targets_amount_levels(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -10453,7 +11408,7 @@ pub fn xp_set_targets_amount_levels(
     Ok(Some("".to_string()))
 }
 
-#[command(usage = "xp set <targets> <amount> points")]
+#[command(usage = "xp set <targets> <amount> points", level = 2)]
 pub fn xp_set_targets_amount_points(
     ctx: &mut CommandCtx,
     _targets: MultiplePlayers,
@@ -0,0 +1,106 @@
+//! Permission requirements of commands.
+//!
+//! Every command declares the vanilla operator level it requires
+//! in its `#[command]` attribute. Players may run a command if they
+//! have that level or the permission node `minecraft.command.<root literal>`.
+//! Players are checked against their `Permissions` component;
+//! non-player senders bypass the checks.
+
+use crate::{tree, CommandCtx};
+use feather_core::network::packets::EntityStatus;
+use feather_server_types::{Network, NetworkId, Permissions, Player};
+use fecs::{Entity, World};
+use thiserror::Error;
+
+/// Entity status which sets a player's operator level
+/// on the client; the level is added to this value.
+const OP_LEVEL_STATUS: i8 = 24;
+
+#[derive(Debug, Error)]
+pub enum PermissionError {
+    #[error("You do not have permission to use this command")]
+    Denied,
+}
+
+/// Returns the permission node which grants access to
+/// commands with the given root literal regardless of level.
+pub fn permission_node(root: &str) -> String {
+    format!("minecraft.command.{}", root)
+}
+
+/// Returns whether `permissions` allow running commands
+/// with the given root literal which require operator level `level`.
+pub fn permits(permissions: &Permissions, level: u8, root: &str) -> bool {
+    permissions.has(level, &permission_node(root))
+}
+
+/// Checks whether the sender of a command may run it. The
+/// command has the given root literal and requires operator level `level`.
+///
+/// Called before running each command by the `#[command]` attribute.
+pub fn check(ctx: &CommandCtx, level: u8, root: &str) -> Result<(), PermissionError> {
+    if !ctx.world.has::<Player>(ctx.sender) {
+        return Ok(());
+    }
+
+    match ctx.world.try_get::<Permissions>(ctx.sender) {
+        Some(permissions) if permits(&permissions, level, root) => Ok(()),
+        _ => Err(PermissionError::Denied),
+    }
+}
+
+/// Sends a player their operator level and the
+/// commands they may run.
+///
+/// Should be called on join and whenever the
+/// player's `Permissions` change.
+pub fn send_permissions(world: &World, player: Entity) {
+    let (network, id, permissions) = match (
+        world.try_get::<Network>(player),
+        world.try_get::<NetworkId>(player),
+        world.try_get::<Permissions>(player),
+    ) {
+        (Some(network), Some(id), Some(permissions)) => (network, id, permissions),
+        _ => return,
+    };
+
+    network.send(EntityStatus {
+        entity_id: id.0,
+        entity_status: OP_LEVEL_STATUS + permissions.level() as i8,
+    });
+    network.send(tree::TREE.declare(&permissions));
+}
+
+/// Sets the operator level of a player and
+/// resends their permissions.
+pub fn set_operator_level(world: &World, player: Entity, level: u8) {
+    match world.try_get_mut::<Permissions>(player) {
+        Some(mut permissions) => permissions.set_level(level),
+        None => return,
+    }
+    send_permissions(world, player);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn permits_by_level() {
+        let permissions = Permissions::with_level(2);
+        assert!(permits(&permissions, 0, "msg"));
+        assert!(permits(&permissions, 2, "gamemode"));
+        assert!(!permits(&permissions, 3, "kick"));
+    }
+
+    #[test]
+    fn permits_by_node() {
+        let mut permissions = Permissions::with_level(0);
+        assert!(permits(&permissions, 0, "msg"));
+        assert!(!permits(&permissions, 3, "kick"));
+
+        permissions.grant("minecraft.command.kick");
+        assert!(permits(&permissions, 3, "kick"));
+        assert!(!permits(&permissions, 3, "ban"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Shared logic for the `/scoreboard`, `/team`, `/teammsg`
+//! and `/trigger` commands.
+
+use crate::selector::{select_entities, SelectorError};
+use crate::CommandCtx;
+use feather_server_types::{Name, Player, Uuid};
+use fecs::{Entity, World};
+use std::convert::TryFrom;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ScoreboardCommandError {
+    #[error("No entity was found")]
+    NoneFound,
+    #[error("Only one entity is allowed, but the provided selector allows for more than one")]
+    TooMany,
+    #[error(transparent)]
+    Selector(#[from] SelectorError),
+    #[error("Integer must be between {} and {}, found {0}", i32::MIN, i32::MAX)]
+    ValueOutOfRange(i64),
+    #[error("A player is required to run this command here")]
+    NotAPlayer,
+    #[error("You must be on a team to message your team")]
+    NoTeam,
+}
+
+/// Resolves a score holder argument to holder names.
+///
+/// The argument may be a target selector, a literal
+/// holder name or, if `allow_wildcard` is set, `*` for
+/// all holders tracked by the scoreboard.
+pub fn score_holders(
+    ctx: &CommandCtx,
+    argument: &str,
+    allow_wildcard: bool,
+) -> Result<Vec<String>, ScoreboardCommandError> {
+    let holders = if argument == "*" && allow_wildcard {
+        ctx.game
+            .scoreboard
+            .holders()
+            .map(ToOwned::to_owned)
+            .collect()
+    } else if argument.starts_with('@') {
+        select_entities(ctx, argument)?
+            .into_iter()
+            .filter_map(|entity| holder_name(&ctx.world, entity))
+            .collect()
+    } else {
+        vec![argument.to_owned()]
+    };
+
+    if holders.is_empty() {
+        Err(ScoreboardCommandError::NoneFound)
+    } else {
+        Ok(holders)
+    }
+}
+
+/// Resolves a score holder argument which must match exactly one holder.
+pub fn single_score_holder(
+    ctx: &CommandCtx,
+    argument: &str,
+) -> Result<String, ScoreboardCommandError> {
+    let mut holders = score_holders(ctx, argument, false)?;
+    if holders.len() > 1 {
+        return Err(ScoreboardCommandError::TooMany);
+    }
+    Ok(holders.remove(0))
+}
+
+/// Returns the holder name of the command sender, which must be a player.
+pub fn player_sender(ctx: &CommandCtx) -> Result<String, ScoreboardCommandError> {
+    if ctx.world.has::<Player>(ctx.sender) {
+        holder_name(&ctx.world, ctx.sender).ok_or(ScoreboardCommandError::NotAPlayer)
+    } else {
+        Err(ScoreboardCommandError::NotAPlayer)
+    }
+}
+
+/// Returns the name an entity is tracked by in the scoreboard:
+/// the username for players and the UUID for other entities.
+pub fn holder_name(world: &World, entity: Entity) -> Option<String> {
+    if world.has::<Player>(entity) {
+        world.try_get::<Name>(entity).map(|name| name.0.clone())
+    } else {
+        world
+            .try_get::<Uuid>(entity)
+            .map(|uuid| uuid.to_hyphenated().to_string())
+    }
+}
+
+/// Converts a command integer argument to a score value.
+pub fn score_value(value: i64) -> Result<i32, ScoreboardCommandError> {
+    i32::try_from(value).map_err(|_| ScoreboardCommandError::ValueOutOfRange(value))
+}
@@ -0,0 +1,90 @@
+//! Resolution of target selectors and player names.
+
+use crate::CommandCtx;
+use feather_core::util::Position;
+use feather_server_types::{Name, Player, Uuid};
+use fecs::{component, Entity, IntoQuery, Read};
+use rand::Rng;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SelectorError {
+    #[error("No player was found")]
+    NoPlayers,
+    #[error("Selector arguments are not supported yet: {0}")]
+    Arguments(String),
+    #[error("Unknown selector type '{0}'")]
+    Unknown(String),
+}
+
+/// Resolves a target selector (`@a`, `@e`, `@p`, `@r` or `@s`) to entities.
+pub fn select_entities(ctx: &CommandCtx, selector: &str) -> Result<Vec<Entity>, SelectorError> {
+    if let Some(index) = selector.find('[') {
+        return Err(SelectorError::Arguments(selector[index..].to_owned()));
+    }
+
+    let world = &*ctx.world;
+    let players = || {
+        <Read<Player>>::query()
+            .iter_entities(world.inner())
+            .map(|(entity, _)| entity)
+    };
+
+    let entities = match selector {
+        "@s" => vec![ctx.sender],
+        "@a" => players().collect(),
+        "@e" => <Read<Uuid>>::query()
+            .iter_entities(world.inner())
+            .map(|(entity, _)| entity)
+            .collect(),
+        "@p" => {
+            let origin = world
+                .try_get::<Position>(ctx.sender)
+                .map(|pos| *pos)
+                .unwrap_or_default();
+            <Read<Position>>::query()
+                .filter(component::<Player>())
+                .iter_entities(world.inner())
+                .min_by_key(|(_, pos)| origin.distance_squared_to(**pos).floor() as u64)
+                .map(|(entity, _)| entity)
+                .into_iter()
+                .collect()
+        }
+        "@r" => {
+            let count = players().count();
+            if count == 0 {
+                vec![]
+            } else {
+                let index = ctx.game.rng().gen_range(0, count);
+                players().nth(index).into_iter().collect()
+            }
+        }
+        _ => return Err(SelectorError::Unknown(selector.to_owned())),
+    };
+
+    Ok(entities)
+}
+
+/// Resolves a player argument, which is either a
+/// target selector or a player name, to players.
+pub fn select_players(ctx: &CommandCtx, argument: &str) -> Result<Vec<Entity>, SelectorError> {
+    let players: Vec<Entity> = if argument.starts_with('@') {
+        select_entities(ctx, argument)?
+            .into_iter()
+            .filter(|entity| ctx.world.has::<Player>(*entity))
+            .collect()
+    } else {
+        <Read<Name>>::query()
+            .filter(component::<Player>())
+            .iter_entities(ctx.world.inner())
+            .filter(|(_, name)| name.0 == argument)
+            .map(|(entity, _)| entity)
+            .collect()
+    };
+
+    if players.is_empty() {
+        Err(SelectorError::NoPlayers)
+    } else {
+        Ok(players)
+    }
+}
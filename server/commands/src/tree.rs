@@ -0,0 +1,430 @@
+//! The command tree sent to clients in the Declare Commands packet.
+//!
+//! The `#[command]` attribute registers the usage of each command
+//! as a `CommandSpec`. The specs of all commands registered with
+//! the dispatcher are merged into a single `CommandTree`, which is
+//! filtered by the permissions of each player it is sent to.
+
+use crate::permissions;
+use feather_core::network::packets::{CommandNode, CommandNodeKind, DeclareCommands};
+use feather_server_types::Permissions;
+use once_cell::sync::Lazy;
+
+/// The tree of all commands registered in `CommandState`.
+pub static TREE: Lazy<CommandTree> = Lazy::new(|| {
+    CommandTree::new(
+        inventory::iter::<CommandSpec>
+            .into_iter()
+            .filter(|spec| crate::REGISTERED_COMMANDS.contains(&spec.name)),
+    )
+});
+
+/// How a `brigadier:string` argument is parsed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StringMode {
+    /// A single word.
+    Word = 0,
+    /// A single word, or a quoted phrase.
+    Phrase = 1,
+    /// The rest of the input.
+    Greedy = 2,
+}
+
+/// The parser used by the client to parse
+/// and suggest an argument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Parser {
+    identifier: &'static str,
+    properties: Vec<u8>,
+}
+
+impl Parser {
+    /// Creates a parser which has no properties.
+    pub fn new(identifier: &'static str) -> Self {
+        Self {
+            identifier,
+            properties: vec![],
+        }
+    }
+
+    /// Creates a `brigadier:integer` parser with the given bounds.
+    pub fn integer(min: Option<i32>, max: Option<i32>) -> Self {
+        Self::range(
+            "brigadier:integer",
+            min.map(|min| min.to_be_bytes().to_vec()),
+            max.map(|max| max.to_be_bytes().to_vec()),
+        )
+    }
+
+    /// Creates a `brigadier:float` parser with the given bounds.
+    pub fn float(min: Option<f32>, max: Option<f32>) -> Self {
+        Self::range(
+            "brigadier:float",
+            min.map(|min| min.to_be_bytes().to_vec()),
+            max.map(|max| max.to_be_bytes().to_vec()),
+        )
+    }
+
+    /// Creates a `brigadier:double` parser with the given bounds.
+    pub fn double(min: Option<f64>, max: Option<f64>) -> Self {
+        Self::range(
+            "brigadier:double",
+            min.map(|min| min.to_be_bytes().to_vec()),
+            max.map(|max| max.to_be_bytes().to_vec()),
+        )
+    }
+
+    /// Creates a `brigadier:string` parser.
+    pub fn string(mode: StringMode) -> Self {
+        Self {
+            identifier: "brigadier:string",
+            properties: vec![mode as u8],
+        }
+    }
+
+    /// Creates a `minecraft:entity` parser.
+    pub fn entity(single: bool, players_only: bool) -> Self {
+        Self {
+            identifier: "minecraft:entity",
+            properties: vec![single as u8 | (players_only as u8) << 1],
+        }
+    }
+
+    /// Creates a `minecraft:score_holder` parser.
+    pub fn score_holder(multiple: bool) -> Self {
+        Self {
+            identifier: "minecraft:score_holder",
+            properties: vec![multiple as u8],
+        }
+    }
+
+    /// Encodes the optional bounds of a numeric parser,
+    /// preceded by flags indicating which are present.
+    fn range(identifier: &'static str, min: Option<Vec<u8>>, max: Option<Vec<u8>>) -> Self {
+        let flags = min.is_some() as u8 | (max.is_some() as u8) << 1;
+        let mut properties = vec![flags];
+        properties.extend(min.into_iter().chain(max).flatten());
+        Self {
+            identifier,
+            properties,
+        }
+    }
+}
+
+/// An argument type which can be declared to clients.
+pub trait DeclaredArgument {
+    /// Returns the parser clients should use for this argument.
+    fn parser() -> Parser;
+}
+
+/// A node in the usage of a command.
+#[derive(Clone, Debug)]
+pub enum UsageNode {
+    /// A literal, along with its aliases.
+    Literal(&'static [&'static str]),
+    Argument {
+        name: &'static str,
+        parser: Parser,
+    },
+}
+
+/// The usage of a command, registered by the `#[command]` attribute.
+#[derive(Clone, Debug)]
+pub struct CommandSpec {
+    /// Name of the function implementing the command.
+    pub name: &'static str,
+    /// Operator level required to run the command.
+    pub level: u8,
+    pub nodes: Vec<UsageNode>,
+}
+
+inventory::collect!(CommandSpec);
+
+#[derive(Debug)]
+struct Node {
+    kind: CommandNodeKind,
+    /// If a command ends at this node, the lowest operator level
+    /// required to run it and the root literal of its permission node.
+    executable: Option<(u8, &'static str)>,
+    children: Vec<usize>,
+}
+
+/// A tree of commands, merged from their `CommandSpec`s.
+#[derive(Debug)]
+pub struct CommandTree {
+    /// All nodes; the root node is at index 0.
+    nodes: Vec<Node>,
+}
+
+impl CommandTree {
+    /// Merges the given commands into a tree.
+    pub fn new<'a>(specs: impl IntoIterator<Item = &'a CommandSpec>) -> Self {
+        let mut tree = Self {
+            nodes: vec![Node {
+                kind: CommandNodeKind::Root,
+                executable: None,
+                children: vec![],
+            }],
+        };
+
+        for spec in specs {
+            let root = match spec.nodes.first() {
+                Some(UsageNode::Literal(names)) => names[0],
+                _ => continue,
+            };
+            tree.insert(0, &spec.nodes, spec.level, root);
+        }
+
+        tree
+    }
+
+    fn insert(&mut self, parent: usize, usage: &[UsageNode], level: u8, root: &'static str) {
+        let (first, rest) = match usage.split_first() {
+            Some(split) => split,
+            None => {
+                let executable = &mut self.nodes[parent].executable;
+                *executable = match *executable {
+                    Some((existing, _)) if existing <= level => *executable,
+                    _ => Some((level, root)),
+                };
+                return;
+            }
+        };
+
+        match first {
+            UsageNode::Literal(names) => {
+                for name in names.iter() {
+                    let child = self.child(
+                        parent,
+                        CommandNodeKind::Literal {
+                            name: (*name).to_owned(),
+                        },
+                    );
+                    self.insert(child, rest, level, root);
+                }
+            }
+            UsageNode::Argument { name, parser } => {
+                let child = self.child(
+                    parent,
+                    CommandNodeKind::Argument {
+                        name: (*name).to_owned(),
+                        parser: parser.identifier.to_owned(),
+                        properties: parser.properties.clone(),
+                        suggestions: None,
+                    },
+                );
+                self.insert(child, rest, level, root);
+            }
+        }
+    }
+
+    /// Returns the child of `parent` with the given kind,
+    /// adding it if it does not exist.
+    fn child(&mut self, parent: usize, kind: CommandNodeKind) -> usize {
+        let existing = self.nodes[parent]
+            .children
+            .iter()
+            .copied()
+            .find(|&child| same_kind(&self.nodes[child].kind, &kind));
+        if let Some(child) = existing {
+            return child;
+        }
+
+        let child = self.nodes.len();
+        self.nodes.push(Node {
+            kind,
+            executable: None,
+            children: vec![],
+        });
+        self.nodes[parent].children.push(child);
+        child
+    }
+
+    /// Builds the Declare Commands packet for a player with the given
+    /// permissions. Only commands they may run are included.
+    pub fn declare(&self, permissions: &Permissions) -> DeclareCommands {
+        let mut nodes = vec![];
+        let root_index = self
+            .declare_node(0, permissions, &mut nodes)
+            .expect("root node is always declared");
+
+        DeclareCommands { nodes, root_index }
+    }
+
+    /// Appends the node at `index` to `out` if it, or any of its
+    /// descendants, may be run. Returns its index in `out`.
+    fn declare_node(
+        &self,
+        index: usize,
+        permissions: &Permissions,
+        out: &mut Vec<CommandNode>,
+    ) -> Option<i32> {
+        let node = &self.nodes[index];
+        let children: Vec<i32> = node
+            .children
+            .iter()
+            .filter_map(|&child| self.declare_node(child, permissions, out))
+            .collect();
+        let executable = node.executable.map_or(false, |(level, root)| {
+            permissions.has(level, &permissions::permission_node(root))
+        });
+
+        if index != 0 && !executable && children.is_empty() {
+            return None;
+        }
+
+        out.push(CommandNode {
+            kind: node.kind.clone(),
+            executable,
+            children,
+            redirect: None,
+        });
+        Some(out.len() as i32 - 1)
+    }
+}
+
+fn same_kind(a: &CommandNodeKind, b: &CommandNodeKind) -> bool {
+    match (a, b) {
+        (CommandNodeKind::Literal { name: a }, CommandNodeKind::Literal { name: b }) => a == b,
+        (
+            CommandNodeKind::Argument {
+                name: a,
+                parser: a_parser,
+                properties: a_properties,
+                ..
+            },
+            CommandNodeKind::Argument {
+                name: b,
+                parser: b_parser,
+                properties: b_properties,
+                ..
+            },
+        ) => a == b && a_parser == b_parser && a_properties == b_properties,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(name: &'static str, level: u8, nodes: Vec<UsageNode>) -> CommandSpec {
+        CommandSpec { name, level, nodes }
+    }
+
+    fn names(packet: &DeclareCommands, index: i32) -> Vec<String> {
+        packet.nodes[index as usize]
+            .children
+            .iter()
+            .map(|&child| match &packet.nodes[child as usize].kind {
+                CommandNodeKind::Literal { name } | CommandNodeKind::Argument { name, .. } => {
+                    name.clone()
+                }
+                CommandNodeKind::Root => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn numeric_properties() {
+        assert_eq!(Parser::integer(None, None).properties, vec![0]);
+        assert_eq!(
+            Parser::integer(Some(1), None).properties,
+            vec![1, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            Parser::integer(Some(0), Some(255)).properties,
+            vec![3, 0, 0, 0, 0, 0, 0, 0, 255]
+        );
+        assert_eq!(Parser::entity(true, true).properties, vec![3]);
+        assert_eq!(Parser::entity(false, true).properties, vec![2]);
+    }
+
+    #[test]
+    fn filtered_by_permissions() {
+        let specs = vec![
+            spec(
+                "tell",
+                0,
+                vec![
+                    UsageNode::Literal(&["tell", "msg"]),
+                    UsageNode::Argument {
+                        name: "targets",
+                        parser: Parser::entity(false, true),
+                    },
+                    UsageNode::Argument {
+                        name: "message",
+                        parser: Parser::new("minecraft:message"),
+                    },
+                ],
+            ),
+            spec(
+                "time_query",
+                2,
+                vec![
+                    UsageNode::Literal(&["time"]),
+                    UsageNode::Literal(&["query"]),
+                ],
+            ),
+            spec(
+                "time_set",
+                2,
+                vec![
+                    UsageNode::Literal(&["time"]),
+                    UsageNode::Literal(&["set"]),
+                    UsageNode::Argument {
+                        name: "time",
+                        parser: Parser::new("minecraft:time"),
+                    },
+                ],
+            ),
+        ];
+        let tree = CommandTree::new(&specs);
+
+        let packet = tree.declare(&Permissions::with_level(0));
+        assert_eq!(names(&packet, packet.root_index), vec!["tell", "msg"]);
+        assert_eq!(packet.nodes.len(), 1 + 2 * 3);
+
+        let tell = packet.nodes[packet.root_index as usize].children[0];
+        let targets = packet.nodes[tell as usize].children[0];
+        match &packet.nodes[targets as usize].kind {
+            CommandNodeKind::Argument {
+                parser, properties, ..
+            } => {
+                assert_eq!(parser, "minecraft:entity");
+                assert_eq!(properties, &vec![2]);
+            }
+            kind => panic!("unexpected node {:?}", kind),
+        }
+        let message = packet.nodes[targets as usize].children[0];
+        assert!(!packet.nodes[targets as usize].executable);
+        assert!(packet.nodes[message as usize].executable);
+
+        let mut permissions = Permissions::with_level(0);
+        permissions.grant("minecraft.command.time");
+        let packet = tree.declare(&permissions);
+        assert_eq!(
+            names(&packet, packet.root_index),
+            vec!["tell", "msg", "time"]
+        );
+        let time = packet.nodes[packet.root_index as usize].children[2];
+        assert_eq!(names(&packet, time), vec!["query", "set"]);
+        assert!(!packet.nodes[time as usize].executable);
+    }
+
+    #[test]
+    fn registered_commands_are_declared() {
+        let literals = |permissions: &Permissions| {
+            let packet = TREE.declare(permissions);
+            names(&packet, packet.root_index)
+        };
+
+        let player = literals(&Permissions::with_level(0));
+        assert!(player.contains(&String::from("tell")));
+        assert!(!player.contains(&String::from("gamemode")));
+
+        let operator = literals(&Permissions::with_level(4));
+        assert!(operator.contains(&String::from("gamemode")));
+        assert!(operator.contains(&String::from("stop")));
+    }
+}
@@ -22,7 +22,14 @@ pub struct Config {
 impl Config {
     /// Loads a config from the given string.
     pub fn load(s: &str) -> anyhow::Result<Config> {
-        toml::from_str(s).map_err(Into::into)
+        let config: Config = toml::from_str(s)?;
+
+        if config.proxy.proxy_mode == ProxyMode::Velocity && config.proxy.velocity_secret.is_empty()
+        {
+            anyhow::bail!("proxy_mode is Velocity, but velocity_secret is empty");
+        }
+
+        Ok(config)
     }
 
     /// Loads a config from the given file.
@@ -61,6 +68,10 @@ pub struct IO {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Proxy {
     pub proxy_mode: ProxyMode,
+    /// The secret shared with a Velocity proxy, used to verify
+    /// the forwarded player information.
+    #[serde(default)]
+    pub velocity_secret: String,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -72,6 +83,9 @@ pub struct Server {
     pub address: String,
     pub port: u16,
     pub default_gamemode: Gamemode,
+    /// Whether only players on the whitelist (and operators) may join.
+    #[serde(default)]
+    pub whitelist: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -80,6 +94,14 @@ pub struct Gameplay {
     pub animal_spawning: bool,
     pub pvp: bool,
     pub nerf_spawner_mobs: bool,
+    /// The maximum number of blocks which may be affected
+    /// by a single `/fill` or `/clone` command.
+    #[serde(default = "default_max_command_volume")]
+    pub max_command_volume: u64,
+}
+
+fn default_max_command_volume() -> u64 {
+    32768
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -100,6 +122,26 @@ pub struct World {
     pub seed: String,
     #[serde(with = "humantime_serde")]
     pub save_interval: Duration,
+    /// Compression used for chunks saved to region files.
+    #[serde(default)]
+    pub chunk_compression: ChunkCompression,
+}
+
+/// The compression of chunks saved to region files.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChunkCompression {
+    Gzip,
+    Zlib,
+    None,
+    Lz4,
+    Zstd,
+}
+
+impl Default for ChunkCompression {
+    fn default() -> Self {
+        ChunkCompression::Zlib
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
@@ -137,6 +179,7 @@ mod tests {
         assert_eq!(gameplay.monster_spawning, true);
         assert_eq!(gameplay.pvp, true);
         assert_eq!(gameplay.nerf_spawner_mobs, false);
+        assert_eq!(gameplay.max_command_volume, 32768);
 
         let log = &config.log;
         assert_eq!(log.level, "debug");
@@ -150,8 +193,21 @@ mod tests {
         assert_eq!(world.generator, "default");
         assert_eq!(world.seed, "");
         assert_eq!(world.save_interval.as_millis(), 1000 * 60);
+        assert_eq!(world.chunk_compression, ChunkCompression::Zlib);
 
         let proxy = &config.proxy;
         assert_eq!(proxy.proxy_mode, ProxyMode::None);
+        assert_eq!(proxy.velocity_secret, "");
+    }
+
+    #[test]
+    fn test_velocity_requires_secret() {
+        let input = include_str!("../feather.toml")
+            .replace(r#"proxy_mode = "None""#, r#"proxy_mode = "Velocity""#);
+        assert!(Config::load(&input).is_err());
+
+        let input = input.replace(r#"velocity_secret = """#, r#"velocity_secret = "secret""#);
+        let config = Config::load(&input).unwrap();
+        assert_eq!(config.proxy.velocity_secret, "secret");
     }
 }
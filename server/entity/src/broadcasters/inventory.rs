@@ -293,6 +293,7 @@ mod tests {
             ty: Item::StoneShovel,
             amount: 1,
             damage: Some(10),
+            tag: None,
         };
         let slot = SlotIndex {
             area: Area::Main,
@@ -1,9 +1,10 @@
 use crate::{item, InventoryExt};
-use feather_core::items::ItemStack;
-use feather_core::loot::{loot_table, Conditions};
+use feather_core::items::{ItemStack, NbtCompound};
+use feather_core::loot::{loot_table, BlockState, Conditions};
 use feather_core::util::Position;
 use feather_server_types::{
-    BlockUpdateEvent, CanInstaBreak, EntitySpawnEvent, Game, Inventory, Velocity, TPS,
+    BlockSerializer, BlockUpdateCause, BlockUpdateEvent, CanInstaBreak, EntitySpawnEvent, Game,
+    Inventory, Velocity, TPS,
 };
 use fecs::{Entity, World};
 use rand::Rng;
@@ -12,12 +13,14 @@ use rand::Rng;
 /// yields items from the block's loot table.
 #[fecs::event_handler]
 pub fn on_block_break_drop_loot(event: &BlockUpdateEvent, game: &mut Game, world: &mut World) {
-    if event.old.is_air() || !event.new.is_air() {
+    // Blocks washed away by fluids drop their loot too.
+    let washed_away = event.cause == BlockUpdateCause::Fluid;
+    if event.old.is_air() || event.old.is_fluid() || !(event.new.is_air() || washed_away) {
         return;
     }
 
     let item = match event.cause {
-        feather_server_types::BlockUpdateCause::Entity(entity) => {
+        BlockUpdateCause::Entity(entity) => {
             // If broken by a player who can insta-break, don't drop loot.
             if world.has::<CanInstaBreak>(entity) {
                 return;
@@ -41,12 +44,34 @@ pub fn on_block_break_drop_loot(event: &BlockUpdateEvent, game: &mut Game, world
 
             item
         }
-        feather_server_types::BlockUpdateCause::Unsupported => None,
+        BlockUpdateCause::Unsupported
+        | BlockUpdateCause::Destroyed
+        | BlockUpdateCause::Fluid
+        | BlockUpdateCause::Explosion { .. } => None,
         _ => return,
     };
 
     if let Some(loot_table) = loot_table(&format!("blocks/{}", &event.old.identifier()[10..])) {
-        let conditions = Conditions { item };
+        let explosion_radius = match event.cause {
+            BlockUpdateCause::Explosion { radius } => Some(radius),
+            _ => None,
+        };
+        // The block entity is removed after this handler runs.
+        let block_entity = game
+            .block_entities
+            .get(&event.pos)
+            .and_then(|entity| block_entity_nbt(game, world, *entity));
+
+        let conditions = Conditions {
+            item,
+            block: Some(BlockState {
+                identifier: event.old.identifier(),
+                properties: event.old.to_properties_map(),
+            }),
+            block_entity,
+            explosion_radius,
+            ..Default::default()
+        };
         let items = loot_table
             .sample(&mut *game.rng(), &conditions)
             .unwrap_or_else(|e| {
@@ -64,6 +89,19 @@ pub fn on_block_break_drop_loot(event: &BlockUpdateEvent, game: &mut Game, world
     }
 }
 
+/// Serializes a block entity to NBT for loot functions like `copy_name`.
+fn block_entity_nbt(game: &Game, world: &World, entity: Entity) -> Option<NbtCompound> {
+    let serializer = world.try_get::<BlockSerializer>(entity)?;
+    let accessor = world.entity(entity).expect("entity does not exist");
+    let data = serializer.serialize(game, &accessor);
+
+    let mut bytes = Vec::new();
+    nbt::to_writer(&mut bytes, &data, None)
+        .and_then(|_| nbt::from_reader(bytes.as_slice()))
+        .map_err(|e| log::warn!("Failed to encode block entity data: {}", e))
+        .ok()
+}
+
 /// "Naturally" drops an item caused by e.g. a broken block or a dead entity.
 pub fn drop_item(game: &mut Game, world: &mut World, item: ItemStack, pos: Position) -> Entity {
     // Compute velocity. Based on Glowstone's implementation of `World#dropItemNaturally()`.
@@ -88,3 +126,83 @@ pub fn drop_item(game: &mut Game, world: &mut World, item: ItemStack, pos: Posit
 
     entity
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::blocks::BlockId;
+    use feather_core::inventory::Area;
+    use feather_core::items::{Enchantment, Item};
+    use feather_core::position;
+    use feather_core::util::BlockPosition;
+    use feather_test_framework::Test;
+    use fecs::{IntoQuery, Read};
+
+    fn break_ore(tool: Option<ItemStack>, cause: Option<BlockUpdateCause>) -> Vec<ItemStack> {
+        let mut test = Test::new();
+        let player = test.player("player", position!(0.0, 64.0, 0.0));
+        // Creative players don't get drops.
+        test.world.remove::<CanInstaBreak>(player).unwrap();
+        if let Some(tool) = tool {
+            test.world
+                .get::<Inventory>(player)
+                .set_item_at(Area::Hotbar, 0, tool)
+                .unwrap();
+        }
+
+        for x in 0..50 {
+            let event = BlockUpdateEvent {
+                pos: BlockPosition::new(x, 64, 0),
+                old: BlockId::diamond_ore(),
+                new: BlockId::air(),
+                cause: cause.unwrap_or(BlockUpdateCause::Entity(player)),
+            };
+            test.handle(event, on_block_break_drop_loot);
+        }
+
+        <Read<ItemStack>>::query()
+            .iter(test.world.inner())
+            .map(|stack| *stack)
+            .collect()
+    }
+
+    fn pickaxe(enchantment: Option<(Enchantment, u32)>) -> Option<ItemStack> {
+        let mut pickaxe = ItemStack::new(Item::DiamondPickaxe, 1);
+        if let Some((enchantment, level)) = enchantment {
+            pickaxe.add_enchantment(enchantment, level);
+        }
+        Some(pickaxe)
+    }
+
+    #[test]
+    fn break_ore_with_pickaxe() {
+        let drops = break_ore(pickaxe(None), None);
+        assert_eq!(drops, vec![ItemStack::new(Item::Diamond, 1); 50]);
+
+        // Diamond ore needs a pickaxe.
+        assert!(break_ore(None, None).is_empty());
+    }
+
+    #[test]
+    fn break_ore_with_silk_touch() {
+        let drops = break_ore(pickaxe(Some((Enchantment::SilkTouch, 1))), None);
+        assert_eq!(drops, vec![ItemStack::new(Item::DiamondOre, 1); 50]);
+    }
+
+    #[test]
+    fn break_ore_with_fortune() {
+        let drops = break_ore(pickaxe(Some((Enchantment::Fortune, 3))), None);
+        assert_eq!(drops.len(), 50);
+        assert!(drops.iter().all(|stack| stack.ty == Item::Diamond));
+        assert!(drops.iter().all(|stack| (1..=4).contains(&stack.amount)));
+        assert!(drops.iter().any(|stack| stack.amount > 1));
+    }
+
+    #[test]
+    fn explosion_decays_drops() {
+        let explosion = BlockUpdateCause::Explosion { radius: 4.0 };
+        let drops = break_ore(None, Some(explosion));
+        assert!(drops.iter().all(|stack| stack.ty == Item::Diamond));
+        assert!(!drops.is_empty() && drops.len() < 50);
+    }
+}
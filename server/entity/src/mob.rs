@@ -1,11 +1,15 @@
 //! Components and functionality shared across all mobs.
 
+pub mod ai;
 mod boss;
 mod defensive;
 mod hostile;
 mod neutral;
 mod passive;
+mod persistence;
+mod spawning;
 
+pub use ai::{on_health_update_record_hurt, tick_mob_ai};
 pub use boss::*;
 pub use defensive::*;
 use feather_core::entitymeta::EntityMetadata;
@@ -18,11 +22,16 @@ use fecs::{EntityBuilder, EntityRef};
 pub use hostile::*;
 pub use neutral::*;
 pub use passive::*;
+pub use persistence::{
+    create_mob, default_max_health, Age, Attributes, CustomName, PersistenceRequired,
+};
+pub use spawning::{despawn_mobs, spawn_category, spawn_mobs, IdleTime};
 
 /// Enumeration of mob types. Note that this enum should not be
 /// used in queries to identify mobs of a given type.
 ///
-/// This is _not_ a component. It is only used for utility
+/// Every mob stores its kind as a component so that it can be
+/// saved to chunk data. Otherwise, it is only used for utility
 /// functions such as `mob::spawn_packet_creator`.
 ///
 /// https://wiki.vg/Entity_metadata#Mobs
@@ -88,10 +97,81 @@ pub enum MobKind {
     Phantom = 90,
 }
 
+impl MobKind {
+    /// Returns the mob kind with the given namespaced
+    /// entity ID, e.g. `minecraft:zombie`.
+    pub fn from_identifier(identifier: &str) -> Option<Self> {
+        Some(match identifier {
+            "minecraft:bat" => MobKind::Bat,
+            "minecraft:blaze" => MobKind::Blaze,
+            "minecraft:cave_spider" => MobKind::CaveSpider,
+            "minecraft:chicken" => MobKind::Chicken,
+            "minecraft:cod" => MobKind::Cod,
+            "minecraft:cow" => MobKind::Cow,
+            "minecraft:creeper" => MobKind::Creeper,
+            "minecraft:donkey" => MobKind::Donkey,
+            "minecraft:dolphin" => MobKind::Dolphin,
+            "minecraft:drowned" => MobKind::Drowned,
+            "minecraft:elder_guardian" => MobKind::ElderGuardian,
+            "minecraft:ender_dragon" => MobKind::EnderDragon,
+            "minecraft:enderman" => MobKind::Enderman,
+            "minecraft:endermite" => MobKind::Endermite,
+            "minecraft:evoker" => MobKind::EvocationIllager,
+            "minecraft:ghast" => MobKind::Ghast,
+            "minecraft:giant" => MobKind::Giant,
+            "minecraft:guardian" => MobKind::Guardian,
+            "minecraft:horse" => MobKind::Horse,
+            "minecraft:husk" => MobKind::Husk,
+            "minecraft:illusioner" => MobKind::IllusionIllager,
+            "minecraft:llama" => MobKind::Llama,
+            "minecraft:magma_cube" => MobKind::MagmaCube,
+            "minecraft:mule" => MobKind::Mule,
+            "minecraft:mooshroom" => MobKind::MushroomCow,
+            "minecraft:ocelot" => MobKind::Ocelot,
+            "minecraft:parrot" => MobKind::Parrot,
+            "minecraft:pig" => MobKind::Pig,
+            "minecraft:pufferfish" => MobKind::Pufferfish,
+            "minecraft:zombie_pigman" => MobKind::PigZombie,
+            "minecraft:polar_bear" => MobKind::PolarBear,
+            "minecraft:rabbit" => MobKind::Rabbit,
+            "minecraft:salmon" => MobKind::Salmon,
+            "minecraft:sheep" => MobKind::Sheep,
+            "minecraft:shulker" => MobKind::Shulker,
+            "minecraft:silverfish" => MobKind::Silverfish,
+            "minecraft:skeleton" => MobKind::Skeleton,
+            "minecraft:skeleton_horse" => MobKind::SkeletonHorse,
+            "minecraft:slime" => MobKind::Slime,
+            "minecraft:snow_golem" => MobKind::SnowGolem,
+            "minecraft:spider" => MobKind::Spider,
+            "minecraft:squid" => MobKind::Squid,
+            "minecraft:stray" => MobKind::Stray,
+            "minecraft:tropical_fish" => MobKind::TropicalFish,
+            "minecraft:turtle" => MobKind::Turtle,
+            "minecraft:vex" => MobKind::Vex,
+            "minecraft:villager" => MobKind::Villager,
+            "minecraft:iron_golem" => MobKind::IronGolem,
+            "minecraft:vindicator" => MobKind::VindicationIllager,
+            "minecraft:witch" => MobKind::Witch,
+            "minecraft:wither" => MobKind::Wither,
+            "minecraft:wither_skeleton" => MobKind::WitherSkeleton,
+            "minecraft:wolf" => MobKind::Wolf,
+            "minecraft:zombie" => MobKind::Zombie,
+            "minecraft:zombie_horse" => MobKind::ZombieHorse,
+            "minecraft:zombie_villager" => MobKind::ZombieVillager,
+            "minecraft:phantom" => MobKind::Phantom,
+            _ => return None,
+        })
+    }
+}
+
 /// Returns the base components for a mob with the given
 /// kind.
 pub fn base(kind: MobKind) -> EntityBuilder {
-    super::base().with(spawn_packet_creator(kind))
+    super::base()
+        .with(kind)
+        .with(spawn_packet_creator(kind))
+        .with(persistence::serializer())
+        .with(IdleTime::default())
 }
 
 /// Returns a `SpawnPacketCreator` for a mob with the given kind.
@@ -0,0 +1,547 @@
+//! Goal-based mob AI.
+//!
+//! Each mob with AI has a `Goals` component holding a list of
+//! goals, such as wandering or attacking a player, ordered by
+//! priority. Every tick, the goal selector stops goals which
+//! no longer apply and starts the highest-priority goals which do,
+//! as long as no two running goals need the same `Controls`.
+//! Running goals then steer the mob through its `MobControl`, which
+//! `tick_mob_ai` applies by walking the mob along its current path
+//! and turning its head. The resulting movement is sent to clients
+//! by `broadcast_movement`.
+
+mod goals;
+pub mod pathfinding;
+
+pub use goals::{AvoidPlayer, FollowParent, LookAtPlayer, MeleeAttack, Panic, Wander};
+
+use crate::mob::MobKind;
+use bitflags::bitflags;
+use feather_core::util::{BlockPosition, Position};
+use feather_server_types::{BumpVec, CanTakeDamage, Dead, Game, HealthUpdateEvent, Player};
+use fecs::{component, Entity, IntoQuery, Read, World};
+use pathfinding::{find_path, node_at, standing_height, Path};
+
+/// Height of a player's eyes above their feet.
+const PLAYER_EYE_HEIGHT: f64 = 1.62;
+
+bitflags! {
+    /// The parts of a mob a goal controls. Goals
+    /// with overlapping controls cannot run at once.
+    pub struct Controls: u8 {
+        const MOVE = 0b01;
+        const LOOK = 0b10;
+    }
+}
+
+/// A behavior of a mob, such as wandering or attacking a player.
+pub trait Goal: Send + Sync + 'static {
+    /// Returns the parts of the mob this goal controls.
+    fn controls(&self) -> Controls;
+
+    /// Determines whether this goal should start running.
+    fn should_start(&mut self, ctx: &GoalContext) -> bool;
+
+    /// Determines whether this goal should keep running.
+    /// Defaults to `should_start`.
+    fn should_continue(&mut self, ctx: &GoalContext) -> bool {
+        self.should_start(ctx)
+    }
+
+    /// Called when the goal starts running.
+    fn start(&mut self, _ctx: &GoalContext, _control: &mut MobControl) {}
+
+    /// Called each tick while the goal is running.
+    fn tick(&mut self, _ctx: &GoalContext, _control: &mut MobControl) {}
+
+    /// Called when the goal stops running, either because
+    /// it no longer applies or because a goal with higher
+    /// priority took over its controls.
+    fn stop(&mut self, _control: &mut MobControl) {}
+}
+
+/// State available to goals while the goal selector runs.
+pub struct GoalContext<'a> {
+    pub game: &'a Game,
+    pub world: &'a World,
+    /// The mob running the goals.
+    pub entity: Entity,
+    /// The mob's position.
+    pub pos: Position,
+}
+
+impl<'a> GoalContext<'a> {
+    /// Returns the closest living player within `range` blocks of the mob.
+    pub fn nearest_player(&self, range: f64) -> Option<(Entity, Position)> {
+        self.nearest(range, |_| true)
+    }
+
+    /// Returns the closest player within `range` blocks of the mob
+    /// which can be attacked, i.e. is in survival or adventure mode.
+    pub fn nearest_target(&self, range: f64) -> Option<(Entity, Position)> {
+        self.nearest(range, |player| self.world.has::<CanTakeDamage>(player))
+    }
+
+    fn nearest(&self, range: f64, filter: impl Fn(Entity) -> bool) -> Option<(Entity, Position)> {
+        <Read<Position>>::query()
+            .filter(component::<Player>() & !component::<Dead>())
+            .iter_entities(self.world.inner())
+            .map(|(entity, pos)| (entity, *pos))
+            .filter(|(entity, pos)| {
+                pos.distance_squared_to(self.pos) <= range * range && filter(*entity)
+            })
+            .min_by(|(_, a), (_, b)| {
+                a.distance_squared_to(self.pos)
+                    .partial_cmp(&b.distance_squared_to(self.pos))
+                    .unwrap()
+            })
+    }
+
+    /// Returns the position of an entity if it is
+    /// still alive, or `None` otherwise.
+    pub fn living_position(&self, entity: Entity) -> Option<Position> {
+        if !self.world.is_alive(entity) || self.world.has::<Dead>(entity) {
+            return None;
+        }
+        self.world.try_get::<Position>(entity).map(|pos| *pos)
+    }
+
+    /// Returns a random position within `horizontal` blocks of the mob,
+    /// horizontally, and `vertical` blocks vertically, where the mob
+    /// can stand. Returns `None` if none was found after a few tries.
+    pub fn random_position(&self, horizontal: i32, vertical: i32) -> Option<BlockPosition> {
+        self.random_position_towards(horizontal, vertical, None)
+    }
+
+    /// Like `random_position`, but only returns positions
+    /// in the same direction as `direction` (x, z).
+    pub fn random_position_towards(
+        &self,
+        horizontal: i32,
+        vertical: i32,
+        direction: Option<(f64, f64)>,
+    ) -> Option<BlockPosition> {
+        use rand::Rng;
+
+        let origin = node_at(self.pos);
+        let mut rng = self.game.rng();
+        for _ in 0..10 {
+            let dx = rng.gen_range(-horizontal, horizontal + 1);
+            let dz = rng.gen_range(-horizontal, horizontal + 1);
+            if let Some((x, z)) = direction {
+                if f64::from(dx) * x + f64::from(dz) * z <= 0.0 {
+                    continue;
+                }
+            }
+
+            let dy = rng.gen_range(-vertical, vertical + 1);
+            let candidate = BlockPosition::new(origin.x + dx, origin.y + dy, origin.z + dz);
+            if candidate != origin && pathfinding::can_stand_at(&self.game.chunk_map, candidate) {
+                return Some(candidate);
+            }
+        }
+        None
+    }
+}
+
+/// Intentions of a mob's running goals, applied by `tick_mob_ai`.
+#[derive(Debug, Default)]
+pub struct MobControl {
+    pub navigator: Navigator,
+    /// Position the mob should look at, if any.
+    pub look_at: Option<Position>,
+    /// Entity to hit this tick and the damage to deal.
+    pub attack: Option<(Entity, u32)>,
+}
+
+/// Walks a mob along a path.
+#[derive(Debug, Default)]
+pub struct Navigator {
+    path: Path,
+    next: usize,
+    /// Speed in blocks per tick.
+    speed: f64,
+}
+
+impl Navigator {
+    /// Finds a path from the mob's position to `goal` and starts
+    /// walking along it. Returns `false` if there is no path.
+    pub fn navigate(
+        &mut self,
+        game: &Game,
+        from: Position,
+        goal: BlockPosition,
+        speed: f64,
+    ) -> bool {
+        match find_path(&game.chunk_map, node_at(from), goal) {
+            Some(path) if !path.is_empty() => {
+                self.path = path;
+                self.next = 0;
+                self.speed = speed;
+                true
+            }
+            _ => {
+                self.stop();
+                false
+            }
+        }
+    }
+
+    /// Stops walking.
+    pub fn stop(&mut self) {
+        self.path = Path::default();
+        self.next = 0;
+    }
+
+    /// Returns whether the mob has reached the end of its path.
+    pub fn is_idle(&self) -> bool {
+        self.next >= self.path.nodes.len()
+    }
+
+    /// Returns the node at the end of the current path.
+    pub fn goal(&self) -> Option<BlockPosition> {
+        if self.is_idle() {
+            None
+        } else {
+            self.path.goal()
+        }
+    }
+
+    /// Moves `pos` one tick along the path, returning
+    /// the direction moved in, if any.
+    fn step(&mut self, game: &Game, pos: &mut Position) -> Option<(f64, f64, f64)> {
+        let node = *self.path.nodes.get(self.next)?;
+        let target = (
+            f64::from(node.x) + 0.5,
+            standing_height(&game.chunk_map, node),
+            f64::from(node.z) + 0.5,
+        );
+
+        let (dx, dy, dz) = (target.0 - pos.x, target.1 - pos.y, target.2 - pos.z);
+        let distance = (dx * dx + dy * dy + dz * dz).sqrt();
+        if distance <= self.speed {
+            pos.x = target.0;
+            pos.y = target.1;
+            pos.z = target.2;
+            self.next += 1;
+        } else {
+            let scale = self.speed / distance;
+            pos.x += dx * scale;
+            pos.y += dy * scale;
+            pos.z += dz * scale;
+        }
+        pos.on_ground = true;
+
+        Some((dx, dy, dz))
+    }
+}
+
+/// The goals of a mob with AI.
+#[derive(Default)]
+pub struct Goals {
+    /// Sorted by priority, lowest (most important) first.
+    entries: Vec<GoalEntry>,
+    control: MobControl,
+}
+
+struct GoalEntry {
+    priority: u8,
+    goal: Box<dyn Goal>,
+    running: bool,
+}
+
+impl Goals {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a goal with the given priority. Goals with lower
+    /// priority values take precedence.
+    pub fn with(mut self, priority: u8, goal: impl Goal) -> Self {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.priority > priority)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(
+            index,
+            GoalEntry {
+                priority,
+                goal: Box::new(goal),
+                running: false,
+            },
+        );
+        self
+    }
+
+    /// Returns the mob's control state.
+    pub fn control(&self) -> &MobControl {
+        &self.control
+    }
+
+    /// Stops goals which no longer apply, starts goals which do
+    /// and ticks the running goals.
+    fn select(&mut self, ctx: &GoalContext) {
+        let control = &mut self.control;
+
+        for entry in &mut self.entries {
+            if entry.running && !entry.goal.should_continue(ctx) {
+                entry.goal.stop(control);
+                entry.running = false;
+            }
+        }
+
+        for i in 0..self.entries.len() {
+            if self.entries[i].running {
+                continue;
+            }
+
+            let priority = self.entries[i].priority;
+            let controls = self.entries[i].goal.controls();
+            let blocked = self.entries.iter().any(|other| {
+                other.running
+                    && other.priority <= priority
+                    && other.goal.controls().intersects(controls)
+            });
+            if blocked || !self.entries[i].goal.should_start(ctx) {
+                continue;
+            }
+
+            // Interrupt less important goals using the same controls.
+            for other in &mut self.entries {
+                if other.running && other.goal.controls().intersects(controls) {
+                    other.goal.stop(control);
+                    other.running = false;
+                }
+            }
+
+            let entry = &mut self.entries[i];
+            entry.goal.start(ctx, control);
+            entry.running = true;
+        }
+
+        for entry in self.entries.iter_mut().filter(|entry| entry.running) {
+            entry.goal.tick(ctx, control);
+        }
+    }
+}
+
+/// The tick at which a mob was last hurt, used by `Panic`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LastHurt(pub u64);
+
+/// Goals for passive animals: panic when hurt, follow
+/// their parent while young, wander and look at players.
+pub fn passive_goals() -> Goals {
+    Goals::new()
+        .with(1, Panic::new(0.25))
+        .with(4, FollowParent::new(0.18))
+        .with(5, Wander::new(0.15))
+        .with(6, LookAtPlayer::new(6.0))
+}
+
+/// Goals for animals which run from players, such as ocelots.
+pub fn skittish_goals() -> Goals {
+    Goals::new()
+        .with(1, Panic::new(0.3))
+        .with(2, AvoidPlayer::new(8.0, 0.25))
+        .with(4, FollowParent::new(0.18))
+        .with(5, Wander::new(0.15))
+        .with(6, LookAtPlayer::new(6.0))
+}
+
+/// Goals for neutral mobs, which wander and look at players.
+pub fn neutral_goals() -> Goals {
+    Goals::new()
+        .with(4, FollowParent::new(0.18))
+        .with(5, Wander::new(0.15))
+        .with(6, LookAtPlayer::new(8.0))
+}
+
+/// Goals for hostile mobs which chase and hit the nearest player,
+/// dealing `damage` half-hearts per hit.
+pub fn hostile_goals(damage: u32) -> Goals {
+    Goals::new()
+        .with(2, MeleeAttack::new(damage, 0.2))
+        .with(7, Wander::new(0.15))
+        .with(8, LookAtPlayer::new(8.0))
+}
+
+/// Goals for hostile mobs which do not attack in melee,
+/// such as skeletons and creepers.
+pub fn idle_goals() -> Goals {
+    Goals::new()
+        .with(7, Wander::new(0.15))
+        .with(8, LookAtPlayer::new(8.0))
+}
+
+/// System which runs mob goals and moves mobs
+/// along their paths.
+#[fecs::system]
+pub fn tick_mob_ai(game: &mut Game, world: &mut World) {
+    let mut mobs = BumpVec::new_in(game.bump());
+    mobs.extend(
+        <(Read<Goals>, Read<Position>)>::query()
+            .filter(!component::<Dead>())
+            .iter_entities(world.inner())
+            .map(|(entity, _)| entity),
+    );
+
+    let mut attacks = BumpVec::new_in(game.bump());
+    for entity in mobs {
+        let mut goals = std::mem::take(&mut *world.get_mut::<Goals>(entity));
+        let mut pos = *world.get::<Position>(entity);
+
+        let ctx = GoalContext {
+            game: &*game,
+            world: &*world,
+            entity,
+            pos,
+        };
+        goals.select(&ctx);
+
+        let moved = goals.control.navigator.step(game, &mut pos);
+        let look_at = goals
+            .control
+            .look_at
+            .map(|target| (target.x - pos.x, target.y - pos.y, target.z - pos.z));
+        if let Some((dx, dy, dz)) = look_at.or(moved) {
+            let (yaw, pitch) = rotation(dx, dy, dz, look_at.is_some());
+            pos.yaw = yaw;
+            pos.pitch = pitch;
+        }
+
+        if let Some(attack) = goals.control.attack.take() {
+            attacks.push(attack);
+        }
+
+        *world.get_mut::<Position>(entity) = pos;
+        *world.get_mut::<Goals>(entity) = goals;
+    }
+
+    for (target, damage) in attacks {
+        if world.is_alive(target) {
+            game.damage(target, damage, world);
+        }
+    }
+}
+
+/// Records when mobs with AI are hurt so that they can panic.
+#[fecs::event_handler]
+pub fn on_health_update_record_hurt(event: &HealthUpdateEvent, game: &mut Game, world: &mut World) {
+    if event.new < event.old
+        && world.has::<Goals>(event.entity)
+        && world.has::<MobKind>(event.entity)
+    {
+        let _ = world.add(event.entity, LastHurt(game.tick_count));
+    }
+}
+
+/// Returns the yaw and pitch, in degrees, of a mob facing in the
+/// given direction. Walking mobs keep their head level.
+fn rotation(dx: f64, dy: f64, dz: f64, with_pitch: bool) -> (f32, f32) {
+    let yaw = (-dx).atan2(dz).to_degrees();
+    let pitch = if with_pitch {
+        -dy.atan2((dx * dx + dz * dz).sqrt()).to_degrees()
+    } else {
+        0.0
+    };
+    (yaw as f32, pitch as f32)
+}
+
+/// Returns the position of a player's eyes.
+fn eye_position(pos: Position) -> Position {
+    Position {
+        y: pos.y + PLAYER_EYE_HEIGHT,
+        ..pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::blocks::BlockId;
+    use feather_core::position;
+    use feather_server_types::Health;
+    use feather_test_framework::Test;
+
+    fn floor(test: &mut Test) {
+        for x in -16..32 {
+            for z in -16..32 {
+                test.game
+                    .chunk_map
+                    .set_block_at(BlockPosition::new(x, 63, z), BlockId::stone());
+            }
+        }
+    }
+
+    #[test]
+    fn test_zombie_attacks_player() {
+        let mut test = Test::new();
+        floor(&mut test);
+
+        let player = test.player("player", position!(4.5, 64.0, 0.5));
+        test.world.add(player, CanTakeDamage).unwrap();
+        let zombie = test.entity(
+            crate::mob::zombie::create()
+                .with(position!(0.5, 64.0, 0.5))
+                .with(Health(20)),
+        );
+
+        test.run(tick_mob_ai);
+        let pos = *test.world.get::<Position>(zombie);
+        assert!(pos.x > 0.5);
+        // Facing the player, who is in the +x direction.
+        assert!((pos.yaw + 90.0).abs() < 1.0);
+
+        for _ in 0..40 {
+            test.run(tick_mob_ai);
+        }
+        assert!(test.world.get::<Health>(player).0 < 20);
+    }
+
+    #[test]
+    fn test_goal_priority() {
+        let mut test = Test::new();
+        floor(&mut test);
+
+        let mut goals = hostile_goals(3);
+        let zombie = test.entity(crate::mob::base(MobKind::Zombie).with(position!(0.5, 64.0, 0.5)));
+        let pos = *test.world.get::<Position>(zombie);
+
+        // With no player around, the attack goal doesn't start.
+        goals.select(&GoalContext {
+            game: &test.game,
+            world: &test.world,
+            entity: zombie,
+            pos,
+        });
+        assert!(!goals.entries[0].running);
+
+        let player = test.player("player", position!(3.5, 64.0, 0.5));
+        test.world.add(player, CanTakeDamage).unwrap();
+        goals.select(&GoalContext {
+            game: &test.game,
+            world: &test.world,
+            entity: zombie,
+            pos,
+        });
+        assert!(goals.entries[0].running);
+        // Wandering and looking around can't run alongside the attack.
+        assert!(goals.entries[1..].iter().all(|entry| !entry.running));
+        assert_eq!(
+            goals.control().navigator.goal(),
+            Some(BlockPosition::new(3, 64, 0))
+        );
+    }
+
+    #[test]
+    fn test_rotation() {
+        let (yaw, pitch) = rotation(0.0, 0.0, 1.0, true);
+        assert_eq!((yaw, pitch), (0.0, 0.0));
+        let (yaw, _) = rotation(1.0, 0.0, 0.0, false);
+        assert!((yaw + 90.0).abs() < 0.001);
+        let (_, pitch) = rotation(0.0, 1.0, 1.0, true);
+        assert!((pitch + 45.0).abs() < 0.001);
+    }
+}
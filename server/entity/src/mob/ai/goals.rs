@@ -0,0 +1,427 @@
+//! Goals shared by many mobs.
+
+use super::pathfinding::node_at;
+use super::{eye_position, Controls, Goal, GoalContext, LastHurt, MobControl};
+use crate::mob::{Age, MobKind};
+use feather_core::util::{BlockPosition, Position};
+use feather_server_types::CanTakeDamage;
+use fecs::{Entity, IntoQuery, Read};
+use rand::Rng;
+
+/// Number of ticks a mob panics for after being hurt.
+const PANIC_TICKS: u64 = 100;
+
+/// Number of ticks between recalculating paths to moving targets.
+const REPATH_INTERVAL: u32 = 10;
+
+/// Walks to random nearby positions every now and then.
+pub struct Wander {
+    speed: f64,
+    target: Option<BlockPosition>,
+}
+
+impl Wander {
+    pub fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            target: None,
+        }
+    }
+}
+
+impl Goal for Wander {
+    fn controls(&self) -> Controls {
+        Controls::MOVE
+    }
+
+    fn should_start(&mut self, ctx: &GoalContext) -> bool {
+        // On average, mobs start wandering every six seconds.
+        if ctx.game.rng().gen_range(0, 120) != 0 {
+            return false;
+        }
+        self.target = ctx.random_position(10, 7);
+        self.target.is_some()
+    }
+
+    fn should_continue(&mut self, _ctx: &GoalContext) -> bool {
+        self.target.is_some()
+    }
+
+    fn start(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        if let Some(target) = self.target {
+            if !control
+                .navigator
+                .navigate(ctx.game, ctx.pos, target, self.speed)
+            {
+                self.target = None;
+            }
+        }
+    }
+
+    fn tick(&mut self, _ctx: &GoalContext, control: &mut MobControl) {
+        if control.navigator.is_idle() {
+            self.target = None;
+        }
+    }
+
+    fn stop(&mut self, control: &mut MobControl) {
+        self.target = None;
+        control.navigator.stop();
+    }
+}
+
+/// Looks at a nearby player for a few seconds.
+pub struct LookAtPlayer {
+    range: f64,
+    target: Option<Entity>,
+    ticks_left: u32,
+}
+
+impl LookAtPlayer {
+    pub fn new(range: f64) -> Self {
+        Self {
+            range,
+            target: None,
+            ticks_left: 0,
+        }
+    }
+}
+
+impl Goal for LookAtPlayer {
+    fn controls(&self) -> Controls {
+        Controls::LOOK
+    }
+
+    fn should_start(&mut self, ctx: &GoalContext) -> bool {
+        if ctx.game.rng().gen_range(0, 50) != 0 {
+            return false;
+        }
+        self.target = ctx.nearest_player(self.range).map(|(player, _)| player);
+        self.target.is_some()
+    }
+
+    fn should_continue(&mut self, ctx: &GoalContext) -> bool {
+        let range = self.range;
+        self.ticks_left > 0
+            && self
+                .target
+                .and_then(|target| ctx.living_position(target))
+                .map_or(false, |pos| {
+                    pos.distance_squared_to(ctx.pos) <= range * range
+                })
+    }
+
+    fn start(&mut self, ctx: &GoalContext, _control: &mut MobControl) {
+        self.ticks_left = 40 + ctx.game.rng().gen_range(0, 40);
+    }
+
+    fn tick(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        self.ticks_left = self.ticks_left.saturating_sub(1);
+        control.look_at = self
+            .target
+            .and_then(|target| ctx.living_position(target))
+            .map(eye_position);
+    }
+
+    fn stop(&mut self, control: &mut MobControl) {
+        self.target = None;
+        control.look_at = None;
+    }
+}
+
+/// Runs to a random nearby position after being hurt.
+pub struct Panic {
+    speed: f64,
+    running: bool,
+}
+
+impl Panic {
+    pub fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            running: false,
+        }
+    }
+}
+
+impl Goal for Panic {
+    fn controls(&self) -> Controls {
+        Controls::MOVE
+    }
+
+    fn should_start(&mut self, ctx: &GoalContext) -> bool {
+        ctx.world
+            .try_get::<LastHurt>(ctx.entity)
+            .map_or(false, |hurt| ctx.game.tick_count < hurt.0 + PANIC_TICKS)
+    }
+
+    fn should_continue(&mut self, ctx: &GoalContext) -> bool {
+        // Keep running to new positions until the panic wears off.
+        self.running || self.should_start(ctx)
+    }
+
+    fn start(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        self.running = false;
+        if let Some(target) = ctx.random_position(5, 4) {
+            self.running = control
+                .navigator
+                .navigate(ctx.game, ctx.pos, target, self.speed);
+        }
+    }
+
+    fn tick(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        if control.navigator.is_idle() {
+            self.running = false;
+            if self.should_start(ctx) {
+                self.start(ctx, control);
+            }
+        }
+    }
+
+    fn stop(&mut self, control: &mut MobControl) {
+        self.running = false;
+        control.navigator.stop();
+    }
+}
+
+/// Runs away from players who come too close.
+pub struct AvoidPlayer {
+    distance: f64,
+    speed: f64,
+    target: Option<BlockPosition>,
+}
+
+impl AvoidPlayer {
+    pub fn new(distance: f64, speed: f64) -> Self {
+        Self {
+            distance,
+            speed,
+            target: None,
+        }
+    }
+}
+
+impl Goal for AvoidPlayer {
+    fn controls(&self) -> Controls {
+        Controls::MOVE
+    }
+
+    fn should_start(&mut self, ctx: &GoalContext) -> bool {
+        let (_, player) = match ctx.nearest_player(self.distance) {
+            Some(player) => player,
+            None => return false,
+        };
+
+        // Flee to a position further from the player than the mob is now.
+        let away = (ctx.pos.x - player.x, ctx.pos.z - player.z);
+        self.target = ctx
+            .random_position_towards(16, 7, Some(away))
+            .filter(|target| {
+                target.position().distance_squared_to(player) > ctx.pos.distance_squared_to(player)
+            });
+        self.target.is_some()
+    }
+
+    fn should_continue(&mut self, _ctx: &GoalContext) -> bool {
+        self.target.is_some()
+    }
+
+    fn start(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        if let Some(target) = self.target {
+            if !control
+                .navigator
+                .navigate(ctx.game, ctx.pos, target, self.speed)
+            {
+                self.target = None;
+            }
+        }
+    }
+
+    fn tick(&mut self, _ctx: &GoalContext, control: &mut MobControl) {
+        if control.navigator.is_idle() {
+            self.target = None;
+        }
+    }
+
+    fn stop(&mut self, control: &mut MobControl) {
+        self.target = None;
+        control.navigator.stop();
+    }
+}
+
+/// Makes baby animals follow an adult of the same kind.
+pub struct FollowParent {
+    speed: f64,
+    parent: Option<Entity>,
+    repath_in: u32,
+}
+
+impl FollowParent {
+    pub fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            parent: None,
+            repath_in: 0,
+        }
+    }
+}
+
+impl Goal for FollowParent {
+    fn controls(&self) -> Controls {
+        Controls::MOVE
+    }
+
+    fn should_start(&mut self, ctx: &GoalContext) -> bool {
+        let is_baby = ctx
+            .world
+            .try_get::<Age>(ctx.entity)
+            .map_or(false, |age| age.0 < 0);
+        if !is_baby {
+            return false;
+        }
+
+        let kind = *ctx.world.get::<MobKind>(ctx.entity);
+        self.parent = <(Read<MobKind>, Read<Position>)>::query()
+            .iter_entities(ctx.world.inner())
+            .filter(|(entity, (other_kind, _))| {
+                *entity != ctx.entity
+                    && **other_kind == kind
+                    && ctx
+                        .world
+                        .try_get::<Age>(*entity)
+                        .map_or(true, |age| age.0 >= 0)
+            })
+            .map(|(entity, (_, pos))| (entity, pos.distance_squared_to(ctx.pos)))
+            .filter(|(_, distance)| *distance <= 8.0 * 8.0)
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+            .filter(|(_, distance)| *distance >= 3.0 * 3.0)
+            .map(|(entity, _)| entity);
+        self.parent.is_some()
+    }
+
+    fn should_continue(&mut self, ctx: &GoalContext) -> bool {
+        self.parent
+            .and_then(|parent| ctx.living_position(parent))
+            .map_or(false, |pos| {
+                let distance = pos.distance_squared_to(ctx.pos);
+                distance >= 3.0 * 3.0 && distance <= 16.0 * 16.0
+            })
+    }
+
+    fn start(&mut self, _ctx: &GoalContext, _control: &mut MobControl) {
+        self.repath_in = 0;
+    }
+
+    fn tick(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        if self.repath_in > 0 {
+            self.repath_in -= 1;
+            return;
+        }
+        self.repath_in = REPATH_INTERVAL;
+
+        if let Some(pos) = self.parent.and_then(|parent| ctx.living_position(parent)) {
+            control
+                .navigator
+                .navigate(ctx.game, ctx.pos, node_at(pos), self.speed);
+        }
+    }
+
+    fn stop(&mut self, control: &mut MobControl) {
+        self.parent = None;
+        control.navigator.stop();
+    }
+}
+
+/// Chases the nearest player who can be attacked
+/// and hits them when in reach.
+pub struct MeleeAttack {
+    damage: u32,
+    speed: f64,
+    target: Option<Entity>,
+    cooldown: u32,
+    repath_in: u32,
+}
+
+impl MeleeAttack {
+    /// Range within which mobs notice players.
+    const FOLLOW_RANGE: f64 = 16.0;
+    /// Distance within which mobs can hit players.
+    const REACH: f64 = 2.0;
+    /// Ticks between hits.
+    const ATTACK_INTERVAL: u32 = 20;
+
+    pub fn new(damage: u32, speed: f64) -> Self {
+        Self {
+            damage,
+            speed,
+            target: None,
+            cooldown: 0,
+            repath_in: 0,
+        }
+    }
+}
+
+impl Goal for MeleeAttack {
+    fn controls(&self) -> Controls {
+        Controls::MOVE | Controls::LOOK
+    }
+
+    fn should_start(&mut self, ctx: &GoalContext) -> bool {
+        self.target = ctx
+            .nearest_target(Self::FOLLOW_RANGE)
+            .map(|(player, _)| player);
+        self.target.is_some()
+    }
+
+    fn should_continue(&mut self, ctx: &GoalContext) -> bool {
+        self.target
+            .filter(|target| ctx.world.has::<CanTakeDamage>(*target))
+            .and_then(|target| ctx.living_position(target))
+            .map_or(false, |pos| {
+                // Mobs keep chasing players a little
+                // further than they notice them.
+                pos.distance_squared_to(ctx.pos) <= square(Self::FOLLOW_RANGE * 2.0)
+            })
+    }
+
+    fn start(&mut self, _ctx: &GoalContext, _control: &mut MobControl) {
+        self.repath_in = 0;
+    }
+
+    fn tick(&mut self, ctx: &GoalContext, control: &mut MobControl) {
+        self.cooldown = self.cooldown.saturating_sub(1);
+
+        let (target, pos) = match self
+            .target
+            .and_then(|target| Some((target, ctx.living_position(target)?)))
+        {
+            Some(target) => target,
+            None => return,
+        };
+        control.look_at = Some(eye_position(pos));
+
+        if self.repath_in > 0 {
+            self.repath_in -= 1;
+        } else {
+            self.repath_in = REPATH_INTERVAL;
+            control
+                .navigator
+                .navigate(ctx.game, ctx.pos, node_at(pos), self.speed);
+        }
+
+        if self.cooldown == 0 && pos.distance_squared_to(ctx.pos) <= square(Self::REACH) {
+            control.attack = Some((target, self.damage));
+            self.cooldown = Self::ATTACK_INTERVAL;
+        }
+    }
+
+    fn stop(&mut self, control: &mut MobControl) {
+        self.target = None;
+        control.look_at = None;
+        control.navigator.stop();
+    }
+}
+
+fn square(x: f64) -> f64 {
+    x * x
+}
@@ -0,0 +1,370 @@
+//! A* pathfinding for walking mobs.
+//!
+//! Paths are made of nodes, each the block position a mob's
+//! feet occupy while standing on the ground. A mob may walk
+//! to a horizontally adjacent node, jump up one block or drop
+//! down at most `MAX_DROP` blocks.
+
+use feather_core::blocks::{BlockId, SimplifiedBlockKind};
+use feather_core::chunk_map::ChunkMap;
+use feather_core::util::{BlockPosition, Position};
+use feather_server_physics::bbox_for_block;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Maximum number of nodes visited before a search gives up.
+pub const MAX_VISITED_NODES: usize = 1024;
+
+/// Maximum number of blocks a mob will drop down in one step.
+pub const MAX_DROP: i32 = 3;
+
+/// Offsets to the horizontally adjacent nodes.
+const NEIGHBORS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// A path found by `find_path`, from the start node
+/// (exclusive) to the goal node (inclusive).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    pub nodes: Vec<BlockPosition>,
+}
+
+impl Path {
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the last node in the path.
+    pub fn goal(&self) -> Option<BlockPosition> {
+        self.nodes.last().copied()
+    }
+}
+
+/// Finds the shortest walking path between two nodes,
+/// or `None` if there is none within `MAX_VISITED_NODES`.
+///
+/// Blocks in unloaded chunks are treated as impassable.
+pub fn find_path(chunk_map: &ChunkMap, start: BlockPosition, goal: BlockPosition) -> Option<Path> {
+    if !can_stand_at(chunk_map, goal) {
+        return None;
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<BlockPosition, BlockPosition> = HashMap::new();
+    let mut costs: HashMap<BlockPosition, u32> = HashMap::new();
+
+    costs.insert(start, 0);
+    open.push(OpenNode {
+        pos: start,
+        estimate: heuristic(start, goal),
+    });
+
+    let mut visited = 0;
+    while let Some(OpenNode { pos, .. }) = open.pop() {
+        if pos == goal {
+            return Some(reconstruct(&came_from, start, goal));
+        }
+
+        visited += 1;
+        if visited > MAX_VISITED_NODES {
+            break;
+        }
+
+        let cost = costs[&pos];
+        for neighbor in neighbors(chunk_map, pos) {
+            let neighbor_cost = cost + step_cost(pos, neighbor);
+            if costs
+                .get(&neighbor)
+                .map_or(true, |existing| neighbor_cost < *existing)
+            {
+                costs.insert(neighbor, neighbor_cost);
+                came_from.insert(neighbor, pos);
+                open.push(OpenNode {
+                    pos: neighbor,
+                    estimate: neighbor_cost + heuristic(neighbor, goal),
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Determines whether a mob can stand with its
+/// feet in the given block.
+pub fn can_stand_at(chunk_map: &ChunkMap, pos: BlockPosition) -> bool {
+    let below = match chunk_map.block_at(pos.down()) {
+        Some(block) => block,
+        None => return false,
+    };
+
+    collision_height(below).is_some()
+        && !is_dangerous(below)
+        && is_passable(chunk_map, pos)
+        && is_passable(chunk_map, pos.up())
+}
+
+/// Returns the node of a mob at the given position. Mobs standing
+/// on blocks lower than a full block, such as slabs, are on the
+/// node above that block.
+pub fn node_at(pos: Position) -> BlockPosition {
+    BlockPosition::new(
+        pos.x.floor() as i32,
+        (pos.y + 0.5).floor() as i32,
+        pos.z.floor() as i32,
+    )
+}
+
+/// Returns the y coordinate a mob standing on the given node is at,
+/// accounting for blocks below it which are lower than a full block.
+pub fn standing_height(chunk_map: &ChunkMap, pos: BlockPosition) -> f64 {
+    let below = chunk_map
+        .block_at(pos.down())
+        .and_then(collision_height)
+        .unwrap_or(1.0);
+    f64::from(pos.y - 1) + below
+}
+
+/// Returns the nodes reachable in one step from the given node.
+fn neighbors(chunk_map: &ChunkMap, pos: BlockPosition) -> impl Iterator<Item = BlockPosition> {
+    let mut result = Vec::with_capacity(NEIGHBORS.len());
+
+    for (dx, dz) in NEIGHBORS.iter().copied() {
+        let adjacent = BlockPosition::new(pos.x + dx, pos.y, pos.z + dz);
+
+        if can_stand_at(chunk_map, adjacent) {
+            result.push(adjacent);
+            continue;
+        }
+
+        // Jump up, which needs room above the mob's head.
+        let up = adjacent.up();
+        if can_stand_at(chunk_map, up) && is_passable(chunk_map, pos.up().up()) {
+            result.push(up);
+            continue;
+        }
+
+        // Walk off the edge and fall.
+        if !is_passable(chunk_map, adjacent) || !is_passable(chunk_map, adjacent.up()) {
+            continue;
+        }
+        let mut below = adjacent;
+        for _ in 0..MAX_DROP {
+            below = below.down();
+            if can_stand_at(chunk_map, below) {
+                result.push(below);
+                break;
+            }
+            if !is_passable(chunk_map, below) {
+                break;
+            }
+        }
+    }
+
+    result.into_iter()
+}
+
+/// Determines whether a mob's body can occupy the given block.
+fn is_passable(chunk_map: &ChunkMap, pos: BlockPosition) -> bool {
+    match chunk_map.block_at(pos) {
+        Some(block) => collision_height(block).is_none() && !is_dangerous(block),
+        None => false,
+    }
+}
+
+/// Returns the height of a block's collision shape,
+/// or `None` if it has none.
+fn collision_height(block: BlockId) -> Option<f64> {
+    if block.is_solid() {
+        Some(bbox_for_block(block).maxs().y)
+    } else {
+        None
+    }
+}
+
+/// Blocks which hurt mobs walking into or onto them.
+fn is_dangerous(block: BlockId) -> bool {
+    matches!(
+        block.simplified_kind(),
+        SimplifiedBlockKind::Lava
+            | SimplifiedBlockKind::Fire
+            | SimplifiedBlockKind::Cactus
+            | SimplifiedBlockKind::MagmaBlock
+    )
+}
+
+fn step_cost(from: BlockPosition, to: BlockPosition) -> u32 {
+    // Prefer level paths over jumping and falling.
+    if from.y == to.y {
+        2
+    } else {
+        3
+    }
+}
+
+/// Horizontal Manhattan distance, which never overestimates
+/// the cost of a path since each step moves one block horizontally.
+fn heuristic(from: BlockPosition, to: BlockPosition) -> u32 {
+    (((from.x - to.x).abs() + (from.z - to.z).abs()) * 2) as u32
+}
+
+fn reconstruct(
+    came_from: &HashMap<BlockPosition, BlockPosition>,
+    start: BlockPosition,
+    goal: BlockPosition,
+) -> Path {
+    let mut nodes = vec![goal];
+    let mut current = goal;
+    while let Some(previous) = came_from.get(&current).copied() {
+        if previous == start {
+            break;
+        }
+        nodes.push(previous);
+        current = previous;
+    }
+    nodes.reverse();
+    Path { nodes }
+}
+
+/// A node in the open set, ordered so that `BinaryHeap`
+/// pops the node with the lowest estimated total cost.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct OpenNode {
+    pos: BlockPosition,
+    estimate: u32,
+}
+
+impl Ord for OpenNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.pos.cmp(&other.pos))
+    }
+}
+
+impl PartialOrd for OpenNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::chunk::Chunk;
+    use feather_core::position;
+    use feather_core::util::ChunkPosition;
+
+    /// A single chunk with a stone floor at y = 63.
+    fn chunk_map() -> ChunkMap {
+        let mut chunk_map = ChunkMap::new();
+        chunk_map.insert(Chunk::new(ChunkPosition::new(0, 0)));
+        for x in 0..16 {
+            for z in 0..16 {
+                chunk_map.set_block_at(BlockPosition::new(x, 63, z), BlockId::stone());
+            }
+        }
+        chunk_map
+    }
+
+    fn wall(chunk_map: &ChunkMap, x: i32, zs: std::ops::Range<i32>, height: i32) {
+        for z in zs {
+            for y in 64..64 + height {
+                chunk_map.set_block_at(BlockPosition::new(x, y, z), BlockId::stone());
+            }
+        }
+    }
+
+    #[test]
+    fn test_straight_line() {
+        let chunk_map = chunk_map();
+        let start = BlockPosition::new(0, 64, 0);
+        let goal = BlockPosition::new(5, 64, 0);
+
+        let path = find_path(&chunk_map, start, goal).unwrap();
+        assert_eq!(path.nodes.len(), 5);
+        assert_eq!(path.goal(), Some(goal));
+        assert!(path.nodes.iter().all(|node| node.y == 64 && node.z == 0));
+    }
+
+    #[test]
+    fn test_around_wall() {
+        let chunk_map = chunk_map();
+        // Two-high wall with a gap at z = 5.
+        wall(&chunk_map, 3, 0..5, 2);
+        wall(&chunk_map, 3, 6..16, 2);
+
+        let start = BlockPosition::new(0, 64, 0);
+        let goal = BlockPosition::new(6, 64, 0);
+        let path = find_path(&chunk_map, start, goal).unwrap();
+
+        assert_eq!(path.goal(), Some(goal));
+        assert!(path.nodes.contains(&BlockPosition::new(3, 64, 5)));
+        // 6 blocks east plus 5 blocks to the gap and back.
+        assert_eq!(path.nodes.len(), 16);
+    }
+
+    #[test]
+    fn test_jump_and_drop() {
+        let chunk_map = chunk_map();
+        // A one-high step can be jumped onto.
+        wall(&chunk_map, 2, 0..16, 1);
+
+        let start = BlockPosition::new(0, 64, 0);
+        let goal = BlockPosition::new(4, 64, 0);
+        let path = find_path(&chunk_map, start, goal).unwrap();
+
+        assert_eq!(
+            path.nodes,
+            vec![
+                BlockPosition::new(1, 64, 0),
+                BlockPosition::new(2, 65, 0),
+                BlockPosition::new(3, 64, 0),
+                BlockPosition::new(4, 64, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_blocked() {
+        let chunk_map = chunk_map();
+        // A two-high wall across the whole chunk can't be crossed.
+        wall(&chunk_map, 3, 0..16, 2);
+
+        let start = BlockPosition::new(0, 64, 0);
+        let goal = BlockPosition::new(6, 64, 0);
+        assert!(find_path(&chunk_map, start, goal).is_none());
+
+        // Nor can the goal be in a wall or in the air.
+        assert!(find_path(&chunk_map, start, BlockPosition::new(3, 64, 0)).is_none());
+        assert!(find_path(&chunk_map, start, BlockPosition::new(1, 66, 0)).is_none());
+    }
+
+    #[test]
+    fn test_avoids_lava() {
+        let chunk_map = chunk_map();
+        for z in 0..15 {
+            chunk_map.set_block_at(BlockPosition::new(3, 64, z), BlockId::lava());
+        }
+
+        let start = BlockPosition::new(0, 64, 0);
+        let goal = BlockPosition::new(6, 64, 0);
+        let path = find_path(&chunk_map, start, goal).unwrap();
+        assert!(path.nodes.contains(&BlockPosition::new(3, 64, 15)));
+    }
+
+    #[test]
+    fn test_slab_height() {
+        let chunk_map = chunk_map();
+        chunk_map.set_block_at(BlockPosition::new(1, 64, 0), BlockId::oak_slab());
+
+        let on_slab = BlockPosition::new(1, 65, 0);
+        assert!(can_stand_at(&chunk_map, on_slab));
+        assert_eq!(standing_height(&chunk_map, on_slab), 64.5);
+        assert_eq!(
+            standing_height(&chunk_map, BlockPosition::new(0, 64, 0)),
+            64.0
+        );
+        assert_eq!(node_at(position!(1.5, 64.5, 0.5)), on_slab);
+    }
+}
@@ -5,8 +5,10 @@ pub mod elder_guardian;
 pub mod endermite;
 pub mod evoker;
 pub mod ghast;
+pub mod giant;
 pub mod guardian;
 pub mod husk;
+pub mod illusioner;
 pub mod magma_cube;
 pub mod phantom;
 pub mod shulker;
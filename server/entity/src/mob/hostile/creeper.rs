@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Creeper;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Creeper).with(Creeper)
+    mob::base(MobKind::Creeper)
+        .with(Creeper)
+        .with(ai::idle_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Drowned;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Drowned).with(Drowned)
+    mob::base(MobKind::Drowned)
+        .with(Drowned)
+        .with(ai::hostile_goals(3))
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Endermite;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Endermite).with(Endermite)
+    mob::base(MobKind::Endermite)
+        .with(Endermite)
+        .with(ai::hostile_goals(2))
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Evoker;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::EvocationIllager).with(Evoker)
+    mob::base(MobKind::EvocationIllager)
+        .with(Evoker)
+        .with(ai::idle_goals())
 }
@@ -0,0 +1,10 @@
+use crate::mob::{self, ai, MobKind};
+use fecs::EntityBuilder;
+
+pub struct Giant;
+
+pub fn create() -> EntityBuilder {
+    mob::base(MobKind::Giant)
+        .with(Giant)
+        .with(ai::hostile_goals(50))
+}
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Husk;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Husk).with(Husk)
+    mob::base(MobKind::Husk)
+        .with(Husk)
+        .with(ai::hostile_goals(3))
 }
@@ -0,0 +1,10 @@
+use crate::mob::{self, ai, MobKind};
+use fecs::EntityBuilder;
+
+pub struct Illusioner;
+
+pub fn create() -> EntityBuilder {
+    mob::base(MobKind::IllusionIllager)
+        .with(Illusioner)
+        .with(ai::idle_goals())
+}
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Silverfish;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Silverfish).with(Silverfish)
+    mob::base(MobKind::Silverfish)
+        .with(Silverfish)
+        .with(ai::hostile_goals(1))
 }
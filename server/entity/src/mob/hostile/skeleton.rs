@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Skeleton;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Skeleton).with(Skeleton)
+    mob::base(MobKind::Skeleton)
+        .with(Skeleton)
+        .with(ai::idle_goals())
 }
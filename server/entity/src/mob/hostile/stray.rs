@@ -1,8 +1,8 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Stray;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Stray).with(Stray)
+    mob::base(MobKind::Stray).with(Stray).with(ai::idle_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Vindicator;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::VindicationIllager).with(Vindicator)
+    mob::base(MobKind::VindicationIllager)
+        .with(Vindicator)
+        .with(ai::hostile_goals(13))
 }
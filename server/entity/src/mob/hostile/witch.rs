@@ -1,8 +1,8 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Witch;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Witch).with(Witch)
+    mob::base(MobKind::Witch).with(Witch).with(ai::idle_goals())
 }
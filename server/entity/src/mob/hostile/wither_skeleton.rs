@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct WitherSkeleton;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::WitherSkeleton).with(WitherSkeleton)
+    mob::base(MobKind::WitherSkeleton)
+        .with(WitherSkeleton)
+        .with(ai::hostile_goals(8))
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Zombie;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Zombie).with(Zombie)
+    mob::base(MobKind::Zombie)
+        .with(Zombie)
+        .with(ai::hostile_goals(3))
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct ZombieVillager;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::ZombieVillager).with(ZombieVillager)
+    mob::base(MobKind::ZombieVillager)
+        .with(ZombieVillager)
+        .with(ai::hostile_goals(3))
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct CaveSpider;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::CaveSpider).with(CaveSpider)
+    mob::base(MobKind::CaveSpider)
+        .with(CaveSpider)
+        .with(ai::hostile_goals(2))
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Enderman;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Enderman).with(Enderman)
+    mob::base(MobKind::Enderman)
+        .with(Enderman)
+        .with(ai::neutral_goals())
 }
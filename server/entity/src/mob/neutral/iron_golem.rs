@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct IronGolem;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::IronGolem).with(IronGolem)
+    mob::base(MobKind::IronGolem)
+        .with(IronGolem)
+        .with(ai::neutral_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Llama;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Llama).with(Llama)
+    mob::base(MobKind::Llama)
+        .with(Llama)
+        .with(ai::neutral_goals())
 }
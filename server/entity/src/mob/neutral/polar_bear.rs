@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct PolarBear;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::PolarBear).with(PolarBear)
+    mob::base(MobKind::PolarBear)
+        .with(PolarBear)
+        .with(ai::neutral_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Spider;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Spider).with(Spider)
+    mob::base(MobKind::Spider)
+        .with(Spider)
+        .with(ai::hostile_goals(2))
 }
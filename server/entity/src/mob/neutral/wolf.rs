@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Wolf;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Wolf).with(Wolf)
+    mob::base(MobKind::Wolf)
+        .with(Wolf)
+        .with(ai::neutral_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct ZombiePigman;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::PigZombie).with(ZombiePigman)
+    mob::base(MobKind::PigZombie)
+        .with(ZombiePigman)
+        .with(ai::neutral_goals())
 }
@@ -19,5 +19,6 @@ pub mod squid;
 pub mod tropical_fish;
 pub mod turtle;
 pub mod villager;
+pub mod zombie_horse;
 
 // Base components for all passive mobs.
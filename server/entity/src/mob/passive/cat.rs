@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Cat;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Ocelot).with(Cat)
+    mob::base(MobKind::Ocelot)
+        .with(Cat)
+        .with(ai::skittish_goals())
 }
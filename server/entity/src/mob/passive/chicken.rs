@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Chicken;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Chicken).with(Chicken)
+    mob::base(MobKind::Chicken)
+        .with(Chicken)
+        .with(ai::passive_goals())
 }
@@ -1,8 +1,8 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Cow;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Cow).with(Cow)
+    mob::base(MobKind::Cow).with(Cow).with(ai::passive_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Donkey;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Donkey).with(Donkey)
+    mob::base(MobKind::Donkey)
+        .with(Donkey)
+        .with(ai::passive_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Horse;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Horse).with(Horse)
+    mob::base(MobKind::Horse)
+        .with(Horse)
+        .with(ai::passive_goals())
 }
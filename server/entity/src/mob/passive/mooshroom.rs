@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Mooshroom;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::MushroomCow).with(Mooshroom)
+    mob::base(MobKind::MushroomCow)
+        .with(Mooshroom)
+        .with(ai::passive_goals())
 }
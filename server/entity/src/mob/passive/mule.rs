@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Mule;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Mule).with(Mule)
+    mob::base(MobKind::Mule)
+        .with(Mule)
+        .with(ai::passive_goals())
 }
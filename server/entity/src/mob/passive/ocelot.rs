@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Ocelot;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Ocelot).with(Ocelot)
+    mob::base(MobKind::Ocelot)
+        .with(Ocelot)
+        .with(ai::skittish_goals())
 }
@@ -1,8 +1,8 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Pig;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Pig).with(Pig)
+    mob::base(MobKind::Pig).with(Pig).with(ai::passive_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Rabbit;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Rabbit).with(Rabbit)
+    mob::base(MobKind::Rabbit)
+        .with(Rabbit)
+        .with(ai::skittish_goals())
 }
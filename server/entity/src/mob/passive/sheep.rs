@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Sheep;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Sheep).with(Sheep)
+    mob::base(MobKind::Sheep)
+        .with(Sheep)
+        .with(ai::passive_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct SkeletonHorse;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Horse).with(SkeletonHorse)
+    mob::base(MobKind::Horse)
+        .with(SkeletonHorse)
+        .with(ai::passive_goals())
 }
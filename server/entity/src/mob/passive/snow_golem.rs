@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct SnowGolem;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::SnowGolem).with(SnowGolem)
+    mob::base(MobKind::SnowGolem)
+        .with(SnowGolem)
+        .with(ai::idle_goals())
 }
@@ -1,8 +1,10 @@
-use crate::{mob, MobKind};
+use crate::mob::{self, ai, MobKind};
 use fecs::EntityBuilder;
 
 pub struct Villager;
 
 pub fn create() -> EntityBuilder {
-    mob::base(MobKind::Villager).with(Villager)
+    mob::base(MobKind::Villager)
+        .with(Villager)
+        .with(ai::passive_goals())
 }
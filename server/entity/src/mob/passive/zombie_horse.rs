@@ -0,0 +1,10 @@
+use crate::mob::{self, ai, MobKind};
+use fecs::EntityBuilder;
+
+pub struct ZombieHorse;
+
+pub fn create() -> EntityBuilder {
+    mob::base(MobKind::ZombieHorse)
+        .with(ZombieHorse)
+        .with(ai::passive_goals())
+}
@@ -0,0 +1,272 @@
+//! Loading and saving of mobs to chunk entity data.
+
+use super::*;
+use feather_core::anvil::entity::{
+    AttributeData, BaseEntityData, EntityData, EntityDataKind, MobData,
+};
+use feather_core::util::{Position, Vec3d};
+use feather_server_types::{
+    ComponentSerializer, EntityLoaderRegistration, Game, Health, MaxHealth, Velocity,
+};
+use fecs::{EntityBuilder, EntityRef};
+
+/// Name of the attribute holding a mob's maximum health.
+const MAX_HEALTH_ATTRIBUTE: &str = "generic.maxHealth";
+
+/// The name tag of a mob.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomName {
+    /// JSON text of the name.
+    pub name: String,
+    /// Whether the name is shown even when the mob is not looked at.
+    pub visible: bool,
+}
+
+/// Age of a mob in ticks. Negative for babies, which grow
+/// up when it reaches zero. Positive for adults which have
+/// recently bred and cannot breed again until it reaches zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Age(pub i32);
+
+/// Marker component for mobs which never despawn naturally,
+/// e.g. because they were named or picked up items.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct PersistenceRequired;
+
+/// Attributes of a mob, such as its maximum health and movement speed.
+/// These are kept as loaded so they survive a round trip.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Attributes(pub Vec<AttributeData>);
+
+/// Defines, for each `MobKind`, the `EntityData` variant it
+/// is saved as, the function to create it and its default
+/// maximum health in half-hearts.
+macro_rules! mobs {
+    ($($kind:ident => $data:ident, $create:path, $health:expr;)*) => {
+        $(
+            inventory::submit! {
+                EntityLoaderRegistration::new(EntityDataKind::$data, &load)
+            }
+        )*
+
+        /// Splits `EntityData` for a mob into the mob's kind,
+        /// the function to create it and its saved data.
+        fn unwrap_data(data: EntityData) -> Option<(MobKind, fn() -> EntityBuilder, MobData)> {
+            match data {
+                $(EntityData::$data(data) => Some((MobKind::$kind, $create, data)),)*
+                _ => None,
+            }
+        }
+
+        fn wrap_data(kind: MobKind, data: MobData) -> EntityData {
+            match kind {
+                $(MobKind::$kind => EntityData::$data(data),)*
+            }
+        }
+
+        /// Creates a mob with the given kind, without a position or health.
+        pub fn create_mob(kind: MobKind) -> EntityBuilder {
+            match kind {
+                $(MobKind::$kind => $create(),)*
+            }
+        }
+
+        /// Returns the maximum health of a mob with the given kind
+        /// when it has no attributes saying otherwise.
+        pub fn default_max_health(kind: MobKind) -> u32 {
+            match kind {
+                $(MobKind::$kind => $health,)*
+            }
+        }
+    };
+}
+
+// Horses, donkeys, mules and llamas roll their health between 15 and 30,
+// and slimes and magma cubes depend on their size; these use
+// a typical value. Loaded mobs use the value from their attributes.
+mobs! {
+    Bat => Bat, passive::bat::create, 6;
+    Blaze => Blaze, hostile::blaze::create, 20;
+    CaveSpider => CaveSpider, neutral::cave_spider::create, 12;
+    Chicken => Chicken, passive::chicken::create, 4;
+    Cod => Cod, passive::cod::create, 3;
+    Cow => Cow, passive::cow::create, 10;
+    Creeper => Creeper, hostile::creeper::create, 20;
+    Donkey => Donkey, passive::donkey::create, 22;
+    Dolphin => Dolphin, neutral::dolphin::create, 10;
+    Drowned => Drowned, hostile::drowned::create, 20;
+    ElderGuardian => ElderGuardian, hostile::elder_guardian::create, 80;
+    EnderDragon => EnderDragon, boss::ender_dragon::create, 200;
+    Enderman => Enderman, neutral::enderman::create, 40;
+    Endermite => Endermite, hostile::endermite::create, 8;
+    EvocationIllager => Evoker, hostile::evoker::create, 24;
+    Ghast => Ghast, hostile::ghast::create, 10;
+    Giant => Giant, hostile::giant::create, 100;
+    Guardian => Guardian, hostile::guardian::create, 30;
+    Horse => Horse, passive::horse::create, 22;
+    Husk => Husk, hostile::husk::create, 20;
+    IllusionIllager => Illusioner, hostile::illusioner::create, 32;
+    Llama => Llama, neutral::llama::create, 22;
+    MagmaCube => MagmaCube, hostile::magma_cube::create, 16;
+    Mule => Mule, passive::mule::create, 22;
+    MushroomCow => Mooshroom, passive::mooshroom::create, 10;
+    Ocelot => Ocelot, passive::ocelot::create, 10;
+    Parrot => Parrot, passive::parrot::create, 6;
+    Pig => Pig, passive::pig::create, 10;
+    Pufferfish => Pufferfish, defensive::pufferfish::create, 3;
+    PigZombie => ZombiePigman, neutral::zombie_pigman::create, 20;
+    PolarBear => PolarBear, neutral::polar_bear::create, 30;
+    Rabbit => Rabbit, passive::rabbit::create, 3;
+    Salmon => Salmon, passive::salmon::create, 3;
+    Sheep => Sheep, passive::sheep::create, 8;
+    Shulker => Shulker, hostile::shulker::create, 30;
+    Silverfish => Silverfish, hostile::silverfish::create, 8;
+    Skeleton => Skeleton, hostile::skeleton::create, 20;
+    SkeletonHorse => SkeletonHorse, passive::skeleton_horse::create, 15;
+    Slime => Slime, hostile::slime::create, 16;
+    SnowGolem => SnowGolem, passive::snow_golem::create, 4;
+    Spider => Spider, neutral::spider::create, 16;
+    Squid => Squid, passive::squid::create, 10;
+    Stray => Stray, hostile::stray::create, 20;
+    TropicalFish => TropicalFish, passive::tropical_fish::create, 3;
+    Turtle => Turtle, passive::turtle::create, 30;
+    Vex => Vex, hostile::vex::create, 14;
+    Villager => Villager, passive::villager::create, 20;
+    IronGolem => IronGolem, neutral::iron_golem::create, 100;
+    VindicationIllager => Vindicator, hostile::vindicator::create, 24;
+    Witch => Witch, hostile::witch::create, 26;
+    Wither => Wither, boss::wither::create, 300;
+    WitherSkeleton => WitherSkeleton, hostile::wither_skeleton::create, 20;
+    Wolf => Wolf, neutral::wolf::create, 8;
+    Zombie => Zombie, hostile::zombie::create, 20;
+    ZombieHorse => ZombieHorse, passive::zombie_horse::create, 15;
+    ZombieVillager => ZombieVillager, hostile::zombie_villager::create, 20;
+    Phantom => Phantom, hostile::phantom::create, 20;
+}
+
+/// Returns the `ComponentSerializer` shared by all mobs.
+pub(super) fn serializer() -> ComponentSerializer {
+    ComponentSerializer(&serialize)
+}
+
+fn serialize(_game: &Game, accessor: &EntityRef) -> EntityData {
+    let kind = *accessor.get::<MobKind>();
+    let vel = accessor.get::<Velocity>().0;
+    let base = BaseEntityData::new(*accessor.get::<Position>(), Vec3d::new(vel.x, vel.y, vel.z));
+    let health = accessor
+        .try_get::<Health>()
+        .map(|health| health.0)
+        .unwrap_or_else(|| default_max_health(kind));
+
+    let mut data = MobData::new(base, health as f32);
+    if let Some(name) = accessor.try_get::<CustomName>() {
+        data.custom_name = Some(name.name.clone());
+        data.custom_name_visible = name.visible as i8;
+    }
+    data.age = accessor
+        .try_get::<Age>()
+        .map(|age| age.0)
+        .unwrap_or_default();
+    data.persistence_required = accessor.try_get::<PersistenceRequired>().is_some() as i8;
+    data.attributes = accessor
+        .try_get::<Attributes>()
+        .map(|attributes| attributes.0.clone())
+        .unwrap_or_default();
+
+    wrap_data(kind, data)
+}
+
+fn load(data: EntityData) -> anyhow::Result<EntityBuilder> {
+    let (kind, create, data) =
+        unwrap_data(data).ok_or_else(|| anyhow::anyhow!("attempted to load a non-mob as a mob"))?;
+
+    let pos = data.animal.base.read_position()?;
+    let vel = data.animal.base.read_velocity()?;
+    let max_health = data
+        .attribute(MAX_HEALTH_ATTRIBUTE)
+        .map(|attribute| attribute.base.ceil() as u32)
+        .unwrap_or_else(|| default_max_health(kind));
+
+    let mut builder = create()
+        .with(pos)
+        .with(Velocity(glm::vec3(vel.x, vel.y, vel.z)))
+        .with(Health(data.animal.health.ceil() as u32))
+        .with(MaxHealth(max_health))
+        .with(Age(data.age))
+        .with(Attributes(data.attributes));
+
+    if let Some(name) = data.custom_name {
+        builder = builder.with(CustomName {
+            name,
+            visible: data.custom_name_visible != 0,
+        });
+    }
+    if data.persistence_required != 0 {
+        builder = builder.with(PersistenceRequired);
+    }
+
+    Ok(builder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::position;
+    use feather_test_framework::Test;
+
+    #[test]
+    fn test_mob_round_trip() {
+        let mut test = Test::new();
+
+        let mut data = MobData::new(
+            BaseEntityData::new(
+                position!(1.0, 64.0, 2.0, 0.0, 45.0),
+                Vec3d::new(0.0, 0.0, 0.0),
+            ),
+            7.0,
+        );
+        data.custom_name = Some(String::from(r#"{"text":"Dinnerbone"}"#));
+        data.age = -24000;
+        data.persistence_required = 1;
+
+        let sheep = test.entity(load(EntityData::Sheep(data)).unwrap());
+        assert_eq!(test.world.get::<Health>(sheep).0, 7);
+        assert_eq!(test.world.get::<MaxHealth>(sheep).0, 8);
+        assert_eq!(*test.world.get::<Age>(sheep), Age(-24000));
+        assert!(test.world.has::<PersistenceRequired>(sheep));
+
+        let saved = test
+            .world
+            .get::<ComponentSerializer>(sheep)
+            .serialize(&test.game, &test.world.entity(sheep).unwrap());
+        match saved {
+            EntityData::Sheep(data) => {
+                assert_eq!(data.animal.health, 7.0);
+                assert_eq!(
+                    data.custom_name.as_deref(),
+                    Some(r#"{"text":"Dinnerbone"}"#)
+                );
+                assert_eq!(data.custom_name_visible, 0);
+                assert_eq!(data.age, -24000);
+                assert_eq!(data.persistence_required, 1);
+            }
+            data => panic!("expected sheep, got {:?}", data),
+        }
+    }
+
+    #[test]
+    fn test_mob_kind_data_mapping() {
+        let kinds = [
+            MobKind::Bat,
+            MobKind::EvocationIllager,
+            MobKind::MushroomCow,
+            MobKind::PigZombie,
+            MobKind::Phantom,
+        ];
+        for kind in kinds.iter().copied() {
+            let data = wrap_data(kind, MobData::default());
+            let (loaded_kind, _, _) = unwrap_data(data).unwrap();
+            assert_eq!(loaded_kind, kind);
+        }
+    }
+}
@@ -0,0 +1,604 @@
+//! Natural spawning and despawning of mobs.
+//!
+//! This follows the vanilla algorithm: each tick, for each
+//! `SpawnCategory` below its mob cap, a random position is chosen
+//! in every chunk near a player, and up to three groups of mobs
+//! from the biome's spawn list are spawned around it. Caps are
+//! scaled by the number of chunks near players, so each player
+//! contributes one vanilla cap. Mobs other than animals despawn
+//! when far away from all players.
+
+use super::{create_mob, default_max_health, CustomName, MobKind, PersistenceRequired};
+use feather_core::biomes::{SpawnCategory, SpawnEntry};
+use feather_core::blocks::{BlockId, BlockKind, SimplifiedBlockKind};
+use feather_core::chunk_map::{chunk_relative_pos, ChunkMap};
+use feather_core::util::{BlockPosition, ChunkPosition, Difficulty, Position};
+use feather_server_types::{
+    BumpVec, Dead, EntitySpawnEvent, Game, Health, MaxHealth, Player, Uuid,
+};
+use fecs::{component, IntoQuery, Read, World, Write};
+use rand::Rng;
+use std::collections::HashMap;
+
+/// Radius, in chunks, around each player in which mobs spawn.
+const SPAWN_CHUNK_RADIUS: i32 = 8;
+/// Number of chunks around a single player. Mob caps
+/// are defined per this many chunks.
+const CHUNKS_PER_PLAYER: u32 = 289;
+/// Mobs never spawn closer than this to a player or the world spawn.
+const MIN_SPAWN_DISTANCE: f64 = 24.0;
+/// Animals only spawn once every this many ticks.
+const CREATURE_SPAWN_INTERVAL: u64 = 400;
+/// Number of groups spawned around each random position.
+const GROUPS_PER_POSITION: usize = 3;
+/// Maximum number of positions tried for each mob in a group.
+const MAX_GROUP_ATTEMPTS: u32 = 4;
+/// Sea level, which limits where bats, squid and turtles spawn.
+const SEA_LEVEL: i32 = 63;
+
+/// Mobs further than this from all players despawn immediately.
+const INSTANT_DESPAWN_DISTANCE: f64 = 128.0;
+/// Mobs further than this from all players may despawn randomly.
+const RANDOM_DESPAWN_DISTANCE: f64 = 32.0;
+/// Number of ticks a mob must have been far from players
+/// before it may despawn randomly.
+const MIN_IDLE_TICKS: u32 = 600;
+
+/// Number of ticks a mob has been far away from all players.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IdleTime(pub u32);
+
+/// Returns the spawn category a mob counts towards, or `None`
+/// for mobs which aren't counted, such as golems and villagers.
+pub fn spawn_category(kind: MobKind) -> Option<SpawnCategory> {
+    use MobKind::*;
+    Some(match kind {
+        Chicken | Cow | Donkey | Horse | Llama | Mule | MushroomCow | Ocelot | Parrot | Pig
+        | PolarBear | Rabbit | Sheep | SkeletonHorse | Turtle | Wolf | ZombieHorse => {
+            SpawnCategory::Creature
+        }
+        Bat => SpawnCategory::Ambient,
+        Cod | Dolphin | Pufferfish | Salmon | Squid | TropicalFish => SpawnCategory::WaterCreature,
+        IronGolem | SnowGolem | Villager => return None,
+        _ => SpawnCategory::Monster,
+    })
+}
+
+/// System which spawns mobs near players.
+#[fecs::system]
+pub fn spawn_mobs(game: &mut Game, world: &mut World) {
+    let gameplay = &game.config.gameplay;
+    let peaceful = game.level.difficulty == Difficulty::Peaceful.id() as i8;
+    let categories: BumpVec<SpawnCategory> = {
+        let mut categories = BumpVec::new_in(game.bump());
+        categories.extend(
+            SpawnCategory::ALL
+                .iter()
+                .copied()
+                .filter(|category| !peaceful || category.is_peaceful())
+                .filter(|category| match category {
+                    SpawnCategory::Monster => gameplay.monster_spawning,
+                    SpawnCategory::Creature => {
+                        gameplay.animal_spawning && game.tick_count % CREATURE_SPAWN_INTERVAL == 0
+                    }
+                    _ => gameplay.animal_spawning,
+                }),
+        );
+        categories
+    };
+    if categories.is_empty() {
+        return;
+    }
+
+    let players: Vec<Position> = <Read<Position>>::query()
+        .filter(component::<Player>() & !component::<Dead>())
+        .iter(world.inner())
+        .map(|pos| *pos)
+        .collect();
+    let chunks = eligible_chunks(&game.chunk_map, &players);
+    if chunks.is_empty() {
+        return;
+    }
+
+    let mut counts = HashMap::new();
+    for kind in <Read<MobKind>>::query()
+        .filter(!component::<Dead>())
+        .iter(world.inner())
+    {
+        if let Some(category) = spawn_category(*kind) {
+            *counts.entry(category).or_insert(0) += 1;
+        }
+    }
+
+    let mut spawns = Vec::new();
+    for category in categories {
+        let cap = category.cap() * chunks.len() as u32 / CHUNKS_PER_PLAYER;
+        let mut count = counts.get(&category).copied().unwrap_or(0u32);
+
+        for (chunk, _) in chunks.iter().filter(|(_, spawnable)| **spawnable) {
+            if count > cap {
+                break;
+            }
+            count += plan_spawns(game, &players, category, *chunk, &mut spawns);
+        }
+    }
+
+    for (kind, pos) in spawns {
+        let health = default_max_health(kind);
+        let entity = create_mob(kind)
+            .with(pos)
+            .with(Health(health))
+            .with(MaxHealth(health))
+            .with(Uuid::new_v4())
+            .build()
+            .spawn_in(world);
+        game.handle(world, EntitySpawnEvent { entity });
+    }
+}
+
+/// System which despawns mobs far away from players.
+#[fecs::system]
+pub fn despawn_mobs(game: &mut Game, world: &mut World) {
+    let players: Vec<Position> = <Read<Position>>::query()
+        .filter(component::<Player>() & !component::<Dead>())
+        .iter(world.inner())
+        .map(|pos| *pos)
+        .collect();
+    if players.is_empty() {
+        return;
+    }
+
+    let mut despawned = BumpVec::new_in(game.bump());
+    for (entity, (kind, pos, mut idle)) in
+        <(Read<MobKind>, Read<Position>, Write<IdleTime>)>::query()
+            .filter(
+                !component::<Dead>()
+                    & !component::<PersistenceRequired>()
+                    & !component::<CustomName>(),
+            )
+            .iter_entities_mut(world.inner_mut())
+    {
+        if !can_despawn(*kind) {
+            continue;
+        }
+
+        let distance = nearest_player_distance(&players, *pos);
+        if distance > INSTANT_DESPAWN_DISTANCE {
+            despawned.push(entity);
+        } else if distance > RANDOM_DESPAWN_DISTANCE {
+            idle.0 += 1;
+            if idle.0 > MIN_IDLE_TICKS && game.rng().gen_range(0, 800) == 0 {
+                despawned.push(entity);
+            }
+        } else {
+            idle.0 = 0;
+        }
+    }
+
+    for entity in despawned {
+        game.despawn(entity, world);
+    }
+}
+
+/// Animals never despawn; neither do bosses.
+fn can_despawn(kind: MobKind) -> bool {
+    !matches!(kind, MobKind::EnderDragon | MobKind::Wither)
+        && !matches!(spawn_category(kind), Some(SpawnCategory::Creature) | None)
+}
+
+/// Returns the loaded chunks near players, mapped to whether
+/// mobs may spawn in them. Chunks on the edge of the spawning
+/// radius count towards mob caps, but mobs don't spawn in them.
+fn eligible_chunks(chunk_map: &ChunkMap, players: &[Position]) -> HashMap<ChunkPosition, bool> {
+    let mut chunks = HashMap::new();
+    for player in players {
+        let center = player.chunk();
+        for dx in -SPAWN_CHUNK_RADIUS..=SPAWN_CHUNK_RADIUS {
+            for dz in -SPAWN_CHUNK_RADIUS..=SPAWN_CHUNK_RADIUS {
+                let chunk = ChunkPosition::new(center.x + dx, center.z + dz);
+                if chunk_map.chunk_at(chunk).is_none() {
+                    continue;
+                }
+                let edge = dx.abs() == SPAWN_CHUNK_RADIUS || dz.abs() == SPAWN_CHUNK_RADIUS;
+                *chunks.entry(chunk).or_insert(false) |= !edge;
+            }
+        }
+    }
+    chunks
+}
+
+/// Chooses where to spawn groups of mobs in the given
+/// chunk, returning the number of mobs to spawn.
+fn plan_spawns(
+    game: &Game,
+    players: &[Position],
+    category: SpawnCategory,
+    chunk: ChunkPosition,
+    spawns: &mut Vec<(MobKind, Position)>,
+) -> u32 {
+    let mut rng = game.rng();
+    let start = match random_position_in_chunk(&game.chunk_map, chunk, &mut *rng) {
+        Some(start) => start,
+        None => return 0,
+    };
+    if game
+        .chunk_map
+        .block_at(start)
+        .map_or(true, |block| block.is_solid())
+    {
+        return 0;
+    }
+
+    let world_spawn =
+        BlockPosition::new(game.level.spawn_x, game.level.spawn_y, game.level.spawn_z).position();
+    let darkening = sky_darkening(game.time.time_of_day());
+
+    let mut spawned = 0;
+    for _ in 0..GROUPS_PER_POSITION {
+        let (mut x, mut z) = (start.x, start.z);
+        let mut entry: Option<(&SpawnEntry, MobKind)> = None;
+        let mut group_size = 0;
+        // The size of the group is chosen along with the mob kind.
+        let mut target_size = 1;
+        let mut attempts = 0;
+
+        while group_size < target_size && attempts < target_size * MAX_GROUP_ATTEMPTS {
+            attempts += 1;
+            x += rng.gen_range(0, 6) - rng.gen_range(0, 6);
+            z += rng.gen_range(0, 6) - rng.gen_range(0, 6);
+            let block = BlockPosition::new(x, start.y, z);
+            let pos = Position {
+                x: f64::from(x) + 0.5,
+                y: f64::from(start.y),
+                z: f64::from(z) + 0.5,
+                pitch: 0.0,
+                yaw: rng.gen_range(0.0, 360.0),
+                on_ground: true,
+            };
+
+            if nearest_player_distance(players, pos) < MIN_SPAWN_DISTANCE
+                || pos.distance_to(world_spawn) < MIN_SPAWN_DISTANCE
+            {
+                continue;
+            }
+
+            // The mob kind is chosen at the first suitable position in each group.
+            let (_, kind) = match entry {
+                Some(entry) => entry,
+                None => {
+                    let biome = match biome_at(&game.chunk_map, block) {
+                        Some(biome) => biome,
+                        None => break,
+                    };
+                    match choose_entry(biome.spawns(category), &mut *rng) {
+                        Some(chosen) => {
+                            let (spawn_entry, _) = chosen;
+                            target_size =
+                                rng.gen_range(spawn_entry.min_group, spawn_entry.max_group + 1);
+                            entry = Some(chosen);
+                            chosen
+                        }
+                        None => break,
+                    }
+                }
+            };
+
+            if !can_spawn_at(&game.chunk_map, kind, block, darkening, &mut *rng) {
+                continue;
+            }
+
+            spawns.push((kind, pos));
+            spawned += 1;
+            group_size += 1;
+        }
+    }
+    spawned
+}
+
+/// Picks a random position in a chunk, below its highest block.
+fn random_position_in_chunk(
+    chunk_map: &ChunkMap,
+    chunk: ChunkPosition,
+    rng: &mut impl Rng,
+) -> Option<BlockPosition> {
+    let x = rng.gen_range(0, 16);
+    let z = rng.gen_range(0, 16);
+    let top = {
+        let chunk = chunk_map.chunk_at(chunk)?;
+        i32::from(chunk.heightmap(x, z).world_surface()) + 1
+    };
+    // Vanilla rounds up to the top of the chunk section.
+    let max_y = ((top + 15) / 16 * 16).max(1);
+    let y = rng.gen_range(0, max_y);
+    Some(BlockPosition::new(
+        chunk.x * 16 + x as i32,
+        y,
+        chunk.z * 16 + z as i32,
+    ))
+}
+
+/// Chooses a random entry from a spawn list based on their weights.
+fn choose_entry(entries: &[SpawnEntry], rng: &mut impl Rng) -> Option<(&SpawnEntry, MobKind)> {
+    let total: u32 = entries.iter().map(|entry| entry.weight).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut choice = rng.gen_range(0, total);
+    for entry in entries {
+        if choice < entry.weight {
+            return MobKind::from_identifier(entry.entity).map(|kind| (entry, kind));
+        }
+        choice -= entry.weight;
+    }
+    None
+}
+
+/// Determines whether a mob of the given kind can spawn with its feet in `pos`.
+fn can_spawn_at(
+    chunk_map: &ChunkMap,
+    kind: MobKind,
+    pos: BlockPosition,
+    darkening: u8,
+    rng: &mut impl Rng,
+) -> bool {
+    let block = |pos| chunk_map.block_at(pos).unwrap_or_else(BlockId::air);
+    let (feet, head, below) = (block(pos), block(pos.up()), block(pos.down()));
+
+    let placed =
+        if spawn_category(kind) == Some(SpawnCategory::WaterCreature) || kind == MobKind::Drowned {
+            feet.kind() == BlockKind::Water && below.kind() == BlockKind::Water && !head.is_solid()
+        } else {
+            below.is_solid()
+                && below.kind() != BlockKind::Bedrock
+                && below.kind() != BlockKind::Barrier
+                && !feet.is_solid()
+                && !feet.is_fluid()
+                && !head.is_solid()
+                && !head.is_fluid()
+        };
+    if !placed {
+        return false;
+    }
+
+    let (sky, block_light) = match chunk_map.light_at(pos) {
+        Some(light) => light,
+        None => return false,
+    };
+    let light = block_light.max(sky.saturating_sub(darkening));
+    let ground = below.kind();
+
+    use MobKind::*;
+    match kind {
+        // Nether mobs ignore light levels.
+        Ghast => rng.gen_range(0, 20) == 0,
+        PigZombie | MagmaCube => true,
+        // Husks and strays only spawn under the open sky.
+        Husk | Stray => sky == 15 && is_dark(sky, light, rng),
+        Drowned => pos.y < SEA_LEVEL - 5 && is_dark(sky, light, rng),
+        Bat => pos.y < SEA_LEVEL && i32::from(light) <= rng.gen_range(0, 4),
+        Squid => pos.y > 45 && pos.y < SEA_LEVEL,
+        Cod | Salmon | Pufferfish | TropicalFish | Dolphin => true,
+        MushroomCow => ground == BlockKind::Mycelium && light > 8,
+        Rabbit | PolarBear => {
+            matches!(
+                ground,
+                BlockKind::GrassBlock | BlockKind::SnowBlock | BlockKind::Sand
+            ) && light > 8
+        }
+        Turtle => ground == BlockKind::Sand && pos.y < SEA_LEVEL + 4 && light > 8,
+        Ocelot | Parrot => {
+            (ground == BlockKind::GrassBlock
+                || matches!(
+                    below.simplified_kind(),
+                    SimplifiedBlockKind::Leaves | SimplifiedBlockKind::Log
+                ))
+                && light > 8
+        }
+        _ => match spawn_category(kind) {
+            Some(SpawnCategory::Creature) => ground == BlockKind::GrassBlock && light > 8,
+            _ => is_dark(sky, light, rng),
+        },
+    }
+}
+
+/// Vanilla's light check for monsters, which is
+/// randomized so that mobs spawn less often near light.
+fn is_dark(sky: u8, light: u8, rng: &mut impl Rng) -> bool {
+    if i32::from(sky) > rng.gen_range(0, 32) {
+        return false;
+    }
+    i32::from(light) <= rng.gen_range(0, 8)
+}
+
+fn biome_at(chunk_map: &ChunkMap, pos: BlockPosition) -> Option<feather_core::biomes::Biome> {
+    let chunk = chunk_map.chunk_at(pos.chunk())?;
+    let (x, _, z) = chunk_relative_pos(pos);
+    Some(chunk.biome_at(x, z))
+}
+
+/// Returns how much sky light is reduced by at the given
+/// time of day: 0 at noon up to 11 at midnight.
+fn sky_darkening(time_of_day: u64) -> u8 {
+    use std::f64::consts::PI;
+
+    // The angle of the sun, from 0 at noon to 0.5 at midnight.
+    let mut angle = time_of_day as f64 / 24000.0 - 0.25;
+    if angle < 0.0 {
+        angle += 1.0;
+    }
+    let eased = 1.0 - ((angle * PI).cos() + 1.0) / 2.0;
+    angle += (eased - angle) / 3.0;
+
+    let darkness = (1.0 - ((angle * PI * 2.0).cos() * 2.0 + 0.5))
+        .max(0.0)
+        .min(1.0);
+    (darkness * 11.0) as u8
+}
+
+fn nearest_player_distance(players: &[Position], pos: Position) -> f64 {
+    players
+        .iter()
+        .map(|player| player.distance_squared_to(pos))
+        .fold(f64::INFINITY, f64::min)
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::biomes::Biome;
+    use feather_core::chunk::Chunk;
+    use feather_core::position;
+    use feather_test_framework::Test;
+
+    #[test]
+    fn test_sky_darkening() {
+        assert_eq!(sky_darkening(6000), 0);
+        assert_eq!(sky_darkening(18000), 11);
+        assert_eq!(sky_darkening(0), 0);
+        assert!(sky_darkening(13000) > 0);
+    }
+
+    #[test]
+    fn test_spawn_category() {
+        assert_eq!(spawn_category(MobKind::Cow), Some(SpawnCategory::Creature));
+        assert_eq!(
+            spawn_category(MobKind::Zombie),
+            Some(SpawnCategory::Monster)
+        );
+        assert_eq!(
+            spawn_category(MobKind::Squid),
+            Some(SpawnCategory::WaterCreature)
+        );
+        assert_eq!(spawn_category(MobKind::Villager), None);
+    }
+
+    #[test]
+    fn test_can_spawn_at() {
+        let mut chunk_map = ChunkMap::new();
+        chunk_map.insert(Chunk::new(ChunkPosition::new(0, 0)));
+        chunk_map.set_block_at(BlockPosition::new(0, 63, 0), BlockId::grass_block());
+        chunk_map.set_block_at(BlockPosition::new(1, 63, 0), BlockId::stone());
+        let mut rng = rand::thread_rng();
+
+        // Animals spawn on grass in the light.
+        assert!(can_spawn_at(
+            &chunk_map,
+            MobKind::Cow,
+            BlockPosition::new(0, 64, 0),
+            0,
+            &mut rng
+        ));
+        assert!(!can_spawn_at(
+            &chunk_map,
+            MobKind::Cow,
+            BlockPosition::new(1, 64, 0),
+            0,
+            &mut rng
+        ));
+        // Not in mid-air.
+        assert!(!can_spawn_at(
+            &chunk_map,
+            MobKind::Cow,
+            BlockPosition::new(0, 65, 0),
+            0,
+            &mut rng
+        ));
+        // Monsters don't spawn in full sky light.
+        assert!(!can_spawn_at(
+            &chunk_map,
+            MobKind::Zombie,
+            BlockPosition::new(1, 64, 0),
+            0,
+            &mut rng
+        ));
+    }
+
+    /// A 17x17 chunk grass plain around the origin.
+    fn plains() -> Test {
+        let mut test = Test::new();
+        for cx in -SPAWN_CHUNK_RADIUS..=SPAWN_CHUNK_RADIUS {
+            for cz in -SPAWN_CHUNK_RADIUS..=SPAWN_CHUNK_RADIUS {
+                let mut chunk = Chunk::new(ChunkPosition::new(cx, cz));
+                for x in 0..16 {
+                    for z in 0..16 {
+                        chunk.set_block_at(x, 63, z, BlockId::grass_block());
+                    }
+                }
+                test.game.chunk_map.insert(chunk);
+            }
+        }
+        test.game.time.0 = 6000;
+        test
+    }
+
+    #[test]
+    fn test_spawn_animals() {
+        let mut test = plains();
+        let player = test.player("player", position!(8.0, 64.0, 8.0));
+
+        for _ in 0..200 {
+            test.run(spawn_mobs);
+        }
+
+        let player_pos = *test.world.get::<Position>(player);
+        let mobs: Vec<(MobKind, Position)> = <(Read<MobKind>, Read<Position>)>::query()
+            .iter(test.world.inner())
+            .map(|(kind, pos)| (*kind, *pos))
+            .collect();
+
+        let max_group = Biome::Plains
+            .spawns(SpawnCategory::Creature)
+            .iter()
+            .map(|entry| entry.max_group as usize)
+            .max()
+            .unwrap();
+        assert!(!mobs.is_empty());
+        // The cap of 10 may be exceeded by the groups spawned in one chunk.
+        assert!(mobs.len() <= 10 + GROUPS_PER_POSITION * max_group);
+        for (kind, pos) in mobs {
+            assert_eq!(spawn_category(kind), Some(SpawnCategory::Creature));
+            assert_eq!(pos.y, 64.0);
+            assert!(pos.distance_to(player_pos) >= MIN_SPAWN_DISTANCE);
+        }
+    }
+
+    #[test]
+    fn test_no_monsters_on_peaceful() {
+        let mut test = plains();
+        test.game.time.0 = 18000;
+        test.game.level.difficulty = Difficulty::Peaceful.id() as i8;
+        test.player("player", position!(8.0, 64.0, 8.0));
+
+        for _ in 0..200 {
+            test.run(spawn_mobs);
+        }
+
+        for kind in <Read<MobKind>>::query().iter(test.world.inner()) {
+            assert_ne!(spawn_category(*kind), Some(SpawnCategory::Monster));
+        }
+    }
+
+    #[test]
+    fn test_despawn() {
+        let mut test = plains();
+        test.player("player", position!(8.0, 64.0, 8.0));
+
+        let far_zombie = test.entity(create_mob(MobKind::Zombie).with(position!(200.0, 64.0, 8.0)));
+        let near_zombie = test.entity(create_mob(MobKind::Zombie).with(position!(16.0, 64.0, 8.0)));
+        let named_zombie = test.entity(
+            create_mob(MobKind::Zombie)
+                .with(position!(200.0, 64.0, 8.0))
+                .with(PersistenceRequired),
+        );
+        let far_cow = test.entity(create_mob(MobKind::Cow).with(position!(200.0, 64.0, 8.0)));
+
+        test.run(despawn_mobs);
+
+        test.assert_dead(far_zombie)
+            .assert_alive(near_zombie)
+            .assert_alive(named_zombie)
+            .assert_alive(far_cow);
+    }
+}
@@ -38,6 +38,31 @@
 //! If we are recalculating light for an entire chunk, e.g. when a chunk is generated,
 //! we first zero out light, then find all light sources in the chunk and perform
 //! algorithm #1 on them as if they had just been placed.
+//!
+//! # Algorithms: sky light
+//! A block which has no opaque blocks above it, as determined by the
+//! light-blocking heightmap, receives the full sky light of 15. All
+//! other non-opaque blocks receive the highest sky light of an adjacent
+//! block minus 1, which lets sky light spread sideways into caves and
+//! under overhangs.
+//!
+//! When a chunk is loaded, we first perform a column pass which sets
+//! sky light to 15 above the heightmap and to 0 below it. We then flood fill
+//! from the sky-exposed blocks which border a column with a greater height,
+//! as well as from the light in adjacent chunks.
+//!
+//! Block updates which do not change whether a block is opaque do not
+//! affect sky light. Otherwise:
+//!
+//! * Creation of an opaque block. The block and any blocks below it which
+//! were lit directly by the sky are set to 0. We then flood fill outwards,
+//! zeroing any light which was derived from the darkened blocks, and finally
+//! propagate light back in from the boundary of the darkened area.
+//!
+//! * Removal of an opaque block. If the block now has access to the sky,
+//! it and the blocks below it down to the heightmap are set to 15. Otherwise
+//! it receives the highest light value of an adjacent block minus 1. We then
+//! propagate the new light values using flood fill.
 
 extern crate nalgebra_glm as glm;
 
@@ -224,6 +249,10 @@ fn load_chunk(worker: &mut Worker, pos: ChunkPosition, handle: Arc<RwLock<Chunk>
         .0
         .insert(pos, lights_in_chunk(&*handle.read()).collect());
     worker.chunk_map.0.insert(pos, handle);
+
+    if let Some(mut ctx) = Context::new(&worker.chunk_map, pos) {
+        initialize_sky_light(&mut ctx, pos);
+    }
 }
 
 fn lights_in_chunk<'a>(chunk: &'a Chunk) -> impl Iterator<Item = BlockPosition> + 'a {
@@ -299,6 +328,45 @@ impl<'a> Context<'a> {
         }
     }
 
+    /// Returns whether the block at `pos` has direct access to the sky,
+    /// i.e. whether it lies at or above the light-blocking heightmap.
+    fn sees_sky(&mut self, pos: BlockPosition) -> bool {
+        match self.chunk_at_mut(pos.chunk()) {
+            Some(chunk) => {
+                let (x, y, z) = chunk_relative_pos(pos);
+                y >= chunk.heightmap(x, z).light_blocking() as usize
+            }
+            None => false,
+        }
+    }
+
+    fn sky_light_at(&mut self, pos: BlockPosition) -> u8 {
+        match self.chunk_at_mut(pos.chunk()) {
+            Some(chunk) => {
+                let (x, y, z) = chunk_relative_pos(pos);
+                if chunk.section(y / 16).is_none() {
+                    missing_section_sky_light(chunk, x, y, z)
+                } else {
+                    chunk.sky_light_at(x, y, z)
+                }
+            }
+            None => 0,
+        }
+    }
+
+    fn set_sky_light_at(&mut self, pos: BlockPosition, value: u8) {
+        if let Some(chunk) = self.chunk_at_mut(pos.chunk()) {
+            let (x, y, z) = chunk_relative_pos(pos);
+            // Avoid creating a section only to store
+            // the value it would already be assumed to have.
+            if chunk.section(y / 16).is_none() && missing_section_sky_light(chunk, x, y, z) == value
+            {
+                return;
+            }
+            chunk.set_sky_light_at(x, y, z, value);
+        }
+    }
+
     fn block_at(&mut self, pos: BlockPosition) -> BlockId {
         match self.chunk_at_mut(pos.chunk()) {
             Some(chunk) => {
@@ -317,8 +385,21 @@ impl<'a> Context<'a> {
     }
 }
 
+/// Returns the sky light of a block within a missing (empty) chunk section,
+/// which is 15 if the block has access to the sky and 0 otherwise.
+fn missing_section_sky_light(chunk: &Chunk, x: usize, y: usize, z: usize) -> u8 {
+    if y >= chunk.heightmap(x, z).light_blocking() as usize {
+        MAX_SKY_LIGHT
+    } else {
+        0
+    }
+}
+
 const MAX_TRAVEL_DISTANCE: u8 = 15;
 
+/// The sky light of blocks with direct access to the sky.
+const MAX_SKY_LIGHT: u8 = 15;
+
 fn handle_block_update(worker: &mut Worker, pos: BlockPosition, old: BlockId, new: BlockId) {
     let mut ctx = match Context::new(&worker.chunk_map, pos.chunk()) {
         Some(ctx) => ctx,
@@ -338,6 +419,10 @@ fn handle_block_update(worker: &mut Worker, pos: BlockPosition, old: BlockId, ne
         opaque_non_emitting_creation(&mut ctx, &worker.lights, pos, new);
     }
 
+    if old.is_opaque() != new.is_opaque() {
+        update_sky_light(&mut ctx, pos, new);
+    }
+
     // Update `ChunkLights`.
     if old.light_emission() != new.light_emission() {
         if new.light_emission() == 0 {
@@ -430,6 +515,192 @@ fn opaque_non_emitting_removal(context: &mut Context, position: BlockPosition) {
     }
 }
 
+/// Computes sky light for a newly loaded chunk, as described
+/// in the module-level docs.
+fn initialize_sky_light(context: &mut Context, chunk: ChunkPosition) {
+    let mut queue = VecDeque::new();
+
+    let (min_x, min_z) = (chunk.x * 16, chunk.z * 16);
+
+    // Column pass.
+    for x in min_x..min_x + 16 {
+        for z in min_z..min_z + 16 {
+            let column = BlockPosition::new(x, 0, z);
+            let height = column_height(context, column).unwrap_or(0);
+
+            for section in 0..16 {
+                let section_exists = context
+                    .chunk_at_mut(chunk)
+                    .map(|chunk| chunk.section(section).is_some())
+                    .unwrap_or(false);
+                if !section_exists {
+                    continue;
+                }
+
+                for y in section as i32 * 16..section as i32 * 16 + 16 {
+                    let value = if y >= height { MAX_SKY_LIGHT } else { 0 };
+                    context.set_sky_light_at(BlockPosition::new(x, y, z), value);
+                }
+            }
+
+            // Sky-exposed blocks next to a taller column
+            // shine sideways under its overhang.
+            let neighbor_height = [(-1, 0), (1, 0), (0, -1), (0, 1)]
+                .iter()
+                .filter_map(|(dx, dz)| {
+                    column_height(context, BlockPosition::new(x + dx, 0, z + dz))
+                })
+                .max()
+                .unwrap_or(0);
+            for y in height..neighbor_height {
+                queue.push_back(BlockPosition::new(x, y, z));
+            }
+        }
+    }
+
+    // Pull in light from the borders of adjacent chunks.
+    for offset in 0..16 {
+        for y in 0..256 {
+            let border = [
+                BlockPosition::new(min_x - 1, y, min_z + offset),
+                BlockPosition::new(min_x + 16, y, min_z + offset),
+                BlockPosition::new(min_x + offset, y, min_z - 1),
+                BlockPosition::new(min_x + offset, y, min_z + 16),
+            ];
+            for pos in border.iter() {
+                if context.sky_light_at(*pos) > 1 {
+                    queue.push_back(*pos);
+                }
+            }
+        }
+    }
+
+    propagate_sky_light(context, queue);
+}
+
+/// Handles a block update which changed whether a block
+/// is opaque, as described in the module-level docs.
+fn update_sky_light(context: &mut Context, position: BlockPosition, new_block: BlockId) {
+    if new_block.is_opaque() {
+        let mut removal = VecDeque::new();
+
+        removal.push_back((position, context.sky_light_at(position)));
+        context.set_sky_light_at(position, 0);
+
+        // If this is now the highest opaque block in its column,
+        // blocks below which were lit directly by the sky
+        // are now in the shadow of this block.
+        if column_height(context, position) == Some(position.y + 1) {
+            for y in (0..position.y).rev() {
+                let pos = BlockPosition::new(position.x, y, position.z);
+                if context.block_at(pos).is_opaque() {
+                    break;
+                }
+
+                removal.push_back((pos, MAX_SKY_LIGHT));
+                context.set_sky_light_at(pos, 0);
+            }
+        }
+
+        let queue = remove_sky_light(context, removal);
+        propagate_sky_light(context, queue);
+    } else {
+        let mut queue = VecDeque::new();
+
+        if context.sees_sky(position) {
+            // Blocks below are now lit directly by the sky.
+            for y in (0..=position.y).rev() {
+                let pos = BlockPosition::new(position.x, y, position.z);
+                if !context.sees_sky(pos) {
+                    break;
+                }
+
+                context.set_sky_light_at(pos, MAX_SKY_LIGHT);
+                queue.push_back(pos);
+            }
+        } else {
+            let value = sky_light_value_for_block(context, position);
+            context.set_sky_light_at(position, value);
+            queue.push_back(position);
+        }
+
+        propagate_sky_light(context, queue);
+    }
+}
+
+/// Performs flood fill from the given darkened blocks and their
+/// previous light values, setting any light which was derived from
+/// them to 0.
+///
+/// Returns the blocks bordering the darkened area whose light
+/// should be propagated back into it.
+fn remove_sky_light(
+    context: &mut Context,
+    mut removal: VecDeque<(BlockPosition, u8)>,
+) -> VecDeque<BlockPosition> {
+    let mut queue = VecDeque::new();
+
+    while let Some((pos, old_value)) = removal.pop_front() {
+        for adjacent in adjacent_blocks(pos) {
+            let value = context.sky_light_at(adjacent);
+            if value == 0 {
+                continue;
+            }
+
+            if value < old_value && !context.sees_sky(adjacent) {
+                context.set_sky_light_at(adjacent, 0);
+                removal.push_back((adjacent, value));
+            } else {
+                queue.push_back(adjacent);
+            }
+        }
+    }
+
+    queue
+}
+
+/// Performs flood fill starting at the given blocks, raising
+/// the sky light of each non-opaque block to the light
+/// value of an adjacent block minus 1.
+fn propagate_sky_light(context: &mut Context, mut queue: VecDeque<BlockPosition>) {
+    while let Some(pos) = queue.pop_front() {
+        let value = context.sky_light_at(pos);
+        if value <= 1 {
+            continue;
+        }
+
+        for adjacent in adjacent_blocks(pos) {
+            if context.sky_light_at(adjacent) >= value - 1 || context.block_at(adjacent).is_opaque()
+            {
+                continue;
+            }
+
+            context.set_sky_light_at(adjacent, value - 1);
+            queue.push_back(adjacent);
+        }
+    }
+}
+
+/// Returns the sky light value for the non-opaque block at `position`
+/// which does not have access to the sky, equivalent to the maximum
+/// sky light value of an adjacent block minus 1.
+fn sky_light_value_for_block(context: &mut Context, position: BlockPosition) -> u8 {
+    adjacent_blocks(position)
+        .into_iter()
+        .map(|pos| context.sky_light_at(pos))
+        .max()
+        .unwrap_or(0)
+        .saturating_sub(1)
+}
+
+/// Returns the light-blocking height of the column containing
+/// `pos`, or `None` if its chunk is not loaded.
+fn column_height(context: &mut Context, pos: BlockPosition) -> Option<i32> {
+    let chunk = context.chunk_at_mut(pos.chunk())?;
+    let (x, _, z) = chunk_relative_pos(pos);
+    Some(i32::from(chunk.heightmap(x, z).light_blocking()))
+}
+
 /// Returns the light value for the block at `position`,
 /// equivalent to the maximum light value of an adjacent block
 /// minus 1.
@@ -608,6 +879,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_initialize_sky_light_overhang() {
+        let chunk_map = chunk_map();
+        let mut ctx = Context::new(&chunk_map, ChunkPosition::new(0, 0)).unwrap();
+
+        // Roof covering half of the chunk
+        for x in 0..8 {
+            for z in 0..16 {
+                ctx.set_block_at(BlockPosition::new(x, 20, z), BlockId::stone());
+            }
+        }
+
+        initialize_sky_light(&mut ctx, ChunkPosition::new(0, 0));
+
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 21, 5)), 15);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 20, 5)), 0);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(8, 15, 5)), 15);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(7, 15, 5)), 14);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(6, 15, 5)), 13);
+        // Lit from the adjacent chunk
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(0, 15, 5)), 14);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(3, 15, 5)), 11);
+    }
+
+    #[test]
+    fn test_sky_light_block_update() {
+        let chunk_map = chunk_map();
+        let mut ctx = Context::new(&chunk_map, ChunkPosition::new(0, 0)).unwrap();
+        initialize_sky_light(&mut ctx, ChunkPosition::new(0, 0));
+
+        let pos = BlockPosition::new(5, 100, 5);
+        ctx.set_block_at(pos, BlockId::stone());
+        update_sky_light(&mut ctx, pos, BlockId::stone());
+
+        assert_eq!(ctx.sky_light_at(pos), 0);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 101, 5)), 15);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 99, 5)), 14);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 50, 5)), 14);
+
+        ctx.set_block_at(pos, BlockId::air());
+        update_sky_light(&mut ctx, pos, BlockId::air());
+
+        assert_eq!(ctx.sky_light_at(pos), 15);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 99, 5)), 15);
+        assert_eq!(ctx.sky_light_at(BlockPosition::new(5, 50, 5)), 15);
+    }
+
+    #[test]
+    fn test_sky_light_enclosed() {
+        let chunk_map = chunk_map();
+        let mut ctx = Context::new(&chunk_map, ChunkPosition::new(0, 0)).unwrap();
+        initialize_sky_light(&mut ctx, ChunkPosition::new(0, 0));
+
+        // Build a closed stone box around (5, 50, 5)
+        let inside = BlockPosition::new(5, 50, 5);
+        for pos in adjacent_blocks(inside) {
+            ctx.set_block_at(pos, BlockId::stone());
+            update_sky_light(&mut ctx, pos, BlockId::stone());
+        }
+
+        assert_eq!(ctx.sky_light_at(inside), 0);
+
+        // Open the box from the side. Nothing is above the
+        // removed block, so it is lit directly by the sky.
+        let side = BlockPosition::new(6, 50, 5);
+        ctx.set_block_at(side, BlockId::air());
+        update_sky_light(&mut ctx, side, BlockId::air());
+
+        assert_eq!(ctx.sky_light_at(side), 15);
+        assert_eq!(ctx.sky_light_at(inside), 14);
+    }
+
     fn chunk_map() -> ChunkMap {
         let mut chunk_map = ChunkMap::new();
 
@@ -14,26 +14,32 @@
 //! speeding up the login process and making the latency calculation in
 //! the server list ping as low as possible.
 
+use std::io::Cursor;
+use std::net::IpAddr;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 
+use hmac::{Hmac, Mac, NewMac};
 use rand::rngs::OsRng;
 use rsa::{PaddingScheme, PublicKey, RSAPrivateKey};
 use rsa_der as der;
+use sha2::Sha256;
 
 use thiserror::Error;
 
-use feather_core::network::{cast_packet, Packet, PacketStage, PacketType};
+use feather_core::network::{cast_packet, McTypeRead, Packet, PacketStage, PacketType};
 use feather_core::text::{Text, TextRoot};
 
 use crate::{PROTOCOL_VERSION, SERVER_VERSION};
 use feather_core::network::packets::{
-    DisconnectLogin, EncryptionRequest, EncryptionResponse, Handshake, HandshakeState, LoginStart,
-    LoginSuccess, Ping, Pong, Request, Response, SetCompression,
+    DisconnectLogin, EncryptionRequest, EncryptionResponse, Handshake, HandshakeState,
+    LoginPluginRequest, LoginPluginResponse, LoginStart, LoginSuccess, Ping, Pong, Request,
+    Response, SetCompression,
 };
-use feather_server_types::{Config, ProxyMode};
+use feather_server_types::{offline_mode_uuid, AccessLists, Config, LoginDenied, ProxyMode};
 use mojang_api::ProfileProperty;
 use once_cell::sync::Lazy;
+use parking_lot::RwLock;
 use uuid::Uuid;
 
 /// The key used for symmetric encryption.
@@ -47,6 +53,16 @@ const RSA_KEY_BITS: usize = 1024;
 /// The number of bytes in the shared secret
 const SHARED_SECRET_LEN: usize = 128 / 8;
 
+/// The login plugin channel used by Velocity for modern forwarding.
+const VELOCITY_CHANNEL: &str = "velocity:player_info";
+/// The version of Velocity's forwarding format which we understand.
+const VELOCITY_FORWARDING_VERSION: i32 = 1;
+/// The number of bytes in the HMAC-SHA256 signature
+/// which prefixes Velocity forwarding data.
+const VELOCITY_SIGNATURE_LEN: usize = 32;
+
+type HmacSha256 = Hmac<Sha256>;
+
 pub static RSA_KEY: Lazy<RSAPrivateKey> = Lazy::new(|| {
     let mut rng = OsRng;
     RSAPrivateKey::new(&mut rng, RSA_KEY_BITS).unwrap()
@@ -69,6 +85,9 @@ pub struct JoinResult {
     pub username: Option<String>,
     pub uuid: Uuid,
     pub props: Vec<mojang_api::ProfileProperty>,
+    /// The real IP address of the client, if it was
+    /// forwarded by a proxy.
+    pub ip: Option<IpAddr>,
 }
 
 impl JoinResult {
@@ -77,26 +96,11 @@ impl JoinResult {
     /// The UUID will be computed using Minecraft's method for computing offline mode
     /// UUIDs as a function of the username.
     fn with_username(username: String) -> Self {
-        let mut join_result = JoinResult::default();
-
-        // Compute offline mode UUID
-        // https://gist.github.com/games647/2b6a00a8fc21fd3b88375f03c9e2e603
-        let mut context = md5::Context::new();
-        context.consume(format!("OfflinePlayer:{}", username).as_bytes());
-        let computed = context.compute();
-        let bytes = computed.into();
-
-        let mut builder = uuid::Builder::from_bytes(bytes);
-
-        builder
-            .set_variant(uuid::Variant::RFC4122)
-            .set_version(uuid::Version::Md5);
-
-        join_result.uuid = builder.build();
-
-        join_result.username = Some(username);
-
-        join_result
+        JoinResult {
+            uuid: offline_mode_uuid(&username),
+            username: Some(username),
+            ..Default::default()
+        }
     }
 }
 
@@ -106,6 +110,7 @@ impl Default for JoinResult {
             username: None,
             uuid: Uuid::new_v4(),
             props: vec![],
+            ip: None,
         }
     }
 }
@@ -138,10 +143,20 @@ pub struct InitialHandler {
     /// The verify token generated for this exchange.
     verify_token: VerifyToken,
 
+    /// The message ID of the login plugin request
+    /// sent to a Velocity proxy.
+    velocity_message_id: i32,
+
+    /// The address of the client.
+    ip: IpAddr,
+
     /// The server's configuration.
     config: Arc<Config>,
     /// The server's player count.
     player_count: Arc<AtomicU32>,
+    /// The ban lists, whitelist and operator list,
+    /// checked before the player may join.
+    access_lists: Arc<RwLock<AccessLists>>,
     /// The server's icon, if any was loaded.
     server_icon: Arc<Option<String>>,
 
@@ -156,8 +171,10 @@ pub struct InitialHandler {
 
 impl InitialHandler {
     pub fn new(
+        ip: IpAddr,
         config: Arc<Config>,
         player_count: Arc<AtomicU32>,
+        access_lists: Arc<RwLock<AccessLists>>,
         server_icon: Arc<Option<String>>,
     ) -> Self {
         Self {
@@ -168,8 +185,13 @@ impl InitialHandler {
 
             verify_token: rand::random(),
 
+            velocity_message_id: rand::random(),
+
+            ip,
+
             config,
             player_count,
+            access_lists,
             server_icon,
 
             info: None,
@@ -221,6 +243,9 @@ async fn _handle_packet(ih: &mut InitialHandler, packet: Box<dyn Packet>) -> Res
         PacketType::EncryptionResponse => {
             handle_encryption_response(ih, &cast_packet::<EncryptionResponse>(packet)).await?
         }
+        PacketType::LoginPluginResponse => {
+            handle_login_plugin_response(ih, &cast_packet::<LoginPluginResponse>(packet))?
+        }
         ty => return Err(Error::InvalidPacket(ty, ih.stage)),
     }
 
@@ -252,6 +277,7 @@ fn handle_handshake(ih: &mut InitialHandler, packet: &Handshake) -> Result<(), E
                     username: None,
                     uuid: bungeecord_data.uuid,
                     props: bungeecord_data.properties,
+                    ip: bungeecord_data.client.parse().ok(),
                 });
             }
 
@@ -367,8 +393,18 @@ fn handle_ping(ih: &mut InitialHandler, packet: &Ping) -> Result<(), Error> {
 fn handle_login_start(ih: &mut InitialHandler, packet: &LoginStart) -> Result<(), Error> {
     check_stage(ih, Stage::AwaitLoginStart, packet.ty())?;
 
-    if ih.player_count.load(Ordering::Acquire) >= ih.config.server.max_players as u32 {
-        disconnect_login(ih, Text::from("Server is full!"));
+    // Velocity has already authenticated the player. Ask it
+    // for the forwarded player information instead of
+    // performing authentication ourselves.
+    if ih.config.proxy.proxy_mode == ProxyMode::Velocity {
+        let request = LoginPluginRequest {
+            message_id: ih.velocity_message_id,
+            channel: VELOCITY_CHANNEL.to_string(),
+            data: vec![],
+        };
+        send_packet(ih, request);
+
+        ih.stage = Stage::AwaitLoginPluginResponse;
         return Ok(());
     }
 
@@ -409,7 +445,7 @@ fn handle_login_start(ih: &mut InitialHandler, packet: &LoginStart) -> Result<()
             ih.info = Some(JoinResult::with_username(username))
         }
 
-        finish(ih);
+        finish(ih)?;
     }
 
     Ok(())
@@ -474,17 +510,137 @@ async fn handle_encryption_response(
                 username: Some(auth.name),
                 uuid: auth.id,
                 props: auth.properties,
+                ip: None,
             };
             ih.info = Some(info);
         }
         Err(e) => return Err(Error::AuthenticationFailed(e)),
     }
 
-    finish(ih);
+    finish(ih)?;
 
     Ok(())
 }
 
+fn handle_login_plugin_response(
+    ih: &mut InitialHandler,
+    packet: &LoginPluginResponse,
+) -> Result<(), Error> {
+    check_stage(ih, Stage::AwaitLoginPluginResponse, packet.ty())?;
+
+    if packet.message_id != ih.velocity_message_id {
+        return Err(Error::VelocitySpecMismatch(format!(
+            "Unexpected message ID {}",
+            packet.message_id
+        )));
+    }
+
+    // A client which connected directly (or through a proxy
+    // without modern forwarding) does not understand the channel.
+    if !packet.successful {
+        return Err(Error::VelocityRequired);
+    }
+
+    let velocity_data =
+        extract_velocity_data(&packet.data, ih.config.proxy.velocity_secret.as_bytes())?;
+    ih.info = Some(JoinResult {
+        username: Some(velocity_data.username),
+        uuid: velocity_data.uuid,
+        props: velocity_data.properties,
+        ip: Some(velocity_data.client),
+    });
+
+    finish(ih)?;
+
+    Ok(())
+}
+
+/// Verifies and parses the player information sent by a Velocity proxy
+/// in response to a login plugin request on the `velocity:player_info` channel.
+///
+/// The data starts with an HMAC-SHA256 signature of the remaining bytes,
+/// computed using the forwarding secret shared with the proxy. The signed
+/// payload has the following format:
+///
+/// | Field          | Type                                                   |
+/// |----------------|--------------------------------------------------------|
+/// | Version        | VarInt, always 1                                       |
+/// | Client address | String                                                 |
+/// | UUID           | UUID                                                   |
+/// | Username       | String                                                 |
+/// | Properties     | VarInt count, then name, value and optional signature  |
+fn extract_velocity_data(data: &[u8], secret: &[u8]) -> Result<VelocityData, Error> {
+    // Anyone could sign forwarding data with an empty key.
+    if secret.is_empty() {
+        return Err(Error::VelocitySecretMissing);
+    }
+
+    if data.len() < VELOCITY_SIGNATURE_LEN {
+        return Err(Error::VelocitySpecMismatch("Missing signature".to_string()));
+    }
+
+    let (signature, payload) = data.split_at(VELOCITY_SIGNATURE_LEN);
+    let mut mac = HmacSha256::new_varkey(secret).expect("HMAC accepts keys of any length");
+    mac.update(payload);
+    mac.verify(signature)
+        .map_err(|_| Error::VelocityBadSignature)?;
+
+    let mut buf = Cursor::new(payload);
+
+    let version = buf.try_get_var_int().map_err(velocity_mismatch)?;
+    if version != VELOCITY_FORWARDING_VERSION {
+        return Err(Error::VelocitySpecMismatch(format!(
+            "Unsupported forwarding version {}",
+            version
+        )));
+    }
+
+    let client = buf
+        .try_get_string()
+        .map_err(velocity_mismatch)?
+        .parse::<IpAddr>()
+        .map_err(velocity_mismatch)?;
+    let uuid = buf.try_get_uuid().map_err(velocity_mismatch)?;
+    let username = buf.try_get_string().map_err(velocity_mismatch)?;
+
+    let property_count = buf.try_get_var_int().map_err(velocity_mismatch)?;
+    let mut properties = Vec::new();
+    for _ in 0..property_count {
+        let name = buf.try_get_string().map_err(velocity_mismatch)?;
+        let value = buf.try_get_string().map_err(velocity_mismatch)?;
+        let signature = if buf.try_get_bool().map_err(velocity_mismatch)? {
+            buf.try_get_string().map_err(velocity_mismatch)?
+        } else {
+            String::new()
+        };
+
+        properties.push(ProfileProperty {
+            name,
+            value,
+            signature,
+        });
+    }
+
+    Ok(VelocityData {
+        client,
+        uuid,
+        username,
+        properties,
+    })
+}
+
+fn velocity_mismatch(e: impl std::fmt::Display) -> Error {
+    Error::VelocitySpecMismatch(e.to_string())
+}
+
+#[derive(Debug, PartialEq)]
+struct VelocityData {
+    client: IpAddr,
+    uuid: Uuid,
+    username: String,
+    properties: Vec<ProfileProperty>,
+}
+
 fn decrypt_using_rsa(data: &[u8], key: &RSAPrivateKey) -> Result<Vec<u8>, Error> {
     let buf = key
         .decrypt(PaddingScheme::PKCS1v15, data)
@@ -496,14 +652,19 @@ fn decrypt_using_rsa(data: &[u8], key: &RSAPrivateKey) -> Result<Vec<u8>, Error>
 /// Terminates the login process, sending Set Compression (if necessary)
 /// and Login Success.
 ///
+/// Returns `Err` if the player is not allowed to join, because they
+/// are banned, not whitelisted or the server is full.
+///
 /// Before calling this function, it is expected that:
 /// * `info` is set to a valid value
 /// * Encryption has been enabled, if necessary
 /// * All other login processes have already run
-fn finish(ih: &mut InitialHandler) {
+fn finish(ih: &mut InitialHandler) -> Result<(), Error> {
     assert!(ih.info.is_some());
     assert!(ih.info.as_ref().unwrap().username.is_some());
 
+    check_access(ih)?;
+
     // Enable compression if necessary
     let compression_threshold = ih.config.io.compression_threshold;
     if compression_threshold > 0 {
@@ -521,6 +682,21 @@ fn finish(ih: &mut InitialHandler) {
     ih.action_queue.push(Action::SetStage(PacketStage::Play));
     ih.action_queue
         .push(Action::JoinGame(ih.info.clone().unwrap()));
+
+    Ok(())
+}
+
+/// Checks the ban lists, whitelist and player limit.
+fn check_access(ih: &InitialHandler) -> Result<(), LoginDenied> {
+    let info = ih.info.as_ref().unwrap();
+    // Prefer the client address forwarded by a proxy, if any.
+    let ip = info.ip.unwrap_or(ih.ip);
+    let server_full =
+        ih.player_count.load(Ordering::Acquire) >= ih.config.server.max_players as u32;
+
+    ih.access_lists
+        .read()
+        .check_login(info.uuid, ip, server_full)
 }
 
 /// Enables compression, sending the Set Compression
@@ -578,6 +754,16 @@ enum Error {
     AuthenticationFailed(mojang_api::Error),
     #[error("received BungeeCord data does not match the specification: {0}")]
     BungeeSpecMismatch(String),
+    #[error("received Velocity data does not match the specification: {0}")]
+    VelocitySpecMismatch(String),
+    #[error("Velocity forwarding data has an invalid signature")]
+    VelocityBadSignature,
+    #[error("This server requires you to connect with Velocity.")]
+    VelocityRequired,
+    #[error("Velocity forwarding is enabled, but no forwarding secret is configured")]
+    VelocitySecretMissing,
+    #[error(transparent)]
+    LoginDenied(#[from] LoginDenied),
     #[error("option that should not be None was None")]
     /// An Error type than can be used as the error type of using the Try operator on Option
     /// types. In rust-core, this is an unstable feature (issue #42327)
@@ -592,6 +778,7 @@ enum Stage {
     AwaitPing,
     AwaitLoginStart,
     AwaitEncryptionResponse,
+    AwaitLoginPluginResponse,
     Finished,
 }
 
@@ -606,6 +793,9 @@ mod tests {
     use crate::PROTOCOL_VERSION;
 
     use super::*;
+    use bytes::BytesMut;
+    use feather_core::network::McTypeWrite;
+    use feather_server_types::{BanDetails, Profile};
     use mojang_api::ProfileProperty;
 
     #[test]
@@ -746,6 +936,344 @@ mod tests {
         }
     }
 
+    /// A fake Velocity proxy which signs forwarding data
+    /// using a known secret.
+    struct FakeVelocity {
+        secret: &'static str,
+    }
+
+    impl FakeVelocity {
+        fn forwarding_data(
+            &self,
+            client: &str,
+            uuid: Uuid,
+            username: &str,
+            properties: &[ProfileProperty],
+        ) -> Vec<u8> {
+            let mut payload = BytesMut::new();
+            payload.push_var_int(VELOCITY_FORWARDING_VERSION);
+            payload.push_string(client);
+            payload.push_uuid(&uuid);
+            payload.push_string(username);
+
+            payload.push_var_int(properties.len() as i32);
+            for property in properties {
+                payload.push_string(&property.name);
+                payload.push_string(&property.value);
+                payload.push_bool(true);
+                payload.push_string(&property.signature);
+            }
+
+            let mut mac = HmacSha256::new_varkey(self.secret.as_bytes()).unwrap();
+            mac.update(&payload);
+
+            let mut data = mac.finalize().into_bytes().to_vec();
+            data.extend_from_slice(&payload);
+            data
+        }
+
+        fn respond(&self, request: &LoginPluginRequest, data: Vec<u8>) -> LoginPluginResponse {
+            assert_eq!(request.channel, VELOCITY_CHANNEL);
+
+            LoginPluginResponse {
+                message_id: request.message_id,
+                successful: true,
+                data,
+            }
+        }
+    }
+
+    const VELOCITY_SECRET: &str = "velocity_secret";
+
+    fn velocity_uuid() -> Uuid {
+        Uuid::parse_str("905c7e4fb96b45139645d123225575e2").unwrap()
+    }
+
+    fn velocity_properties() -> Vec<ProfileProperty> {
+        vec![ProfileProperty {
+            name: "textures".to_string(),
+            value: "textures_value".to_string(),
+            signature: "textures_signature".to_string(),
+        }]
+    }
+
+    #[test]
+    fn extract_velocity_data_normal() {
+        let proxy = FakeVelocity {
+            secret: VELOCITY_SECRET,
+        };
+        let data = proxy.forwarding_data(
+            "192.168.1.67",
+            velocity_uuid(),
+            "test",
+            &velocity_properties(),
+        );
+
+        assert_eq!(
+            extract_velocity_data(&data, VELOCITY_SECRET.as_bytes()).unwrap(),
+            VelocityData {
+                client: "192.168.1.67".parse().unwrap(),
+                uuid: velocity_uuid(),
+                username: "test".to_string(),
+                properties: velocity_properties(),
+            }
+        );
+    }
+
+    #[test]
+    fn extract_velocity_data_ipv6_client() {
+        let proxy = FakeVelocity {
+            secret: VELOCITY_SECRET,
+        };
+        let data = proxy.forwarding_data("::1", velocity_uuid(), "test", &[]);
+
+        let velocity_data = extract_velocity_data(&data, VELOCITY_SECRET.as_bytes()).unwrap();
+        assert_eq!(velocity_data.client, "::1".parse::<IpAddr>().unwrap());
+        assert!(velocity_data.properties.is_empty());
+    }
+
+    #[test]
+    fn extract_velocity_data_wrong_secret() {
+        let proxy = FakeVelocity {
+            secret: "another_secret",
+        };
+        let data = proxy.forwarding_data(
+            "192.168.1.67",
+            velocity_uuid(),
+            "test",
+            &velocity_properties(),
+        );
+
+        assert_eq!(
+            extract_velocity_data(&data, VELOCITY_SECRET.as_bytes())
+                .err()
+                .unwrap(),
+            Error::VelocityBadSignature
+        );
+    }
+
+    #[test]
+    fn extract_velocity_data_tampered() {
+        let proxy = FakeVelocity {
+            secret: VELOCITY_SECRET,
+        };
+        let mut data = proxy.forwarding_data(
+            "192.168.1.67",
+            velocity_uuid(),
+            "test",
+            &velocity_properties(),
+        );
+        *data.last_mut().unwrap() ^= 1;
+
+        assert_eq!(
+            extract_velocity_data(&data, VELOCITY_SECRET.as_bytes())
+                .err()
+                .unwrap(),
+            Error::VelocityBadSignature
+        );
+    }
+
+    #[test]
+    fn extract_velocity_data_empty_secret() {
+        let proxy = FakeVelocity { secret: "" };
+        let data = proxy.forwarding_data(
+            "192.168.1.67",
+            velocity_uuid(),
+            "test",
+            &velocity_properties(),
+        );
+
+        assert_eq!(
+            extract_velocity_data(&data, b"").err().unwrap(),
+            Error::VelocitySecretMissing
+        );
+    }
+
+    #[test]
+    fn extract_velocity_data_too_short() {
+        assert_eq!(
+            extract_velocity_data(&[0; 8], VELOCITY_SECRET.as_bytes())
+                .err()
+                .unwrap(),
+            Error::VelocitySpecMismatch("Missing signature".to_string())
+        );
+    }
+
+    #[test]
+    fn extract_velocity_data_invalid_address() {
+        let proxy = FakeVelocity {
+            secret: VELOCITY_SECRET,
+        };
+        let data = proxy.forwarding_data("not an address", velocity_uuid(), "test", &[]);
+
+        match extract_velocity_data(&data, VELOCITY_SECRET.as_bytes()) {
+            Err(Error::VelocitySpecMismatch(_)) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_velocity_login_sequence() {
+        let mut config = Config::default();
+        config.server.online_mode = false;
+        config.proxy.proxy_mode = ProxyMode::Velocity;
+        config.proxy.velocity_secret = VELOCITY_SECRET.to_string();
+        let mut ih = ih_with_config(config);
+
+        let handshake = Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            server_address: String::from("localhost"),
+            server_port: 25565,
+            next_state: HandshakeState::Login,
+        };
+        ih.handle_packet(Box::new(handshake)).await;
+        assert_eq!(ih.actions_to_execute().len(), 1);
+
+        let login_start = LoginStart {
+            username: String::from("test"),
+        };
+        ih.handle_packet(Box::new(login_start)).await;
+
+        // The server should ask the proxy for forwarding data
+        let mut actions = ih.actions_to_execute();
+        assert_eq!(actions.len(), 1);
+        let request = match actions.remove(0) {
+            Action::SendPacket(request) => {
+                assert_eq!(request.ty(), PacketType::LoginPluginRequest);
+                cast_packet::<LoginPluginRequest>(request)
+            }
+            _ => panic!(),
+        };
+
+        let proxy = FakeVelocity {
+            secret: VELOCITY_SECRET,
+        };
+        let data = proxy.forwarding_data(
+            "192.168.1.67",
+            velocity_uuid(),
+            "forwarded",
+            &velocity_properties(),
+        );
+        ih.handle_packet(Box::new(proxy.respond(&request, data)))
+            .await;
+
+        let mut actions = ih.actions_to_execute();
+        assert_eq!(actions.len(), 5);
+
+        match actions.remove(2) {
+            Action::SendPacket(login_success) => {
+                let login_success = cast_packet::<LoginSuccess>(login_success);
+                assert_eq!(login_success.username, "forwarded");
+                assert_eq!(
+                    login_success.uuid,
+                    velocity_uuid().to_hyphenated_ref().to_string()
+                );
+            }
+            _ => panic!(),
+        }
+
+        match actions.pop().unwrap() {
+            Action::JoinGame(info) => {
+                assert_eq!(info.username.as_deref(), Some("forwarded"));
+                assert_eq!(info.uuid, velocity_uuid());
+                assert_eq!(info.props, velocity_properties());
+                assert_eq!(info.ip, Some("192.168.1.67".parse().unwrap()));
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_velocity_login_without_proxy() {
+        let mut config = Config::default();
+        config.proxy.proxy_mode = ProxyMode::Velocity;
+        config.proxy.velocity_secret = VELOCITY_SECRET.to_string();
+        let mut ih = ih_with_config(config);
+
+        let handshake = Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            server_address: String::from("localhost"),
+            server_port: 25565,
+            next_state: HandshakeState::Login,
+        };
+        ih.handle_packet(Box::new(handshake)).await;
+        ih.handle_packet(Box::new(LoginStart {
+            username: String::from("test"),
+        }))
+        .await;
+
+        let request = match ih.actions_to_execute().remove(0) {
+            Action::SendPacket(request) => cast_packet::<LoginPluginRequest>(request),
+            _ => panic!(),
+        };
+
+        // A vanilla client does not understand the channel
+        let response = LoginPluginResponse {
+            message_id: request.message_id,
+            successful: false,
+            data: vec![],
+        };
+        ih.handle_packet(Box::new(response)).await;
+
+        let mut actions = ih.actions_to_execute();
+        assert_eq!(actions.len(), 2);
+        match actions.remove(0) {
+            Action::SendPacket(disconnect) => {
+                assert_eq!(disconnect.ty(), PacketType::DisconnectLogin)
+            }
+            _ => panic!(),
+        }
+        match actions.remove(0) {
+            Action::Disconnect => (),
+            _ => panic!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_velocity_login_empty_secret() {
+        let mut config = Config::default();
+        config.server.online_mode = false;
+        config.proxy.proxy_mode = ProxyMode::Velocity;
+        let mut ih = ih_with_config(config);
+
+        let handshake = Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            server_address: String::from("localhost"),
+            server_port: 25565,
+            next_state: HandshakeState::Login,
+        };
+        ih.handle_packet(Box::new(handshake)).await;
+        ih.handle_packet(Box::new(LoginStart {
+            username: String::from("test"),
+        }))
+        .await;
+
+        let request = match ih.actions_to_execute().remove(0) {
+            Action::SendPacket(request) => cast_packet::<LoginPluginRequest>(request),
+            _ => panic!(),
+        };
+
+        // Forwarding data signed with the empty key must not be trusted
+        let proxy = FakeVelocity { secret: "" };
+        let data = proxy.forwarding_data("192.168.1.67", velocity_uuid(), "forwarded", &[]);
+        ih.handle_packet(Box::new(proxy.respond(&request, data)))
+            .await;
+
+        let mut actions = ih.actions_to_execute();
+        assert_eq!(actions.len(), 2);
+        match actions.remove(0) {
+            Action::SendPacket(disconnect) => {
+                assert_eq!(disconnect.ty(), PacketType::DisconnectLogin)
+            }
+            _ => panic!(),
+        }
+        match actions.remove(0) {
+            Action::Disconnect => (),
+            _ => panic!(),
+        }
+        assert!(ih.info.is_none());
+    }
+
     #[test]
     fn test_initial_handler_new() {
         let mut ih = ih();
@@ -892,27 +1420,102 @@ mod tests {
         }
     }
 
+    async fn offline_login(ih: &mut InitialHandler, username: &str) -> Vec<Action> {
+        let handshake = Handshake {
+            protocol_version: PROTOCOL_VERSION,
+            server_address: String::default(),
+            server_port: 25565,
+            next_state: HandshakeState::Login,
+        };
+        ih.handle_packet(Box::new(handshake)).await;
+        ih.actions_to_execute();
+
+        ih.handle_packet(Box::new(LoginStart {
+            username: String::from(username),
+        }))
+        .await;
+        ih.actions_to_execute()
+    }
+
+    fn assert_disconnected(mut actions: Vec<Action>, reason: &str) {
+        assert_eq!(actions.len(), 2);
+        match actions.remove(0) {
+            Action::SendPacket(disconnect) => {
+                let disconnect = cast_packet::<DisconnectLogin>(disconnect);
+                assert!(disconnect.reason.contains(reason), "{}", disconnect.reason);
+            }
+            _ => panic!(),
+        }
+        match actions.remove(0) {
+            Action::Disconnect => (),
+            _ => panic!(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_login_denied() {
+        let mut config = Config::default();
+        config.server.online_mode = false;
+
+        let mut access_lists = AccessLists::new();
+        let banned = Profile {
+            uuid: offline_mode_uuid("banned"),
+            name: String::from("banned"),
+        };
+        access_lists
+            .ban(banned, BanDetails::new("Server", None))
+            .unwrap();
+
+        let mut ih = ih_with_access_lists(config.clone(), access_lists);
+        let actions = offline_login(&mut ih, "banned").await;
+        assert_disconnected(actions, "You are banned from this server.");
+
+        let mut access_lists = AccessLists::new();
+        access_lists
+            .ban_ip(client_ip(), BanDetails::new("Server", None))
+            .unwrap();
+
+        let mut ih = ih_with_access_lists(config.clone(), access_lists);
+        let actions = offline_login(&mut ih, "test").await;
+        assert_disconnected(actions, "Your IP address is banned from this server.");
+
+        let mut access_lists = AccessLists::new();
+        access_lists.set_whitelist_enabled(true);
+
+        let mut ih = ih_with_access_lists(config, access_lists);
+        let actions = offline_login(&mut ih, "test").await;
+        assert_disconnected(actions, "You are not white-listed on this server!");
+    }
+
     fn ih() -> InitialHandler {
-        InitialHandler::new(
-            Arc::new(Config::default()),
-            Arc::new(AtomicU32::new(0)),
-            Arc::new(Some(String::from("test"))),
-        )
+        ih_with_config(Config::default())
     }
 
     fn ih_with_player_count(count: u32) -> InitialHandler {
         InitialHandler::new(
+            client_ip(),
             Arc::new(Config::default()),
             Arc::new(AtomicU32::new(count)),
+            Arc::new(RwLock::new(AccessLists::new())),
             Arc::new(Some(String::from("test"))),
         )
     }
 
     fn ih_with_config(config: Config) -> InitialHandler {
+        ih_with_access_lists(config, AccessLists::new())
+    }
+
+    fn ih_with_access_lists(config: Config, access_lists: AccessLists) -> InitialHandler {
         InitialHandler::new(
+            client_ip(),
             Arc::new(config),
             Arc::new(AtomicU32::new(0)),
+            Arc::new(RwLock::new(access_lists)),
             Arc::new(Some(String::from("test"))),
         )
     }
+
+    fn client_ip() -> IpAddr {
+        "192.168.1.67".parse().unwrap()
+    }
 }
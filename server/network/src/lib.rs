@@ -14,11 +14,11 @@ use derivative::Derivative;
 use feather_core::anvil::player::PlayerData;
 use feather_core::util::Position;
 use feather_server_types::{
-    Config, PacketBuffers, ServerToWorkerMessage, Uuid, WorkerToServerMessage,
+    AccessLists, Config, PacketBuffers, ServerToWorkerMessage, Uuid, WorkerToServerMessage,
 };
 use fecs::Entity;
 use once_cell::sync::Lazy;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, RwLock};
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
@@ -85,6 +85,7 @@ impl NetworkIoManager {
         listener: TcpListener,
         config: Arc<Config>,
         player_count: Arc<AtomicU32>,
+        access_lists: Arc<RwLock<AccessLists>>,
         server_icon: Arc<Option<String>>,
         packet_buffers: Arc<PacketBuffers>,
     ) -> Self {
@@ -97,6 +98,7 @@ impl NetworkIoManager {
             listener_rx,
             config,
             player_count,
+            access_lists,
             server_icon,
             packet_buffers,
         );
@@ -121,12 +123,14 @@ pub fn init() {
     Lazy::force(&initial_handler::RSA_KEY);
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn run_listener(
     listener: TcpListener,
     tx: flume::Sender<ListenerToServerMessage>,
     rx: flume::Receiver<ServerToListenerMessage>,
     config: Arc<Config>,
     player_count: Arc<AtomicU32>,
+    access_lists: Arc<RwLock<AccessLists>>,
     server_icon: Arc<Option<String>>,
     packet_buffers: Arc<PacketBuffers>,
 ) {
@@ -136,6 +140,7 @@ async fn run_listener(
         rx,
         config,
         player_count,
+        access_lists,
         server_icon,
         packet_buffers,
     )
@@ -5,7 +5,8 @@
 
 use crate::worker::run_worker;
 use crate::{ListenerToServerMessage, ServerToListenerMessage};
-use feather_server_types::{Config, PacketBuffers};
+use feather_server_types::{AccessLists, Config, PacketBuffers};
+use parking_lot::RwLock;
 
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
@@ -13,12 +14,14 @@ use tokio::io;
 use tokio::net::TcpListener;
 use tokio::sync::Mutex;
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run_listener(
     mut listener: TcpListener,
     tx: flume::Sender<ListenerToServerMessage>,
     rx: flume::Receiver<ServerToListenerMessage>,
     config: Arc<Config>,
     player_count: Arc<AtomicU32>,
+    access_lists: Arc<RwLock<AccessLists>>,
     server_icon: Arc<Option<String>>,
     packet_buffers: Arc<PacketBuffers>,
 ) -> Result<(), io::Error> {
@@ -42,6 +45,7 @@ pub async fn run_listener(
             Arc::clone(&rx),
             Arc::clone(&config),
             Arc::clone(&player_count),
+            Arc::clone(&access_lists),
             Arc::clone(&server_icon),
             Arc::clone(&packet_buffers),
         ));
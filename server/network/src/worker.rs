@@ -13,12 +13,13 @@ use feather_core::anvil::player::PlayerData;
 use feather_core::network::{MinecraftCodec, Packet, PacketDirection};
 use feather_core::util::{Position, Vec3d};
 use feather_server_types::{
-    Config, PacketBuffers, ServerToWorkerMessage, Uuid, WorkerToServerMessage,
+    AccessLists, Config, PacketBuffers, ServerToWorkerMessage, Uuid, WorkerToServerMessage,
 };
 use fecs::Entity;
 use futures::future::Either;
 use futures::SinkExt;
 use futures::StreamExt;
+use parking_lot::RwLock;
 use std::net::SocketAddr;
 use std::path::Path;
 use std::sync::atomic::AtomicU32;
@@ -62,6 +63,7 @@ pub async fn run_worker(
     listener_rx: Arc<Mutex<flume::Receiver<ServerToListenerMessage>>>,
     config: Arc<Config>,
     player_count: Arc<AtomicU32>,
+    access_lists: Arc<RwLock<AccessLists>>,
     server_icon: Arc<Option<String>>,
     packet_buffers: Arc<PacketBuffers>,
 ) {
@@ -69,8 +71,10 @@ pub async fn run_worker(
     let (tx, server_rx) = flume::unbounded();
 
     let initial_handler = Some(InitialHandler::new(
+        ip.ip(),
         Arc::clone(&config),
         Arc::clone(&player_count),
+        access_lists,
         Arc::clone(&server_icon),
     ));
 
@@ -196,8 +200,13 @@ async fn handle_ih_actions(worker: &mut Worker) -> anyhow::Result<()> {
             Action::JoinGame(info) => {
                 let data = load_player_data(&worker.config, info.uuid).await?;
                 let position = data.animal.base.read_position()?;
+                // Prefer the client address forwarded by a proxy, if any.
+                let ip = info
+                    .ip
+                    .map(|ip| SocketAddr::new(ip, worker.ip.port()))
+                    .unwrap_or(worker.ip);
                 let info = NewClientInfo {
-                    ip: worker.ip,
+                    ip,
                     username: info.username.unwrap_or_else(|| String::from("undefined")),
                     profile: info.props,
                     uuid: info.uuid,
@@ -242,6 +251,7 @@ async fn load_player_data(config: &Config, uuid: Uuid) -> Result<PlayerData, any
                 gamemode: config.server.default_gamemode.id() as i32,
                 inventory: vec![],
                 held_item: 0,
+                ..Default::default()
             };
 
             feather_core::anvil::player::save_player_data(
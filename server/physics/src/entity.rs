@@ -1,7 +1,7 @@
 //! Module for performing entity physics, including velocity, drag
 //! and position updates each tick.
 
-use crate::{block_impacted_by_ray, blocks_intersecting_bbox, Side};
+use crate::{block_impacted_by_ray, blocks_intersecting_bbox, flow_at, Side};
 use feather_core::blocks::BlockKind;
 use feather_core::position;
 use feather_core::util::Position;
@@ -103,15 +103,18 @@ pub fn entity_physics(game: &mut Game, world: &mut World) {
             // Apply drag and gravity.
 
             // In water and lava, gravity is four times less, and velocity is multiplied by a special drag force.
+            // Flowing fluids also push entities along.
             let liquid_drag = 0.8;
             match block_at_pos.kind() {
                 BlockKind::Water => {
                     velocity.0 *= liquid_drag;
                     velocity.0.y += physics.gravity / 4.0;
+                    velocity.0 += flow_at(&game.chunk_map, pending_position.block()) * 0.014;
                 }
                 BlockKind::Lava => {
                     velocity.0 *= liquid_drag - 0.3;
                     velocity.0.y += physics.gravity / 4.0;
+                    velocity.0 += flow_at(&game.chunk_map, pending_position.block()) * 0.0023;
                 }
                 _ => {
                     let slip_multiplier = physics.slip_multiplier;
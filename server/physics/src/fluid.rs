@@ -0,0 +1,665 @@
+//! Flowing water and lava.
+//!
+//! Fluids only change in scheduled ticks: whenever a block is
+//! updated, the fluids at and next to it are scheduled a tick in
+//! `Game::scheduled_ticks` and flow a few ticks later, so that
+//! fluids spread gradually, one block at a time.
+//!
+//! A fluid's state is stored in the `level` property. Level 0 is a
+//! source block. Levels 1 through 7 are flowing fluid, which gets
+//! lower the further it is from its source; and levels 8 and above
+//! are falling fluid, which is as high as a source.
+
+use feather_core::blocks::{BlockId, BlockKind, SimplifiedBlockKind};
+use feather_core::chunk_map::ChunkMap;
+use feather_core::util::BlockPosition;
+use feather_server_types::{BlockUpdateCause, BlockUpdateEvent, Game, ScheduledTickEvent};
+use fecs::World;
+use std::iter;
+
+/// Amount of fluid in source and falling blocks.
+const MAX_AMOUNT: i32 = 8;
+
+/// Horizontal directions, as offsets along the x and z axes.
+const HORIZONTAL: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// A kind of fluid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Fluid {
+    Water,
+    Lava,
+}
+
+impl Fluid {
+    /// Returns the fluid which makes up the given block.
+    pub fn of(block: BlockId) -> Option<Self> {
+        match block.kind() {
+            BlockKind::Water => Some(Fluid::Water),
+            BlockKind::Lava => Some(Fluid::Lava),
+            _ => None,
+        }
+    }
+
+    /// Number of ticks between a fluid being updated and it flowing.
+    pub fn tick_delay(self) -> u64 {
+        match self {
+            Fluid::Water => 5,
+            Fluid::Lava => 30,
+        }
+    }
+
+    /// Amount of fluid lost for each block it flows horizontally.
+    fn amount_lost_per_block(self) -> i32 {
+        match self {
+            Fluid::Water => 1,
+            Fluid::Lava => 2,
+        }
+    }
+
+    /// Maximum distance fluid looks for a hole to flow towards.
+    fn slope_find_distance(self) -> i32 {
+        match self {
+            Fluid::Water => 4,
+            Fluid::Lava => 2,
+        }
+    }
+
+    fn block(self) -> BlockId {
+        match self {
+            Fluid::Water => BlockId::water(),
+            Fluid::Lava => BlockId::lava(),
+        }
+    }
+}
+
+/// The state of a fluid block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FluidState {
+    pub fluid: Fluid,
+    /// Amount of fluid in the block, from 1 to 8.
+    pub amount: i32,
+    /// Whether the fluid is falling from the block above.
+    pub falling: bool,
+}
+
+impl FluidState {
+    pub fn source(fluid: Fluid) -> Self {
+        Self {
+            fluid,
+            amount: MAX_AMOUNT,
+            falling: false,
+        }
+    }
+
+    pub fn flowing(fluid: Fluid, amount: i32) -> Self {
+        Self {
+            fluid,
+            amount,
+            falling: false,
+        }
+    }
+
+    pub fn falling(fluid: Fluid) -> Self {
+        Self {
+            fluid,
+            amount: MAX_AMOUNT,
+            falling: true,
+        }
+    }
+
+    /// Returns the state of the fluid in the given block, or `None`
+    /// if it is not a fluid.
+    pub fn of(block: BlockId) -> Option<Self> {
+        let fluid = Fluid::of(block)?;
+        let level = block.water_level().unwrap_or(0);
+        Some(if level >= MAX_AMOUNT {
+            Self::falling(fluid)
+        } else {
+            Self::flowing(fluid, MAX_AMOUNT - level)
+        })
+    }
+
+    pub fn is_source(self) -> bool {
+        !self.falling && self.amount == MAX_AMOUNT
+    }
+
+    /// Returns the block with this fluid state.
+    pub fn block(self) -> BlockId {
+        let level = if self.falling {
+            MAX_AMOUNT
+        } else {
+            MAX_AMOUNT - self.amount
+        };
+        self.fluid.block().with_water_level(level)
+    }
+
+    /// Height of the fluid's surface within its block.
+    pub fn height(self) -> f64 {
+        f64::from(self.amount) / 9.0
+    }
+}
+
+/// Makes fluids flow when their scheduled ticks are due.
+#[fecs::event_handler]
+pub fn on_scheduled_tick_flow_fluid(
+    event: &ScheduledTickEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    if Fluid::of(event.block).is_some() {
+        tick_fluid(game, world, event.pos);
+    }
+}
+
+/// When a block is updated, schedules the fluids
+/// at and next to it to flow.
+#[fecs::event_handler]
+pub fn on_block_update_schedule_fluids(event: &BlockUpdateEvent, game: &mut Game) {
+    schedule_around(game, event.pos);
+}
+
+/// Returns the direction in which the fluid at the given position
+/// flows, normalized, or a zero vector if the fluid is still or
+/// there is no fluid. Entities in fluids are pushed in this direction.
+pub fn flow_at(chunk_map: &ChunkMap, pos: BlockPosition) -> glm::DVec3 {
+    let state = match chunk_map.block_at(pos).and_then(FluidState::of) {
+        Some(state) => state,
+        None => return glm::zero(),
+    };
+
+    let mut flow: glm::DVec3 = glm::zero();
+    for (dx, dz) in HORIZONTAL.iter().copied() {
+        let neighbor = offset(pos, dx, dz);
+        let neighbor_block = match chunk_map.block_at(neighbor) {
+            Some(block) => block,
+            None => continue,
+        };
+
+        let difference = match fluid_at(chunk_map, neighbor, state.fluid) {
+            Some(other) => state.height() - other.height(),
+            // Fluid flows towards holes it can fall into.
+            None if !neighbor_block.is_solid() => {
+                match fluid_at(chunk_map, neighbor.down(), state.fluid) {
+                    Some(below) => state.height() - (below.height() - 8.0 / 9.0),
+                    None => 0.0,
+                }
+            }
+            None => 0.0,
+        };
+        flow.x += f64::from(dx) * difference;
+        flow.z += f64::from(dz) * difference;
+    }
+
+    if state.falling {
+        flow.y -= 6.0;
+    }
+
+    if flow == glm::zero() {
+        flow
+    } else {
+        glm::normalize(&flow)
+    }
+}
+
+/// Updates the fluid at `pos`: recalculates its amount
+/// from its neighbors and makes it flow into adjacent blocks.
+fn tick_fluid(game: &mut Game, world: &mut World, pos: BlockPosition) {
+    let old = match game.block_at(pos).and_then(FluidState::of) {
+        Some(state) => state,
+        None => return,
+    };
+
+    if old.fluid == Fluid::Lava && solidify_lava(game, world, pos, old) {
+        return;
+    }
+
+    let state = if old.is_source() {
+        old
+    } else {
+        match new_state(&game.chunk_map, pos, old.fluid) {
+            Some(state) => state,
+            None => {
+                set_block(game, world, pos, BlockId::air());
+                return;
+            }
+        }
+    };
+    if state != old {
+        set_block(game, world, pos, state.block());
+    }
+
+    spread(game, world, pos, state);
+}
+
+/// Calculates the state of a flowing fluid block from the
+/// fluid next to it, or `None` if the fluid dries up.
+fn new_state(chunk_map: &ChunkMap, pos: BlockPosition, fluid: Fluid) -> Option<FluidState> {
+    let mut max_amount = 0;
+    let mut sources = 0;
+    for (dx, dz) in HORIZONTAL.iter().copied() {
+        if let Some(neighbor) = fluid_at(chunk_map, offset(pos, dx, dz), fluid) {
+            if neighbor.is_source() {
+                sources += 1;
+            }
+            max_amount = max_amount.max(neighbor.amount);
+        }
+    }
+
+    // Water between two sources becomes a source itself,
+    // as long as it doesn't drain down.
+    if fluid == Fluid::Water && sources >= 2 {
+        let below = chunk_map.block_at(pos.down());
+        let supported = below.map_or(false, |below| {
+            below.is_solid() || FluidState::of(below) == Some(FluidState::source(fluid))
+        });
+        if supported {
+            return Some(FluidState::source(fluid));
+        }
+    }
+
+    if fluid_at(chunk_map, pos.up(), fluid).is_some() {
+        return Some(FluidState::falling(fluid));
+    }
+
+    let amount = max_amount - fluid.amount_lost_per_block();
+    if amount > 0 {
+        Some(FluidState::flowing(fluid, amount))
+    } else {
+        None
+    }
+}
+
+/// Makes fluid flow down, or to the sides if it can't.
+fn spread(game: &mut Game, world: &mut World, pos: BlockPosition, state: FluidState) {
+    let fluid = state.fluid;
+    let below = pos.down();
+
+    if can_flow_into(&game.chunk_map, below, fluid, true) {
+        flow_into(game, world, below, FluidState::falling(fluid));
+        // Fluid surrounded by sources is as wide as it is deep.
+        if adjacent_sources(&game.chunk_map, pos, fluid) >= 3 {
+            spread_sideways(game, world, pos, state);
+        }
+    } else if state.is_source() || fluid_at(&game.chunk_map, below, fluid).is_none() {
+        spread_sideways(game, world, pos, state);
+    }
+}
+
+fn spread_sideways(game: &mut Game, world: &mut World, pos: BlockPosition, state: FluidState) {
+    let amount = state.amount - state.fluid.amount_lost_per_block();
+    if amount <= 0 {
+        return;
+    }
+
+    for target in flow_targets(&game.chunk_map, pos, state.fluid) {
+        flow_into(
+            game,
+            world,
+            target,
+            FluidState::flowing(state.fluid, amount),
+        );
+    }
+}
+
+/// Returns the horizontally adjacent blocks fluid flows into: those
+/// closest to a hole the fluid can fall into, or all of them
+/// if there is no hole within the slope find distance.
+fn flow_targets(chunk_map: &ChunkMap, pos: BlockPosition, fluid: Fluid) -> Vec<BlockPosition> {
+    let mut targets = Vec::new();
+    let mut shortest = i32::MAX;
+
+    for (dx, dz) in HORIZONTAL.iter().copied() {
+        let neighbor = offset(pos, dx, dz);
+        if !can_flow_into(chunk_map, neighbor, fluid, false) {
+            continue;
+        }
+
+        let distance = if is_hole(chunk_map, neighbor, fluid) {
+            0
+        } else {
+            slope_distance(chunk_map, neighbor, fluid, 1, (-dx, -dz))
+        };
+
+        if distance < shortest {
+            shortest = distance;
+            targets.clear();
+        }
+        if distance == shortest {
+            targets.push(neighbor);
+        }
+    }
+
+    targets
+}
+
+/// Returns the distance from `pos` to the nearest hole, not going back
+/// in direction `from`, or `i32::MAX` if there is none close enough.
+fn slope_distance(
+    chunk_map: &ChunkMap,
+    pos: BlockPosition,
+    fluid: Fluid,
+    depth: i32,
+    from: (i32, i32),
+) -> i32 {
+    let mut shortest = i32::MAX;
+    for (dx, dz) in HORIZONTAL.iter().copied() {
+        if (dx, dz) == from {
+            continue;
+        }
+
+        let neighbor = offset(pos, dx, dz);
+        if !can_flow_into(chunk_map, neighbor, fluid, false) {
+            continue;
+        }
+        if is_hole(chunk_map, neighbor, fluid) {
+            return depth;
+        }
+        if depth < fluid.slope_find_distance() {
+            shortest = shortest.min(slope_distance(
+                chunk_map,
+                neighbor,
+                fluid,
+                depth + 1,
+                (-dx, -dz),
+            ));
+        }
+    }
+    shortest
+}
+
+/// Determines whether fluid at `pos` could fall down.
+fn is_hole(chunk_map: &ChunkMap, pos: BlockPosition, fluid: Fluid) -> bool {
+    let below = pos.down();
+    fluid_at(chunk_map, below, fluid).is_some() || can_flow_into(chunk_map, below, fluid, true)
+}
+
+/// Determines whether fluid can flow into the block at `pos`.
+fn can_flow_into(chunk_map: &ChunkMap, pos: BlockPosition, fluid: Fluid, down: bool) -> bool {
+    let block = match chunk_map.block_at(pos) {
+        Some(block) => block,
+        None => return false,
+    };
+
+    match FluidState::of(block) {
+        Some(other) if other.fluid == fluid => !other.is_source(),
+        // Lava flowing down into water turns it into stone.
+        Some(_) => fluid == Fluid::Lava && down,
+        None => is_washed_away(block),
+    }
+}
+
+/// Blocks which fluids flow into, replacing them.
+fn is_washed_away(block: BlockId) -> bool {
+    block.is_air()
+        || matches!(
+            block.simplified_kind(),
+            SimplifiedBlockKind::Grass
+                | SimplifiedBlockKind::TallGrass
+                | SimplifiedBlockKind::DeadBush
+                | SimplifiedBlockKind::Flower
+                | SimplifiedBlockKind::Sapling
+                | SimplifiedBlockKind::Mushroom
+                | SimplifiedBlockKind::Vine
+                | SimplifiedBlockKind::Snow
+        )
+}
+
+fn flow_into(game: &mut Game, world: &mut World, pos: BlockPosition, state: FluidState) {
+    let block = match game.block_at(pos) {
+        Some(block) => block,
+        None => return,
+    };
+
+    if let Some(other) = FluidState::of(block) {
+        if other.fluid != state.fluid {
+            // Only lava flows into water, which turns into stone.
+            set_block(game, world, pos, BlockId::stone());
+            return;
+        }
+        if other == state || (!state.falling && other.amount >= state.amount) {
+            return;
+        }
+    }
+
+    set_block(game, world, pos, state.block());
+}
+
+/// Turns lava touching water into obsidian if it is
+/// a source, or cobblestone otherwise. Returns whether
+/// the lava was solidified.
+fn solidify_lava(
+    game: &mut Game,
+    world: &mut World,
+    pos: BlockPosition,
+    state: FluidState,
+) -> bool {
+    let touches_water = iter::once(pos.up())
+        .chain(HORIZONTAL.iter().map(|(dx, dz)| offset(pos, *dx, *dz)))
+        .any(|neighbor| fluid_at(&game.chunk_map, neighbor, Fluid::Water).is_some());
+    if !touches_water {
+        return false;
+    }
+
+    let block = if state.is_source() {
+        BlockId::obsidian()
+    } else {
+        BlockId::cobblestone()
+    };
+    set_block(game, world, pos, block);
+    true
+}
+
+fn adjacent_sources(chunk_map: &ChunkMap, pos: BlockPosition, fluid: Fluid) -> usize {
+    HORIZONTAL
+        .iter()
+        .filter_map(|(dx, dz)| fluid_at(chunk_map, offset(pos, *dx, *dz), fluid))
+        .filter(|state| state.is_source())
+        .count()
+}
+
+/// Returns the state of the fluid at `pos` if it is of the given kind.
+fn fluid_at(chunk_map: &ChunkMap, pos: BlockPosition, fluid: Fluid) -> Option<FluidState> {
+    chunk_map
+        .block_at(pos)
+        .and_then(FluidState::of)
+        .filter(|state| state.fluid == fluid)
+}
+
+/// Sets a block changed by a fluid and schedules
+/// the fluids around it to flow.
+fn set_block(game: &mut Game, world: &mut World, pos: BlockPosition, block: BlockId) {
+    if game.set_block_at(world, pos, block, BlockUpdateCause::Fluid) {
+        schedule_around(game, pos);
+    }
+}
+
+/// Schedules the fluids at and next to `pos` to flow.
+fn schedule_around(game: &mut Game, pos: BlockPosition) {
+    let neighbors = [
+        pos,
+        pos.up(),
+        pos.down(),
+        offset(pos, 0, -1),
+        offset(pos, 1, 0),
+        offset(pos, 0, 1),
+        offset(pos, -1, 0),
+    ];
+    for neighbor in neighbors.iter().copied() {
+        let block = match game.block_at(neighbor) {
+            Some(block) => block,
+            None => continue,
+        };
+        if let Some(fluid) = Fluid::of(block) {
+            let due = game.tick_count + fluid.tick_delay();
+            game.scheduled_ticks.schedule(neighbor, block, due, 0);
+        }
+    }
+}
+
+fn offset(pos: BlockPosition, dx: i32, dz: i32) -> BlockPosition {
+    BlockPosition::new(pos.x + dx, pos.y, pos.z + dz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_test_framework::Test;
+
+    /// A test with a stone floor at y = 63 across all loaded chunks,
+    /// so that fluids on it have no holes to flow towards.
+    fn test() -> Test {
+        let test = Test::new();
+        for x in -16..32 {
+            for z in -16..32 {
+                test.game
+                    .chunk_map
+                    .set_block_at(BlockPosition::new(x, 63, z), BlockId::stone());
+            }
+        }
+        test
+    }
+
+    fn place(test: &mut Test, pos: BlockPosition, block: BlockId) {
+        test.game.chunk_map.set_block_at(pos, block);
+        schedule_around(&mut test.game, pos);
+    }
+
+    fn run_ticks(test: &mut Test, ticks: u64) {
+        for _ in 0..ticks {
+            test.game.tick_count += 1;
+            let due = test
+                .game
+                .scheduled_ticks
+                .take_due(test.game.tick_count, usize::MAX);
+            for tick in due {
+                let event = ScheduledTickEvent {
+                    pos: tick.pos,
+                    block: tick.block,
+                };
+                test.handle(event, on_scheduled_tick_flow_fluid);
+            }
+        }
+    }
+
+    fn block(test: &Test, x: i32, y: i32, z: i32) -> BlockId {
+        test.game
+            .chunk_map
+            .block_at(BlockPosition::new(x, y, z))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_water_spreads() {
+        let mut test = test();
+        place(&mut test, BlockPosition::new(8, 64, 8), BlockId::water());
+        run_ticks(&mut test, 100);
+
+        for x in 0..16 {
+            for z in 0..16 {
+                let distance = (x - 8).abs() + (z - 8).abs();
+                let actual = block(&test, x, 64, z);
+                if distance <= 7 {
+                    assert_eq!(actual, BlockId::water().with_water_level(distance));
+                } else {
+                    assert!(actual.is_air());
+                }
+            }
+        }
+        assert!(test.game.scheduled_ticks.is_empty());
+    }
+
+    #[test]
+    fn test_water_recedes() {
+        let mut test = test();
+        place(&mut test, BlockPosition::new(8, 64, 8), BlockId::water());
+        run_ticks(&mut test, 100);
+
+        place(&mut test, BlockPosition::new(8, 64, 8), BlockId::air());
+        run_ticks(&mut test, 300);
+
+        for x in 0..16 {
+            for z in 0..16 {
+                assert!(block(&test, x, 64, z).is_air());
+            }
+        }
+    }
+
+    #[test]
+    fn test_water_flows_towards_hole() {
+        let mut test = test();
+        test.game
+            .chunk_map
+            .set_block_at(BlockPosition::new(10, 63, 8), BlockId::air());
+        place(&mut test, BlockPosition::new(8, 64, 8), BlockId::water());
+        run_ticks(&mut test, 6);
+
+        // Only the direction towards the hole is taken.
+        assert_eq!(block(&test, 9, 64, 8), BlockId::water().with_water_level(1));
+        assert!(block(&test, 7, 64, 8).is_air());
+        assert!(block(&test, 8, 64, 9).is_air());
+
+        run_ticks(&mut test, 10);
+        assert_eq!(
+            block(&test, 10, 63, 8),
+            BlockId::water().with_water_level(8)
+        );
+    }
+
+    #[test]
+    fn test_infinite_water() {
+        let mut test = test();
+        place(&mut test, BlockPosition::new(4, 64, 8), BlockId::water());
+        place(&mut test, BlockPosition::new(6, 64, 8), BlockId::water());
+        run_ticks(&mut test, 20);
+
+        assert_eq!(block(&test, 5, 64, 8), BlockId::water());
+    }
+
+    #[test]
+    fn test_lava_and_water() {
+        let mut test = test();
+
+        // Lava sources touching water turn into obsidian.
+        place(&mut test, BlockPosition::new(2, 64, 2), BlockId::lava());
+        place(&mut test, BlockPosition::new(3, 64, 2), BlockId::water());
+        // Flowing lava turns into cobblestone.
+        place(
+            &mut test,
+            BlockPosition::new(12, 64, 12),
+            BlockId::lava().with_water_level(2),
+        );
+        place(&mut test, BlockPosition::new(12, 64, 13), BlockId::water());
+        // Lava flowing down into water turns it into stone.
+        test.game
+            .chunk_map
+            .set_block_at(BlockPosition::new(8, 64, 8), BlockId::stone());
+        place(&mut test, BlockPosition::new(8, 65, 8), BlockId::water());
+        place(&mut test, BlockPosition::new(8, 66, 8), BlockId::lava());
+        run_ticks(&mut test, 40);
+
+        assert_eq!(block(&test, 2, 64, 2), BlockId::obsidian());
+        assert_eq!(block(&test, 12, 64, 12), BlockId::cobblestone());
+        assert_eq!(block(&test, 8, 65, 8), BlockId::stone());
+    }
+
+    #[test]
+    fn test_flow_direction() {
+        let test = test();
+        let chunk_map = &test.game.chunk_map;
+        chunk_map.set_block_at(BlockPosition::new(8, 64, 8), BlockId::water());
+        chunk_map.set_block_at(
+            BlockPosition::new(9, 64, 8),
+            BlockId::water().with_water_level(1),
+        );
+
+        for x in 8..=9 {
+            let flow = flow_at(chunk_map, BlockPosition::new(x, 64, 8));
+            assert!(flow.x > 0.0);
+            assert_eq!(flow.z, 0.0);
+        }
+        assert_eq!(
+            flow_at(chunk_map, BlockPosition::new(10, 64, 8)),
+            glm::zero()
+        );
+    }
+}
@@ -4,7 +4,12 @@ extern crate nalgebra_glm as glm;
 
 mod block_bboxes;
 mod entity;
+mod fluid;
 mod math;
 
+pub use block_bboxes::bbox_for_block;
 pub use entity::entity_physics;
+pub use fluid::{
+    flow_at, on_block_update_schedule_fluids, on_scheduled_tick_flow_fluid, Fluid, FluidState,
+};
 pub use math::*;
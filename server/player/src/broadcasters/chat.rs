@@ -1,7 +1,7 @@
 //! Broadcasting of chat messages
 
 use feather_core::network::packets::ChatMessageClientbound;
-use feather_server_types::{ChatEvent, ChatPosition, Game, MessageReceiver, Network, Player};
+use feather_server_types::{ChatEvent, ChatPosition, Game, MessageReceiver, Name, Network, Player};
 use fecs::{component, IntoQuery, Read, World, Write};
 
 /// System that broadcasts chat messages to all players
@@ -19,12 +19,16 @@ pub fn on_chat_broadcast(event: &ChatEvent, game: &Game, world: &mut World) {
 }
 
 /// System to flush a players `MessageReceiver` component and send the messages.
+///
+/// Score components in messages are resolved from the
+/// point of view of the receiving player.
 #[fecs::system]
-pub fn flush_player_message_receiver(world: &mut World) {
-    <(Write<MessageReceiver>, Read<Network>)>::query()
+pub fn flush_player_message_receiver(game: &Game, world: &mut World) {
+    <(Write<MessageReceiver>, Read<Network>, Read<Name>)>::query()
         .filter(component::<Player>())
-        .par_for_each_mut(world.inner_mut(), |(mut receiver, network)| {
-            for message in receiver.flush() {
+        .par_for_each_mut(world.inner_mut(), |(mut receiver, network, name)| {
+            for mut message in receiver.flush() {
+                game.scoreboard.resolve_text(&mut message, &name.0);
                 network.send(ChatMessageClientbound {
                     json_data: message.to_string(),
                     position: 0,
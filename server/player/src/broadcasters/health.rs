@@ -1,15 +1,19 @@
 use feather_core::network::packets::UpdateHealth;
-use feather_server_types::{HealthUpdateEvent, Network};
+use feather_server_types::{HealthUpdateEvent, Hunger, Network};
 use fecs::World;
 
 /// When a player's health is updated, updates it on the client.
 #[fecs::event_handler]
 pub fn on_health_update_send(event: &HealthUpdateEvent, world: &mut World) {
     if let Some(network) = world.try_get::<Network>(event.entity) {
+        let hunger = world
+            .try_get::<Hunger>(event.entity)
+            .map(|hunger| *hunger)
+            .unwrap_or_default();
         let packet = UpdateHealth {
             health: event.new as f32,
-            food: 20,        // todo
-            saturation: 5.0, // todo
+            food: hunger.food as i32,
+            saturation: hunger.saturation,
         };
         network.send(packet);
     }
@@ -0,0 +1,434 @@
+//! Crafting in a player's inventory and in crafting tables,
+//! along with the recipe book.
+
+use entity::drops::drop_item;
+use feather_core::blocks::BlockKind;
+use feather_core::inventory::{slot, Area, Inventory, SlotIndex, Window};
+use feather_core::items::{Item, ItemStack};
+use feather_core::network::packets::{
+    DeclareRecipes, DeclaredRecipe, DeclaredRecipeKind, OpenWindow, SetSlot, UnlockRecipes,
+};
+use feather_core::recipes::{self, CookingMethod, CraftingRecipe, Shape};
+use feather_core::text::TextRoot;
+use feather_core::util::{BlockPosition, Position};
+use feather_server_types::{
+    Game, InteractionHandler, InventoryUpdateEvent, Network, PlayerLeaveEvent, WindowCloseEvent,
+    WindowOpenEvent,
+};
+use fecs::{Entity, EntityBuilder, World};
+use once_cell::sync::Lazy;
+use smallvec::{smallvec, SmallVec};
+
+/// The Declare Recipes packet, which is the same for all players.
+static DECLARE_RECIPES: Lazy<DeclareRecipes> = Lazy::new(|| {
+    let crafting = recipes::crafting_recipes().iter().map(declared_crafting);
+    let smelting = recipes::cooking_recipes()
+        .iter()
+        .filter(|entry| entry.method == CookingMethod::Smelting)
+        .map(|entry| DeclaredRecipe {
+            id: entry.id.clone(),
+            kind: DeclaredRecipeKind::Smelting {
+                group: entry.group.clone(),
+                ingredient: ingredient(&entry.inputs),
+                result: Some(entry.recipe.result),
+                experience: entry.recipe.experience,
+                cooking_time: entry.recipe.cook_time as i32,
+            },
+        });
+
+    DeclareRecipes {
+        recipes: crafting.chain(smelting).collect(),
+    }
+});
+
+fn declared_crafting(recipe: &CraftingRecipe) -> DeclaredRecipe {
+    let kind = match &recipe.shape {
+        Shape::Shaped {
+            width,
+            height,
+            ingredients,
+        } => DeclaredRecipeKind::Shaped {
+            width: *width as i32,
+            height: *height as i32,
+            group: recipe.group.clone(),
+            ingredients: ingredients
+                .iter()
+                .map(|items| items.as_deref().map(ingredient).unwrap_or_default())
+                .collect(),
+            result: Some(recipe.result),
+        },
+        Shape::Shapeless { ingredients } => DeclaredRecipeKind::Shapeless {
+            group: recipe.group.clone(),
+            ingredients: ingredients.iter().map(|items| ingredient(items)).collect(),
+            result: Some(recipe.result),
+        },
+    };
+
+    DeclaredRecipe {
+        id: recipe.id.clone(),
+        kind,
+    }
+}
+
+/// Converts the items accepted by an ingredient to slots.
+fn ingredient(items: &[Item]) -> Vec<Option<ItemStack>> {
+    items
+        .iter()
+        .map(|item| Some(ItemStack::new(*item, 1)))
+        .collect()
+}
+
+/// Returns the Declare Recipes packet sent to joining players.
+pub(crate) fn declare_recipes() -> DeclareRecipes {
+    DECLARE_RECIPES.clone()
+}
+
+/// Returns the Unlock Recipes packet sent to joining players.
+///
+/// Recipe unlocking is not tracked, so every recipe
+/// in the recipe book is unlocked from the start.
+pub(crate) fn unlock_recipes() -> UnlockRecipes {
+    UnlockRecipes {
+        action: 0,
+        recipes: DECLARE_RECIPES
+            .recipes
+            .iter()
+            .map(|recipe| recipe.id.clone())
+            .collect(),
+        ..Default::default()
+    }
+}
+
+/// Component of the entity holding the crafting grid of an open
+/// crafting table. Crafting tables have no block entity; the grid
+/// exists only while the table is open and is despawned when it is closed.
+#[derive(Copy, Clone, Debug)]
+pub struct CraftingTable {
+    /// The player using the crafting table.
+    pub player: Entity,
+    /// The ID of the window the player has it open under.
+    pub window_id: u8,
+}
+
+/// Handler for player right clicking on crafting tables.
+pub struct CraftingTableInteraction;
+inventory::submit!(Box::new(CraftingTableInteraction) as Box<dyn InteractionHandler>);
+
+impl InteractionHandler for CraftingTableInteraction {
+    fn handle_interaction(
+        &self,
+        game: &mut Game,
+        world: &mut World,
+        _pos: BlockPosition,
+        player: Entity,
+        window_id: u8,
+    ) {
+        let grid = EntityBuilder::new()
+            .with(Inventory::crafting_table())
+            .with(CraftingTable { player, window_id })
+            .build()
+            .spawn_in(world);
+
+        world.get::<Network>(player).send(OpenWindow {
+            window_id,
+            window_type: String::from("minecraft:crafting_table"),
+            window_title: TextRoot::from("Crafting").into(),
+            number_of_slots: 0,
+            entity_id: None,
+        });
+
+        *world.get_mut::<Window>(player) = Window::crafting_table(player, grid);
+        game.handle(
+            world,
+            WindowOpenEvent {
+                player,
+                opened: grid,
+            },
+        );
+    }
+
+    fn block_kind(&self) -> BlockKind {
+        BlockKind::CraftingTable
+    }
+}
+
+/// When a crafting table is closed, gives the items left
+/// in its grid back to the player and removes the grid.
+#[fecs::event_handler]
+pub fn on_crafting_table_close_return_items(
+    event: &WindowCloseEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    if !world.has::<CraftingTable>(event.closed) {
+        return;
+    }
+
+    return_grid_items(game, world, event.player, event.closed);
+    world.despawn(event.closed);
+}
+
+/// When a player leaves, gives back the items in the crafting grid
+/// of their inventory and of any crafting table they have open, so
+/// that the items are saved, and removes the crafting table's grid.
+#[fecs::event_handler]
+pub fn on_player_leave_return_crafting_items(
+    event: &PlayerLeaveEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    let open: SmallVec<[Entity; 2]> = match world.try_get::<Window>(event.player) {
+        Some(window) => window.wrapped_entities().into(),
+        None => return,
+    };
+
+    for grid in open {
+        if world.has::<CraftingTable>(grid) {
+            return_grid_items(game, world, event.player, grid);
+            world.despawn(grid);
+        }
+    }
+    return_grid_items(game, world, event.player, event.player);
+}
+
+/// Moves the items in a crafting grid into a player's inventory,
+/// dropping those which do not fit.
+///
+/// `grid` is either a crafting table's grid or the player themselves.
+pub(crate) fn return_grid_items(game: &mut Game, world: &mut World, player: Entity, grid: Entity) {
+    let mut grid_slots: SmallVec<[SlotIndex; 9]> = SmallVec::new();
+    let mut player_slots: SmallVec<[SlotIndex; 2]> = SmallVec::new();
+    let mut leftover: SmallVec<[ItemStack; 2]> = SmallVec::new();
+
+    {
+        let grid_inventory = world.get::<Inventory>(grid);
+        let player_inventory = world.get::<Inventory>(player);
+        let size = grid_inventory.area_size(Area::CraftingInput).unwrap_or(0);
+
+        for i in 0..size {
+            if let Ok(Some(item)) = grid_inventory.remove_item_at(Area::CraftingInput, i) {
+                grid_slots.push(slot(Area::CraftingInput, i));
+
+                let (affected, remaining) = player_inventory.collect_item(item);
+                player_slots.extend(affected);
+                if remaining > 0 {
+                    leftover.push(item.of_amount(remaining));
+                }
+            }
+        }
+    }
+
+    if grid == player {
+        player_slots.extend(grid_slots);
+    } else if !grid_slots.is_empty() {
+        game.handle(
+            world,
+            InventoryUpdateEvent {
+                entity: grid,
+                slots: grid_slots.into_iter().collect(),
+            },
+        );
+    }
+
+    if !player_slots.is_empty() {
+        game.handle(
+            world,
+            InventoryUpdateEvent {
+                entity: player,
+                slots: player_slots,
+            },
+        );
+    }
+
+    let pos = *world.get::<Position>(player);
+    for item in leftover {
+        drop_item(game, world, item, pos);
+    }
+}
+
+/// When the crafting grid of an inventory changes,
+/// updates the crafting result.
+#[fecs::event_handler]
+pub fn on_inventory_update_update_crafting_result(
+    event: &InventoryUpdateEvent,
+    game: &mut Game,
+    world: &mut World,
+) {
+    if !event
+        .slots
+        .iter()
+        .any(|slot| slot.area == Area::CraftingInput)
+    {
+        return;
+    }
+
+    let changed = match world.try_get::<Inventory>(event.entity) {
+        Some(inventory) => update_result(&inventory),
+        None => return,
+    };
+
+    if changed {
+        game.handle(
+            world,
+            InventoryUpdateEvent {
+                entity: event.entity,
+                slots: smallvec![slot(Area::CraftingOutput, 0)],
+            },
+        );
+    }
+}
+
+/// Sends changes to a crafting table's grid to the player using it.
+#[fecs::event_handler]
+pub fn on_crafting_table_update_send_set_slot(event: &InventoryUpdateEvent, world: &mut World) {
+    let table = match world.try_get::<CraftingTable>(event.entity) {
+        Some(table) => *table,
+        None => return,
+    };
+    let network = match world.try_get::<Network>(table.player) {
+        Some(network) => network,
+        None => return,
+    };
+
+    let window = world.get::<Window>(table.player);
+    let inventory = world.get::<Inventory>(event.entity);
+    for slot in &event.slots {
+        if let Some(index) = window.convert_slot(*slot, event.entity) {
+            network.send(SetSlot {
+                window_id: table.window_id as i8,
+                slot: index as i16,
+                slot_data: inventory.item_at(slot.area, slot.slot).unwrap(),
+            });
+        }
+    }
+}
+
+/// Returns the recipe matching the items in an inventory's crafting grid.
+pub fn current_recipe(inventory: &Inventory) -> Option<&'static CraftingRecipe> {
+    let size = inventory.area_size(Area::CraftingInput).ok()?;
+    let width = if size == 9 { 3 } else { 2 };
+    let grid: SmallVec<[_; 9]> = (0..size)
+        .map(|i| {
+            inventory
+                .item_at(Area::CraftingInput, i)
+                .unwrap()
+                .map(|stack| stack.ty)
+        })
+        .collect();
+
+    recipes::crafting_recipe(&grid, width)
+}
+
+/// Sets the crafting output slot of an inventory to the result
+/// of the recipe in its grid. Returns whether the slot changed.
+pub fn update_result(inventory: &Inventory) -> bool {
+    let result = current_recipe(inventory).map(|recipe| recipe.result);
+    let old = inventory.item_at(Area::CraftingOutput, 0).unwrap();
+    if old == result {
+        return false;
+    }
+
+    match result {
+        Some(result) => inventory.set_item_at(Area::CraftingOutput, 0, result),
+        None => inventory.remove_item_at(Area::CraftingOutput, 0),
+    }
+    .unwrap();
+    true
+}
+
+/// Uses up one item from each slot of a crafting grid, leaving
+/// behind remainders such as empty buckets. Then updates the result.
+///
+/// Returns the slots which changed, including the output slot.
+pub fn consume_ingredients(inventory: &Inventory) -> SmallVec<[SlotIndex; 2]> {
+    let mut changed = SmallVec::new();
+    let size = inventory.area_size(Area::CraftingInput).unwrap_or(0);
+
+    for i in 0..size {
+        let stack = match inventory.item_at(Area::CraftingInput, i).unwrap() {
+            Some(stack) => stack,
+            None => continue,
+        };
+
+        let remainder = recipes::crafting_remainder(stack.ty);
+        match remainder {
+            Some(remainder) if stack.amount == 1 => {
+                inventory.set_item_at(Area::CraftingInput, i, ItemStack::new(remainder, 1))
+            }
+            _ => inventory.set_item_at(Area::CraftingInput, i, stack.of_amount(stack.amount - 1)),
+        }
+        .unwrap();
+        changed.push(slot(Area::CraftingInput, i));
+    }
+
+    update_result(inventory);
+    changed.push(slot(Area::CraftingOutput, 0));
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::items::Item;
+
+    #[test]
+    fn test_crafting_grid() {
+        let inventory = Inventory::player();
+        inventory
+            .set_item_at(Area::CraftingInput, 0, ItemStack::new(Item::OakPlanks, 2))
+            .unwrap();
+        inventory
+            .set_item_at(Area::CraftingInput, 2, ItemStack::new(Item::OakPlanks, 1))
+            .unwrap();
+
+        assert!(update_result(&inventory));
+        assert!(!update_result(&inventory));
+        assert_eq!(
+            inventory.item_at(Area::CraftingOutput, 0).unwrap(),
+            Some(ItemStack::new(Item::Stick, 4))
+        );
+
+        let changed = consume_ingredients(&inventory);
+        assert_eq!(changed.len(), 3);
+        assert_eq!(
+            inventory.item_at(Area::CraftingInput, 0).unwrap(),
+            Some(ItemStack::new(Item::OakPlanks, 1))
+        );
+        assert_eq!(inventory.item_at(Area::CraftingInput, 2).unwrap(), None);
+        assert_eq!(inventory.item_at(Area::CraftingOutput, 0).unwrap(), None);
+    }
+
+    #[test]
+    fn test_crafting_remainder() {
+        let inventory = Inventory::crafting_table();
+        let items = [
+            Item::MilkBucket,
+            Item::MilkBucket,
+            Item::MilkBucket,
+            Item::Sugar,
+            Item::Egg,
+            Item::Sugar,
+            Item::Wheat,
+            Item::Wheat,
+            Item::Wheat,
+        ];
+        for (i, item) in items.iter().enumerate() {
+            inventory
+                .set_item_at(Area::CraftingInput, i, ItemStack::new(*item, 1))
+                .unwrap();
+        }
+
+        assert!(update_result(&inventory));
+        assert_eq!(
+            inventory.item_at(Area::CraftingOutput, 0).unwrap(),
+            Some(ItemStack::new(Item::Cake, 1))
+        );
+
+        consume_ingredients(&inventory);
+        assert_eq!(
+            inventory.item_at(Area::CraftingInput, 0).unwrap(),
+            Some(ItemStack::new(Item::Bucket, 1))
+        );
+        assert_eq!(inventory.item_at(Area::CraftingInput, 4).unwrap(), None);
+    }
+}
@@ -0,0 +1,54 @@
+//! Status effects of players.
+
+use feather_core::network::packets::{EntityEffect, RemoveEntityEffect};
+use feather_server_types::{ActiveEffect, ActiveEffects, Network, NetworkId};
+use fecs::{IntoQuery, Read, World, Write};
+
+/// Returns the packet which shows the given effect
+/// on the entity with the given network ID.
+pub fn effect_packet(entity_id: i32, effect: &ActiveEffect) -> EntityEffect {
+    let mut flags = 0;
+    if effect.ambient {
+        flags |= 0x01;
+    }
+    if effect.show_particles {
+        flags |= 0x02;
+    }
+    if effect.show_icon {
+        flags |= 0x04;
+    }
+
+    EntityEffect {
+        entity_id,
+        effect_id: effect.id as i8,
+        amplifier: effect.amplifier as i8,
+        duration: effect.duration as i32,
+        flags,
+    }
+}
+
+/// System which counts down the duration of active
+/// effects, removing those which have expired.
+#[fecs::system]
+pub fn tick_active_effects(world: &mut World) {
+    <(Write<ActiveEffects>, Read<NetworkId>, Read<Network>)>::query().for_each_mut(
+        world.inner_mut(),
+        |(mut effects, id, network)| {
+            effects.0.retain(|effect| {
+                if effect.duration > 1 {
+                    return true;
+                }
+
+                network.send(RemoveEntityEffect {
+                    entity_id: id.0,
+                    effect_id: effect.id as i8,
+                });
+                false
+            });
+
+            for effect in &mut effects.0 {
+                effect.duration -= 1;
+            }
+        },
+    );
+}
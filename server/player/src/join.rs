@@ -2,13 +2,14 @@
 
 use feather_core::blocks::BlockId;
 use feather_core::network::packets::{
-    HeldItemChangeClientbound, JoinGame, PlayerPositionAndLookClientbound, SpawnPosition, Tags,
+    HeldItemChangeClientbound, JoinGame, PlayerPositionAndLookClientbound, SetExperience,
+    SpawnPosition, Tags,
 };
 use feather_core::util::{BlockPosition, Difficulty, Dimension, Gamemode, Position};
 use feather_server_network::{ListenerToServerMessage, NetworkIoManager, ServerToListenerMessage};
 use feather_server_types::{
-    BumpVec, ChunkSendEvent, Game, HeldItem, Network, NetworkId, PlayerJoinEvent,
-    WorkerToServerMessage,
+    ActiveEffects, BumpVec, ChunkSendEvent, Experience, Game, HeldItem, Network, NetworkId,
+    PlayerJoinEvent, Uuid, WorkerToServerMessage,
 };
 use fecs::{IntoQuery, Read, World};
 use std::iter;
@@ -114,7 +115,8 @@ pub fn on_player_join_send_join_packets(event: &PlayerJoinEvent, game: &Game, wo
     let gamemode = *world.get::<Gamemode>(event.player);
     let held_item_slot = world.get::<HeldItem>(event.player);
 
-    // TODO
+    // TODO: use the player's `Dimension` once
+    // worlds other than the overworld exist
     let join_packet = JoinGame {
         entity_id: id.0,
         gamemode: gamemode.id(),
@@ -131,7 +133,7 @@ pub fn on_player_join_send_join_packets(event: &PlayerJoinEvent, game: &Game, wo
     };
     network.send(held_item_packet);
 
-    // TODO declare recipes
+    network.send(crate::crafting::declare_recipes());
 
     let tags_packet = Tags {
         block_tags: vec![],
@@ -161,7 +163,59 @@ pub fn on_player_join_send_join_packets(event: &PlayerJoinEvent, game: &Game, wo
     };
     network.send(tags_packet);
 
-    // TODO declare commands
+    feather_server_commands::permissions::send_permissions(world, event.player);
 
-    // TODO unlock recipes
+    network.send(crate::crafting::unlock_recipes());
+}
+
+/// Shows a player who has just joined the boss bars
+/// they were added to.
+#[fecs::event_handler]
+pub fn on_player_join_send_boss_bars(event: &PlayerJoinEvent, game: &Game, world: &mut World) {
+    let network = world.get::<Network>(event.player);
+    let uuid = *world.get::<Uuid>(event.player);
+
+    for packet in game.boss_bars.packets_for(uuid) {
+        network.send_boxed(packet);
+    }
+}
+
+/// Sends the experience and status effects restored
+/// from a player's data file to a player who has just joined.
+#[fecs::event_handler]
+pub fn on_player_join_send_player_state(event: &PlayerJoinEvent, world: &mut World) {
+    let network = world.get::<Network>(event.player);
+
+    if let Some(experience) = world.try_get::<Experience>(event.player) {
+        network.send(SetExperience {
+            experience_bar: experience.progress,
+            level: experience.level as i32,
+            total_experience: experience.total as i32,
+        });
+    }
+
+    if let Some(effects) = world.try_get::<ActiveEffects>(event.player) {
+        let entity_id = world.get::<NetworkId>(event.player).0;
+        for effect in &effects.0 {
+            network.send(crate::effect_packet(entity_id, effect));
+        }
+    }
+}
+
+/// Sends the world border to a player who has just joined.
+#[fecs::event_handler]
+pub fn on_player_join_send_world_border(event: &PlayerJoinEvent, game: &Game, world: &mut World) {
+    world
+        .get::<Network>(event.player)
+        .send(game.world_border.initialize_packet());
+}
+
+/// Sends the scoreboard to a player who has just joined.
+#[fecs::event_handler]
+pub fn on_player_join_send_scoreboard(event: &PlayerJoinEvent, game: &Game, world: &mut World) {
+    let network = world.get::<Network>(event.player);
+
+    for packet in game.scoreboard.packets() {
+        network.send_boxed(packet);
+    }
 }
@@ -4,34 +4,47 @@ extern crate nalgebra_glm as glm;
 
 mod broadcasters;
 mod chat;
+mod crafting;
 mod death;
+mod effects;
 mod join;
 mod packet_handlers;
 mod view;
+mod world_border;
 
+use feather_core::anvil::player::PlayerData;
 use feather_core::inventory::{Area, Inventory, SlotIndex, Window};
+use feather_core::items::Item;
 use feather_core::network::packets::{PlayerInfo, PlayerInfoAction, SpawnPlayer};
 use feather_core::network::Packet;
 use feather_core::text::Text;
-use feather_core::util::{Gamemode, Position};
+use feather_core::util::{BlockPosition, Dimension, Gamemode, Position};
 use feather_server_network::NewClientInfo;
 use feather_server_types::{
-    BlocksFallen, CanBreak, CanInstaBreak, CanRespawn, CanTakeDamage, ChunkHolder,
-    CreationPacketCreator, EntitySpawnEvent, Game, GamemodeUpdateEvent, Health, HealthUpdateEvent,
-    HeldItem, InventoryUpdateEvent, LastKnownPositions, MaxHealth, MessageReceiver, Name, Network,
-    NetworkId, OpenWindowCount, Player, PlayerJoinEvent, PlayerPreJoinEvent, PreviousPosition,
-    PreviousVelocity, ProfileProperties, SpawnPacketCreator, Uuid, Velocity,
+    ActiveEffect, ActiveEffects, BlocksFallen, CanBreak, CanInstaBreak, CanRespawn, CanTakeDamage,
+    ChunkHolder, CreationPacketCreator, EnderChest, EntitySpawnEvent, Experience, Game,
+    GamemodeUpdateEvent, Health, HealthUpdateEvent, HeldItem, Hunger, InventoryUpdateEvent,
+    LastKnownPositions, MaxHealth, MessageReceiver, Name, Network, NetworkId, OpenWindowCount,
+    Permissions, Player, PlayerJoinEvent, PlayerPreJoinEvent, PreviousPosition, PreviousVelocity,
+    ProfileProperties, SpawnPacketCreator, SpawnPoint, Uuid, Velocity,
 };
 use feather_server_util::degrees_to_stops;
 use fecs::{Entity, EntityRef, World};
 
 pub use broadcasters::*;
 pub use chat::*;
+pub use crafting::{
+    on_crafting_table_close_return_items, on_crafting_table_update_send_set_slot,
+    on_inventory_update_update_crafting_result, on_player_leave_return_crafting_items,
+    CraftingTable,
+};
 pub use death::*;
+pub use effects::*;
 pub use join::*;
 pub use packet_handlers::*;
 use std::sync::atomic::Ordering;
 pub use view::*;
+pub use world_border::*;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ItemTimedUse {
@@ -112,6 +125,15 @@ pub fn create(game: &mut Game, world: &mut World, info: NewClientInfo) -> Entity
 
     world.add(entity, MessageReceiver::default()).unwrap();
 
+    let op_level = game
+        .access_lists
+        .read()
+        .operator(info.uuid)
+        .map_or(0, |operator| operator.level);
+    world
+        .add(entity, Permissions::with_level(op_level))
+        .unwrap();
+
     world.add(entity, Player).unwrap();
 
     world.add(entity, CanRespawn).unwrap();
@@ -119,7 +141,10 @@ pub fn create(game: &mut Game, world: &mut World, info: NewClientInfo) -> Entity
     world
         .add(entity, Health(info.data.animal.health as u32))
         .unwrap();
-    world.add(entity, BlocksFallen::default()).unwrap();
+    world
+        .add(entity, BlocksFallen(info.data.fall_distance as f64))
+        .unwrap();
+    add_player_state_comps(world, entity, &info.data);
 
     game.player_count.fetch_add(1, Ordering::SeqCst);
     game.handle(world, EntitySpawnEvent { entity });
@@ -144,6 +169,79 @@ pub fn create(game: &mut Game, world: &mut World, info: NewClientInfo) -> Entity
     entity
 }
 
+/// Adds the components for the state restored
+/// from a player's data file: food, experience,
+/// spawn point, ender chest, effects and dimension.
+fn add_player_state_comps(world: &mut World, entity: Entity, data: &PlayerData) {
+    world
+        .add(
+            entity,
+            Hunger {
+                food: data.food_level.max(0).min(20) as u32,
+                saturation: data.food_saturation_level,
+                exhaustion: data.food_exhaustion_level,
+                tick_timer: data.food_tick_timer.max(0) as u32,
+            },
+        )
+        .unwrap();
+    world
+        .add(
+            entity,
+            Experience {
+                level: data.xp_level.max(0) as u32,
+                progress: data.xp_progress,
+                total: data.xp_total.max(0) as u32,
+                seed: data.xp_seed,
+                score: data.score,
+            },
+        )
+        .unwrap();
+
+    if let (Some(x), Some(y), Some(z)) = (data.spawn_x, data.spawn_y, data.spawn_z) {
+        world
+            .add(
+                entity,
+                SpawnPoint {
+                    position: BlockPosition::new(x, y, z),
+                    forced: data.spawn_forced.unwrap_or(false),
+                },
+            )
+            .unwrap();
+    }
+
+    let ender_chest = EnderChest::default();
+    for slot in &data.ender_items {
+        if Item::from_identifier(&slot.item).is_some() {
+            if let Err(e) = ender_chest
+                .0
+                .set_item_at(Area::Chest, slot.slot as usize, slot.into())
+            {
+                log::warn!("Invalid ender chest slot: {}", e);
+            }
+        }
+    }
+    world.add(entity, ender_chest).unwrap();
+
+    let effects = data
+        .active_effects
+        .iter()
+        .filter(|effect| effect.duration > 0)
+        .map(|effect| ActiveEffect {
+            id: effect.id as u8,
+            amplifier: effect.amplifier as u8,
+            duration: effect.duration as u32,
+            ambient: effect.ambient,
+            show_particles: effect.show_particles,
+            show_icon: effect.show_icon,
+        })
+        .collect();
+    world.add(entity, ActiveEffects(effects)).unwrap();
+
+    world
+        .add(entity, Dimension::from_id(data.dimension))
+        .unwrap();
+}
+
 fn add_gamemode_comps(world: &mut World, gamemode: Gamemode, entity: Entity) {
     world.add(entity, gamemode).unwrap();
 
@@ -1,15 +1,22 @@
 use crate::packet_handlers::IteratorExt;
 use feather_core::network::packets::ClientStatus;
 use feather_core::network::packets::Respawn;
-use feather_core::util::{Gamemode, Position};
-use feather_server_types::{Dead, Health, Network, PacketBuffers, Teleported};
-use fecs::World;
+use feather_core::position;
+use feather_core::util::{BlockPosition, Gamemode, Position};
+use feather_server_types::{
+    ActiveEffects, Dead, Game, Health, Hunger, Network, PacketBuffers, SpawnPoint, Teleported,
+};
+use fecs::{Entity, World};
 use std::sync::Arc;
 
 /// Handles the Client Status packet, which is sent
 /// when the user clicks the respawn button.
 #[fecs::system]
-pub fn handle_client_status(world: &mut World, packet_buffers: &Arc<PacketBuffers>) {
+pub fn handle_client_status(
+    game: &mut Game,
+    world: &mut World,
+    packet_buffers: &Arc<PacketBuffers>,
+) {
     packet_buffers
         .received::<ClientStatus>()
         .for_each_valid(world, |world, (player, packet)| {
@@ -18,10 +25,16 @@ pub fn handle_client_status(world: &mut World, packet_buffers: &Arc<PacketBuffer
                     // Perform respawn
                     let _ = world.remove::<Dead>(player);
 
-                    // TODO: support spawn positons
-                    *world.get_mut::<Position>(player) = Position::default();
+                    let position = respawn_position(game, world, player);
+                    *world.get_mut::<Position>(player) = position;
 
                     world.get_mut::<Health>(player).0 = 20;
+                    if let Some(mut hunger) = world.try_get_mut::<Hunger>(player) {
+                        *hunger = Hunger::default();
+                    }
+                    if let Some(mut effects) = world.try_get_mut::<ActiveEffects>(player) {
+                        effects.0.clear();
+                    }
 
                     world.add(player, Teleported).unwrap();
 
@@ -40,3 +53,20 @@ pub fn handle_client_status(world: &mut World, packet_buffers: &Arc<PacketBuffer
             }
         });
 }
+
+/// Returns the position at which a player respawns: their
+/// `SpawnPoint` if they have one, or the world spawn otherwise.
+fn respawn_position(game: &Game, world: &World, player: Entity) -> Position {
+    let spawn = world
+        .try_get::<SpawnPoint>(player)
+        .map(|point| point.position)
+        .unwrap_or_else(|| {
+            BlockPosition::new(game.level.spawn_x, game.level.spawn_y, game.level.spawn_z)
+        });
+
+    position!(
+        f64::from(spawn.x) + 0.5,
+        f64::from(spawn.y),
+        f64::from(spawn.z) + 0.5
+    )
+}
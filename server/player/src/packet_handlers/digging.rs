@@ -4,7 +4,7 @@
 //! for actions mostly unrelated to digging including eating, shooting bows,
 //! swapping items out to the offhand, and dropping items.
 
-use crate::{ItemTimedUse, IteratorExt};
+use crate::{may_modify_block, ItemTimedUse, IteratorExt};
 use entity::InventoryExt;
 use feather_core::blocks::{BlockId, HalfUpperLower, Part, SimplifiedBlockKind};
 use feather_core::inventory::{slot, Area, Inventory, Slot, SlotIndex};
@@ -247,6 +247,10 @@ fn handle_finished_digging(
 }
 
 fn dig(game: &mut Game, world: &mut World, player: Entity, pos: BlockPosition) {
+    if !may_modify_block(game, world, player, pos) {
+        return;
+    }
+
     let block = match game.block_at(pos) {
         Some(block) => block,
         None => {
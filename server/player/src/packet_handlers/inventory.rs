@@ -2,6 +2,7 @@
 //! This currently includes Creative Inventory Action, Held Item
 //! Change, and the venerable Click Window.
 
+use crate::crafting;
 use crate::IteratorExt;
 use feather_core::inventory::{Area, Inventory, SlotIndex, Window};
 use feather_core::items::ItemStack;
@@ -13,7 +14,7 @@ use feather_server_types::{
     Game, HeldItem, InventoryUpdateEvent, ItemDropEvent, Network, PacketBuffers,
 };
 use fecs::{Entity, World};
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 use std::convert::TryFrom;
 use std::sync::Arc;
 use thiserror::Error;
@@ -311,6 +312,17 @@ fn handle_click_window(
     packet: ClickWindow,
     mode: Mode,
 ) -> anyhow::Result<()> {
+    // Clicks on a crafting output slot take the crafted item.
+    if let Some(grid) = crafting_output_entity(world, player, packet.slot) {
+        return match mode {
+            Mode::SingleClick(_) => handle_craft(game, world, player, grid),
+            Mode::ShiftClick => handle_shift_craft(game, world, player, grid),
+            // Items cannot be placed in the output slot, and
+            // other actions on it are not supported.
+            _ => Ok(()),
+        };
+    }
+
     match mode {
         Mode::SingleClick(button) => handle_single_click(game, world, player, packet, button),
         Mode::DoubleClick => handle_double_click(game, world, player, packet),
@@ -418,6 +430,142 @@ fn handle_single_click(
     Ok(())
 }
 
+/// If the given protocol slot is a crafting output slot,
+/// returns the entity owning the crafting grid.
+fn crafting_output_entity(world: &World, player: Entity, slot: i16) -> Option<Entity> {
+    if slot < 0 {
+        return None;
+    }
+
+    let window = world.get::<Window>(player);
+    let index = window.convert_network(slot as usize)?;
+    if index.area == Area::CraftingOutput {
+        window.corresponding_entity(slot as usize)
+    } else {
+        None
+    }
+}
+
+/// Picks up the result of a crafting grid, using up its ingredients.
+fn handle_craft(
+    game: &mut Game,
+    world: &mut World,
+    player: Entity,
+    grid: Entity,
+) -> anyhow::Result<()> {
+    let result = match world
+        .get::<Inventory>(grid)
+        .item_at(Area::CraftingOutput, 0)?
+    {
+        Some(result) => result,
+        None => return Ok(()),
+    };
+
+    match world.try_get::<PickedItem>(player).map(|i| *i) {
+        Some(picked) => {
+            // The result can only be added to a picked stack of the same item.
+            if !picked.0.eq_ignore_amount(result)
+                || u32::from(picked.0.amount) + u32::from(result.amount) > result.ty.stack_size()
+            {
+                return Ok(());
+            }
+            world.get_mut::<PickedItem>(player).0.amount += result.amount;
+        }
+        None => world.add(player, PickedItem(result)).unwrap(),
+    }
+
+    let slots = crafting::consume_ingredients(&world.get::<Inventory>(grid));
+    game.handle(
+        world,
+        InventoryUpdateEvent {
+            entity: grid,
+            slots,
+        },
+    );
+
+    Ok(())
+}
+
+/// Crafts as many items as possible from a crafting grid,
+/// moving them into the player's inventory.
+fn handle_shift_craft(
+    game: &mut Game,
+    world: &mut World,
+    player: Entity,
+    grid: Entity,
+) -> anyhow::Result<()> {
+    let mut player_slots: SmallVec<[SlotIndex; 2]> = SmallVec::new();
+    let mut grid_slots: SmallVec<[SlotIndex; 2]> = SmallVec::new();
+
+    {
+        let player_inventory = world.get::<Inventory>(player);
+        let grid_inventory = world.get::<Inventory>(grid);
+        let first = grid_inventory.item_at(Area::CraftingOutput, 0)?;
+
+        // Craft until the recipe changes, the ingredients
+        // run out or the player's inventory is full.
+        while let Some(result) = grid_inventory.item_at(Area::CraftingOutput, 0)? {
+            if Some(result) != first
+                || space_for(&player_inventory, result) < u32::from(result.amount)
+            {
+                break;
+            }
+
+            let (affected, _) = player_inventory.collect_item(result);
+            player_slots.extend(affected);
+            grid_slots.extend(crafting::consume_ingredients(&grid_inventory));
+        }
+    }
+
+    if grid == player {
+        player_slots.extend(grid_slots);
+    } else if !grid_slots.is_empty() {
+        grid_slots.sort();
+        grid_slots.dedup();
+        game.handle(
+            world,
+            InventoryUpdateEvent {
+                entity: grid,
+                slots: grid_slots,
+            },
+        );
+    }
+
+    if !player_slots.is_empty() {
+        player_slots.sort();
+        player_slots.dedup();
+        game.handle(
+            world,
+            InventoryUpdateEvent {
+                entity: player,
+                slots: player_slots,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Returns the number of the given item which fit
+/// into a player's hotbar and main inventory.
+fn space_for(inventory: &Inventory, item: ItemStack) -> u32 {
+    let stack_size = item.ty.stack_size();
+    [Area::Hotbar, Area::Main]
+        .iter()
+        .flat_map(|area| {
+            let size = inventory.area_size(*area).unwrap_or(0);
+            (0..size).map(move |i| inventory.item_at(*area, i).unwrap())
+        })
+        .map(|slot| match slot {
+            None => stack_size,
+            Some(stack) if stack.eq_ignore_amount(item) => {
+                stack_size.saturating_sub(stack.amount.into())
+            }
+            Some(_) => 0,
+        })
+        .sum()
+}
+
 fn handle_double_click(
     game: &mut Game,
     world: &mut World,
@@ -447,6 +595,11 @@ fn handle_double_click(
 
         // Iterate through all inventory slots, picking up items of the same type
         for (index, slot) in inventory.enumerate() {
+            // Crafting results can only be taken by crafting.
+            if index.area == Area::CraftingOutput {
+                continue;
+            }
+
             if let Some(slot) = slot {
                 // Remove items from the inventory until the player's PickedItem has reached its max stack size
                 if picked.eq_ignore_amount(slot) && slot.amount != stack_size {
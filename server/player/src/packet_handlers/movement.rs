@@ -2,17 +2,31 @@ use feather_core::network::packets::{
     PlayerLook, PlayerPosition, PlayerPositionAndLookServerbound,
 };
 use feather_core::util::Position;
-use feather_server_types::{Network, PacketBuffers};
+use feather_server_types::{Game, Network, PacketBuffers, Teleported};
 use fecs::{component, IntoQuery, World, Write};
+use parking_lot::Mutex;
 use std::sync::Arc;
 
 /// System to handle player movement updates.
+///
+/// Players may not move across the world border. Players
+/// who are beyond it, e.g. because it shrank, may only move
+/// towards it.
 #[fecs::system]
-pub fn handle_movement_packets(world: &mut World, packet_buffers: &Arc<PacketBuffers>) {
+pub fn handle_movement_packets(
+    game: &mut Game,
+    world: &mut World,
+    packet_buffers: &Arc<PacketBuffers>,
+) {
+    let border = &game.world_border;
+    let corrected = Mutex::new(vec![]);
+
     <Write<Position>>::query()
         .filter(component::<Network>())
         .par_entities_for_each_mut(world.inner_mut(), |(player, mut position)| {
             let mut position: &mut Position = &mut *position;
+            let old_position = *position;
+
             for position_and_look in
                 packet_buffers.received_for::<PlayerPositionAndLookServerbound>(player)
             {
@@ -36,5 +50,24 @@ pub fn handle_movement_packets(world: &mut World, packet_buffers: &Arc<PacketBuf
                 position.yaw = look.yaw;
                 position.on_ground = look.on_ground;
             }
+
+            let old_distance = border.distance_inside(old_position.x, old_position.z);
+            let new_distance = border.distance_inside(position.x, position.z);
+            if new_distance < 0.0 && new_distance < old_distance {
+                *position = if old_distance >= 0.0 {
+                    border.clamp(*position)
+                } else {
+                    Position {
+                        x: old_position.x,
+                        z: old_position.z,
+                        ..*position
+                    }
+                };
+                corrected.lock().push(player);
+            }
         });
+
+    for player in corrected.into_inner() {
+        let _ = world.add(player, Teleported);
+    }
 }
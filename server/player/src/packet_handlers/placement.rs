@@ -1,6 +1,6 @@
 //! Handling of player block placement packets.
 
-use crate::IteratorExt;
+use crate::{may_modify_block, IteratorExt};
 use entity::InventoryExt;
 use feather_core::blocks::categories::PlacementType;
 use feather_core::blocks::{
@@ -130,6 +130,10 @@ pub fn handle_block_placement(
             return;
         }
 
+        if !may_modify_block(game, world, player, pos) {
+            return;
+        }
+
         // Deny replacing grass with grass for example
         if current_block.is_replaceable()
             && !current_block.is_air()
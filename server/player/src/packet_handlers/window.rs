@@ -25,6 +25,12 @@ pub fn handle_close_window(
                 windows_closed
             };
 
+            // Closing the player's own inventory returns
+            // the items in its crafting grid.
+            if windows_closed.is_empty() {
+                crate::crafting::return_grid_items(game, world, player, player);
+            }
+
             for closed in windows_closed {
                 game.handle(world, WindowCloseEvent { player, closed });
             }
@@ -0,0 +1,51 @@
+//! Enforcement of the world border.
+
+use feather_core::network::packets::BlockChange;
+use feather_core::util::{BlockPosition, Position};
+use feather_server_types::{CanTakeDamage, Dead, Game, Network, Player, TPS};
+use fecs::{component, Entity, IntoQuery, Read, World};
+
+/// System which moves the world border and damages
+/// players beyond its safe zone once per second.
+#[fecs::system]
+pub fn tick_world_border(game: &mut Game, world: &mut World) {
+    game.world_border.tick();
+
+    if game.tick_count % TPS != 0 {
+        return;
+    }
+
+    let mut damaged = vec![];
+    for (player, pos) in <Read<Position>>::query()
+        .filter(component::<Player>())
+        .filter(component::<CanTakeDamage>())
+        .filter(!component::<Dead>())
+        .iter_entities(world.inner())
+    {
+        let damage = game.world_border.damage_at(pos.x, pos.z);
+        if damage > 0 {
+            damaged.push((player, damage));
+        }
+    }
+
+    for (player, damage) in damaged {
+        game.damage(player, damage, world);
+    }
+}
+
+/// Returns whether a player may place or break the block
+/// at the given position. If not, the player is sent the
+/// current block to undo the change predicted by the client.
+pub fn may_modify_block(game: &Game, world: &World, player: Entity, pos: BlockPosition) -> bool {
+    if game.world_border.contains_block(pos) {
+        return true;
+    }
+
+    if let (Some(network), Some(block)) = (world.try_get::<Network>(player), game.block_at(pos)) {
+        network.send(BlockChange {
+            location: pos,
+            block_id: block.vanilla_id() as i32,
+        });
+    }
+    false
+}
@@ -3,6 +3,7 @@ use feather_server_block::*;
 use feather_server_chunk::*;
 use feather_server_entity::*;
 use feather_server_lighting::*;
+use feather_server_physics::*;
 use feather_server_player::*;
 use feather_server_util::*;
 use feather_server_weather::*;
@@ -21,14 +22,21 @@ macro_rules! event_handlers {
 pub fn build_event_handlers() -> EventHandlers {
     event_handlers! {
         on_block_update_notify_adjacent,
+        on_block_update_schedule_fluids,
+        on_scheduled_tick_flow_fluid,
+        on_block_update_schedule_leaves,
+        on_scheduled_tick_update_leaves,
         on_block_break_broadcast_effect,
         on_block_update_broadcast,
         on_block_update_notify_lighting_worker,
         on_block_break_drop_loot,
         on_chest_break_drop_contents,
+        on_container_break_drop_contents,
         on_block_update_create_block_entity,
         on_chest_create_try_connect,
         on_chest_break_try_disconnect,
+        on_block_update_update_redstone,
+        on_scheduled_tick_update_redstone,
 
         on_entity_despawn_remove_chunk_holder,
         on_entity_despawn_update_chunk_entities,
@@ -45,12 +53,17 @@ pub fn build_event_handlers() -> EventHandlers {
         on_entity_client_remove_update_last_known_positions,
 
         on_player_join_send_join_packets,
+        on_player_join_send_scoreboard,
+        on_player_join_send_boss_bars,
+        on_player_join_send_world_border,
+        on_player_join_send_player_state,
         on_player_join_send_existing_entities,
         on_player_join_send_time,
         on_player_join_trigger_chunk_cross,
         on_player_join_send_weather,
         on_player_join_broadcast_join_message,
 
+        on_player_leave_return_crafting_items,
         on_player_leave_save_data,
 
         on_chunk_load_notify_lighting_worker,
@@ -69,6 +82,8 @@ pub fn build_event_handlers() -> EventHandlers {
         on_damage_item,
 
         on_inventory_update_send_set_slot,
+        on_inventory_update_update_crafting_result,
+        on_crafting_table_update_send_set_slot,
         on_inventory_update_broadcast_equipment_update,
 
         on_player_animation_broadcast_animation,
@@ -97,6 +112,7 @@ pub fn build_event_handlers() -> EventHandlers {
         on_gamemode_update_send,
 
         on_health_update_send,
+        on_health_update_record_hurt,
 
         on_player_death_scatter_inventory,
         on_player_death_mark_dead,
@@ -104,5 +120,7 @@ pub fn build_event_handlers() -> EventHandlers {
         on_chest_open_increment_viewers,
 
         on_chest_close_decrement_viewers,
+        on_furnace_close_remove_viewer,
+        on_crafting_table_close_return_items,
     }
 }
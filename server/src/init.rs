@@ -3,17 +3,23 @@
 use crate::{event_handlers, systems};
 use anyhow::Context;
 use feather_core::anvil::level::{LevelData, LevelGeneratorType};
+use feather_core::anvil::region::ChunkCompression;
+use feather_core::anvil::scoreboard::ScoreboardData;
 use feather_core::util::ChunkPosition;
 use feather_server_chunk::{chunk_worker, ChunkWorkerHandle};
 use feather_server_config::DEFAULT_CONFIG_STR;
 use feather_server_network::NetworkIoManager;
 use feather_server_packet_buffer::PacketBuffers;
-use feather_server_types::{task, Config, Game, Shared, ShutdownChannels};
+use feather_server_types::{
+    task, AccessLists, BossBars, Config, Game, Scoreboard, Shared, ShutdownChannels, WorldBorder,
+};
 use feather_server_worldgen::{
-    ComposableGenerator, EmptyWorldGenerator, SuperflatWorldGenerator, WorldGenerator,
+    ComposableGenerator, EmptyWorldGenerator, OreTable, SuperflatWorldGenerator, WorldGenerator,
+    DEFAULT_ORE_TABLE_STR,
 };
 use fecs::{EntityBuilder, Executor, OwnedResources, ResourcesProvider, World};
 use fxhash::FxHasher;
+use parking_lot::RwLock;
 use rand::Rng;
 use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
@@ -45,21 +51,38 @@ pub async fn init(
     let level = load_level(&config)
         .await
         .context("Failed to load level file (is your world directory corrupted?)")?;
+    let scoreboard = load_scoreboard(&config)
+        .await
+        .context("Failed to load scoreboard file")?;
+    let boss_bars = BossBars::from_data(&level.custom_boss_events);
+    let world_border = WorldBorder::from_level(&level);
+    let access_lists = AccessLists::load(".", config.server.whitelist)
+        .context("Failed to load the ban lists, whitelist or operator list")?;
+
+    let ore_table = load_ore_table()
+        .await
+        .context("Failed to load ore table `ores.toml`")?;
 
-    let cworker_handle = create_cworker_handle(&config, &level);
+    let cworker_handle = create_cworker_handle(&config, &level, ore_table);
 
     let mut game = Game {
         shared: Arc::new(Shared {
             config: Arc::clone(&config),
             rng: Default::default(),
             player_count: Arc::new(Default::default()),
+            access_lists: Arc::new(RwLock::new(access_lists)),
         }),
         chunk_map: Default::default(),
         tick_count: 0,
         chunk_holders: Default::default(),
         block_entities: Default::default(),
         level,
+        scoreboard,
+        boss_bars,
+        world_border,
         chunk_entities: Default::default(),
+        scheduled_ticks: Default::default(),
+        redstone: Default::default(),
         time: Default::default(),
         event_handlers: Arc::new(event_handlers),
         resources: Arc::new(Default::default()), // we override this momentarily
@@ -113,6 +136,25 @@ async fn load_config() -> anyhow::Result<Arc<Config>> {
     .map(Arc::new)
 }
 
+async fn load_ore_table() -> anyhow::Result<OreTable> {
+    const PATH: &str = "ores.toml";
+
+    match File::open(PATH).await {
+        Ok(mut file) => {
+            let mut s = String::new();
+            file.read_to_string(&mut s).await?;
+            OreTable::load(&s)
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let mut file = File::create(PATH).await?;
+            file.write_all(DEFAULT_ORE_TABLE_STR.as_bytes()).await?;
+
+            Ok(OreTable::default())
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
 fn set_up_logging(config: &Config) -> anyhow::Result<()> {
     use log::Level::*;
     let level = match config.log.level.as_str() {
@@ -155,6 +197,22 @@ async fn load_level(config: &Config) -> anyhow::Result<LevelData> {
     }
 }
 
+async fn load_scoreboard(config: &Config) -> anyhow::Result<Scoreboard> {
+    const SCOREBOARD_FILE_NAME: &str = "scoreboard.dat";
+    let data_dir = Path::new(&config.world.name).join("data");
+
+    // Create data directory (silently fail if it already exists)
+    let _ = tokio::fs::create_dir(&data_dir).await;
+
+    match File::open(data_dir.join(SCOREBOARD_FILE_NAME)).await {
+        Ok(mut file) => ScoreboardData::load_from_file(&mut file)
+            .await
+            .map(Scoreboard::from_data),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Scoreboard::new()),
+        Err(e) => Err(e.into()),
+    }
+}
+
 fn generate_level(config: &Config) -> LevelData {
     let seed = seed_for_config(config);
     let world_name = &config.world.name;
@@ -165,9 +223,13 @@ fn generate_level(config: &Config) -> LevelData {
         allow_commands: false,
         border_center_x: 0.0,
         border_center_z: 0.0,
-        border_damage_per_block: 0.0,
-        border_safe_zone: 0.0,
-        border_size: 0.0,
+        border_damage_per_block: 0.2,
+        border_safe_zone: 5.0,
+        border_size: 60_000_000.0,
+        border_size_lerp_target: 60_000_000.0,
+        border_size_lerp_time: 0,
+        border_warning_blocks: 5.0,
+        border_warning_time: 15.0,
         clear_weather_time: 0,
         data_version: 0,
         day_time: 0,
@@ -189,6 +251,8 @@ fn generate_level(config: &Config) -> LevelData {
         version: Default::default(),
         generator_name: config.world.generator.to_string(),
         generator_options: None,
+        custom_boss_events: Default::default(),
+        game_rules: Default::default(),
     }
 }
 
@@ -215,24 +279,46 @@ fn hash_seed(seed_raw: &str) -> i64 {
     hasher.finish() as i64
 }
 
-fn create_cworker_handle(config: &Config, level: &LevelData) -> ChunkWorkerHandle {
+fn create_cworker_handle(
+    config: &Config,
+    level: &LevelData,
+    ore_table: OreTable,
+) -> ChunkWorkerHandle {
     let generator: Arc<dyn WorldGenerator> = match level.generator_type() {
         LevelGeneratorType::Flat => Arc::new(SuperflatWorldGenerator {
             options: level.clone().generator_options.unwrap_or_default(),
         }),
-        LevelGeneratorType::Default => {
-            Arc::new(ComposableGenerator::default_with_seed(level.seed as u64))
-        }
+        LevelGeneratorType::Default => Arc::new(ComposableGenerator::with_ore_table(
+            level.seed as u64,
+            ore_table,
+        )),
         _ => Arc::new(EmptyWorldGenerator {}),
     };
 
-    let (tx, rx) = chunk_worker::start(Path::new(&config.world.name), generator);
+    let (tx, rx) = chunk_worker::start(
+        Path::new(&config.world.name),
+        generator,
+        chunk_compression(config.world.chunk_compression),
+    );
     ChunkWorkerHandle {
         sender: tx,
         receiver: rx,
     }
 }
 
+/// Converts the configured chunk compression to the
+/// one used by region files.
+fn chunk_compression(compression: feather_server_config::ChunkCompression) -> ChunkCompression {
+    use feather_server_config::ChunkCompression as Configured;
+    match compression {
+        Configured::Gzip => ChunkCompression::Gzip,
+        Configured::Zlib => ChunkCompression::Zlib,
+        Configured::None => ChunkCompression::None,
+        Configured::Lz4 => ChunkCompression::Lz4,
+        Configured::Zstd => ChunkCompression::Zstd,
+    }
+}
+
 async fn create_networking_handle(
     config: Arc<Config>,
     game: &Game,
@@ -253,6 +339,7 @@ async fn create_networking_handle(
         socket,
         config,
         Arc::clone(&game.player_count),
+        Arc::clone(&game.access_lists),
         Arc::new(server_icon),
         packet_buffers,
     ))
@@ -133,6 +133,8 @@ async fn shut_down(resources: &OwnedResources, world: &mut World) -> anyhow::Res
     )?;
     log::info!("Saving level.dat");
     shutdown::save_level(&mut *resources.get_mut::<Game>()).await?;
+    log::info!("Saving scoreboard.dat");
+    shutdown::save_scoreboard(&*resources.get::<Game>()).await?;
     log::info!("Saving player data");
     shutdown::save_player_data(&*resources.get::<Game>(), &world)?;
     log::info!("Waiting for tasks to finish");
@@ -51,6 +51,8 @@ pub async fn save_level(game: &mut Game) -> anyhow::Result<()> {
     // Sync world time + level time
     let time = game.time.world_age() as i64;
     game.level.time = time;
+    game.level.custom_boss_events = game.boss_bars.to_data();
+    game.world_border.save_to_level(&mut game.level);
 
     let level_path = format!("{}/{}", game.config.world.name, "level.dat");
 
@@ -65,6 +67,21 @@ pub async fn save_level(game: &mut Game) -> anyhow::Result<()> {
     Ok(())
 }
 
+pub async fn save_scoreboard(game: &Game) -> anyhow::Result<()> {
+    let scoreboard_path = format!("{}/data/{}", game.config.world.name, "scoreboard.dat");
+
+    let mut file = File::create(&scoreboard_path).await?;
+    game.scoreboard
+        .to_data()
+        .save_to_file(&mut file)
+        .await
+        .context("failed to save scoreboard file")?;
+
+    file.flush().await?;
+
+    Ok(())
+}
+
 pub fn save_player_data(game: &Game, world: &World) -> anyhow::Result<()> {
     <Read<Player>>::query().for_each_entities(&world.inner(), |(player, _)| {
         feather_server_chunk::save_player_data(game, world, player);
@@ -2,6 +2,7 @@
 
 use fecs::Executor;
 
+use feather_server_block as block;
 use feather_server_chunk as chunk_logic;
 use feather_server_entity as entity;
 use feather_server_physics as physics;
@@ -14,6 +15,7 @@ pub fn build_executor() -> Executor {
     Executor::new()
         .with(player::poll_player_disconnect)
         .with(player::poll_new_clients)
+        .with(game::run_scheduled_ticks)
         .with(physics::entity_physics)
         .with(player::handle_movement_packets)
         .with(player::handle_close_window)
@@ -32,7 +34,12 @@ pub fn build_executor() -> Executor {
         .with(game::task::run_sync_tasks)
         .with(player::send_teleported)
         .with(weather::update_weather)
+        .with(player::tick_world_border)
+        .with(player::tick_active_effects)
         .with(entity::item::item_collect)
+        .with(entity::tick_mob_ai)
+        .with(entity::spawn_mobs)
+        .with(entity::despawn_mobs)
         .with(chunk_logic::handle_chunk_worker_replies)
         .with(chunk_logic::chunk_unload)
         .with(chunk_logic::chunk_optimize)
@@ -43,6 +50,9 @@ pub fn build_executor() -> Executor {
         .with(entity::broadcast_velocity)
         .with(entity::falling_block::spawn_falling_blocks)
         .with(entity::supported_blocks::break_unsupported_blocks)
+        .with(block::tick_furnaces)
+        .with(block::random_tick_blocks)
+        .with(block::update_comparators)
         .with(chunk_logic::chunk_save)
         .with(game::reset_bump_allocators)
         .with(game::increment_tick_count)
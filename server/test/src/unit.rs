@@ -86,8 +86,13 @@ impl Test {
             tick_count: 0,
             chunk_holders: Default::default(),
             level: Default::default(),
+            scoreboard: Default::default(),
+            boss_bars: Default::default(),
+            world_border: Default::default(),
             chunk_entities: Default::default(),
             block_entities: Default::default(),
+            scheduled_ticks: Default::default(),
+            redstone: Default::default(),
             time: Default::default(),
             event_handlers: Arc::new(event_handlers),
             resources: Arc::new(Default::default()),
@@ -96,6 +101,7 @@ impl Test {
                 config: Arc::new(Default::default()),
                 rng: Default::default(),
                 player_count: Arc::new(Default::default()),
+                access_lists: Arc::new(Default::default()),
             }),
         };
         resources.insert(cworker_handle);
@@ -204,6 +210,7 @@ impl Test {
                 gamemode: 1,
                 inventory: vec![],
                 held_item: 0,
+                ..Default::default()
             },
             position,
             sender: server_tx,
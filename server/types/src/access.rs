@@ -0,0 +1,629 @@
+//! Ban lists, the whitelist and the operator list.
+//!
+//! These are stored in the server directory using the same
+//! JSON files as vanilla: `banned-players.json`, `banned-ips.json`,
+//! `whitelist.json` and `ops.json`.
+
+use crate::task::tasks;
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+pub const BANNED_PLAYERS_FILE: &str = "banned-players.json";
+pub const BANNED_IPS_FILE: &str = "banned-ips.json";
+pub const WHITELIST_FILE: &str = "whitelist.json";
+pub const OPS_FILE: &str = "ops.json";
+
+/// The reason given for bans which don't specify one.
+pub const DEFAULT_BAN_REASON: &str = "Banned by an operator.";
+/// The permission level given to new operators.
+pub const DEFAULT_OP_LEVEL: u8 = 4;
+
+/// The date format used by the ban lists.
+const DATE_FORMAT: &str = "%Y-%m-%d %H:%M:%S %z";
+/// The expiry date of a permanent ban.
+const FOREVER: &str = "forever";
+
+/// Returns the UUID of an offline mode player with the given username.
+///
+/// This is Minecraft's method for computing offline mode UUIDs:
+/// an MD5 hash of `OfflinePlayer:<username>`.
+pub fn offline_mode_uuid(username: &str) -> Uuid {
+    // https://gist.github.com/games647/2b6a00a8fc21fd3b88375f03c9e2e603
+    let mut context = md5::Context::new();
+    context.consume(format!("OfflinePlayer:{}", username).as_bytes());
+    let computed = context.compute();
+    let bytes = computed.into();
+
+    let mut builder = uuid::Builder::from_bytes(bytes);
+
+    builder
+        .set_variant(uuid::Variant::RFC4122)
+        .set_version(uuid::Version::Md5);
+
+    builder.build()
+}
+
+/// The current time, in the local time zone.
+fn now() -> DateTime<FixedOffset> {
+    let now = Local::now();
+    now.with_timezone(now.offset())
+}
+
+/// A player's UUID and username. Whitelist entries
+/// consist only of a profile.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Profile {
+    pub uuid: Uuid,
+    pub name: String,
+}
+
+/// Information common to player and IP bans.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BanDetails {
+    #[serde(with = "date")]
+    pub created: DateTime<FixedOffset>,
+    /// The name of whoever issued the ban.
+    #[serde(default = "default_source")]
+    pub source: String,
+    /// `None` if the ban is permanent.
+    #[serde(default, with = "expiry")]
+    pub expires: Option<DateTime<FixedOffset>>,
+    #[serde(default = "default_reason")]
+    pub reason: String,
+}
+
+fn default_source() -> String {
+    String::from("(Unknown)")
+}
+
+fn default_reason() -> String {
+    String::from(DEFAULT_BAN_REASON)
+}
+
+impl BanDetails {
+    /// Creates details for a permanent ban issued now.
+    pub fn new(source: impl Into<String>, reason: Option<String>) -> Self {
+        Self {
+            created: now(),
+            source: source.into(),
+            expires: None,
+            reason: reason.unwrap_or_else(default_reason),
+        }
+    }
+
+    /// Returns whether the ban has expired.
+    pub fn is_expired(&self) -> bool {
+        self.expires.map(|expires| expires < now()).unwrap_or(false)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PlayerBan {
+    #[serde(flatten)]
+    pub profile: Profile,
+    #[serde(flatten)]
+    pub details: BanDetails,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IpBan {
+    pub ip: IpAddr,
+    #[serde(flatten)]
+    pub details: BanDetails,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Operator {
+    #[serde(flatten)]
+    pub profile: Profile,
+    pub level: u8,
+    /// Whether the operator may join when the server is full.
+    #[serde(rename = "bypassesPlayerLimit", default)]
+    pub bypasses_player_limit: bool,
+}
+
+/// The reason a player may not join the server.
+#[derive(Debug, Error, PartialEq)]
+pub enum LoginDenied {
+    #[error("You are banned from this server.\nReason: {}{}", .0.reason, removal_note(.0))]
+    Banned(BanDetails),
+    #[error("Your IP address is banned from this server.\nReason: {}{}", .0.reason, removal_note(.0))]
+    IpBanned(BanDetails),
+    #[error("You are not white-listed on this server!")]
+    NotWhitelisted,
+    #[error("Server is full!")]
+    ServerFull,
+}
+
+fn removal_note(details: &BanDetails) -> String {
+    match details.expires {
+        Some(expires) => format!(
+            "\nYour ban will be removed on {}",
+            expires.format(DATE_FORMAT)
+        ),
+        None => String::new(),
+    }
+}
+
+/// The server's ban lists, whitelist and operator list.
+///
+/// Lists loaded using `AccessLists::load` are saved back
+/// to their files in the background whenever they are modified.
+/// Lists created using `AccessLists::new` only exist in memory.
+#[derive(Debug, Default)]
+pub struct AccessLists {
+    directory: Option<PathBuf>,
+    writer: ListWriter,
+    banned_players: Vec<PlayerBan>,
+    banned_ips: Vec<IpBan>,
+    whitelist: Vec<Profile>,
+    ops: Vec<Operator>,
+    whitelist_enabled: bool,
+}
+
+impl AccessLists {
+    /// Creates empty lists which are not backed by files.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the lists from the given directory. Missing
+    /// files are treated as empty lists.
+    pub fn load(directory: impl Into<PathBuf>, whitelist_enabled: bool) -> anyhow::Result<Self> {
+        let directory = directory.into();
+
+        Ok(Self {
+            banned_players: load_list(&directory.join(BANNED_PLAYERS_FILE))?,
+            banned_ips: load_list(&directory.join(BANNED_IPS_FILE))?,
+            whitelist: load_list(&directory.join(WHITELIST_FILE))?,
+            ops: load_list(&directory.join(OPS_FILE))?,
+            whitelist_enabled,
+            directory: Some(directory),
+            writer: ListWriter::default(),
+        })
+    }
+
+    /// Reloads the whitelist from its file.
+    pub fn reload_whitelist(&mut self) -> anyhow::Result<()> {
+        if let Some(directory) = &self.directory {
+            self.whitelist = load_list(&directory.join(WHITELIST_FILE))?;
+        }
+        Ok(())
+    }
+
+    /// Determines whether a player may join the server.
+    ///
+    /// `server_full` should be set if the server has reached
+    /// its player limit, in which case only operators who
+    /// bypass the limit may join.
+    pub fn check_login(
+        &self,
+        uuid: Uuid,
+        ip: IpAddr,
+        server_full: bool,
+    ) -> Result<(), LoginDenied> {
+        if let Some(ban) = self.player_ban(uuid) {
+            return Err(LoginDenied::Banned(ban.details.clone()));
+        }
+        if let Some(ban) = self.ip_ban(ip) {
+            return Err(LoginDenied::IpBanned(ban.details.clone()));
+        }
+
+        let operator = self.operator(uuid);
+        if self.whitelist_enabled && !self.is_whitelisted(uuid) && operator.is_none() {
+            return Err(LoginDenied::NotWhitelisted);
+        }
+        if server_full && !operator.map(|op| op.bypasses_player_limit).unwrap_or(false) {
+            return Err(LoginDenied::ServerFull);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the active ban on the given player, if any.
+    pub fn player_ban(&self, uuid: Uuid) -> Option<&PlayerBan> {
+        self.banned_players
+            .iter()
+            .find(|ban| ban.profile.uuid == uuid && !ban.details.is_expired())
+    }
+
+    /// Returns the active ban on the given IP address, if any.
+    pub fn ip_ban(&self, ip: IpAddr) -> Option<&IpBan> {
+        self.banned_ips
+            .iter()
+            .find(|ban| ban.ip == ip && !ban.details.is_expired())
+    }
+
+    /// Returns all active player bans.
+    pub fn banned_players(&self) -> impl Iterator<Item = &PlayerBan> {
+        self.banned_players
+            .iter()
+            .filter(|ban| !ban.details.is_expired())
+    }
+
+    /// Returns all active IP bans.
+    pub fn banned_ips(&self) -> impl Iterator<Item = &IpBan> {
+        self.banned_ips
+            .iter()
+            .filter(|ban| !ban.details.is_expired())
+    }
+
+    pub fn whitelist(&self) -> impl Iterator<Item = &Profile> {
+        self.whitelist.iter()
+    }
+
+    pub fn is_whitelisted(&self, uuid: Uuid) -> bool {
+        self.whitelist.iter().any(|profile| profile.uuid == uuid)
+    }
+
+    pub fn is_whitelist_enabled(&self) -> bool {
+        self.whitelist_enabled
+    }
+
+    pub fn ops(&self) -> impl Iterator<Item = &Operator> {
+        self.ops.iter()
+    }
+
+    pub fn operator(&self, uuid: Uuid) -> Option<&Operator> {
+        self.ops.iter().find(|op| op.profile.uuid == uuid)
+    }
+
+    /// Finds a profile with the given name (ignoring case) in any of the lists.
+    pub fn profile_by_name(&self, name: &str) -> Option<&Profile> {
+        self.banned_players
+            .iter()
+            .map(|ban| &ban.profile)
+            .chain(self.whitelist.iter())
+            .chain(self.ops.iter().map(|op| &op.profile))
+            .find(|profile| profile.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Bans a player. Returns `false` if the player
+    /// was already banned.
+    pub fn ban(&mut self, profile: Profile, details: BanDetails) -> anyhow::Result<bool> {
+        if self.player_ban(profile.uuid).is_some() {
+            return Ok(false);
+        }
+
+        self.banned_players
+            .retain(|ban| ban.profile.uuid != profile.uuid);
+        self.banned_players.push(PlayerBan { profile, details });
+        self.save_list(BANNED_PLAYERS_FILE, &self.banned_players)?;
+        Ok(true)
+    }
+
+    /// Removes a player's ban. Returns `false` if the player
+    /// was not banned.
+    pub fn pardon(&mut self, uuid: Uuid) -> anyhow::Result<bool> {
+        let banned = self.player_ban(uuid).is_some();
+        self.banned_players.retain(|ban| ban.profile.uuid != uuid);
+        self.save_list(BANNED_PLAYERS_FILE, &self.banned_players)?;
+        Ok(banned)
+    }
+
+    /// Bans an IP address. Returns `false` if the address
+    /// was already banned.
+    pub fn ban_ip(&mut self, ip: IpAddr, details: BanDetails) -> anyhow::Result<bool> {
+        if self.ip_ban(ip).is_some() {
+            return Ok(false);
+        }
+
+        self.banned_ips.retain(|ban| ban.ip != ip);
+        self.banned_ips.push(IpBan { ip, details });
+        self.save_list(BANNED_IPS_FILE, &self.banned_ips)?;
+        Ok(true)
+    }
+
+    /// Removes an IP ban. Returns `false` if the address
+    /// was not banned.
+    pub fn pardon_ip(&mut self, ip: IpAddr) -> anyhow::Result<bool> {
+        let banned = self.ip_ban(ip).is_some();
+        self.banned_ips.retain(|ban| ban.ip != ip);
+        self.save_list(BANNED_IPS_FILE, &self.banned_ips)?;
+        Ok(banned)
+    }
+
+    /// Adds a player to the whitelist. Returns `false` if the
+    /// player was already whitelisted.
+    pub fn add_to_whitelist(&mut self, profile: Profile) -> anyhow::Result<bool> {
+        if self.is_whitelisted(profile.uuid) {
+            return Ok(false);
+        }
+
+        self.whitelist.push(profile);
+        self.save_list(WHITELIST_FILE, &self.whitelist)?;
+        Ok(true)
+    }
+
+    /// Removes a player from the whitelist. Returns `false` if
+    /// the player was not whitelisted.
+    pub fn remove_from_whitelist(&mut self, uuid: Uuid) -> anyhow::Result<bool> {
+        if !self.is_whitelisted(uuid) {
+            return Ok(false);
+        }
+
+        self.whitelist.retain(|profile| profile.uuid != uuid);
+        self.save_list(WHITELIST_FILE, &self.whitelist)?;
+        Ok(true)
+    }
+
+    /// Turns the whitelist on or off. Returns `false` if
+    /// it was already in the given state.
+    ///
+    /// This is not persisted; the whitelist state on startup
+    /// is determined by the `whitelist` option in the config.
+    pub fn set_whitelist_enabled(&mut self, enabled: bool) -> bool {
+        let changed = self.whitelist_enabled != enabled;
+        self.whitelist_enabled = enabled;
+        changed
+    }
+
+    /// Makes a player an operator with the given permission level.
+    /// Returns `false` if the player was already an operator.
+    pub fn op(&mut self, profile: Profile, level: u8) -> anyhow::Result<bool> {
+        if self.operator(profile.uuid).is_some() {
+            return Ok(false);
+        }
+
+        self.ops.push(Operator {
+            profile,
+            level,
+            bypasses_player_limit: false,
+        });
+        self.save_list(OPS_FILE, &self.ops)?;
+        Ok(true)
+    }
+
+    /// Removes a player from the operator list. Returns `false`
+    /// if the player was not an operator.
+    pub fn deop(&mut self, uuid: Uuid) -> anyhow::Result<bool> {
+        if self.operator(uuid).is_none() {
+            return Ok(false);
+        }
+
+        self.ops.retain(|op| op.profile.uuid != uuid);
+        self.save_list(OPS_FILE, &self.ops)?;
+        Ok(true)
+    }
+
+    /// Saves a list to its file. The list is serialized
+    /// immediately, but written by an asynchronous task.
+    fn save_list<T: Serialize>(&self, file_name: &'static str, list: &[T]) -> anyhow::Result<()> {
+        if let Some(directory) = &self.directory {
+            let json = serde_json::to_vec_pretty(list)?;
+            self.writer
+                .write(directory.join(file_name), file_name, json);
+        }
+        Ok(())
+    }
+}
+
+/// Writes list files in the background, ensuring that
+/// a list is never overwritten by an older version of it.
+#[derive(Debug)]
+struct ListWriter {
+    /// The number of writes requested so far.
+    requested: AtomicU64,
+    /// The newest write performed for each file.
+    written: Arc<Mutex<HashMap<&'static str, u64>>>,
+}
+
+impl Default for ListWriter {
+    fn default() -> Self {
+        Self {
+            requested: AtomicU64::new(0),
+            written: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl ListWriter {
+    fn write(&self, path: PathBuf, file_name: &'static str, contents: Vec<u8>) {
+        let version = self.requested.fetch_add(1, Ordering::AcqRel) + 1;
+        let written = Arc::clone(&self.written);
+
+        tasks().spawn(async move {
+            let mut written = written.lock().await;
+            let newest = written.entry(file_name).or_insert(0);
+            if *newest > version {
+                return;
+            }
+            *newest = version;
+
+            if let Err(e) = tokio::fs::write(&path, contents).await {
+                log::error!("Failed to save {}: {}", path.display(), e);
+            }
+        });
+    }
+}
+
+fn load_list<T>(path: &Path) -> anyhow::Result<Vec<T>>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    match File::open(path) {
+        Ok(file) => Ok(serde_json::from_reader(BufReader::new(file))?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
+
+mod date {
+    use super::DATE_FORMAT;
+    use chrono::{DateTime, FixedOffset};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(date: &DateTime<FixedOffset>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&date.format(DATE_FORMAT))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<FixedOffset>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        DateTime::parse_from_str(&s, DATE_FORMAT).map_err(D::Error::custom)
+    }
+}
+
+mod expiry {
+    use super::{DATE_FORMAT, FOREVER};
+    use chrono::{DateTime, FixedOffset};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(
+        expires: &Option<DateTime<FixedOffset>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match expires {
+            Some(date) => serializer.collect_str(&date.format(DATE_FORMAT)),
+            None => serializer.serialize_str(FOREVER),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s == FOREVER {
+            Ok(None)
+        } else {
+            DateTime::parse_from_str(&s, DATE_FORMAT)
+                .map(Some)
+                .map_err(D::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(name: &str) -> Profile {
+        Profile {
+            uuid: offline_mode_uuid(name),
+            name: name.to_string(),
+        }
+    }
+
+    #[test]
+    fn vanilla_format() {
+        let json = r#"[
+            {
+                "uuid": "069a79f4-44e9-4726-a5be-fca90e38aaf5",
+                "name": "Notch",
+                "created": "2020-06-01 12:30:00 +0200",
+                "source": "Server",
+                "expires": "forever",
+                "reason": "Banned by an operator."
+            }
+        ]"#;
+
+        let bans: Vec<PlayerBan> = serde_json::from_str(json).unwrap();
+        assert_eq!(bans.len(), 1);
+        assert_eq!(bans[0].profile.name, "Notch");
+        assert_eq!(bans[0].details.expires, None);
+        assert_eq!(
+            bans[0].details.created.format(DATE_FORMAT).to_string(),
+            "2020-06-01 12:30:00 +0200"
+        );
+
+        let value = serde_json::to_value(&bans).unwrap();
+        assert_eq!(value[0]["uuid"], "069a79f4-44e9-4726-a5be-fca90e38aaf5");
+        assert_eq!(value[0]["created"], "2020-06-01 12:30:00 +0200");
+        assert_eq!(value[0]["expires"], "forever");
+
+        let ops: Vec<Operator> = serde_json::from_str(
+            r#"[{"uuid":"069a79f4-44e9-4726-a5be-fca90e38aaf5","name":"Notch","level":4,"bypassesPlayerLimit":true}]"#,
+        )
+        .unwrap();
+        assert_eq!(ops[0].level, 4);
+        assert!(ops[0].bypasses_player_limit);
+    }
+
+    #[test]
+    fn check_login() {
+        let mut lists = AccessLists::new();
+        let ip: IpAddr = "192.168.1.67".parse().unwrap();
+        let steve = profile("Steve");
+        let alex = profile("Alex");
+
+        assert_eq!(lists.check_login(steve.uuid, ip, false), Ok(()));
+        assert_eq!(
+            lists.check_login(steve.uuid, ip, true),
+            Err(LoginDenied::ServerFull)
+        );
+
+        assert!(lists
+            .ban(steve.clone(), BanDetails::new("Server", None))
+            .unwrap());
+        assert!(!lists
+            .ban(steve.clone(), BanDetails::new("Server", None))
+            .unwrap());
+        match lists.check_login(steve.uuid, ip, false) {
+            Err(LoginDenied::Banned(details)) => assert_eq!(details.reason, DEFAULT_BAN_REASON),
+            r => panic!("{:?}", r),
+        }
+        assert!(lists.pardon(steve.uuid).unwrap());
+        assert!(!lists.pardon(steve.uuid).unwrap());
+
+        lists
+            .ban_ip(ip, BanDetails::new("Server", Some("Griefing".to_string())))
+            .unwrap();
+        assert!(matches!(
+            lists.check_login(alex.uuid, ip, false),
+            Err(LoginDenied::IpBanned(_))
+        ));
+        assert!(lists.pardon_ip(ip).unwrap());
+
+        lists.set_whitelist_enabled(true);
+        assert_eq!(
+            lists.check_login(alex.uuid, ip, false),
+            Err(LoginDenied::NotWhitelisted)
+        );
+        assert!(lists.add_to_whitelist(alex.clone()).unwrap());
+        assert_eq!(lists.check_login(alex.uuid, ip, false), Ok(()));
+
+        // Operators bypass the whitelist
+        assert!(lists.op(steve.clone(), DEFAULT_OP_LEVEL).unwrap());
+        assert_eq!(lists.check_login(steve.uuid, ip, false), Ok(()));
+        assert_eq!(lists.profile_by_name("steve"), Some(&steve));
+    }
+
+    #[test]
+    fn expired_ban() {
+        let mut lists = AccessLists::new();
+        let steve = profile("Steve");
+        let mut details = BanDetails::new("Server", None);
+        details.expires =
+            Some(DateTime::parse_from_str("2000-01-01 00:00:00 +0000", DATE_FORMAT).unwrap());
+
+        lists.ban(steve.clone(), details).unwrap();
+        assert!(lists.player_ban(steve.uuid).is_none());
+        assert_eq!(lists.banned_players().count(), 0);
+        // An expired ban may be replaced
+        assert!(lists
+            .ban(steve.clone(), BanDetails::new("Server", None))
+            .unwrap());
+        assert!(lists.player_ban(steve.uuid).is_some());
+    }
+}
@@ -0,0 +1,476 @@
+//! Custom boss bars, created using `/bossbar` or by plugins.
+//!
+//! All modifications made through `BossBars` are sent to
+//! the affected online players. Players who join later
+//! receive their boss bars through `BossBars::packets_for`.
+
+use crate::{Network, Player};
+use feather_core::anvil::level::BossEventData;
+use feather_core::network::packets::{BossBar as BossBarPacket, BossBarAction};
+use feather_core::network::Packet;
+use feather_core::text::Text;
+use fecs::{component, IntoQuery, Read, World};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use thiserror::Error;
+use uuid::Uuid;
+
+pub use feather_core::network::packets::{BossBarColor, BossBarDivision as BossBarStyle};
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BossBarError {
+    #[error("A bossbar already exists with the ID '{0}'")]
+    Exists(String),
+    #[error("No bossbar exists with the ID '{0}'")]
+    Unknown(String),
+}
+
+/// A custom boss bar.
+#[derive(Clone, Debug)]
+pub struct BossBar {
+    id: String,
+    /// The UUID identifying this boss bar to clients.
+    uuid: Uuid,
+    name: Text,
+    color: BossBarColor,
+    style: BossBarStyle,
+    value: i32,
+    max: i32,
+    visible: bool,
+    /// The players this boss bar is shown to,
+    /// including offline ones.
+    players: BTreeSet<Uuid>,
+}
+
+impl BossBar {
+    fn new(id: String, name: Text) -> Self {
+        Self {
+            id,
+            uuid: Uuid::new_v4(),
+            name,
+            color: BossBarColor::White,
+            style: BossBarStyle::NoDivision,
+            value: 0,
+            max: 100,
+            visible: true,
+            players: BTreeSet::new(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> &Text {
+        &self.name
+    }
+
+    pub fn color(&self) -> BossBarColor {
+        self.color
+    }
+
+    pub fn style(&self) -> BossBarStyle {
+        self.style
+    }
+
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    pub fn max(&self) -> i32 {
+        self.max
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    pub fn players(&self) -> impl Iterator<Item = Uuid> + '_ {
+        self.players.iter().copied()
+    }
+
+    pub fn has_player(&self, player: Uuid) -> bool {
+        self.players.contains(&player)
+    }
+
+    /// Returns the filled fraction of the bar.
+    pub fn health(&self) -> f32 {
+        if self.max <= 0 {
+            return 0.0;
+        }
+        (self.value as f32 / self.max as f32).max(0.0).min(1.0)
+    }
+
+    fn packet(&self, action: BossBarAction) -> BossBarPacket {
+        BossBarPacket {
+            uuid: self.uuid,
+            action,
+        }
+    }
+
+    fn add_packet(&self) -> BossBarPacket {
+        self.packet(BossBarAction::Add(
+            String::from(self.name.clone()),
+            self.health(),
+            self.color,
+            self.style,
+            0,
+        ))
+    }
+
+    fn to_data(&self) -> BossEventData {
+        BossEventData {
+            name: String::from(self.name.clone()),
+            color: color_name(self.color).to_owned(),
+            overlay: style_name(self.style).to_owned(),
+            value: self.value,
+            max: self.max,
+            visible: self.visible,
+            players: self.players.iter().map(|uuid| (*uuid).into()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn from_data(id: String, data: &BossEventData) -> Self {
+        let name =
+            serde_json::from_str(&data.name).unwrap_or_else(|_| Text::from(data.name.clone()));
+        let mut bar = Self::new(id, name);
+        bar.color = parse_color(&data.color).unwrap_or(BossBarColor::White);
+        bar.style = parse_style(&data.overlay).unwrap_or(BossBarStyle::NoDivision);
+        bar.value = data.value;
+        bar.max = data.max;
+        bar.visible = data.visible;
+        bar.players = data.players.iter().map(|uuid| (*uuid).into()).collect();
+        bar
+    }
+}
+
+/// The registry of custom boss bars, keyed by namespaced ID.
+#[derive(Default, Debug)]
+pub struct BossBars {
+    bars: BTreeMap<String, BossBar>,
+}
+
+impl BossBars {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&BossBar> {
+        self.bars.get(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &BossBar> {
+        self.bars.values()
+    }
+
+    /// Creates a boss bar. It is initially white, visible
+    /// and not shown to any players.
+    pub fn add(&mut self, id: &str, name: Text) -> Result<&BossBar, BossBarError> {
+        if self.bars.contains_key(id) {
+            return Err(BossBarError::Exists(id.to_owned()));
+        }
+
+        Ok(self
+            .bars
+            .entry(id.to_owned())
+            .or_insert_with(|| BossBar::new(id.to_owned(), name)))
+    }
+
+    /// Removes a boss bar, hiding it from its players.
+    pub fn remove(&mut self, world: &World, id: &str) -> Result<BossBar, BossBarError> {
+        let bar = self
+            .bars
+            .remove(id)
+            .ok_or_else(|| BossBarError::Unknown(id.to_owned()))?;
+
+        if bar.visible {
+            send_to(world, &bar.players, &bar.packet(BossBarAction::Remove));
+        }
+        Ok(bar)
+    }
+
+    pub fn set_name(&mut self, world: &World, id: &str, name: Text) -> Result<bool, BossBarError> {
+        self.update(world, id, |bar| {
+            if bar.name == name {
+                return None;
+            }
+            bar.name = name;
+            Some(BossBarAction::UpdateTitle(String::from(bar.name.clone())))
+        })
+    }
+
+    pub fn set_color(
+        &mut self,
+        world: &World,
+        id: &str,
+        color: BossBarColor,
+    ) -> Result<bool, BossBarError> {
+        self.update(world, id, |bar| {
+            if bar.color == color {
+                return None;
+            }
+            bar.color = color;
+            Some(BossBarAction::UpdateStyle(bar.color, bar.style))
+        })
+    }
+
+    pub fn set_style(
+        &mut self,
+        world: &World,
+        id: &str,
+        style: BossBarStyle,
+    ) -> Result<bool, BossBarError> {
+        self.update(world, id, |bar| {
+            if bar.style == style {
+                return None;
+            }
+            bar.style = style;
+            Some(BossBarAction::UpdateStyle(bar.color, bar.style))
+        })
+    }
+
+    pub fn set_value(&mut self, world: &World, id: &str, value: i32) -> Result<bool, BossBarError> {
+        self.update(world, id, |bar| {
+            if bar.value == value {
+                return None;
+            }
+            bar.value = value;
+            Some(BossBarAction::UpdateHealth(bar.health()))
+        })
+    }
+
+    pub fn set_max(&mut self, world: &World, id: &str, max: i32) -> Result<bool, BossBarError> {
+        self.update(world, id, |bar| {
+            if bar.max == max {
+                return None;
+            }
+            bar.max = max;
+            Some(BossBarAction::UpdateHealth(bar.health()))
+        })
+    }
+
+    /// Shows or hides a boss bar for all its players.
+    pub fn set_visible(
+        &mut self,
+        world: &World,
+        id: &str,
+        visible: bool,
+    ) -> Result<bool, BossBarError> {
+        let bar = self.get_mut(id)?;
+        if bar.visible == visible {
+            return Ok(false);
+        }
+        bar.visible = visible;
+
+        let packet = if visible {
+            bar.add_packet()
+        } else {
+            bar.packet(BossBarAction::Remove)
+        };
+        send_to(world, &bar.players, &packet);
+        Ok(true)
+    }
+
+    /// Sets the players a boss bar is shown to.
+    pub fn set_players(
+        &mut self,
+        world: &World,
+        id: &str,
+        players: impl IntoIterator<Item = Uuid>,
+    ) -> Result<bool, BossBarError> {
+        let bar = self.get_mut(id)?;
+        let players: BTreeSet<Uuid> = players.into_iter().collect();
+        if bar.players == players {
+            return Ok(false);
+        }
+
+        if bar.visible {
+            let removed = bar.players.difference(&players).copied().collect();
+            let added = players.difference(&bar.players).copied().collect();
+            send_to(world, &removed, &bar.packet(BossBarAction::Remove));
+            send_to(world, &added, &bar.add_packet());
+        }
+
+        bar.players = players;
+        Ok(true)
+    }
+
+    fn get_mut(&mut self, id: &str) -> Result<&mut BossBar, BossBarError> {
+        self.bars
+            .get_mut(id)
+            .ok_or_else(|| BossBarError::Unknown(id.to_owned()))
+    }
+
+    /// Applies `update` to a boss bar and sends the returned
+    /// action to its players. `update` returns `None` if
+    /// nothing changed.
+    fn update(
+        &mut self,
+        world: &World,
+        id: &str,
+        update: impl FnOnce(&mut BossBar) -> Option<BossBarAction>,
+    ) -> Result<bool, BossBarError> {
+        let bar = self.get_mut(id)?;
+        match update(bar) {
+            Some(action) => {
+                if bar.visible {
+                    send_to(world, &bar.players, &bar.packet(action));
+                }
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the packets needed to show a player
+    /// its boss bars after joining.
+    pub fn packets_for(&self, player: Uuid) -> Vec<Box<dyn Packet>> {
+        self.bars
+            .values()
+            .filter(|bar| bar.visible && bar.has_player(player))
+            .map(|bar| Box::new(bar.add_packet()) as Box<dyn Packet>)
+            .collect()
+    }
+
+    /// Loads boss bars from the level data.
+    pub fn from_data(data: &HashMap<String, BossEventData>) -> Self {
+        let bars = data
+            .iter()
+            .map(|(id, data)| (id.clone(), BossBar::from_data(id.clone(), data)))
+            .collect();
+        Self { bars }
+    }
+
+    /// Converts the boss bars to level data.
+    pub fn to_data(&self) -> HashMap<String, BossEventData> {
+        self.bars
+            .iter()
+            .map(|(id, bar)| (id.clone(), bar.to_data()))
+            .collect()
+    }
+}
+
+/// Sends a packet to the online players among `players`.
+fn send_to(world: &World, players: &BTreeSet<Uuid>, packet: &BossBarPacket) {
+    if players.is_empty() {
+        return;
+    }
+
+    <(Read<Uuid>, Read<Network>)>::query()
+        .filter(component::<Player>())
+        .iter(world.inner())
+        .filter(|(uuid, _)| players.contains(&**uuid))
+        .for_each(|(_, network)| network.send(packet.clone()));
+}
+
+fn color_name(color: BossBarColor) -> &'static str {
+    match color {
+        BossBarColor::Pink => "pink",
+        BossBarColor::Blue => "blue",
+        BossBarColor::Red => "red",
+        BossBarColor::Green => "green",
+        BossBarColor::Yellow => "yellow",
+        BossBarColor::Purple => "purple",
+        BossBarColor::White => "white",
+    }
+}
+
+fn parse_color(name: &str) -> Option<BossBarColor> {
+    Some(match name {
+        "pink" => BossBarColor::Pink,
+        "blue" => BossBarColor::Blue,
+        "red" => BossBarColor::Red,
+        "green" => BossBarColor::Green,
+        "yellow" => BossBarColor::Yellow,
+        "purple" => BossBarColor::Purple,
+        "white" => BossBarColor::White,
+        _ => return None,
+    })
+}
+
+fn style_name(style: BossBarStyle) -> &'static str {
+    match style {
+        BossBarStyle::NoDivision => "progress",
+        BossBarStyle::SixNotches => "notched_6",
+        BossBarStyle::TenNotches => "notched_10",
+        BossBarStyle::TwelveNotches => "notched_12",
+        BossBarStyle::TwentyNotches => "notched_20",
+    }
+}
+
+fn parse_style(name: &str) -> Option<BossBarStyle> {
+    Some(match name {
+        "progress" => BossBarStyle::NoDivision,
+        "notched_6" => BossBarStyle::SixNotches,
+        "notched_10" => BossBarStyle::TenNotches,
+        "notched_12" => BossBarStyle::TwelveNotches,
+        "notched_20" => BossBarStyle::TwentyNotches,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modify_boss_bar() {
+        let world = World::new();
+        let mut bars = BossBars::new();
+
+        bars.add("minecraft:test", Text::from("Test")).unwrap();
+        assert_eq!(
+            bars.add("minecraft:test", Text::from("Test")).unwrap_err(),
+            BossBarError::Exists("minecraft:test".to_owned())
+        );
+
+        assert_eq!(bars.set_value(&world, "minecraft:test", 50), Ok(true));
+        assert_eq!(bars.set_value(&world, "minecraft:test", 50), Ok(false));
+        assert_eq!(bars.get("minecraft:test").unwrap().health(), 0.5);
+
+        assert_eq!(bars.set_max(&world, "minecraft:test", 25), Ok(true));
+        assert_eq!(bars.get("minecraft:test").unwrap().health(), 1.0);
+
+        let player = Uuid::new_v4();
+        bars.set_players(&world, "minecraft:test", vec![player])
+            .unwrap();
+        assert_eq!(bars.packets_for(player).len(), 1);
+        assert!(bars.packets_for(Uuid::new_v4()).is_empty());
+
+        bars.set_visible(&world, "minecraft:test", false).unwrap();
+        assert!(bars.packets_for(player).is_empty());
+
+        bars.remove(&world, "minecraft:test").unwrap();
+        assert!(bars.get("minecraft:test").is_none());
+        assert_eq!(
+            bars.set_value(&world, "minecraft:test", 1),
+            Err(BossBarError::Unknown("minecraft:test".to_owned()))
+        );
+    }
+
+    #[test]
+    fn data_roundtrip() {
+        let world = World::new();
+        let mut bars = BossBars::new();
+        let player = Uuid::new_v4();
+
+        bars.add("minecraft:test", Text::from("Test")).unwrap();
+        bars.set_color(&world, "minecraft:test", BossBarColor::Red)
+            .unwrap();
+        bars.set_style(&world, "minecraft:test", BossBarStyle::TenNotches)
+            .unwrap();
+        bars.set_players(&world, "minecraft:test", vec![player])
+            .unwrap();
+
+        let data = bars.to_data();
+        assert_eq!(data["minecraft:test"].color, "red");
+        assert_eq!(data["minecraft:test"].overlay, "notched_10");
+
+        let loaded = BossBars::from_data(&data);
+        let bar = loaded.get("minecraft:test").unwrap();
+        assert_eq!(bar.color(), BossBarColor::Red);
+        assert_eq!(bar.style(), BossBarStyle::TenNotches);
+        assert!(bar.has_player(player));
+        assert_eq!(loaded.to_data(), data);
+    }
+}
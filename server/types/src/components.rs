@@ -1,5 +1,6 @@
 mod marker;
 mod network;
+mod permissions;
 mod physics;
 mod serialize;
 
@@ -8,13 +9,14 @@ pub use serialize::*;
 
 pub use feather_core::inventory::Inventory;
 pub use network::{Network, ServerToWorkerMessage, WorkerToServerMessage};
+pub use permissions::Permissions;
 pub use physics::{AABBExt, Physics, PhysicsBuilder};
 pub use uuid::Uuid;
 
 use ahash::AHashSet;
 use dashmap::DashMap;
 use feather_core::text::Text;
-use feather_core::util::{ChunkPosition, Position};
+use feather_core::util::{BlockPosition, ChunkPosition, Position};
 use fecs::Entity;
 
 /// The item an entity is currently holding.
@@ -130,3 +132,75 @@ pub struct MaxHealth(pub u32);
 /// since the last time they were on_ground.
 #[derive(Default, Copy, Clone, Debug)]
 pub struct BlocksFallen(pub f64);
+
+/// Food level of a player.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hunger {
+    /// Food level, between 0 and 20.
+    pub food: u32,
+    pub saturation: f32,
+    pub exhaustion: f32,
+    /// Ticks since the player last regenerated
+    /// or starved due to their food level.
+    pub tick_timer: u32,
+}
+
+impl Default for Hunger {
+    fn default() -> Self {
+        Self {
+            food: 20,
+            saturation: 5.0,
+            exhaustion: 0.0,
+            tick_timer: 0,
+        }
+    }
+}
+
+/// Experience of a player.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Experience {
+    pub level: u32,
+    /// Progress towards the next level, between 0 and 1.
+    pub progress: f32,
+    pub total: u32,
+    /// Seed used to choose enchantments.
+    pub seed: i32,
+    /// Score shown on the death screen.
+    pub score: i32,
+}
+
+/// Position at which a player respawns, set
+/// by sleeping in a bed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SpawnPoint {
+    pub position: BlockPosition,
+    /// Whether the player respawns here even
+    /// if there is no bed.
+    pub forced: bool,
+}
+
+/// Contents of a player's ender chest.
+#[derive(Debug)]
+pub struct EnderChest(pub Inventory);
+
+impl Default for EnderChest {
+    fn default() -> Self {
+        EnderChest(Inventory::chest())
+    }
+}
+
+/// A status effect active on an entity.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ActiveEffect {
+    pub id: u8,
+    pub amplifier: u8,
+    /// Remaining duration in ticks.
+    pub duration: u32,
+    pub ambient: bool,
+    pub show_particles: bool,
+    pub show_icon: bool,
+}
+
+/// Status effects active on an entity.
+#[derive(Clone, Debug, Default)]
+pub struct ActiveEffects(pub Vec<ActiveEffect>);
@@ -0,0 +1,109 @@
+use ahash::AHashSet;
+
+/// The permissions of a command sender: a vanilla operator
+/// level from 0 to 4, plus any number of named permission nodes
+/// granted independently of that level.
+///
+/// Nodes are dot-separated, e.g. `minecraft.command.gamemode`.
+/// Granting a node ending in `.*` grants every node below it;
+/// the single node `*` grants everything.
+#[derive(Clone, Debug, Default)]
+pub struct Permissions {
+    level: u8,
+    nodes: AHashSet<String>,
+}
+
+impl Permissions {
+    /// The highest operator level.
+    pub const MAX_LEVEL: u8 = 4;
+
+    /// Creates a set of permissions with the given
+    /// operator level and no additional nodes.
+    pub fn with_level(level: u8) -> Self {
+        Self {
+            level: level.min(Self::MAX_LEVEL),
+            nodes: AHashSet::new(),
+        }
+    }
+
+    /// Returns the operator level.
+    pub fn level(&self) -> u8 {
+        self.level
+    }
+
+    /// Sets the operator level, clamped to `MAX_LEVEL`.
+    pub fn set_level(&mut self, level: u8) {
+        self.level = level.min(Self::MAX_LEVEL);
+    }
+
+    /// Grants a permission node. Returns `false` if
+    /// the node had already been granted.
+    pub fn grant(&mut self, node: impl Into<String>) -> bool {
+        self.nodes.insert(node.into())
+    }
+
+    /// Revokes a permission node. Returns `false` if
+    /// the node had not been granted.
+    pub fn revoke(&mut self, node: &str) -> bool {
+        self.nodes.remove(node)
+    }
+
+    /// Returns whether the given node has been granted,
+    /// either directly or through a wildcard node.
+    pub fn has_node(&self, node: &str) -> bool {
+        if self.nodes.contains(node) || self.nodes.contains("*") {
+            return true;
+        }
+
+        node.match_indices('.')
+            .any(|(index, _)| self.nodes.contains(&format!("{}.*", &node[..index])))
+    }
+
+    /// Returns whether these permissions satisfy a requirement
+    /// of the given operator level _or_ the given node.
+    pub fn has(&self, level: u8, node: &str) -> bool {
+        self.level >= level || self.has_node(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels() {
+        let mut permissions = Permissions::with_level(2);
+        assert!(permissions.has(2, "minecraft.command.gamemode"));
+        assert!(!permissions.has(3, "minecraft.command.ban"));
+
+        permissions.set_level(10);
+        assert_eq!(permissions.level(), Permissions::MAX_LEVEL);
+        assert!(permissions.has(4, "minecraft.command.stop"));
+    }
+
+    #[test]
+    fn nodes() {
+        let mut permissions = Permissions::default();
+        assert!(!permissions.has_node("minecraft.command.ban"));
+
+        assert!(permissions.grant("minecraft.command.ban"));
+        assert!(!permissions.grant("minecraft.command.ban"));
+        assert!(permissions.has(3, "minecraft.command.ban"));
+        assert!(!permissions.has_node("minecraft.command.ban-ip"));
+
+        assert!(permissions.revoke("minecraft.command.ban"));
+        assert!(!permissions.has_node("minecraft.command.ban"));
+    }
+
+    #[test]
+    fn wildcards() {
+        let mut permissions = Permissions::default();
+        permissions.grant("minecraft.command.*");
+        assert!(permissions.has_node("minecraft.command.stop"));
+        assert!(!permissions.has_node("minecraft.commands"));
+        assert!(!permissions.has_node("feather.debug"));
+
+        permissions.grant("*");
+        assert!(permissions.has_node("feather.debug"));
+    }
+}
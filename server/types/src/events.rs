@@ -18,7 +18,7 @@ pub struct BlockUpdateEvent {
     pub cause: BlockUpdateCause,
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum BlockUpdateCause {
     /// The update was caused by an entity performing
     /// a block break/placement. Usually a player.
@@ -26,10 +26,29 @@ pub enum BlockUpdateCause {
     /// So far only when a block that needs to be
     /// supported loses it's support.
     Unsupported,
+    /// The block was destroyed without an entity breaking it,
+    /// e.g. by the `destroy` mode of `/setblock` and `/fill`.
+    /// Drops the block's loot like a broken block.
+    Destroyed,
+    /// The block was changed by flowing water or lava.
+    /// Blocks washed away drop their loot.
+    Fluid,
+    /// The block was blown up by an explosion with the given radius.
+    /// Drops the block's loot, though some of it may be destroyed.
+    Explosion { radius: f32 },
     /// Unknown cause.
     Unknown,
 }
 
+/// Triggered when a tick scheduled in `Game::scheduled_ticks` is due.
+#[derive(Copy, Clone, Debug)]
+pub struct ScheduledTickEvent {
+    /// Position of the ticked block
+    pub pos: BlockPosition,
+    /// The ticked block
+    pub block: BlockId,
+}
+
 /// Triggered directly _before_ an entity is removed from the world.
 ///
 /// As such, components can still be accessed.
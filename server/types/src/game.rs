@@ -1,4 +1,7 @@
-use crate::{BlockUpdateCause, Network, ServerToWorkerMessage};
+use crate::{
+    AccessLists, BlockUpdateCause, BossBars, Network, Redstone, ScheduledTicks, Scoreboard,
+    ServerToWorkerMessage, WorldBorder,
+};
 use crate::{
     BlockUpdateEvent, CanRespawn, Dead, EntityDeathEvent, EntityDespawnEvent, Health,
     HealthUpdateEvent, Name, PlayerLeaveEvent,
@@ -8,10 +11,13 @@ use bumpalo::Bump;
 use feather_core::anvil::level::LevelData;
 use feather_core::blocks::BlockId;
 use feather_core::chunk_map::ChunkMap;
+use feather_core::network::packets::DisconnectPlay;
 use feather_core::network::Packet;
+use feather_core::text::TextRoot;
 use feather_core::util::{BlockPosition, ChunkPosition, Position};
 use feather_server_config::Config;
 use fecs::{Entity, Event, EventHandlers, IntoQuery, OwnedResources, Read, RefResources, World};
+use parking_lot::RwLock;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 use smallvec::SmallVec;
@@ -31,6 +37,9 @@ pub struct Shared {
     pub rng: CachedThreadLocal<RefCell<SmallRng>>,
     /// The server player count.
     pub player_count: Arc<AtomicU32>, // fixme: double Arc
+    /// Ban lists, the whitelist and operators. Shared
+    /// with the network tasks, which check them on login.
+    pub access_lists: Arc<RwLock<AccessLists>>,
 }
 
 /// The `Game` resource, which acts as a central bus to bind together
@@ -49,9 +58,19 @@ pub struct Game {
     pub block_entities: AHashMap<BlockPosition, Entity>,
     /// The level data.
     pub level: LevelData,
+    /// The scoreboard: objectives, scores and teams.
+    pub scoreboard: Scoreboard,
+    /// Custom boss bars.
+    pub boss_bars: BossBars,
+    /// The world border.
+    pub world_border: WorldBorder,
     /// Associates chunks with the entities that reside in them. Used
     /// as an acceleration structure for spacial lookups.
     pub chunk_entities: ChunkEntities,
+    /// Ticks scheduled for blocks, such as flowing fluids.
+    pub scheduled_ticks: ScheduledTicks,
+    /// Pending redstone updates and recent torch toggles.
+    pub redstone: Redstone,
     /// World time, in the Minecraft way.
     pub time: Time,
     /// The event handler map.
@@ -159,6 +178,18 @@ impl Game {
         self.despawn(player, world);
     }
 
+    /// Kicks a player, showing them the given reason
+    /// on the disconnect screen.
+    pub fn kick(&mut self, player: Entity, world: &mut World, reason: &str) {
+        if let Some(network) = world.try_get::<Network>(player) {
+            network.send(DisconnectPlay {
+                reason: TextRoot::from(reason.to_owned()).into(),
+            });
+        }
+
+        self.disconnect(player, world, reason);
+    }
+
     /* BROADCAST FUNCTIONS */
     /// Broadcasts a packet to all online players.
     pub fn broadcast_global(&self, world: &World, packet: impl Packet, neq: Option<Entity>) {
@@ -3,17 +3,29 @@
 
 extern crate nalgebra_glm as glm;
 
+mod access;
+mod bossbar;
 mod components;
 mod events;
 mod game;
 mod misc;
+mod redstone;
 mod resources;
+mod scheduled_ticks;
+mod scoreboard;
 pub mod task;
+mod world_border;
 
+pub use access::*;
+pub use bossbar::*;
 pub use components::*;
 pub use events::*;
 pub use misc::*;
+pub use redstone::*;
 pub use resources::*;
+pub use scheduled_ticks::*;
+pub use scoreboard::*;
+pub use world_border::*;
 
 // Constants
 /// The number of ticks executed per second.
@@ -0,0 +1,134 @@
+//! State of the redstone engine which is kept between block updates.
+
+use ahash::AHashSet;
+use feather_core::util::BlockPosition;
+use std::collections::VecDeque;
+
+/// Blocks which are waiting to update their redstone state,
+/// and recent redstone torch toggles.
+///
+/// Updates caused while the queue is being processed are
+/// appended to it rather than handled immediately, so large
+/// circuits are updated in a loop instead of recursively.
+#[derive(Clone, Debug, Default)]
+pub struct Redstone {
+    queue: VecDeque<BlockPosition>,
+    /// Positions in `queue`. A position removed from this set
+    /// is skipped when it reaches the front of the queue.
+    queued: AHashSet<BlockPosition>,
+    processing: bool,
+    /// Game ticks at which redstone torches turned off, oldest first.
+    torch_toggles: VecDeque<(BlockPosition, u64)>,
+}
+
+impl Redstone {
+    /// Queues an update for the block at `pos`,
+    /// unless one is already queued.
+    pub fn push(&mut self, pos: BlockPosition) {
+        if self.queued.insert(pos) {
+            self.queue.push_back(pos);
+        }
+    }
+
+    /// Removes the next queued update.
+    pub fn pop(&mut self) -> Option<BlockPosition> {
+        while let Some(pos) = self.queue.pop_front() {
+            if self.queued.remove(&pos) {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    /// Cancels the queued update for the block at `pos`, if any.
+    pub fn remove(&mut self, pos: BlockPosition) {
+        self.queued.remove(&pos);
+    }
+
+    pub fn len(&self) -> usize {
+        self.queued.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queued.is_empty()
+    }
+
+    /// Marks the queue as being processed. Returns `false`
+    /// if it already was, in which case the caller should
+    /// leave the queued updates to the running loop.
+    pub fn begin_processing(&mut self) -> bool {
+        !std::mem::replace(&mut self.processing, true)
+    }
+
+    /// Marks the queue as no longer being processed.
+    pub fn end_processing(&mut self) {
+        self.processing = false;
+    }
+
+    /// Records that the torch at `pos` turned off at game tick
+    /// `now`, and returns how many times it has done so within
+    /// the last `window` ticks.
+    pub fn record_torch_toggle(&mut self, pos: BlockPosition, now: u64, window: u64) -> usize {
+        self.torch_toggles.push_back((pos, now));
+        self.torch_toggles(pos, now, window)
+    }
+
+    /// Returns how many times the torch at `pos` turned
+    /// off within the last `window` ticks before `now`.
+    pub fn torch_toggles(&mut self, pos: BlockPosition, now: u64, window: u64) -> usize {
+        while let Some((_, tick)) = self.torch_toggles.front() {
+            if now.saturating_sub(*tick) <= window {
+                break;
+            }
+            self.torch_toggles.pop_front();
+        }
+
+        self.torch_toggles
+            .iter()
+            .filter(|(toggled, _)| *toggled == pos)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue() {
+        let mut redstone = Redstone::default();
+        let a = BlockPosition::new(0, 64, 0);
+        let b = BlockPosition::new(1, 64, 0);
+        let c = BlockPosition::new(2, 64, 0);
+        redstone.push(a);
+        redstone.push(b);
+        redstone.push(a);
+        redstone.push(c);
+        redstone.remove(b);
+        assert_eq!(redstone.len(), 2);
+
+        assert_eq!(redstone.pop(), Some(a));
+        assert_eq!(redstone.pop(), Some(c));
+        assert_eq!(redstone.pop(), None);
+        assert!(redstone.is_empty());
+
+        assert!(redstone.begin_processing());
+        assert!(!redstone.begin_processing());
+        redstone.end_processing();
+        assert!(redstone.begin_processing());
+    }
+
+    #[test]
+    fn test_torch_toggles() {
+        let mut redstone = Redstone::default();
+        let a = BlockPosition::new(0, 64, 0);
+        let b = BlockPosition::new(1, 64, 0);
+        assert_eq!(redstone.record_torch_toggle(a, 0, 60), 1);
+        assert_eq!(redstone.record_torch_toggle(b, 10, 60), 1);
+        assert_eq!(redstone.record_torch_toggle(a, 20, 60), 2);
+        assert_eq!(redstone.torch_toggles(a, 60, 60), 2);
+        assert_eq!(redstone.torch_toggles(a, 61, 60), 1);
+        assert_eq!(redstone.torch_toggles(a, 100, 60), 0);
+        assert_eq!(redstone.torch_toggles(b, 100, 60), 0);
+    }
+}
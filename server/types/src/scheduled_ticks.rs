@@ -0,0 +1,265 @@
+//! Scheduled block ticks, which blocks such as flowing fluids
+//! and redstone repeaters request to receive a number of game
+//! ticks in the future.
+//!
+//! Pending ticks are saved with their chunk, in its
+//! `TileTicks` and `LiquidTicks` lists.
+
+use crate::{Game, ScheduledTickEvent};
+use ahash::{AHashMap, AHashSet};
+use feather_core::anvil::region::ScheduledBlockUpdate;
+use feather_core::blocks::{BlockId, BlockKind};
+use feather_core::util::{BlockPosition, ChunkPosition};
+use fecs::World;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Maximum number of scheduled ticks run in a single game tick.
+/// Any further ticks which are due run in the following game ticks.
+pub const MAX_SCHEDULED_TICKS_PER_TICK: usize = 65_536;
+
+/// Key of a tick in the queue: its due game tick, its
+/// priority, and the order in which it was scheduled.
+type TickKey = (u64, i32, u64);
+
+/// A tick scheduled for a block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScheduledTick {
+    pub pos: BlockPosition,
+    /// The block which scheduled the tick. The tick only runs
+    /// if the block at `pos` is still of the same kind.
+    pub block: BlockId,
+    /// The game tick at which the tick is due.
+    pub due: u64,
+    /// Ticks due at the same game tick run in
+    /// order of increasing priority.
+    pub priority: i32,
+}
+
+impl ScheduledTick {
+    /// Converts a tick loaded from a chunk, which is due `ticks_from_now`
+    /// after `now`. Returns `None` if the block is unknown.
+    pub fn from_anvil(update: &ScheduledBlockUpdate, now: u64) -> Option<Self> {
+        Some(Self {
+            pos: BlockPosition::new(update.x, update.y, update.z),
+            block: BlockId::from_identifier(&update.name)?,
+            due: now + update.ticks_from_now.max(0) as u64,
+            priority: update.priority,
+        })
+    }
+
+    /// Converts this tick to be saved with its chunk.
+    pub fn to_anvil(&self, now: u64) -> ScheduledBlockUpdate {
+        ScheduledBlockUpdate {
+            name: self.block.identifier().into(),
+            ticks_from_now: (self.due as i64 - now as i64) as i32,
+            priority: self.priority,
+            x: self.pos.x,
+            y: self.pos.y,
+            z: self.pos.z,
+        }
+    }
+}
+
+/// The queue of scheduled ticks, ordered by the game
+/// tick they are due at, then by priority, then by
+/// the order they were scheduled in.
+///
+/// A block has at most one pending tick; scheduling another
+/// tick for the same block kind at the same position has no effect.
+#[derive(Clone, Debug, Default)]
+pub struct ScheduledTicks {
+    queue: BTreeMap<TickKey, ScheduledTick>,
+    pending: AHashSet<(BlockPosition, BlockKind)>,
+    /// Keys of the ticks pending in each chunk.
+    by_chunk: AHashMap<ChunkPosition, BTreeSet<TickKey>>,
+    /// Number of ticks scheduled so far, used to keep
+    /// ticks with the same due time and priority in order.
+    scheduled_count: u64,
+}
+
+impl ScheduledTicks {
+    /// Schedules a tick for `block` at `pos`, due at game tick `due`.
+    pub fn schedule(&mut self, pos: BlockPosition, block: BlockId, due: u64, priority: i32) {
+        self.insert(ScheduledTick {
+            pos,
+            block,
+            due,
+            priority,
+        });
+    }
+
+    /// Inserts a scheduled tick, e.g. one loaded with a chunk.
+    pub fn insert(&mut self, tick: ScheduledTick) {
+        if self.pending.insert((tick.pos, tick.block.kind())) {
+            let key = (tick.due, tick.priority, self.scheduled_count);
+            self.queue.insert(key, tick);
+            self.by_chunk
+                .entry(tick.pos.chunk())
+                .or_default()
+                .insert(key);
+            self.scheduled_count += 1;
+        }
+    }
+
+    /// Returns whether a tick is pending for the given block kind at `pos`.
+    pub fn is_scheduled(&self, pos: BlockPosition, kind: BlockKind) -> bool {
+        self.pending.contains(&(pos, kind))
+    }
+
+    /// Removes and returns at most `limit` ticks which are
+    /// due at or before game tick `now`, in the order they should run.
+    pub fn take_due(&mut self, now: u64, limit: usize) -> Vec<ScheduledTick> {
+        let mut due = Vec::new();
+        while due.len() < limit {
+            let key = match self.queue.keys().next() {
+                Some(key) if key.0 <= now => *key,
+                _ => break,
+            };
+            let tick = self.queue.remove(&key).expect("key exists");
+            self.pending.remove(&(tick.pos, tick.block.kind()));
+
+            let chunk = tick.pos.chunk();
+            if let Some(keys) = self.by_chunk.get_mut(&chunk) {
+                keys.remove(&key);
+                if keys.is_empty() {
+                    self.by_chunk.remove(&chunk);
+                }
+            }
+
+            due.push(tick);
+        }
+        due
+    }
+
+    /// Returns the ticks pending in the given chunk.
+    pub fn in_chunk(&self, chunk: ChunkPosition) -> impl Iterator<Item = &ScheduledTick> + '_ {
+        self.by_chunk
+            .get(&chunk)
+            .into_iter()
+            .flatten()
+            .map(move |key| &self.queue[key])
+    }
+
+    /// Removes the ticks pending in the given chunk, which
+    /// should be done when the chunk is unloaded.
+    pub fn remove_chunk(&mut self, chunk: ChunkPosition) {
+        let keys = match self.by_chunk.remove(&chunk) {
+            Some(keys) => keys,
+            None => return,
+        };
+
+        for key in keys {
+            if let Some(tick) = self.queue.remove(&key) {
+                self.pending.remove(&(tick.pos, tick.block.kind()));
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+}
+
+/// System which runs the scheduled ticks which are due,
+/// triggering a `ScheduledTickEvent` for each.
+#[fecs::system]
+pub fn run_scheduled_ticks(game: &mut Game, world: &mut World) {
+    let due = game
+        .scheduled_ticks
+        .take_due(game.tick_count, MAX_SCHEDULED_TICKS_PER_TICK);
+
+    for tick in due {
+        // Blocks changed since the tick was scheduled don't receive it.
+        let block = match game.block_at(tick.pos) {
+            Some(block) if block.kind() == tick.block.kind() => block,
+            _ => continue,
+        };
+        game.handle(
+            world,
+            ScheduledTickEvent {
+                pos: tick.pos,
+                block,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let mut ticks = ScheduledTicks::default();
+        let a = BlockPosition::new(0, 64, 0);
+        let b = BlockPosition::new(-5, 10, 3);
+        let c = BlockPosition::new(1, 64, 0);
+        ticks.schedule(a, BlockId::water(), 10, 0);
+        ticks.schedule(b, BlockId::repeater(), 10, -1);
+        ticks.schedule(c, BlockId::water(), 5, 0);
+        // Already pending.
+        ticks.schedule(a, BlockId::water().with_water_level(2), 1, 0);
+        // Different block kind at the same position.
+        ticks.schedule(a, BlockId::lava(), 10, 0);
+
+        assert_eq!(ticks.len(), 4);
+        assert!(ticks.take_due(4, usize::MAX).is_empty());
+
+        let due: Vec<_> = ticks
+            .take_due(10, usize::MAX)
+            .into_iter()
+            .map(|tick| (tick.pos, tick.block.kind()))
+            .collect();
+        assert_eq!(
+            due,
+            vec![
+                (c, BlockKind::Water),
+                (b, BlockKind::Repeater),
+                (a, BlockKind::Water),
+                (a, BlockKind::Lava),
+            ]
+        );
+        assert!(ticks.is_empty());
+        assert!(!ticks.is_scheduled(a, BlockKind::Water));
+    }
+
+    #[test]
+    fn test_limit_and_chunks() {
+        let mut ticks = ScheduledTicks::default();
+        for x in 0..20 {
+            ticks.schedule(BlockPosition::new(x, 0, 0), BlockId::stone(), 0, 0);
+        }
+
+        assert_eq!(ticks.in_chunk(ChunkPosition::new(1, 0)).count(), 4);
+        ticks.remove_chunk(ChunkPosition::new(1, 0));
+        assert_eq!(ticks.len(), 16);
+        assert!(!ticks.is_scheduled(BlockPosition::new(16, 0, 0), BlockKind::Stone));
+
+        assert_eq!(ticks.take_due(0, 10).len(), 10);
+        assert_eq!(ticks.in_chunk(ChunkPosition::new(0, 0)).count(), 6);
+        assert_eq!(ticks.take_due(0, 10).len(), 6);
+        assert_eq!(ticks.in_chunk(ChunkPosition::new(0, 0)).count(), 0);
+        assert!(ticks.by_chunk.is_empty());
+    }
+
+    #[test]
+    fn test_anvil_round_trip() {
+        let tick = ScheduledTick {
+            pos: BlockPosition::new(-20, 70, 300),
+            block: BlockId::lava(),
+            due: 130,
+            priority: 2,
+        };
+
+        let update = tick.to_anvil(100);
+        assert_eq!(update.ticks_from_now, 30);
+        assert_eq!(update.name, "minecraft:lava");
+        assert_eq!(ScheduledTick::from_anvil(&update, 100), Some(tick));
+        // Loaded ticks are relative to the current game tick.
+        assert_eq!(ScheduledTick::from_anvil(&update, 5).unwrap().due, 35);
+    }
+}
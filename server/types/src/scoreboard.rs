@@ -0,0 +1,1145 @@
+//! The scoreboard, which stores objectives, scores and teams.
+//!
+//! All modifications made through `Scoreboard` are broadcast to
+//! online players. Players who join later receive the full
+//! scoreboard through `Scoreboard::packets`.
+
+use crate::Network;
+use feather_core::anvil::scoreboard::{ObjectiveData, ScoreData, ScoreboardData, TeamData};
+use feather_core::network::packets::{
+    DisplayScoreboard, ObjectiveRenderType, ScoreboardObjective, ScoreboardObjectiveAction,
+    TeamInfo, Teams, TeamsAction, UpdateScore, UpdateScoreAction,
+};
+use feather_core::network::Packet;
+use feather_core::text::{Color, Text};
+use fecs::{IntoQuery, Read, World};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The maximum length of objective and team names.
+pub const MAX_NAME_LENGTH: usize = 16;
+
+/// Criteria whose scores are managed by the server and
+/// cannot be modified using commands.
+const READ_ONLY_CRITERIA: [&str; 6] = ["health", "food", "air", "armor", "level", "xp"];
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ScoreboardError {
+    #[error("An objective already exists by that name")]
+    ObjectiveExists,
+    #[error("Unknown scoreboard objective '{0}'")]
+    UnknownObjective(String),
+    #[error("The name '{0}' is too long (maximum {} characters)", MAX_NAME_LENGTH)]
+    NameTooLong(String),
+    #[error("The objective '{0}' is read-only")]
+    ReadOnlyObjective(String),
+    #[error("Can't get value of {objective} for {holder}; none is set")]
+    NoScore { holder: String, objective: String },
+    #[error("You can only trigger objectives that are 'trigger' type")]
+    NotTrigger,
+    #[error("You cannot trigger this objective yet")]
+    TriggerLocked,
+    #[error("A team already exists by that name")]
+    TeamExists,
+    #[error("Unknown team '{0}'")]
+    UnknownTeam(String),
+}
+
+/// A scoreboard objective.
+#[derive(Clone, Debug)]
+pub struct Objective {
+    pub name: String,
+    /// The criteria which determines how scores are
+    /// updated, e.g. `dummy` or `deathCount`.
+    pub criteria: String,
+    pub display_name: Text,
+    pub render_type: ObjectiveRenderType,
+}
+
+impl Objective {
+    /// Returns whether scores of this objective can be
+    /// modified using commands.
+    pub fn is_read_only(&self) -> bool {
+        READ_ONLY_CRITERIA.contains(&self.criteria.as_str())
+    }
+
+    fn packet(&self, create: bool) -> ScoreboardObjective {
+        let display_name = String::from(self.display_name.clone());
+        ScoreboardObjective {
+            objective_name: self.name.clone(),
+            action: if create {
+                ScoreboardObjectiveAction::Create(display_name, self.render_type)
+            } else {
+                ScoreboardObjectiveAction::Update(display_name, self.render_type)
+            },
+        }
+    }
+}
+
+/// The score of a holder in some objective.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Score {
+    pub value: i32,
+    /// Whether the holder is prevented from changing this
+    /// score using `/trigger`.
+    pub locked: bool,
+}
+
+impl Default for Score {
+    fn default() -> Self {
+        Self {
+            value: 0,
+            locked: true,
+        }
+    }
+}
+
+/// A location where an objective can be displayed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DisplaySlot {
+    List,
+    Sidebar,
+    BelowName,
+    /// The sidebar shown to members of teams
+    /// with the given color, identified by its formatting code.
+    TeamSidebar(u8),
+}
+
+impl DisplaySlot {
+    pub const COUNT: usize = 19;
+
+    /// Returns the index of this slot as used by the
+    /// Display Scoreboard packet.
+    pub fn index(self) -> usize {
+        match self {
+            DisplaySlot::List => 0,
+            DisplaySlot::Sidebar => 1,
+            DisplaySlot::BelowName => 2,
+            DisplaySlot::TeamSidebar(code) => 3 + code as usize,
+        }
+    }
+
+    pub fn from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(DisplaySlot::List),
+            1 => Some(DisplaySlot::Sidebar),
+            2 => Some(DisplaySlot::BelowName),
+            3..=18 => Some(DisplaySlot::TeamSidebar(index as u8 - 3)),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for DisplaySlot {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "list" => Ok(DisplaySlot::List),
+            "sidebar" => Ok(DisplaySlot::Sidebar),
+            "belowName" => Ok(DisplaySlot::BelowName),
+            _ => {
+                let color = s.strip_prefix("sidebar.team.").ok_or(())?;
+                let color = Color::from_str(color).map_err(|_| ())?;
+                color_code(&color).map(DisplaySlot::TeamSidebar).ok_or(())
+            }
+        }
+    }
+}
+
+impl Display for DisplaySlot {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DisplaySlot::List => f.write_str("list"),
+            DisplaySlot::Sidebar => f.write_str("sidebar"),
+            DisplaySlot::BelowName => f.write_str("belowName"),
+            DisplaySlot::TeamSidebar(code) => {
+                write!(f, "sidebar.team.{}", COLOR_NAMES[*code as usize])
+            }
+        }
+    }
+}
+
+/// Determines for whom name tags or death messages of team members are shown.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    Always,
+    Never,
+    HideForOtherTeams,
+    HideForOwnTeam,
+}
+
+impl Visibility {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Visibility::Always => "always",
+            Visibility::Never => "never",
+            Visibility::HideForOtherTeams => "hideForOtherTeams",
+            Visibility::HideForOwnTeam => "hideForOwnTeam",
+        }
+    }
+}
+
+impl FromStr for Visibility {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Visibility::Always),
+            "never" => Ok(Visibility::Never),
+            "hideForOtherTeams" => Ok(Visibility::HideForOtherTeams),
+            "hideForOwnTeam" => Ok(Visibility::HideForOwnTeam),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Determines which entities team members collide with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CollisionRule {
+    Always,
+    Never,
+    PushOtherTeams,
+    PushOwnTeam,
+}
+
+impl CollisionRule {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CollisionRule::Always => "always",
+            CollisionRule::Never => "never",
+            CollisionRule::PushOtherTeams => "pushOtherTeams",
+            CollisionRule::PushOwnTeam => "pushOwnTeam",
+        }
+    }
+}
+
+impl FromStr for CollisionRule {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(CollisionRule::Always),
+            "never" => Ok(CollisionRule::Never),
+            "pushOtherTeams" => Ok(CollisionRule::PushOtherTeams),
+            "pushOwnTeam" => Ok(CollisionRule::PushOwnTeam),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A team of score holders.
+#[derive(Clone, Debug)]
+pub struct Team {
+    pub name: String,
+    pub display_name: Text,
+    /// Text prepended to the names of members.
+    pub prefix: Text,
+    /// Text appended to the names of members.
+    pub suffix: Text,
+    pub color: Option<Color>,
+    pub friendly_fire: bool,
+    pub see_friendly_invisibles: bool,
+    pub name_tag_visibility: Visibility,
+    pub death_message_visibility: Visibility,
+    pub collision_rule: CollisionRule,
+    members: BTreeSet<String>,
+}
+
+impl Team {
+    fn new(name: String, display_name: Text) -> Self {
+        Self {
+            name,
+            display_name,
+            prefix: Text::empty(),
+            suffix: Text::empty(),
+            color: None,
+            friendly_fire: true,
+            see_friendly_invisibles: true,
+            name_tag_visibility: Visibility::Always,
+            death_message_visibility: Visibility::Always,
+            collision_rule: CollisionRule::Always,
+            members: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the names of the members of this team.
+    pub fn members(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(String::as_str)
+    }
+
+    fn info(&self) -> TeamInfo {
+        let mut friendly_flags = 0;
+        if self.friendly_fire {
+            friendly_flags |= 0x01;
+        }
+        if self.see_friendly_invisibles {
+            friendly_flags |= 0x02;
+        }
+
+        TeamInfo {
+            display_name: String::from(self.display_name.clone()),
+            friendly_flags,
+            name_tag_visibility: self.name_tag_visibility.as_str().to_owned(),
+            collision_rule: self.collision_rule.as_str().to_owned(),
+            color: self
+                .color
+                .as_ref()
+                .and_then(color_code)
+                .map(i32::from)
+                .unwrap_or(RESET_COLOR_CODE),
+            prefix: String::from(self.prefix.clone()),
+            suffix: String::from(self.suffix.clone()),
+        }
+    }
+}
+
+/// Names of the colors indexed by formatting code.
+const COLOR_NAMES: [&str; 16] = [
+    "black",
+    "dark_blue",
+    "dark_green",
+    "dark_aqua",
+    "dark_red",
+    "dark_purple",
+    "gold",
+    "gray",
+    "dark_gray",
+    "blue",
+    "green",
+    "aqua",
+    "red",
+    "light_purple",
+    "yellow",
+    "white",
+];
+
+/// The formatting code sent for teams without a color.
+const RESET_COLOR_CODE: i32 = 21;
+
+/// Returns the formatting code of a color.
+fn color_code(color: &Color) -> Option<u8> {
+    Some(match color {
+        Color::Black => 0,
+        Color::DarkBlue => 1,
+        Color::DarkGreen => 2,
+        Color::DarkAqua => 3,
+        Color::DarkRed => 4,
+        Color::DarkPurple => 5,
+        Color::Gold => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::Blue => 9,
+        Color::Green => 10,
+        Color::Aqua => 11,
+        Color::Red => 12,
+        Color::LightPurple => 13,
+        Color::Yellow => 14,
+        Color::White => 15,
+        Color::Custom(_) => return None,
+    })
+}
+
+/// The scoreboard. Holds objectives, the scores of
+/// score holders in those objectives, and teams.
+///
+/// Score holders are identified by name: a player's
+/// username, an entity's UUID, or an arbitrary string.
+#[derive(Default, Debug)]
+pub struct Scoreboard {
+    objectives: BTreeMap<String, Objective>,
+    /// Holder => objective => score
+    scores: BTreeMap<String, BTreeMap<String, Score>>,
+    teams: BTreeMap<String, Team>,
+    /// Holder => team name
+    holder_teams: BTreeMap<String, String>,
+    display_slots: [Option<String>; DisplaySlot::COUNT],
+}
+
+impl Scoreboard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /* OBJECTIVES */
+    pub fn objective(&self, name: &str) -> Option<&Objective> {
+        self.objectives.get(name)
+    }
+
+    pub fn objectives(&self) -> impl Iterator<Item = &Objective> {
+        self.objectives.values()
+    }
+
+    fn objective_or_err(&self, name: &str) -> Result<&Objective, ScoreboardError> {
+        self.objective(name)
+            .ok_or_else(|| ScoreboardError::UnknownObjective(name.to_owned()))
+    }
+
+    /// Adds an objective. If `display_name` is `None`, the name is used.
+    pub fn add_objective(
+        &mut self,
+        world: &World,
+        name: &str,
+        criteria: &str,
+        display_name: Option<Text>,
+    ) -> Result<(), ScoreboardError> {
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(ScoreboardError::NameTooLong(name.to_owned()));
+        }
+        if self.objectives.contains_key(name) {
+            return Err(ScoreboardError::ObjectiveExists);
+        }
+
+        let objective = Objective {
+            name: name.to_owned(),
+            criteria: criteria.to_owned(),
+            display_name: display_name.unwrap_or_else(|| Text::from(name.to_owned())),
+            render_type: ObjectiveRenderType::Integer,
+        };
+        broadcast(world, objective.packet(true));
+        self.objectives.insert(name.to_owned(), objective);
+
+        Ok(())
+    }
+
+    /// Removes an objective, along with all its scores.
+    pub fn remove_objective(&mut self, world: &World, name: &str) -> Result<(), ScoreboardError> {
+        self.objectives
+            .remove(name)
+            .ok_or_else(|| ScoreboardError::UnknownObjective(name.to_owned()))?;
+
+        for scores in self.scores.values_mut() {
+            scores.remove(name);
+        }
+        self.scores.retain(|_, scores| !scores.is_empty());
+
+        for slot in self.display_slots.iter_mut() {
+            if slot.as_deref() == Some(name) {
+                *slot = None;
+            }
+        }
+
+        broadcast(
+            world,
+            ScoreboardObjective {
+                objective_name: name.to_owned(),
+                action: ScoreboardObjectiveAction::Remove,
+            },
+        );
+        Ok(())
+    }
+
+    /// Modifies the display name or render type of an objective.
+    pub fn modify_objective(
+        &mut self,
+        world: &World,
+        name: &str,
+        modify: impl FnOnce(&mut Objective),
+    ) -> Result<(), ScoreboardError> {
+        let objective = self
+            .objectives
+            .get_mut(name)
+            .ok_or_else(|| ScoreboardError::UnknownObjective(name.to_owned()))?;
+
+        modify(objective);
+        broadcast(world, objective.packet(false));
+        Ok(())
+    }
+
+    /* DISPLAY SLOTS */
+    /// Returns the name of the objective shown in a display slot.
+    pub fn displayed_objective(&self, slot: DisplaySlot) -> Option<&str> {
+        self.display_slots[slot.index()].as_deref()
+    }
+
+    /// Shows an objective in a display slot, or clears
+    /// the slot if `objective` is `None`.
+    pub fn set_display_slot(
+        &mut self,
+        world: &World,
+        slot: DisplaySlot,
+        objective: Option<&str>,
+    ) -> Result<(), ScoreboardError> {
+        if let Some(objective) = objective {
+            self.objective_or_err(objective)?;
+        }
+
+        self.display_slots[slot.index()] = objective.map(ToOwned::to_owned);
+        broadcast(world, display_packet(slot, objective));
+        Ok(())
+    }
+
+    /* SCORES */
+    /// Returns the names of all holders with at least one score.
+    pub fn holders(&self) -> impl Iterator<Item = &str> {
+        self.scores.keys().map(String::as_str)
+    }
+
+    /// Returns the scores of a holder, keyed by objective name.
+    pub fn scores_of(&self, holder: &str) -> impl Iterator<Item = (&str, Score)> {
+        self.scores
+            .get(holder)
+            .into_iter()
+            .flatten()
+            .map(|(objective, score)| (objective.as_str(), *score))
+    }
+
+    pub fn score(&self, holder: &str, objective: &str) -> Option<Score> {
+        self.scores.get(holder)?.get(objective).copied()
+    }
+
+    /// Sets a score. Fails if the objective does not exist or is read-only.
+    pub fn set_score(
+        &mut self,
+        world: &World,
+        holder: &str,
+        objective: &str,
+        value: i32,
+    ) -> Result<(), ScoreboardError> {
+        let objective = self.objective_or_err(objective)?;
+        if objective.is_read_only() {
+            return Err(ScoreboardError::ReadOnlyObjective(objective.name.clone()));
+        }
+        let objective = objective.name.clone();
+
+        self.update_score(world, holder, &objective, |score| score.value = value);
+        Ok(())
+    }
+
+    /// Sets a score, ignoring whether the objective is read-only.
+    /// Used by the server to update scores of managed criteria.
+    ///
+    /// Does nothing if the objective does not exist.
+    pub fn set_score_unchecked(
+        &mut self,
+        world: &World,
+        holder: &str,
+        objective: &str,
+        value: i32,
+    ) {
+        if self.objectives.contains_key(objective) {
+            self.update_score(world, holder, objective, |score| score.value = value);
+        }
+    }
+
+    fn update_score(
+        &mut self,
+        world: &World,
+        holder: &str,
+        objective: &str,
+        update: impl FnOnce(&mut Score),
+    ) {
+        let score = self
+            .scores
+            .entry(holder.to_owned())
+            .or_default()
+            .entry(objective.to_owned())
+            .or_default();
+        update(score);
+
+        broadcast(
+            world,
+            UpdateScore {
+                entity_name: holder.to_owned(),
+                objective_name: objective.to_owned(),
+                action: UpdateScoreAction::Update(score.value),
+            },
+        );
+    }
+
+    /// Removes the score of a holder in an objective, or all
+    /// the holder's scores if `objective` is `None`.
+    ///
+    /// Returns whether any score was removed.
+    pub fn reset_score(&mut self, world: &World, holder: &str, objective: Option<&str>) -> bool {
+        let scores = match self.scores.get_mut(holder) {
+            Some(scores) => scores,
+            None => return false,
+        };
+
+        let removed = match objective {
+            Some(objective) => scores.remove(objective).is_some(),
+            None => {
+                scores.clear();
+                true
+            }
+        };
+        if scores.is_empty() {
+            self.scores.remove(holder);
+        }
+
+        if removed {
+            broadcast(
+                world,
+                UpdateScore {
+                    entity_name: holder.to_owned(),
+                    objective_name: objective.unwrap_or_default().to_owned(),
+                    action: UpdateScoreAction::Remove,
+                },
+            );
+        }
+        removed
+    }
+
+    /// Allows a holder to change its score in a `trigger` objective once.
+    pub fn enable_trigger(
+        &mut self,
+        world: &World,
+        holder: &str,
+        objective: &str,
+    ) -> Result<(), ScoreboardError> {
+        if self.objective_or_err(objective)?.criteria != "trigger" {
+            return Err(ScoreboardError::NotTrigger);
+        }
+
+        self.update_score(world, holder, objective, |score| score.locked = false);
+        Ok(())
+    }
+
+    /// Changes a holder's score in a `trigger` objective, if it was enabled
+    /// using `enable_trigger`. The score is locked again afterwards.
+    ///
+    /// Returns the new score.
+    pub fn trigger(
+        &mut self,
+        world: &World,
+        holder: &str,
+        objective: &str,
+        update: impl FnOnce(i32) -> i32,
+    ) -> Result<i32, ScoreboardError> {
+        if self.objective_or_err(objective)?.criteria != "trigger" {
+            return Err(ScoreboardError::NotTrigger);
+        }
+        match self.score(holder, objective) {
+            Some(score) if !score.locked => (),
+            _ => return Err(ScoreboardError::TriggerLocked),
+        }
+
+        let mut value = 0;
+        self.update_score(world, holder, objective, |score| {
+            score.value = update(score.value);
+            score.locked = true;
+            value = score.value;
+        });
+        Ok(value)
+    }
+
+    /// Fills in score components of a text. `viewer`
+    /// is the holder name of the player the text is sent to,
+    /// used to resolve the `*` holder name.
+    pub fn resolve_text(&self, text: &mut Text, viewer: &str) {
+        text.resolve_scores(&mut |holder, objective| {
+            let holder = if holder == "*" { viewer } else { holder };
+            self.score(holder, objective).map(|score| score.value)
+        });
+    }
+
+    /* TEAMS */
+    pub fn team(&self, name: &str) -> Option<&Team> {
+        self.teams.get(name)
+    }
+
+    pub fn teams(&self) -> impl Iterator<Item = &Team> {
+        self.teams.values()
+    }
+
+    /// Returns the team a holder belongs to.
+    pub fn team_of(&self, holder: &str) -> Option<&Team> {
+        self.teams.get(self.holder_teams.get(holder)?)
+    }
+
+    /// Adds a team. If `display_name` is `None`, the name is used.
+    pub fn add_team(
+        &mut self,
+        world: &World,
+        name: &str,
+        display_name: Option<Text>,
+    ) -> Result<(), ScoreboardError> {
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(ScoreboardError::NameTooLong(name.to_owned()));
+        }
+        if self.teams.contains_key(name) {
+            return Err(ScoreboardError::TeamExists);
+        }
+
+        let team = Team::new(
+            name.to_owned(),
+            display_name.unwrap_or_else(|| Text::from(name.to_owned())),
+        );
+        broadcast(world, team_create_packet(&team));
+        self.teams.insert(name.to_owned(), team);
+
+        Ok(())
+    }
+
+    pub fn remove_team(&mut self, world: &World, name: &str) -> Result<(), ScoreboardError> {
+        let team = self
+            .teams
+            .remove(name)
+            .ok_or_else(|| ScoreboardError::UnknownTeam(name.to_owned()))?;
+
+        for member in &team.members {
+            self.holder_teams.remove(member);
+        }
+
+        broadcast(
+            world,
+            Teams {
+                team_name: name.to_owned(),
+                action: TeamsAction::Remove,
+            },
+        );
+        Ok(())
+    }
+
+    /// Modifies the properties of a team.
+    pub fn modify_team(
+        &mut self,
+        world: &World,
+        name: &str,
+        modify: impl FnOnce(&mut Team),
+    ) -> Result<(), ScoreboardError> {
+        let team = self
+            .teams
+            .get_mut(name)
+            .ok_or_else(|| ScoreboardError::UnknownTeam(name.to_owned()))?;
+
+        modify(team);
+        broadcast(
+            world,
+            Teams {
+                team_name: name.to_owned(),
+                action: TeamsAction::UpdateInfo(team.info()),
+            },
+        );
+        Ok(())
+    }
+
+    /// Adds holders to a team, removing them from
+    /// their previous teams.
+    pub fn join_team(
+        &mut self,
+        world: &World,
+        name: &str,
+        holders: &[String],
+    ) -> Result<(), ScoreboardError> {
+        if !self.teams.contains_key(name) {
+            return Err(ScoreboardError::UnknownTeam(name.to_owned()));
+        }
+
+        self.leave_team(world, holders);
+
+        let team = self.teams.get_mut(name).unwrap();
+        for holder in holders {
+            team.members.insert(holder.clone());
+            self.holder_teams.insert(holder.clone(), name.to_owned());
+        }
+
+        broadcast(
+            world,
+            Teams {
+                team_name: name.to_owned(),
+                action: TeamsAction::AddEntities(holders.to_vec()),
+            },
+        );
+        Ok(())
+    }
+
+    /// Removes holders from their teams. Returns the number
+    /// of holders which were in a team.
+    pub fn leave_team(&mut self, world: &World, holders: &[String]) -> usize {
+        // Team => holders removed from it
+        let mut removed: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for holder in holders {
+            if let Some(team_name) = self.holder_teams.remove(holder) {
+                if let Some(team) = self.teams.get_mut(&team_name) {
+                    team.members.remove(holder);
+                }
+                removed.entry(team_name).or_default().push(holder.clone());
+            }
+        }
+
+        let count = removed.values().map(Vec::len).sum();
+        for (team_name, holders) in removed {
+            broadcast(
+                world,
+                Teams {
+                    team_name,
+                    action: TeamsAction::RemoveEntities(holders),
+                },
+            );
+        }
+        count
+    }
+
+    /// Removes all members from a team. Returns the
+    /// number of removed members.
+    pub fn empty_team(&mut self, world: &World, name: &str) -> Result<usize, ScoreboardError> {
+        let members: Vec<String> = self
+            .team(name)
+            .ok_or_else(|| ScoreboardError::UnknownTeam(name.to_owned()))?
+            .members
+            .iter()
+            .cloned()
+            .collect();
+
+        Ok(self.leave_team(world, &members))
+    }
+
+    /* SYNCHRONIZATION */
+    /// Returns the packets needed to send the entire
+    /// scoreboard to a client which has just joined.
+    pub fn packets(&self) -> Vec<Box<dyn Packet>> {
+        let mut packets: Vec<Box<dyn Packet>> = Vec::new();
+
+        for objective in self.objectives.values() {
+            packets.push(Box::new(objective.packet(true)));
+        }
+
+        for (holder, scores) in &self.scores {
+            for (objective, score) in scores {
+                packets.push(Box::new(UpdateScore {
+                    entity_name: holder.clone(),
+                    objective_name: objective.clone(),
+                    action: UpdateScoreAction::Update(score.value),
+                }));
+            }
+        }
+
+        for (index, objective) in self.display_slots.iter().enumerate() {
+            if let Some(objective) = objective {
+                let slot = DisplaySlot::from_index(index).unwrap();
+                packets.push(Box::new(display_packet(slot, Some(objective))));
+            }
+        }
+
+        for team in self.teams.values() {
+            packets.push(Box::new(team_create_packet(team)));
+        }
+
+        packets
+    }
+
+    /* SERIALIZATION */
+    /// Loads a scoreboard from the contents of `scoreboard.dat`.
+    pub fn from_data(data: ScoreboardData) -> Self {
+        let mut scoreboard = Self::new();
+
+        for objective in data.objectives {
+            let render_type = match objective.render_type.as_str() {
+                "hearts" => ObjectiveRenderType::Hearts,
+                _ => ObjectiveRenderType::Integer,
+            };
+            scoreboard.objectives.insert(
+                objective.name.clone(),
+                Objective {
+                    name: objective.name,
+                    criteria: objective.criteria_name,
+                    display_name: parse_text(objective.display_name),
+                    render_type,
+                },
+            );
+        }
+
+        for score in data.player_scores {
+            if !scoreboard.objectives.contains_key(&score.objective) {
+                continue;
+            }
+            scoreboard.scores.entry(score.name).or_default().insert(
+                score.objective,
+                Score {
+                    value: score.score,
+                    locked: score.locked,
+                },
+            );
+        }
+
+        for data in data.teams {
+            let mut team = Team::new(data.name.clone(), parse_text(data.display_name));
+            team.prefix = parse_text(data.member_name_prefix);
+            team.suffix = parse_text(data.member_name_suffix);
+            team.color = data
+                .team_color
+                .and_then(|color| Color::from_str(&color).ok());
+            team.friendly_fire = data.allow_friendly_fire;
+            team.see_friendly_invisibles = data.see_friendly_invisibles;
+            team.name_tag_visibility =
+                Visibility::from_str(&data.name_tag_visibility).unwrap_or(Visibility::Always);
+            team.death_message_visibility =
+                Visibility::from_str(&data.death_message_visibility).unwrap_or(Visibility::Always);
+            team.collision_rule =
+                CollisionRule::from_str(&data.collision_rule).unwrap_or(CollisionRule::Always);
+
+            for player in data.players {
+                scoreboard
+                    .holder_teams
+                    .insert(player.clone(), data.name.clone());
+                team.members.insert(player);
+            }
+            scoreboard.teams.insert(data.name, team);
+        }
+
+        for (slot, objective) in data.display_slots {
+            let index = slot
+                .strip_prefix("slot_")
+                .and_then(|index| index.parse::<usize>().ok())
+                .filter(|index| *index < DisplaySlot::COUNT);
+            if let Some(index) = index {
+                if scoreboard.objectives.contains_key(&objective) {
+                    scoreboard.display_slots[index] = Some(objective);
+                }
+            }
+        }
+
+        scoreboard
+    }
+
+    /// Converts this scoreboard to the contents of `scoreboard.dat`.
+    pub fn to_data(&self) -> ScoreboardData {
+        let objectives = self
+            .objectives
+            .values()
+            .map(|objective| ObjectiveData {
+                name: objective.name.clone(),
+                criteria_name: objective.criteria.clone(),
+                display_name: String::from(objective.display_name.clone()),
+                render_type: match objective.render_type {
+                    ObjectiveRenderType::Integer => "integer",
+                    ObjectiveRenderType::Hearts => "hearts",
+                }
+                .to_owned(),
+            })
+            .collect();
+
+        let player_scores = self
+            .scores
+            .iter()
+            .flat_map(|(holder, scores)| {
+                scores.iter().map(move |(objective, score)| ScoreData {
+                    name: holder.clone(),
+                    objective: objective.clone(),
+                    score: score.value,
+                    locked: score.locked,
+                })
+            })
+            .collect();
+
+        let teams = self
+            .teams
+            .values()
+            .map(|team| TeamData {
+                name: team.name.clone(),
+                display_name: String::from(team.display_name.clone()),
+                member_name_prefix: String::from(team.prefix.clone()),
+                member_name_suffix: String::from(team.suffix.clone()),
+                team_color: team
+                    .color
+                    .as_ref()
+                    .and_then(color_code)
+                    .map(|code| COLOR_NAMES[code as usize].to_owned()),
+                allow_friendly_fire: team.friendly_fire,
+                see_friendly_invisibles: team.see_friendly_invisibles,
+                name_tag_visibility: team.name_tag_visibility.as_str().to_owned(),
+                death_message_visibility: team.death_message_visibility.as_str().to_owned(),
+                collision_rule: team.collision_rule.as_str().to_owned(),
+                players: team.members.iter().cloned().collect(),
+            })
+            .collect();
+
+        let display_slots = self
+            .display_slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, objective)| {
+                objective
+                    .as_ref()
+                    .map(|objective| (format!("slot_{}", index), objective.clone()))
+            })
+            .collect();
+
+        ScoreboardData {
+            objectives,
+            player_scores,
+            teams,
+            display_slots,
+        }
+    }
+}
+
+fn display_packet(slot: DisplaySlot, objective: Option<&str>) -> DisplayScoreboard {
+    DisplayScoreboard {
+        position: slot.index() as i8,
+        score_name: objective.unwrap_or_default().to_owned(),
+    }
+}
+
+fn team_create_packet(team: &Team) -> Teams {
+    Teams {
+        team_name: team.name.clone(),
+        action: TeamsAction::Create(team.info(), team.members.iter().cloned().collect()),
+    }
+}
+
+/// Parses JSON text, falling back to plain text
+/// if the JSON is invalid.
+fn parse_text(json: String) -> Text {
+    serde_json::from_str(&json).unwrap_or_else(|_| Text::from(json))
+}
+
+fn broadcast(world: &World, packet: impl Packet) {
+    for network in <Read<Network>>::query().iter(world.inner()) {
+        network.send_boxed(packet.box_clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_slot_names() {
+        for index in 0..DisplaySlot::COUNT {
+            let slot = DisplaySlot::from_index(index).unwrap();
+            assert_eq!(slot.index(), index);
+            assert_eq!(DisplaySlot::from_str(&slot.to_string()), Ok(slot));
+        }
+
+        assert_eq!(
+            DisplaySlot::from_str("sidebar.team.red"),
+            Ok(DisplaySlot::TeamSidebar(12))
+        );
+        assert!(DisplaySlot::from_str("sidebar.team.pink").is_err());
+    }
+
+    #[test]
+    fn name_length_counts_characters() {
+        let world = World::new();
+        let mut scoreboard = Scoreboard::new();
+
+        // 16 characters, but 32 bytes.
+        let name = "éééééééééééééééé";
+        scoreboard
+            .add_objective(&world, name, "dummy", None)
+            .unwrap();
+        scoreboard.add_team(&world, name, None).unwrap();
+
+        let too_long = "ééééééééééééééééé";
+        assert_eq!(
+            scoreboard.add_objective(&world, too_long, "dummy", None),
+            Err(ScoreboardError::NameTooLong(too_long.to_owned()))
+        );
+        assert_eq!(
+            scoreboard.add_team(&world, too_long, None),
+            Err(ScoreboardError::NameTooLong(too_long.to_owned()))
+        );
+    }
+
+    #[test]
+    fn scores_and_triggers() {
+        let world = World::new();
+        let mut scoreboard = Scoreboard::new();
+
+        scoreboard
+            .add_objective(&world, "vote", "trigger", None)
+            .unwrap();
+        scoreboard
+            .add_objective(&world, "hp", "health", None)
+            .unwrap();
+        assert_eq!(
+            scoreboard.add_objective(&world, "vote", "dummy", None),
+            Err(ScoreboardError::ObjectiveExists)
+        );
+
+        assert_eq!(
+            scoreboard.set_score(&world, "Steve", "hp", 3),
+            Err(ScoreboardError::ReadOnlyObjective("hp".to_owned()))
+        );
+
+        assert_eq!(
+            scoreboard.trigger(&world, "Steve", "vote", |v| v + 1),
+            Err(ScoreboardError::TriggerLocked)
+        );
+        scoreboard.enable_trigger(&world, "Steve", "vote").unwrap();
+        assert_eq!(
+            scoreboard.trigger(&world, "Steve", "vote", |v| v + 1),
+            Ok(1)
+        );
+        assert_eq!(
+            scoreboard.trigger(&world, "Steve", "vote", |v| v + 1),
+            Err(ScoreboardError::TriggerLocked)
+        );
+
+        let mut text = Text::score("*", "vote", None::<&str>);
+        scoreboard.resolve_text(&mut text, "Steve");
+        assert_eq!(text, Text::score("*", "vote", Some("1")));
+
+        scoreboard.remove_objective(&world, "vote").unwrap();
+        assert_eq!(scoreboard.score("Steve", "vote"), None);
+        assert_eq!(scoreboard.holders().count(), 0);
+    }
+
+    #[test]
+    fn teams() {
+        let world = World::new();
+        let mut scoreboard = Scoreboard::new();
+
+        scoreboard.add_team(&world, "red", None).unwrap();
+        scoreboard.add_team(&world, "blue", None).unwrap();
+
+        let holders = vec!["Steve".to_owned(), "Alex".to_owned()];
+        scoreboard.join_team(&world, "red", &holders).unwrap();
+        scoreboard.join_team(&world, "blue", &holders[..1]).unwrap();
+
+        assert_eq!(scoreboard.team_of("Steve").unwrap().name, "blue");
+        assert_eq!(
+            scoreboard
+                .team("red")
+                .unwrap()
+                .members()
+                .collect::<Vec<_>>(),
+            vec!["Alex"]
+        );
+
+        assert_eq!(scoreboard.empty_team(&world, "red"), Ok(1));
+        assert!(scoreboard.team_of("Alex").is_none());
+
+        scoreboard.remove_team(&world, "blue").unwrap();
+        assert!(scoreboard.team_of("Steve").is_none());
+    }
+
+    #[test]
+    fn data_roundtrip() {
+        let world = World::new();
+        let mut scoreboard = Scoreboard::new();
+
+        scoreboard
+            .add_objective(&world, "kills", "playerKillCount", None)
+            .unwrap();
+        scoreboard
+            .set_display_slot(&world, DisplaySlot::Sidebar, Some("kills"))
+            .unwrap();
+        scoreboard.set_score(&world, "Steve", "kills", 7).unwrap();
+        scoreboard.add_team(&world, "red", None).unwrap();
+        scoreboard
+            .modify_team(&world, "red", |team| team.color = Some(Color::Red))
+            .unwrap();
+        scoreboard
+            .join_team(&world, "red", &["Steve".to_owned()])
+            .unwrap();
+
+        let data = scoreboard.to_data();
+        assert_eq!(data.display_slots["slot_1"], "kills");
+        assert_eq!(data.teams[0].team_color.as_deref(), Some("red"));
+
+        let loaded = Scoreboard::from_data(data.clone());
+        assert_eq!(loaded.score("Steve", "kills").unwrap().value, 7);
+        assert_eq!(
+            loaded.displayed_objective(DisplaySlot::Sidebar),
+            Some("kills")
+        );
+        assert_eq!(loaded.team_of("Steve").unwrap().color, Some(Color::Red));
+        assert_eq!(loaded.to_data(), data);
+    }
+}
@@ -0,0 +1,409 @@
+//! The world border, loaded from and saved to the level data.
+//!
+//! All modifications made through `WorldBorder` are sent to
+//! online players. Players who join later receive the border
+//! through `WorldBorder::initialize_packet`.
+
+use crate::{Network, Player, TICK_LENGTH};
+use feather_core::anvil::level::LevelData;
+use feather_core::network::packets::{WorldBorder as WorldBorderPacket, WorldBorderAction};
+use feather_core::util::{BlockPosition, Position};
+use fecs::{component, IntoQuery, Read, World};
+
+/// The largest allowed diameter of the border.
+pub const MAX_BORDER_DIAMETER: f64 = 60_000_000.0;
+/// The farthest the center of the border may be from the origin.
+pub const MAX_BORDER_CENTER: f64 = 29_999_984.0;
+
+/// Distance from the border at which players are kept
+/// when their movement is clamped, i.e. half a player's width.
+const CLAMP_MARGIN: f64 = 0.3;
+
+/// A change in the diameter of the border over time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Lerp {
+    from: f64,
+    to: f64,
+    /// Total duration, in milliseconds.
+    duration: u64,
+    /// Elapsed time, in milliseconds.
+    elapsed: u64,
+}
+
+/// The world border: a square, centered on `center`, outside
+/// of which players cannot place or break blocks and take damage.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorldBorder {
+    center_x: f64,
+    center_z: f64,
+    /// The current diameter.
+    diameter: f64,
+    lerp: Option<Lerp>,
+    /// Damage per second for each block a player
+    /// is beyond the safe zone.
+    damage_per_block: f64,
+    /// Distance beyond the border in which players take no damage.
+    safe_zone: f64,
+    /// Distance from the border at which
+    /// clients start to show a warning.
+    warning_blocks: i32,
+    /// Time, in seconds, before a moving border reaches
+    /// a client at which the client shows a warning.
+    warning_time: i32,
+}
+
+impl Default for WorldBorder {
+    fn default() -> Self {
+        Self {
+            center_x: 0.0,
+            center_z: 0.0,
+            diameter: MAX_BORDER_DIAMETER,
+            lerp: None,
+            damage_per_block: 0.2,
+            safe_zone: 5.0,
+            warning_blocks: 5,
+            warning_time: 15,
+        }
+    }
+}
+
+impl WorldBorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the border from the level data.
+    pub fn from_level(level: &LevelData) -> Self {
+        // Earlier versions of Feather wrote a size of zero,
+        // which means that no border was set.
+        if level.border_size <= 0.0 {
+            return Self::default();
+        }
+
+        let lerp = if level.border_size_lerp_time > 0 {
+            Some(Lerp {
+                from: level.border_size,
+                to: level.border_size_lerp_target,
+                duration: level.border_size_lerp_time as u64,
+                elapsed: 0,
+            })
+        } else {
+            None
+        };
+
+        Self {
+            center_x: level.border_center_x,
+            center_z: level.border_center_z,
+            diameter: level.border_size,
+            lerp,
+            damage_per_block: level.border_damage_per_block,
+            safe_zone: level.border_safe_zone,
+            warning_blocks: level.border_warning_blocks as i32,
+            warning_time: level.border_warning_time as i32,
+        }
+    }
+
+    /// Writes the border to the level data.
+    pub fn save_to_level(&self, level: &mut LevelData) {
+        level.border_center_x = self.center_x;
+        level.border_center_z = self.center_z;
+        level.border_size = self.diameter;
+        level.border_size_lerp_target = self.target_diameter();
+        level.border_size_lerp_time = self.remaining_time() as i64;
+        level.border_damage_per_block = self.damage_per_block;
+        level.border_safe_zone = self.safe_zone;
+        level.border_warning_blocks = self.warning_blocks as f64;
+        level.border_warning_time = self.warning_time as f64;
+    }
+
+    pub fn center(&self) -> (f64, f64) {
+        (self.center_x, self.center_z)
+    }
+
+    /// Returns the current diameter.
+    pub fn diameter(&self) -> f64 {
+        self.diameter
+    }
+
+    /// Returns the diameter the border is moving towards,
+    /// or the current diameter if it isn't moving.
+    pub fn target_diameter(&self) -> f64 {
+        self.lerp.map(|lerp| lerp.to).unwrap_or(self.diameter)
+    }
+
+    /// Returns the time, in milliseconds, until the
+    /// border reaches its target diameter.
+    pub fn remaining_time(&self) -> u64 {
+        self.lerp
+            .map(|lerp| lerp.duration - lerp.elapsed)
+            .unwrap_or(0)
+    }
+
+    pub fn damage_per_block(&self) -> f64 {
+        self.damage_per_block
+    }
+
+    pub fn safe_zone(&self) -> f64 {
+        self.safe_zone
+    }
+
+    pub fn warning_blocks(&self) -> i32 {
+        self.warning_blocks
+    }
+
+    pub fn warning_time(&self) -> i32 {
+        self.warning_time
+    }
+
+    pub fn min_x(&self) -> f64 {
+        (self.center_x - self.diameter / 2.0).max(-MAX_BORDER_CENTER)
+    }
+
+    pub fn max_x(&self) -> f64 {
+        (self.center_x + self.diameter / 2.0).min(MAX_BORDER_CENTER)
+    }
+
+    pub fn min_z(&self) -> f64 {
+        (self.center_z - self.diameter / 2.0).max(-MAX_BORDER_CENTER)
+    }
+
+    pub fn max_z(&self) -> f64 {
+        (self.center_z + self.diameter / 2.0).min(MAX_BORDER_CENTER)
+    }
+
+    /// Returns whether the given position is within the border.
+    pub fn contains(&self, x: f64, z: f64) -> bool {
+        x > self.min_x() && x < self.max_x() && z > self.min_z() && z < self.max_z()
+    }
+
+    /// Returns whether any part of the given block is within the border.
+    pub fn contains_block(&self, pos: BlockPosition) -> bool {
+        let (x, z) = (pos.x as f64, pos.z as f64);
+        x + 1.0 > self.min_x() && x < self.max_x() && z + 1.0 > self.min_z() && z < self.max_z()
+    }
+
+    /// Returns the distance from the given position to the
+    /// nearest edge of the border. This is negative if the
+    /// position is outside the border.
+    pub fn distance_inside(&self, x: f64, z: f64) -> f64 {
+        (x - self.min_x())
+            .min(self.max_x() - x)
+            .min(z - self.min_z())
+            .min(self.max_z() - z)
+    }
+
+    /// Moves the given position to within the border.
+    pub fn clamp(&self, mut pos: Position) -> Position {
+        pos.x = pos
+            .x
+            .max(self.min_x() + CLAMP_MARGIN)
+            .min(self.max_x() - CLAMP_MARGIN);
+        pos.z = pos
+            .z
+            .max(self.min_z() + CLAMP_MARGIN)
+            .min(self.max_z() - CLAMP_MARGIN);
+        pos
+    }
+
+    /// Returns the damage a player at the given position takes
+    /// each second, or zero if the player is within the safe zone.
+    pub fn damage_at(&self, x: f64, z: f64) -> u32 {
+        let beyond = -(self.distance_inside(x, z) + self.safe_zone);
+        if beyond <= 0.0 || self.damage_per_block <= 0.0 {
+            return 0;
+        }
+
+        (beyond * self.damage_per_block).floor().max(1.0) as u32
+    }
+
+    /// Sets the diameter immediately.
+    pub fn set_diameter(&mut self, world: &World, diameter: f64) {
+        self.diameter = diameter;
+        self.lerp = None;
+        broadcast(world, WorldBorderAction::SetSize { diameter });
+    }
+
+    /// Moves the border to the given diameter over
+    /// the given time, in milliseconds.
+    pub fn lerp_diameter(&mut self, world: &World, diameter: f64, time: u64) {
+        if time == 0 {
+            self.set_diameter(world, diameter);
+            return;
+        }
+
+        self.lerp = Some(Lerp {
+            from: self.diameter,
+            to: diameter,
+            duration: time,
+            elapsed: 0,
+        });
+        broadcast(
+            world,
+            WorldBorderAction::LerpSize {
+                old_diameter: self.diameter,
+                new_diameter: diameter,
+                speed: time as i64,
+            },
+        );
+    }
+
+    pub fn set_center(&mut self, world: &World, x: f64, z: f64) {
+        self.center_x = x;
+        self.center_z = z;
+        broadcast(world, WorldBorderAction::SetCenter { x, z });
+    }
+
+    pub fn set_damage_per_block(&mut self, damage_per_block: f64) {
+        self.damage_per_block = damage_per_block;
+    }
+
+    pub fn set_safe_zone(&mut self, safe_zone: f64) {
+        self.safe_zone = safe_zone;
+    }
+
+    pub fn set_warning_blocks(&mut self, world: &World, warning_blocks: i32) {
+        self.warning_blocks = warning_blocks;
+        broadcast(
+            world,
+            WorldBorderAction::SetWarningBlocks { warning_blocks },
+        );
+    }
+
+    pub fn set_warning_time(&mut self, world: &World, warning_time: i32) {
+        self.warning_time = warning_time;
+        broadcast(world, WorldBorderAction::SetWarningTime { warning_time });
+    }
+
+    /// Advances a moving border by one tick.
+    ///
+    /// Clients move the border themselves, so nothing is sent.
+    pub fn tick(&mut self) {
+        let mut lerp = match self.lerp {
+            Some(lerp) => lerp,
+            None => return,
+        };
+
+        lerp.elapsed = (lerp.elapsed + TICK_LENGTH).min(lerp.duration);
+        let progress = lerp.elapsed as f64 / lerp.duration as f64;
+        self.diameter = lerp.from + (lerp.to - lerp.from) * progress;
+
+        self.lerp = if lerp.elapsed < lerp.duration {
+            Some(lerp)
+        } else {
+            None
+        };
+    }
+
+    /// Returns the packet which sends the whole border to a client.
+    pub fn initialize_packet(&self) -> WorldBorderPacket {
+        WorldBorderPacket {
+            action: WorldBorderAction::Initialize {
+                x: self.center_x,
+                z: self.center_z,
+                old_diameter: self.diameter,
+                new_diameter: self.target_diameter(),
+                speed: self.remaining_time() as i64,
+                portal_teleport_boundary: MAX_BORDER_CENTER as i32,
+                warning_time: self.warning_time,
+                warning_blocks: self.warning_blocks,
+            },
+        }
+    }
+}
+
+/// Sends a World Border packet to all online players.
+fn broadcast(world: &World, action: WorldBorderAction) {
+    let packet = WorldBorderPacket { action };
+    <Read<Network>>::query()
+        .filter(component::<Player>())
+        .iter(world.inner())
+        .for_each(|network| network.send(packet.clone()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use feather_core::position;
+
+    #[test]
+    fn bounds() {
+        let world = World::new();
+        let mut border = WorldBorder::new();
+        border.set_center(&world, 10.0, -10.0);
+        border.set_diameter(&world, 20.0);
+
+        assert!(border.contains(10.0, -10.0));
+        assert!(border.contains(19.5, -19.5));
+        assert!(!border.contains(20.5, -10.0));
+        assert!(border.contains_block(BlockPosition::new(0, 64, -20)));
+        assert!(!border.contains_block(BlockPosition::new(-1, 64, -10)));
+        assert!(!border.contains_block(BlockPosition::new(10, 64, 0)));
+
+        assert_eq!(border.distance_inside(10.0, -10.0), 10.0);
+        assert_eq!(border.distance_inside(25.0, -10.0), -5.0);
+
+        let clamped = border.clamp(position!(30.0, 64.0, -40.0));
+        assert_eq!((clamped.x, clamped.y, clamped.z), (19.7, 64.0, -19.7));
+    }
+
+    #[test]
+    fn damage() {
+        let world = World::new();
+        let mut border = WorldBorder::new();
+        border.set_diameter(&world, 10.0);
+
+        // Within the border and the safe zone
+        assert_eq!(border.damage_at(0.0, 0.0), 0);
+        assert_eq!(border.damage_at(9.0, 0.0), 0);
+        // At least one point of damage beyond the safe zone
+        assert_eq!(border.damage_at(11.0, 0.0), 1);
+        assert_eq!(border.damage_at(30.0, 0.0), 4);
+
+        border.set_damage_per_block(0.0);
+        assert_eq!(border.damage_at(30.0, 0.0), 0);
+    }
+
+    #[test]
+    fn lerp() {
+        let world = World::new();
+        let mut border = WorldBorder::new();
+        border.set_diameter(&world, 100.0);
+        border.lerp_diameter(&world, 50.0, 1000);
+
+        assert_eq!(border.target_diameter(), 50.0);
+        for _ in 0..10 {
+            border.tick();
+        }
+        assert_eq!(border.diameter(), 75.0);
+        assert_eq!(border.remaining_time(), 500);
+
+        for _ in 0..20 {
+            border.tick();
+        }
+        assert_eq!(border.diameter(), 50.0);
+        assert_eq!(border.remaining_time(), 0);
+    }
+
+    #[test]
+    fn level_data() {
+        let world = World::new();
+        let mut border = WorldBorder::new();
+        border.set_center(&world, 5.0, 6.0);
+        border.set_diameter(&world, 200.0);
+        border.lerp_diameter(&world, 100.0, 2000);
+        border.set_safe_zone(2.0);
+        border.set_warning_blocks(&world, 10);
+
+        let mut level = LevelData::default();
+        border.save_to_level(&mut level);
+        assert_eq!(level.border_size_lerp_target, 100.0);
+        assert_eq!(level.border_size_lerp_time, 2000);
+        assert_eq!(WorldBorder::from_level(&level), border);
+
+        // No border was set
+        assert_eq!(
+            WorldBorder::from_level(&LevelData::default()),
+            WorldBorder::default()
+        );
+    }
+}
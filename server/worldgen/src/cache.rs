@@ -0,0 +1,93 @@
+//! Caching of per-chunk generation results which
+//! are shared between neighbouring chunks.
+
+use feather_core::util::ChunkPosition;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// A bounded cache of values computed for chunks.
+///
+/// When full, the value cached first is evicted.
+pub struct ChunkCache<T> {
+    inner: Mutex<Inner<T>>,
+    capacity: usize,
+}
+
+struct Inner<T> {
+    values: HashMap<ChunkPosition, T>,
+    /// Cached chunks, oldest first.
+    order: VecDeque<ChunkPosition>,
+}
+
+impl<T> ChunkCache<T>
+where
+    T: Clone,
+{
+    /// Creates a cache holding values for at most `capacity` chunks.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                values: HashMap::with_capacity(capacity),
+                order: VecDeque::with_capacity(capacity),
+            }),
+            capacity,
+        }
+    }
+
+    /// Returns the value cached for `pos`, computing
+    /// and caching it with `compute` if there is none.
+    ///
+    /// The cache is not locked while computing the value,
+    /// so it may be computed concurrently by several threads.
+    pub fn get_or_insert_with(&self, pos: ChunkPosition, compute: impl FnOnce() -> T) -> T {
+        if let Some(value) = self.inner.lock().unwrap().values.get(&pos) {
+            return value.clone();
+        }
+
+        let value = compute();
+
+        let mut inner = self.inner.lock().unwrap();
+        if inner.values.insert(pos, value.clone()).is_none() {
+            inner.order.push_back(pos);
+            if inner.order.len() > self.capacity {
+                let oldest = inner.order.pop_front().unwrap();
+                inner.values.remove(&oldest);
+            }
+        }
+
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_once() {
+        let cache = ChunkCache::new(4);
+        let mut computed = 0;
+        for _ in 0..3 {
+            let value = cache.get_or_insert_with(ChunkPosition::new(1, 2), || {
+                computed += 1;
+                5
+            });
+            assert_eq!(value, 5);
+        }
+        assert_eq!(computed, 1);
+    }
+
+    #[test]
+    fn evicts_oldest() {
+        let cache = ChunkCache::new(2);
+        for x in 0..3 {
+            cache.get_or_insert_with(ChunkPosition::new(x, 0), || x);
+        }
+
+        assert_eq!(cache.get_or_insert_with(ChunkPosition::new(2, 0), || -1), 2);
+        assert_eq!(
+            cache.get_or_insert_with(ChunkPosition::new(0, 0), || -1),
+            -1
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use super::{carve_worms, Worm};
+use crate::{CarvingGenerator, ChunkBiomes, TopBlocks};
+use feather_core::chunk::Chunk;
+use rand::Rng;
+use std::f32::consts::PI;
+
+/// Carver generating winding caves.
+#[derive(Default)]
+pub struct CaveCarver;
+
+impl CarvingGenerator for CaveCarver {
+    fn generate_for_chunk(
+        &self,
+        chunk: &mut Chunk,
+        biomes: &ChunkBiomes,
+        top_blocks: &TopBlocks,
+        seed: u64,
+    ) {
+        carve_worms(
+            chunk,
+            biomes,
+            top_blocks,
+            seed,
+            |rng, offset_x, offset_z| {
+                // One in seven chunks contains the start of caves.
+                let count = if rng.gen_range(0, 7) == 0 {
+                    rng.gen_range(1, 4)
+                } else {
+                    0
+                };
+
+                (0..count)
+                    .map(|_| Worm {
+                        x: offset_x + rng.gen_range(0.0, 16.0),
+                        y: rng.gen_range(8.0, 72.0),
+                        z: offset_z + rng.gen_range(0.0, 16.0),
+                        yaw: rng.gen_range(0.0, 2.0 * PI),
+                        pitch: rng.gen_range(-0.2, 0.2),
+                        yaw_variation: PI,
+                        pitch_variation: 0.5,
+                        length: rng.gen_range(40, 73),
+                        radius: rng.gen_range(1.5, 3.5),
+                        vertical_scale: 0.8,
+                        noise_seed: rng.gen(),
+                    })
+                    .collect()
+            },
+        );
+    }
+}
@@ -0,0 +1,301 @@
+//! Carvers for world generation, which carve caves
+//! and ravines out of the terrain.
+//!
+//! Both are carved by "worms": tunnels which follow a path
+//! whose direction is driven by 1D gradient noise. A worm
+//! may cross many chunks. To carve it consistently regardless
+//! of the order in which chunks are generated, worms are planned
+//! using only the seed and the position of the chunk they start in,
+//! and each chunk carves the worms of all chunks in range.
+
+mod caves;
+mod ravines;
+
+pub use caves::CaveCarver;
+pub use ravines::RavineCarver;
+
+use crate::util::shuffle_seed_for_chunk;
+use crate::{ChunkBiomes, TopBlocks, SEA_LEVEL};
+use feather_core::biomes::Biome;
+use feather_core::blocks::BlockId;
+use feather_core::chunk::Chunk;
+use feather_core::util::ChunkPosition;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use simdnoise::NoiseBuilder;
+use std::f32::consts::PI;
+
+/// Range, in chunks, from which worms may reach
+/// a chunk. Worms must be shorter than this allows.
+const CARVER_RANGE: i32 = 6;
+
+/// Number of solid blocks kept below the top block of
+/// each column, so that carvers never break the surface.
+const SURFACE_CRUST: usize = 4;
+
+/// Carved blocks at or below this height are filled with lava.
+const LAVA_LEVEL: usize = 10;
+
+/// Calls `plan` with an RNG seeded for each chunk within
+/// `CARVER_RANGE` of the given chunk, along with the offset of that chunk
+/// in blocks. `plan` returns the worms starting in that chunk, which
+/// are then carved into the given chunk.
+fn carve_worms(
+    chunk: &mut Chunk,
+    biomes: &ChunkBiomes,
+    top_blocks: &TopBlocks,
+    seed: u64,
+    mut plan: impl FnMut(&mut XorShiftRng, f32, f32) -> Vec<Worm>,
+) {
+    let center = chunk.position();
+
+    for offset_z in -CARVER_RANGE..=CARVER_RANGE {
+        for offset_x in -CARVER_RANGE..=CARVER_RANGE {
+            let origin = ChunkPosition::new(center.x + offset_x, center.z + offset_z);
+            let mut rng = XorShiftRng::seed_from_u64(shuffle_seed_for_chunk(seed, origin));
+
+            for worm in plan(&mut rng, offset_x as f32 * 16.0, offset_z as f32 * 16.0) {
+                if worm.may_reach_chunk() {
+                    worm.carve(chunk, biomes, top_blocks);
+                }
+            }
+        }
+    }
+}
+
+/// A tunnel carved along a noise-driven path.
+///
+/// Positions are relative to the chunk being carved.
+struct Worm {
+    x: f32,
+    y: f32,
+    z: f32,
+    /// Initial horizontal direction, in radians.
+    yaw: f32,
+    /// Initial vertical direction, in radians.
+    pitch: f32,
+    /// Maximum deviation from the initial yaw.
+    yaw_variation: f32,
+    /// Maximum deviation from the initial pitch.
+    pitch_variation: f32,
+    /// Number of one-block steps along the path.
+    length: usize,
+    /// Horizontal radius at the middle of the worm.
+    radius: f32,
+    /// Ratio of the vertical radius to the horizontal radius.
+    vertical_scale: f32,
+    noise_seed: i32,
+}
+
+impl Worm {
+    /// Returns whether any part of this worm could be
+    /// within the chunk, judging by its length.
+    fn may_reach_chunk(&self) -> bool {
+        let reach = self.length as f32 + self.radius;
+        let distance_x = (-self.x).max(self.x - 16.0).max(0.0);
+        let distance_z = (-self.z).max(self.z - 16.0).max(0.0);
+
+        distance_x * distance_x + distance_z * distance_z <= reach * reach
+    }
+
+    fn carve(&self, chunk: &mut Chunk, biomes: &ChunkBiomes, top_blocks: &TopBlocks) {
+        let noise = |offset: i32| {
+            NoiseBuilder::gradient_1d(self.length)
+                .with_seed(self.noise_seed.wrapping_add(offset))
+                .with_freq(0.05)
+                .generate_scaled(-1.0, 1.0)
+        };
+        let yaw_noise = noise(0);
+        let pitch_noise = noise(1);
+        let radius_noise = noise(2);
+
+        let (mut x, mut y, mut z) = (self.x, self.y, self.z);
+        for step in 0..self.length {
+            let yaw = self.yaw + yaw_noise[step] * self.yaw_variation;
+            let pitch = (self.pitch + pitch_noise[step] * self.pitch_variation)
+                .max(-PI / 2.0)
+                .min(PI / 2.0);
+
+            x += pitch.cos() * yaw.cos();
+            y += pitch.sin();
+            z += pitch.cos() * yaw.sin();
+
+            // Taper the ends of the worm.
+            let taper = (PI * step as f32 / self.length as f32).sin();
+            let radius = self.radius * (0.5 + 0.5 * taper) * (1.0 + 0.3 * radius_noise[step]);
+
+            carve_ellipsoid(
+                chunk,
+                biomes,
+                top_blocks,
+                (x, y, z),
+                radius,
+                radius * self.vertical_scale,
+            );
+        }
+    }
+}
+
+/// Carves the blocks within the given ellipsoid
+/// which fall within the chunk.
+fn carve_ellipsoid(
+    chunk: &mut Chunk,
+    biomes: &ChunkBiomes,
+    top_blocks: &TopBlocks,
+    (center_x, center_y, center_z): (f32, f32, f32),
+    radius: f32,
+    vertical_radius: f32,
+) {
+    if center_x + radius < 0.0
+        || center_x - radius >= 16.0
+        || center_z + radius < 0.0
+        || center_z - radius >= 16.0
+    {
+        return;
+    }
+
+    let min_x = (center_x - radius).floor().max(0.0) as usize;
+    let max_x = (center_x + radius).ceil().min(15.0) as usize;
+    let min_y = (center_y - vertical_radius).floor().max(1.0) as usize;
+    let max_y = (center_y + vertical_radius).ceil().min(255.0) as usize;
+    let min_z = (center_z - radius).floor().max(0.0) as usize;
+    let max_z = (center_z + radius).ceil().min(15.0) as usize;
+
+    for x in min_x..=max_x {
+        for z in min_z..=max_z {
+            let top = top_blocks.top_block_at(x, z);
+            let water_filled = biomes.biome_at(x, z) == Biome::Ocean;
+
+            for y in min_y..=max_y {
+                let dx = (x as f32 + 0.5 - center_x) / radius;
+                let dy = (y as f32 + 0.5 - center_y) / vertical_radius;
+                let dz = (z as f32 + 0.5 - center_z) / radius;
+                if dx * dx + dy * dy + dz * dz >= 1.0 || y + SURFACE_CRUST > top {
+                    continue;
+                }
+
+                let block = chunk.block_at(x, y, z);
+                if block.is_air() || block.is_fluid() || block == BlockId::bedrock() {
+                    continue;
+                }
+
+                let carved = if water_filled && y <= SEA_LEVEL {
+                    BlockId::water()
+                } else if y <= LAVA_LEVEL {
+                    BlockId::lava()
+                } else {
+                    BlockId::air()
+                };
+                chunk.set_block_at(x, y, z, carved);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CarvingGenerator;
+
+    const TOP: usize = 100;
+
+    /// Creates a chunk of stone up to `TOP`
+    /// along with its biomes and top blocks.
+    fn stone_chunk(position: ChunkPosition, biome: Biome) -> (Chunk, ChunkBiomes, TopBlocks) {
+        let mut chunk = Chunk::new(position);
+        let mut top_blocks = TopBlocks::new();
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 0..=TOP {
+                    chunk.set_block_at(x, y, z, BlockId::stone());
+                }
+                top_blocks.set_top_block_at(x, z, TOP);
+            }
+        }
+
+        (chunk, ChunkBiomes::from_array([biome; 16 * 16]), top_blocks)
+    }
+
+    /// Carves chunks until one has been carved
+    /// and returns its blocks.
+    fn carve(carver: &impl CarvingGenerator, biome: Biome) -> Chunk {
+        for x in 0..64 {
+            let (mut chunk, biomes, top_blocks) = stone_chunk(ChunkPosition::new(x, 0), biome);
+            carver.generate_for_chunk(&mut chunk, &biomes, &top_blocks, 1234);
+
+            let carved = (0..16).any(|x| {
+                (0..16).any(|z| (0..=TOP).any(|y| chunk.block_at(x, y, z) != BlockId::stone()))
+            });
+            if carved {
+                return chunk;
+            }
+        }
+        panic!("nothing was carved");
+    }
+
+    fn check_carved(chunk: &Chunk, water_filled: bool) {
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 0..=TOP {
+                    let block = chunk.block_at(x, y, z);
+                    if block == BlockId::stone() {
+                        continue;
+                    }
+
+                    assert!(y + SURFACE_CRUST <= TOP, "carved through the surface");
+                    let expected = if water_filled && y <= SEA_LEVEL {
+                        BlockId::water()
+                    } else if y <= LAVA_LEVEL {
+                        BlockId::lava()
+                    } else {
+                        BlockId::air()
+                    };
+                    assert_eq!(block, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn caves() {
+        check_carved(&carve(&CaveCarver, Biome::Plains), false);
+        check_carved(&carve(&CaveCarver, Biome::Ocean), true);
+    }
+
+    #[test]
+    fn ravines() {
+        check_carved(&carve(&RavineCarver, Biome::Plains), false);
+        check_carved(&carve(&RavineCarver, Biome::Ocean), true);
+    }
+
+    #[test]
+    fn worms_cross_chunk_borders() {
+        let worm = Worm {
+            x: 12.0,
+            y: 40.0,
+            z: 8.0,
+            yaw: 0.0,
+            pitch: 0.0,
+            yaw_variation: 0.0,
+            pitch_variation: 0.0,
+            length: 16,
+            radius: 2.0,
+            vertical_scale: 1.0,
+            noise_seed: 0,
+        };
+        assert!(worm.may_reach_chunk());
+
+        let (mut chunk, biomes, top_blocks) = stone_chunk(ChunkPosition::new(0, 0), Biome::Plains);
+        worm.carve(&mut chunk, &biomes, &top_blocks);
+        assert!(chunk.block_at(15, 40, 8).is_air());
+
+        // The same worm, as seen from the chunk to the east.
+        let worm = Worm { x: -4.0, ..worm };
+        let (mut chunk, biomes, top_blocks) = stone_chunk(ChunkPosition::new(1, 0), Biome::Plains);
+        worm.carve(&mut chunk, &biomes, &top_blocks);
+        assert!(chunk.block_at(0, 40, 8).is_air());
+
+        let worm = Worm { x: -40.0, ..worm };
+        assert!(!worm.may_reach_chunk());
+    }
+}
@@ -0,0 +1,47 @@
+use super::{carve_worms, Worm};
+use crate::{CarvingGenerator, ChunkBiomes, TopBlocks};
+use feather_core::chunk::Chunk;
+use rand::Rng;
+use std::f32::consts::PI;
+
+/// Carver generating ravines: long, narrow
+/// and tall tunnels which hardly turn.
+#[derive(Default)]
+pub struct RavineCarver;
+
+impl CarvingGenerator for RavineCarver {
+    fn generate_for_chunk(
+        &self,
+        chunk: &mut Chunk,
+        biomes: &ChunkBiomes,
+        top_blocks: &TopBlocks,
+        seed: u64,
+    ) {
+        carve_worms(
+            chunk,
+            biomes,
+            top_blocks,
+            seed,
+            |rng, offset_x, offset_z| {
+                // One in fifty chunks contains the start of a ravine.
+                if rng.gen_range(0, 50) != 0 {
+                    return vec![];
+                }
+
+                vec![Worm {
+                    x: offset_x + rng.gen_range(0.0, 16.0),
+                    y: rng.gen_range(20.0, 56.0),
+                    z: offset_z + rng.gen_range(0.0, 16.0),
+                    yaw: rng.gen_range(0.0, 2.0 * PI),
+                    pitch: rng.gen_range(-0.1, 0.1),
+                    yaw_variation: PI / 4.0,
+                    pitch_variation: 0.1,
+                    length: rng.gen_range(48, 73),
+                    radius: rng.gen_range(1.5, 3.0),
+                    vertical_scale: 3.0,
+                    noise_seed: rng.gen(),
+                }]
+            },
+        );
+    }
+}
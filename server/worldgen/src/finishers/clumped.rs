@@ -1,5 +1,5 @@
 use crate::util::shuffle_seed_for_chunk;
-use crate::{ChunkBiomes, FinishingGenerator, TopBlocks};
+use crate::{FinishingGenerator, NearbyBiomes, NearbyTopBlocks};
 use feather_core::biomes::Biome;
 use feather_core::blocks::BlockId;
 use feather_core::chunk::Chunk;
@@ -15,8 +15,8 @@ impl FinishingGenerator for ClumpedFoliageFinisher {
     fn generate_for_chunk(
         &self,
         chunk: &mut Chunk,
-        biomes: &ChunkBiomes,
-        top_blocks: &TopBlocks,
+        biomes: &NearbyBiomes,
+        top_blocks: &NearbyTopBlocks,
         seed: u64,
     ) {
         // Generate clumps of foliage for the biome.
@@ -1,9 +1,13 @@
-//! Various finishers for world generation, such as grass, snow, and trees.
+//! Various finishers for world generation, such as ores, grass, snow, and trees.
 
 mod clumped;
+mod ores;
 mod single;
 mod snow;
+mod trees;
 
 pub use clumped::ClumpedFoliageFinisher;
+pub use ores::{OreFinisher, OreTable, OreVein, DEFAULT_ORE_TABLE_STR};
 pub use single::SingleFoliageFinisher;
 pub use snow::SnowFinisher;
+pub use trees::{sapling_tree, TreeFinisher};
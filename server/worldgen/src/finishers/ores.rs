@@ -0,0 +1,370 @@
+use crate::util::shuffle_seed_for_chunk;
+use crate::{FinishingGenerator, NearbyBiomes, NearbyTopBlocks};
+use anyhow::Context;
+use feather_core::biomes::Biome;
+use feather_core::blocks::BlockId;
+use feather_core::chunk::Chunk;
+use feather_core::util::ChunkPosition;
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::f64::consts::PI;
+
+/// The default ore table, matching vanilla ore distribution.
+pub const DEFAULT_ORE_TABLE_STR: &str = include_str!("../../ores.toml");
+
+/// Maximum number of blocks in a vein. This keeps veins
+/// small enough to only reach the neighbouring chunks.
+const MAX_VEIN_SIZE: u32 = 64;
+
+/// A type of ore vein placed in a biome.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OreVein {
+    /// The ore block.
+    pub block: BlockId,
+    /// Number of blocks in each vein.
+    pub size: u32,
+    /// Number of veins placed in each chunk.
+    pub count: u32,
+    /// Lowest height at which veins are placed.
+    pub min_height: u32,
+    /// Height below which veins are placed.
+    pub max_height: u32,
+}
+
+/// The ore veins placed in each biome, as
+/// loaded from an ore table file such as `ores.toml`.
+#[derive(Clone, Debug)]
+pub struct OreTable {
+    default: Vec<OreVein>,
+    biomes: HashMap<Biome, Vec<OreVein>>,
+}
+
+#[derive(Deserialize)]
+struct RawOreTable {
+    #[serde(default)]
+    default: Vec<RawOreVein>,
+    #[serde(default)]
+    biomes: BTreeMap<String, Vec<RawOreVein>>,
+}
+
+#[derive(Deserialize)]
+struct RawOreVein {
+    block: String,
+    size: u32,
+    count: u32,
+    min_height: u32,
+    max_height: u32,
+}
+
+impl RawOreVein {
+    fn resolve(&self) -> anyhow::Result<OreVein> {
+        let block = BlockId::from_identifier(&self.block)
+            .with_context(|| format!("unknown block `{}`", self.block))?;
+
+        if self.size == 0 || self.size > MAX_VEIN_SIZE {
+            anyhow::bail!(
+                "vein size of `{}` must be between 1 and {}",
+                self.block,
+                MAX_VEIN_SIZE
+            );
+        }
+        if self.min_height >= self.max_height || self.max_height > 256 {
+            anyhow::bail!(
+                "invalid height range {}..{} for `{}`",
+                self.min_height,
+                self.max_height,
+                self.block
+            );
+        }
+
+        Ok(OreVein {
+            block,
+            size: self.size,
+            count: self.count,
+            min_height: self.min_height,
+            max_height: self.max_height,
+        })
+    }
+}
+
+impl OreTable {
+    /// Parses an ore table from the given TOML string.
+    pub fn load(s: &str) -> anyhow::Result<Self> {
+        let raw: RawOreTable = toml::from_str(s)?;
+
+        let default = raw
+            .default
+            .iter()
+            .map(RawOreVein::resolve)
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut biomes = HashMap::new();
+        for (identifier, overrides) in &raw.biomes {
+            let biome = Biome::from_identifier(identifier)
+                .with_context(|| format!("unknown biome `{}`", identifier))?;
+
+            let mut veins = default.clone();
+            for vein in overrides {
+                let vein = vein.resolve()?;
+                match veins.iter_mut().find(|v| v.block == vein.block) {
+                    Some(existing) => *existing = vein,
+                    None => veins.push(vein),
+                }
+            }
+            veins.retain(|vein| vein.count > 0);
+
+            biomes.insert(biome, veins);
+        }
+
+        Ok(Self { default, biomes })
+    }
+
+    /// Returns the ore veins placed in the given biome.
+    pub fn veins(&self, biome: Biome) -> &[OreVein] {
+        self.biomes.get(&biome).unwrap_or(&self.default)
+    }
+}
+
+impl Default for OreTable {
+    fn default() -> Self {
+        Self::load(DEFAULT_ORE_TABLE_STR).expect("default ore table is invalid")
+    }
+}
+
+/// Finisher which places ore veins in stone.
+///
+/// As with trees, the veins of a chunk are planned using only the
+/// seed and the chunk position, and each chunk places the parts of the
+/// veins of its own and its neighbouring chunks which fall within it.
+/// The veins placed in a chunk are chosen from the biome at its center.
+#[derive(Default)]
+pub struct OreFinisher {
+    table: OreTable,
+}
+
+impl OreFinisher {
+    pub fn new(table: OreTable) -> Self {
+        Self { table }
+    }
+}
+
+impl FinishingGenerator for OreFinisher {
+    fn generate_for_chunk(
+        &self,
+        chunk: &mut Chunk,
+        biomes: &NearbyBiomes,
+        _top_blocks: &NearbyTopBlocks,
+        seed: u64,
+    ) {
+        let center = chunk.position();
+
+        for offset_z in -1..=1 {
+            for offset_x in -1..=1 {
+                let origin = ChunkPosition::new(center.x + offset_x, center.z + offset_z);
+                let mut rng = XorShiftRng::seed_from_u64(shuffle_seed_for_chunk(seed, origin));
+
+                let biome = biomes.biome_at(offset_x * 16 + 8, offset_z * 16 + 8);
+                for vein in self.table.veins(biome) {
+                    for _ in 0..vein.count {
+                        let x = offset_x * 16 + rng.gen_range(0, 16);
+                        let z = offset_z * 16 + rng.gen_range(0, 16);
+                        let y = rng.gen_range(vein.min_height, vein.max_height) as i32;
+                        place_vein(chunk, &mut rng, vein, x, y, z);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Places the blocks of a vein within the chunk. The vein is
+/// a series of spheres along a line through the given position.
+///
+/// The same values are drawn from `rng` whether or not any part
+/// of the vein falls within the chunk.
+fn place_vein(chunk: &mut Chunk, rng: &mut XorShiftRng, vein: &OreVein, x: i32, y: i32, z: i32) {
+    let size = vein.size as f64;
+    let angle = rng.gen::<f64>() * PI;
+    let spread = size / 8.0;
+
+    let (x, y, z) = (x as f64 + 0.5, y as f64, z as f64 + 0.5);
+    let (start_x, end_x) = (x + angle.sin() * spread, x - angle.sin() * spread);
+    let (start_z, end_z) = (z + angle.cos() * spread, z - angle.cos() * spread);
+    let start_y = y + rng.gen_range(-2, 1) as f64;
+    let end_y = y + rng.gen_range(-2, 1) as f64;
+
+    for i in 0..vein.size {
+        let progress = i as f64 / size;
+        let center_x = start_x + (end_x - start_x) * progress;
+        let center_y = start_y + (end_y - start_y) * progress;
+        let center_z = start_z + (end_z - start_z) * progress;
+
+        let scale = rng.gen::<f64>() * size / 16.0;
+        let radius = ((PI * progress).sin() + 1.0) * scale / 2.0 + 0.5;
+
+        let min_x = (center_x - radius).floor().max(0.0) as usize;
+        let max_x = (center_x + radius).floor().min(15.0);
+        let min_y = (center_y - radius).floor().max(0.0) as usize;
+        let max_y = (center_y + radius).floor().min(255.0);
+        let min_z = (center_z - radius).floor().max(0.0) as usize;
+        let max_z = (center_z + radius).floor().min(15.0);
+        if max_x < 0.0 || max_y < 0.0 || max_z < 0.0 {
+            continue;
+        }
+
+        for block_x in min_x..=max_x as usize {
+            for block_y in min_y..=max_y as usize {
+                for block_z in min_z..=max_z as usize {
+                    let dx = (block_x as f64 + 0.5 - center_x) / radius;
+                    let dy = (block_y as f64 + 0.5 - center_y) / radius;
+                    let dz = (block_z as f64 + 0.5 - center_z) / radius;
+
+                    if dx * dx + dy * dy + dz * dz < 1.0
+                        && chunk.block_at(block_x, block_y, block_z) == BlockId::stone()
+                    {
+                        chunk.set_block_at(block_x, block_y, block_z, vein.block);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChunkBiomes, TopBlocks};
+
+    const TOP: usize = 100;
+
+    fn generate(finisher: &OreFinisher, biome: Biome) -> Chunk {
+        let biomes = NearbyBiomes::from_vec(
+            (0..9)
+                .map(|_| ChunkBiomes::from_array([biome; 16 * 16]))
+                .collect(),
+        );
+        let top_blocks = NearbyTopBlocks::from_vec((0..9).map(|_| TopBlocks::new()).collect());
+
+        let mut chunk = Chunk::new(ChunkPosition::new(0, 0));
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 0..=TOP {
+                    chunk.set_block_at(x, y, z, BlockId::stone());
+                }
+            }
+        }
+
+        finisher.generate_for_chunk(&mut chunk, &biomes, &top_blocks, 1234);
+        chunk
+    }
+
+    fn count(chunk: &Chunk, block: BlockId) -> usize {
+        let mut count = 0;
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 0..256 {
+                    if chunk.block_at(x, y, z) == block {
+                        count += 1;
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn default_table() {
+        let table = OreTable::default();
+        let plains = table.veins(Biome::Plains);
+        assert_eq!(plains.len(), 6);
+        assert!(plains.iter().all(|v| v.block != BlockId::emerald_ore()));
+
+        let mountains = table.veins(Biome::Mountains);
+        assert_eq!(mountains.len(), 7);
+        assert!(mountains.iter().any(|v| v.block == BlockId::emerald_ore()));
+
+        let gold = table
+            .veins(Biome::Badlands)
+            .iter()
+            .find(|v| v.block == BlockId::gold_ore())
+            .unwrap();
+        assert_eq!(gold.count, 22);
+        assert_eq!(gold.max_height, 80);
+    }
+
+    #[test]
+    fn overrides() {
+        let table = OreTable::load(
+            r#"
+            [[default]]
+            block = "minecraft:coal_ore"
+            size = 17
+            count = 20
+            min_height = 0
+            max_height = 128
+
+            [[biomes."minecraft:desert"]]
+            block = "minecraft:coal_ore"
+            size = 17
+            count = 0
+            min_height = 0
+            max_height = 128
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(table.veins(Biome::Plains).len(), 1);
+        assert!(table.veins(Biome::Desert).is_empty());
+    }
+
+    #[test]
+    fn invalid_tables() {
+        let vein = |block: &str, size: u32, min: u32, max: u32| {
+            format!(
+                "[[default]]\nblock = \"{}\"\nsize = {}\ncount = 1\nmin_height = {}\nmax_height = {}\n",
+                block, size, min, max
+            )
+        };
+
+        assert!(OreTable::load(&vein("minecraft:iron_ore", 9, 0, 64)).is_ok());
+        assert!(OreTable::load(&vein("minecraft:mithril_ore", 9, 0, 64)).is_err());
+        assert!(OreTable::load(&vein("minecraft:iron_ore", 65, 0, 64)).is_err());
+        assert!(OreTable::load(&vein("minecraft:iron_ore", 9, 64, 64)).is_err());
+        assert!(OreTable::load(&vein("minecraft:iron_ore", 9, 0, 300)).is_err());
+        assert!(OreTable::load("[[biomes.\"minecraft:moon\"]]").is_err());
+    }
+
+    #[test]
+    fn ores_replace_stone() {
+        let chunk = generate(&OreFinisher::default(), Biome::Mountains);
+
+        for &block in &[
+            BlockId::coal_ore(),
+            BlockId::iron_ore(),
+            BlockId::redstone_ore(),
+            BlockId::emerald_ore(),
+        ] {
+            assert!(count(&chunk, block) > 0, "no {:?} placed", block);
+        }
+
+        // Ores are only placed in stone.
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in TOP + 1..256 {
+                    assert!(chunk.block_at(x, y, z).is_air());
+                }
+            }
+        }
+
+        // Veins extend at most a block above their height range.
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 20..256 {
+                    assert_ne!(chunk.block_at(x, y, z), BlockId::redstone_ore());
+                }
+            }
+        }
+    }
+}
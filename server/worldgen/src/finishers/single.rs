@@ -1,5 +1,5 @@
 use crate::util::shuffle_seed_for_chunk;
-use crate::{ChunkBiomes, FinishingGenerator, TopBlocks};
+use crate::{FinishingGenerator, NearbyBiomes, NearbyTopBlocks};
 use feather_core::biomes::Biome;
 use feather_core::blocks::BlockId;
 use feather_core::chunk::Chunk;
@@ -14,8 +14,8 @@ impl FinishingGenerator for SingleFoliageFinisher {
     fn generate_for_chunk(
         &self,
         chunk: &mut Chunk,
-        biomes: &ChunkBiomes,
-        top_blocks: &TopBlocks,
+        biomes: &NearbyBiomes,
+        top_blocks: &NearbyTopBlocks,
         seed: u64,
     ) {
         let mut rng = XorShiftRng::seed_from_u64(shuffle_seed_for_chunk(seed, chunk.position()));
@@ -1,4 +1,4 @@
-use crate::{ChunkBiomes, FinishingGenerator, TopBlocks};
+use crate::{FinishingGenerator, NearbyBiomes, NearbyTopBlocks};
 use feather_core::biomes::Biome;
 use feather_core::blocks::BlockId;
 use feather_core::chunk::Chunk;
@@ -11,8 +11,8 @@ impl FinishingGenerator for SnowFinisher {
     fn generate_for_chunk(
         &self,
         chunk: &mut Chunk,
-        biomes: &ChunkBiomes,
-        top_blocks: &TopBlocks,
+        biomes: &NearbyBiomes,
+        top_blocks: &NearbyTopBlocks,
         _seed: u64,
     ) {
         for x in 0..16 {
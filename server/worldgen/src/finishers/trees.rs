@@ -0,0 +1,572 @@
+use crate::util::shuffle_seed_for_chunk;
+use crate::{FinishingGenerator, NearbyBiomes, NearbyTopBlocks};
+use feather_core::biomes::Biome;
+use feather_core::blocks::{BlockId, BlockKind, SimplifiedBlockKind};
+use feather_core::chunk::Chunk;
+use feather_core::util::{BlockPosition, ChunkPosition};
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+/// Number of attempts to plant a tree in each chunk.
+const ATTEMPTS_PER_CHUNK: usize = 16;
+
+/// Finisher which generates trees depending on the biome.
+///
+/// Trees may cross chunk borders. To keep them intact regardless
+/// of the order in which chunks are generated, the trees of a chunk
+/// are planned using only the seed and the chunk position, and each
+/// chunk places the parts of the trees planned in its own and its
+/// neighbouring chunks which fall within it.
+#[derive(Default)]
+pub struct TreeFinisher;
+
+impl FinishingGenerator for TreeFinisher {
+    fn generate_for_chunk(
+        &self,
+        chunk: &mut Chunk,
+        biomes: &NearbyBiomes,
+        top_blocks: &NearbyTopBlocks,
+        seed: u64,
+    ) {
+        let center = chunk.position();
+
+        // Chunks are visited in the same (absolute) order
+        // from every chunk, so that overlapping trees
+        // are resolved consistently.
+        for offset_z in -1..=1 {
+            for offset_x in -1..=1 {
+                let origin = ChunkPosition::new(center.x + offset_x, center.z + offset_z);
+                let mut rng = XorShiftRng::seed_from_u64(shuffle_seed_for_chunk(seed, origin));
+
+                for _ in 0..ATTEMPTS_PER_CHUNK {
+                    // Always draw the same values, whether or not a tree
+                    // is planted, so that attempts don't affect each other.
+                    let x = offset_x * 16 + rng.gen_range(0, 16);
+                    let z = offset_z * 16 + rng.gen_range(0, 16);
+                    let chance: f32 = rng.gen();
+                    let choice: f32 = rng.gen();
+                    let tree_seed: u64 = rng.gen();
+
+                    let trees = biome_trees(biomes.biome_at(x, z));
+                    if chance >= trees.density {
+                        continue;
+                    }
+
+                    if let Some(kind) = trees.choose(choice) {
+                        let y = top_blocks.top_block_at(x, z) as i32 + 1;
+                        if let Some(tree) = Tree::generate(kind, x, y, z, tree_seed) {
+                            tree.place(chunk);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generates the tree a sapling of the given kind grows into,
+/// with its trunk starting at `pos`. Returns the dirt beneath
+/// the trunk, the logs and the leaves, in that order, or `None`
+/// if the block isn't a sapling or the tree wouldn't fit below
+/// the sky limit.
+///
+/// Dark oak trees grow from 2x2 saplings, in which case `pos`
+/// is the sapling with the lowest x and z coordinates.
+pub fn sapling_tree(
+    sapling: BlockKind,
+    pos: BlockPosition,
+    seed: u64,
+) -> Option<Vec<(BlockPosition, BlockId)>> {
+    let kind = match sapling {
+        BlockKind::OakSapling => TreeKind::Oak,
+        BlockKind::BirchSapling => TreeKind::Birch,
+        BlockKind::SpruceSapling => TreeKind::Spruce,
+        BlockKind::JungleSapling => TreeKind::Jungle,
+        BlockKind::AcaciaSapling => TreeKind::Acacia,
+        BlockKind::DarkOakSapling => TreeKind::DarkOak,
+        _ => return None,
+    };
+    let tree = Tree::generate(kind, pos.x, pos.y, pos.z, seed)?;
+
+    let to_pos = |&(x, y, z): &(i32, i32, i32)| BlockPosition::new(x, y, z);
+    let ground = tree.ground.iter().map(|pos| (to_pos(pos), BlockId::dirt()));
+    let logs = tree.logs.iter().map(|pos| (to_pos(pos), kind.log()));
+    let leaves = tree.leaves.iter().map(|&(x, y, z)| {
+        let leaves = kind.leaves().with_distance(tree.leaf_distance(x, y, z));
+        (BlockPosition::new(x, y, z), leaves)
+    });
+    Some(ground.chain(logs).chain(leaves).collect())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TreeKind {
+    Oak,
+    Birch,
+    Spruce,
+    Jungle,
+    Acacia,
+    DarkOak,
+}
+
+impl TreeKind {
+    fn log(self) -> BlockId {
+        match self {
+            TreeKind::Oak => BlockId::oak_log(),
+            TreeKind::Birch => BlockId::birch_log(),
+            TreeKind::Spruce => BlockId::spruce_log(),
+            TreeKind::Jungle => BlockId::jungle_log(),
+            TreeKind::Acacia => BlockId::acacia_log(),
+            TreeKind::DarkOak => BlockId::dark_oak_log(),
+        }
+    }
+
+    fn leaves(self) -> BlockId {
+        match self {
+            TreeKind::Oak => BlockId::oak_leaves(),
+            TreeKind::Birch => BlockId::birch_leaves(),
+            TreeKind::Spruce => BlockId::spruce_leaves(),
+            TreeKind::Jungle => BlockId::jungle_leaves(),
+            TreeKind::Acacia => BlockId::acacia_leaves(),
+            TreeKind::DarkOak => BlockId::dark_oak_leaves(),
+        }
+    }
+}
+
+/// The trees generated in a biome.
+struct BiomeTrees {
+    /// Chance for each attempt in a chunk to plant a tree.
+    density: f32,
+    /// Kinds of trees along with their weights.
+    kinds: &'static [(TreeKind, u32)],
+}
+
+impl BiomeTrees {
+    const fn new(density: f32, kinds: &'static [(TreeKind, u32)]) -> Self {
+        Self { density, kinds }
+    }
+
+    /// Chooses a kind of tree, weighted by the given
+    /// value in the range `[0, 1)`.
+    fn choose(&self, choice: f32) -> Option<TreeKind> {
+        let total: u32 = self.kinds.iter().map(|(_, weight)| *weight).sum();
+        let mut target = (choice * total as f32) as u32;
+
+        for (kind, weight) in self.kinds {
+            if target < *weight {
+                return Some(*kind);
+            }
+            target -= weight;
+        }
+
+        None
+    }
+}
+
+fn biome_trees(biome: Biome) -> BiomeTrees {
+    use TreeKind::*;
+
+    match biome {
+        Biome::Forest | Biome::FlowerForest | Biome::WoodedHills => {
+            BiomeTrees::new(0.6, &[(Oak, 4), (Birch, 1)])
+        }
+        Biome::BirchForest
+        | Biome::BirchForestHills
+        | Biome::TallBirchForest
+        | Biome::TallBirchHills => BiomeTrees::new(0.6, &[(Birch, 1)]),
+        Biome::DarkForest | Biome::DarkForestHills => {
+            BiomeTrees::new(0.9, &[(DarkOak, 4), (Oak, 1)])
+        }
+        Biome::Taiga
+        | Biome::TaigaHills
+        | Biome::TaigaMountains
+        | Biome::SnowyTaiga
+        | Biome::SnowyTaigaHills
+        | Biome::SnowyTaigaMountains
+        | Biome::GiantTreeTaiga
+        | Biome::GiantTreeTaigaHills
+        | Biome::GiantSpruceTaiga
+        | Biome::GiantSpruceTaigaHills => BiomeTrees::new(0.6, &[(Spruce, 1)]),
+        Biome::Jungle | Biome::JungleHills | Biome::ModifiedJungle => {
+            BiomeTrees::new(0.9, &[(Jungle, 3), (Oak, 1)])
+        }
+        Biome::JungleEdge | Biome::ModifiedJungleEdge => {
+            BiomeTrees::new(0.2, &[(Jungle, 3), (Oak, 1)])
+        }
+        Biome::Savanna
+        | Biome::SavannaPlateau
+        | Biome::ShatteredSavanna
+        | Biome::ShatteredSavannaPlateau => BiomeTrees::new(0.1, &[(Acacia, 4), (Oak, 1)]),
+        Biome::Mountains | Biome::WoodedMountains | Biome::MountainEdge => {
+            BiomeTrees::new(0.1, &[(Spruce, 1), (Oak, 1)])
+        }
+        Biome::Swamp | Biome::SwampHills => BiomeTrees::new(0.1, &[(Oak, 1)]),
+        Biome::WoodedBadlandsPlateau | Biome::ModifiedWoodedBadlandsPlateau => {
+            BiomeTrees::new(0.2, &[(Oak, 1)])
+        }
+        Biome::Plains | Biome::SunflowerPlains => BiomeTrees::new(0.03, &[(Oak, 1)]),
+        Biome::SnowyTundra | Biome::SnowyMountains => BiomeTrees::new(0.03, &[(Spruce, 1)]),
+        _ => BiomeTrees::new(0.0, &[]),
+    }
+}
+
+/// A tree, with block positions relative
+/// to the chunk currently being generated.
+struct Tree {
+    kind: TreeKind,
+    /// Positions of the dirt blocks beneath the trunk.
+    ground: Vec<(i32, i32, i32)>,
+    logs: Vec<(i32, i32, i32)>,
+    leaves: Vec<(i32, i32, i32)>,
+}
+
+impl Tree {
+    /// Generates a tree of the given kind with its trunk
+    /// starting at the given position. Returns `None` if there
+    /// is no ground to plant it on or it would not fit below
+    /// the sky limit.
+    fn generate(kind: TreeKind, x: i32, y: i32, z: i32, seed: u64) -> Option<Self> {
+        let mut rng = XorShiftRng::seed_from_u64(seed);
+        let mut tree = Tree {
+            kind,
+            ground: vec![(x, y - 1, z)],
+            logs: vec![],
+            leaves: vec![],
+        };
+
+        match kind {
+            TreeKind::Oak | TreeKind::Birch | TreeKind::Jungle => {
+                let height = match kind {
+                    TreeKind::Oak => rng.gen_range(4, 7),
+                    TreeKind::Birch => rng.gen_range(5, 8),
+                    _ => rng.gen_range(4, 11),
+                };
+                tree.blob(&mut rng, x, y, z, height);
+            }
+            TreeKind::Spruce => tree.spruce(&mut rng, x, y, z),
+            TreeKind::Acacia => tree.acacia(&mut rng, x, y, z),
+            TreeKind::DarkOak => tree.dark_oak(&mut rng, x, y, z),
+        }
+
+        let fits = tree
+            .logs
+            .iter()
+            .chain(&tree.leaves)
+            .all(|(_, y, _)| *y < 256);
+        if y > 1 && fits {
+            Some(tree)
+        } else {
+            None
+        }
+    }
+
+    /// Generates the round canopy of oak, birch and small jungle trees.
+    fn blob(&mut self, rng: &mut XorShiftRng, x: i32, y: i32, z: i32, height: i32) {
+        self.trunk(x, y, z, height);
+
+        let top = y + height;
+        for leaf_y in top - 3..=top {
+            let offset_y = leaf_y - top;
+            let radius = 1 - offset_y / 2;
+            for offset_x in -radius..=radius {
+                for offset_z in -radius..=radius {
+                    let corner = offset_x.abs() == radius && offset_z.abs() == radius;
+                    if corner && (offset_y == 0 || rng.gen()) {
+                        continue;
+                    }
+                    self.leaves.push((x + offset_x, leaf_y, z + offset_z));
+                }
+            }
+        }
+    }
+
+    /// Generates a spruce tree with its conical canopy.
+    fn spruce(&mut self, rng: &mut XorShiftRng, x: i32, y: i32, z: i32) {
+        let height = rng.gen_range(6, 10);
+        let bare = rng.gen_range(1, 3);
+        let max_radius = rng.gen_range(2, 4);
+
+        self.trunk(x, y, z, height - rng.gen_range(0, 3));
+
+        let mut radius = rng.gen_range(0, 2);
+        let mut layer_radius = 1;
+        let mut next_radius = 0;
+        for offset_y in 0..=height - bare {
+            let leaf_y = y + height - offset_y;
+            for offset_x in -radius..=radius {
+                for offset_z in -radius..=radius {
+                    if radius > 0 && offset_x.abs() == radius && offset_z.abs() == radius {
+                        continue;
+                    }
+                    self.leaves.push((x + offset_x, leaf_y, z + offset_z));
+                }
+            }
+
+            if radius >= layer_radius {
+                radius = next_radius;
+                next_radius = 1;
+                layer_radius = (layer_radius + 1).min(max_radius);
+            } else {
+                radius += 1;
+            }
+        }
+    }
+
+    /// Generates an acacia tree, whose trunk leans to one side.
+    fn acacia(&mut self, rng: &mut XorShiftRng, x: i32, y: i32, z: i32) {
+        let height = rng.gen_range(0, 3) + rng.gen_range(0, 3) + 5;
+        let (direction_x, direction_z) = match rng.gen_range(0, 4) {
+            0 => (1, 0),
+            1 => (-1, 0),
+            2 => (0, 1),
+            _ => (0, -1),
+        };
+        let lean_start = height - rng.gen_range(0, 4) - 1;
+        let mut lean = 3 - rng.gen_range(0, 3);
+
+        let (mut log_x, mut log_z) = (x, z);
+        for offset_y in 0..height {
+            if offset_y >= lean_start && lean > 0 {
+                log_x += direction_x;
+                log_z += direction_z;
+                lean -= 1;
+            }
+            self.logs.push((log_x, y + offset_y, log_z));
+        }
+
+        let top = y + height - 1;
+        for offset_x in -3i32..=3 {
+            for offset_z in -3i32..=3 {
+                if offset_x.abs() != 3 || offset_z.abs() != 3 {
+                    self.leaves.push((log_x + offset_x, top, log_z + offset_z));
+                }
+            }
+        }
+        for offset_x in -1..=1 {
+            for offset_z in -1..=1 {
+                self.leaves
+                    .push((log_x + offset_x, top + 1, log_z + offset_z));
+            }
+        }
+        for (offset_x, offset_z) in &[(2, 0), (-2, 0), (0, 2), (0, -2)] {
+            self.leaves
+                .push((log_x + offset_x, top + 1, log_z + offset_z));
+        }
+    }
+
+    /// Generates a dark oak tree, which has a 2x2 trunk.
+    fn dark_oak(&mut self, rng: &mut XorShiftRng, x: i32, y: i32, z: i32) {
+        let height = rng.gen_range(0, 3) + rng.gen_range(0, 2) + 6;
+
+        self.ground.clear();
+        for (offset_x, offset_z) in &[(0, 0), (1, 0), (0, 1), (1, 1)] {
+            self.ground.push((x + offset_x, y - 1, z + offset_z));
+            self.trunk(x + offset_x, y, z + offset_z, height);
+        }
+
+        let top = y + height - 1;
+        for (leaf_y, extent) in &[(top - 1, 3), (top, 2), (top + 1, 1)] {
+            let (low, high) = (-extent, extent + 1);
+            for offset_x in low..=high {
+                for offset_z in low..=high {
+                    let corner = (offset_x == low || offset_x == high)
+                        && (offset_z == low || offset_z == high);
+                    if !corner {
+                        self.leaves.push((x + offset_x, *leaf_y, z + offset_z));
+                    }
+                }
+            }
+        }
+    }
+
+    fn trunk(&mut self, x: i32, y: i32, z: i32, height: i32) {
+        for offset_y in 0..height {
+            self.logs.push((x, y + offset_y, z));
+        }
+    }
+
+    /// Places the blocks of this tree which fall within the chunk.
+    fn place(&self, chunk: &mut Chunk) {
+        for &(x, y, z) in &self.ground {
+            if let Some((x, y, z)) = local(x, y, z) {
+                chunk.set_block_at(x, y, z, BlockId::dirt());
+            }
+        }
+
+        let log = self.kind.log();
+        for &(x, y, z) in &self.logs {
+            if let Some((x, y, z)) = local(x, y, z) {
+                let block = chunk.block_at(x, y, z);
+                if block.is_replaceable() || block.simplified_kind() == SimplifiedBlockKind::Leaves
+                {
+                    chunk.set_block_at(x, y, z, log);
+                }
+            }
+        }
+
+        for &(x, y, z) in &self.leaves {
+            if let Some((local_x, local_y, local_z)) = local(x, y, z) {
+                if chunk.block_at(local_x, local_y, local_z).is_air() {
+                    let leaves = self
+                        .kind
+                        .leaves()
+                        .with_distance(self.leaf_distance(x, y, z));
+                    chunk.set_block_at(local_x, local_y, local_z, leaves);
+                }
+            }
+        }
+    }
+
+    /// Returns the distance of leaves at the given
+    /// position to the closest log of this tree.
+    fn leaf_distance(&self, x: i32, y: i32, z: i32) -> i32 {
+        self.logs
+            .iter()
+            .map(|(log_x, log_y, log_z)| (x - log_x).abs() + (y - log_y).abs() + (z - log_z).abs())
+            .min()
+            .unwrap_or(7)
+            .max(1)
+            .min(7)
+    }
+}
+
+/// Converts a position relative to the chunk into chunk-local
+/// coordinates, returning `None` if it lies outside the chunk.
+fn local(x: i32, y: i32, z: i32) -> Option<(usize, usize, usize)> {
+    if (0..16).contains(&x) && (0..256).contains(&y) && (0..16).contains(&z) {
+        Some((x as usize, y as usize, z as usize))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChunkBiomes, TopBlocks};
+
+    const GROUND: usize = 64;
+
+    /// Runs the finisher on the 3x3 chunks around the origin
+    /// in a flat forest world.
+    fn generate(biome: Biome, seed: u64) -> Vec<Chunk> {
+        let biomes = NearbyBiomes::from_vec(
+            (0..9)
+                .map(|_| ChunkBiomes::from_array([biome; 16 * 16]))
+                .collect(),
+        );
+        let top_blocks = NearbyTopBlocks::from_vec(
+            (0..9)
+                .map(|_| {
+                    let mut top_blocks = TopBlocks::new();
+                    for x in 0..16 {
+                        for z in 0..16 {
+                            top_blocks.set_top_block_at(x, z, GROUND);
+                        }
+                    }
+                    top_blocks
+                })
+                .collect(),
+        );
+
+        let mut chunks = vec![];
+        for z in -1..=1 {
+            for x in -1..=1 {
+                let mut chunk = Chunk::new(ChunkPosition::new(x, z));
+                TreeFinisher.generate_for_chunk(&mut chunk, &biomes, &top_blocks, seed);
+                chunks.push(chunk);
+            }
+        }
+        chunks
+    }
+
+    fn block_at(chunks: &[Chunk], x: i32, y: usize, z: i32) -> BlockId {
+        let index = ((x.div_euclid(16) + 1) + (z.div_euclid(16) + 1) * 3) as usize;
+        chunks[index].block_at(x.rem_euclid(16) as usize, y, z.rem_euclid(16) as usize)
+    }
+
+    #[test]
+    fn leaves_are_attached() {
+        for &biome in &[
+            Biome::Forest,
+            Biome::DarkForest,
+            Biome::Taiga,
+            Biome::Jungle,
+            Biome::Savanna,
+        ] {
+            let chunks = generate(biome, 1234);
+            let mut found_leaves = false;
+
+            // Every leaf block in the center chunk, including those
+            // of trees planted in neighbouring chunks, must belong
+            // to a tree whose trunk was placed as well.
+            for x in 0..16 {
+                for z in 0..16 {
+                    for y in GROUND..256 {
+                        let block = block_at(&chunks, x, y, z);
+                        if block.simplified_kind() != SimplifiedBlockKind::Leaves {
+                            continue;
+                        }
+                        found_leaves = true;
+
+                        let distance = block.distance().unwrap();
+                        let attached = (-distance..=distance).any(|dx| {
+                            (-distance..=distance).any(|dz| {
+                                (-distance..=distance).any(|dy| {
+                                    let y = y as i32 + dy;
+                                    dx.abs() + dy.abs() + dz.abs() <= distance
+                                        && y >= 0
+                                        && y < 256
+                                        && block_at(&chunks, x + dx, y as usize, z + dz)
+                                            .simplified_kind()
+                                            == SimplifiedBlockKind::Log
+                                })
+                            })
+                        });
+                        assert!(attached, "{:?} leaves at {} {} {}", biome, x, y, z);
+                    }
+                }
+            }
+
+            assert!(found_leaves, "no trees generated in {:?}", biome);
+        }
+    }
+
+    #[test]
+    fn no_trees_in_ocean() {
+        let chunks = generate(Biome::Ocean, 1234);
+        for x in 0..16 {
+            for z in 0..16 {
+                for y in 0..256 {
+                    assert!(block_at(&chunks, x, y, z).is_air());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn choose_tree_kind() {
+        let trees = biome_trees(Biome::Forest);
+        assert_eq!(trees.choose(0.0), Some(TreeKind::Oak));
+        assert_eq!(trees.choose(0.79), Some(TreeKind::Oak));
+        assert_eq!(trees.choose(0.81), Some(TreeKind::Birch));
+        assert_eq!(biome_trees(Biome::Desert).choose(0.5), None);
+    }
+
+    #[test]
+    fn grow_sapling() {
+        let pos = BlockPosition::new(-20, 64, 35);
+        let blocks = sapling_tree(BlockKind::BirchSapling, pos, 1234).unwrap();
+
+        assert_eq!(
+            blocks[0],
+            (pos + BlockPosition::new(0, -1, 0), BlockId::dirt())
+        );
+        assert_eq!(blocks[1], (pos, BlockId::birch_log()));
+        assert!(blocks
+            .iter()
+            .any(|(_, block)| block.simplified_kind() == SimplifiedBlockKind::Leaves));
+
+        assert!(sapling_tree(BlockKind::Stone, pos, 1234).is_none());
+        assert!(sapling_tree(BlockKind::OakSapling, BlockPosition::new(0, 254, 0), 1).is_none());
+    }
+}
@@ -6,6 +6,8 @@
 //! which allows configuration of a world generator pipeline.
 
 mod biomes;
+mod cache;
+mod carvers;
 mod composition;
 mod density_map;
 mod finishers;
@@ -18,19 +20,25 @@ pub use biomes::{DistortedVoronoiBiomeGenerator, TwoLevelBiomeGenerator};
 use bitvec::order::Local;
 use bitvec::slice::BitSlice;
 use bitvec::vec::BitVec;
+use cache::ChunkCache;
+pub use carvers::{CaveCarver, RavineCarver};
 pub use composition::BasicCompositionGenerator;
 pub use density_map::{DensityMapGeneratorImpl, HeightMapGenerator};
 use feather_core::biomes::Biome;
 use feather_core::blocks::BlockId;
 use feather_core::chunk::Chunk;
 use feather_core::util::ChunkPosition;
-use finishers::{ClumpedFoliageFinisher, SingleFoliageFinisher, SnowFinisher};
+pub use finishers::{sapling_tree, OreTable, OreVein, DEFAULT_ORE_TABLE_STR};
+use finishers::{
+    ClumpedFoliageFinisher, OreFinisher, SingleFoliageFinisher, SnowFinisher, TreeFinisher,
+};
 pub use noise::NoiseLerper;
 use num_traits::ToPrimitive;
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use smallvec::SmallVec;
 use std::fmt;
+use std::sync::Arc;
 pub use superflat::SuperflatWorldGenerator;
 
 /// Sea-level height.
@@ -39,6 +47,9 @@ pub const SEA_LEVEL: usize = 64;
 pub const SKY_LIMIT: usize = 255;
 /// Depth of an ocean.
 const OCEAN_DEPTH: usize = 30;
+/// Number of chunks whose biomes and density maps
+/// are cached by a `ComposableGenerator`.
+const CACHE_CAPACITY: usize = 1024;
 
 pub trait WorldGenerator: Send + Sync {
     /// Generates the chunk at the given position.
@@ -63,7 +74,8 @@ impl WorldGenerator for EmptyWorldGenerator {
 /// * Biomes - generates a biome grid.
 /// * Terrain density - generates the terrain density values using Perlin noise.
 /// * Terrain composition - sets the correct block types based on the biome and terrain density.
-/// * Finishing generators - generates final elements, such as grass, snow, and trees.
+/// * Carvers - carves caves and ravines out of the terrain.
+/// * Finishing generators - generates final elements, such as ores, grass, snow, and trees.
 ///
 /// This generator is based on [this document](http://cuberite.xoft.cz/docs/Generator.html).
 pub struct ComposableGenerator {
@@ -73,19 +85,29 @@ pub struct ComposableGenerator {
     density_map: Box<dyn DensityMapGenerator>,
     /// The composition generator.
     composition: Box<dyn CompositionGenerator>,
+    /// A vector of carvers used
+    /// by this composable generator.
+    carvers: SmallVec<[Box<dyn CarvingGenerator>; 2]>,
     /// A vector of finishing generators used
     /// by this composable generator.
     finishers: SmallVec<[Box<dyn FinishingGenerator>; 8]>,
     /// The world seed.
     seed: u64,
+    /// Biomes of recently generated chunks. Each chunk
+    /// needs the biomes of the surrounding 5x5 chunks.
+    biome_cache: ChunkCache<ChunkBiomes>,
+    /// Density maps of recently generated chunks. Each chunk
+    /// needs the density maps of the surrounding 3x3 chunks.
+    density_cache: ChunkCache<Arc<BitVec<Local, u8>>>,
 }
 
 impl ComposableGenerator {
     /// Creates a new `ComposableGenerator` with the given stages.
-    pub fn new<B, D, C, F>(
+    pub fn new<B, D, C, R, F>(
         biome: B,
         density_map: D,
         composition: C,
+        carvers: R,
         finishers: F,
         seed: u64,
     ) -> Self
@@ -93,22 +115,38 @@ impl ComposableGenerator {
         B: BiomeGenerator + 'static,
         D: DensityMapGenerator + 'static,
         C: CompositionGenerator + 'static,
+        R: IntoIterator<Item = Box<dyn CarvingGenerator>>,
         F: IntoIterator<Item = Box<dyn FinishingGenerator>>,
     {
         Self {
             biome: Box::new(biome),
             density_map: Box::new(density_map),
             composition: Box::new(composition),
+            carvers: carvers.into_iter().collect(),
             finishers: finishers.into_iter().collect(),
             seed,
+            biome_cache: ChunkCache::new(CACHE_CAPACITY),
+            density_cache: ChunkCache::new(CACHE_CAPACITY),
         }
     }
 
     /// A default composable generator, used
     /// for worlds with "default" world type.
     pub fn default_with_seed(seed: u64) -> Self {
+        Self::with_ore_table(seed, OreTable::default())
+    }
+
+    /// The default composable generator, placing
+    /// ores according to the given ore table.
+    pub fn with_ore_table(seed: u64, ore_table: OreTable) -> Self {
+        let carvers: Vec<Box<dyn CarvingGenerator>> = vec![
+            Box::new(CaveCarver::default()),
+            Box::new(RavineCarver::default()),
+        ];
         let finishers: Vec<Box<dyn FinishingGenerator>> = vec![
+            Box::new(OreFinisher::new(ore_table)),
             Box::new(SnowFinisher::default()),
+            Box::new(TreeFinisher::default()),
             Box::new(SingleFoliageFinisher::default()),
             Box::new(ClumpedFoliageFinisher::default()),
         ];
@@ -116,32 +154,51 @@ impl ComposableGenerator {
             TwoLevelBiomeGenerator::default(),
             DensityMapGeneratorImpl::default(),
             BasicCompositionGenerator::default(),
+            carvers,
             finishers,
             seed,
         )
     }
+
+    /// Returns the density map of the chunk at `pos`,
+    /// given the biomes surrounding it.
+    fn density_for_chunk(
+        &self,
+        pos: ChunkPosition,
+        biomes: &NearbyBiomes,
+        seed: u64,
+    ) -> Arc<BitVec<Local, u8>> {
+        self.density_cache.get_or_insert_with(pos, || {
+            Arc::new(self.density_map.generate_for_chunk(pos, biomes, seed))
+        })
+    }
 }
 
 impl WorldGenerator for ComposableGenerator {
     fn generate_chunk(&self, position: ChunkPosition) -> Chunk {
         let mut seed_shuffler = XorShiftRng::seed_from_u64(self.seed);
 
-        // Generate biomes for 3x3 grid of chunks around current chunk.
+        // Generate biomes for 5x5 grid of chunks around current chunk.
+        // The outer ring is needed to generate the terrain
+        // of the neighbouring chunks below. Biomes and density maps
+        // are cached, as neighbouring chunks need most of the same ones.
         let biome_seed = seed_shuffler.gen();
 
-        let mut biomes = vec![];
+        let mut biome_grid = vec![];
 
-        for z in -1..=1 {
-            for x in -1..=1 {
+        for z in -2..=2 {
+            for x in -2..=2 {
                 let pos = ChunkPosition::new(position.x + x, position.z + z);
-                biomes.push(self.biome.generate_for_chunk(pos, biome_seed));
+                let chunk_biomes = self
+                    .biome_cache
+                    .get_or_insert_with(pos, || self.biome.generate_for_chunk(pos, biome_seed));
+                biome_grid.push(chunk_biomes);
             }
         }
-        let biomes = NearbyBiomes::from_vec(biomes);
+        let biomes = NearbyBiomes::from_grid(&biome_grid, 0, 0);
 
-        let density_map =
-            self.density_map
-                .generate_for_chunk(position, &biomes, seed_shuffler.gen());
+        let density_seed = seed_shuffler.gen();
+        let density_map = self.density_for_chunk(position, &biomes, density_seed);
 
         let mut chunk = Chunk::new(position);
 
@@ -173,11 +230,10 @@ impl WorldGenerator for ComposableGenerator {
             }
         }
 
-        chunk.recalculate_heightmap();
-
-        // Finishers.
-        for finisher in &self.finishers {
-            finisher.generate_for_chunk(
+        // Carvers. They leave the surface intact,
+        // so the top blocks remain valid.
+        for carver in &self.carvers {
+            carver.generate_for_chunk(
                 &mut chunk,
                 &biomes.biomes[4],
                 &top_blocks,
@@ -185,6 +241,37 @@ impl WorldGenerator for ComposableGenerator {
             );
         }
 
+        chunk.recalculate_heightmap();
+
+        // Calculate top blocks in the neighbouring chunks from
+        // their density maps, so that finishers can place features
+        // crossing chunk borders without depending on the order
+        // in which chunks are generated.
+        let mut nearby_top_blocks = vec![];
+        for z in -1..=1 {
+            for x in -1..=1 {
+                if x == 0 && z == 0 {
+                    nearby_top_blocks.push(TopBlocks::new());
+                    continue;
+                }
+
+                let pos = ChunkPosition::new(position.x + x, position.z + z);
+                let biomes = NearbyBiomes::from_grid(&biome_grid, x, z);
+                let density = self.density_for_chunk(pos, &biomes, density_seed);
+                nearby_top_blocks.push(TopBlocks::from_density(
+                    density.as_bitslice(),
+                    &biomes.biomes[4],
+                ));
+            }
+        }
+        nearby_top_blocks[4] = top_blocks;
+        let top_blocks = NearbyTopBlocks::from_vec(nearby_top_blocks);
+
+        // Finishers.
+        for finisher in &self.finishers {
+            finisher.generate_for_chunk(&mut chunk, &biomes, &top_blocks, seed_shuffler.gen());
+        }
+
         // TODO: correct lighting.
         // Fill chunk with 15 light levels.
         chunk
@@ -241,17 +328,43 @@ pub trait CompositionGenerator: Send + Sync {
     );
 }
 
-/// A generator, run after composition,
+/// A generator, run after composition, which
+/// carves caves and ravines out of the terrain.
+///
+/// Carvers must not carve through the surface of the
+/// terrain, i.e. the given top blocks and those directly
+/// beneath them, since the top blocks of neighbouring chunks
+/// are known to finishers only as they were after composition.
+pub trait CarvingGenerator: Send + Sync {
+    /// Carves the given chunk.
+    ///
+    /// This function should be deterministic.
+    fn generate_for_chunk(
+        &self,
+        chunk: &mut Chunk,
+        biomes: &ChunkBiomes,
+        top_blocks: &TopBlocks,
+        seed: u64,
+    );
+}
+
+/// A generator, run after carving,
 /// which can add finishing elements to chunks,
 /// such as grass, trees, and snow.
+///
+/// Finishers are given the biomes and top blocks of the
+/// neighbouring chunks as well, so that features crossing
+/// chunk borders can be placed consistently in each chunk.
 pub trait FinishingGenerator: Send + Sync {
     /// Populates the given chunk with any
     /// finishing blocks.
+    ///
+    /// This function should be deterministic.
     fn generate_for_chunk(
         &self,
         chunk: &mut Chunk,
-        biomes: &ChunkBiomes,
-        top_blocks: &TopBlocks,
+        biomes: &NearbyBiomes,
+        top_blocks: &NearbyTopBlocks,
         seed: u64,
     );
 }
@@ -285,6 +398,55 @@ impl TopBlocks {
     pub fn set_top_block_at(&mut self, x: usize, z: usize, top: usize) {
         self.top_blocks[x + (z << 4)] = top as u8;
     }
+
+    /// Computes the top blocks of a chunk from its density map,
+    /// matching those of the chunk after composition.
+    ///
+    /// Columns without solid blocks are given a top block of 0.
+    pub fn from_density(density: &BitSlice<Local, u8>, biomes: &ChunkBiomes) -> Self {
+        let mut top_blocks = Self::new();
+        for x in 0..16 {
+            for z in 0..16 {
+                let top = if biomes.biome_at(x, z) == Biome::Ocean {
+                    // Oceans are filled with water up to sea level,
+                    // and there are no blocks above it.
+                    SEA_LEVEL
+                } else {
+                    (0..256)
+                        .rev()
+                        .find(|y| density[block_index(x, *y, z)])
+                        .unwrap_or(0)
+                };
+                top_blocks.set_top_block_at(x, z, top);
+            }
+        }
+        top_blocks
+    }
+}
+
+/// Represents the top blocks in a 3x3 grid of chunks,
+/// centered on the chunk currently being generated.
+#[derive(Default)]
+pub struct NearbyTopBlocks {
+    /// 2D array of top blocks, indexed in the
+    /// same way as `NearbyBiomes::biomes`.
+    pub top_blocks: Vec<TopBlocks>,
+}
+
+impl NearbyTopBlocks {
+    pub fn from_vec(top_blocks: Vec<TopBlocks>) -> Self {
+        Self { top_blocks }
+    }
+
+    /// Fetches the highest solid block for the given column.
+    ///
+    /// The column position is an offset from the
+    /// bottom-left corner of the center chunk.
+    pub fn top_block_at<N: ToPrimitive>(&self, x: N, z: N) -> usize {
+        let (index, local_x, local_z) = nearby_index(x, z);
+
+        self.top_blocks[index].top_block_at(local_x, local_z)
+    }
 }
 
 /// Represents the biomes in a 3x3 grid of chunks,
@@ -301,46 +463,54 @@ impl NearbyBiomes {
         Self { biomes }
     }
 
+    /// Creates the `NearbyBiomes` centered on the chunk at the given
+    /// offset from the center of a 5x5 grid of chunk biomes.
+    pub fn from_grid(grid: &[ChunkBiomes], offset_x: i32, offset_z: i32) -> Self {
+        let mut biomes = vec![];
+        for z in -1..=1 {
+            for x in -1..=1 {
+                let index = (offset_x + x + 2) + (offset_z + z + 2) * 5;
+                biomes.push(grid[index as usize].clone());
+            }
+        }
+        Self { biomes }
+    }
+
     /// Gets the biome at the given column position.
     ///
     /// The column position is an offset from the
     /// bottom-left corner of the center chunk.
     pub fn biome_at<N: ToPrimitive>(&self, x: N, z: N) -> Biome {
-        let (index, local_x, local_z) = self.index(x, z);
+        let (index, local_x, local_z) = nearby_index(x, z);
 
         self.biomes[index].biome_at(local_x, local_z)
     }
 
     pub fn set_biome_at<N: ToPrimitive>(&mut self, x: N, z: N, biome: Biome) {
-        let (index, local_x, local_z) = self.index(x, z);
+        let (index, local_x, local_z) = nearby_index(x, z);
 
         self.biomes[index].set_biome_at(local_x, local_z, biome);
     }
+}
 
-    fn index<N: ToPrimitive>(&self, ox: N, oz: N) -> (usize, usize, usize) {
-        let ox = ox.to_isize().unwrap();
-        let oz = oz.to_isize().unwrap();
-        let x = ox + 16;
-        let z = oz + 16;
+/// Returns the index of the chunk into a 3x3 grid and the
+/// chunk-local column coordinates for the given column position,
+/// which is an offset from the bottom-left corner of the center chunk.
+fn nearby_index<N: ToPrimitive>(ox: N, oz: N) -> (usize, usize, usize) {
+    let ox = ox.to_isize().unwrap();
+    let oz = oz.to_isize().unwrap();
 
-        let chunk_x = (x / 16) as usize;
-        let chunk_z = (z / 16) as usize;
+    let chunk_x = (ox.div_euclid(16) + 1) as usize;
+    let chunk_z = (oz.div_euclid(16) + 1) as usize;
 
-        let mut local_x = (ox % 16).abs() as usize;
-        let mut local_z = (oz % 16).abs() as usize;
+    let local_x = ox.rem_euclid(16) as usize;
+    let local_z = oz.rem_euclid(16) as usize;
 
-        if ox < 0 {
-            local_x = 16 - local_x;
-        }
-        if oz < 0 {
-            local_z = 16 - local_z;
-        }
-
-        (chunk_x + chunk_z * 3, local_x, local_z)
-    }
+    (chunk_x + chunk_z * 3, local_x, local_z)
 }
 
 /// Represents the biomes of a chunk.
+#[derive(Clone)]
 pub struct ChunkBiomes {
     /// 2D array of biome values. The biome for a given
     /// column local to the chunk can be indexed using
@@ -497,5 +667,7 @@ mod tests {
         assert_eq!(biomes.biome_at(16, 16), Biome::Taiga);
         assert_eq!(biomes.biome_at(-1, -1), Biome::Plains);
         assert_eq!(biomes.biome_at(-1, 0), Biome::BirchForest);
+        assert_eq!(biomes.biome_at(-16, -16), Biome::Plains);
+        assert_eq!(biomes.biome_at(31, 0), Biome::Mountains);
     }
 }